@@ -0,0 +1,56 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use dicom_rs::dataelem::{attribute_by_keyword, attribute_by_tag};
+use dicom_rs::modules::io::read_dicom_bytes;
+use std::hint::black_box;
+
+fn element(buf: &mut Vec<u8>, group: u16, element: u16, vr: &[u8; 2], value: &[u8]) {
+    buf.extend_from_slice(&group.to_le_bytes());
+    buf.extend_from_slice(&element.to_le_bytes());
+    buf.extend_from_slice(vr);
+    buf.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    buf.extend_from_slice(value);
+}
+
+/// Explicit VR Little Endian file of short elements, three in five of them
+/// private, like a vendor-heavy header.
+fn synthetic_header(elements: usize) -> Vec<u8> {
+    let mut buf = vec![0u8; 128];
+    buf.extend_from_slice(b"DICM");
+    element(&mut buf, 0x0002, 0x0010, b"UI", b"1.2.840.10008.1.2.1\0");
+    let public: [(u16, u16, &[u8; 2], &[u8]); 4] = [
+        (0x0008, 0x0060, b"CS", b"CT"),
+        (0x0010, 0x0010, b"PN", b"DOE^JOHN"),
+        (0x0018, 0x0050, b"DS", b"1.25"),
+        (0x0028, 0x0010, b"US", &[0x00, 0x02]),
+    ];
+    for i in 0..elements {
+        if i % 5 < 2 {
+            let (g, e, vr, v) = public[i % public.len()];
+            element(&mut buf, g, e, vr, v);
+        } else {
+            element(&mut buf, 0x0009, 0x1000 + (i % 0x100) as u16, b"LO", b"VENDOR");
+        }
+    }
+    buf
+}
+
+fn bench_header_parsing(c: &mut Criterion) {
+    let buf = synthetic_header(100_000);
+    let mut group = c.benchmark_group("header");
+    group.throughput(Throughput::Bytes(buf.len() as u64));
+    group.bench_function("read_dicom_bytes", |b| b.iter(|| read_dicom_bytes(black_box(&buf))));
+    group.finish();
+}
+
+fn bench_lookups(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lookup");
+    group.bench_function("tag_hit", |b| b.iter(|| attribute_by_tag(black_box("(0028,0010)"))));
+    group.bench_function("tag_miss_private", |b| b.iter(|| attribute_by_tag(black_box("(0009,1010)"))));
+    group.bench_function("tag_miss_public", |b| b.iter(|| attribute_by_tag(black_box("(0028,7FFE)"))));
+    group.bench_function("keyword_hit", |b| b.iter(|| attribute_by_keyword(black_box("PatientName"))));
+    group.bench_function("keyword_miss", |b| b.iter(|| attribute_by_keyword(black_box("PatientNmae"))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_header_parsing, bench_lookups);
+criterion_main!(benches);
@@ -0,0 +1,684 @@
+// Generates the registries in src/ from the DocBook sources of the DICOM
+// standard vendored under standard/. The output is written to OUT_DIR and
+// pulled in with include!().
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+const PART03: &str = "standard/part03.xml";
+const PART04: &str = "standard/part04.xml";
+const PART06: &str = "standard/part06.xml";
+const PART07: &str = "standard/part07.xml";
+const PART16: &str = "standard/part16.xml";
+const XML_NS: &str = "http://www.w3.org/XML/1998/namespace";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={PART03}");
+    println!("cargo:rerun-if-changed={PART04}");
+    println!("cargo:rerun-if-changed={PART06}");
+    println!("cargo:rerun-if-changed={PART07}");
+    println!("cargo:rerun-if-changed={PART16}");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    let part06 = fs::read_to_string(PART06).expect("failed to read standard/part06.xml");
+    let part06 = roxmltree::Document::parse(&part06).expect("failed to parse standard/part06.xml");
+    let part07 = fs::read_to_string(PART07).expect("failed to read standard/part07.xml");
+    let part07 = roxmltree::Document::parse(&part07).expect("failed to parse standard/part07.xml");
+
+    let uids = generate_uids(&part06);
+    fs::write(Path::new(&out_dir).join("uids.rs"), uids).expect("failed to write uids.rs");
+    let dicts = generate_dicts(&part06, &part07);
+    fs::write(Path::new(&out_dir).join("dicts.rs"), dicts).expect("failed to write dicts.rs");
+
+    let part03 = fs::read_to_string(PART03).expect("failed to read standard/part03.xml");
+    let part03 = roxmltree::Document::parse(&part03).expect("failed to parse standard/part03.xml");
+    let part04 = fs::read_to_string(PART04).expect("failed to read standard/part04.xml");
+    let part04 = roxmltree::Document::parse(&part04).expect("failed to parse standard/part04.xml");
+    let iods = generate_iods(&part03, &part04);
+    fs::write(Path::new(&out_dir).join("iods.rs"), iods).expect("failed to write iods.rs");
+
+    let part16 = fs::read_to_string(PART16).expect("failed to read standard/part16.xml");
+    let part16 = roxmltree::Document::parse(&part16).expect("failed to parse standard/part16.xml");
+    let codes = generate_codes(&part16);
+    fs::write(Path::new(&out_dir).join("codes.rs"), codes).expect("failed to write codes.rs");
+}
+
+/// Text of a table cell with the zero-width spaces used for line breaking
+/// in the standard removed and whitespace collapsed.
+fn cell_text(cell: roxmltree::Node) -> String {
+    let raw: String = cell
+        .descendants()
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect();
+    raw.replace('\u{200b}', "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Element with the given xml:id.
+fn by_id<'a, 'input>(doc: &'a roxmltree::Document<'input>, id: &str) -> Option<roxmltree::Node<'a, 'input>> {
+    doc.descendants().find(|n| n.attribute((XML_NS, "id")) == Some(id))
+}
+
+/// Body rows of a table element, as cell nodes.
+fn table_cells<'a, 'input>(table: roxmltree::Node<'a, 'input>) -> Vec<Vec<roxmltree::Node<'a, 'input>>> {
+    let tbody = table
+        .children()
+        .find(|n| n.has_tag_name("tbody"))
+        .unwrap_or_else(|| panic!("table {:?} has no body", table.attribute((XML_NS, "id"))));
+    tbody
+        .children()
+        .filter(|n| n.has_tag_name("tr"))
+        .map(|tr| tr.children().filter(|n| n.has_tag_name("td")).collect())
+        .collect()
+}
+
+/// Body rows of the table with the given xml:id, as cell texts.
+fn table_rows(doc: &roxmltree::Document, id: &str) -> Vec<Vec<String>> {
+    let table = by_id(doc, id)
+        .filter(|n| n.has_tag_name("table"))
+        .unwrap_or_else(|| panic!("table {id} not found"));
+    table_cells(table)
+        .into_iter()
+        .map(|cells| cells.into_iter().map(cell_text).collect())
+        .collect()
+}
+
+/// Target of the first xref or olink in a cell.
+fn cell_link<'a>(cell: roxmltree::Node<'a, '_>) -> Option<&'a str> {
+    cell.descendants().find_map(|n| {
+        if n.has_tag_name("xref") {
+            n.attribute("linkend")
+        } else if n.has_tag_name("olink") {
+            n.attribute("targetptr")
+        } else {
+            None
+        }
+    })
+}
+
+/// True for a node inside a hand-written excerpt, marked role="excerpt":
+/// its tables hold only some of the published rows. The published files
+/// carry no such role.
+fn is_excerpt(node: roxmltree::Node) -> bool {
+    node.ancestors().any(|n| n.attribute("role") == Some("excerpt"))
+}
+
+/// Edition of the standard, e.g. "2025c", taken from the book subtitle.
+fn edition(doc: &roxmltree::Document) -> String {
+    doc.descendants()
+        .find(|n| n.has_tag_name("subtitle"))
+        .map(cell_text)
+        .and_then(|s| s.split_whitespace().nth(2).map(str::to_string))
+        .expect("subtitle with the edition not found")
+}
+
+fn uid_type_variant(uid_type: &str) -> &'static str {
+    match uid_type {
+        "Transfer Syntax" => "TransferSyntax",
+        "SOP Class" => "SopClass",
+        "Meta SOP Class" => "MetaSopClass",
+        "Well-known SOP Instance" => "WellKnownSopInstance",
+        "Service Class" => "ServiceClass",
+        "Coding Scheme" => "CodingScheme",
+        "DICOM UIDs as a Coding Scheme" => "CodingScheme",
+        "Application Context Name" => "ApplicationContextName",
+        "Application Hosting Model" => "ApplicationHostingModel",
+        "Mapping Resource" => "MappingResource",
+        "LDAP OID" => "LdapOid",
+        "Synchronization Frame of Reference" => "SynchronizationFrameOfReference",
+        "Well-known frame of reference" => "WellKnownFrameOfReference",
+        _ => "Other",
+    }
+}
+
+struct UidRow {
+    uid: String,
+    name: String,
+    keyword: String,
+    variant: &'static str,
+}
+
+fn generate_uids(doc: &roxmltree::Document) -> String {
+    let mut rows: Vec<UidRow> = Vec::new();
+    for cells in table_rows(doc, "table_A-1") {
+        if cells.len() < 4 || cells[0].is_empty() {
+            continue;
+        }
+        rows.push(UidRow {
+            uid: cells[0].clone(),
+            name: cells[1].clone(),
+            keyword: cells[2].clone(),
+            variant: uid_type_variant(&cells[3]),
+        });
+    }
+    for cells in table_rows(doc, "table_A-2") {
+        if cells.len() < 3 || cells[0].is_empty() {
+            continue;
+        }
+        rows.push(UidRow {
+            uid: cells[0].clone(),
+            name: cells[1].clone(),
+            keyword: cells[2].clone(),
+            variant: "WellKnownFrameOfReference",
+        });
+    }
+    rows.sort_by(|a, b| a.uid.cmp(&b.uid));
+    rows.dedup_by(|a, b| a.uid == b.uid);
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by build.rs from PS3.6 {} Tables A-1 and A-2. Do not edit.", edition(doc)).unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static UIDS: &[DicomUid] = &[").unwrap();
+    for r in &rows {
+        writeln!(
+            out,
+            "    DicomUid {{ uid: {:?}, name: {:?}, keyword: {:?}, uid_type: UidType::{}, retired: {} }},",
+            r.uid,
+            r.name,
+            r.keyword,
+            r.variant,
+            r.name.ends_with("(Retired)")
+        )
+        .unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+
+    let mut by_keyword: Vec<(&str, usize)> = rows
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| !r.keyword.is_empty())
+        .map(|(i, r)| (r.keyword.as_str(), i))
+        .collect();
+    by_keyword.sort();
+    writeln!(out, "pub static UID_KEYWORD_INDEX: &[(&str, usize)] = &[").unwrap();
+    for (kw, i) in by_keyword {
+        writeln!(out, "    ({kw:?}, {i}),").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    out
+}
+
+fn vr_variant(vr: &str) -> Option<&'static str> {
+    Some(match vr {
+        "AE" => "Ae",
+        "AS" => "As",
+        "AT" => "At",
+        "CS" => "Cs",
+        "DA" => "Da",
+        "DS" => "Ds",
+        "DT" => "Dt",
+        "FD" => "Fd",
+        "FL" => "Fl",
+        "IS" => "Is",
+        "LO" => "Lo",
+        "LT" => "Lt",
+        "OB" => "Ob",
+        "OB or OW" => "ObOrOw",
+        "OD" => "Od",
+        "OF" => "Of",
+        "OL" => "Ol",
+        "OV" => "Ov",
+        "OW" => "Ow",
+        "PN" => "Pn",
+        "SH" => "Sh",
+        "SL" => "Sl",
+        "SQ" => "Sq",
+        "SS" => "Ss",
+        "ST" => "St",
+        "SV" => "Sv",
+        "TM" => "Tm",
+        "UC" => "Uc",
+        "UI" => "Ui",
+        "UL" => "Ul",
+        "UN" => "Un",
+        "UR" => "Ur",
+        "US" => "Us",
+        "US or OW" => "UsOrOw",
+        "US or SS" => "UsOrSs",
+        "US or SS or OW" => "UsOrSsOrOw",
+        "UT" => "Ut",
+        "UV" => "Uv",
+        // Items and delimiters have no VR ("See Note")
+        _ => return None,
+    })
+}
+
+struct AttributeRow {
+    tag: String,
+    name: String,
+    keyword: String,
+    vr: Option<&'static str>,
+    vm: String,
+    retired: bool,
+}
+
+/// Rows of a registry table laid out as Tag, Name, Keyword, VR, VM and a
+/// trailing note column ("RET" for retired elements).
+fn attribute_rows(doc: &roxmltree::Document, id: &str, retired_table: bool) -> Vec<AttributeRow> {
+    table_rows(doc, id)
+        .into_iter()
+        .filter(|cells| cells.len() >= 5 && cells[0].starts_with('('))
+        .map(|cells| AttributeRow {
+            // (60xx,3000) is written (60XX,3000) in our tables
+            tag: cells[0].to_ascii_uppercase(),
+            name: cells[1].clone(),
+            keyword: cells[2].clone(),
+            vr: vr_variant(&cells[3]),
+            vm: cells[4].clone(),
+            retired: retired_table || cells.get(5).is_some_and(|note| note.starts_with("RET")),
+        })
+        .collect()
+}
+
+fn generate_dicts(part06: &roxmltree::Document, part07: &roxmltree::Document) -> String {
+    let mut rows = Vec::new();
+    rows.extend(attribute_rows(part07, "table_E.1-1", false));
+    rows.extend(attribute_rows(part07, "table_E.2-1", true));
+    for table in ["table_6-1", "table_7-1", "table_8-1", "table_9-1"] {
+        rows.extend(attribute_rows(part06, table, false));
+    }
+    rows.sort_by(|a, b| a.tag.cmp(&b.tag));
+    rows.dedup_by(|a, b| a.tag == b.tag);
+
+    let edition = edition(part06);
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Generated by build.rs from PS3.6 {edition} Tables 6-1, 7-1, 8-1 and 9-1 and PS3.7 {} Annex E. Do not edit.",
+        self::edition(part07)
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "/// Edition of the standard the dictionary was generated from").unwrap();
+    writeln!(out, "pub const STANDARD_EDITION: &str = {edition:?};").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static ATTRIBUTES: &[DicomAttribute] = &[").unwrap();
+    for r in &rows {
+        let vr = match r.vr {
+            Some(v) => format!("Some(DicomVr::{v})"),
+            None => "None".to_string(),
+        };
+        writeln!(
+            out,
+            "    DicomAttribute {{ tag: {:?}, name: {:?}, keyword: {:?}, vr: {vr}, vm: {:?}, retired: {} }},",
+            r.tag, r.name, r.keyword, r.vm, r.retired
+        )
+        .unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+
+    // Exact tags keyed on (group << 16 | element); repeating ones such as
+    // (60XX,3000) are grouped by the mask that blanks out their XX digits.
+    let mut exact: Vec<(u32, usize)> = Vec::new();
+    let mut repeating: Vec<(u32, Vec<(u32, usize)>)> = Vec::new();
+    for (i, r) in rows.iter().enumerate() {
+        let (value, mask) = tag_value_and_mask(&r.tag);
+        if mask == 0xFFFF_FFFF {
+            exact.push((value, i));
+        } else {
+            match repeating.iter_mut().find(|(m, _)| *m == mask) {
+                Some((_, entries)) => entries.push((value, i)),
+                None => repeating.push((mask, vec![(value, i)])),
+            }
+        }
+    }
+    exact.sort();
+    writeln!(out, "pub static TAG_INDEX: &[(u32, usize)] = &[").unwrap();
+    for (value, i) in exact {
+        writeln!(out, "    (0x{value:08X}, {i}),").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static REPEATING_TAG_INDEX: &[(u32, &[(u32, usize)])] = &[").unwrap();
+    for (mask, mut entries) in repeating {
+        entries.sort();
+        writeln!(out, "    (0x{mask:08X}, &[").unwrap();
+        for (value, i) in entries {
+            writeln!(out, "        (0x{value:08X}, {i}),").unwrap();
+        }
+        writeln!(out, "    ]),").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+
+    let mut by_keyword: Vec<(&str, usize)> = rows
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| !r.keyword.is_empty())
+        .map(|(i, r)| (r.keyword.as_str(), i))
+        .collect();
+    by_keyword.sort();
+    writeln!(out, "pub static KEYWORD_INDEX: &[(&str, usize)] = &[").unwrap();
+    for (kw, i) in by_keyword {
+        writeln!(out, "    ({kw:?}, {i}),").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    out
+}
+
+/// Numeric value of a "(gggg,eeee)" tag with any X digits zeroed, and the
+/// mask selecting the digits that are fixed.
+fn tag_value_and_mask(tag: &str) -> (u32, u32) {
+    let digits: Vec<char> = tag.chars().filter(|c| c.is_ascii_hexdigit() || *c == 'X').collect();
+    assert_eq!(digits.len(), 8, "malformed tag {tag}");
+    let mut value = 0u32;
+    let mut mask = 0u32;
+    for c in digits {
+        value <<= 4;
+        mask <<= 4;
+        if let Some(d) = c.to_digit(16) {
+            value |= d;
+            mask |= 0xF;
+        }
+    }
+    (value, mask)
+}
+
+/// One row of a module or macro table after includes are expanded; depth
+/// counts the ">" nesting marks.
+struct ModuleRow {
+    depth: usize,
+    tag: u32,
+    name: String,
+    attr_type: &'static str,
+}
+
+fn attribute_type_variant(attr_type: &str) -> &'static str {
+    match attr_type {
+        "1" => "Type1",
+        "1C" => "Type1C",
+        "2" => "Type2",
+        "2C" => "Type2C",
+        "3" => "Type3",
+        _ => panic!("unknown attribute type {attr_type}"),
+    }
+}
+
+/// Rows of the attribute table with the given xml:id, with "Include" rows
+/// replaced by the rows of the referenced macro table. Returns false when
+/// the table or an included one is an excerpt.
+fn module_rows(doc: &roxmltree::Document, id: &str, base_depth: usize, out: &mut Vec<ModuleRow>) -> bool {
+    let table = by_id(doc, id)
+        .filter(|n| n.has_tag_name("table"))
+        .unwrap_or_else(|| panic!("table {id} not found"));
+    let mut complete = !is_excerpt(table);
+    for cells in table_cells(table) {
+        let first = cell_text(cells[0]);
+        let name = first.trim_start_matches('>');
+        let depth = base_depth + first.len() - name.len();
+        if name.starts_with("Include") {
+            let target = cell_link(cells[0]).unwrap_or_else(|| panic!("include without a link in {id}"));
+            complete &= module_rows(doc, target, depth, out);
+            continue;
+        }
+        if cells.len() < 3 {
+            continue;
+        }
+        let tag = cell_text(cells[1]).to_ascii_uppercase();
+        out.push(ModuleRow {
+            depth,
+            tag: tag_value_and_mask(&tag).0,
+            name: name.to_string(),
+            attr_type: attribute_type_variant(&cell_text(cells[2])),
+        });
+    }
+    complete
+}
+
+/// Writes rows[*i..] at the given depth as a nested ModuleAttribute slice.
+fn write_module_rows(out: &mut String, rows: &[ModuleRow], i: &mut usize, depth: usize) {
+    let indent = "    ".repeat(depth + 2);
+    out.push_str("&[\n");
+    while *i < rows.len() && rows[*i].depth == depth {
+        let r = &rows[*i];
+        *i += 1;
+        write!(
+            out,
+            "{indent}ModuleAttribute {{ tag: 0x{:08X}, name: {:?}, attr_type: AttributeType::{}, items: ",
+            r.tag, r.name, r.attr_type
+        )
+        .unwrap();
+        if *i < rows.len() && rows[*i].depth > depth {
+            write_module_rows(out, rows, i, depth + 1);
+        } else {
+            out.push_str("&[]");
+        }
+        out.push_str(" },\n");
+    }
+    write!(out, "{}]", "    ".repeat(depth + 1)).unwrap();
+}
+
+/// Title of a section without its trailing word, e.g. "Patient" for the
+/// "Patient Module" section.
+fn section_title(section: roxmltree::Node, suffix: &str) -> String {
+    let title = section
+        .children()
+        .find(|n| n.has_tag_name("title"))
+        .map(cell_text)
+        .unwrap_or_default();
+    title.strip_suffix(suffix).unwrap_or(&title).to_string()
+}
+
+fn generate_iods(part03: &roxmltree::Document, part04: &roxmltree::Document) -> String {
+    // SOP Classes by the PS3.3 section of their IOD (PS3.4 Table B.5-1)
+    let mut sop_classes: Vec<(String, String)> = Vec::new();
+    let table = by_id(part04, "table_B.5-1").expect("table B.5-1 not found");
+    for cells in table_cells(table) {
+        if let (Some(uid), Some(link)) = (cells.get(1), cells.get(2).and_then(|c| cell_link(*c))) {
+            sop_classes.push((link.to_string(), cell_text(*uid)));
+        }
+    }
+
+    // IOD module tables, e.g. "CT Image IOD Modules"
+    let iod_tables: Vec<roxmltree::Node> = part03
+        .descendants()
+        .filter(|n| n.has_tag_name("table"))
+        .filter(|n| {
+            n.children()
+                .find(|c| c.has_tag_name("caption"))
+                .is_some_and(|c| cell_text(c).ends_with(" IOD Modules"))
+        })
+        .collect();
+
+    let mut modules: Vec<(String, String)> = Vec::new(); // (section id, generated code)
+    let mut iods = String::new();
+    let mut by_sop_class: Vec<(String, usize)> = Vec::new();
+    for (index, table) in iod_tables.iter().enumerate() {
+        let caption = table.children().find(|c| c.has_tag_name("caption")).map(cell_text).unwrap();
+        let name = caption.strip_suffix(" IOD Modules").unwrap();
+        // The IOD section is the outermost one that PS3.4 links to
+        let iod_section = table
+            .ancestors()
+            .filter(|n| n.has_tag_name("section"))
+            .filter_map(|n| n.attribute((XML_NS, "id")))
+            .filter(|id| sop_classes.iter().any(|(link, _)| link == id))
+            .last()
+            .unwrap_or_else(|| panic!("no SOP Class refers to the {name} IOD"));
+        let uids: Vec<&str> = sop_classes
+            .iter()
+            .filter(|(link, _)| link == iod_section)
+            .map(|(_, uid)| uid.as_str())
+            .collect();
+        for uid in &uids {
+            by_sop_class.push((uid.to_string(), index));
+        }
+        writeln!(
+            iods,
+            "    Iod {{\n        name: {name:?},\n        section: {:?},\n        sop_classes: &{uids:?},\n        complete: {},\n        modules: &[",
+            iod_section.trim_start_matches("sect_"),
+            !is_excerpt(*table)
+        )
+        .unwrap();
+
+        // The Information Entity cell spans the rows of its modules
+        let mut entity = String::new();
+        for cells in table_cells(*table) {
+            let cells = if cells.len() == 4 {
+                entity = cell_text(cells[0]);
+                &cells[1..]
+            } else {
+                &cells[..]
+            };
+            let section_id = cell_link(cells[1]).expect("module row without a reference");
+            let usage = cell_text(cells[2]);
+            let usage = match usage.as_str() {
+                "M" => "ModuleUsage::Mandatory".to_string(),
+                "U" => "ModuleUsage::UserOption".to_string(),
+                c => format!(
+                    "ModuleUsage::Conditional({:?})",
+                    c.trim_start_matches('C').trim_start_matches([' ', '-'])
+                ),
+            };
+            let ident = format!("MODULE_{}", section_id.trim_start_matches("sect_").replace(['.', '-'], "_"));
+            writeln!(
+                iods,
+                "            IodModule {{ information_entity: {entity:?}, module: &{ident}, usage: {usage} }},"
+            )
+            .unwrap();
+            if modules.iter().any(|(id, _)| id == section_id) {
+                continue;
+            }
+            let section = by_id(part03, section_id).unwrap_or_else(|| panic!("section {section_id} not found"));
+            let module_table = section
+                .descendants()
+                .find(|n| n.has_tag_name("table"))
+                .and_then(|n| n.attribute((XML_NS, "id")))
+                .unwrap_or_else(|| panic!("section {section_id} has no attribute table"));
+            let mut rows = Vec::new();
+            let complete = module_rows(part03, module_table, 0, &mut rows);
+            let mut code = String::new();
+            writeln!(
+                code,
+                "static {ident}: Module = Module {{\n    name: {:?},\n    section: {:?},\n    complete: {complete},\n    attributes: ",
+                section_title(section, " Module"),
+                section_id.trim_start_matches("sect_")
+            )
+            .unwrap();
+            code.pop();
+            let mut i = 0;
+            write_module_rows(&mut code, &rows, &mut i, 0);
+            assert_eq!(i, rows.len(), "bad nesting in {module_table}");
+            code.push_str(",\n};\n\n");
+            modules.push((section_id.to_string(), code));
+        }
+        writeln!(iods, "        ],\n    }},").unwrap();
+    }
+    by_sop_class.sort();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Generated by build.rs from PS3.3 {} Annexes A and C and PS3.4 {} Table B.5-1. Do not edit.",
+        edition(part03),
+        edition(part04)
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    for (_, code) in &modules {
+        out.push_str(code);
+    }
+    writeln!(out, "pub static MODULES: &[&Module] = &[").unwrap();
+    for (id, _) in &modules {
+        writeln!(out, "    &MODULE_{},", id.trim_start_matches("sect_").replace(['.', '-'], "_")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static IODS: &[Iod] = &[").unwrap();
+    out.push_str(&iods);
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static IOD_SOP_CLASS_INDEX: &[(&str, usize)] = &[").unwrap();
+    for (uid, i) in by_sop_class {
+        writeln!(out, "    ({uid:?}, {i}),").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    out
+}
+
+/// Concepts of a context group table as (scheme, value, meaning), with
+/// "Include CID nnn" rows replaced by the concepts of that group. Returns
+/// false when the table or an included one is an excerpt.
+fn context_group_rows(doc: &roxmltree::Document, table: roxmltree::Node, out: &mut Vec<[String; 3]>) -> bool {
+    let mut complete = !is_excerpt(table);
+    for cells in table_cells(table) {
+        if cell_text(cells[0]).starts_with("Include") {
+            let target = cell_link(cells[0]).expect("include without a link");
+            let section = by_id(doc, target).unwrap_or_else(|| panic!("section {target} not found"));
+            let table = section.descendants().find(|n| n.has_tag_name("table")).unwrap();
+            complete &= context_group_rows(doc, table, out);
+            continue;
+        }
+        if cells.len() < 3 {
+            continue;
+        }
+        let row = [cell_text(cells[0]), cell_text(cells[1]), cell_text(cells[2])];
+        if !out.contains(&row) {
+            out.push(row);
+        }
+    }
+    complete
+}
+
+fn generate_codes(doc: &roxmltree::Document) -> String {
+    let mut schemes: Vec<Vec<String>> = table_rows(doc, "table_8-1")
+        .into_iter()
+        .filter(|cells| cells.len() >= 3 && !cells[0].is_empty())
+        .collect();
+    schemes.sort_by(|a, b| a[0].cmp(&b[0]));
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by build.rs from PS3.16 {} Table 8-1 and Annex B. Do not edit.", edition(doc)).unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static CODING_SCHEMES: &[CodingScheme] = &[").unwrap();
+    for s in &schemes {
+        writeln!(out, "    CodingScheme {{ designator: {:?}, uid: {:?}, name: {:?} }},", s[0], s[1], s[2]).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+
+    // Context groups are the sections titled "CID nnn Name"
+    let mut groups: Vec<(u32, String, bool, Vec<[String; 3]>)> = Vec::new();
+    for section in doc.descendants().filter(|n| n.has_tag_name("section")) {
+        let title = section_title(section, "");
+        let Some((cid, name)) = title
+            .strip_prefix("CID ")
+            .and_then(|rest| rest.split_once(' '))
+            .and_then(|(cid, name)| Some((cid.parse::<u32>().ok()?, name)))
+        else {
+            continue;
+        };
+        let table = section
+            .descendants()
+            .find(|n| n.has_tag_name("table"))
+            .unwrap_or_else(|| panic!("CID {cid} has no table"));
+        let mut rows = Vec::new();
+        let complete = context_group_rows(doc, table, &mut rows);
+        groups.push((cid, name.to_string(), complete, rows));
+    }
+    groups.sort_by_key(|g| g.0);
+    writeln!(out, "pub static CONTEXT_GROUPS: &[ContextGroup] = &[").unwrap();
+    for (cid, name, complete, rows) in &groups {
+        writeln!(
+            out,
+            "    ContextGroup {{\n        cid: {cid},\n        name: {name:?},\n        complete: {complete},\n        concepts: &["
+        )
+        .unwrap();
+        for [scheme, value, meaning] in rows {
+            writeln!(
+                out,
+                "            CodedConcept {{ scheme: {scheme:?}, value: {value:?}, meaning: {meaning:?} }},"
+            )
+            .unwrap();
+        }
+        writeln!(out, "        ],\n    }},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    out
+}
@@ -0,0 +1,84 @@
+//! Bulk byte order conversion for arrays of 16, 32 and 64-bit values, such
+//! as OW/OL/OF/OD values and native Pixel Data. Eight bytes are swapped at
+//! a time with word-wide shifts and masks, in fixed-size blocks written so
+//! the compiler can auto-vectorize them, without unsafe code or CPU feature
+//! detection.
+
+use crate::uids::Endianness;
+
+const EVEN_BYTES: u64 = 0x00FF_00FF_00FF_00FF;
+const EVEN_PAIRS: u64 = 0x0000_FFFF_0000_FFFF;
+
+fn swap_word(word: &mut [u8], swap: &impl Fn(u64) -> u64) {
+    let value = u64::from_ne_bytes(word.try_into().unwrap());
+    word.copy_from_slice(&swap(value).to_ne_bytes());
+}
+
+/// Applies `swap` to each whole 8-byte word and reverses the `width`-byte
+/// values after the last one. Words are taken in fixed blocks of eight so
+/// the inner loop unrolls into vector instructions.
+fn swap_words(data: &mut [u8], width: usize, swap: impl Fn(u64) -> u64) {
+    let mut blocks = data.chunks_exact_mut(64);
+    for block in &mut blocks {
+        block.chunks_exact_mut(8).for_each(|word| swap_word(word, &swap));
+    }
+    let mut words = blocks.into_remainder().chunks_exact_mut(8);
+    for word in &mut words {
+        swap_word(word, &swap);
+    }
+    for value in words.into_remainder().chunks_exact_mut(width) {
+        value.reverse();
+    }
+}
+
+/// Reverses the bytes of every `width`-byte value in place. Widths 2, 4
+/// and 8 are supported; width 1 leaves the data as it is. Trailing bytes
+/// that do not make a whole value are left alone.
+pub fn swap_bytes(data: &mut [u8], width: usize) {
+    match width {
+        // Byte order within each 8-byte word is the same whichever way the
+        // word is loaded, so these hold on big endian hosts too
+        2 => swap_words(data, 2, |w| (w & EVEN_BYTES) << 8 | (w >> 8) & EVEN_BYTES),
+        4 => swap_words(data, 4, |w| {
+            let w = (w & EVEN_BYTES) << 8 | (w >> 8) & EVEN_BYTES;
+            (w & EVEN_PAIRS) << 16 | (w >> 16) & EVEN_PAIRS
+        }),
+        8 => swap_words(data, 8, u64::swap_bytes),
+        1 => {}
+        other => eprintln!("Cannot swap {other}-byte values"),
+    }
+}
+
+/// Converts `width`-byte values in place from one byte order to the other;
+/// does nothing when they are the same.
+pub fn convert_in_place(data: &mut [u8], width: usize, from: Endianness, to: Endianness) {
+    if from != to {
+        swap_bytes(data, width);
+    }
+}
+
+/// Copy of the data with `width`-byte values in little endian order
+pub fn to_little_endian(data: &[u8], width: usize, from: Endianness) -> Vec<u8> {
+    let mut out = data.to_vec();
+    convert_in_place(&mut out, width, from, Endianness::Little);
+    out
+}
+
+macro_rules! values_from_bytes {
+    ($name:ident, $t:ty, $n:literal) => {
+        #[doc = concat!("Whole ", stringify!($t), " values of a byte array in the given order")]
+        pub fn $name(bytes: &[u8], endian: Endianness) -> Vec<$t> {
+            let chunks = bytes.chunks_exact($n).map(|c| <[u8; $n]>::try_from(c).unwrap());
+            match endian {
+                Endianness::Little => chunks.map(<$t>::from_le_bytes).collect(),
+                Endianness::Big => chunks.map(<$t>::from_be_bytes).collect(),
+            }
+        }
+    };
+}
+
+values_from_bytes!(u16s, u16, 2);
+values_from_bytes!(u32s, u32, 4);
+values_from_bytes!(u64s, u64, 8);
+values_from_bytes!(f32s, f32, 4);
+values_from_bytes!(f64s, f64, 8);
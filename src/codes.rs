@@ -0,0 +1,207 @@
+use crate::dataelem::{attribute_by_keyword, attribute_by_tag, DataElement, DataElementValue, DicomVr};
+use crate::dataset::Dataset;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Coded concept as carried by a Code Sequence item (PS3.3 Section 8.8).
+///
+/// Two codes are equal when their value and Coding Scheme Designator match;
+/// Code Meaning and Coding Scheme Version are descriptive only.
+#[derive(Debug, Clone)]
+pub struct Code {
+    /// Code Value, Long Code Value or URN Code Value, whichever the item has
+    pub value: String,
+    /// Coding Scheme Designator; empty for URN codes
+    pub scheme: String,
+    pub scheme_version: Option<String>,
+    pub meaning: String,
+}
+
+impl Code {
+    pub fn new(value: &str, scheme: &str, meaning: &str) -> Self {
+        Self {
+            value: value.to_string(),
+            scheme: scheme.to_string(),
+            scheme_version: None,
+            meaning: meaning.to_string(),
+        }
+    }
+
+    /// Reads a code from a Code Sequence item; None when the item has no
+    /// code value or no Code Meaning.
+    pub fn from_item(item: &Dataset) -> Option<Self> {
+        let text = |keyword: &str| match item.get(keyword).and_then(|e| e.value.as_ref()) {
+            Some(DataElementValue::String(s)) if !s.is_empty() => Some(s.clone()),
+            _ => None,
+        };
+        let value = text("CodeValue")
+            .or_else(|| text("LongCodeValue"))
+            .or_else(|| text("URNCodeValue"))?;
+        Some(Self {
+            value,
+            scheme: text("CodingSchemeDesignator").unwrap_or_default(),
+            scheme_version: text("CodingSchemeVersion"),
+            meaning: text("CodeMeaning")?,
+        })
+    }
+
+    /// Writes the code as a Code Sequence item. Values longer than the 16
+    /// characters of Code Value go to Long Code Value, and URNs without a
+    /// coding scheme go to URN Code Value.
+    pub fn to_item(&self) -> Dataset {
+        let mut item = Dataset::new();
+        let value_keyword = if self.scheme.is_empty() && self.value.contains(':') {
+            "URNCodeValue"
+        } else if self.value.len() > 16 {
+            "LongCodeValue"
+        } else {
+            "CodeValue"
+        };
+        let mut fields = vec![(value_keyword, self.value.as_str())];
+        if !self.scheme.is_empty() {
+            fields.push(("CodingSchemeDesignator", self.scheme.as_str()));
+        }
+        if let Some(version) = &self.scheme_version {
+            fields.push(("CodingSchemeVersion", version.as_str()));
+        }
+        fields.push(("CodeMeaning", self.meaning.as_str()));
+        // Code Sequence macro order: (0008,0100) ... (0008,0120)
+        fields.sort_by_key(|(keyword, _)| attribute_by_keyword(keyword).map(|a| a.tag));
+        for (keyword, value) in fields {
+            if let Some(attribute) = attribute_by_keyword(keyword) {
+                item.push(DataElement {
+                    attribute,
+                    tag: attribute.tag_number(),
+                    vr: attribute.vr.unwrap_or(DicomVr::Un),
+                    value: Some(DataElementValue::String(value.to_string())),
+                });
+            }
+        }
+        item
+    }
+}
+
+impl PartialEq for Code {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.scheme == other.scheme
+    }
+}
+
+impl Eq for Code {}
+
+impl Hash for Code {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+        self.scheme.hash(state);
+    }
+}
+
+/// Triplet notation used throughout the standard: (value, scheme, "meaning")
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, \"{}\")", self.value, self.scheme, self.meaning)
+    }
+}
+
+impl From<&CodedConcept> for Code {
+    fn from(concept: &CodedConcept) -> Self {
+        Code::new(concept.value, concept.scheme, concept.meaning)
+    }
+}
+
+/// Codes of every item of a code sequence in the dataset; items that are not
+/// valid codes are skipped.
+pub fn code_sequence(ds: &Dataset, tag_or_keyword: &str) -> Vec<Code> {
+    match ds.get(tag_or_keyword).and_then(|e| e.value.as_ref()) {
+        Some(DataElementValue::Sequence(items)) => items.iter().filter_map(Code::from_item).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Builds a code sequence element, e.g. ConceptNameCodeSequence, holding one
+/// item per code.
+pub fn code_sequence_element(tag_or_keyword: &str, codes: &[Code]) -> Option<DataElement> {
+    let attribute = attribute_by_tag(tag_or_keyword).or_else(|| attribute_by_keyword(tag_or_keyword))?;
+    if attribute.vr != Some(DicomVr::Sq) {
+        eprintln!("{} is not a sequence", attribute.keyword);
+        return None;
+    }
+    Some(DataElement {
+        attribute,
+        tag: attribute.tag_number(),
+        vr: DicomVr::Sq,
+        value: Some(DataElementValue::Sequence(codes.iter().map(Code::to_item).collect())),
+    })
+}
+
+/// Coding scheme registered in PS3.16 Table 8-1
+#[derive(Debug, Clone, Copy)]
+pub struct CodingScheme {
+    pub designator: &'static str,
+    pub uid: &'static str,
+    pub name: &'static str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CodedConcept {
+    pub scheme: &'static str,
+    pub value: &'static str,
+    pub meaning: &'static str,
+}
+
+impl CodedConcept {
+    pub fn matches(&self, code: &Code) -> bool {
+        self.value == code.value && self.scheme == code.scheme
+    }
+}
+
+/// Context group from PS3.16 Annex B, with included groups expanded
+#[derive(Debug)]
+pub struct ContextGroup {
+    pub cid: u32,
+    pub name: &'static str,
+    /// False when the vendored table is an excerpt holding only some of the
+    /// group's concepts
+    pub complete: bool,
+    pub concepts: &'static [CodedConcept],
+}
+
+impl ContextGroup {
+    /// Whether the code is a member, or None when it is not listed and the
+    /// group is incomplete, so membership cannot be told.
+    pub fn contains(&self, code: &Code) -> Option<bool> {
+        match self.concept(code) {
+            Some(_) => Some(true),
+            None if self.complete => Some(false),
+            None => None,
+        }
+    }
+
+    pub fn concept(&self, code: &Code) -> Option<&'static CodedConcept> {
+        let concepts: &'static [CodedConcept] = self.concepts;
+        concepts.iter().find(|c| c.matches(code))
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/codes.rs"));
+
+pub fn coding_scheme(designator: &str) -> Option<&'static CodingScheme> {
+    CODING_SCHEMES
+        .binary_search_by(|entry| entry.designator.cmp(designator))
+        .ok()
+        .map(|idx| &CODING_SCHEMES[idx])
+}
+
+pub fn context_group(cid: u32) -> Option<&'static ContextGroup> {
+    CONTEXT_GROUPS
+        .binary_search_by_key(&cid, |group| group.cid)
+        .ok()
+        .map(|idx| &CONTEXT_GROUPS[idx])
+}
+
+/// Whether the code is a member of the context group, e.g.
+/// `in_context_group(7151, &code)`. None when the group is unknown, or when
+/// the code is not listed in a group the vendored tables hold only part of.
+pub fn in_context_group(cid: u32, code: &Code) -> Option<bool> {
+    context_group(cid)?.contains(code)
+}
@@ -1,7 +1,10 @@
+use crate::byteswap;
+use crate::dataset::Dataset;
 use crate::dicts::*;
+use crate::uids::{uid_by_value, Endianness};
 use std::fmt;
 
-/// DICOM dictionary data structures and lookups generated from parsed/full.csv
+/// DICOM dictionary data structures and lookups generated from PS3.6 and PS3.7
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DicomVr {
     Ae,
@@ -39,6 +42,7 @@ pub enum DicomVr {
     Us,
     UsOrOw,
     UsOrSs,
+    UsOrSsOrOw,
     Ut,
     Uv,
 }
@@ -81,11 +85,59 @@ impl DicomVr {
             DicomVr::Us => "US",
             DicomVr::UsOrOw => "US or OW",
             DicomVr::UsOrSs => "US or SS",
+            DicomVr::UsOrSsOrOw => "US or SS or OW",
             DicomVr::Ut => "UT",
             DicomVr::Uv => "UV",
         }
     }
 
+    /// Parses the two-letter code of an explicit VR element header.
+    pub fn from_code(code: &[u8]) -> Option<DicomVr> {
+        Some(match code {
+            b"AE" => DicomVr::Ae,
+            b"AS" => DicomVr::As,
+            b"AT" => DicomVr::At,
+            b"CS" => DicomVr::Cs,
+            b"DA" => DicomVr::Da,
+            b"DS" => DicomVr::Ds,
+            b"DT" => DicomVr::Dt,
+            b"FD" => DicomVr::Fd,
+            b"FL" => DicomVr::Fl,
+            b"IS" => DicomVr::Is,
+            b"LO" => DicomVr::Lo,
+            b"LT" => DicomVr::Lt,
+            b"OB" => DicomVr::Ob,
+            b"OD" => DicomVr::Od,
+            b"OF" => DicomVr::Of,
+            b"OL" => DicomVr::Ol,
+            b"OV" => DicomVr::Ov,
+            b"OW" => DicomVr::Ow,
+            b"PN" => DicomVr::Pn,
+            b"SH" => DicomVr::Sh,
+            b"SL" => DicomVr::Sl,
+            b"SQ" => DicomVr::Sq,
+            b"SS" => DicomVr::Ss,
+            b"ST" => DicomVr::St,
+            b"SV" => DicomVr::Sv,
+            b"TM" => DicomVr::Tm,
+            b"UC" => DicomVr::Uc,
+            b"UI" => DicomVr::Ui,
+            b"UL" => DicomVr::Ul,
+            b"UN" => DicomVr::Un,
+            b"UR" => DicomVr::Ur,
+            b"US" => DicomVr::Us,
+            b"UT" => DicomVr::Ut,
+            b"UV" => DicomVr::Uv,
+            _ => return None,
+        })
+    }
+
+    /// True for the dictionary VRs that depend on the rest of the data set
+    /// ("US or SS", "OB or OW", "US or OW", "US or SS or OW").
+    pub const fn is_ambiguous(&self) -> bool {
+        matches!(self, DicomVr::UsOrSs | DicomVr::ObOrOw | DicomVr::UsOrOw | DicomVr::UsOrSsOrOw)
+    }
+
     pub fn suggested_value_kind(&self) -> ValueKind {
         match self {
             DicomVr::Ae => ValueKind::String,
@@ -101,7 +153,7 @@ impl DicomVr {
             DicomVr::Lo => ValueKind::String,
             DicomVr::Lt => ValueKind::String,
             DicomVr::Ob => ValueKind::Data,
-            DicomVr::ObOrOw => ValueKind::String,
+            DicomVr::ObOrOw => ValueKind::Data,
             DicomVr::Od => ValueKind::Data,
             DicomVr::Of => ValueKind::Data,
             DicomVr::Ol => ValueKind::Data,
@@ -121,8 +173,9 @@ impl DicomVr {
             DicomVr::Un => ValueKind::Data,
             DicomVr::Ur => ValueKind::String,
             DicomVr::Us => ValueKind::UInt16,
-            DicomVr::UsOrOw => ValueKind::String,
-            DicomVr::UsOrSs => ValueKind::String,
+            DicomVr::UsOrOw => ValueKind::Data,
+            DicomVr::UsOrSs => ValueKind::Data,
+            DicomVr::UsOrSsOrOw => ValueKind::Data,
             DicomVr::Ut => ValueKind::String,
             DicomVr::Uv => ValueKind::UInt64,
         }
@@ -134,7 +187,7 @@ pub enum ValueKind { Sequence, String, Data, Int16, Int32, Int64, UInt16, UInt32
 
 #[derive(Debug, Clone)]
 pub enum DataElementValue {
-    Sequence(Vec<DataElement>),
+    Sequence(Vec<Dataset>),
     String(String),
     Data(Vec<u8>),
     Int16(i16),
@@ -148,6 +201,66 @@ pub enum DataElementValue {
     Tag(u16, u16),
 }
 
+impl DataElementValue {
+    /// First value as an integer; IS/DS strings are parsed, DS truncated.
+    pub fn to_int(&self) -> Option<i64> {
+        match self {
+            DataElementValue::Int16(v) => Some(*v as i64),
+            DataElementValue::Int32(v) => Some(*v as i64),
+            DataElementValue::Int64(v) => Some(*v),
+            DataElementValue::UInt16(v) => Some(*v as i64),
+            DataElementValue::UInt32(v) => Some(*v as i64),
+            DataElementValue::UInt64(v) => i64::try_from(*v).ok(),
+            DataElementValue::Float(_) | DataElementValue::Double(_) | DataElementValue::String(_) => {
+                let f = self.to_float()?;
+                f.is_finite().then_some(f as i64)
+            }
+            _ => None,
+        }
+    }
+
+    /// First value as a float; IS/DS strings are parsed.
+    pub fn to_float(&self) -> Option<f64> {
+        match self {
+            DataElementValue::Float(v) => Some(*v as f64),
+            DataElementValue::Double(v) => Some(*v),
+            DataElementValue::String(s) => s.split('\\').next()?.trim().parse().ok(),
+            other => other.to_int().map(|v| v as f64),
+        }
+    }
+
+    /// All values of a multi-valued IS/DS string, e.g. "40\400" for two
+    /// window centers. Single numeric values give one entry.
+    pub fn to_floats(&self) -> Vec<f64> {
+        match self {
+            DataElementValue::String(s) => s.split('\\').filter_map(|v| v.trim().parse().ok()).collect(),
+            other => other.to_float().into_iter().collect(),
+        }
+    }
+
+    /// Values of an FD/OD value; multi-valued ones are kept as raw little
+    /// endian bytes.
+    pub fn to_f64s(&self) -> Vec<f64> {
+        match self {
+            DataElementValue::Double(v) => vec![*v],
+            DataElementValue::Float(v) => vec![*v as f64],
+            DataElementValue::Data(bytes) => byteswap::f64s(bytes, Endianness::Little),
+            _ => Vec::new(),
+        }
+    }
+
+    /// 16-bit words of a US/SS/OW value. Multi-valued and binary values are
+    /// kept as raw bytes, in little endian whatever the file's byte order.
+    pub fn to_u16s(&self) -> Vec<u16> {
+        match self {
+            DataElementValue::UInt16(v) => vec![*v],
+            DataElementValue::Int16(v) => vec![*v as u16],
+            DataElementValue::Data(bytes) => byteswap::u16s(bytes, Endianness::Little),
+            _ => Vec::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct DicomAttribute {
     pub tag: &'static str,
@@ -155,15 +268,64 @@ pub struct DicomAttribute {
     pub keyword: &'static str,
     pub vr: Option<DicomVr>,
     pub vm: &'static str,
-    pub attr_type: &'static str,
+    pub retired: bool,
+}
+
+impl DicomAttribute {
+    /// Dictionary tag as (group << 16 | element), with X digits as zero
+    pub fn tag_number(&self) -> u32 {
+        parse_tag(self.tag).unwrap_or(0)
+    }
+}
+
+/// Stand-in dictionary entry for private attributes (PS3.5 Section 7.8),
+/// which the standard dictionary cannot describe; the element's tag and the
+/// Private Creator of its block identify them.
+pub static PRIVATE_ATTRIBUTE: DicomAttribute = DicomAttribute {
+    tag: "(XXXX,XXXX)",
+    name: "Private Attribute",
+    keyword: "",
+    vr: None,
+    vm: "1-n",
+    retired: false,
+};
+
+/// Tag in an odd group other than 0001, 0003, 0005, 0007 and FFFF
+pub fn is_private_tag(tag: u32) -> bool {
+    let group = tag >> 16;
+    group % 2 == 1 && group > 0x0008 && group != 0xFFFF
 }
 
 #[derive(Debug, Clone)]
 pub struct DataElement {
     pub attribute: &'static DicomAttribute,
+    /// Tag as (group << 16 | element). Differs from the dictionary tag for
+    /// repeating groups, e.g. (6002,3000) for the (60XX,3000) attribute.
+    pub tag: u32,
+    /// VR the value was decoded with: the explicit VR from the stream, or the
+    /// dictionary VR with any "US or SS"-style ambiguity resolved.
+    pub vr: DicomVr,
     pub value: Option<DataElementValue>,
 }
 
+impl DataElement {
+    /// Element for a dictionary attribute given by keyword or tag. Ambiguous
+    /// dictionary VRs are settled by the kind of value, e.g. Int16 makes
+    /// "US or SS" an SS.
+    pub fn new(tag_or_keyword: &str, value: DataElementValue) -> Option<DataElement> {
+        let attribute = attribute_by_tag(tag_or_keyword).or_else(|| attribute_by_keyword(tag_or_keyword))?;
+        let vr = match (attribute.vr?, &value) {
+            (DicomVr::UsOrSs | DicomVr::UsOrSsOrOw, DataElementValue::Int16(_)) => DicomVr::Ss,
+            (DicomVr::UsOrSs | DicomVr::UsOrOw | DicomVr::UsOrSsOrOw, DataElementValue::UInt16(_)) => DicomVr::Us,
+            (DicomVr::UsOrSs, _) => DicomVr::Us,
+            (vr, _) if vr.is_ambiguous() => DicomVr::Ow,
+            (vr, _) => vr,
+        };
+        let tag = parse_tag(tag_or_keyword).unwrap_or_else(|| attribute.tag_number());
+        Some(DataElement { attribute, tag, vr, value: Some(value) })
+    }
+}
+
 impl fmt::Display for DataElementValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -189,52 +351,68 @@ impl fmt::Display for DataElement {
             Some(v) => v.to_string(),
             None => String::from("<empty>"),
         };
-        write!(f, "{} {} = {}", self.attribute.tag, self.attribute.keyword, value_str)
+        let tag = format!("({:04X},{:04X})", self.tag >> 16, self.tag & 0xFFFF);
+        // Name well-known UIDs, e.g. "CT Image Storage" next to a SOP Class UID
+        if let (Some(DicomVr::Ui), Some(DataElementValue::String(s))) = (self.attribute.vr, &self.value)
+            && let Some(uid) = uid_by_value(s)
+        {
+            return write!(f, "{tag} {} = {} ({})", self.attribute.keyword, value_str, uid.name);
+        }
+        let label = if self.attribute.keyword.is_empty() { self.attribute.name } else { self.attribute.keyword };
+        write!(f, "{tag} {label} = {value_str}")
     }
 }
 
-fn normalize_tag(id_or_tag: &str) -> String {
+/// Parses "(gggg,eeee)", "gggg,eeee" or "ggggeeee" into (group << 16 | element).
+/// X digits, as in "(60XX,3000)", read as zero.
+pub(crate) fn parse_tag(id_or_tag: &str) -> Option<u32> {
     let s = id_or_tag.trim();
-    if s.starts_with('(') && s.ends_with(')') && s.contains(',') {
-        return s.to_string();
-    }
-    let hex: String = s.chars()
-        .filter(|c| c.is_ascii_hexdigit())
-        .map(|c| c.to_ascii_uppercase())
-        .collect();
-    if hex.len() == 8 {
-        let group = &hex[..4];
-        let element = &hex[4..];
-        return format!("({group},{element})");
-    }
-    s.to_string()
+    let s = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')).unwrap_or(s);
+    let mut tag = 0u32;
+    let mut digits = 0;
+    for c in s.chars() {
+        if c == ',' || c == ' ' {
+            continue;
+        }
+        let d = match c {
+            'x' | 'X' => 0,
+            _ => c.to_digit(16)?,
+        };
+        tag = (tag << 4) | d;
+        digits += 1;
+        if digits > 8 {
+            return None;
+        }
+    }
+    (digits == 8).then_some(tag)
 }
 
 pub fn attribute_by_tag(id_or_tag: &str) -> Option<&'static DicomAttribute> {
-    let normalized = normalize_tag(id_or_tag);
-    if let Ok(idx) = TAG_INDEX.binary_search_by(|entry| entry.0.cmp(normalized.as_str())) {
-        let (_, attr_idx) = TAG_INDEX[idx];
-        return Some(&ATTRIBUTES[attr_idx]);
-    }
-    // Fallback linear scan for cases where TAG_INDEX might not be perfectly sorted
-    for attr in ATTRIBUTES.iter() {
-        if attr.tag == normalized {
-            return Some(attr);
+    attribute_by_tag_number(parse_tag(id_or_tag)?)
+}
+
+/// Looks up a tag given as (group << 16 | element). Repeating group entries
+/// such as (60XX,3000) match any tag in their range.
+pub fn attribute_by_tag_number(tag: u32) -> Option<&'static DicomAttribute> {
+    // Private groups are never in the standard dictionary
+    if (tag >> 16) & 1 == 1 {
+        return None;
+    }
+    if let Ok(idx) = TAG_INDEX.binary_search_by(|entry| entry.0.cmp(&tag)) {
+        return Some(&ATTRIBUTES[TAG_INDEX[idx].1]);
+    }
+    for (mask, entries) in REPEATING_TAG_INDEX {
+        let masked = tag & mask;
+        if let Ok(idx) = entries.binary_search_by(|entry| entry.0.cmp(&masked)) {
+            return Some(&ATTRIBUTES[entries[idx].1]);
         }
     }
     None
 }
 
 pub fn attribute_by_keyword(keyword: &str) -> Option<&'static DicomAttribute> {
-    if let Ok(idx) = KEYWORD_INDEX.binary_search_by(|entry| entry.0.cmp(keyword)) {
-        let (_, attr_idx) = KEYWORD_INDEX[idx];
-        return Some(&ATTRIBUTES[attr_idx]);
-    }
-    // Fallback linear scan for cases where KEYWORD_INDEX might not be perfectly sorted
-    for attr in ATTRIBUTES.iter() {
-        if attr.keyword == keyword {
-            return Some(attr);
-        }
-    }
-    None
+    KEYWORD_INDEX
+        .binary_search_by(|entry| entry.0.cmp(keyword))
+        .ok()
+        .map(|idx| &ATTRIBUTES[KEYWORD_INDEX[idx].1])
 }
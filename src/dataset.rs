@@ -1,10 +1,102 @@
 use crate::dataelem::*;
+use crate::search::suggest_keyword;
+use crate::uids::{transfer_syntax, TransferSyntax};
 
 #[derive(Debug, Default, Clone)]
 pub struct Dataset {
     file_meta: Vec<DataElement>,
     data_elements: Vec<DataElement>,
     pixel_data: Option<Vec<u8>>,
+    encapsulated_pixel_data: Option<EncapsulatedPixelData>,
+}
+
+/// Encapsulated Pixel Data (PS3.5 Section A.4): the Basic Offset Table
+/// followed by the fragments of the compressed frames.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EncapsulatedPixelData {
+    /// Offset of each frame's first fragment, counted from the first byte
+    /// of the first fragment's item tag. Often empty.
+    pub offset_table: Vec<u32>,
+    pub fragments: Vec<Vec<u8>>,
+}
+
+impl EncapsulatedPixelData {
+    /// One fragment per frame, with an offset table pointing at each.
+    pub fn from_frames(frames: Vec<Vec<u8>>) -> Self {
+        let mut offset_table = Vec::with_capacity(frames.len());
+        let mut offset = 0u32;
+        for frame in &frames {
+            offset_table.push(offset);
+            // 8-byte item header plus the even-padded fragment
+            offset += 8 + frame.len().next_multiple_of(2) as u32;
+        }
+        Self { offset_table, fragments: frames }
+    }
+
+    /// Fragment indices making up each frame. Uses the offset table when
+    /// present; otherwise one fragment per frame, all fragments for a single
+    /// frame, or a new frame at every fragment that starts a JPEG or JPEG
+    /// 2000 codestream.
+    pub fn frame_fragments(&self, number_of_frames: usize) -> Option<Vec<std::ops::Range<usize>>> {
+        let n = self.fragments.len();
+        let starts: Vec<usize> = if !self.offset_table.is_empty() {
+            // The first frame starts at the first fragment, and every frame
+            // has at least one fragment of its own
+            if self.offset_table[0] != 0 || self.offset_table.windows(2).any(|w| w[1] <= w[0]) {
+                eprintln!("Basic Offset Table does not start at 0 or is not strictly increasing");
+                return None;
+            }
+            // Map byte offsets onto fragment indices
+            let mut starts = Vec::with_capacity(self.offset_table.len());
+            let mut position = 0u64;
+            let mut index = 0;
+            for &offset in &self.offset_table {
+                while index < n && position < offset as u64 {
+                    position += 8 + self.fragments[index].len() as u64;
+                    index += 1;
+                }
+                if position != offset as u64 || index >= n {
+                    eprintln!("Basic Offset Table entry {offset} does not start a fragment");
+                    return None;
+                }
+                starts.push(index);
+            }
+            starts
+        } else if n == number_of_frames {
+            (0..n).collect()
+        } else if number_of_frames == 1 {
+            vec![0]
+        } else {
+            let starts: Vec<usize> = (0..n)
+                .filter(|&i| {
+                    let f = &self.fragments[i];
+                    f.starts_with(&[0xFF, 0xD8]) || f.starts_with(&[0xFF, 0x4F, 0xFF, 0x51]) || f.starts_with(&[0, 0, 0, 0x0C])
+                })
+                .collect();
+            if starts.first() != Some(&0) {
+                eprintln!("Cannot tell where the frames of the encapsulated Pixel Data start");
+                return None;
+            }
+            starts
+        };
+        if starts.len() != number_of_frames {
+            eprintln!("Found {} encapsulated frames, NumberOfFrames is {number_of_frames}", starts.len());
+            return None;
+        }
+        Some(
+            starts
+                .iter()
+                .enumerate()
+                .map(|(i, &start)| start..starts.get(i + 1).copied().unwrap_or(n))
+                .collect(),
+        )
+    }
+
+    /// Bytes of one frame, with its fragments joined.
+    pub fn frame(&self, index: usize, number_of_frames: usize) -> Option<Vec<u8>> {
+        let range = self.frame_fragments(number_of_frames)?.get(index)?.clone();
+        Some(self.fragments[range].concat())
+    }
 }
 
 impl Dataset {
@@ -13,6 +105,7 @@ impl Dataset {
             file_meta: Vec::new(),
             data_elements: Vec::new(),
             pixel_data: None,
+            encapsulated_pixel_data: None,
         }
     }
 
@@ -28,27 +121,76 @@ impl Dataset {
         self.data_elements.push(elem);
     }
 
+    /// Replaces the element with the same tag, or appends it.
+    pub fn set(&mut self, elem: DataElement) {
+        match self.data_elements.iter_mut().find(|de| de.tag == elem.tag) {
+            Some(existing) => *existing = elem,
+            None => self.data_elements.push(elem),
+        }
+    }
+
+    /// Sets native Pixel Data, dropping any encapsulated Pixel Data
     pub fn set_pixel_data(&mut self, data: Vec<u8>) {
         self.pixel_data = Some(data);
+        self.encapsulated_pixel_data = None;
+    }
+
+    /// Sets encapsulated Pixel Data, dropping any native Pixel Data
+    pub fn set_encapsulated_pixel_data(&mut self, data: EncapsulatedPixelData) {
+        self.encapsulated_pixel_data = Some(data);
+        self.pixel_data = None;
+    }
+
+    pub fn encapsulated_pixel_data(&self) -> Option<&EncapsulatedPixelData> {
+        self.encapsulated_pixel_data.as_ref()
     }
 
     pub fn elements(&self) -> &[DataElement] {
         &self.data_elements
     }
 
+    /// Native Pixel Data in little endian, whatever the byte order of the
+    /// transfer syntax, or the encapsulated fragments as stored. Use
+    /// pixel::decode_pixel_data for decoded values.
     pub fn pixel_data(&self) -> Option<&[u8]> {
         self.pixel_data.as_deref()
     }
 
+    /// Transfer syntax named by the File Meta Information, if any
+    pub fn transfer_syntax(&self) -> Option<TransferSyntax> {
+        let uid = self.file_meta.iter().find(|de| de.attribute.keyword == "TransferSyntaxUID")?;
+        match uid.value.as_ref()? {
+            DataElementValue::String(s) => transfer_syntax(s),
+            _ => None,
+        }
+    }
+
+    /// Value of a numeric or IS/DS attribute as an integer
+    pub fn get_int(&self, tag_or_keyword: &str) -> Option<i64> {
+        self.get(tag_or_keyword)?.value.as_ref()?.to_int()
+    }
+
+    /// Value of a numeric or IS/DS attribute as a float
+    pub fn get_float(&self, tag_or_keyword: &str) -> Option<f64> {
+        self.get(tag_or_keyword)?.value.as_ref()?.to_float()
+    }
+
+    /// Value of a string attribute without padding
+    pub fn get_str(&self, tag_or_keyword: &str) -> Option<&str> {
+        match self.get(tag_or_keyword)?.value.as_ref()? {
+            DataElementValue::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
     pub fn get(&self, tag_or_keyword: &str) -> Option<&DataElement> {
         // Try interpret input as tag first, then as keyword
-        if let Some(attr) = attribute_by_tag(tag_or_keyword) {
-            let tag = attr.tag;
+        if let Some(tag) = parse_tag(tag_or_keyword).filter(|&tag| is_private_tag(tag) || attribute_by_tag(tag_or_keyword).is_some()) {
             // Search File Meta first, then main dataset
-            if let Some(found) = self.file_meta.iter().find(|de| de.attribute.tag == tag) {
+            if let Some(found) = self.file_meta.iter().find(|de| de.tag == tag) {
                 return Some(found);
             }
-            if let Some(found) = self.data_elements.iter().find(|de| de.attribute.tag == tag) {
+            if let Some(found) = self.data_elements.iter().find(|de| de.tag == tag) {
                 return Some(found);
             }
             return None;
@@ -65,4 +207,33 @@ impl Dataset {
         }
         None
     }
+
+    /// Like get, but a miss on an unknown keyword comes with the dictionary
+    /// keyword it most likely misspells, if any. Tags and known keywords
+    /// that are not present give Err(None).
+    pub fn get_or_suggest(&self, tag_or_keyword: &str) -> Result<&DataElement, Option<&'static str>> {
+        if let Some(found) = self.get(tag_or_keyword) {
+            return Ok(found);
+        }
+        if parse_tag(tag_or_keyword).is_some() || attribute_by_keyword(tag_or_keyword).is_some() {
+            return Err(None);
+        }
+        Err(suggest_keyword(tag_or_keyword))
+    }
+
+    /// Private element at `offset` within the block that `creator` reserved
+    /// in an odd group, wherever that block landed (PS3.5 Section 7.8.1)
+    pub fn get_private(&self, group: u16, creator: &str, offset: u8) -> Option<&DataElement> {
+        let block = self.data_elements.iter().find(|de| {
+            de.tag >> 16 == group as u32
+                && (0x0010..=0x00FF).contains(&(de.tag & 0xFFFF))
+                && match &de.value {
+                    Some(DataElementValue::String(s)) => s.trim() == creator,
+                    Some(DataElementValue::Data(bytes)) => String::from_utf8_lossy(bytes).trim_end_matches(['\0', ' ']) == creator,
+                    _ => false,
+                }
+        })?;
+        let tag = (group as u32) << 16 | (block.tag & 0xFF) << 8 | offset as u32;
+        self.data_elements.iter().find(|de| de.tag == tag)
+    }
 }
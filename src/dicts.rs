@@ -1,12197 +1,5 @@
-// This file is auto-generated. Do not edit manually.
-// Generated by to_rust_struct.py on 2025-08-23 04:56:39Z
+// Data dictionary generated at build time from the vendored PS3.6 and PS3.7
+// tables; see build.rs.
 use crate::dataelem::*;
 
-pub static ATTRIBUTES: &[DicomAttribute] = &[
-    DicomAttribute { tag: "(0004,1130)", name: "File-set ID", keyword: "FileSetID", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0004,1141)", name: "File-set Descriptor File ID", keyword: "FileSetDescriptorFileID", vr: Some(DicomVr::Cs), vm: "1-8", attr_type: "3" },
-    DicomAttribute { tag: "(0004,1142)", name: "Specific Character Set of File-set Descriptor File", keyword: "SpecificCharacterSetOfFileSetDescriptorFile", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0004,1200)", name: "Offset of the First Directory Record of the Root Directory Entity", keyword: "OffsetOfTheFirstDirectoryRecordOfTheRootDirectoryEntity", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0004,1202)", name: "Offset of the Last Directory Record of the Root Directory Entity", keyword: "OffsetOfTheLastDirectoryRecordOfTheRootDirectoryEntity", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0004,1212)", name: "File-set Consistency Flag", keyword: "FileSetConsistencyFlag", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0004,1220)", name: "Directory Record Sequence", keyword: "DirectoryRecordSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0004,1400)", name: "Offset of the Next Directory Record", keyword: "OffsetOfTheNextDirectoryRecord", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0004,1410)", name: "Record In-use Flag", keyword: "RecordInUseFlag", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0004,1420)", name: "Offset of Referenced Lower-Level Directory Entity", keyword: "OffsetOfReferencedLowerLevelDirectoryEntity", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0004,1430)", name: "Directory Record Type", keyword: "DirectoryRecordType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0004,1432)", name: "Private Record UID", keyword: "PrivateRecordUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0004,1500)", name: "Referenced File ID", keyword: "ReferencedFileID", vr: Some(DicomVr::Cs), vm: "1-8", attr_type: "1C" },
-    DicomAttribute { tag: "(0004,1510)", name: "Referenced SOP Class UID in File", keyword: "ReferencedSOPClassUIDInFile", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0004,1511)", name: "Referenced SOP Instance UID in File", keyword: "ReferencedSOPInstanceUIDInFile", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0004,1512)", name: "Referenced Transfer Syntax UID in File", keyword: "ReferencedTransferSyntaxUIDInFile", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0004,151A)", name: "Referenced Related General SOP Class UID in File", keyword: "ReferencedRelatedGeneralSOPClassUIDInFile", vr: Some(DicomVr::Ui), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0006,0001)", name: "Current Frame Functional Groups Sequence", keyword: "CurrentFrameFunctionalGroupsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0005)", name: "Specific Character Set", keyword: "SpecificCharacterSet", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0006)", name: "Language Code Sequence", keyword: "LanguageCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0008)", name: "Image Type", keyword: "ImageType", vr: Some(DicomVr::Cs), vm: "2-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0012)", name: "Instance Creation Date", keyword: "InstanceCreationDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0013)", name: "Instance Creation Time", keyword: "InstanceCreationTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0014)", name: "Instance Creator UID", keyword: "InstanceCreatorUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0015)", name: "Instance Coercion DateTime", keyword: "InstanceCoercionDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0016)", name: "SOP Class UID", keyword: "SOPClassUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0017)", name: "Acquisition UID", keyword: "AcquisitionUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0018)", name: "SOP Instance UID", keyword: "SOPInstanceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0019)", name: "Pyramid UID", keyword: "PyramidUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,001A)", name: "Related General SOP Class UID", keyword: "RelatedGeneralSOPClassUID", vr: Some(DicomVr::Ui), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,001B)", name: "Original Specialized SOP Class UID", keyword: "OriginalSpecializedSOPClassUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,001C)", name: "Synthetic Data", keyword: "SyntheticData", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0020)", name: "Study Date", keyword: "StudyDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0021)", name: "Series Date", keyword: "SeriesDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0022)", name: "Acquisition Date", keyword: "AcquisitionDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0023)", name: "Content Date", keyword: "ContentDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0008,002A)", name: "Acquisition DateTime", keyword: "AcquisitionDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0030)", name: "Study Time", keyword: "StudyTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0031)", name: "Series Time", keyword: "SeriesTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0032)", name: "Acquisition Time", keyword: "AcquisitionTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0033)", name: "Content Time", keyword: "ContentTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0008,0050)", name: "Accession Number", keyword: "AccessionNumber", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0051)", name: "Issuer of Accession Number Sequence", keyword: "IssuerOfAccessionNumberSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0052)", name: "Query/Retrieve Level", keyword: "QueryRetrieveLevel", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0053)", name: "Query/Retrieve View", keyword: "QueryRetrieveView", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0054)", name: "Retrieve AE Title", keyword: "RetrieveAETitle", vr: Some(DicomVr::Ae), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0055)", name: "Station AE Title", keyword: "StationAETitle", vr: Some(DicomVr::Ae), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0056)", name: "Instance Availability", keyword: "InstanceAvailability", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0060)", name: "Modality", keyword: "Modality", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0061)", name: "Modalities in Study", keyword: "ModalitiesInStudy", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0063)", name: "Anatomic Regions in Study Code Sequence", keyword: "AnatomicRegionsInStudyCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0064)", name: "Conversion Type", keyword: "ConversionType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0068)", name: "Presentation Intent Type", keyword: "PresentationIntentType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0070)", name: "Manufacturer", keyword: "Manufacturer", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0080)", name: "Institution Name", keyword: "InstitutionName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0081)", name: "Institution Address", keyword: "InstitutionAddress", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0082)", name: "Institution Code Sequence", keyword: "InstitutionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0090)", name: "Referring Physician's Name", keyword: "ReferringPhysicianName", vr: Some(DicomVr::Pn), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0096)", name: "Referring Physician Identification Sequence", keyword: "ReferringPhysicianIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,009C)", name: "Consulting Physician's Name", keyword: "ConsultingPhysicianName", vr: Some(DicomVr::Pn), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,009D)", name: "Consulting Physician Identification Sequence", keyword: "ConsultingPhysicianIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0100)", name: "Code Value", keyword: "CodeValue", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0102)", name: "Coding Scheme Designator", keyword: "CodingSchemeDesignator", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0103)", name: "Coding Scheme Version", keyword: "CodingSchemeVersion", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0104)", name: "Code Meaning", keyword: "CodeMeaning", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0105)", name: "Mapping Resource", keyword: "MappingResource", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0106)", name: "Context Group Version", keyword: "ContextGroupVersion", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0107)", name: "Context Group Local Version", keyword: "ContextGroupLocalVersion", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0109)", name: "Coding Scheme Resources Sequence", keyword: "CodingSchemeResourcesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,010A)", name: "Coding Scheme URL Type", keyword: "CodingSchemeURLType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,010B)", name: "Context Group Extension Flag", keyword: "ContextGroupExtensionFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,010C)", name: "Coding Scheme UID", keyword: "CodingSchemeUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,010D)", name: "Context Group Extension Creator UID", keyword: "ContextGroupExtensionCreatorUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,010E)", name: "Coding Scheme URL", keyword: "CodingSchemeURL", vr: Some(DicomVr::Ur), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,010F)", name: "Context Identifier", keyword: "ContextIdentifier", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0110)", name: "Coding Scheme Identification Sequence", keyword: "CodingSchemeIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0112)", name: "Coding Scheme Registry", keyword: "CodingSchemeRegistry", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0114)", name: "Coding Scheme External ID", keyword: "CodingSchemeExternalID", vr: Some(DicomVr::St), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0008,0115)", name: "Coding Scheme Name", keyword: "CodingSchemeName", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0116)", name: "Coding Scheme Responsible Organization", keyword: "CodingSchemeResponsibleOrganization", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0117)", name: "Context UID", keyword: "ContextUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0118)", name: "Mapping Resource UID", keyword: "MappingResourceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0119)", name: "Long Code Value", keyword: "LongCodeValue", vr: Some(DicomVr::Uc), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0120)", name: "URN Code Value", keyword: "URNCodeValue", vr: Some(DicomVr::Ur), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0121)", name: "Equivalent Code Sequence", keyword: "EquivalentCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0122)", name: "Mapping Resource Name", keyword: "MappingResourceName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0123)", name: "Context Group Identification Sequence", keyword: "ContextGroupIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0124)", name: "Mapping Resource Identification Sequence", keyword: "MappingResourceIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0201)", name: "Timezone Offset From UTC", keyword: "TimezoneOffsetFromUTC", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0220)", name: "Responsible Group Code Sequence", keyword: "ResponsibleGroupCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0221)", name: "Equipment Modality", keyword: "EquipmentModality", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0222)", name: "Manufacturer's Related Model Group", keyword: "ManufacturerRelatedModelGroup", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0300)", name: "Private Data Element Characteristics Sequence", keyword: "PrivateDataElementCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0301)", name: "Private Group Reference", keyword: "PrivateGroupReference", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0302)", name: "Private Creator Reference", keyword: "PrivateCreatorReference", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0303)", name: "Block Identifying Information Status", keyword: "BlockIdentifyingInformationStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0304)", name: "Nonidentifying Private Elements", keyword: "NonidentifyingPrivateElements", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0305)", name: "Deidentification Action Sequence", keyword: "DeidentificationActionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0306)", name: "Identifying Private Elements", keyword: "IdentifyingPrivateElements", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0307)", name: "Deidentification Action", keyword: "DeidentificationAction", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0308)", name: "Private Data Element", keyword: "PrivateDataElement", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0309)", name: "Private Data Element Value Multiplicity", keyword: "PrivateDataElementValueMultiplicity", vr: Some(DicomVr::Ul), vm: "1-3", attr_type: "1" },
-    DicomAttribute { tag: "(0008,030A)", name: "Private Data Element Value Representation", keyword: "PrivateDataElementValueRepresentation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,030B)", name: "Private Data Element Number of Items", keyword: "PrivateDataElementNumberOfItems", vr: Some(DicomVr::Ul), vm: "1-2", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,030C)", name: "Private Data Element Name", keyword: "PrivateDataElementName", vr: Some(DicomVr::Uc), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,030D)", name: "Private Data Element Keyword", keyword: "PrivateDataElementKeyword", vr: Some(DicomVr::Uc), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,030E)", name: "Private Data Element Description", keyword: "PrivateDataElementDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,030F)", name: "Private Data Element Encoding", keyword: "PrivateDataElementEncoding", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0310)", name: "Private Data Element Definition Sequence", keyword: "PrivateDataElementDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0400)", name: "Scope of Inventory Sequence", keyword: "ScopeOfInventorySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0401)", name: "Inventory Purpose", keyword: "InventoryPurpose", vr: Some(DicomVr::Lt), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0402)", name: "Inventory Instance Description", keyword: "InventoryInstanceDescription", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0403)", name: "Inventory Level", keyword: "InventoryLevel", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0404)", name: "Item Inventory DateTime", keyword: "ItemInventoryDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0405)", name: "Removed from Operational Use", keyword: "RemovedFromOperationalUse", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0406)", name: "Reason for Removal Code Sequence", keyword: "ReasonForRemovalCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0008,0407)", name: "Stored Instance Base URI", keyword: "StoredInstanceBaseURI", vr: Some(DicomVr::Ur), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0408)", name: "Folder Access URI", keyword: "FolderAccessURI", vr: Some(DicomVr::Ur), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0409)", name: "File Access URI", keyword: "FileAccessURI", vr: Some(DicomVr::Ur), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,040A)", name: "Container File Type", keyword: "ContainerFileType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,040B)", name: "Filename in Container", keyword: "FilenameInContainer", vr: Some(DicomVr::Ur), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,040C)", name: "File Offset in Container", keyword: "FileOffsetInContainer", vr: Some(DicomVr::Uv), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,040D)", name: "File Length in Container", keyword: "FileLengthInContainer", vr: Some(DicomVr::Uv), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,040E)", name: "Stored Instance Transfer Syntax UID", keyword: "StoredInstanceTransferSyntaxUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,040F)", name: "Extended Matching Mechanisms", keyword: "ExtendedMatchingMechanisms", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0410)", name: "Range Matching Sequence", keyword: "RangeMatchingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0411)", name: "List of UID Matching Sequence", keyword: "ListOfUIDMatchingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0412)", name: "Empty Value Matching Sequence", keyword: "EmptyValueMatchingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0413)", name: "General Matching Sequence", keyword: "GeneralMatchingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,0416)", name: "Expiration DateTime", keyword: "ExpirationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0419)", name: "File Set Access Sequence", keyword: "FileSetAccessSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,041A)", name: "File Access Sequence", keyword: "FileAccessSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,041F)", name: "Study Update DateTime", keyword: "StudyUpdateDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0420)", name: "Inventory Access End Points Sequence", keyword: "InventoryAccessEndPointsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0421)", name: "Study Access End Points Sequence", keyword: "StudyAccessEndPointsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,0422)", name: "Incorporated Inventory Instance Sequence", keyword: "IncorporatedInventoryInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0423)", name: "Inventoried Studies Sequence", keyword: "InventoriedStudiesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,0424)", name: "Inventoried Series Sequence", keyword: "InventoriedSeriesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0008,0425)", name: "Inventoried Instances Sequence", keyword: "InventoriedInstancesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0008,0426)", name: "Inventory Completion Status", keyword: "InventoryCompletionStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0427)", name: "Number of Study Records in Instance", keyword: "NumberOfStudyRecordsInInstance", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,0428)", name: "Total Number of Study Records", keyword: "TotalNumberOfStudyRecords", vr: Some(DicomVr::Uv), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,1010)", name: "Station Name", keyword: "StationName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1030)", name: "Study Description", keyword: "StudyDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1032)", name: "Procedure Code Sequence", keyword: "ProcedureCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,103E)", name: "Series Description", keyword: "SeriesDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,103F)", name: "Series Description Code Sequence", keyword: "SeriesDescriptionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1040)", name: "Institutional Department Name", keyword: "InstitutionalDepartmentName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1041)", name: "Institutional Department Type Code Sequence", keyword: "InstitutionalDepartmentTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1048)", name: "Physician(s) of Record", keyword: "PhysiciansOfRecord", vr: Some(DicomVr::Pn), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1049)", name: "Physician(s) of Record Identification Sequence", keyword: "PhysiciansOfRecordIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1050)", name: "Performing Physician's Name", keyword: "PerformingPhysicianName", vr: Some(DicomVr::Pn), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1052)", name: "Performing Physician Identification Sequence", keyword: "PerformingPhysicianIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1060)", name: "Name of Physician(s) Reading Study", keyword: "NameOfPhysiciansReadingStudy", vr: Some(DicomVr::Pn), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1062)", name: "Physician(s) Reading Study Identification Sequence", keyword: "PhysiciansReadingStudyIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1070)", name: "Operators' Name", keyword: "OperatorsName", vr: Some(DicomVr::Pn), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1072)", name: "Operator Identification Sequence", keyword: "OperatorIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1080)", name: "Admitting Diagnoses Description", keyword: "AdmittingDiagnosesDescription", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1084)", name: "Admitting Diagnoses Code Sequence", keyword: "AdmittingDiagnosesCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1088)", name: "Pyramid Description", keyword: "PyramidDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1090)", name: "Manufacturer's Model Name", keyword: "ManufacturerModelName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1110)", name: "Referenced Study Sequence", keyword: "ReferencedStudySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1111)", name: "Referenced Performed Procedure Step Sequence", keyword: "ReferencedPerformedProcedureStepSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1115)", name: "Referenced Series Sequence", keyword: "ReferencedSeriesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,1120)", name: "Referenced Patient Sequence", keyword: "ReferencedPatientSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1134)", name: "Referenced Stereometric Instance Sequence", keyword: "ReferencedStereometricInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,113A)", name: "Referenced Waveform Sequence", keyword: "ReferencedWaveformSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1140)", name: "Referenced Image Sequence", keyword: "ReferencedImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,114A)", name: "Referenced Instance Sequence", keyword: "ReferencedInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,114B)", name: "Referenced Real World Value Mapping Instance Sequence", keyword: "ReferencedRealWorldValueMappingInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1150)", name: "Referenced SOP Class UID", keyword: "ReferencedSOPClassUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,1155)", name: "Referenced SOP Instance UID", keyword: "ReferencedSOPInstanceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,1156)", name: "Definition Source Sequence", keyword: "DefinitionSourceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,1160)", name: "Referenced Frame Number", keyword: "ReferencedFrameNumber", vr: Some(DicomVr::Is), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,1161)", name: "Simple Frame List", keyword: "SimpleFrameList", vr: Some(DicomVr::Ul), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,1162)", name: "Calculated Frame List", keyword: "CalculatedFrameList", vr: Some(DicomVr::Ul), vm: "3-3n", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,1163)", name: "Time Range", keyword: "TimeRange", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,1164)", name: "Frame Extraction Sequence", keyword: "FrameExtractionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,1167)", name: "Multi-frame Source SOP Instance UID", keyword: "MultiFrameSourceSOPInstanceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,1190)", name: "Retrieve URL", keyword: "RetrieveURL", vr: Some(DicomVr::Ur), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,1195)", name: "Transaction UID", keyword: "TransactionUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,1199)", name: "Referenced SOP Sequence", keyword: "ReferencedSOPSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,1200)", name: "Studies Containing Other Referenced Instances Sequence", keyword: "StudiesContainingOtherReferencedInstancesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,1250)", name: "Related Series Sequence", keyword: "RelatedSeriesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2111)", name: "Derivation Description", keyword: "DerivationDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2112)", name: "Source Image Sequence", keyword: "SourceImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2120)", name: "Stage Name", keyword: "StageName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2122)", name: "Stage Number", keyword: "StageNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2124)", name: "Number of Stages", keyword: "NumberOfStages", vr: Some(DicomVr::Is), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0008,2127)", name: "View Name", keyword: "ViewName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2128)", name: "View Number", keyword: "ViewNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2129)", name: "Number of Event Timers", keyword: "NumberOfEventTimers", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,212A)", name: "Number of Views in Stage", keyword: "NumberOfViewsInStage", vr: Some(DicomVr::Is), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0008,2130)", name: "Event Elapsed Time(s)", keyword: "EventElapsedTimes", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2132)", name: "Event Timer Name(s)", keyword: "EventTimerNames", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2133)", name: "Event Timer Sequence", keyword: "EventTimerSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2134)", name: "Event Time Offset", keyword: "EventTimeOffset", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,2135)", name: "Event Code Sequence", keyword: "EventCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,2142)", name: "Start Trim", keyword: "StartTrim", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2143)", name: "Stop Trim", keyword: "StopTrim", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2144)", name: "Recommended Display Frame Rate", keyword: "RecommendedDisplayFrameRate", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2218)", name: "Anatomic Region Sequence", keyword: "AnatomicRegionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2220)", name: "Anatomic Region Modifier Sequence", keyword: "AnatomicRegionModifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2228)", name: "Primary Anatomic Structure Sequence", keyword: "PrimaryAnatomicStructureSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,2230)", name: "Primary Anatomic Structure Modifier Sequence", keyword: "PrimaryAnatomicStructureModifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,3001)", name: "Alternate Representation Sequence", keyword: "AlternateRepresentationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,3010)", name: "Irradiation Event UID", keyword: "IrradiationEventUID", vr: Some(DicomVr::Ui), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0008,3011)", name: "Source Irradiation Event Sequence", keyword: "SourceIrradiationEventSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,3012)", name: "Radiopharmaceutical Administration Event UID", keyword: "RadiopharmaceuticalAdministrationEventUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,9007)", name: "Frame Type", keyword: "FrameType", vr: Some(DicomVr::Cs), vm: "4-5", attr_type: "1" },
-    DicomAttribute { tag: "(0008,9092)", name: "Referenced Image Evidence Sequence", keyword: "ReferencedImageEvidenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,9121)", name: "Referenced Raw Data Sequence", keyword: "ReferencedRawDataSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,9123)", name: "Creator-Version UID", keyword: "CreatorVersionUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,9124)", name: "Derivation Image Sequence", keyword: "DerivationImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0008,9154)", name: "Source Image Evidence Sequence", keyword: "SourceImageEvidenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,9205)", name: "Pixel Presentation", keyword: "PixelPresentation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,9206)", name: "Volumetric Properties", keyword: "VolumetricProperties", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,9207)", name: "Volume Based Calculation Technique", keyword: "VolumeBasedCalculationTechnique", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,9208)", name: "Complex Image Component", keyword: "ComplexImageComponent", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,9209)", name: "Acquisition Contrast", keyword: "AcquisitionContrast", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,9215)", name: "Derivation Code Sequence", keyword: "DerivationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,9237)", name: "Referenced Presentation State Sequence", keyword: "ReferencedPresentationStateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,9410)", name: "Referenced Other Plane Sequence", keyword: "ReferencedOtherPlaneSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0008,9458)", name: "Frame Display Sequence", keyword: "FrameDisplaySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0008,9459)", name: "Recommended Display Frame Rate in Float", keyword: "RecommendedDisplayFrameRateInFloat", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0008,9460)", name: "Skip Frame Range Flag", keyword: "SkipFrameRangeFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0010,0010)", name: "Patient's Name", keyword: "PatientName", vr: Some(DicomVr::Pn), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0010,0020)", name: "Patient ID", keyword: "PatientID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0010,0021)", name: "Issuer of Patient ID", keyword: "IssuerOfPatientID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0022)", name: "Type of Patient ID", keyword: "TypeOfPatientID", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0024)", name: "Issuer of Patient ID Qualifiers Sequence", keyword: "IssuerOfPatientIDQualifiersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0026)", name: "Source Patient Group Identification Sequence", keyword: "SourcePatientGroupIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0027)", name: "Group of Patients Identification Sequence", keyword: "GroupOfPatientsIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0028)", name: "Subject Relative Position in Image", keyword: "SubjectRelativePositionInImage", vr: Some(DicomVr::Us), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0030)", name: "Patient's Birth Date", keyword: "PatientBirthDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0010,0032)", name: "Patient's Birth Time", keyword: "PatientBirthTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0033)", name: "Patient's Birth Date in Alternative Calendar", keyword: "PatientBirthDateInAlternativeCalendar", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0034)", name: "Patient's Death Date in Alternative Calendar", keyword: "PatientDeathDateInAlternativeCalendar", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0035)", name: "Patient's Alternative Calendar", keyword: "PatientAlternativeCalendar", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0010,0040)", name: "Patient's Sex", keyword: "PatientSex", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0010,0200)", name: "Quality Control Subject", keyword: "QualityControlSubject", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0212)", name: "Strain Description", keyword: "StrainDescription", vr: Some(DicomVr::Uc), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0213)", name: "Strain Nomenclature", keyword: "StrainNomenclature", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0214)", name: "Strain Stock Number", keyword: "StrainStockNumber", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0010,0215)", name: "Strain Source Registry Code Sequence", keyword: "StrainSourceRegistryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0010,0216)", name: "Strain Stock Sequence", keyword: "StrainStockSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0217)", name: "Strain Source", keyword: "StrainSource", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0010,0218)", name: "Strain Additional Information", keyword: "StrainAdditionalInformation", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0219)", name: "Strain Code Sequence", keyword: "StrainCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0221)", name: "Genetic Modifications Sequence", keyword: "GeneticModificationsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,0222)", name: "Genetic Modifications Description", keyword: "GeneticModificationsDescription", vr: Some(DicomVr::Uc), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0010,0223)", name: "Genetic Modifications Nomenclature", keyword: "GeneticModificationsNomenclature", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0010,0229)", name: "Genetic Modifications Code Sequence", keyword: "GeneticModificationsCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,1001)", name: "Other Patient Names", keyword: "OtherPatientNames", vr: Some(DicomVr::Pn), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0010,1002)", name: "Other Patient IDs Sequence", keyword: "OtherPatientIDsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,1010)", name: "Patient's Age", keyword: "PatientAge", vr: Some(DicomVr::As), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,1020)", name: "Patient's Size", keyword: "PatientSize", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,1021)", name: "Patient's Size Code Sequence", keyword: "PatientSizeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,1022)", name: "Patient's Body Mass Index", keyword: "PatientBodyMassIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,1023)", name: "Measured AP Dimension", keyword: "MeasuredAPDimension", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,1024)", name: "Measured Lateral Dimension", keyword: "MeasuredLateralDimension", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,1030)", name: "Patient's Weight", keyword: "PatientWeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,1100)", name: "Referenced Patient Photo Sequence", keyword: "ReferencedPatientPhotoSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,2000)", name: "Medical Alerts", keyword: "MedicalAlerts", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0010,2110)", name: "Allergies", keyword: "Allergies", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0010,2160)", name: "Ethnic Group", keyword: "EthnicGroup", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,2180)", name: "Occupation", keyword: "Occupation", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,21A0)", name: "Smoking Status", keyword: "SmokingStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,21B0)", name: "Additional Patient History", keyword: "AdditionalPatientHistory", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,21C0)", name: "Pregnancy Status", keyword: "PregnancyStatus", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,21D0)", name: "Last Menstrual Date", keyword: "LastMenstrualDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,2201)", name: "Patient Species Description", keyword: "PatientSpeciesDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0010,2202)", name: "Patient Species Code Sequence", keyword: "PatientSpeciesCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0010,2203)", name: "Patient's Sex Neutered", keyword: "PatientSexNeutered", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0010,2210)", name: "Anatomical Orientation Type", keyword: "AnatomicalOrientationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0010,2292)", name: "Patient Breed Description", keyword: "PatientBreedDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0010,2293)", name: "Patient Breed Code Sequence", keyword: "PatientBreedCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0010,2294)", name: "Breed Registration Sequence", keyword: "BreedRegistrationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0010,2295)", name: "Breed Registration Number", keyword: "BreedRegistrationNumber", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0010,2296)", name: "Breed Registry Code Sequence", keyword: "BreedRegistryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0010,2297)", name: "Responsible Person", keyword: "ResponsiblePerson", vr: Some(DicomVr::Pn), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0010,2298)", name: "Responsible Person Role", keyword: "ResponsiblePersonRole", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0010,2299)", name: "Responsible Organization", keyword: "ResponsibleOrganization", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0010,4000)", name: "Patient Comments", keyword: "PatientComments", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0010,9431)", name: "Examined Body Thickness", keyword: "ExaminedBodyThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0010)", name: "Clinical Trial Sponsor Name", keyword: "ClinicalTrialSponsorName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0012,0020)", name: "Clinical Trial Protocol ID", keyword: "ClinicalTrialProtocolID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0012,0021)", name: "Clinical Trial Protocol Name", keyword: "ClinicalTrialProtocolName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0012,0022)", name: "Issuer of Clinical Trial Protocol ID", keyword: "IssuerOfClinicalTrialProtocolID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0023)", name: "Other Clinical Trial Protocol IDs Sequence", keyword: "OtherClinicalTrialProtocolIDsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0030)", name: "Clinical Trial Site ID", keyword: "ClinicalTrialSiteID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0012,0031)", name: "Clinical Trial Site Name", keyword: "ClinicalTrialSiteName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0012,0032)", name: "Issuer of Clinical Trial Site ID", keyword: "IssuerOfClinicalTrialSiteID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0040)", name: "Clinical Trial Subject ID", keyword: "ClinicalTrialSubjectID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0012,0041)", name: "Issuer of Clinical Trial Subject ID", keyword: "IssuerOfClinicalTrialSubjectID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0042)", name: "Clinical Trial Subject Reading ID", keyword: "ClinicalTrialSubjectReadingID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0012,0043)", name: "Issuer of Clinical Trial Subject Reading ID", keyword: "IssuerOfClinicalTrialSubjectReadingID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0050)", name: "Clinical Trial Time Point ID", keyword: "ClinicalTrialTimePointID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0012,0051)", name: "Clinical Trial Time Point Description", keyword: "ClinicalTrialTimePointDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0052)", name: "Longitudinal Temporal Offset from Event", keyword: "LongitudinalTemporalOffsetFromEvent", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0053)", name: "Longitudinal Temporal Event Type", keyword: "LongitudinalTemporalEventType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0012,0054)", name: "Clinical Trial Time Point Type Code Sequence", keyword: "ClinicalTrialTimePointTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0055)", name: "Issuer of Clinical Trial Time Point ID", keyword: "IssuerOfClinicalTrialTimePointID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0060)", name: "Clinical Trial Coordinating Center Name", keyword: "ClinicalTrialCoordinatingCenterName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0012,0062)", name: "Patient Identity Removed", keyword: "PatientIdentityRemoved", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0063)", name: "De-identification Method", keyword: "DeidentificationMethod", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0012,0064)", name: "De-identification Method Code Sequence", keyword: "DeidentificationMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0012,0071)", name: "Clinical Trial Series ID", keyword: "ClinicalTrialSeriesID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0072)", name: "Clinical Trial Series Description", keyword: "ClinicalTrialSeriesDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0073)", name: "Issuer of Clinical Trial Series ID", keyword: "IssuerOfClinicalTrialSeriesID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0081)", name: "Clinical Trial Protocol Ethics Committee Name", keyword: "ClinicalTrialProtocolEthicsCommitteeName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0012,0082)", name: "Clinical Trial Protocol Ethics Committee Approval Number", keyword: "ClinicalTrialProtocolEthicsCommitteeApprovalNumber", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0083)", name: "Consent for Clinical Trial Use Sequence", keyword: "ConsentForClinicalTrialUseSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0084)", name: "Distribution Type", keyword: "DistributionType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0012,0085)", name: "Consent for Distribution Flag", keyword: "ConsentForDistributionFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0012,0086)", name: "Ethics Committee Approval Effectiveness Start Date", keyword: "EthicsCommitteeApprovalEffectivenessStartDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0012,0087)", name: "Ethics Committee Approval Effectiveness End Date", keyword: "EthicsCommitteeApprovalEffectivenessEndDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0001)", name: "White Point", keyword: "WhitePoint", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0002)", name: "Primary Chromaticities", keyword: "PrimaryChromaticities", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0003)", name: "Battery Level", keyword: "BatteryLevel", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0004)", name: "Exposure Time in Seconds", keyword: "ExposureTimeInSeconds", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0005)", name: "F-Number", keyword: "FNumber", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0006)", name: "OECF Rows", keyword: "OECFRows", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0007)", name: "OECF Columns", keyword: "OECFColumns", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0008)", name: "OECF Column Names", keyword: "OECFColumnNames", vr: Some(DicomVr::Uc), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0009)", name: "OECF Values", keyword: "OECFValues", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0016,000A)", name: "Spatial Frequency Response Rows", keyword: "SpatialFrequencyResponseRows", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,000B)", name: "Spatial Frequency Response Columns", keyword: "SpatialFrequencyResponseColumns", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,000C)", name: "Spatial Frequency Response Column Names", keyword: "SpatialFrequencyResponseColumnNames", vr: Some(DicomVr::Uc), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0016,000D)", name: "Spatial Frequency Response Values", keyword: "SpatialFrequencyResponseValues", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0016,000E)", name: "Color Filter Array Pattern Rows", keyword: "ColorFilterArrayPatternRows", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,000F)", name: "Color Filter Array Pattern Columns", keyword: "ColorFilterArrayPatternColumns", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0010)", name: "Color Filter Array Pattern Values", keyword: "ColorFilterArrayPatternValues", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0011)", name: "Flash Firing Status", keyword: "FlashFiringStatus", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0012)", name: "Flash Return Status", keyword: "FlashReturnStatus", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0013)", name: "Flash Mode", keyword: "FlashMode", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0014)", name: "Flash Function Present", keyword: "FlashFunctionPresent", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0015)", name: "Flash Red Eye Mode", keyword: "FlashRedEyeMode", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0016)", name: "Exposure Program", keyword: "ExposureProgram", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0017)", name: "Spectral Sensitivity", keyword: "SpectralSensitivity", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0018)", name: "Photographic Sensitivity", keyword: "PhotographicSensitivity", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0019)", name: "Self Timer Mode", keyword: "SelfTimerMode", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,001A)", name: "Sensitivity Type", keyword: "SensitivityType", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,001B)", name: "Standard Output Sensitivity", keyword: "StandardOutputSensitivity", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,001C)", name: "Recommended Exposure Index", keyword: "RecommendedExposureIndex", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,001D)", name: "ISO Speed", keyword: "ISOSpeed", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,001E)", name: "ISO Speed Latitude yyy", keyword: "ISOSpeedLatitudeyyy", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,001F)", name: "ISO Speed Latitude zzz", keyword: "ISOSpeedLatitudezzz", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0020)", name: "EXIF Version", keyword: "EXIFVersion", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0021)", name: "Shutter Speed Value", keyword: "ShutterSpeedValue", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0022)", name: "Aperture Value", keyword: "ApertureValue", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0023)", name: "Brightness Value", keyword: "BrightnessValue", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0024)", name: "Exposure Bias Value", keyword: "ExposureBiasValue", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0025)", name: "Max Aperture Value", keyword: "MaxApertureValue", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0026)", name: "Subject Distance", keyword: "SubjectDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0027)", name: "Metering Mode", keyword: "MeteringMode", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0028)", name: "Light Source", keyword: "LightSource", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0029)", name: "Focal Length", keyword: "FocalLength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,002A)", name: "Subject Area", keyword: "SubjectArea", vr: Some(DicomVr::Is), vm: "2-4", attr_type: "3" },
-    DicomAttribute { tag: "(0016,002B)", name: "Maker Note", keyword: "MakerNote", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0030)", name: "Temperature", keyword: "Temperature", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0031)", name: "Humidity", keyword: "Humidity", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0032)", name: "Pressure", keyword: "Pressure", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0033)", name: "Water Depth", keyword: "WaterDepth", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0034)", name: "Acceleration", keyword: "Acceleration", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0035)", name: "Camera Elevation Angle", keyword: "CameraElevationAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0036)", name: "Flash Energy", keyword: "FlashEnergy", vr: Some(DicomVr::Ds), vm: "1-2", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0037)", name: "Subject Location", keyword: "SubjectLocation", vr: Some(DicomVr::Is), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0038)", name: "Photographic Exposure Index", keyword: "PhotographicExposureIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0039)", name: "Sensing Method", keyword: "SensingMethod", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,003A)", name: "File Source", keyword: "FileSource", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,003B)", name: "Scene Type", keyword: "SceneType", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0041)", name: "Custom Rendered", keyword: "CustomRendered", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0042)", name: "Exposure Mode", keyword: "ExposureMode", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0043)", name: "White Balance", keyword: "WhiteBalance", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0044)", name: "Digital Zoom Ratio", keyword: "DigitalZoomRatio", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0045)", name: "Focal Length In 35mm Film", keyword: "FocalLengthIn35mmFilm", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0046)", name: "Scene Capture Type", keyword: "SceneCaptureType", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0047)", name: "Gain Control", keyword: "GainControl", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0048)", name: "Contrast", keyword: "Contrast", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0049)", name: "Saturation", keyword: "Saturation", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,004A)", name: "Sharpness", keyword: "Sharpness", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,004B)", name: "Device Setting Description", keyword: "DeviceSettingDescription", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,004C)", name: "Subject Distance Range", keyword: "SubjectDistanceRange", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,004D)", name: "Camera Owner Name", keyword: "CameraOwnerName", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,004E)", name: "Lens Specification", keyword: "LensSpecification", vr: Some(DicomVr::Ds), vm: "4", attr_type: "3" },
-    DicomAttribute { tag: "(0016,004F)", name: "Lens Make", keyword: "LensMake", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0050)", name: "Lens Model", keyword: "LensModel", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0051)", name: "Lens Serial Number", keyword: "LensSerialNumber", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0061)", name: "Interoperability Index", keyword: "InteroperabilityIndex", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0062)", name: "Interoperability Version", keyword: "InteroperabilityVersion", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0070)", name: "GPS Version ID", keyword: "GPSVersionID", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0071)", name: "GPS Latitude Ref", keyword: "GPSLatitudeRef", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0072)", name: "GPS Latitude", keyword: "GPSLatitude", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0073)", name: "GPS Longitude Ref", keyword: "GPSLongitudeRef", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0074)", name: "GPS Longitude", keyword: "GPSLongitude", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0075)", name: "GPS Altitude Ref", keyword: "GPSAltitudeRef", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0076)", name: "GPS Altitude", keyword: "GPSAltitude", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0077)", name: "GPS Time Stamp", keyword: "GPSTimeStamp", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0078)", name: "GPS Satellites", keyword: "GPSSatellites", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0079)", name: "GPS Status", keyword: "GPSStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,007A)", name: "GPS Measure Mode", keyword: "GPSMeasureMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,007B)", name: "GPS DOP", keyword: "GPSDOP", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,007C)", name: "GPS Speed Ref", keyword: "GPSSpeedRef", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,007D)", name: "GPS Speed", keyword: "GPSSpeed", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,007E)", name: "GPS Track Ref", keyword: "GPSTrackRef", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,007F)", name: "GPS Track", keyword: "GPSTrack", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0080)", name: "GPS Img Direction Ref", keyword: "GPSImgDirectionRef", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0081)", name: "GPS Img Direction", keyword: "GPSImgDirection", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0082)", name: "GPS Map Datum", keyword: "GPSMapDatum", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0083)", name: "GPS Dest Latitude Ref", keyword: "GPSDestLatitudeRef", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0084)", name: "GPS Dest Latitude", keyword: "GPSDestLatitude", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0085)", name: "GPS Dest Longitude Ref", keyword: "GPSDestLongitudeRef", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0086)", name: "GPS Dest Longitude", keyword: "GPSDestLongitude", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0087)", name: "GPS Dest Bearing Ref", keyword: "GPSDestBearingRef", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0088)", name: "GPS Dest Bearing", keyword: "GPSDestBearing", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,0089)", name: "GPS Dest Distance Ref", keyword: "GPSDestDistanceRef", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,008A)", name: "GPS Dest Distance", keyword: "GPSDestDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,008B)", name: "GPS Processing Method", keyword: "GPSProcessingMethod", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,008C)", name: "GPS Area Information", keyword: "GPSAreaInformation", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,008D)", name: "GPS Date Stamp", keyword: "GPSDateStamp", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,008E)", name: "GPS Differential", keyword: "GPSDifferential", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0016,1001)", name: "Light Source Polarization", keyword: "LightSourcePolarization", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0016,1002)", name: "Emitter Color Temperature", keyword: "EmitterColorTemperature", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0016,1003)", name: "Contact Method", keyword: "ContactMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0016,1004)", name: "Immersion Media", keyword: "ImmersionMedia", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "2C" },
-    DicomAttribute { tag: "(0016,1005)", name: "Optical Magnification Factor", keyword: "OpticalMagnificationFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,0010)", name: "Contrast/Bolus Agent", keyword: "ContrastBolusAgent", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,0012)", name: "Contrast/Bolus Agent Sequence", keyword: "ContrastBolusAgentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0013)", name: "Contrast/Bolus T1 Relaxivity", keyword: "ContrastBolusT1Relaxivity", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0014)", name: "Contrast/Bolus Administration Route Sequence", keyword: "ContrastBolusAdministrationRouteSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0015)", name: "Body Part Examined", keyword: "BodyPartExamined", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0020)", name: "Scanning Sequence", keyword: "ScanningSequence", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0018,0021)", name: "Sequence Variant", keyword: "SequenceVariant", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0018,0022)", name: "Scan Options", keyword: "ScanOptions", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0023)", name: "MR Acquisition Type", keyword: "MRAcquisitionType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,0024)", name: "Sequence Name", keyword: "SequenceName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0025)", name: "Angio Flag", keyword: "AngioFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0026)", name: "Intervention Drug Information Sequence", keyword: "InterventionDrugInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0027)", name: "Intervention Drug Stop Time", keyword: "InterventionDrugStopTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0028)", name: "Intervention Drug Dose", keyword: "InterventionDrugDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0029)", name: "Intervention Drug Code Sequence", keyword: "InterventionDrugCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,002A)", name: "Additional Drug Sequence", keyword: "AdditionalDrugSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0031)", name: "Radiopharmaceutical", keyword: "Radiopharmaceutical", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0034)", name: "Intervention Drug Name", keyword: "InterventionDrugName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0035)", name: "Intervention Drug Start Time", keyword: "InterventionDrugStartTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0036)", name: "Intervention Sequence", keyword: "InterventionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0038)", name: "Intervention Status", keyword: "InterventionStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,003A)", name: "Intervention Description", keyword: "InterventionDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0040)", name: "Cine Rate", keyword: "CineRate", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0042)", name: "Initial Cine Run State", keyword: "InitialCineRunState", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,0050)", name: "Slice Thickness", keyword: "SliceThickness", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,0060)", name: "KVP", keyword: "KVP", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0070)", name: "Counts Accumulated", keyword: "CountsAccumulated", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,0071)", name: "Acquisition Termination Condition", keyword: "AcquisitionTerminationCondition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0072)", name: "Effective Duration", keyword: "EffectiveDuration", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0073)", name: "Acquisition Start Condition", keyword: "AcquisitionStartCondition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0074)", name: "Acquisition Start Condition Data", keyword: "AcquisitionStartConditionData", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0075)", name: "Acquisition Termination Condition Data", keyword: "AcquisitionTerminationConditionData", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0080)", name: "Repetition Time", keyword: "RepetitionTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,0081)", name: "Echo Time", keyword: "EchoTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,0082)", name: "Inversion Time", keyword: "InversionTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,0083)", name: "Number of Averages", keyword: "NumberOfAverages", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0084)", name: "Imaging Frequency", keyword: "ImagingFrequency", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0085)", name: "Imaged Nucleus", keyword: "ImagedNucleus", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0086)", name: "Echo Number(s)", keyword: "EchoNumbers", vr: Some(DicomVr::Is), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0087)", name: "Magnetic Field Strength", keyword: "MagneticFieldStrength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0088)", name: "Spacing Between Slices", keyword: "SpacingBetweenSlices", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0089)", name: "Number of Phase Encoding Steps", keyword: "NumberOfPhaseEncodingSteps", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0090)", name: "Data Collection Diameter", keyword: "DataCollectionDiameter", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0091)", name: "Echo Train Length", keyword: "EchoTrainLength", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,0093)", name: "Percent Sampling", keyword: "PercentSampling", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0094)", name: "Percent Phase Field of View", keyword: "PercentPhaseFieldOfView", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,0095)", name: "Pixel Bandwidth", keyword: "PixelBandwidth", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1000)", name: "Device Serial Number", keyword: "DeviceSerialNumber", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1002)", name: "Device UID", keyword: "DeviceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1003)", name: "Device ID", keyword: "DeviceID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1004)", name: "Plate ID", keyword: "PlateID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1005)", name: "Generator ID", keyword: "GeneratorID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1006)", name: "Grid ID", keyword: "GridID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1007)", name: "Cassette ID", keyword: "CassetteID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1008)", name: "Gantry ID", keyword: "GantryID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1009)", name: "Unique Device Identifier", keyword: "UniqueDeviceIdentifier", vr: Some(DicomVr::Ut), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,100A)", name: "UDI Sequence", keyword: "UDISequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,100B)", name: "Manufacturer's Device Class UID", keyword: "ManufacturerDeviceClassUID", vr: Some(DicomVr::Ui), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1010)", name: "Secondary Capture Device ID", keyword: "SecondaryCaptureDeviceID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1012)", name: "Date of Secondary Capture", keyword: "DateOfSecondaryCapture", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1014)", name: "Time of Secondary Capture", keyword: "TimeOfSecondaryCapture", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1016)", name: "Secondary Capture Device Manufacturer", keyword: "SecondaryCaptureDeviceManufacturer", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1018)", name: "Secondary Capture Device Manufacturer's Model Name", keyword: "SecondaryCaptureDeviceManufacturerModelName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1019)", name: "Secondary Capture Device Software Versions", keyword: "SecondaryCaptureDeviceSoftwareVersions", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1020)", name: "Software Versions", keyword: "SoftwareVersions", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1022)", name: "Video Image Format Acquired", keyword: "VideoImageFormatAcquired", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1023)", name: "Digital Image Format Acquired", keyword: "DigitalImageFormatAcquired", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1030)", name: "Protocol Name", keyword: "ProtocolName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1040)", name: "Contrast/Bolus Route", keyword: "ContrastBolusRoute", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1041)", name: "Contrast/Bolus Volume", keyword: "ContrastBolusVolume", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1042)", name: "Contrast/Bolus Start Time", keyword: "ContrastBolusStartTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1043)", name: "Contrast/Bolus Stop Time", keyword: "ContrastBolusStopTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1044)", name: "Contrast/Bolus Total Dose", keyword: "ContrastBolusTotalDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1045)", name: "Syringe Counts", keyword: "SyringeCounts", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1046)", name: "Contrast Flow Rate", keyword: "ContrastFlowRate", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1047)", name: "Contrast Flow Duration", keyword: "ContrastFlowDuration", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1048)", name: "Contrast/Bolus Ingredient", keyword: "ContrastBolusIngredient", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1049)", name: "Contrast/Bolus Ingredient Concentration", keyword: "ContrastBolusIngredientConcentration", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1050)", name: "Spatial Resolution", keyword: "SpatialResolution", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1060)", name: "Trigger Time", keyword: "TriggerTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,1061)", name: "Trigger Source or Type", keyword: "TriggerSourceOrType", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1062)", name: "Nominal Interval", keyword: "NominalInterval", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1063)", name: "Frame Time", keyword: "FrameTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,1064)", name: "Cardiac Framing Type", keyword: "CardiacFramingType", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1065)", name: "Frame Time Vector", keyword: "FrameTimeVector", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1066)", name: "Frame Delay", keyword: "FrameDelay", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1067)", name: "Image Trigger Delay", keyword: "ImageTriggerDelay", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1068)", name: "Multiplex Group Time Offset", keyword: "MultiplexGroupTimeOffset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1069)", name: "Trigger Time Offset", keyword: "TriggerTimeOffset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,106A)", name: "Synchronization Trigger", keyword: "SynchronizationTrigger", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,106C)", name: "Synchronization Channel", keyword: "SynchronizationChannel", vr: Some(DicomVr::Us), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,106E)", name: "Trigger Sample Position", keyword: "TriggerSamplePosition", vr: Some(DicomVr::Ul), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1070)", name: "Radiopharmaceutical Route", keyword: "RadiopharmaceuticalRoute", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1071)", name: "Radiopharmaceutical Volume", keyword: "RadiopharmaceuticalVolume", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1072)", name: "Radiopharmaceutical Start Time", keyword: "RadiopharmaceuticalStartTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1073)", name: "Radiopharmaceutical Stop Time", keyword: "RadiopharmaceuticalStopTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1074)", name: "Radionuclide Total Dose", keyword: "RadionuclideTotalDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1075)", name: "Radionuclide Half Life", keyword: "RadionuclideHalfLife", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1076)", name: "Radionuclide Positron Fraction", keyword: "RadionuclidePositronFraction", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1077)", name: "Radiopharmaceutical Specific Activity", keyword: "RadiopharmaceuticalSpecificActivity", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1078)", name: "Radiopharmaceutical Start DateTime", keyword: "RadiopharmaceuticalStartDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1079)", name: "Radiopharmaceutical Stop DateTime", keyword: "RadiopharmaceuticalStopDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1080)", name: "Beat Rejection Flag", keyword: "BeatRejectionFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1081)", name: "Low R-R Value", keyword: "LowRRValue", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1082)", name: "High R-R Value", keyword: "HighRRValue", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1083)", name: "Intervals Acquired", keyword: "IntervalsAcquired", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1084)", name: "Intervals Rejected", keyword: "IntervalsRejected", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1085)", name: "PVC Rejection", keyword: "PVCRejection", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1086)", name: "Skip Beats", keyword: "SkipBeats", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1088)", name: "Heart Rate", keyword: "HeartRate", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1090)", name: "Cardiac Number of Images", keyword: "CardiacNumberOfImages", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1094)", name: "Trigger Window", keyword: "TriggerWindow", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1100)", name: "Reconstruction Diameter", keyword: "ReconstructionDiameter", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1110)", name: "Distance Source to Detector", keyword: "DistanceSourceToDetector", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1111)", name: "Distance Source to Patient", keyword: "DistanceSourceToPatient", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1114)", name: "Estimated Radiographic Magnification Factor", keyword: "EstimatedRadiographicMagnificationFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1120)", name: "Gantry/Detector Tilt", keyword: "GantryDetectorTilt", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1121)", name: "Gantry/Detector Slew", keyword: "GantryDetectorSlew", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1130)", name: "Table Height", keyword: "TableHeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1131)", name: "Table Traverse", keyword: "TableTraverse", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1134)", name: "Table Motion", keyword: "TableMotion", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,1135)", name: "Table Vertical Increment", keyword: "TableVerticalIncrement", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,1136)", name: "Table Lateral Increment", keyword: "TableLateralIncrement", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,1137)", name: "Table Longitudinal Increment", keyword: "TableLongitudinalIncrement", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,1138)", name: "Table Angle", keyword: "TableAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,113A)", name: "Table Type", keyword: "TableType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1140)", name: "Rotation Direction", keyword: "RotationDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1142)", name: "Radial Position", keyword: "RadialPosition", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1143)", name: "Scan Arc", keyword: "ScanArc", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,1144)", name: "Angular Step", keyword: "AngularStep", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,1145)", name: "Center of Rotation Offset", keyword: "CenterOfRotationOffset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1147)", name: "Field of View Shape", keyword: "FieldOfViewShape", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1149)", name: "Field of View Dimension(s)", keyword: "FieldOfViewDimensions", vr: Some(DicomVr::Is), vm: "1-2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1150)", name: "Exposure Time", keyword: "ExposureTime", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1151)", name: "X-Ray Tube Current", keyword: "XRayTubeCurrent", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1152)", name: "Exposure", keyword: "Exposure", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1153)", name: "Exposure in µAs", keyword: "ExposureInuAs", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1154)", name: "Average Pulse Width", keyword: "AveragePulseWidth", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1155)", name: "Radiation Setting", keyword: "RadiationSetting", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,1156)", name: "Rectification Type", keyword: "RectificationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,115A)", name: "Radiation Mode", keyword: "RadiationMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,115E)", name: "Image and Fluoroscopy Area Dose Product", keyword: "ImageAndFluoroscopyAreaDoseProduct", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1160)", name: "Filter Type", keyword: "FilterType", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1161)", name: "Type of Filters", keyword: "TypeOfFilters", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1162)", name: "Intensifier Size", keyword: "IntensifierSize", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1164)", name: "Imager Pixel Spacing", keyword: "ImagerPixelSpacing", vr: Some(DicomVr::Ds), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1166)", name: "Grid", keyword: "Grid", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1170)", name: "Generator Power", keyword: "GeneratorPower", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1180)", name: "Collimator/grid Name", keyword: "CollimatorGridName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1181)", name: "Collimator Type", keyword: "CollimatorType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,1182)", name: "Focal Distance", keyword: "FocalDistance", vr: Some(DicomVr::Is), vm: "1-2", attr_type: "2" },
-    DicomAttribute { tag: "(0018,1183)", name: "X Focus Center", keyword: "XFocusCenter", vr: Some(DicomVr::Ds), vm: "1-2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1184)", name: "Y Focus Center", keyword: "YFocusCenter", vr: Some(DicomVr::Ds), vm: "1-2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1190)", name: "Focal Spot(s)", keyword: "FocalSpots", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1191)", name: "Anode Target Material", keyword: "AnodeTargetMaterial", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11A0)", name: "Body Part Thickness", keyword: "BodyPartThickness", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11A2)", name: "Compression Force", keyword: "CompressionForce", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11A3)", name: "Compression Pressure", keyword: "CompressionPressure", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11A4)", name: "Paddle Description", keyword: "PaddleDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11A5)", name: "Compression Contact Area", keyword: "CompressionContactArea", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11B0)", name: "Acquisition Mode", keyword: "AcquisitionMode", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,11B1)", name: "Dose Mode Name", keyword: "DoseModeName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11B2)", name: "Acquired Subtraction Mask Flag", keyword: "AcquiredSubtractionMaskFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11B3)", name: "Fluoroscopy Persistence Flag", keyword: "FluoroscopyPersistenceFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11B4)", name: "Fluoroscopy Last Image Hold Persistence Flag", keyword: "FluoroscopyLastImageHoldPersistenceFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11B5)", name: "Upper Limit Number Of Persistent Fluoroscopy Frames", keyword: "UpperLimitNumberOfPersistentFluoroscopyFrames", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11B6)", name: "Contrast/Bolus Auto Injection Trigger Flag", keyword: "ContrastBolusAutoInjectionTriggerFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11B7)", name: "Contrast/Bolus Injection Delay", keyword: "ContrastBolusInjectionDelay", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11B8)", name: "XA Acquisition Phase Details Sequence", keyword: "XAAcquisitionPhaseDetailsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11B9)", name: "XA Acquisition Frame Rate", keyword: "XAAcquisitionFrameRate", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,11BA)", name: "XA Plane Details Sequence", keyword: "XAPlaneDetailsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11BB)", name: "Acquisition Field of View Label", keyword: "AcquisitionFieldOfViewLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11BC)", name: "X-Ray Filter Details Sequence", keyword: "XRayFilterDetailsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11BD)", name: "XA Acquisition Duration", keyword: "XAAcquisitionDuration", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11BE)", name: "Reconstruction Pipeline Type", keyword: "ReconstructionPipelineType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,11BF)", name: "Image Filter Details Sequence", keyword: "ImageFilterDetailsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11C0)", name: "Applied Mask Subtraction Flag", keyword: "AppliedMaskSubtractionFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,11C1)", name: "Requested Series Description Code Sequence", keyword: "RequestedSeriesDescriptionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1200)", name: "Date of Last Calibration", keyword: "DateOfLastCalibration", vr: Some(DicomVr::Da), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1201)", name: "Time of Last Calibration", keyword: "TimeOfLastCalibration", vr: Some(DicomVr::Tm), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1203)", name: "Calibration DateTime", keyword: "CalibrationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,1204)", name: "Date of Manufacture", keyword: "DateOfManufacture", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1205)", name: "Date of Installation", keyword: "DateOfInstallation", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1210)", name: "Convolution Kernel", keyword: "ConvolutionKernel", vr: Some(DicomVr::Sh), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1242)", name: "Actual Frame Duration", keyword: "ActualFrameDuration", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1243)", name: "Count Rate", keyword: "CountRate", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1244)", name: "Preferred Playback Sequencing", keyword: "PreferredPlaybackSequencing", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1250)", name: "Receive Coil Name", keyword: "ReceiveCoilName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1251)", name: "Transmit Coil Name", keyword: "TransmitCoilName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1260)", name: "Plate Type", keyword: "PlateType", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1261)", name: "Phosphor Type", keyword: "PhosphorType", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1271)", name: "Water Equivalent Diameter", keyword: "WaterEquivalentDiameter", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1272)", name: "Water Equivalent Diameter Calculation Method Code Sequence", keyword: "WaterEquivalentDiameterCalculationMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1300)", name: "Scan Velocity", keyword: "ScanVelocity", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,1301)", name: "Whole Body Technique", keyword: "WholeBodyTechnique", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1302)", name: "Scan Length", keyword: "ScanLength", vr: Some(DicomVr::Is), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,1310)", name: "Acquisition Matrix", keyword: "AcquisitionMatrix", vr: Some(DicomVr::Us), vm: "4", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1312)", name: "In-plane Phase Encoding Direction", keyword: "InPlanePhaseEncodingDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1314)", name: "Flip Angle", keyword: "FlipAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1315)", name: "Variable Flip Angle Flag", keyword: "VariableFlipAngleFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1316)", name: "SAR", keyword: "SAR", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1318)", name: "dB/dt", keyword: "dBdt", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1320)", name: "B1rms", keyword: "B1rms", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1400)", name: "Acquisition Device Processing Description", keyword: "AcquisitionDeviceProcessingDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1401)", name: "Acquisition Device Processing Code", keyword: "AcquisitionDeviceProcessingCode", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1402)", name: "Cassette Orientation", keyword: "CassetteOrientation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1403)", name: "Cassette Size", keyword: "CassetteSize", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1404)", name: "Exposures on Plate", keyword: "ExposuresOnPlate", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1405)", name: "Relative X-Ray Exposure", keyword: "RelativeXRayExposure", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1411)", name: "Exposure Index", keyword: "ExposureIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1412)", name: "Target Exposure Index", keyword: "TargetExposureIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1413)", name: "Deviation Index", keyword: "DeviationIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1450)", name: "Column Angulation", keyword: "ColumnAngulation", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1460)", name: "Tomo Layer Height", keyword: "TomoLayerHeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,1470)", name: "Tomo Angle", keyword: "TomoAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1480)", name: "Tomo Time", keyword: "TomoTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1490)", name: "Tomo Type", keyword: "TomoType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1491)", name: "Tomo Class", keyword: "TomoClass", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1495)", name: "Number of Tomosynthesis Source Images", keyword: "NumberOfTomosynthesisSourceImages", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1500)", name: "Positioner Motion", keyword: "PositionerMotion", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,1508)", name: "Positioner Type", keyword: "PositionerType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,1510)", name: "Positioner Primary Angle", keyword: "PositionerPrimaryAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,1511)", name: "Positioner Secondary Angle", keyword: "PositionerSecondaryAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,1520)", name: "Positioner Primary Angle Increment", keyword: "PositionerPrimaryAngleIncrement", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,1521)", name: "Positioner Secondary Angle Increment", keyword: "PositionerSecondaryAngleIncrement", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,1530)", name: "Detector Primary Angle", keyword: "DetectorPrimaryAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1531)", name: "Detector Secondary Angle", keyword: "DetectorSecondaryAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1600)", name: "Shutter Shape", keyword: "ShutterShape", vr: Some(DicomVr::Cs), vm: "1-3", attr_type: "1" },
-    DicomAttribute { tag: "(0018,1602)", name: "Shutter Left Vertical Edge", keyword: "ShutterLeftVerticalEdge", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1604)", name: "Shutter Right Vertical Edge", keyword: "ShutterRightVerticalEdge", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1606)", name: "Shutter Upper Horizontal Edge", keyword: "ShutterUpperHorizontalEdge", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1608)", name: "Shutter Lower Horizontal Edge", keyword: "ShutterLowerHorizontalEdge", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1610)", name: "Center of Circular Shutter", keyword: "CenterOfCircularShutter", vr: Some(DicomVr::Is), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1612)", name: "Radius of Circular Shutter", keyword: "RadiusOfCircularShutter", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1620)", name: "Vertices of the Polygonal Shutter", keyword: "VerticesOfThePolygonalShutter", vr: Some(DicomVr::Is), vm: "2-2n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1622)", name: "Shutter Presentation Value", keyword: "ShutterPresentationValue", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1623)", name: "Shutter Overlay Group", keyword: "ShutterOverlayGroup", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,1624)", name: "Shutter Presentation Color CIELab Value", keyword: "ShutterPresentationColorCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1630)", name: "Outline Shape Type", keyword: "OutlineShapeType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,1631)", name: "Outline Left Vertical Edge", keyword: "OutlineLeftVerticalEdge", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1632)", name: "Outline Right Vertical Edge", keyword: "OutlineRightVerticalEdge", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1633)", name: "Outline Upper Horizontal Edge", keyword: "OutlineUpperHorizontalEdge", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1634)", name: "Outline Lower Horizontal Edge", keyword: "OutlineLowerHorizontalEdge", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1635)", name: "Center of Circular Outline", keyword: "CenterOfCircularOutline", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1636)", name: "Diameter of Circular Outline", keyword: "DiameterOfCircularOutline", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1637)", name: "Number of Polygonal Vertices", keyword: "NumberOfPolygonalVertices", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1638)", name: "Vertices of the Polygonal Outline", keyword: "VerticesOfThePolygonalOutline", vr: Some(DicomVr::Of), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1700)", name: "Collimator Shape", keyword: "CollimatorShape", vr: Some(DicomVr::Cs), vm: "1-3", attr_type: "1" },
-    DicomAttribute { tag: "(0018,1702)", name: "Collimator Left Vertical Edge", keyword: "CollimatorLeftVerticalEdge", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1704)", name: "Collimator Right Vertical Edge", keyword: "CollimatorRightVerticalEdge", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1706)", name: "Collimator Upper Horizontal Edge", keyword: "CollimatorUpperHorizontalEdge", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1708)", name: "Collimator Lower Horizontal Edge", keyword: "CollimatorLowerHorizontalEdge", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1710)", name: "Center of Circular Collimator", keyword: "CenterOfCircularCollimator", vr: Some(DicomVr::Is), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1712)", name: "Radius of Circular Collimator", keyword: "RadiusOfCircularCollimator", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1720)", name: "Vertices of the Polygonal Collimator", keyword: "VerticesOfThePolygonalCollimator", vr: Some(DicomVr::Is), vm: "2-2n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,1800)", name: "Acquisition Time Synchronized", keyword: "AcquisitionTimeSynchronized", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,1801)", name: "Time Source", keyword: "TimeSource", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1802)", name: "Time Distribution Protocol", keyword: "TimeDistributionProtocol", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,1803)", name: "NTP Source Address", keyword: "NTPSourceAddress", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,2001)", name: "Page Number Vector", keyword: "PageNumberVector", vr: Some(DicomVr::Is), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,2002)", name: "Frame Label Vector", keyword: "FrameLabelVector", vr: Some(DicomVr::Sh), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,2003)", name: "Frame Primary Angle Vector", keyword: "FramePrimaryAngleVector", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,2004)", name: "Frame Secondary Angle Vector", keyword: "FrameSecondaryAngleVector", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,2005)", name: "Slice Location Vector", keyword: "SliceLocationVector", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,2006)", name: "Display Window Label Vector", keyword: "DisplayWindowLabelVector", vr: Some(DicomVr::Sh), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,2010)", name: "Nominal Scanned Pixel Spacing", keyword: "NominalScannedPixelSpacing", vr: Some(DicomVr::Ds), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,2020)", name: "Digitizing Device Transport Direction", keyword: "DigitizingDeviceTransportDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,2030)", name: "Rotation of Scanned Film", keyword: "RotationOfScannedFilm", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,2041)", name: "Biopsy Target Sequence", keyword: "BiopsyTargetSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,2042)", name: "Target UID", keyword: "TargetUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,2043)", name: "Localizing Cursor Position", keyword: "LocalizingCursorPosition", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0018,2044)", name: "Calculated Target Position", keyword: "CalculatedTargetPosition", vr: Some(DicomVr::Fl), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0018,2045)", name: "Target Label", keyword: "TargetLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,2046)", name: "Displayed Z Value", keyword: "DisplayedZValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,3100)", name: "IVUS Acquisition", keyword: "IVUSAcquisition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,3101)", name: "IVUS Pullback Rate", keyword: "IVUSPullbackRate", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,3102)", name: "IVUS Gated Rate", keyword: "IVUSGatedRate", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,3103)", name: "IVUS Pullback Start Frame Number", keyword: "IVUSPullbackStartFrameNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,3104)", name: "IVUS Pullback Stop Frame Number", keyword: "IVUSPullbackStopFrameNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,3105)", name: "Lesion Number", keyword: "LesionNumber", vr: Some(DicomVr::Is), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5000)", name: "Output Power", keyword: "OutputPower", vr: Some(DicomVr::Sh), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5010)", name: "Transducer Data", keyword: "TransducerData", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5011)", name: "Transducer Identification Sequence", keyword: "TransducerIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5012)", name: "Focus Depth", keyword: "FocusDepth", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5020)", name: "Processing Function", keyword: "ProcessingFunction", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5022)", name: "Mechanical Index", keyword: "MechanicalIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5024)", name: "Bone Thermal Index", keyword: "BoneThermalIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5026)", name: "Cranial Thermal Index", keyword: "CranialThermalIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5027)", name: "Soft Tissue Thermal Index", keyword: "SoftTissueThermalIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5028)", name: "Soft Tissue-focus Thermal Index", keyword: "SoftTissueFocusThermalIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5029)", name: "Soft Tissue-surface Thermal Index", keyword: "SoftTissueSurfaceThermalIndex", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5050)", name: "Depth of Scan Field", keyword: "DepthOfScanField", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5100)", name: "Patient Position", keyword: "PatientPosition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,5101)", name: "View Position", keyword: "ViewPosition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,5104)", name: "Projection Eponymous Name Code Sequence", keyword: "ProjectionEponymousNameCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6000)", name: "Sensitivity", keyword: "Sensitivity", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6011)", name: "Sequence of Ultrasound Regions", keyword: "SequenceOfUltrasoundRegions", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,6012)", name: "Region Spatial Format", keyword: "RegionSpatialFormat", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,6014)", name: "Region Data Type", keyword: "RegionDataType", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,6016)", name: "Region Flags", keyword: "RegionFlags", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,6018)", name: "Region Location Min X0", keyword: "RegionLocationMinX0", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,601A)", name: "Region Location Min Y0", keyword: "RegionLocationMinY0", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,601C)", name: "Region Location Max X1", keyword: "RegionLocationMaxX1", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,601E)", name: "Region Location Max Y1", keyword: "RegionLocationMaxY1", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,6020)", name: "Reference Pixel X0", keyword: "ReferencePixelX0", vr: Some(DicomVr::Sl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6022)", name: "Reference Pixel Y0", keyword: "ReferencePixelY0", vr: Some(DicomVr::Sl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6024)", name: "Physical Units X Direction", keyword: "PhysicalUnitsXDirection", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,6026)", name: "Physical Units Y Direction", keyword: "PhysicalUnitsYDirection", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,6028)", name: "Reference Pixel Physical Value X", keyword: "ReferencePixelPhysicalValueX", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,602A)", name: "Reference Pixel Physical Value Y", keyword: "ReferencePixelPhysicalValueY", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,602C)", name: "Physical Delta X", keyword: "PhysicalDeltaX", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,602E)", name: "Physical Delta Y", keyword: "PhysicalDeltaY", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,6030)", name: "Transducer Frequency", keyword: "TransducerFrequency", vr: Some(DicomVr::Ul), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6031)", name: "Transducer Type", keyword: "TransducerType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6032)", name: "Pulse Repetition Frequency", keyword: "PulseRepetitionFrequency", vr: Some(DicomVr::Ul), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6034)", name: "Doppler Correction Angle", keyword: "DopplerCorrectionAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6036)", name: "Steering Angle", keyword: "SteeringAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6039)", name: "Doppler Sample Volume X Position", keyword: "DopplerSampleVolumeXPosition", vr: Some(DicomVr::Sl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,603B)", name: "Doppler Sample Volume Y Position", keyword: "DopplerSampleVolumeYPosition", vr: Some(DicomVr::Sl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,603D)", name: "TM-Line Position X0", keyword: "TMLinePositionX0", vr: Some(DicomVr::Sl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,603F)", name: "TM-Line Position Y0", keyword: "TMLinePositionY0", vr: Some(DicomVr::Sl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6041)", name: "TM-Line Position X1", keyword: "TMLinePositionX1", vr: Some(DicomVr::Sl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6043)", name: "TM-Line Position Y1", keyword: "TMLinePositionY1", vr: Some(DicomVr::Sl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6044)", name: "Pixel Component Organization", keyword: "PixelComponentOrganization", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,6046)", name: "Pixel Component Mask", keyword: "PixelComponentMask", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,6048)", name: "Pixel Component Range Start", keyword: "PixelComponentRangeStart", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,604A)", name: "Pixel Component Range Stop", keyword: "PixelComponentRangeStop", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,604C)", name: "Pixel Component Physical Units", keyword: "PixelComponentPhysicalUnits", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,604E)", name: "Pixel Component Data Type", keyword: "PixelComponentDataType", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,6050)", name: "Number of Table Break Points", keyword: "NumberOfTableBreakPoints", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,6052)", name: "Table of X Break Points", keyword: "TableOfXBreakPoints", vr: Some(DicomVr::Ul), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,6054)", name: "Table of Y Break Points", keyword: "TableOfYBreakPoints", vr: Some(DicomVr::Fd), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,6056)", name: "Number of Table Entries", keyword: "NumberOfTableEntries", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,6058)", name: "Table of Pixel Values", keyword: "TableOfPixelValues", vr: Some(DicomVr::Ul), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,605A)", name: "Table of Parameter Values", keyword: "TableOfParameterValues", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,6060)", name: "R Wave Time Vector", keyword: "RWaveTimeVector", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,6070)", name: "Active Image Area Overlay Group", keyword: "ActiveImageAreaOverlayGroup", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7000)", name: "Detector Conditions Nominal Flag", keyword: "DetectorConditionsNominalFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7001)", name: "Detector Temperature", keyword: "DetectorTemperature", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7004)", name: "Detector Type", keyword: "DetectorType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,7005)", name: "Detector Configuration", keyword: "DetectorConfiguration", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7006)", name: "Detector Description", keyword: "DetectorDescription", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7008)", name: "Detector Mode", keyword: "DetectorMode", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,700A)", name: "Detector ID", keyword: "DetectorID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,700C)", name: "Date of Last Detector Calibration", keyword: "DateOfLastDetectorCalibration", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,700E)", name: "Time of Last Detector Calibration", keyword: "TimeOfLastDetectorCalibration", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7010)", name: "Exposures on Detector Since Last Calibration", keyword: "ExposuresOnDetectorSinceLastCalibration", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7011)", name: "Exposures on Detector Since Manufactured", keyword: "ExposuresOnDetectorSinceManufactured", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7012)", name: "Detector Time Since Last Exposure", keyword: "DetectorTimeSinceLastExposure", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7014)", name: "Detector Active Time", keyword: "DetectorActiveTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7016)", name: "Detector Activation Offset From Exposure", keyword: "DetectorActivationOffsetFromExposure", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,701A)", name: "Detector Binning", keyword: "DetectorBinning", vr: Some(DicomVr::Ds), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7020)", name: "Detector Element Physical Size", keyword: "DetectorElementPhysicalSize", vr: Some(DicomVr::Ds), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7022)", name: "Detector Element Spacing", keyword: "DetectorElementSpacing", vr: Some(DicomVr::Ds), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7024)", name: "Detector Active Shape", keyword: "DetectorActiveShape", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7026)", name: "Detector Active Dimension(s)", keyword: "DetectorActiveDimensions", vr: Some(DicomVr::Ds), vm: "1-2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7028)", name: "Detector Active Origin", keyword: "DetectorActiveOrigin", vr: Some(DicomVr::Ds), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,702A)", name: "Detector Manufacturer Name", keyword: "DetectorManufacturerName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,702B)", name: "Detector Manufacturer's Model Name", keyword: "DetectorManufacturerModelName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7030)", name: "Field of View Origin", keyword: "FieldOfViewOrigin", vr: Some(DicomVr::Ds), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,7032)", name: "Field of View Rotation", keyword: "FieldOfViewRotation", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,7034)", name: "Field of View Horizontal Flip", keyword: "FieldOfViewHorizontalFlip", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,7036)", name: "Pixel Data Area Origin Relative To FOV", keyword: "PixelDataAreaOriginRelativeToFOV", vr: Some(DicomVr::Fl), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7038)", name: "Pixel Data Area Rotation Angle Relative To FOV", keyword: "PixelDataAreaRotationAngleRelativeToFOV", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7040)", name: "Grid Absorbing Material", keyword: "GridAbsorbingMaterial", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7041)", name: "Grid Spacing Material", keyword: "GridSpacingMaterial", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7042)", name: "Grid Thickness", keyword: "GridThickness", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7044)", name: "Grid Pitch", keyword: "GridPitch", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7046)", name: "Grid Aspect Ratio", keyword: "GridAspectRatio", vr: Some(DicomVr::Is), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7048)", name: "Grid Period", keyword: "GridPeriod", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,704C)", name: "Grid Focal Distance", keyword: "GridFocalDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7050)", name: "Filter Material", keyword: "FilterMaterial", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0018,7052)", name: "Filter Thickness Minimum", keyword: "FilterThicknessMinimum", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7054)", name: "Filter Thickness Maximum", keyword: "FilterThicknessMaximum", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7056)", name: "Filter Beam Path Length Minimum", keyword: "FilterBeamPathLengthMinimum", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7058)", name: "Filter Beam Path Length Maximum", keyword: "FilterBeamPathLengthMaximum", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7060)", name: "Exposure Control Mode", keyword: "ExposureControlMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7062)", name: "Exposure Control Mode Description", keyword: "ExposureControlModeDescription", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7064)", name: "Exposure Status", keyword: "ExposureStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,7065)", name: "Phototimer Setting", keyword: "PhototimerSetting", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,8150)", name: "Exposure Time in µS", keyword: "ExposureTimeInuS", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,8151)", name: "X-Ray Tube Current in µA", keyword: "XRayTubeCurrentInuA", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9004)", name: "Content Qualification", keyword: "ContentQualification", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9005)", name: "Pulse Sequence Name", keyword: "PulseSequenceName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9006)", name: "MR Imaging Modifier Sequence", keyword: "MRImagingModifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9008)", name: "Echo Pulse Sequence", keyword: "EchoPulseSequence", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9009)", name: "Inversion Recovery", keyword: "InversionRecovery", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9010)", name: "Flow Compensation", keyword: "FlowCompensation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9011)", name: "Multiple Spin Echo", keyword: "MultipleSpinEcho", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9012)", name: "Multi-planar Excitation", keyword: "MultiPlanarExcitation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9014)", name: "Phase Contrast", keyword: "PhaseContrast", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9015)", name: "Time of Flight Contrast", keyword: "TimeOfFlightContrast", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9016)", name: "Spoiling", keyword: "Spoiling", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9017)", name: "Steady State Pulse Sequence", keyword: "SteadyStatePulseSequence", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9018)", name: "Echo Planar Pulse Sequence", keyword: "EchoPlanarPulseSequence", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9019)", name: "Tag Angle First Axis", keyword: "TagAngleFirstAxis", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9020)", name: "Magnetization Transfer", keyword: "MagnetizationTransfer", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9021)", name: "T2 Preparation", keyword: "T2Preparation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9022)", name: "Blood Signal Nulling", keyword: "BloodSignalNulling", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9024)", name: "Saturation Recovery", keyword: "SaturationRecovery", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9025)", name: "Spectrally Selected Suppression", keyword: "SpectrallySelectedSuppression", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9026)", name: "Spectrally Selected Excitation", keyword: "SpectrallySelectedExcitation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9027)", name: "Spatial Pre-saturation", keyword: "SpatialPresaturation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9028)", name: "Tagging", keyword: "Tagging", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9029)", name: "Oversampling Phase", keyword: "OversamplingPhase", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9030)", name: "Tag Spacing First Dimension", keyword: "TagSpacingFirstDimension", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9032)", name: "Geometry of k-Space Traversal", keyword: "GeometryOfKSpaceTraversal", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9033)", name: "Segmented k-Space Traversal", keyword: "SegmentedKSpaceTraversal", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9034)", name: "Rectilinear Phase Encode Reordering", keyword: "RectilinearPhaseEncodeReordering", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9035)", name: "Tag Thickness", keyword: "TagThickness", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9036)", name: "Partial Fourier Direction", keyword: "PartialFourierDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9037)", name: "Cardiac Synchronization Technique", keyword: "CardiacSynchronizationTechnique", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9041)", name: "Receive Coil Manufacturer Name", keyword: "ReceiveCoilManufacturerName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,9042)", name: "MR Receive Coil Sequence", keyword: "MRReceiveCoilSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9043)", name: "Receive Coil Type", keyword: "ReceiveCoilType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9044)", name: "Quadrature Receive Coil", keyword: "QuadratureReceiveCoil", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9045)", name: "Multi-Coil Definition Sequence", keyword: "MultiCoilDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9046)", name: "Multi-Coil Configuration", keyword: "MultiCoilConfiguration", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9047)", name: "Multi-Coil Element Name", keyword: "MultiCoilElementName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9048)", name: "Multi-Coil Element Used", keyword: "MultiCoilElementUsed", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9049)", name: "MR Transmit Coil Sequence", keyword: "MRTransmitCoilSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9050)", name: "Transmit Coil Manufacturer Name", keyword: "TransmitCoilManufacturerName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,9051)", name: "Transmit Coil Type", keyword: "TransmitCoilType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9052)", name: "Spectral Width", keyword: "SpectralWidth", vr: Some(DicomVr::Fd), vm: "1-2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9053)", name: "Chemical Shift Reference", keyword: "ChemicalShiftReference", vr: Some(DicomVr::Fd), vm: "1-2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9054)", name: "Volume Localization Technique", keyword: "VolumeLocalizationTechnique", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9058)", name: "MR Acquisition Frequency Encoding Steps", keyword: "MRAcquisitionFrequencyEncodingSteps", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9059)", name: "De-coupling", keyword: "Decoupling", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9060)", name: "De-coupled Nucleus", keyword: "DecoupledNucleus", vr: Some(DicomVr::Cs), vm: "1-2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9061)", name: "De-coupling Frequency", keyword: "DecouplingFrequency", vr: Some(DicomVr::Fd), vm: "1-2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9062)", name: "De-coupling Method", keyword: "DecouplingMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9063)", name: "De-coupling Chemical Shift Reference", keyword: "DecouplingChemicalShiftReference", vr: Some(DicomVr::Fd), vm: "1-2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9064)", name: "k-space Filtering", keyword: "KSpaceFiltering", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9065)", name: "Time Domain Filtering", keyword: "TimeDomainFiltering", vr: Some(DicomVr::Cs), vm: "1-2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9066)", name: "Number of Zero Fills", keyword: "NumberOfZeroFills", vr: Some(DicomVr::Us), vm: "1-2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9067)", name: "Baseline Correction", keyword: "BaselineCorrection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9069)", name: "Parallel Reduction Factor In-plane", keyword: "ParallelReductionFactorInPlane", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9070)", name: "Cardiac R-R Interval Specified", keyword: "CardiacRRIntervalSpecified", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9073)", name: "Acquisition Duration", keyword: "AcquisitionDuration", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9074)", name: "Frame Acquisition DateTime", keyword: "FrameAcquisitionDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9075)", name: "Diffusion Directionality", keyword: "DiffusionDirectionality", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9076)", name: "Diffusion Gradient Direction Sequence", keyword: "DiffusionGradientDirectionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9077)", name: "Parallel Acquisition", keyword: "ParallelAcquisition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9078)", name: "Parallel Acquisition Technique", keyword: "ParallelAcquisitionTechnique", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9079)", name: "Inversion Times", keyword: "InversionTimes", vr: Some(DicomVr::Fd), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9080)", name: "Metabolite Map Description", keyword: "MetaboliteMapDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9081)", name: "Partial Fourier", keyword: "PartialFourier", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9082)", name: "Effective Echo Time", keyword: "EffectiveEchoTime", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9083)", name: "Metabolite Map Code Sequence", keyword: "MetaboliteMapCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9084)", name: "Chemical Shift Sequence", keyword: "ChemicalShiftSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9085)", name: "Cardiac Signal Source", keyword: "CardiacSignalSource", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9087)", name: "Diffusion b-value", keyword: "DiffusionBValue", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9089)", name: "Diffusion Gradient Orientation", keyword: "DiffusionGradientOrientation", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9090)", name: "Velocity Encoding Direction", keyword: "VelocityEncodingDirection", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9091)", name: "Velocity Encoding Minimum Value", keyword: "VelocityEncodingMinimumValue", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9092)", name: "Velocity Encoding Acquisition Sequence", keyword: "VelocityEncodingAcquisitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9093)", name: "Number of k-Space Trajectories", keyword: "NumberOfKSpaceTrajectories", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9094)", name: "Coverage of k-Space", keyword: "CoverageOfKSpace", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9095)", name: "Spectroscopy Acquisition Phase Rows", keyword: "SpectroscopyAcquisitionPhaseRows", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9098)", name: "Transmitter Frequency", keyword: "TransmitterFrequency", vr: Some(DicomVr::Fd), vm: "1-2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9100)", name: "Resonant Nucleus", keyword: "ResonantNucleus", vr: Some(DicomVr::Cs), vm: "1-2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9101)", name: "Frequency Correction", keyword: "FrequencyCorrection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9103)", name: "MR Spectroscopy FOV/Geometry Sequence", keyword: "MRSpectroscopyFOVGeometrySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9104)", name: "Slab Thickness", keyword: "SlabThickness", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9105)", name: "Slab Orientation", keyword: "SlabOrientation", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9106)", name: "Mid Slab Position", keyword: "MidSlabPosition", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9107)", name: "MR Spatial Saturation Sequence", keyword: "MRSpatialSaturationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,9112)", name: "MR Timing and Related Parameters Sequence", keyword: "MRTimingAndRelatedParametersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9114)", name: "MR Echo Sequence", keyword: "MREchoSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9115)", name: "MR Modifier Sequence", keyword: "MRModifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9117)", name: "MR Diffusion Sequence", keyword: "MRDiffusionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9118)", name: "Cardiac Synchronization Sequence", keyword: "CardiacSynchronizationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9119)", name: "MR Averages Sequence", keyword: "MRAveragesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9125)", name: "MR FOV/Geometry Sequence", keyword: "MRFOVGeometrySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9126)", name: "Volume Localization Sequence", keyword: "VolumeLocalizationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9127)", name: "Spectroscopy Acquisition Data Columns", keyword: "SpectroscopyAcquisitionDataColumns", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9147)", name: "Diffusion Anisotropy Type", keyword: "DiffusionAnisotropyType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9151)", name: "Frame Reference DateTime", keyword: "FrameReferenceDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9152)", name: "MR Metabolite Map Sequence", keyword: "MRMetaboliteMapSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9155)", name: "Parallel Reduction Factor out-of-plane", keyword: "ParallelReductionFactorOutOfPlane", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9159)", name: "Spectroscopy Acquisition Out-of-plane Phase Steps", keyword: "SpectroscopyAcquisitionOutOfPlanePhaseSteps", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9168)", name: "Parallel Reduction Factor Second In-plane", keyword: "ParallelReductionFactorSecondInPlane", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9169)", name: "Cardiac Beat Rejection Technique", keyword: "CardiacBeatRejectionTechnique", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9170)", name: "Respiratory Motion Compensation Technique", keyword: "RespiratoryMotionCompensationTechnique", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9171)", name: "Respiratory Signal Source", keyword: "RespiratorySignalSource", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9172)", name: "Bulk Motion Compensation Technique", keyword: "BulkMotionCompensationTechnique", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9173)", name: "Bulk Motion Signal Source", keyword: "BulkMotionSignalSource", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9174)", name: "Applicable Safety Standard Agency", keyword: "ApplicableSafetyStandardAgency", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9175)", name: "Applicable Safety Standard Description", keyword: "ApplicableSafetyStandardDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9176)", name: "Operating Mode Sequence", keyword: "OperatingModeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9177)", name: "Operating Mode Type", keyword: "OperatingModeType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9178)", name: "Operating Mode", keyword: "OperatingMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9179)", name: "Specific Absorption Rate Definition", keyword: "SpecificAbsorptionRateDefinition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9180)", name: "Gradient Output Type", keyword: "GradientOutputType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9181)", name: "Specific Absorption Rate Value", keyword: "SpecificAbsorptionRateValue", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9182)", name: "Gradient Output", keyword: "GradientOutput", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9183)", name: "Flow Compensation Direction", keyword: "FlowCompensationDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9184)", name: "Tagging Delay", keyword: "TaggingDelay", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9185)", name: "Respiratory Motion Compensation Technique Description", keyword: "RespiratoryMotionCompensationTechniqueDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9186)", name: "Respiratory Signal Source ID", keyword: "RespiratorySignalSourceID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9197)", name: "MR Velocity Encoding Sequence", keyword: "MRVelocityEncodingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9198)", name: "First Order Phase Correction", keyword: "FirstOrderPhaseCorrection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9199)", name: "Water Referenced Phase Correction", keyword: "WaterReferencedPhaseCorrection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9200)", name: "MR Spectroscopy Acquisition Type", keyword: "MRSpectroscopyAcquisitionType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9214)", name: "Respiratory Cycle Position", keyword: "RespiratoryCyclePosition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9217)", name: "Velocity Encoding Maximum Value", keyword: "VelocityEncodingMaximumValue", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9218)", name: "Tag Spacing Second Dimension", keyword: "TagSpacingSecondDimension", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9219)", name: "Tag Angle Second Axis", keyword: "TagAngleSecondAxis", vr: Some(DicomVr::Ss), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9220)", name: "Frame Acquisition Duration", keyword: "FrameAcquisitionDuration", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9226)", name: "MR Image Frame Type Sequence", keyword: "MRImageFrameTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9227)", name: "MR Spectroscopy Frame Type Sequence", keyword: "MRSpectroscopyFrameTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9231)", name: "MR Acquisition Phase Encoding Steps in-plane", keyword: "MRAcquisitionPhaseEncodingStepsInPlane", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9232)", name: "MR Acquisition Phase Encoding Steps out-of-plane", keyword: "MRAcquisitionPhaseEncodingStepsOutOfPlane", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9234)", name: "Spectroscopy Acquisition Phase Columns", keyword: "SpectroscopyAcquisitionPhaseColumns", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9236)", name: "Cardiac Cycle Position", keyword: "CardiacCyclePosition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9239)", name: "Specific Absorption Rate Sequence", keyword: "SpecificAbsorptionRateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9240)", name: "RF Echo Train Length", keyword: "RFEchoTrainLength", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9241)", name: "Gradient Echo Train Length", keyword: "GradientEchoTrainLength", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9250)", name: "Arterial Spin Labeling Contrast", keyword: "ArterialSpinLabelingContrast", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9251)", name: "MR Arterial Spin Labeling Sequence", keyword: "MRArterialSpinLabelingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9252)", name: "ASL Technique Description", keyword: "ASLTechniqueDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,9253)", name: "ASL Slab Number", keyword: "ASLSlabNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9254)", name: "ASL Slab Thickness", keyword: "ASLSlabThickness", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9255)", name: "ASL Slab Orientation", keyword: "ASLSlabOrientation", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9256)", name: "ASL Mid Slab Position", keyword: "ASLMidSlabPosition", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9257)", name: "ASL Context", keyword: "ASLContext", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9258)", name: "ASL Pulse Train Duration", keyword: "ASLPulseTrainDuration", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9259)", name: "ASL Crusher Flag", keyword: "ASLCrusherFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,925A)", name: "ASL Crusher Flow Limit", keyword: "ASLCrusherFlowLimit", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,925B)", name: "ASL Crusher Description", keyword: "ASLCrusherDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,925C)", name: "ASL Bolus Cut-off Flag", keyword: "ASLBolusCutoffFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,925D)", name: "ASL Bolus Cut-off Timing Sequence", keyword: "ASLBolusCutoffTimingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,925E)", name: "ASL Bolus Cut-off Technique", keyword: "ASLBolusCutoffTechnique", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,925F)", name: "ASL Bolus Cut-off Delay Time", keyword: "ASLBolusCutoffDelayTime", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9260)", name: "ASL Slab Sequence", keyword: "ASLSlabSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9295)", name: "Chemical Shift Minimum Integration Limit in ppm", keyword: "ChemicalShiftMinimumIntegrationLimitInppm", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9296)", name: "Chemical Shift Maximum Integration Limit in ppm", keyword: "ChemicalShiftMaximumIntegrationLimitInppm", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9297)", name: "Water Reference Acquisition", keyword: "WaterReferenceAcquisition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9298)", name: "Echo Peak Position", keyword: "EchoPeakPosition", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9301)", name: "CT Acquisition Type Sequence", keyword: "CTAcquisitionTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9302)", name: "Acquisition Type", keyword: "AcquisitionType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9303)", name: "Tube Angle", keyword: "TubeAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9304)", name: "CT Acquisition Details Sequence", keyword: "CTAcquisitionDetailsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9305)", name: "Revolution Time", keyword: "RevolutionTime", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9306)", name: "Single Collimation Width", keyword: "SingleCollimationWidth", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9307)", name: "Total Collimation Width", keyword: "TotalCollimationWidth", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9308)", name: "CT Table Dynamics Sequence", keyword: "CTTableDynamicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9309)", name: "Table Speed", keyword: "TableSpeed", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9310)", name: "Table Feed per Rotation", keyword: "TableFeedPerRotation", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9311)", name: "Spiral Pitch Factor", keyword: "SpiralPitchFactor", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9312)", name: "CT Geometry Sequence", keyword: "CTGeometrySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9313)", name: "Data Collection Center (Patient)", keyword: "DataCollectionCenterPatient", vr: Some(DicomVr::Fd), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9314)", name: "CT Reconstruction Sequence", keyword: "CTReconstructionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9315)", name: "Reconstruction Algorithm", keyword: "ReconstructionAlgorithm", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9316)", name: "Convolution Kernel Group", keyword: "ConvolutionKernelGroup", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9317)", name: "Reconstruction Field of View", keyword: "ReconstructionFieldOfView", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9318)", name: "Reconstruction Target Center (Patient)", keyword: "ReconstructionTargetCenterPatient", vr: Some(DicomVr::Fd), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9319)", name: "Reconstruction Angle", keyword: "ReconstructionAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9320)", name: "Image Filter", keyword: "ImageFilter", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9321)", name: "CT Exposure Sequence", keyword: "CTExposureSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9322)", name: "Reconstruction Pixel Spacing", keyword: "ReconstructionPixelSpacing", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9323)", name: "Exposure Modulation Type", keyword: "ExposureModulationType", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9325)", name: "CT X-Ray Details Sequence", keyword: "CTXRayDetailsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9326)", name: "CT Position Sequence", keyword: "CTPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9327)", name: "Table Position", keyword: "TablePosition", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9328)", name: "Exposure Time in ms", keyword: "ExposureTimeInms", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9329)", name: "CT Image Frame Type Sequence", keyword: "CTImageFrameTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9330)", name: "X-Ray Tube Current in mA", keyword: "XRayTubeCurrentInmA", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9332)", name: "Exposure in mAs", keyword: "ExposureInmAs", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9333)", name: "Constant Volume Flag", keyword: "ConstantVolumeFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9334)", name: "Fluoroscopy Flag", keyword: "FluoroscopyFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9335)", name: "Distance Source to Data Collection Center", keyword: "DistanceSourceToDataCollectionCenter", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9337)", name: "Contrast/Bolus Agent Number", keyword: "ContrastBolusAgentNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9338)", name: "Contrast/Bolus Ingredient Code Sequence", keyword: "ContrastBolusIngredientCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,9340)", name: "Contrast Administration Profile Sequence", keyword: "ContrastAdministrationProfileSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9341)", name: "Contrast/Bolus Usage Sequence", keyword: "ContrastBolusUsageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9342)", name: "Contrast/Bolus Agent Administered", keyword: "ContrastBolusAgentAdministered", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9343)", name: "Contrast/Bolus Agent Detected", keyword: "ContrastBolusAgentDetected", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,9344)", name: "Contrast/Bolus Agent Phase", keyword: "ContrastBolusAgentPhase", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,9345)", name: "CTDIvol", keyword: "CTDIvol", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9346)", name: "CTDI Phantom Type Code Sequence", keyword: "CTDIPhantomTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9351)", name: "Calcium Scoring Mass Factor Patient", keyword: "CalciumScoringMassFactorPatient", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9352)", name: "Calcium Scoring Mass Factor Device", keyword: "CalciumScoringMassFactorDevice", vr: Some(DicomVr::Fl), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9353)", name: "Energy Weighting Factor", keyword: "EnergyWeightingFactor", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9360)", name: "CT Additional X-Ray Source Sequence", keyword: "CTAdditionalXRaySourceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9361)", name: "Multi-energy CT Acquisition", keyword: "MultienergyCTAcquisition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9362)", name: "Multi-energy CT Acquisition Sequence", keyword: "MultienergyCTAcquisitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9363)", name: "Multi-energy CT Processing Sequence", keyword: "MultienergyCTProcessingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9364)", name: "Multi-energy CT Characteristics Sequence", keyword: "MultienergyCTCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9365)", name: "Multi-energy CT X-Ray Source Sequence", keyword: "MultienergyCTXRaySourceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9366)", name: "X-Ray Source Index", keyword: "XRaySourceIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9367)", name: "X-Ray Source ID", keyword: "XRaySourceID", vr: Some(DicomVr::Uc), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9368)", name: "Multi-energy Source Technique", keyword: "MultienergySourceTechnique", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9369)", name: "Source Start DateTime", keyword: "SourceStartDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,936A)", name: "Source End DateTime", keyword: "SourceEndDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,936B)", name: "Switching Phase Number", keyword: "SwitchingPhaseNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,936C)", name: "Switching Phase Nominal Duration", keyword: "SwitchingPhaseNominalDuration", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,936D)", name: "Switching Phase Transition Duration", keyword: "SwitchingPhaseTransitionDuration", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,936E)", name: "Effective Bin Energy", keyword: "EffectiveBinEnergy", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,936F)", name: "Multi-energy CT X-Ray Detector Sequence", keyword: "MultienergyCTXRayDetectorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9370)", name: "X-Ray Detector Index", keyword: "XRayDetectorIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9371)", name: "X-Ray Detector ID", keyword: "XRayDetectorID", vr: Some(DicomVr::Uc), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9372)", name: "Multi-energy Detector Type", keyword: "MultienergyDetectorType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9373)", name: "X-Ray Detector Label", keyword: "XRayDetectorLabel", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9374)", name: "Nominal Max Energy", keyword: "NominalMaxEnergy", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9375)", name: "Nominal Min Energy", keyword: "NominalMinEnergy", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9376)", name: "Referenced X-Ray Detector Index", keyword: "ReferencedXRayDetectorIndex", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9377)", name: "Referenced X-Ray Source Index", keyword: "ReferencedXRaySourceIndex", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9378)", name: "Referenced Path Index", keyword: "ReferencedPathIndex", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9379)", name: "Multi-energy CT Path Sequence", keyword: "MultienergyCTPathSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,937A)", name: "Multi-energy CT Path Index", keyword: "MultienergyCTPathIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,937B)", name: "Multi-energy Acquisition Description", keyword: "MultienergyAcquisitionDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,937C)", name: "Monoenergetic Energy Equivalent", keyword: "MonoenergeticEnergyEquivalent", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,937D)", name: "Material Code Sequence", keyword: "MaterialCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,937E)", name: "Decomposition Method", keyword: "DecompositionMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,937F)", name: "Decomposition Description", keyword: "DecompositionDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9380)", name: "Decomposition Algorithm Identification Sequence", keyword: "DecompositionAlgorithmIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9381)", name: "Decomposition Material Sequence", keyword: "DecompositionMaterialSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9382)", name: "Material Attenuation Sequence", keyword: "MaterialAttenuationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9383)", name: "Photon Energy", keyword: "PhotonEnergy", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9384)", name: "X-Ray Mass Attenuation Coefficient", keyword: "XRayMassAttenuationCoefficient", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9401)", name: "Projection Pixel Calibration Sequence", keyword: "ProjectionPixelCalibrationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9402)", name: "Distance Source to Isocenter", keyword: "DistanceSourceToIsocenter", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9403)", name: "Distance Object to Table Top", keyword: "DistanceObjectToTableTop", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,9404)", name: "Object Pixel Spacing in Center of Beam", keyword: "ObjectPixelSpacingInCenterOfBeam", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9405)", name: "Positioner Position Sequence", keyword: "PositionerPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9406)", name: "Table Position Sequence", keyword: "TablePositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9407)", name: "Collimator Shape Sequence", keyword: "CollimatorShapeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9410)", name: "Planes in Acquisition", keyword: "PlanesInAcquisition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9412)", name: "XA/XRF Frame Characteristics Sequence", keyword: "XAXRFFrameCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9417)", name: "Frame Acquisition Sequence", keyword: "FrameAcquisitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9420)", name: "X-Ray Receptor Type", keyword: "XRayReceptorType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9423)", name: "Acquisition Protocol Name", keyword: "AcquisitionProtocolName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9424)", name: "Acquisition Protocol Description", keyword: "AcquisitionProtocolDescription", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9425)", name: "Contrast/Bolus Ingredient Opaque", keyword: "ContrastBolusIngredientOpaque", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9426)", name: "Distance Receptor Plane to Detector Housing", keyword: "DistanceReceptorPlaneToDetectorHousing", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,9427)", name: "Intensifier Active Shape", keyword: "IntensifierActiveShape", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9428)", name: "Intensifier Active Dimension(s)", keyword: "IntensifierActiveDimensions", vr: Some(DicomVr::Fl), vm: "1-2", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9429)", name: "Physical Detector Size", keyword: "PhysicalDetectorSize", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9430)", name: "Position of Isocenter Projection", keyword: "PositionOfIsocenterProjection", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9432)", name: "Field of View Sequence", keyword: "FieldOfViewSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9433)", name: "Field of View Description", keyword: "FieldOfViewDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9434)", name: "Exposure Control Sensing Regions Sequence", keyword: "ExposureControlSensingRegionsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9435)", name: "Exposure Control Sensing Region Shape", keyword: "ExposureControlSensingRegionShape", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9436)", name: "Exposure Control Sensing Region Left Vertical Edge", keyword: "ExposureControlSensingRegionLeftVerticalEdge", vr: Some(DicomVr::Ss), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9437)", name: "Exposure Control Sensing Region Right Vertical Edge", keyword: "ExposureControlSensingRegionRightVerticalEdge", vr: Some(DicomVr::Ss), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9438)", name: "Exposure Control Sensing Region Upper Horizontal Edge", keyword: "ExposureControlSensingRegionUpperHorizontalEdge", vr: Some(DicomVr::Ss), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9439)", name: "Exposure Control Sensing Region Lower Horizontal Edge", keyword: "ExposureControlSensingRegionLowerHorizontalEdge", vr: Some(DicomVr::Ss), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9440)", name: "Center of Circular Exposure Control Sensing Region", keyword: "CenterOfCircularExposureControlSensingRegion", vr: Some(DicomVr::Ss), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9441)", name: "Radius of Circular Exposure Control Sensing Region", keyword: "RadiusOfCircularExposureControlSensingRegion", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9442)", name: "Vertices of the Polygonal Exposure Control Sensing Region", keyword: "VerticesOfThePolygonalExposureControlSensingRegion", vr: Some(DicomVr::Ss), vm: "2-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9447)", name: "Column Angulation (Patient)", keyword: "ColumnAngulationPatient", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9449)", name: "Beam Angle", keyword: "BeamAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9451)", name: "Frame Detector Parameters Sequence", keyword: "FrameDetectorParametersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9452)", name: "Calculated Anatomy Thickness", keyword: "CalculatedAnatomyThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9455)", name: "Calibration Sequence", keyword: "CalibrationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9456)", name: "Object Thickness Sequence", keyword: "ObjectThicknessSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9457)", name: "Plane Identification", keyword: "PlaneIdentification", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9461)", name: "Field of View Dimension(s) in Float", keyword: "FieldOfViewDimensionsInFloat", vr: Some(DicomVr::Fl), vm: "1-2", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9462)", name: "Isocenter Reference System Sequence", keyword: "IsocenterReferenceSystemSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9463)", name: "Positioner Isocenter Primary Angle", keyword: "PositionerIsocenterPrimaryAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9464)", name: "Positioner Isocenter Secondary Angle", keyword: "PositionerIsocenterSecondaryAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9465)", name: "Positioner Isocenter Detector Rotation Angle", keyword: "PositionerIsocenterDetectorRotationAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9466)", name: "Table X Position to Isocenter", keyword: "TableXPositionToIsocenter", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9467)", name: "Table Y Position to Isocenter", keyword: "TableYPositionToIsocenter", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9468)", name: "Table Z Position to Isocenter", keyword: "TableZPositionToIsocenter", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9469)", name: "Table Horizontal Rotation Angle", keyword: "TableHorizontalRotationAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9470)", name: "Table Head Tilt Angle", keyword: "TableHeadTiltAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9471)", name: "Table Cradle Tilt Angle", keyword: "TableCradleTiltAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9472)", name: "Frame Display Shutter Sequence", keyword: "FrameDisplayShutterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9473)", name: "Acquired Image Area Dose Product", keyword: "AcquiredImageAreaDoseProduct", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,9474)", name: "C-arm Positioner Tabletop Relationship", keyword: "CArmPositionerTabletopRelationship", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9476)", name: "X-Ray Geometry Sequence", keyword: "XRayGeometrySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9477)", name: "Irradiation Event Identification Sequence", keyword: "IrradiationEventIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9504)", name: "X-Ray 3D Frame Type Sequence", keyword: "XRay3DFrameTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9506)", name: "Contributing Sources Sequence", keyword: "ContributingSourcesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9507)", name: "X-Ray 3D Acquisition Sequence", keyword: "XRay3DAcquisitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9508)", name: "Primary Positioner Scan Arc", keyword: "PrimaryPositionerScanArc", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9509)", name: "Secondary Positioner Scan Arc", keyword: "SecondaryPositionerScanArc", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9510)", name: "Primary Positioner Scan Start Angle", keyword: "PrimaryPositionerScanStartAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9511)", name: "Secondary Positioner Scan Start Angle", keyword: "SecondaryPositionerScanStartAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9514)", name: "Primary Positioner Increment", keyword: "PrimaryPositionerIncrement", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9515)", name: "Secondary Positioner Increment", keyword: "SecondaryPositionerIncrement", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9516)", name: "Start Acquisition DateTime", keyword: "StartAcquisitionDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9517)", name: "End Acquisition DateTime", keyword: "EndAcquisitionDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9518)", name: "Primary Positioner Increment Sign", keyword: "PrimaryPositionerIncrementSign", vr: Some(DicomVr::Ss), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9519)", name: "Secondary Positioner Increment Sign", keyword: "SecondaryPositionerIncrementSign", vr: Some(DicomVr::Ss), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9524)", name: "Application Name", keyword: "ApplicationName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9525)", name: "Application Version", keyword: "ApplicationVersion", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9526)", name: "Application Manufacturer", keyword: "ApplicationManufacturer", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9527)", name: "Algorithm Type", keyword: "AlgorithmType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9528)", name: "Algorithm Description", keyword: "AlgorithmDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9530)", name: "X-Ray 3D Reconstruction Sequence", keyword: "XRay3DReconstructionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9531)", name: "Reconstruction Description", keyword: "ReconstructionDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9538)", name: "Per Projection Acquisition Sequence", keyword: "PerProjectionAcquisitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9541)", name: "Detector Position Sequence", keyword: "DetectorPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9542)", name: "X-Ray Acquisition Dose Sequence", keyword: "XRayAcquisitionDoseSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9543)", name: "X-Ray Source Isocenter Primary Angle", keyword: "XRaySourceIsocenterPrimaryAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9544)", name: "X-Ray Source Isocenter Secondary Angle", keyword: "XRaySourceIsocenterSecondaryAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9545)", name: "Breast Support Isocenter Primary Angle", keyword: "BreastSupportIsocenterPrimaryAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9546)", name: "Breast Support Isocenter Secondary Angle", keyword: "BreastSupportIsocenterSecondaryAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9547)", name: "Breast Support X Position to Isocenter", keyword: "BreastSupportXPositionToIsocenter", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9548)", name: "Breast Support Y Position to Isocenter", keyword: "BreastSupportYPositionToIsocenter", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9549)", name: "Breast Support Z Position to Isocenter", keyword: "BreastSupportZPositionToIsocenter", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9550)", name: "Detector Isocenter Primary Angle", keyword: "DetectorIsocenterPrimaryAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9551)", name: "Detector Isocenter Secondary Angle", keyword: "DetectorIsocenterSecondaryAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9552)", name: "Detector X Position to Isocenter", keyword: "DetectorXPositionToIsocenter", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9553)", name: "Detector Y Position to Isocenter", keyword: "DetectorYPositionToIsocenter", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9554)", name: "Detector Z Position to Isocenter", keyword: "DetectorZPositionToIsocenter", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9555)", name: "X-Ray Grid Sequence", keyword: "XRayGridSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9556)", name: "X-Ray Filter Sequence", keyword: "XRayFilterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9557)", name: "Detector Active Area TLHC Position", keyword: "DetectorActiveAreaTLHCPosition", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9558)", name: "Detector Active Area Orientation", keyword: "DetectorActiveAreaOrientation", vr: Some(DicomVr::Fd), vm: "6", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9559)", name: "Positioner Primary Angle Direction", keyword: "PositionerPrimaryAngleDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9601)", name: "Diffusion b-matrix Sequence", keyword: "DiffusionBMatrixSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9602)", name: "Diffusion b-value XX", keyword: "DiffusionBValueXX", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9603)", name: "Diffusion b-value XY", keyword: "DiffusionBValueXY", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9604)", name: "Diffusion b-value XZ", keyword: "DiffusionBValueXZ", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9605)", name: "Diffusion b-value YY", keyword: "DiffusionBValueYY", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9606)", name: "Diffusion b-value YZ", keyword: "DiffusionBValueYZ", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9607)", name: "Diffusion b-value ZZ", keyword: "DiffusionBValueZZ", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9621)", name: "Functional MR Sequence", keyword: "FunctionalMRSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9622)", name: "Functional Settling Phase Frames Present", keyword: "FunctionalSettlingPhaseFramesPresent", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9623)", name: "Functional Sync Pulse", keyword: "FunctionalSyncPulse", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9624)", name: "Settling Phase Frame", keyword: "SettlingPhaseFrame", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9701)", name: "Decay Correction DateTime", keyword: "DecayCorrectionDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9715)", name: "Start Density Threshold", keyword: "StartDensityThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9716)", name: "Start Relative Density Difference Threshold", keyword: "StartRelativeDensityDifferenceThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9717)", name: "Start Cardiac Trigger Count Threshold", keyword: "StartCardiacTriggerCountThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9718)", name: "Start Respiratory Trigger Count Threshold", keyword: "StartRespiratoryTriggerCountThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9719)", name: "Termination Counts Threshold", keyword: "TerminationCountsThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9720)", name: "Termination Density Threshold", keyword: "TerminationDensityThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9721)", name: "Termination Relative Density Threshold", keyword: "TerminationRelativeDensityThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9722)", name: "Termination Time Threshold", keyword: "TerminationTimeThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9723)", name: "Termination Cardiac Trigger Count Threshold", keyword: "TerminationCardiacTriggerCountThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9724)", name: "Termination Respiratory Trigger Count Threshold", keyword: "TerminationRespiratoryTriggerCountThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9725)", name: "Detector Geometry", keyword: "DetectorGeometry", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9726)", name: "Transverse Detector Separation", keyword: "TransverseDetectorSeparation", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9727)", name: "Axial Detector Dimension", keyword: "AxialDetectorDimension", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9729)", name: "Radiopharmaceutical Agent Number", keyword: "RadiopharmaceuticalAgentNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9732)", name: "PET Frame Acquisition Sequence", keyword: "PETFrameAcquisitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9733)", name: "PET Detector Motion Details Sequence", keyword: "PETDetectorMotionDetailsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9734)", name: "PET Table Dynamics Sequence", keyword: "PETTableDynamicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9735)", name: "PET Position Sequence", keyword: "PETPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9736)", name: "PET Frame Correction Factors Sequence", keyword: "PETFrameCorrectionFactorsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9737)", name: "Radiopharmaceutical Usage Sequence", keyword: "RadiopharmaceuticalUsageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9738)", name: "Attenuation Correction Source", keyword: "AttenuationCorrectionSource", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9739)", name: "Number of Iterations", keyword: "NumberOfIterations", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9740)", name: "Number of Subsets", keyword: "NumberOfSubsets", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9749)", name: "PET Reconstruction Sequence", keyword: "PETReconstructionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9751)", name: "PET Frame Type Sequence", keyword: "PETFrameTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9755)", name: "Time of Flight Information Used", keyword: "TimeOfFlightInformationUsed", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9756)", name: "Reconstruction Type", keyword: "ReconstructionType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9758)", name: "Decay Corrected", keyword: "DecayCorrected", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9759)", name: "Attenuation Corrected", keyword: "AttenuationCorrected", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9760)", name: "Scatter Corrected", keyword: "ScatterCorrected", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9761)", name: "Dead Time Corrected", keyword: "DeadTimeCorrected", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9762)", name: "Gantry Motion Corrected", keyword: "GantryMotionCorrected", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9763)", name: "Patient Motion Corrected", keyword: "PatientMotionCorrected", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9764)", name: "Count Loss Normalization Corrected", keyword: "CountLossNormalizationCorrected", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9765)", name: "Randoms Corrected", keyword: "RandomsCorrected", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9766)", name: "Non-uniform Radial Sampling Corrected", keyword: "NonUniformRadialSamplingCorrected", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9767)", name: "Sensitivity Calibrated", keyword: "SensitivityCalibrated", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9768)", name: "Detector Normalization Correction", keyword: "DetectorNormalizationCorrection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9769)", name: "Iterative Reconstruction Method", keyword: "IterativeReconstructionMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9770)", name: "Attenuation Correction Temporal Relationship", keyword: "AttenuationCorrectionTemporalRelationship", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9771)", name: "Patient Physiological State Sequence", keyword: "PatientPhysiologicalStateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9772)", name: "Patient Physiological State Code Sequence", keyword: "PatientPhysiologicalStateCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9801)", name: "Depth(s) of Focus", keyword: "DepthsOfFocus", vr: Some(DicomVr::Fd), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9803)", name: "Excluded Intervals Sequence", keyword: "ExcludedIntervalsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9804)", name: "Exclusion Start DateTime", keyword: "ExclusionStartDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9805)", name: "Exclusion Duration", keyword: "ExclusionDuration", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9806)", name: "US Image Description Sequence", keyword: "USImageDescriptionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9807)", name: "Image Data Type Sequence", keyword: "ImageDataTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9808)", name: "Data Type", keyword: "DataType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9809)", name: "Transducer Scan Pattern Code Sequence", keyword: "TransducerScanPatternCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,980B)", name: "Aliased Data Type", keyword: "AliasedDataType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,980C)", name: "Position Measuring Device Used", keyword: "PositionMeasuringDeviceUsed", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,980D)", name: "Transducer Geometry Code Sequence", keyword: "TransducerGeometryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,980E)", name: "Transducer Beam Steering Code Sequence", keyword: "TransducerBeamSteeringCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,980F)", name: "Transducer Application Code Sequence", keyword: "TransducerApplicationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9810)", name: "Zero Velocity Pixel Value", keyword: "ZeroVelocityPixelValue", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9821)", name: "Photoacoustic Excitation Characteristics Sequence", keyword: "PhotoacousticExcitationCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9822)", name: "Excitation Spectral Width", keyword: "ExcitationSpectralWidth", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9823)", name: "Excitation Energy", keyword: "ExcitationEnergy", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9824)", name: "Excitation Pulse Duration", keyword: "ExcitationPulseDuration", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9825)", name: "Excitation Wavelength Sequence", keyword: "ExcitationWavelengthSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9826)", name: "Excitation Wavelength", keyword: "ExcitationWavelength", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9828)", name: "Illumination Translation Flag", keyword: "IlluminationTranslationFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9829)", name: "Acoustic Coupling Medium Flag", keyword: "AcousticCouplingMediumFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,982A)", name: "Acoustic Coupling Medium Code Sequence", keyword: "AcousticCouplingMediumCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,982B)", name: "Acoustic Coupling Medium Temperature", keyword: "AcousticCouplingMediumTemperature", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,982C)", name: "Transducer Response Sequence", keyword: "TransducerResponseSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,982D)", name: "Center Frequency", keyword: "CenterFrequency", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,982E)", name: "Fractional Bandwidth", keyword: "FractionalBandwidth", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,982F)", name: "Lower Cutoff Frequency", keyword: "LowerCutoffFrequency", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9830)", name: "Upper Cutoff Frequency", keyword: "UpperCutoffFrequency", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9831)", name: "Transducer Technology Sequence", keyword: "TransducerTechnologySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9832)", name: "Sound Speed Correction Mechanism Code Sequence", keyword: "SoundSpeedCorrectionMechanismCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9833)", name: "Object Sound Speed", keyword: "ObjectSoundSpeed", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9834)", name: "Acoustic Coupling Medium Sound Speed", keyword: "AcousticCouplingMediumSoundSpeed", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9835)", name: "Photoacoustic Image Frame Type Sequence", keyword: "PhotoacousticImageFrameTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9836)", name: "Image Data Type Code Sequence", keyword: "ImageDataTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9900)", name: "Reference Location Label", keyword: "ReferenceLocationLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9901)", name: "Reference Location Description", keyword: "ReferenceLocationDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9902)", name: "Reference Basis Code Sequence", keyword: "ReferenceBasisCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9903)", name: "Reference Geometry Code Sequence", keyword: "ReferenceGeometryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9904)", name: "Offset Distance", keyword: "OffsetDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9905)", name: "Offset Direction", keyword: "OffsetDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,9906)", name: "Potential Scheduled Protocol Code Sequence", keyword: "PotentialScheduledProtocolCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9907)", name: "Potential Requested Procedure Code Sequence", keyword: "PotentialRequestedProcedureCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9908)", name: "Potential Reasons for Procedure", keyword: "PotentialReasonsForProcedure", vr: Some(DicomVr::Uc), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9909)", name: "Potential Reasons for Procedure Code Sequence", keyword: "PotentialReasonsForProcedureCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,990A)", name: "Potential Diagnostic Tasks", keyword: "PotentialDiagnosticTasks", vr: Some(DicomVr::Uc), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,990B)", name: "Contraindications Code Sequence", keyword: "ContraindicationsCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,990C)", name: "Referenced Defined Protocol Sequence", keyword: "ReferencedDefinedProtocolSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,990D)", name: "Referenced Performed Protocol Sequence", keyword: "ReferencedPerformedProtocolSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0018,990E)", name: "Predecessor Protocol Sequence", keyword: "PredecessorProtocolSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,990F)", name: "Protocol Planning Information", keyword: "ProtocolPlanningInformation", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9910)", name: "Protocol Design Rationale", keyword: "ProtocolDesignRationale", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9911)", name: "Patient Specification Sequence", keyword: "PatientSpecificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9912)", name: "Model Specification Sequence", keyword: "ModelSpecificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9913)", name: "Parameters Specification Sequence", keyword: "ParametersSpecificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9914)", name: "Instruction Sequence", keyword: "InstructionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9915)", name: "Instruction Index", keyword: "InstructionIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9916)", name: "Instruction Text", keyword: "InstructionText", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9917)", name: "Instruction Description", keyword: "InstructionDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9918)", name: "Instruction Performed Flag", keyword: "InstructionPerformedFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,9919)", name: "Instruction Performed DateTime", keyword: "InstructionPerformedDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0018,991A)", name: "Instruction Performance Comment", keyword: "InstructionPerformanceComment", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,991B)", name: "Patient Positioning Instruction Sequence", keyword: "PatientPositioningInstructionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,991C)", name: "Positioning Method Code Sequence", keyword: "PositioningMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,991D)", name: "Positioning Landmark Sequence", keyword: "PositioningLandmarkSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,991E)", name: "Target Frame of Reference UID", keyword: "TargetFrameOfReferenceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,991F)", name: "Acquisition Protocol Element Specification Sequence", keyword: "AcquisitionProtocolElementSpecificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9920)", name: "Acquisition Protocol Element Sequence", keyword: "AcquisitionProtocolElementSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,9921)", name: "Protocol Element Number", keyword: "ProtocolElementNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9922)", name: "Protocol Element Name", keyword: "ProtocolElementName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0018,9923)", name: "Protocol Element Characteristics Summary", keyword: "ProtocolElementCharacteristicsSummary", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9924)", name: "Protocol Element Purpose", keyword: "ProtocolElementPurpose", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9930)", name: "Acquisition Motion", keyword: "AcquisitionMotion", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9931)", name: "Acquisition Start Location Sequence", keyword: "AcquisitionStartLocationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9932)", name: "Acquisition End Location Sequence", keyword: "AcquisitionEndLocationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9933)", name: "Reconstruction Protocol Element Specification Sequence", keyword: "ReconstructionProtocolElementSpecificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9934)", name: "Reconstruction Protocol Element Sequence", keyword: "ReconstructionProtocolElementSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9935)", name: "Storage Protocol Element Specification Sequence", keyword: "StorageProtocolElementSpecificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9936)", name: "Storage Protocol Element Sequence", keyword: "StorageProtocolElementSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9937)", name: "Requested Series Description", keyword: "RequestedSeriesDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9938)", name: "Source Acquisition Protocol Element Number", keyword: "SourceAcquisitionProtocolElementNumber", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9939)", name: "Source Acquisition Beam Number", keyword: "SourceAcquisitionBeamNumber", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0018,993A)", name: "Source Reconstruction Protocol Element Number", keyword: "SourceReconstructionProtocolElementNumber", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0018,993B)", name: "Reconstruction Start Location Sequence", keyword: "ReconstructionStartLocationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,993C)", name: "Reconstruction End Location Sequence", keyword: "ReconstructionEndLocationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,993D)", name: "Reconstruction Algorithm Sequence", keyword: "ReconstructionAlgorithmSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,993E)", name: "Reconstruction Target Center Location Sequence", keyword: "ReconstructionTargetCenterLocationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9941)", name: "Image Filter Description", keyword: "ImageFilterDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9942)", name: "CTDIvol Notification Trigger", keyword: "CTDIvolNotificationTrigger", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9943)", name: "DLP Notification Trigger", keyword: "DLPNotificationTrigger", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9944)", name: "Auto KVP Selection Type", keyword: "AutoKVPSelectionType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,9945)", name: "Auto KVP Upper Bound", keyword: "AutoKVPUpperBound", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9946)", name: "Auto KVP Lower Bound", keyword: "AutoKVPLowerBound", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,9947)", name: "Protocol Defined Patient Position", keyword: "ProtocolDefinedPatientPosition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0018,A001)", name: "Contributing Equipment Sequence", keyword: "ContributingEquipmentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,A002)", name: "Contribution DateTime", keyword: "ContributionDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0018,A003)", name: "Contribution Description", keyword: "ContributionDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,000D)", name: "Study Instance UID", keyword: "StudyInstanceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,000E)", name: "Series Instance UID", keyword: "SeriesInstanceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,0010)", name: "Study ID", keyword: "StudyID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0020,0011)", name: "Series Number", keyword: "SeriesNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0020,0012)", name: "Acquisition Number", keyword: "AcquisitionNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,0013)", name: "Instance Number", keyword: "InstanceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0020,0020)", name: "Patient Orientation", keyword: "PatientOrientation", vr: Some(DicomVr::Cs), vm: "2", attr_type: "2C" },
-    DicomAttribute { tag: "(0020,0027)", name: "Pyramid Label", keyword: "PyramidLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,0032)", name: "Image Position (Patient)", keyword: "ImagePositionPatient", vr: Some(DicomVr::Ds), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0020,0037)", name: "Image Orientation (Patient)", keyword: "ImageOrientationPatient", vr: Some(DicomVr::Ds), vm: "6", attr_type: "1" },
-    DicomAttribute { tag: "(0020,0052)", name: "Frame of Reference UID", keyword: "FrameOfReferenceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,0060)", name: "Laterality", keyword: "Laterality", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0020,0062)", name: "Image Laterality", keyword: "ImageLaterality", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,0100)", name: "Temporal Position Identifier", keyword: "TemporalPositionIdentifier", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,0105)", name: "Number of Temporal Positions", keyword: "NumberOfTemporalPositions", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,0110)", name: "Temporal Resolution", keyword: "TemporalResolution", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,0200)", name: "Synchronization Frame of Reference UID", keyword: "SynchronizationFrameOfReferenceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,0242)", name: "SOP Instance UID of Concatenation Source", keyword: "SOPInstanceUIDOfConcatenationSource", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,1002)", name: "Images in Acquisition", keyword: "ImagesInAcquisition", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,103F)", name: "Target Position Reference Indicator", keyword: "TargetPositionReferenceIndicator", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,1040)", name: "Position Reference Indicator", keyword: "PositionReferenceIndicator", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0020,1041)", name: "Slice Location", keyword: "SliceLocation", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,1206)", name: "Number of Study Related Series", keyword: "NumberOfStudyRelatedSeries", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0020,1208)", name: "Number of Study Related Instances", keyword: "NumberOfStudyRelatedInstances", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0020,4000)", name: "Image Comments", keyword: "ImageComments", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,9056)", name: "Stack ID", keyword: "StackID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9057)", name: "In-Stack Position Number", keyword: "InStackPositionNumber", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9071)", name: "Frame Anatomy Sequence", keyword: "FrameAnatomySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9072)", name: "Frame Laterality", keyword: "FrameLaterality", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9111)", name: "Frame Content Sequence", keyword: "FrameContentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9113)", name: "Plane Position Sequence", keyword: "PlanePositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9116)", name: "Plane Orientation Sequence", keyword: "PlaneOrientationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9128)", name: "Temporal Position Index", keyword: "TemporalPositionIndex", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9153)", name: "Nominal Cardiac Trigger Delay Time", keyword: "NominalCardiacTriggerDelayTime", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9154)", name: "Nominal Cardiac Trigger Time Prior To R-Peak", keyword: "NominalCardiacTriggerTimePriorToRPeak", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,9155)", name: "Actual Cardiac Trigger Time Prior To R-Peak", keyword: "ActualCardiacTriggerTimePriorToRPeak", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,9156)", name: "Frame Acquisition Number", keyword: "FrameAcquisitionNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,9157)", name: "Dimension Index Values", keyword: "DimensionIndexValues", vr: Some(DicomVr::Ul), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9158)", name: "Frame Comments", keyword: "FrameComments", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,9161)", name: "Concatenation UID", keyword: "ConcatenationUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9162)", name: "In-concatenation Number", keyword: "InConcatenationNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9163)", name: "In-concatenation Total Number", keyword: "InConcatenationTotalNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,9164)", name: "Dimension Organization UID", keyword: "DimensionOrganizationUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9165)", name: "Dimension Index Pointer", keyword: "DimensionIndexPointer", vr: Some(DicomVr::At), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9167)", name: "Functional Group Pointer", keyword: "FunctionalGroupPointer", vr: Some(DicomVr::At), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9170)", name: "Unassigned Shared Converted Attributes Sequence", keyword: "UnassignedSharedConvertedAttributesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9171)", name: "Unassigned Per-Frame Converted Attributes Sequence", keyword: "UnassignedPerFrameConvertedAttributesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9172)", name: "Conversion Source Attributes Sequence", keyword: "ConversionSourceAttributesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9213)", name: "Dimension Index Private Creator", keyword: "DimensionIndexPrivateCreator", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9221)", name: "Dimension Organization Sequence", keyword: "DimensionOrganizationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9222)", name: "Dimension Index Sequence", keyword: "DimensionIndexSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9228)", name: "Concatenation Frame Offset Number", keyword: "ConcatenationFrameOffsetNumber", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9238)", name: "Functional Group Private Creator", keyword: "FunctionalGroupPrivateCreator", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9241)", name: "Nominal Percentage of Cardiac Phase", keyword: "NominalPercentageOfCardiacPhase", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9245)", name: "Nominal Percentage of Respiratory Phase", keyword: "NominalPercentageOfRespiratoryPhase", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9246)", name: "Starting Respiratory Amplitude", keyword: "StartingRespiratoryAmplitude", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9247)", name: "Starting Respiratory Phase", keyword: "StartingRespiratoryPhase", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9248)", name: "Ending Respiratory Amplitude", keyword: "EndingRespiratoryAmplitude", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9249)", name: "Ending Respiratory Phase", keyword: "EndingRespiratoryPhase", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9250)", name: "Respiratory Trigger Type", keyword: "RespiratoryTriggerType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9251)", name: "R-R Interval Time Nominal", keyword: "RRIntervalTimeNominal", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9252)", name: "Actual Cardiac Trigger Delay Time", keyword: "ActualCardiacTriggerDelayTime", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9253)", name: "Respiratory Synchronization Sequence", keyword: "RespiratorySynchronizationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9254)", name: "Respiratory Interval Time", keyword: "RespiratoryIntervalTime", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9255)", name: "Nominal Respiratory Trigger Delay Time", keyword: "NominalRespiratoryTriggerDelayTime", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9256)", name: "Respiratory Trigger Delay Threshold", keyword: "RespiratoryTriggerDelayThreshold", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9257)", name: "Actual Respiratory Trigger Delay Time", keyword: "ActualRespiratoryTriggerDelayTime", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9301)", name: "Image Position (Volume)", keyword: "ImagePositionVolume", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9302)", name: "Image Orientation (Volume)", keyword: "ImageOrientationVolume", vr: Some(DicomVr::Fd), vm: "6", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9307)", name: "Ultrasound Acquisition Geometry", keyword: "UltrasoundAcquisitionGeometry", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9308)", name: "Apex Position", keyword: "ApexPosition", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9309)", name: "Volume to Transducer Mapping Matrix", keyword: "VolumeToTransducerMappingMatrix", vr: Some(DicomVr::Fd), vm: "16", attr_type: "1" },
-    DicomAttribute { tag: "(0020,930A)", name: "Volume to Table Mapping Matrix", keyword: "VolumeToTableMappingMatrix", vr: Some(DicomVr::Fd), vm: "16", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,930B)", name: "Volume to Transducer Relationship", keyword: "VolumeToTransducerRelationship", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,930C)", name: "Patient Frame of Reference Source", keyword: "PatientFrameOfReferenceSource", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,930D)", name: "Temporal Position Time Offset", keyword: "TemporalPositionTimeOffset", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,930E)", name: "Plane Position (Volume) Sequence", keyword: "PlanePositionVolumeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,930F)", name: "Plane Orientation (Volume) Sequence", keyword: "PlaneOrientationVolumeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9310)", name: "Temporal Position Sequence", keyword: "TemporalPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9311)", name: "Dimension Organization Type", keyword: "DimensionOrganizationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,9312)", name: "Volume Frame of Reference UID", keyword: "VolumeFrameOfReferenceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9313)", name: "Table Frame of Reference UID", keyword: "TableFrameOfReferenceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9421)", name: "Dimension Description Label", keyword: "DimensionDescriptionLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,9450)", name: "Patient Orientation in Frame Sequence", keyword: "PatientOrientationInFrameSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9453)", name: "Frame Label", keyword: "FrameLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0020,9518)", name: "Acquisition Index", keyword: "AcquisitionIndex", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0020,9529)", name: "Contributing SOP Instances Reference Sequence", keyword: "ContributingSOPInstancesReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0020,9536)", name: "Reconstruction Index", keyword: "ReconstructionIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0001)", name: "Light Path Filter Pass-Through Wavelength", keyword: "LightPathFilterPassThroughWavelength", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0002)", name: "Light Path Filter Pass Band", keyword: "LightPathFilterPassBand", vr: Some(DicomVr::Us), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0003)", name: "Image Path Filter Pass-Through Wavelength", keyword: "ImagePathFilterPassThroughWavelength", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0004)", name: "Image Path Filter Pass Band", keyword: "ImagePathFilterPassBand", vr: Some(DicomVr::Us), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0005)", name: "Patient Eye Movement Commanded", keyword: "PatientEyeMovementCommanded", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,0006)", name: "Patient Eye Movement Command Code Sequence", keyword: "PatientEyeMovementCommandCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0007)", name: "Spherical Lens Power", keyword: "SphericalLensPower", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,0008)", name: "Cylinder Lens Power", keyword: "CylinderLensPower", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,0009)", name: "Cylinder Axis", keyword: "CylinderAxis", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,000A)", name: "Emmetropic Magnification", keyword: "EmmetropicMagnification", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,000B)", name: "Intra Ocular Pressure", keyword: "IntraOcularPressure", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,000C)", name: "Horizontal Field of View", keyword: "HorizontalFieldOfView", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,000D)", name: "Pupil Dilated", keyword: "PupilDilated", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,000E)", name: "Degree of Dilation", keyword: "DegreeOfDilation", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0022,000F)", name: "Vertex Distance", keyword: "VertexDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0010)", name: "Stereo Baseline Angle", keyword: "StereoBaselineAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0011)", name: "Stereo Baseline Displacement", keyword: "StereoBaselineDisplacement", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0012)", name: "Stereo Horizontal Pixel Offset", keyword: "StereoHorizontalPixelOffset", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0013)", name: "Stereo Vertical Pixel Offset", keyword: "StereoVerticalPixelOffset", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0014)", name: "Stereo Rotation", keyword: "StereoRotation", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0015)", name: "Acquisition Device Type Code Sequence", keyword: "AcquisitionDeviceTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,0016)", name: "Illumination Type Code Sequence", keyword: "IlluminationTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,0017)", name: "Light Path Filter Type Stack Code Sequence", keyword: "LightPathFilterTypeStackCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0018)", name: "Image Path Filter Type Stack Code Sequence", keyword: "ImagePathFilterTypeStackCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0019)", name: "Lenses Code Sequence", keyword: "LensesCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,001A)", name: "Channel Description Code Sequence", keyword: "ChannelDescriptionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,001B)", name: "Refractive State Sequence", keyword: "RefractiveStateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,001C)", name: "Mydriatic Agent Code Sequence", keyword: "MydriaticAgentCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,001D)", name: "Relative Image Position Code Sequence", keyword: "RelativeImagePositionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0022,001E)", name: "Camera Angle of View", keyword: "CameraAngleOfView", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0020)", name: "Stereo Pairs Sequence", keyword: "StereoPairsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,0021)", name: "Left Image Sequence", keyword: "LeftImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,0022)", name: "Right Image Sequence", keyword: "RightImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,0028)", name: "Stereo Pairs Present", keyword: "StereoPairsPresent", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0030)", name: "Axial Length of the Eye", keyword: "AxialLengthOfTheEye", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,0031)", name: "Ophthalmic Frame Location Sequence", keyword: "OphthalmicFrameLocationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,0032)", name: "Reference Coordinates", keyword: "ReferenceCoordinates", vr: Some(DicomVr::Fl), vm: "2-2n", attr_type: "1" },
-    DicomAttribute { tag: "(0022,0035)", name: "Depth Spatial Resolution", keyword: "DepthSpatialResolution", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0036)", name: "Maximum Depth Distortion", keyword: "MaximumDepthDistortion", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0037)", name: "Along-scan Spatial Resolution", keyword: "AlongScanSpatialResolution", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0038)", name: "Maximum Along-scan Distortion", keyword: "MaximumAlongScanDistortion", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0039)", name: "Ophthalmic Image Orientation", keyword: "OphthalmicImageOrientation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,0041)", name: "Depth of Transverse Image", keyword: "DepthOfTransverseImage", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0022,0042)", name: "Mydriatic Agent Concentration Units Sequence", keyword: "MydriaticAgentConcentrationUnitsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0048)", name: "Across-scan Spatial Resolution", keyword: "AcrossScanSpatialResolution", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0049)", name: "Maximum Across-scan Distortion", keyword: "MaximumAcrossScanDistortion", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,004E)", name: "Mydriatic Agent Concentration", keyword: "MydriaticAgentConcentration", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,0055)", name: "Illumination Wave Length", keyword: "IlluminationWaveLength", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0056)", name: "Illumination Power", keyword: "IlluminationPower", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0057)", name: "Illumination Bandwidth", keyword: "IlluminationBandwidth", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,0058)", name: "Mydriatic Agent Sequence", keyword: "MydriaticAgentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0022,1007)", name: "Ophthalmic Axial Measurements Right Eye Sequence", keyword: "OphthalmicAxialMeasurementsRightEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1008)", name: "Ophthalmic Axial Measurements Left Eye Sequence", keyword: "OphthalmicAxialMeasurementsLeftEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1009)", name: "Ophthalmic Axial Measurements Device Type", keyword: "OphthalmicAxialMeasurementsDeviceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1010)", name: "Ophthalmic Axial Length Measurements Type", keyword: "OphthalmicAxialLengthMeasurementsType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1012)", name: "Ophthalmic Axial Length Sequence", keyword: "OphthalmicAxialLengthSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1019)", name: "Ophthalmic Axial Length", keyword: "OphthalmicAxialLength", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1024)", name: "Lens Status Code Sequence", keyword: "LensStatusCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1025)", name: "Vitreous Status Code Sequence", keyword: "VitreousStatusCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1028)", name: "IOL Formula Code Sequence", keyword: "IOLFormulaCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1029)", name: "IOL Formula Detail", keyword: "IOLFormulaDetail", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1033)", name: "Keratometer Index", keyword: "KeratometerIndex", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,1035)", name: "Source of Ophthalmic Axial Length Code Sequence", keyword: "SourceOfOphthalmicAxialLengthCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1036)", name: "Source of Corneal Size Data Code Sequence", keyword: "SourceOfCornealSizeDataCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1037)", name: "Target Refraction", keyword: "TargetRefraction", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1039)", name: "Refractive Procedure Occurred", keyword: "RefractiveProcedureOccurred", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,1040)", name: "Refractive Surgery Type Code Sequence", keyword: "RefractiveSurgeryTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0022,1044)", name: "Ophthalmic Ultrasound Method Code Sequence", keyword: "OphthalmicUltrasoundMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1045)", name: "Surgically Induced Astigmatism Sequence", keyword: "SurgicallyInducedAstigmatismSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1046)", name: "Type of Optical Correction", keyword: "TypeOfOpticalCorrection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1047)", name: "Toric IOL Power Sequence", keyword: "ToricIOLPowerSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1048)", name: "Predicted Toric Error Sequence", keyword: "PredictedToricErrorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1049)", name: "Pre-Selected for Implantation", keyword: "PreSelectedForImplantation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,104A)", name: "Toric IOL Power for Exact Emmetropia Sequence", keyword: "ToricIOLPowerForExactEmmetropiaSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0022,104B)", name: "Toric IOL Power for Exact Target Refraction Sequence", keyword: "ToricIOLPowerForExactTargetRefractionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0022,1050)", name: "Ophthalmic Axial Length Measurements Sequence", keyword: "OphthalmicAxialLengthMeasurementsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1053)", name: "IOL Power", keyword: "IOLPower", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1054)", name: "Predicted Refractive Error", keyword: "PredictedRefractiveError", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1059)", name: "Ophthalmic Axial Length Velocity", keyword: "OphthalmicAxialLengthVelocity", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1065)", name: "Lens Status Description", keyword: "LensStatusDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1066)", name: "Vitreous Status Description", keyword: "VitreousStatusDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1090)", name: "IOL Power Sequence", keyword: "IOLPowerSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1092)", name: "Lens Constant Sequence", keyword: "LensConstantSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1093)", name: "IOL Manufacturer", keyword: "IOLManufacturer", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1095)", name: "Implant Name", keyword: "ImplantName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1096)", name: "Keratometry Measurement Type Code Sequence", keyword: "KeratometryMeasurementTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,1097)", name: "Implant Part Number", keyword: "ImplantPartNumber", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,1101)", name: "Ophthalmic Axial Length Measurements Segment Name Code Sequence", keyword: "OphthalmicAxialLengthMeasurementsSegmentNameCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1103)", name: "Refractive Error Before Refractive Surgery Code Sequence", keyword: "RefractiveErrorBeforeRefractiveSurgeryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0022,1121)", name: "IOL Power For Exact Emmetropia", keyword: "IOLPowerForExactEmmetropia", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,1122)", name: "IOL Power For Exact Target Refraction", keyword: "IOLPowerForExactTargetRefraction", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0022,1125)", name: "Anterior Chamber Depth Definition Code Sequence", keyword: "AnteriorChamberDepthDefinitionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1127)", name: "Lens Thickness Sequence", keyword: "LensThicknessSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1128)", name: "Anterior Chamber Depth Sequence", keyword: "AnteriorChamberDepthSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,112A)", name: "Calculation Comment Sequence", keyword: "CalculationCommentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,112B)", name: "Calculation Comment Type", keyword: "CalculationCommentType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,112C)", name: "Calculation Comment", keyword: "CalculationComment", vr: Some(DicomVr::Lt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1130)", name: "Lens Thickness", keyword: "LensThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1131)", name: "Anterior Chamber Depth", keyword: "AnteriorChamberDepth", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1132)", name: "Source of Lens Thickness Data Code Sequence", keyword: "SourceOfLensThicknessDataCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1133)", name: "Source of Anterior Chamber Depth Data Code Sequence", keyword: "SourceOfAnteriorChamberDepthDataCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1134)", name: "Source of Refractive Measurements Sequence", keyword: "SourceOfRefractiveMeasurementsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1135)", name: "Source of Refractive Measurements Code Sequence", keyword: "SourceOfRefractiveMeasurementsCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1140)", name: "Ophthalmic Axial Length Measurement Modified", keyword: "OphthalmicAxialLengthMeasurementModified", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1150)", name: "Ophthalmic Axial Length Data Source Code Sequence", keyword: "OphthalmicAxialLengthDataSourceCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1155)", name: "Signal to Noise Ratio", keyword: "SignalToNoiseRatio", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1159)", name: "Ophthalmic Axial Length Data Source Description", keyword: "OphthalmicAxialLengthDataSourceDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1210)", name: "Ophthalmic Axial Length Measurements Total Length Sequence", keyword: "OphthalmicAxialLengthMeasurementsTotalLengthSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1211)", name: "Ophthalmic Axial Length Measurements Segmental Length Sequence", keyword: "OphthalmicAxialLengthMeasurementsSegmentalLengthSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1212)", name: "Ophthalmic Axial Length Measurements Length Summation Sequence", keyword: "OphthalmicAxialLengthMeasurementsLengthSummationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1220)", name: "Ultrasound Ophthalmic Axial Length Measurements Sequence", keyword: "UltrasoundOphthalmicAxialLengthMeasurementsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1225)", name: "Optical Ophthalmic Axial Length Measurements Sequence", keyword: "OpticalOphthalmicAxialLengthMeasurementsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1230)", name: "Ultrasound Selected Ophthalmic Axial Length Sequence", keyword: "UltrasoundSelectedOphthalmicAxialLengthSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1250)", name: "Ophthalmic Axial Length Selection Method Code Sequence", keyword: "OphthalmicAxialLengthSelectionMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1255)", name: "Optical Selected Ophthalmic Axial Length Sequence", keyword: "OpticalSelectedOphthalmicAxialLengthSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1257)", name: "Selected Segmental Ophthalmic Axial Length Sequence", keyword: "SelectedSegmentalOphthalmicAxialLengthSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1260)", name: "Selected Total Ophthalmic Axial Length Sequence", keyword: "SelectedTotalOphthalmicAxialLengthSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1262)", name: "Ophthalmic Axial Length Quality Metric Sequence", keyword: "OphthalmicAxialLengthQualityMetricSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1300)", name: "Intraocular Lens Calculations Right Eye Sequence", keyword: "IntraocularLensCalculationsRightEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1310)", name: "Intraocular Lens Calculations Left Eye Sequence", keyword: "IntraocularLensCalculationsLeftEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1330)", name: "Referenced Ophthalmic Axial Length Measurement QC Image Sequence", keyword: "ReferencedOphthalmicAxialLengthMeasurementQCImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1415)", name: "Ophthalmic Mapping Device Type", keyword: "OphthalmicMappingDeviceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1420)", name: "Acquisition Method Code Sequence", keyword: "AcquisitionMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1423)", name: "Acquisition Method Algorithm Sequence", keyword: "AcquisitionMethodAlgorithmSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1436)", name: "Ophthalmic Thickness Map Type Code Sequence", keyword: "OphthalmicThicknessMapTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1443)", name: "Ophthalmic Thickness Mapping Normals Sequence", keyword: "OphthalmicThicknessMappingNormalsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1445)", name: "Retinal Thickness Definition Code Sequence", keyword: "RetinalThicknessDefinitionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1450)", name: "Pixel Value Mapping to Coded Concept Sequence", keyword: "PixelValueMappingToCodedConceptSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1452)", name: "Mapped Pixel Value", keyword: "MappedPixelValue", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1454)", name: "Pixel Value Mapping Explanation", keyword: "PixelValueMappingExplanation", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1458)", name: "Ophthalmic Thickness Map Quality Threshold Sequence", keyword: "OphthalmicThicknessMapQualityThresholdSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1460)", name: "Ophthalmic Thickness Map Threshold Quality Rating", keyword: "OphthalmicThicknessMapThresholdQualityRating", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1463)", name: "Anatomic Structure Reference Point", keyword: "AnatomicStructureReferencePoint", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1465)", name: "Registration to Localizer Sequence", keyword: "RegistrationToLocalizerSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1466)", name: "Registered Localizer Units", keyword: "RegisteredLocalizerUnits", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1467)", name: "Registered Localizer Top Left Hand Corner", keyword: "RegisteredLocalizerTopLeftHandCorner", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1468)", name: "Registered Localizer Bottom Right Hand Corner", keyword: "RegisteredLocalizerBottomRightHandCorner", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1470)", name: "Ophthalmic Thickness Map Quality Rating Sequence", keyword: "OphthalmicThicknessMapQualityRatingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1472)", name: "Relevant OPT Attributes Sequence", keyword: "RelevantOPTAttributesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1512)", name: "Transformation Method Code Sequence", keyword: "TransformationMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1513)", name: "Transformation Algorithm Sequence", keyword: "TransformationAlgorithmSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1515)", name: "Ophthalmic Axial Length Method", keyword: "OphthalmicAxialLengthMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1517)", name: "Ophthalmic FOV", keyword: "OphthalmicFOV", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1518)", name: "Two Dimensional to Three Dimensional Map Sequence", keyword: "TwoDimensionalToThreeDimensionalMapSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1525)", name: "Wide Field Ophthalmic Photography Quality Rating Sequence", keyword: "WideFieldOphthalmicPhotographyQualityRatingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1526)", name: "Wide Field Ophthalmic Photography Quality Threshold Sequence", keyword: "WideFieldOphthalmicPhotographyQualityThresholdSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1527)", name: "Wide Field Ophthalmic Photography Threshold Quality Rating", keyword: "WideFieldOphthalmicPhotographyThresholdQualityRating", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1528)", name: "X Coordinates Center Pixel View Angle", keyword: "XCoordinatesCenterPixelViewAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1529)", name: "Y Coordinates Center Pixel View Angle", keyword: "YCoordinatesCenterPixelViewAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1530)", name: "Number of Map Points", keyword: "NumberOfMapPoints", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1531)", name: "Two Dimensional to Three Dimensional Map Data", keyword: "TwoDimensionalToThreeDimensionalMapData", vr: Some(DicomVr::Of), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1612)", name: "Derivation Algorithm Sequence", keyword: "DerivationAlgorithmSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1615)", name: "Ophthalmic Image Type Code Sequence", keyword: "OphthalmicImageTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1616)", name: "Ophthalmic Image Type Description", keyword: "OphthalmicImageTypeDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1618)", name: "Scan Pattern Type Code Sequence", keyword: "ScanPatternTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1620)", name: "Referenced Surface Mesh Identification Sequence", keyword: "ReferencedSurfaceMeshIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1622)", name: "Ophthalmic Volumetric Properties Flag", keyword: "OphthalmicVolumetricPropertiesFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1624)", name: "Ophthalmic Anatomic Reference Point X-Coordinate", keyword: "OphthalmicAnatomicReferencePointXCoordinate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0022,1626)", name: "Ophthalmic Anatomic Reference Point Y-Coordinate", keyword: "OphthalmicAnatomicReferencePointYCoordinate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0022,1628)", name: "Ophthalmic En Face Image Quality Rating Sequence", keyword: "OphthalmicEnFaceImageQualityRatingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1630)", name: "Quality Threshold", keyword: "QualityThreshold", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1640)", name: "OCT B-scan Analysis Acquisition Parameters Sequence", keyword: "OCTBscanAnalysisAcquisitionParametersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1642)", name: "Number of B-scans Per Frame", keyword: "NumberOfBscansPerFrame", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1643)", name: "B-scan Slab Thickness", keyword: "BscanSlabThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1644)", name: "Distance Between B-scan Slabs", keyword: "DistanceBetweenBscanSlabs", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0022,1645)", name: "B-scan Cycle Time", keyword: "BscanCycleTime", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1646)", name: "B-scan Cycle Time Vector", keyword: "BscanCycleTimeVector", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0022,1649)", name: "A-scan Rate", keyword: "AscanRate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1650)", name: "B-scan Rate", keyword: "BscanRate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0022,1658)", name: "Surface Mesh Z-Pixel Offset", keyword: "SurfaceMeshZPixelOffset", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0010)", name: "Visual Field Horizontal Extent", keyword: "VisualFieldHorizontalExtent", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0011)", name: "Visual Field Vertical Extent", keyword: "VisualFieldVerticalExtent", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0012)", name: "Visual Field Shape", keyword: "VisualFieldShape", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0016)", name: "Screening Test Mode Code Sequence", keyword: "ScreeningTestModeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0018)", name: "Maximum Stimulus Luminance", keyword: "MaximumStimulusLuminance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0020)", name: "Background Luminance", keyword: "BackgroundLuminance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0021)", name: "Stimulus Color Code Sequence", keyword: "StimulusColorCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0024)", name: "Background Illumination Color Code Sequence", keyword: "BackgroundIlluminationColorCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0025)", name: "Stimulus Area", keyword: "StimulusArea", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0028)", name: "Stimulus Presentation Time", keyword: "StimulusPresentationTime", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0032)", name: "Fixation Sequence", keyword: "FixationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0033)", name: "Fixation Monitoring Code Sequence", keyword: "FixationMonitoringCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0034)", name: "Visual Field Catch Trial Sequence", keyword: "VisualFieldCatchTrialSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0035)", name: "Fixation Checked Quantity", keyword: "FixationCheckedQuantity", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0036)", name: "Patient Not Properly Fixated Quantity", keyword: "PatientNotProperlyFixatedQuantity", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0037)", name: "Presented Visual Stimuli Data Flag", keyword: "PresentedVisualStimuliDataFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0038)", name: "Number of Visual Stimuli", keyword: "NumberOfVisualStimuli", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0039)", name: "Excessive Fixation Losses Data Flag", keyword: "ExcessiveFixationLossesDataFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0040)", name: "Excessive Fixation Losses", keyword: "ExcessiveFixationLosses", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0042)", name: "Stimuli Retesting Quantity", keyword: "StimuliRetestingQuantity", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0044)", name: "Comments on Patient's Performance of Visual Field", keyword: "CommentsOnPatientPerformanceOfVisualField", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0045)", name: "False Negatives Estimate Flag", keyword: "FalseNegativesEstimateFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0046)", name: "False Negatives Estimate", keyword: "FalseNegativesEstimate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0048)", name: "Negative Catch Trials Quantity", keyword: "NegativeCatchTrialsQuantity", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0050)", name: "False Negatives Quantity", keyword: "FalseNegativesQuantity", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0051)", name: "Excessive False Negatives Data Flag", keyword: "ExcessiveFalseNegativesDataFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0052)", name: "Excessive False Negatives", keyword: "ExcessiveFalseNegatives", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0053)", name: "False Positives Estimate Flag", keyword: "FalsePositivesEstimateFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0054)", name: "False Positives Estimate", keyword: "FalsePositivesEstimate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0055)", name: "Catch Trials Data Flag", keyword: "CatchTrialsDataFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0056)", name: "Positive Catch Trials Quantity", keyword: "PositiveCatchTrialsQuantity", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0057)", name: "Test Point Normals Data Flag", keyword: "TestPointNormalsDataFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0058)", name: "Test Point Normals Sequence", keyword: "TestPointNormalsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0059)", name: "Global Deviation Probability Normals Flag", keyword: "GlobalDeviationProbabilityNormalsFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0060)", name: "False Positives Quantity", keyword: "FalsePositivesQuantity", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0061)", name: "Excessive False Positives Data Flag", keyword: "ExcessiveFalsePositivesDataFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0062)", name: "Excessive False Positives", keyword: "ExcessiveFalsePositives", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0063)", name: "Visual Field Test Normals Flag", keyword: "VisualFieldTestNormalsFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0064)", name: "Results Normals Sequence", keyword: "ResultsNormalsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0065)", name: "Age Corrected Sensitivity Deviation Algorithm Sequence", keyword: "AgeCorrectedSensitivityDeviationAlgorithmSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0066)", name: "Global Deviation From Normal", keyword: "GlobalDeviationFromNormal", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0067)", name: "Generalized Defect Sensitivity Deviation Algorithm Sequence", keyword: "GeneralizedDefectSensitivityDeviationAlgorithmSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0068)", name: "Localized Deviation From Normal", keyword: "LocalizedDeviationFromNormal", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0069)", name: "Patient Reliability Indicator", keyword: "PatientReliabilityIndicator", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0070)", name: "Visual Field Mean Sensitivity", keyword: "VisualFieldMeanSensitivity", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0071)", name: "Global Deviation Probability", keyword: "GlobalDeviationProbability", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0072)", name: "Local Deviation Probability Normals Flag", keyword: "LocalDeviationProbabilityNormalsFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0073)", name: "Localized Deviation Probability", keyword: "LocalizedDeviationProbability", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0074)", name: "Short Term Fluctuation Calculated", keyword: "ShortTermFluctuationCalculated", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0075)", name: "Short Term Fluctuation", keyword: "ShortTermFluctuation", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0076)", name: "Short Term Fluctuation Probability Calculated", keyword: "ShortTermFluctuationProbabilityCalculated", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0077)", name: "Short Term Fluctuation Probability", keyword: "ShortTermFluctuationProbability", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0078)", name: "Corrected Localized Deviation From Normal Calculated", keyword: "CorrectedLocalizedDeviationFromNormalCalculated", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0079)", name: "Corrected Localized Deviation From Normal", keyword: "CorrectedLocalizedDeviationFromNormal", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0080)", name: "Corrected Localized Deviation From Normal Probability Calculated", keyword: "CorrectedLocalizedDeviationFromNormalProbabilityCalculated", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0081)", name: "Corrected Localized Deviation From Normal Probability", keyword: "CorrectedLocalizedDeviationFromNormalProbability", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0083)", name: "Global Deviation Probability Sequence", keyword: "GlobalDeviationProbabilitySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0085)", name: "Localized Deviation Probability Sequence", keyword: "LocalizedDeviationProbabilitySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0086)", name: "Foveal Sensitivity Measured", keyword: "FovealSensitivityMeasured", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0087)", name: "Foveal Sensitivity", keyword: "FovealSensitivity", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0088)", name: "Visual Field Test Duration", keyword: "VisualFieldTestDuration", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0089)", name: "Visual Field Test Point Sequence", keyword: "VisualFieldTestPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0090)", name: "Visual Field Test Point X-Coordinate", keyword: "VisualFieldTestPointXCoordinate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0091)", name: "Visual Field Test Point Y-Coordinate", keyword: "VisualFieldTestPointYCoordinate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0092)", name: "Age Corrected Sensitivity Deviation Value", keyword: "AgeCorrectedSensitivityDeviationValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0093)", name: "Stimulus Results", keyword: "StimulusResults", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0094)", name: "Sensitivity Value", keyword: "SensitivityValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0095)", name: "Retest Stimulus Seen", keyword: "RetestStimulusSeen", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0096)", name: "Retest Sensitivity Value", keyword: "RetestSensitivityValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0097)", name: "Visual Field Test Point Normals Sequence", keyword: "VisualFieldTestPointNormalsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0024,0098)", name: "Quantified Defect", keyword: "QuantifiedDefect", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0100)", name: "Age Corrected Sensitivity Deviation Probability Value", keyword: "AgeCorrectedSensitivityDeviationProbabilityValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0102)", name: "Generalized Defect Corrected Sensitivity Deviation Flag", keyword: "GeneralizedDefectCorrectedSensitivityDeviationFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0103)", name: "Generalized Defect Corrected Sensitivity Deviation Value", keyword: "GeneralizedDefectCorrectedSensitivityDeviationValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0104)", name: "Generalized Defect Corrected Sensitivity Deviation Probability Value", keyword: "GeneralizedDefectCorrectedSensitivityDeviationProbabilityValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0105)", name: "Minimum Sensitivity Value", keyword: "MinimumSensitivityValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0106)", name: "Blind Spot Localized", keyword: "BlindSpotLocalized", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0107)", name: "Blind Spot X-Coordinate", keyword: "BlindSpotXCoordinate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0108)", name: "Blind Spot Y-Coordinate", keyword: "BlindSpotYCoordinate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0110)", name: "Visual Acuity Measurement Sequence", keyword: "VisualAcuityMeasurementSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0112)", name: "Refractive Parameters Used on Patient Sequence", keyword: "RefractiveParametersUsedOnPatientSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0024,0113)", name: "Measurement Laterality", keyword: "MeasurementLaterality", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0114)", name: "Ophthalmic Patient Clinical Information Left Eye Sequence", keyword: "OphthalmicPatientClinicalInformationLeftEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0115)", name: "Ophthalmic Patient Clinical Information Right Eye Sequence", keyword: "OphthalmicPatientClinicalInformationRightEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0117)", name: "Foveal Point Normative Data Flag", keyword: "FovealPointNormativeDataFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0118)", name: "Foveal Point Probability Value", keyword: "FovealPointProbabilityValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0120)", name: "Screening Baseline Measured", keyword: "ScreeningBaselineMeasured", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0122)", name: "Screening Baseline Measured Sequence", keyword: "ScreeningBaselineMeasuredSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0024,0124)", name: "Screening Baseline Type", keyword: "ScreeningBaselineType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0126)", name: "Screening Baseline Value", keyword: "ScreeningBaselineValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0202)", name: "Algorithm Source", keyword: "AlgorithmSource", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0306)", name: "Data Set Name", keyword: "DataSetName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0307)", name: "Data Set Version", keyword: "DataSetVersion", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0308)", name: "Data Set Source", keyword: "DataSetSource", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0309)", name: "Data Set Description", keyword: "DataSetDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0317)", name: "Visual Field Test Reliability Global Index Sequence", keyword: "VisualFieldTestReliabilityGlobalIndexSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0320)", name: "Visual Field Global Results Index Sequence", keyword: "VisualFieldGlobalResultsIndexSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0024,0325)", name: "Data Observation Sequence", keyword: "DataObservationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0338)", name: "Index Normals Flag", keyword: "IndexNormalsFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0341)", name: "Index Probability", keyword: "IndexProbability", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0024,0344)", name: "Index Probability Sequence", keyword: "IndexProbabilitySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,0002)", name: "Samples per Pixel", keyword: "SamplesPerPixel", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,0003)", name: "Samples per Pixel Used", keyword: "SamplesPerPixelUsed", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,0004)", name: "Photometric Interpretation", keyword: "PhotometricInterpretation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,0006)", name: "Planar Configuration", keyword: "PlanarConfiguration", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,0008)", name: "Number of Frames", keyword: "NumberOfFrames", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,0009)", name: "Frame Increment Pointer", keyword: "FrameIncrementPointer", vr: Some(DicomVr::At), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0028,000A)", name: "Frame Dimension Pointer", keyword: "FrameDimensionPointer", vr: Some(DicomVr::At), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0010)", name: "Rows", keyword: "Rows", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,0011)", name: "Columns", keyword: "Columns", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,0014)", name: "Ultrasound Color Data Present", keyword: "UltrasoundColorDataPresent", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0030)", name: "Pixel Spacing", keyword: "PixelSpacing", vr: Some(DicomVr::Ds), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,0031)", name: "Zoom Factor", keyword: "ZoomFactor", vr: Some(DicomVr::Ds), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0032)", name: "Zoom Center", keyword: "ZoomCenter", vr: Some(DicomVr::Ds), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0034)", name: "Pixel Aspect Ratio", keyword: "PixelAspectRatio", vr: Some(DicomVr::Is), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,0051)", name: "Corrected Image", keyword: "CorrectedImage", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0100)", name: "Bits Allocated", keyword: "BitsAllocated", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,0101)", name: "Bits Stored", keyword: "BitsStored", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,0102)", name: "High Bit", keyword: "HighBit", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,0103)", name: "Pixel Representation", keyword: "PixelRepresentation", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,0106)", name: "Smallest Image Pixel Value", keyword: "SmallestImagePixelValue", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0107)", name: "Largest Image Pixel Value", keyword: "LargestImagePixelValue", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0108)", name: "Smallest Pixel Value in Series", keyword: "SmallestPixelValueInSeries", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0109)", name: "Largest Pixel Value in Series", keyword: "LargestPixelValueInSeries", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0120)", name: "Pixel Padding Value", keyword: "PixelPaddingValue", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,0121)", name: "Pixel Padding Range Limit", keyword: "PixelPaddingRangeLimit", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,0122)", name: "Float Pixel Padding Value", keyword: "FloatPixelPaddingValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0123)", name: "Double Float Pixel Padding Value", keyword: "DoubleFloatPixelPaddingValue", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0124)", name: "Float Pixel Padding Range Limit", keyword: "FloatPixelPaddingRangeLimit", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,0125)", name: "Double Float Pixel Padding Range Limit", keyword: "DoubleFloatPixelPaddingRangeLimit", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,0300)", name: "Quality Control Image", keyword: "QualityControlImage", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0301)", name: "Burned In Annotation", keyword: "BurnedInAnnotation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0302)", name: "Recognizable Visual Features", keyword: "RecognizableVisualFeatures", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0303)", name: "Longitudinal Temporal Information Modified", keyword: "LongitudinalTemporalInformationModified", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0304)", name: "Referenced Color Palette Instance UID", keyword: "ReferencedColorPaletteInstanceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,0A02)", name: "Pixel Spacing Calibration Type", keyword: "PixelSpacingCalibrationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,0A04)", name: "Pixel Spacing Calibration Description", keyword: "PixelSpacingCalibrationDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1040)", name: "Pixel Intensity Relationship", keyword: "PixelIntensityRelationship", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,1041)", name: "Pixel Intensity Relationship Sign", keyword: "PixelIntensityRelationshipSign", vr: Some(DicomVr::Ss), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1050)", name: "Window Center", keyword: "WindowCenter", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1051)", name: "Window Width", keyword: "WindowWidth", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1052)", name: "Rescale Intercept", keyword: "RescaleIntercept", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1053)", name: "Rescale Slope", keyword: "RescaleSlope", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1054)", name: "Rescale Type", keyword: "RescaleType", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1055)", name: "Window Center & Width Explanation", keyword: "WindowCenterWidthExplanation", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0028,1056)", name: "VOI LUT Function", keyword: "VOILUTFunction", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,1090)", name: "Recommended Viewing Mode", keyword: "RecommendedViewingMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0028,1101)", name: "Red Palette Color Lookup Table Descriptor", keyword: "RedPaletteColorLookupTableDescriptor", vr: Some(DicomVr::UsOrSs), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1102)", name: "Green Palette Color Lookup Table Descriptor", keyword: "GreenPaletteColorLookupTableDescriptor", vr: Some(DicomVr::UsOrSs), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1103)", name: "Blue Palette Color Lookup Table Descriptor", keyword: "BluePaletteColorLookupTableDescriptor", vr: Some(DicomVr::UsOrSs), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1104)", name: "Alpha Palette Color Lookup Table Descriptor", keyword: "AlphaPaletteColorLookupTableDescriptor", vr: Some(DicomVr::Us), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1199)", name: "Palette Color Lookup Table UID", keyword: "PaletteColorLookupTableUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,1201)", name: "Red Palette Color Lookup Table Data", keyword: "RedPaletteColorLookupTableData", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1202)", name: "Green Palette Color Lookup Table Data", keyword: "GreenPaletteColorLookupTableData", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1203)", name: "Blue Palette Color Lookup Table Data", keyword: "BluePaletteColorLookupTableData", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1204)", name: "Alpha Palette Color Lookup Table Data", keyword: "AlphaPaletteColorLookupTableData", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1221)", name: "Segmented Red Palette Color Lookup Table Data", keyword: "SegmentedRedPaletteColorLookupTableData", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1222)", name: "Segmented Green Palette Color Lookup Table Data", keyword: "SegmentedGreenPaletteColorLookupTableData", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1223)", name: "Segmented Blue Palette Color Lookup Table Data", keyword: "SegmentedBluePaletteColorLookupTableData", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1224)", name: "Segmented Alpha Palette Color Lookup Table Data", keyword: "SegmentedAlphaPaletteColorLookupTableData", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1230)", name: "Stored Value Color Range Sequence", keyword: "StoredValueColorRangeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,1231)", name: "Minimum Stored Value Mapped", keyword: "MinimumStoredValueMapped", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,1232)", name: "Maximum Stored Value Mapped", keyword: "MaximumStoredValueMapped", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,1300)", name: "Breast Implant Present", keyword: "BreastImplantPresent", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,1350)", name: "Partial View", keyword: "PartialView", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,1351)", name: "Partial View Description", keyword: "PartialViewDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,1352)", name: "Partial View Code Sequence", keyword: "PartialViewCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,135A)", name: "Spatial Locations Preserved", keyword: "SpatialLocationsPreserved", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,1401)", name: "Data Frame Assignment Sequence", keyword: "DataFrameAssignmentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,1402)", name: "Data Path Assignment", keyword: "DataPathAssignment", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,1403)", name: "Bits Mapped to Color Lookup Table", keyword: "BitsMappedToColorLookupTable", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,1404)", name: "Blending LUT 1 Sequence", keyword: "BlendingLUT1Sequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1405)", name: "Blending LUT 1 Transfer Function", keyword: "BlendingLUT1TransferFunction", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,1406)", name: "Blending Weight Constant", keyword: "BlendingWeightConstant", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1407)", name: "Blending Lookup Table Descriptor", keyword: "BlendingLookupTableDescriptor", vr: Some(DicomVr::Us), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,1408)", name: "Blending Lookup Table Data", keyword: "BlendingLookupTableData", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,140B)", name: "Enhanced Palette Color Lookup Table Sequence", keyword: "EnhancedPaletteColorLookupTableSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,140C)", name: "Blending LUT 2 Sequence", keyword: "BlendingLUT2Sequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,140D)", name: "Blending LUT 2 Transfer Function", keyword: "BlendingLUT2TransferFunction", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,140E)", name: "Data Path ID", keyword: "DataPathID", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,140F)", name: "RGB LUT Transfer Function", keyword: "RGBLUTTransferFunction", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,1410)", name: "Alpha LUT Transfer Function", keyword: "AlphaLUTTransferFunction", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,2000)", name: "ICC Profile", keyword: "ICCProfile", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,2002)", name: "Color Space", keyword: "ColorSpace", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,2110)", name: "Lossy Image Compression", keyword: "LossyImageCompression", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,2112)", name: "Lossy Image Compression Ratio", keyword: "LossyImageCompressionRatio", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0028,2114)", name: "Lossy Image Compression Method", keyword: "LossyImageCompressionMethod", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0028,3000)", name: "Modality LUT Sequence", keyword: "ModalityLUTSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,3001)", name: "Variable Modality LUT Sequence", keyword: "VariableModalityLUTSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,3002)", name: "LUT Descriptor", keyword: "LUTDescriptor", vr: Some(DicomVr::UsOrSs), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0028,3003)", name: "LUT Explanation", keyword: "LUTExplanation", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,3004)", name: "Modality LUT Type", keyword: "ModalityLUTType", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,3006)", name: "LUT Data", keyword: "LUTData", vr: Some(DicomVr::UsOrOw), vm: "1-n or 1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,3010)", name: "VOI LUT Sequence", keyword: "VOILUTSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,3110)", name: "Softcopy VOI LUT Sequence", keyword: "SoftcopyVOILUTSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,6010)", name: "Representative Frame Number", keyword: "RepresentativeFrameNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,6020)", name: "Frame Numbers of Interest (FOI)", keyword: "FrameNumbersOfInterest", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0028,6022)", name: "Frame of Interest Description", keyword: "FrameOfInterestDescription", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0028,6023)", name: "Frame of Interest Type", keyword: "FrameOfInterestType", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0028,6040)", name: "R Wave Pointer", keyword: "RWavePointer", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0028,6100)", name: "Mask Subtraction Sequence", keyword: "MaskSubtractionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,6101)", name: "Mask Operation", keyword: "MaskOperation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,6102)", name: "Applicable Frame Range", keyword: "ApplicableFrameRange", vr: Some(DicomVr::Us), vm: "2-2n", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,6110)", name: "Mask Frame Numbers", keyword: "MaskFrameNumbers", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,6112)", name: "Contrast Frame Averaging", keyword: "ContrastFrameAveraging", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,6114)", name: "Mask Sub-pixel Shift", keyword: "MaskSubPixelShift", vr: Some(DicomVr::Fl), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0028,6120)", name: "TID Offset", keyword: "TIDOffset", vr: Some(DicomVr::Ss), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0028,6190)", name: "Mask Operation Explanation", keyword: "MaskOperationExplanation", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,7FE0)", name: "Pixel Data Provider URL", keyword: "PixelDataProviderURL", vr: Some(DicomVr::Ur), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,9001)", name: "Data Point Rows", keyword: "DataPointRows", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9002)", name: "Data Point Columns", keyword: "DataPointColumns", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9003)", name: "Signal Domain Columns", keyword: "SignalDomainColumns", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9108)", name: "Data Representation", keyword: "DataRepresentation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9110)", name: "Pixel Measures Sequence", keyword: "PixelMeasuresSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9132)", name: "Frame VOI LUT Sequence", keyword: "FrameVOILUTSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9145)", name: "Pixel Value Transformation Sequence", keyword: "PixelValueTransformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9235)", name: "Signal Domain Rows", keyword: "SignalDomainRows", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,9411)", name: "Display Filter Percentage", keyword: "DisplayFilterPercentage", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0028,9415)", name: "Frame Pixel Shift Sequence", keyword: "FramePixelShiftSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9416)", name: "Subtraction Item ID", keyword: "SubtractionItemID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,9422)", name: "Pixel Intensity Relationship LUT Sequence", keyword: "PixelIntensityRelationshipLUTSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,9443)", name: "Frame Pixel Data Properties Sequence", keyword: "FramePixelDataPropertiesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9444)", name: "Geometrical Properties", keyword: "GeometricalProperties", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9445)", name: "Geometric Maximum Distortion", keyword: "GeometricMaximumDistortion", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0028,9446)", name: "Image Processing Applied", keyword: "ImageProcessingApplied", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9454)", name: "Mask Selection Mode", keyword: "MaskSelectionMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0028,9474)", name: "LUT Function", keyword: "LUTFunction", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9478)", name: "Mask Visibility Percentage", keyword: "MaskVisibilityPercentage", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,9501)", name: "Pixel Shift Sequence", keyword: "PixelShiftSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9502)", name: "Region Pixel Shift Sequence", keyword: "RegionPixelShiftSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9503)", name: "Vertices of the Region", keyword: "VerticesOfTheRegion", vr: Some(DicomVr::Ss), vm: "2-2n", attr_type: "1C" },
-    DicomAttribute { tag: "(0028,9505)", name: "Multi-frame Presentation Sequence", keyword: "MultiFramePresentationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9506)", name: "Pixel Shift Frame Range", keyword: "PixelShiftFrameRange", vr: Some(DicomVr::Us), vm: "2-2n", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9507)", name: "LUT Frame Range", keyword: "LUTFrameRange", vr: Some(DicomVr::Us), vm: "2-2n", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9520)", name: "Image to Equipment Mapping Matrix", keyword: "ImageToEquipmentMappingMatrix", vr: Some(DicomVr::Ds), vm: "16", attr_type: "1" },
-    DicomAttribute { tag: "(0028,9537)", name: "Equipment Coordinate System Identification", keyword: "EquipmentCoordinateSystemIdentification", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0032,1033)", name: "Requesting Service", keyword: "RequestingService", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0032,1034)", name: "Requesting Service Code Sequence", keyword: "RequestingServiceCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0032,1060)", name: "Requested Procedure Description", keyword: "RequestedProcedureDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0032,1064)", name: "Requested Procedure Code Sequence", keyword: "RequestedProcedureCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0032,1066)", name: "Reason for Visit", keyword: "ReasonForVisit", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0032,1067)", name: "Reason for Visit Code Sequence", keyword: "ReasonForVisitCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0034,0001)", name: "Flow Identifier Sequence", keyword: "FlowIdentifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0034,0002)", name: "Flow Identifier", keyword: "FlowIdentifier", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0034,0003)", name: "Flow Transfer Syntax UID", keyword: "FlowTransferSyntaxUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0034,0004)", name: "Flow RTP Sampling Rate", keyword: "FlowRTPSamplingRate", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0034,0005)", name: "Source Identifier", keyword: "SourceIdentifier", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0034,0007)", name: "Frame Origin Timestamp", keyword: "FrameOriginTimestamp", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0034,0008)", name: "Includes Imaging Subject", keyword: "IncludesImagingSubject", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0034,0009)", name: "Frame Usefulness Group Sequence", keyword: "FrameUsefulnessGroupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0034,000A)", name: "Real-Time Bulk Data Flow Sequence", keyword: "RealTimeBulkDataFlowSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0034,000B)", name: "Camera Position Group Sequence", keyword: "CameraPositionGroupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0034,000C)", name: "Includes Information", keyword: "IncludesInformation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0034,000D)", name: "Time of Frame Group Sequence", keyword: "TimeOfFrameGroupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0038,0010)", name: "Admission ID", keyword: "AdmissionID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0038,0014)", name: "Issuer of Admission ID Sequence", keyword: "IssuerOfAdmissionIDSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0038,0060)", name: "Service Episode ID", keyword: "ServiceEpisodeID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0038,0062)", name: "Service Episode Description", keyword: "ServiceEpisodeDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0038,0064)", name: "Issuer of Service Episode ID Sequence", keyword: "IssuerOfServiceEpisodeIDSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0038,0100)", name: "Pertinent Documents Sequence", keyword: "PertinentDocumentsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0038,0101)", name: "Pertinent Resources Sequence", keyword: "PertinentResourcesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0038,0102)", name: "Resource Description", keyword: "ResourceDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0038,0500)", name: "Patient State", keyword: "PatientState", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0004)", name: "Waveform Originality", keyword: "WaveformOriginality", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0005)", name: "Number of Waveform Channels", keyword: "NumberOfWaveformChannels", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0010)", name: "Number of Waveform Samples", keyword: "NumberOfWaveformSamples", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,001A)", name: "Sampling Frequency", keyword: "SamplingFrequency", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0020)", name: "Multiplex Group Label", keyword: "MultiplexGroupLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0200)", name: "Channel Definition Sequence", keyword: "ChannelDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0202)", name: "Waveform Channel Number", keyword: "WaveformChannelNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0203)", name: "Channel Label", keyword: "ChannelLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0205)", name: "Channel Status", keyword: "ChannelStatus", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0208)", name: "Channel Source Sequence", keyword: "ChannelSourceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0209)", name: "Channel Source Modifiers Sequence", keyword: "ChannelSourceModifiersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,020A)", name: "Source Waveform Sequence", keyword: "SourceWaveformSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,020C)", name: "Channel Derivation Description", keyword: "ChannelDerivationDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0210)", name: "Channel Sensitivity", keyword: "ChannelSensitivity", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0211)", name: "Channel Sensitivity Units Sequence", keyword: "ChannelSensitivityUnitsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0212)", name: "Channel Sensitivity Correction Factor", keyword: "ChannelSensitivityCorrectionFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0213)", name: "Channel Baseline", keyword: "ChannelBaseline", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0214)", name: "Channel Time Skew", keyword: "ChannelTimeSkew", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0215)", name: "Channel Sample Skew", keyword: "ChannelSampleSkew", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0218)", name: "Channel Offset", keyword: "ChannelOffset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,021A)", name: "Waveform Bits Stored", keyword: "WaveformBitsStored", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0220)", name: "Filter Low Frequency", keyword: "FilterLowFrequency", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0221)", name: "Filter High Frequency", keyword: "FilterHighFrequency", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0222)", name: "Notch Filter Frequency", keyword: "NotchFilterFrequency", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0223)", name: "Notch Filter Bandwidth", keyword: "NotchFilterBandwidth", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0230)", name: "Waveform Data Display Scale", keyword: "WaveformDataDisplayScale", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0231)", name: "Waveform Display Background CIELab Value", keyword: "WaveformDisplayBackgroundCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0240)", name: "Waveform Presentation Group Sequence", keyword: "WaveformPresentationGroupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0241)", name: "Presentation Group Number", keyword: "PresentationGroupNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0242)", name: "Channel Display Sequence", keyword: "ChannelDisplaySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0244)", name: "Channel Recommended Display CIELab Value", keyword: "ChannelRecommendedDisplayCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0245)", name: "Channel Position", keyword: "ChannelPosition", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0246)", name: "Display Shading Flag", keyword: "DisplayShadingFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0247)", name: "Fractional Channel Display Scale", keyword: "FractionalChannelDisplayScale", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0248)", name: "Absolute Channel Display Scale", keyword: "AbsoluteChannelDisplayScale", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0300)", name: "Multiplexed Audio Channels Description Code Sequence", keyword: "MultiplexedAudioChannelsDescriptionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(003A,0301)", name: "Channel Identification Code", keyword: "ChannelIdentificationCode", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0302)", name: "Channel Mode", keyword: "ChannelMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0310)", name: "Multiplex Group UID", keyword: "MultiplexGroupUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0311)", name: "Powerline Frequency", keyword: "PowerlineFrequency", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0312)", name: "Channel Impedance Sequence", keyword: "ChannelImpedanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0313)", name: "Impedance Value", keyword: "ImpedanceValue", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0314)", name: "Impedance Measurement DateTime", keyword: "ImpedanceMeasurementDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0315)", name: "Impedance Measurement Frequency", keyword: "ImpedanceMeasurementFrequency", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0316)", name: "Impedance Measurement Current Type", keyword: "ImpedanceMeasurementCurrentType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0317)", name: "Waveform Amplifier Type", keyword: "WaveformAmplifierType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0318)", name: "Filter Low Frequency Characteristics Sequence", keyword: "FilterLowFrequencyCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0319)", name: "Filter High Frequency Characteristics Sequence", keyword: "FilterHighFrequencyCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0320)", name: "Summarized Filter Lookup Table Sequence", keyword: "SummarizedFilterLookupTable", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0321)", name: "Notch Filter Characteristics Sequence", keyword: "NotchFilterCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,0322)", name: "Waveform Filter Type", keyword: "WaveformFilterType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0323)", name: "Analog Filter Characteristics Sequence", keyword: "AnalogFilterCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0324)", name: "Analog Filter Roll Off", keyword: "AnalogFilterRollOff", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0325)", name: "Analog Filter Type Code Sequence", keyword: "AnalogFilterType", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0326)", name: "Digital Filter Characteristics Sequence", keyword: "DigitalFilterCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(003A,0327)", name: "Digital Filter Order", keyword: "DigitalFilterOrder", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0328)", name: "Digital Filter Type Code Sequence", keyword: "DigitalFilterTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,0329)", name: "Waveform Filter Description", keyword: "WaveformFilterDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,032A)", name: "Filter Lookup Table Sequence", keyword: "FilterLookupTableSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,032B)", name: "Filter Lookup Table Description", keyword: "FilterLookupTableDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(003A,032C)", name: "Frequency Encoding Code Sequence", keyword: "FrequencyEncodingCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,032D)", name: "Magnitude Encoding Code Sequence", keyword: "MagnitudeEncodingCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(003A,032E)", name: "Filter Lookup Table Data", keyword: "FilterLookupTableData", vr: Some(DicomVr::Od), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,0007)", name: "Scheduled Procedure Step Description", keyword: "ScheduledProcedureStepDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0008)", name: "Scheduled Protocol Code Sequence", keyword: "ScheduledProtocolCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0009)", name: "Scheduled Procedure Step ID", keyword: "ScheduledProcedureStepID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,000A)", name: "Stage Code Sequence", keyword: "StageCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0026)", name: "Order Placer Identifier Sequence", keyword: "OrderPlacerIdentifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0027)", name: "Order Filler Identifier Sequence", keyword: "OrderFillerIdentifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0031)", name: "Local Namespace Entity ID", keyword: "LocalNamespaceEntityID", vr: Some(DicomVr::Ut), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,0032)", name: "Universal Entity ID", keyword: "UniversalEntityID", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0033)", name: "Universal Entity ID Type", keyword: "UniversalEntityIDType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,0035)", name: "Identifier Type Code", keyword: "IdentifierTypeCode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0036)", name: "Assigning Facility Sequence", keyword: "AssigningFacilitySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0039)", name: "Assigning Jurisdiction Code Sequence", keyword: "AssigningJurisdictionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,003A)", name: "Assigning Agency or Department Code Sequence", keyword: "AssigningAgencyOrDepartmentCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0244)", name: "Performed Procedure Step Start Date", keyword: "PerformedProcedureStepStartDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0245)", name: "Performed Procedure Step Start Time", keyword: "PerformedProcedureStepStartTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0250)", name: "Performed Procedure Step End Date", keyword: "PerformedProcedureStepEndDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0251)", name: "Performed Procedure Step End Time", keyword: "PerformedProcedureStepEndTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0253)", name: "Performed Procedure Step ID", keyword: "PerformedProcedureStepID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0254)", name: "Performed Procedure Step Description", keyword: "PerformedProcedureStepDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0260)", name: "Performed Protocol Code Sequence", keyword: "PerformedProtocolCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0261)", name: "Performed Protocol Type", keyword: "PerformedProtocolType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,0275)", name: "Request Attributes Sequence", keyword: "RequestAttributesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0280)", name: "Comments on the Performed Procedure Step", keyword: "CommentsOnThePerformedProcedureStep", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0302)", name: "Entrance Dose", keyword: "EntranceDose", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0303)", name: "Exposed Area", keyword: "ExposedArea", vr: Some(DicomVr::Us), vm: "1-2", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0306)", name: "Distance Source to Entrance", keyword: "DistanceSourceToEntrance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0310)", name: "Comments on Radiation Dose", keyword: "CommentsOnRadiationDose", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0312)", name: "X-Ray Output", keyword: "XRayOutput", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0314)", name: "Half Value Layer", keyword: "HalfValueLayer", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0316)", name: "Organ Dose", keyword: "OrganDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0318)", name: "Organ Exposed", keyword: "OrganExposed", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0440)", name: "Protocol Context Sequence", keyword: "ProtocolContextSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0441)", name: "Content Item Modifier Sequence", keyword: "ContentItemModifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0512)", name: "Container Identifier", keyword: "ContainerIdentifier", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,0513)", name: "Issuer of the Container Identifier Sequence", keyword: "IssuerOfTheContainerIdentifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,0515)", name: "Alternate Container Identifier Sequence", keyword: "AlternateContainerIdentifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0518)", name: "Container Type Code Sequence", keyword: "ContainerTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,051A)", name: "Container Description", keyword: "ContainerDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0520)", name: "Container Component Sequence", keyword: "ContainerComponentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0551)", name: "Specimen Identifier", keyword: "SpecimenIdentifier", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,0554)", name: "Specimen UID", keyword: "SpecimenUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,0555)", name: "Acquisition Context Sequence", keyword: "AcquisitionContextSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,0556)", name: "Acquisition Context Description", keyword: "AcquisitionContextDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0560)", name: "Specimen Description Sequence", keyword: "SpecimenDescriptionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,0562)", name: "Issuer of the Specimen Identifier Sequence", keyword: "IssuerOfTheSpecimenIdentifierSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,059A)", name: "Specimen Type Code Sequence", keyword: "SpecimenTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0600)", name: "Specimen Short Description", keyword: "SpecimenShortDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0602)", name: "Specimen Detailed Description", keyword: "SpecimenDetailedDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,0610)", name: "Specimen Preparation Sequence", keyword: "SpecimenPreparationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,0612)", name: "Specimen Preparation Step Content Item Sequence", keyword: "SpecimenPreparationStepContentItemSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,0620)", name: "Specimen Localization Content Item Sequence", keyword: "SpecimenLocalizationContentItemSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,0710)", name: "Whole Slide Microscopy Image Frame Type Sequence", keyword: "WholeSlideMicroscopyImageFrameTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,071A)", name: "Image Center Point Coordinates Sequence", keyword: "ImageCenterPointCoordinatesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,072A)", name: "X Offset in Slide Coordinate System", keyword: "XOffsetInSlideCoordinateSystem", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,073A)", name: "Y Offset in Slide Coordinate System", keyword: "YOffsetInSlideCoordinateSystem", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,074A)", name: "Z Offset in Slide Coordinate System", keyword: "ZOffsetInSlideCoordinateSystem", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,08EA)", name: "Measurement Units Code Sequence", keyword: "MeasurementUnitsCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,1001)", name: "Requested Procedure ID", keyword: "RequestedProcedureID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,1002)", name: "Reason for the Requested Procedure", keyword: "ReasonForTheRequestedProcedure", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,100A)", name: "Reason for Requested Procedure Code Sequence", keyword: "ReasonForRequestedProcedureCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,1012)", name: "Reason For Performed Procedure Code Sequence", keyword: "ReasonForPerformedProcedureCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,1101)", name: "Person Identification Code Sequence", keyword: "PersonIdentificationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,1102)", name: "Person's Address", keyword: "PersonAddress", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,1103)", name: "Person's Telephone Numbers", keyword: "PersonTelephoneNumbers", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0040,1104)", name: "Person's Telecom Information", keyword: "PersonTelecomInformation", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,2016)", name: "Placer Order Number / Imaging Service Request", keyword: "PlacerOrderNumberImagingServiceRequest", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,2017)", name: "Filler Order Number / Imaging Service Request", keyword: "FillerOrderNumberImagingServiceRequest", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,4033)", name: "Output Information Sequence", keyword: "OutputInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,4071)", name: "DICOM Storage Sequence", keyword: "DICOMStorageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,4072)", name: "STOW-RS Storage Sequence", keyword: "STOWRSStorageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,4073)", name: "Storage URL", keyword: "StorageURL", vr: Some(DicomVr::Ur), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,4074)", name: "XDS Storage Sequence", keyword: "XDSStorageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,8302)", name: "Entrance Dose in mGy", keyword: "EntranceDoseInmGy", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,8303)", name: "Entrance Dose Derivation", keyword: "EntranceDoseDerivation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,9092)", name: "Parametric Map Frame Type Sequence", keyword: "ParametricMapFrameTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,9094)", name: "Referenced Image Real World Value Mapping Sequence", keyword: "ReferencedImageRealWorldValueMappingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,9096)", name: "Real World Value Mapping Sequence", keyword: "RealWorldValueMappingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,9098)", name: "Pixel Value Mapping Code Sequence", keyword: "PixelValueMappingCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,9210)", name: "LUT Label", keyword: "LUTLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,9211)", name: "Real World Value Last Value Mapped", keyword: "RealWorldValueLastValueMapped", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,9212)", name: "Real World Value LUT Data", keyword: "RealWorldValueLUTData", vr: Some(DicomVr::Fd), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,9213)", name: "Double Float Real World Value Last Value Mapped", keyword: "DoubleFloatRealWorldValueLastValueMapped", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,9214)", name: "Double Float Real World Value First Value Mapped", keyword: "DoubleFloatRealWorldValueFirstValueMapped", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,9216)", name: "Real World Value First Value Mapped", keyword: "RealWorldValueFirstValueMapped", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,9220)", name: "Quantity Definition Sequence", keyword: "QuantityDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,9224)", name: "Real World Value Intercept", keyword: "RealWorldValueIntercept", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,9225)", name: "Real World Value Slope", keyword: "RealWorldValueSlope", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A010)", name: "Relationship Type", keyword: "RelationshipType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A027)", name: "Verifying Organization", keyword: "VerifyingOrganization", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A030)", name: "Verification DateTime", keyword: "VerificationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A032)", name: "Observation DateTime", keyword: "ObservationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A033)", name: "Observation Start DateTime", keyword: "ObservationStartDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A040)", name: "Value Type", keyword: "ValueType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A043)", name: "Concept Name Code Sequence", keyword: "ConceptNameCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A050)", name: "Continuity Of Content", keyword: "ContinuityOfContent", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A073)", name: "Verifying Observer Sequence", keyword: "VerifyingObserverSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A075)", name: "Verifying Observer Name", keyword: "VerifyingObserverName", vr: Some(DicomVr::Pn), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A078)", name: "Author Observer Sequence", keyword: "AuthorObserverSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A07A)", name: "Participant Sequence", keyword: "ParticipantSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A07C)", name: "Custodial Organization Sequence", keyword: "CustodialOrganizationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A080)", name: "Participation Type", keyword: "ParticipationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A082)", name: "Participation DateTime", keyword: "ParticipationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,A084)", name: "Observer Type", keyword: "ObserverType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A088)", name: "Verifying Observer Identification Code Sequence", keyword: "VerifyingObserverIdentificationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,A0B0)", name: "Referenced Waveform Channels", keyword: "ReferencedWaveformChannels", vr: Some(DicomVr::Us), vm: "2-2n", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A120)", name: "DateTime", keyword: "DateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A121)", name: "Date", keyword: "Date", vr: Some(DicomVr::Da), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A122)", name: "Time", keyword: "Time", vr: Some(DicomVr::Tm), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A123)", name: "Person Name", keyword: "PersonName", vr: Some(DicomVr::Pn), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A124)", name: "UID", keyword: "UID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A130)", name: "Temporal Range Type", keyword: "TemporalRangeType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A132)", name: "Referenced Sample Positions", keyword: "ReferencedSamplePositions", vr: Some(DicomVr::Ul), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A138)", name: "Referenced Time Offsets", keyword: "ReferencedTimeOffsets", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A13A)", name: "Referenced DateTime", keyword: "ReferencedDateTime", vr: Some(DicomVr::Dt), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A160)", name: "Text Value", keyword: "TextValue", vr: Some(DicomVr::Ut), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A161)", name: "Floating Point Value", keyword: "FloatingPointValue", vr: Some(DicomVr::Fd), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A162)", name: "Rational Numerator Value", keyword: "RationalNumeratorValue", vr: Some(DicomVr::Sl), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A163)", name: "Rational Denominator Value", keyword: "RationalDenominatorValue", vr: Some(DicomVr::Ul), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A168)", name: "Concept Code Sequence", keyword: "ConceptCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A170)", name: "Purpose of Reference Code Sequence", keyword: "PurposeOfReferenceCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,A171)", name: "Observation UID", keyword: "ObservationUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A180)", name: "Annotation Group Number", keyword: "AnnotationGroupNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A195)", name: "Modifier Code Sequence", keyword: "ModifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A300)", name: "Measured Value Sequence", keyword: "MeasuredValueSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,A301)", name: "Numeric Value Qualifier Code Sequence", keyword: "NumericValueQualifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A30A)", name: "Numeric Value", keyword: "NumericValue", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A360)", name: "Predecessor Documents Sequence", keyword: "PredecessorDocumentsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A370)", name: "Referenced Request Sequence", keyword: "ReferencedRequestSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A372)", name: "Performed Procedure Code Sequence", keyword: "PerformedProcedureCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0040,A375)", name: "Current Requested Procedure Evidence Sequence", keyword: "CurrentRequestedProcedureEvidenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A385)", name: "Pertinent Other Evidence Sequence", keyword: "PertinentOtherEvidenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A390)", name: "HL7 Structured Document Reference Sequence", keyword: "HL7StructuredDocumentReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A491)", name: "Completion Flag", keyword: "CompletionFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A492)", name: "Completion Flag Description", keyword: "CompletionFlagDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A493)", name: "Verification Flag", keyword: "VerificationFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A496)", name: "Preliminary Flag", keyword: "PreliminaryFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A504)", name: "Content Template Sequence", keyword: "ContentTemplateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A525)", name: "Identical Documents Sequence", keyword: "IdenticalDocumentsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A730)", name: "Content Sequence", keyword: "ContentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A801)", name: "Tabulated Values Sequence", keyword: "TabulatedValuesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A802)", name: "Number of Table Rows", keyword: "NumberOfTableRows", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A803)", name: "Number of Table Columns", keyword: "NumberOfTableColumns", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,A804)", name: "Table Row Number", keyword: "TableRowNumber", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A805)", name: "Table Column Number", keyword: "TableColumnNumber", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,A806)", name: "Table Row Definition Sequence", keyword: "TableRowDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A807)", name: "Table Column Definition Sequence", keyword: "TableColumnDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,A808)", name: "Cell Values Sequence", keyword: "CellValuesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,B020)", name: "Waveform Annotation Sequence", keyword: "WaveformAnnotationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,DB00)", name: "Template Identifier", keyword: "TemplateIdentifier", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,DB73)", name: "Referenced Content Item Identifier", keyword: "ReferencedContentItemIdentifier", vr: Some(DicomVr::Ul), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,E001)", name: "HL7 Instance Identifier", keyword: "HL7InstanceIdentifier", vr: Some(DicomVr::St), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,E008)", name: "Document Class Code Sequence", keyword: "DocumentClassCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,E010)", name: "Retrieve URI", keyword: "RetrieveURI", vr: Some(DicomVr::Ur), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,E011)", name: "Retrieve Location UID", keyword: "RetrieveLocationUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0040,E020)", name: "Type of Instances", keyword: "TypeOfInstances", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,E021)", name: "DICOM Retrieval Sequence", keyword: "DICOMRetrievalSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,E022)", name: "DICOM Media Retrieval Sequence", keyword: "DICOMMediaRetrievalSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,E023)", name: "WADO Retrieval Sequence", keyword: "WADORetrievalSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,E024)", name: "XDS Retrieval Sequence", keyword: "XDSRetrievalSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,E025)", name: "WADO-RS Retrieval Sequence", keyword: "WADORSRetrievalSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0040,E030)", name: "Repository Unique ID", keyword: "RepositoryUniqueID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0040,E031)", name: "Home Community ID", keyword: "HomeCommunityID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0042,0010)", name: "Document Title", keyword: "DocumentTitle", vr: Some(DicomVr::St), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0042,0011)", name: "Encapsulated Document", keyword: "EncapsulatedDocument", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0042,0012)", name: "MIME Type of Encapsulated Document", keyword: "MIMETypeOfEncapsulatedDocument", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0042,0013)", name: "Source Instance Sequence", keyword: "SourceInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0042,0014)", name: "List of MIME Types", keyword: "ListOfMIMETypes", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0042,0015)", name: "Encapsulated Document Length", keyword: "EncapsulatedDocumentLength", vr: Some(DicomVr::Ul), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0044,0100)", name: "Approval Sequence", keyword: "ApprovalSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0044,0101)", name: "Assertion Code Sequence", keyword: "AssertionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0044,0102)", name: "Assertion UID", keyword: "AssertionUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0044,0103)", name: "Asserter Identification Sequence", keyword: "AsserterIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0044,0104)", name: "Assertion DateTime", keyword: "AssertionDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0044,0105)", name: "Assertion Expiration DateTime", keyword: "AssertionExpirationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0044,0106)", name: "Assertion Comments", keyword: "AssertionComments", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0044,0107)", name: "Related Assertion Sequence", keyword: "RelatedAssertionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0044,0108)", name: "Referenced Assertion UID", keyword: "ReferencedAssertionUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0044,0109)", name: "Approval Subject Sequence", keyword: "ApprovalSubjectSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0044,010A)", name: "Organizational Role Code Sequence", keyword: "OrganizationalRoleCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0012)", name: "Lens Description", keyword: "LensDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0046,0014)", name: "Right Lens Sequence", keyword: "RightLensSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0015)", name: "Left Lens Sequence", keyword: "LeftLensSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0016)", name: "Unspecified Laterality Lens Sequence", keyword: "UnspecifiedLateralityLensSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0018)", name: "Cylinder Sequence", keyword: "CylinderSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0028)", name: "Prism Sequence", keyword: "PrismSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0030)", name: "Horizontal Prism Power", keyword: "HorizontalPrismPower", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0032)", name: "Horizontal Prism Base", keyword: "HorizontalPrismBase", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0034)", name: "Vertical Prism Power", keyword: "VerticalPrismPower", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0036)", name: "Vertical Prism Base", keyword: "VerticalPrismBase", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0038)", name: "Lens Segment Type", keyword: "LensSegmentType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0040)", name: "Optical Transmittance", keyword: "OpticalTransmittance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0042)", name: "Channel Width", keyword: "ChannelWidth", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0044)", name: "Pupil Size", keyword: "PupilSize", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0046)", name: "Corneal Size", keyword: "CornealSize", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0047)", name: "Corneal Size Sequence", keyword: "CornealSizeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0050)", name: "Autorefraction Right Eye Sequence", keyword: "AutorefractionRightEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0052)", name: "Autorefraction Left Eye Sequence", keyword: "AutorefractionLeftEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0060)", name: "Distance Pupillary Distance", keyword: "DistancePupillaryDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0062)", name: "Near Pupillary Distance", keyword: "NearPupillaryDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0063)", name: "Intermediate Pupillary Distance", keyword: "IntermediatePupillaryDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0064)", name: "Other Pupillary Distance", keyword: "OtherPupillaryDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0070)", name: "Keratometry Right Eye Sequence", keyword: "KeratometryRightEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0071)", name: "Keratometry Left Eye Sequence", keyword: "KeratometryLeftEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0074)", name: "Steep Keratometric Axis Sequence", keyword: "SteepKeratometricAxisSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0075)", name: "Radius of Curvature", keyword: "RadiusOfCurvature", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0076)", name: "Keratometric Power", keyword: "KeratometricPower", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0077)", name: "Keratometric Axis", keyword: "KeratometricAxis", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0080)", name: "Flat Keratometric Axis Sequence", keyword: "FlatKeratometricAxisSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0092)", name: "Background Color", keyword: "BackgroundColor", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0094)", name: "Optotype", keyword: "Optotype", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0095)", name: "Optotype Presentation", keyword: "OptotypePresentation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0097)", name: "Subjective Refraction Right Eye Sequence", keyword: "SubjectiveRefractionRightEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0098)", name: "Subjective Refraction Left Eye Sequence", keyword: "SubjectiveRefractionLeftEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0100)", name: "Add Near Sequence", keyword: "AddNearSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0101)", name: "Add Intermediate Sequence", keyword: "AddIntermediateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0102)", name: "Add Other Sequence", keyword: "AddOtherSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0104)", name: "Add Power", keyword: "AddPower", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0106)", name: "Viewing Distance", keyword: "ViewingDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0110)", name: "Cornea Measurements Sequence", keyword: "CorneaMeasurementsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0111)", name: "Source of Cornea Measurement Data Code Sequence", keyword: "SourceOfCorneaMeasurementDataCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0112)", name: "Steep Corneal Axis Sequence", keyword: "SteepCornealAxisSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0113)", name: "Flat Corneal Axis Sequence", keyword: "FlatCornealAxisSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0114)", name: "Corneal Power", keyword: "CornealPower", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0046,0115)", name: "Corneal Axis", keyword: "CornealAxis", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0046,0116)", name: "Cornea Measurement Method Code Sequence", keyword: "CorneaMeasurementMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0117)", name: "Refractive Index of Cornea", keyword: "RefractiveIndexOfCornea", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0118)", name: "Refractive Index of Aqueous Humor", keyword: "RefractiveIndexOfAqueousHumor", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0121)", name: "Visual Acuity Type Code Sequence", keyword: "VisualAcuityTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0122)", name: "Visual Acuity Right Eye Sequence", keyword: "VisualAcuityRightEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0123)", name: "Visual Acuity Left Eye Sequence", keyword: "VisualAcuityLeftEyeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0124)", name: "Visual Acuity Both Eyes Open Sequence", keyword: "VisualAcuityBothEyesOpenSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0125)", name: "Viewing Distance Type", keyword: "ViewingDistanceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0135)", name: "Visual Acuity Modifiers", keyword: "VisualAcuityModifiers", vr: Some(DicomVr::Ss), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0137)", name: "Decimal Visual Acuity", keyword: "DecimalVisualAcuity", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0139)", name: "Optotype Detailed Definition", keyword: "OptotypeDetailedDefinition", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0145)", name: "Referenced Refractive Measurements Sequence", keyword: "ReferencedRefractiveMeasurementsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0046,0146)", name: "Sphere Power", keyword: "SpherePower", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0147)", name: "Cylinder Power", keyword: "CylinderPower", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0201)", name: "Corneal Topography Surface", keyword: "CornealTopographySurface", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0202)", name: "Corneal Vertex Location", keyword: "CornealVertexLocation", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0203)", name: "Pupil Centroid X-Coordinate", keyword: "PupilCentroidXCoordinate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0204)", name: "Pupil Centroid Y-Coordinate", keyword: "PupilCentroidYCoordinate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0205)", name: "Equivalent Pupil Radius", keyword: "EquivalentPupilRadius", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0207)", name: "Corneal Topography Map Type Code Sequence", keyword: "CornealTopographyMapTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0208)", name: "Vertices of the Outline of Pupil", keyword: "VerticesOfTheOutlineOfPupil", vr: Some(DicomVr::Is), vm: "2-2n", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0210)", name: "Corneal Topography Mapping Normals Sequence", keyword: "CornealTopographyMappingNormalsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0211)", name: "Maximum Corneal Curvature Sequence", keyword: "MaximumCornealCurvatureSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0212)", name: "Maximum Corneal Curvature", keyword: "MaximumCornealCurvature", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0213)", name: "Maximum Corneal Curvature Location", keyword: "MaximumCornealCurvatureLocation", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0215)", name: "Minimum Keratometric Sequence", keyword: "MinimumKeratometricSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0218)", name: "Simulated Keratometric Cylinder Sequence", keyword: "SimulatedKeratometricCylinderSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0220)", name: "Average Corneal Power", keyword: "AverageCornealPower", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0224)", name: "Corneal I-S Value", keyword: "CornealISValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0227)", name: "Analyzed Area", keyword: "AnalyzedArea", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0230)", name: "Surface Regularity Index", keyword: "SurfaceRegularityIndex", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0232)", name: "Surface Asymmetry Index", keyword: "SurfaceAsymmetryIndex", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0234)", name: "Corneal Eccentricity Index", keyword: "CornealEccentricityIndex", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0236)", name: "Keratoconus Prediction Index", keyword: "KeratoconusPredictionIndex", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0238)", name: "Decimal Potential Visual Acuity", keyword: "DecimalPotentialVisualAcuity", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0046,0242)", name: "Corneal Topography Map Quality Evaluation", keyword: "CornealTopographyMapQualityEvaluation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0046,0244)", name: "Source Image Corneal Processed Data Sequence", keyword: "SourceImageCornealProcessedDataSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0247)", name: "Corneal Point Location", keyword: "CornealPointLocation", vr: Some(DicomVr::Fl), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0248)", name: "Corneal Point Estimated", keyword: "CornealPointEstimated", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0249)", name: "Axial Power", keyword: "AxialPower", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0250)", name: "Tangential Power", keyword: "TangentialPower", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0251)", name: "Refractive Power", keyword: "RefractivePower", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0252)", name: "Relative Elevation", keyword: "RelativeElevation", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0046,0253)", name: "Corneal Wavefront", keyword: "CornealWavefront", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0001)", name: "Imaged Volume Width", keyword: "ImagedVolumeWidth", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0048,0002)", name: "Imaged Volume Height", keyword: "ImagedVolumeHeight", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0048,0003)", name: "Imaged Volume Depth", keyword: "ImagedVolumeDepth", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0048,0006)", name: "Total Pixel Matrix Columns", keyword: "TotalPixelMatrixColumns", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0007)", name: "Total Pixel Matrix Rows", keyword: "TotalPixelMatrixRows", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0008)", name: "Total Pixel Matrix Origin Sequence", keyword: "TotalPixelMatrixOriginSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0010)", name: "Specimen Label in Image", keyword: "SpecimenLabelInImage", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0011)", name: "Focus Method", keyword: "FocusMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0012)", name: "Extended Depth of Field", keyword: "ExtendedDepthOfField", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0013)", name: "Number of Focal Planes", keyword: "NumberOfFocalPlanes", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0048,0014)", name: "Distance Between Focal Planes", keyword: "DistanceBetweenFocalPlanes", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0048,0015)", name: "Recommended Absent Pixel CIELab Value", keyword: "RecommendedAbsentPixelCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0048,0100)", name: "Illuminator Type Code Sequence", keyword: "IlluminatorTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0048,0102)", name: "Image Orientation (Slide)", keyword: "ImageOrientationSlide", vr: Some(DicomVr::Ds), vm: "6", attr_type: "1C" },
-    DicomAttribute { tag: "(0048,0105)", name: "Optical Path Sequence", keyword: "OpticalPathSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0106)", name: "Optical Path Identifier", keyword: "OpticalPathIdentifier", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0107)", name: "Optical Path Description", keyword: "OpticalPathDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0048,0108)", name: "Illumination Color Code Sequence", keyword: "IlluminationColorCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0048,0110)", name: "Specimen Reference Sequence", keyword: "SpecimenReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0048,0111)", name: "Condenser Lens Power", keyword: "CondenserLensPower", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0048,0112)", name: "Objective Lens Power", keyword: "ObjectiveLensPower", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0048,0113)", name: "Objective Lens Numerical Aperture", keyword: "ObjectiveLensNumericalAperture", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0048,0114)", name: "Confocal Mode", keyword: "ConfocalMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0115)", name: "Tissue Location", keyword: "TissueLocation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0116)", name: "Confocal Microscopy Image Frame Type Sequence", keyword: "ConfocalMicroscopyImageFrameTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0117)", name: "Image Acquisition Depth", keyword: "ImageAcquisitionDepth", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0048,0120)", name: "Palette Color Lookup Table Sequence", keyword: "PaletteColorLookupTableSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0048,0207)", name: "Optical Path Identification Sequence", keyword: "OpticalPathIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,021A)", name: "Plane Position (Slide) Sequence", keyword: "PlanePositionSlideSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,021E)", name: "Column Position In Total Image Pixel Matrix", keyword: "ColumnPositionInTotalImagePixelMatrix", vr: Some(DicomVr::Sl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,021F)", name: "Row Position In Total Image Pixel Matrix", keyword: "RowPositionInTotalImagePixelMatrix", vr: Some(DicomVr::Sl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0048,0301)", name: "Pixel Origin Interpretation", keyword: "PixelOriginInterpretation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0048,0302)", name: "Number of Optical Paths", keyword: "NumberOfOpticalPaths", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0048,0303)", name: "Total Pixel Matrix Focal Planes", keyword: "TotalPixelMatrixFocalPlanes", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0050,0004)", name: "Calibration Image", keyword: "CalibrationImage", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,0010)", name: "Device Sequence", keyword: "DeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0050,0012)", name: "Container Component Type Code Sequence", keyword: "ContainerComponentTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0050,0013)", name: "Container Component Thickness", keyword: "ContainerComponentThickness", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,0014)", name: "Device Length", keyword: "DeviceLength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,0015)", name: "Container Component Width", keyword: "ContainerComponentWidth", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,0016)", name: "Device Diameter", keyword: "DeviceDiameter", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,0017)", name: "Device Diameter Units", keyword: "DeviceDiameterUnits", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0050,0018)", name: "Device Volume", keyword: "DeviceVolume", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,0019)", name: "Inter-Marker Distance", keyword: "InterMarkerDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,001A)", name: "Container Component Material", keyword: "ContainerComponentMaterial", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,001B)", name: "Container Component ID", keyword: "ContainerComponentID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,001C)", name: "Container Component Length", keyword: "ContainerComponentLength", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,001D)", name: "Container Component Diameter", keyword: "ContainerComponentDiameter", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,001E)", name: "Container Component Description", keyword: "ContainerComponentDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,0020)", name: "Device Description", keyword: "DeviceDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0050,0021)", name: "Long Device Description", keyword: "LongDeviceDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0052,0001)", name: "Contrast/Bolus Ingredient Percent by Volume", keyword: "ContrastBolusIngredientPercentByVolume", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0052,0002)", name: "OCT Focal Distance", keyword: "OCTFocalDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0052,0003)", name: "Beam Spot Size", keyword: "BeamSpotSize", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0052,0004)", name: "Effective Refractive Index", keyword: "EffectiveRefractiveIndex", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0052,0006)", name: "OCT Acquisition Domain", keyword: "OCTAcquisitionDomain", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0007)", name: "OCT Optical Center Wavelength", keyword: "OCTOpticalCenterWavelength", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0052,0008)", name: "Axial Resolution", keyword: "AxialResolution", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0052,0009)", name: "Ranging Depth", keyword: "RangingDepth", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0011)", name: "A-line Rate", keyword: "ALineRate", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0012)", name: "A-lines Per Frame", keyword: "ALinesPerFrame", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0013)", name: "Catheter Rotational Rate", keyword: "CatheterRotationalRate", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0052,0014)", name: "A-line Pixel Spacing", keyword: "ALinePixelSpacing", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0016)", name: "Mode of Percutaneous Access Sequence", keyword: "ModeOfPercutaneousAccessSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0052,0025)", name: "Intravascular OCT Frame Type Sequence", keyword: "IntravascularOCTFrameTypeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0026)", name: "OCT Z Offset Applied", keyword: "OCTZOffsetApplied", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0027)", name: "Intravascular Frame Content Sequence", keyword: "IntravascularFrameContentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0028)", name: "Intravascular Longitudinal Distance", keyword: "IntravascularLongitudinalDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0052,0029)", name: "Intravascular OCT Frame Content Sequence", keyword: "IntravascularOCTFrameContentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0030)", name: "OCT Z Offset Correction", keyword: "OCTZOffsetCorrection", vr: Some(DicomVr::Ss), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0031)", name: "Catheter Direction of Rotation", keyword: "CatheterDirectionOfRotation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0052,0033)", name: "Seam Line Location", keyword: "SeamLineLocation", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0052,0034)", name: "First A-line Location", keyword: "FirstALineLocation", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0036)", name: "Seam Line Index", keyword: "SeamLineIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0052,0038)", name: "Number of Padded A-lines", keyword: "NumberOfPaddedALines", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0052,0039)", name: "Interpolation Type", keyword: "InterpolationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0052,003A)", name: "Refractive Index Applied", keyword: "RefractiveIndexApplied", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,0010)", name: "Energy Window Vector", keyword: "EnergyWindowVector", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0011)", name: "Number of Energy Windows", keyword: "NumberOfEnergyWindows", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,0012)", name: "Energy Window Information Sequence", keyword: "EnergyWindowInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0054,0013)", name: "Energy Window Range Sequence", keyword: "EnergyWindowRangeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0014)", name: "Energy Window Lower Limit", keyword: "EnergyWindowLowerLimit", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0015)", name: "Energy Window Upper Limit", keyword: "EnergyWindowUpperLimit", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0016)", name: "Radiopharmaceutical Information Sequence", keyword: "RadiopharmaceuticalInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0054,0017)", name: "Residual Syringe Counts", keyword: "ResidualSyringeCounts", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0018)", name: "Energy Window Name", keyword: "EnergyWindowName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0020)", name: "Detector Vector", keyword: "DetectorVector", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0021)", name: "Number of Detectors", keyword: "NumberOfDetectors", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,0022)", name: "Detector Information Sequence", keyword: "DetectorInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0054,0030)", name: "Phase Vector", keyword: "PhaseVector", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0031)", name: "Number of Phases", keyword: "NumberOfPhases", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0032)", name: "Phase Information Sequence", keyword: "PhaseInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0054,0033)", name: "Number of Frames in Phase", keyword: "NumberOfFramesInPhase", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,0036)", name: "Phase Delay", keyword: "PhaseDelay", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,0038)", name: "Pause Between Frames", keyword: "PauseBetweenFrames", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,0039)", name: "Phase Description", keyword: "PhaseDescription", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0050)", name: "Rotation Vector", keyword: "RotationVector", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0051)", name: "Number of Rotations", keyword: "NumberOfRotations", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0052)", name: "Rotation Information Sequence", keyword: "RotationInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0054,0053)", name: "Number of Frames in Rotation", keyword: "NumberOfFramesInRotation", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,0060)", name: "R-R Interval Vector", keyword: "RRIntervalVector", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0061)", name: "Number of R-R Intervals", keyword: "NumberOfRRIntervals", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0062)", name: "Gated Information Sequence", keyword: "GatedInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0054,0063)", name: "Data Information Sequence", keyword: "DataInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0054,0070)", name: "Time Slot Vector", keyword: "TimeSlotVector", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0071)", name: "Number of Time Slots", keyword: "NumberOfTimeSlots", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0072)", name: "Time Slot Information Sequence", keyword: "TimeSlotInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0054,0073)", name: "Time Slot Time", keyword: "TimeSlotTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0080)", name: "Slice Vector", keyword: "SliceVector", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0081)", name: "Number of Slices", keyword: "NumberOfSlices", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0090)", name: "Angular View Vector", keyword: "AngularViewVector", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0100)", name: "Time Slice Vector", keyword: "TimeSliceVector", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0101)", name: "Number of Time Slices", keyword: "NumberOfTimeSlices", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0200)", name: "Start Angle", keyword: "StartAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0202)", name: "Type of Detector Motion", keyword: "TypeOfDetectorMotion", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0210)", name: "Trigger Vector", keyword: "TriggerVector", vr: Some(DicomVr::Is), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0211)", name: "Number of Triggers in Phase", keyword: "NumberOfTriggersInPhase", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,0220)", name: "View Code Sequence", keyword: "ViewCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0222)", name: "View Modifier Code Sequence", keyword: "ViewModifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0300)", name: "Radionuclide Code Sequence", keyword: "RadionuclideCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0054,0302)", name: "Administration Route Code Sequence", keyword: "AdministrationRouteCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0304)", name: "Radiopharmaceutical Code Sequence", keyword: "RadiopharmaceuticalCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0306)", name: "Calibration Data Sequence", keyword: "CalibrationDataSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0308)", name: "Energy Window Number", keyword: "EnergyWindowNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,0400)", name: "Image ID", keyword: "ImageID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0410)", name: "Patient Orientation Code Sequence", keyword: "PatientOrientationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0054,0412)", name: "Patient Orientation Modifier Code Sequence", keyword: "PatientOrientationModifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0054,0414)", name: "Patient Gantry Relationship Code Sequence", keyword: "PatientGantryRelationshipCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0054,0500)", name: "Slice Progression Direction", keyword: "SliceProgressionDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,0501)", name: "Scan Progression Direction", keyword: "ScanProgressionDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1000)", name: "Series Type", keyword: "SeriesType", vr: Some(DicomVr::Cs), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0054,1001)", name: "Units", keyword: "Units", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,1002)", name: "Counts Source", keyword: "CountsSource", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,1004)", name: "Reprojection Method", keyword: "ReprojectionMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0054,1006)", name: "SUV Type", keyword: "SUVType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1100)", name: "Randoms Correction Method", keyword: "RandomsCorrectionMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1101)", name: "Attenuation Correction Method", keyword: "AttenuationCorrectionMethod", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1102)", name: "Decay Correction", keyword: "DecayCorrection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,1103)", name: "Reconstruction Method", keyword: "ReconstructionMethod", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1104)", name: "Detector Lines of Response Used", keyword: "DetectorLinesOfResponseUsed", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1105)", name: "Scatter Correction Method", keyword: "ScatterCorrectionMethod", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1200)", name: "Axial Acceptance", keyword: "AxialAcceptance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1201)", name: "Axial Mash", keyword: "AxialMash", vr: Some(DicomVr::Is), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1202)", name: "Transverse Mash", keyword: "TransverseMash", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1203)", name: "Detector Element Size", keyword: "DetectorElementSize", vr: Some(DicomVr::Ds), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1210)", name: "Coincidence Window Width", keyword: "CoincidenceWindowWidth", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1220)", name: "Secondary Counts Type", keyword: "SecondaryCountsType", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1300)", name: "Frame Reference Time", keyword: "FrameReferenceTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0054,1310)", name: "Primary (Prompts) Counts Accumulated", keyword: "PrimaryPromptsCountsAccumulated", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1311)", name: "Secondary Counts Accumulated", keyword: "SecondaryCountsAccumulated", vr: Some(DicomVr::Is), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1320)", name: "Slice Sensitivity Factor", keyword: "SliceSensitivityFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1321)", name: "Decay Factor", keyword: "DecayFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0054,1322)", name: "Dose Calibration Factor", keyword: "DoseCalibrationFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1323)", name: "Scatter Fraction Factor", keyword: "ScatterFractionFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1324)", name: "Dead Time Factor", keyword: "DeadTimeFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0054,1330)", name: "Image Index", keyword: "ImageIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0060,3000)", name: "Histogram Sequence", keyword: "HistogramSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0060,3002)", name: "Histogram Number of Bins", keyword: "HistogramNumberOfBins", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0060,3004)", name: "Histogram First Bin Value", keyword: "HistogramFirstBinValue", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0060,3006)", name: "Histogram Last Bin Value", keyword: "HistogramLastBinValue", vr: Some(DicomVr::UsOrSs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0060,3008)", name: "Histogram Bin Width", keyword: "HistogramBinWidth", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0060,3010)", name: "Histogram Explanation", keyword: "HistogramExplanation", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0060,3020)", name: "Histogram Data", keyword: "HistogramData", vr: Some(DicomVr::Ul), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0062,0001)", name: "Segmentation Type", keyword: "SegmentationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0062,0002)", name: "Segment Sequence", keyword: "SegmentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0062,0003)", name: "Segmented Property Category Code Sequence", keyword: "SegmentedPropertyCategoryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0062,0004)", name: "Segment Number", keyword: "SegmentNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0062,0005)", name: "Segment Label", keyword: "SegmentLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0062,0006)", name: "Segment Description", keyword: "SegmentDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0062,0007)", name: "Segmentation Algorithm Identification Sequence", keyword: "SegmentationAlgorithmIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0062,0008)", name: "Segment Algorithm Type", keyword: "SegmentAlgorithmType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0062,0009)", name: "Segment Algorithm Name", keyword: "SegmentAlgorithmName", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0062,000A)", name: "Segment Identification Sequence", keyword: "SegmentIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0062,000B)", name: "Referenced Segment Number", keyword: "ReferencedSegmentNumber", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0062,000C)", name: "Recommended Display Grayscale Value", keyword: "RecommendedDisplayGrayscaleValue", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0062,000D)", name: "Recommended Display CIELab Value", keyword: "RecommendedDisplayCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0062,000E)", name: "Maximum Fractional Value", keyword: "MaximumFractionalValue", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0062,000F)", name: "Segmented Property Type Code Sequence", keyword: "SegmentedPropertyTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0062,0010)", name: "Segmentation Fractional Type", keyword: "SegmentationFractionalType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0062,0011)", name: "Segmented Property Type Modifier Code Sequence", keyword: "SegmentedPropertyTypeModifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0062,0012)", name: "Used Segments Sequence", keyword: "UsedSegmentsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0062,0013)", name: "Segments Overlap", keyword: "SegmentsOverlap", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0062,0020)", name: "Tracking ID", keyword: "TrackingID", vr: Some(DicomVr::Ut), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0062,0021)", name: "Tracking UID", keyword: "TrackingUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0064,0002)", name: "Deformable Registration Sequence", keyword: "DeformableRegistrationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0064,0003)", name: "Source Frame of Reference UID", keyword: "SourceFrameOfReferenceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0064,0005)", name: "Deformable Registration Grid Sequence", keyword: "DeformableRegistrationGridSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0064,0007)", name: "Grid Dimensions", keyword: "GridDimensions", vr: Some(DicomVr::Ul), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0064,0008)", name: "Grid Resolution", keyword: "GridResolution", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0064,0009)", name: "Vector Grid Data", keyword: "VectorGridData", vr: Some(DicomVr::Of), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0064,000F)", name: "Pre Deformation Matrix Registration Sequence", keyword: "PreDeformationMatrixRegistrationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0064,0010)", name: "Post Deformation Matrix Registration Sequence", keyword: "PostDeformationMatrixRegistrationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0066,0001)", name: "Number of Surfaces", keyword: "NumberOfSurfaces", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0002)", name: "Surface Sequence", keyword: "SurfaceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0003)", name: "Surface Number", keyword: "SurfaceNumber", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0004)", name: "Surface Comments", keyword: "SurfaceComments", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0009)", name: "Surface Processing", keyword: "SurfaceProcessing", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0066,000A)", name: "Surface Processing Ratio", keyword: "SurfaceProcessingRatio", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0066,000B)", name: "Surface Processing Description", keyword: "SurfaceProcessingDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,000C)", name: "Recommended Presentation Opacity", keyword: "RecommendedPresentationOpacity", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,000D)", name: "Recommended Presentation Type", keyword: "RecommendedPresentationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,000E)", name: "Finite Volume", keyword: "FiniteVolume", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0010)", name: "Manifold", keyword: "Manifold", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0011)", name: "Surface Points Sequence", keyword: "SurfacePointsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0012)", name: "Surface Points Normals Sequence", keyword: "SurfacePointsNormalsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0066,0013)", name: "Surface Mesh Primitives Sequence", keyword: "SurfaceMeshPrimitivesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0015)", name: "Number of Surface Points", keyword: "NumberOfSurfacePoints", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0016)", name: "Point Coordinates Data", keyword: "PointCoordinatesData", vr: Some(DicomVr::Of), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0017)", name: "Point Position Accuracy", keyword: "PointPositionAccuracy", vr: Some(DicomVr::Fl), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0018)", name: "Mean Point Distance", keyword: "MeanPointDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0019)", name: "Maximum Point Distance", keyword: "MaximumPointDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,001A)", name: "Points Bounding Box Coordinates", keyword: "PointsBoundingBoxCoordinates", vr: Some(DicomVr::Fl), vm: "6", attr_type: "3" },
-    DicomAttribute { tag: "(0066,001B)", name: "Axis of Rotation", keyword: "AxisOfRotation", vr: Some(DicomVr::Fl), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0066,001C)", name: "Center of Rotation", keyword: "CenterOfRotation", vr: Some(DicomVr::Fl), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0066,001E)", name: "Number of Vectors", keyword: "NumberOfVectors", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,001F)", name: "Vector Dimensionality", keyword: "VectorDimensionality", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0020)", name: "Vector Accuracy", keyword: "VectorAccuracy", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0021)", name: "Vector Coordinate Data", keyword: "VectorCoordinateData", vr: Some(DicomVr::Of), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0022)", name: "Double Point Coordinates Data", keyword: "DoublePointCoordinatesData", vr: Some(DicomVr::Od), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0066,0026)", name: "Triangle Strip Sequence", keyword: "TriangleStripSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0066,0027)", name: "Triangle Fan Sequence", keyword: "TriangleFanSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0066,0028)", name: "Line Sequence", keyword: "LineSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0066,002A)", name: "Surface Count", keyword: "SurfaceCount", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,002B)", name: "Referenced Surface Sequence", keyword: "ReferencedSurfaceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,002C)", name: "Referenced Surface Number", keyword: "ReferencedSurfaceNumber", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,002D)", name: "Segment Surface Generation Algorithm Identification Sequence", keyword: "SegmentSurfaceGenerationAlgorithmIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,002E)", name: "Segment Surface Source Instance Sequence", keyword: "SegmentSurfaceSourceInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0066,002F)", name: "Algorithm Family Code Sequence", keyword: "AlgorithmFamilyCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0030)", name: "Algorithm Name Code Sequence", keyword: "AlgorithmNameCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0031)", name: "Algorithm Version", keyword: "AlgorithmVersion", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0032)", name: "Algorithm Parameters", keyword: "AlgorithmParameters", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0034)", name: "Facet Sequence", keyword: "FacetSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0066,0035)", name: "Surface Processing Algorithm Identification Sequence", keyword: "SurfaceProcessingAlgorithmIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0066,0036)", name: "Algorithm Name", keyword: "AlgorithmName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0037)", name: "Recommended Point Radius", keyword: "RecommendedPointRadius", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0038)", name: "Recommended Line Thickness", keyword: "RecommendedLineThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0040)", name: "Long Primitive Point Index List", keyword: "LongPrimitivePointIndexList", vr: Some(DicomVr::Ol), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0041)", name: "Long Triangle Point Index List", keyword: "LongTrianglePointIndexList", vr: Some(DicomVr::Ol), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0066,0042)", name: "Long Edge Point Index List", keyword: "LongEdgePointIndexList", vr: Some(DicomVr::Ol), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0066,0043)", name: "Long Vertex Point Index List", keyword: "LongVertexPointIndexList", vr: Some(DicomVr::Ol), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0066,0101)", name: "Track Set Sequence", keyword: "TrackSetSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0102)", name: "Track Sequence", keyword: "TrackSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0103)", name: "Recommended Display CIELab Value List", keyword: "RecommendedDisplayCIELabValueList", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0066,0104)", name: "Tracking Algorithm Identification Sequence", keyword: "TrackingAlgorithmIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0105)", name: "Track Set Number", keyword: "TrackSetNumber", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0106)", name: "Track Set Label", keyword: "TrackSetLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0107)", name: "Track Set Description", keyword: "TrackSetDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0108)", name: "Track Set Anatomical Type Code Sequence", keyword: "TrackSetAnatomicalTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0121)", name: "Measurements Sequence", keyword: "MeasurementsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0124)", name: "Track Set Statistics Sequence", keyword: "TrackSetStatisticsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0125)", name: "Floating Point Values", keyword: "FloatingPointValues", vr: Some(DicomVr::Of), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0129)", name: "Track Point Index List", keyword: "TrackPointIndexList", vr: Some(DicomVr::Ol), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0066,0130)", name: "Track Statistics Sequence", keyword: "TrackStatisticsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0132)", name: "Measurement Values Sequence", keyword: "MeasurementValuesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0066,0133)", name: "Diffusion Acquisition Code Sequence", keyword: "DiffusionAcquisitionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0066,0134)", name: "Diffusion Model Code Sequence", keyword: "DiffusionModelCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6210)", name: "Implant Size", keyword: "ImplantSize", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,6221)", name: "Implant Template Version", keyword: "ImplantTemplateVersion", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6222)", name: "Replaced Implant Template Sequence", keyword: "ReplacedImplantTemplateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,6223)", name: "Implant Type", keyword: "ImplantType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6224)", name: "Derivation Implant Template Sequence", keyword: "DerivationImplantTemplateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,6225)", name: "Original Implant Template Sequence", keyword: "OriginalImplantTemplateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,6226)", name: "Effective DateTime", keyword: "EffectiveDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6230)", name: "Implant Target Anatomy Sequence", keyword: "ImplantTargetAnatomySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,6260)", name: "Information From Manufacturer Sequence", keyword: "InformationFromManufacturerSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,6265)", name: "Notification From Manufacturer Sequence", keyword: "NotificationFromManufacturerSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,6270)", name: "Information Issue DateTime", keyword: "InformationIssueDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6280)", name: "Information Summary", keyword: "InformationSummary", vr: Some(DicomVr::St), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,62A0)", name: "Implant Regulatory Disapproval Code Sequence", keyword: "ImplantRegulatoryDisapprovalCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,62A5)", name: "Overall Template Spatial Tolerance", keyword: "OverallTemplateSpatialTolerance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0068,62C0)", name: "HPGL Document Sequence", keyword: "HPGLDocumentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,62D0)", name: "HPGL Document ID", keyword: "HPGLDocumentID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,62D5)", name: "HPGL Document Label", keyword: "HPGLDocumentLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,62E0)", name: "View Orientation Code Sequence", keyword: "ViewOrientationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,62F0)", name: "View Orientation Modifier Code Sequence", keyword: "ViewOrientationModifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,62F2)", name: "HPGL Document Scaling", keyword: "HPGLDocumentScaling", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6300)", name: "HPGL Document", keyword: "HPGLDocument", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6310)", name: "HPGL Contour Pen Number", keyword: "HPGLContourPenNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6320)", name: "HPGL Pen Sequence", keyword: "HPGLPenSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6330)", name: "HPGL Pen Number", keyword: "HPGLPenNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6340)", name: "HPGL Pen Label", keyword: "HPGLPenLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6345)", name: "HPGL Pen Description", keyword: "HPGLPenDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,6346)", name: "Recommended Rotation Point", keyword: "RecommendedRotationPoint", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6347)", name: "Bounding Rectangle", keyword: "BoundingRectangle", vr: Some(DicomVr::Fd), vm: "4", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6350)", name: "Implant Template 3D Model Surface Number", keyword: "ImplantTemplate3DModelSurfaceNumber", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6360)", name: "Surface Model Description Sequence", keyword: "SurfaceModelDescriptionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6380)", name: "Surface Model Label", keyword: "SurfaceModelLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6390)", name: "Surface Model Scaling Factor", keyword: "SurfaceModelScalingFactor", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,63A0)", name: "Materials Code Sequence", keyword: "MaterialsCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,63A4)", name: "Coating Materials Code Sequence", keyword: "CoatingMaterialsCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,63A8)", name: "Implant Type Code Sequence", keyword: "ImplantTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,63AC)", name: "Fixation Method Code Sequence", keyword: "FixationMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,63B0)", name: "Mating Feature Sets Sequence", keyword: "MatingFeatureSetsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,63C0)", name: "Mating Feature Set ID", keyword: "MatingFeatureSetID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,63D0)", name: "Mating Feature Set Label", keyword: "MatingFeatureSetLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,63E0)", name: "Mating Feature Sequence", keyword: "MatingFeatureSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,63F0)", name: "Mating Feature ID", keyword: "MatingFeatureID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6400)", name: "Mating Feature Degree of Freedom Sequence", keyword: "MatingFeatureDegreeOfFreedomSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,6410)", name: "Degree of Freedom ID", keyword: "DegreeOfFreedomID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6420)", name: "Degree of Freedom Type", keyword: "DegreeOfFreedomType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6430)", name: "2D Mating Feature Coordinates Sequence", keyword: "TwoDMatingFeatureCoordinatesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,6440)", name: "Referenced HPGL Document ID", keyword: "ReferencedHPGLDocumentID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6450)", name: "2D Mating Point", keyword: "TwoDMatingPoint", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6460)", name: "2D Mating Axes", keyword: "TwoDMatingAxes", vr: Some(DicomVr::Fd), vm: "4", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6470)", name: "2D Degree of Freedom Sequence", keyword: "TwoDDegreeOfFreedomSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,6490)", name: "3D Degree of Freedom Axis", keyword: "ThreeDDegreeOfFreedomAxis", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,64A0)", name: "Range of Freedom", keyword: "RangeOfFreedom", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0068,64C0)", name: "3D Mating Point", keyword: "ThreeDMatingPoint", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,64D0)", name: "3D Mating Axes", keyword: "ThreeDMatingAxes", vr: Some(DicomVr::Fd), vm: "9", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,64F0)", name: "2D Degree of Freedom Axis", keyword: "TwoDDegreeOfFreedomAxis", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6500)", name: "Planning Landmark Point Sequence", keyword: "PlanningLandmarkPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,6510)", name: "Planning Landmark Line Sequence", keyword: "PlanningLandmarkLineSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,6520)", name: "Planning Landmark Plane Sequence", keyword: "PlanningLandmarkPlaneSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,6530)", name: "Planning Landmark ID", keyword: "PlanningLandmarkID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6540)", name: "Planning Landmark Description", keyword: "PlanningLandmarkDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,6545)", name: "Planning Landmark Identification Code Sequence", keyword: "PlanningLandmarkIdentificationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0068,6550)", name: "2D Point Coordinates Sequence", keyword: "TwoDPointCoordinatesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,6560)", name: "2D Point Coordinates", keyword: "TwoDPointCoordinates", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6590)", name: "3D Point Coordinates", keyword: "ThreeDPointCoordinates", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,65A0)", name: "2D Line Coordinates Sequence", keyword: "TwoDLineCoordinatesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,65B0)", name: "2D Line Coordinates", keyword: "TwoDLineCoordinates", vr: Some(DicomVr::Fd), vm: "4", attr_type: "1" },
-    DicomAttribute { tag: "(0068,65D0)", name: "3D Line Coordinates", keyword: "ThreeDLineCoordinates", vr: Some(DicomVr::Fd), vm: "6", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,65E0)", name: "2D Plane Coordinates Sequence", keyword: "TwoDPlaneCoordinatesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,65F0)", name: "2D Plane Intersection", keyword: "TwoDPlaneIntersection", vr: Some(DicomVr::Fd), vm: "4", attr_type: "1" },
-    DicomAttribute { tag: "(0068,6610)", name: "3D Plane Origin", keyword: "ThreeDPlaneOrigin", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,6620)", name: "3D Plane Normal", keyword: "ThreeDPlaneNormal", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0068,7001)", name: "Model Modification", keyword: "ModelModification", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,7002)", name: "Model Mirroring", keyword: "ModelMirroring", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,7003)", name: "Model Usage Code Sequence", keyword: "ModelUsageCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,7004)", name: "Model Group UID", keyword: "ModelGroupUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0068,7005)", name: "Relative URI Reference Within Encapsulated Document", keyword: "RelativeURIReferenceWithinEncapsulatedDocument", vr: Some(DicomVr::Ur), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(006A,0001)", name: "Annotation Coordinate Type", keyword: "AnnotationCoordinateType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(006A,0002)", name: "Annotation Group Sequence", keyword: "AnnotationGroupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(006A,0003)", name: "Annotation Group UID", keyword: "AnnotationGroupUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(006A,0005)", name: "Annotation Group Label", keyword: "AnnotationGroupLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(006A,0006)", name: "Annotation Group Description", keyword: "AnnotationGroupDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(006A,0007)", name: "Annotation Group Generation Type", keyword: "AnnotationGroupGenerationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(006A,0008)", name: "Annotation Group Algorithm Identification Sequence", keyword: "AnnotationGroupAlgorithmIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(006A,0009)", name: "Annotation Property Category Code Sequence", keyword: "AnnotationPropertyCategoryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(006A,000A)", name: "Annotation Property Type Code Sequence", keyword: "AnnotationPropertyTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(006A,000B)", name: "Annotation Property Type Modifier Code Sequence", keyword: "AnnotationPropertyTypeModifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(006A,000C)", name: "Number of Annotations", keyword: "NumberOfAnnotations", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(006A,000D)", name: "Annotation Applies to All Optical Paths", keyword: "AnnotationAppliesToAllOpticalPaths", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(006A,000E)", name: "Referenced Optical Path Identifier", keyword: "ReferencedOpticalPathIdentifier", vr: Some(DicomVr::Sh), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(006A,000F)", name: "Annotation Applies to All Z Planes", keyword: "AnnotationAppliesToAllZPlanes", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(006A,0010)", name: "Common Z Coordinate Value", keyword: "CommonZCoordinateValue", vr: Some(DicomVr::Fd), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(006A,0011)", name: "Annotation Index List", keyword: "AnnotationIndexList", vr: Some(DicomVr::Ol), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0001)", name: "Graphic Annotation Sequence", keyword: "GraphicAnnotationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0002)", name: "Graphic Layer", keyword: "GraphicLayer", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0003)", name: "Bounding Box Annotation Units", keyword: "BoundingBoxAnnotationUnits", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0004)", name: "Anchor Point Annotation Units", keyword: "AnchorPointAnnotationUnits", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0005)", name: "Graphic Annotation Units", keyword: "GraphicAnnotationUnits", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0006)", name: "Unformatted Text Value", keyword: "UnformattedTextValue", vr: Some(DicomVr::St), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0008)", name: "Text Object Sequence", keyword: "TextObjectSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0009)", name: "Graphic Object Sequence", keyword: "GraphicObjectSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0010)", name: "Bounding Box Top Left Hand Corner", keyword: "BoundingBoxTopLeftHandCorner", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0011)", name: "Bounding Box Bottom Right Hand Corner", keyword: "BoundingBoxBottomRightHandCorner", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0012)", name: "Bounding Box Text Horizontal Justification", keyword: "BoundingBoxTextHorizontalJustification", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0014)", name: "Anchor Point", keyword: "AnchorPoint", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0015)", name: "Anchor Point Visibility", keyword: "AnchorPointVisibility", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0020)", name: "Graphic Dimensions", keyword: "GraphicDimensions", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0021)", name: "Number of Graphic Points", keyword: "NumberOfGraphicPoints", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0022)", name: "Graphic Data", keyword: "GraphicData", vr: Some(DicomVr::Fl), vm: "2-n", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0023)", name: "Graphic Type", keyword: "GraphicType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0024)", name: "Graphic Filled", keyword: "GraphicFilled", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0041)", name: "Image Horizontal Flip", keyword: "ImageHorizontalFlip", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0042)", name: "Image Rotation", keyword: "ImageRotation", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0052)", name: "Displayed Area Top Left Hand Corner", keyword: "DisplayedAreaTopLeftHandCorner", vr: Some(DicomVr::Sl), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0053)", name: "Displayed Area Bottom Right Hand Corner", keyword: "DisplayedAreaBottomRightHandCorner", vr: Some(DicomVr::Sl), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0070,005A)", name: "Displayed Area Selection Sequence", keyword: "DisplayedAreaSelectionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0060)", name: "Graphic Layer Sequence", keyword: "GraphicLayerSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0062)", name: "Graphic Layer Order", keyword: "GraphicLayerOrder", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0066)", name: "Graphic Layer Recommended Display Grayscale Value", keyword: "GraphicLayerRecommendedDisplayGrayscaleValue", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0068)", name: "Graphic Layer Description", keyword: "GraphicLayerDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0080)", name: "Content Label", keyword: "ContentLabel", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0081)", name: "Content Description", keyword: "ContentDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0070,0082)", name: "Presentation Creation Date", keyword: "PresentationCreationDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0083)", name: "Presentation Creation Time", keyword: "PresentationCreationTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0084)", name: "Content Creator's Name", keyword: "ContentCreatorName", vr: Some(DicomVr::Pn), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0086)", name: "Content Creator's Identification Code Sequence", keyword: "ContentCreatorIdentificationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0087)", name: "Alternate Content Description Sequence", keyword: "AlternateContentDescriptionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0100)", name: "Presentation Size Mode", keyword: "PresentationSizeMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0101)", name: "Presentation Pixel Spacing", keyword: "PresentationPixelSpacing", vr: Some(DicomVr::Ds), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0102)", name: "Presentation Pixel Aspect Ratio", keyword: "PresentationPixelAspectRatio", vr: Some(DicomVr::Is), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0103)", name: "Presentation Pixel Magnification Ratio", keyword: "PresentationPixelMagnificationRatio", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0207)", name: "Graphic Group Label", keyword: "GraphicGroupLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0208)", name: "Graphic Group Description", keyword: "GraphicGroupDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0209)", name: "Compound Graphic Sequence", keyword: "CompoundGraphicSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0226)", name: "Compound Graphic Instance ID", keyword: "CompoundGraphicInstanceID", vr: Some(DicomVr::Ul), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0227)", name: "Font Name", keyword: "FontName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0228)", name: "Font Name Type", keyword: "FontNameType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0229)", name: "CSS Font Name", keyword: "CSSFontName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0230)", name: "Rotation Angle", keyword: "RotationAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0231)", name: "Text Style Sequence", keyword: "TextStyleSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0232)", name: "Line Style Sequence", keyword: "LineStyleSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0233)", name: "Fill Style Sequence", keyword: "FillStyleSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0234)", name: "Graphic Group Sequence", keyword: "GraphicGroupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0241)", name: "Text Color CIELab Value", keyword: "TextColorCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0242)", name: "Horizontal Alignment", keyword: "HorizontalAlignment", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0243)", name: "Vertical Alignment", keyword: "VerticalAlignment", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0244)", name: "Shadow Style", keyword: "ShadowStyle", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0245)", name: "Shadow Offset X", keyword: "ShadowOffsetX", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0246)", name: "Shadow Offset Y", keyword: "ShadowOffsetY", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0247)", name: "Shadow Color CIELab Value", keyword: "ShadowColorCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0248)", name: "Underlined", keyword: "Underlined", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0249)", name: "Bold", keyword: "Bold", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0250)", name: "Italic", keyword: "Italic", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0251)", name: "Pattern On Color CIELab Value", keyword: "PatternOnColorCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0252)", name: "Pattern Off Color CIELab Value", keyword: "PatternOffColorCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0253)", name: "Line Thickness", keyword: "LineThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0254)", name: "Line Dashing Style", keyword: "LineDashingStyle", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0255)", name: "Line Pattern", keyword: "LinePattern", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0256)", name: "Fill Pattern", keyword: "FillPattern", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0257)", name: "Fill Mode", keyword: "FillMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0258)", name: "Shadow Opacity", keyword: "ShadowOpacity", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0261)", name: "Gap Length", keyword: "GapLength", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0262)", name: "Diameter of Visibility", keyword: "DiameterOfVisibility", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0273)", name: "Rotation Point", keyword: "RotationPoint", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0274)", name: "Tick Alignment", keyword: "TickAlignment", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0278)", name: "Show Tick Label", keyword: "ShowTickLabel", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0279)", name: "Tick Label Alignment", keyword: "TickLabelAlignment", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0282)", name: "Compound Graphic Units", keyword: "CompoundGraphicUnits", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0284)", name: "Pattern On Opacity", keyword: "PatternOnOpacity", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0285)", name: "Pattern Off Opacity", keyword: "PatternOffOpacity", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0287)", name: "Major Ticks Sequence", keyword: "MajorTicksSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0288)", name: "Tick Position", keyword: "TickPosition", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0289)", name: "Tick Label", keyword: "TickLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0294)", name: "Compound Graphic Type", keyword: "CompoundGraphicType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0295)", name: "Graphic Group ID", keyword: "GraphicGroupID", vr: Some(DicomVr::Ul), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0306)", name: "Shape Type", keyword: "ShapeType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0308)", name: "Registration Sequence", keyword: "RegistrationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0309)", name: "Matrix Registration Sequence", keyword: "MatrixRegistrationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,030A)", name: "Matrix Sequence", keyword: "MatrixSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,030B)", name: "Frame of Reference to Displayed Coordinate System Transformation Matrix", keyword: "FrameOfReferenceToDisplayedCoordinateSystemTransformationMatrix", vr: Some(DicomVr::Fd), vm: "16", attr_type: "3" },
-    DicomAttribute { tag: "(0070,030C)", name: "Frame of Reference Transformation Matrix Type", keyword: "FrameOfReferenceTransformationMatrixType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,030D)", name: "Registration Type Code Sequence", keyword: "RegistrationTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0070,030F)", name: "Fiducial Description", keyword: "FiducialDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0310)", name: "Fiducial Identifier", keyword: "FiducialIdentifier", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0311)", name: "Fiducial Identifier Code Sequence", keyword: "FiducialIdentifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,0312)", name: "Contour Uncertainty Radius", keyword: "ContourUncertaintyRadius", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0314)", name: "Used Fiducials Sequence", keyword: "UsedFiducialsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0315)", name: "Used RT Structure Set ROI Sequence", keyword: "UsedRTStructureSetROISequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0318)", name: "Graphic Coordinates Data Sequence", keyword: "GraphicCoordinatesDataSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,031A)", name: "Fiducial UID", keyword: "FiducialUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,031B)", name: "Referenced Fiducial UID", keyword: "ReferencedFiducialUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,031C)", name: "Fiducial Set Sequence", keyword: "FiducialSetSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,031E)", name: "Fiducial Sequence", keyword: "FiducialSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,031F)", name: "Fiducials Property Category Code Sequence", keyword: "FiducialsPropertyCategoryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0401)", name: "Graphic Layer Recommended Display CIELab Value", keyword: "GraphicLayerRecommendedDisplayCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0070,0402)", name: "Blending Sequence", keyword: "BlendingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0403)", name: "Relative Opacity", keyword: "RelativeOpacity", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,0404)", name: "Referenced Spatial Registration Sequence", keyword: "ReferencedSpatialRegistrationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0070,0405)", name: "Blending Position", keyword: "BlendingPosition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1101)", name: "Presentation Display Collection UID", keyword: "PresentationDisplayCollectionUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,1102)", name: "Presentation Sequence Collection UID", keyword: "PresentationSequenceCollectionUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1103)", name: "Presentation Sequence Position Index", keyword: "PresentationSequencePositionIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1104)", name: "Rendered Image Reference Sequence", keyword: "RenderedImageReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,1201)", name: "Volumetric Presentation State Input Sequence", keyword: "VolumetricPresentationStateInputSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1202)", name: "Presentation Input Type", keyword: "PresentationInputType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1203)", name: "Input Sequence Position Index", keyword: "InputSequencePositionIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1204)", name: "Crop", keyword: "Crop", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1205)", name: "Cropping Specification Index", keyword: "CroppingSpecificationIndex", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1207)", name: "Volumetric Presentation Input Number", keyword: "VolumetricPresentationInputNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1209)", name: "Volumetric Presentation Input Set UID", keyword: "VolumetricPresentationInputSetUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,120A)", name: "Volumetric Presentation Input Set Sequence", keyword: "VolumetricPresentationInputSetSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,120B)", name: "Global Crop", keyword: "GlobalCrop", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,120C)", name: "Global Cropping Specification Index", keyword: "GlobalCroppingSpecificationIndex", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,120D)", name: "Rendering Method", keyword: "RenderingMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1301)", name: "Volume Cropping Sequence", keyword: "VolumeCroppingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1302)", name: "Volume Cropping Method", keyword: "VolumeCroppingMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1303)", name: "Bounding Box Crop", keyword: "BoundingBoxCrop", vr: Some(DicomVr::Fd), vm: "6", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1304)", name: "Oblique Cropping Plane Sequence", keyword: "ObliqueCroppingPlaneSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1305)", name: "Plane", keyword: "Plane", vr: Some(DicomVr::Fd), vm: "4", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1306)", name: "Plane Normal", keyword: "PlaneNormal", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1309)", name: "Cropping Specification Number", keyword: "CroppingSpecificationNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1501)", name: "Multi-Planar Reconstruction Style", keyword: "MultiPlanarReconstructionStyle", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1502)", name: "MPR Thickness Type", keyword: "MPRThicknessType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1503)", name: "MPR Slab Thickness", keyword: "MPRSlabThickness", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1505)", name: "MPR Top Left Hand Corner", keyword: "MPRTopLeftHandCorner", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1507)", name: "MPR View Width Direction", keyword: "MPRViewWidthDirection", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1508)", name: "MPR View Width", keyword: "MPRViewWidth", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,150C)", name: "Number of Volumetric Curve Points", keyword: "NumberOfVolumetricCurvePoints", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,150D)", name: "Volumetric Curve Points", keyword: "VolumetricCurvePoints", vr: Some(DicomVr::Od), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1511)", name: "MPR View Height Direction", keyword: "MPRViewHeightDirection", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1512)", name: "MPR View Height", keyword: "MPRViewHeight", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1602)", name: "Render Projection", keyword: "RenderProjection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1603)", name: "Viewpoint Position", keyword: "ViewpointPosition", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1604)", name: "Viewpoint LookAt Point", keyword: "ViewpointLookAtPoint", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1605)", name: "Viewpoint Up Direction", keyword: "ViewpointUpDirection", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1606)", name: "Render Field of View", keyword: "RenderFieldOfView", vr: Some(DicomVr::Fd), vm: "6", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1607)", name: "Sampling Step Size", keyword: "SamplingStepSize", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,1701)", name: "Shading Style", keyword: "ShadingStyle", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1702)", name: "Ambient Reflection Intensity", keyword: "AmbientReflectionIntensity", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1703)", name: "Light Direction", keyword: "LightDirection", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1704)", name: "Diffuse Reflection Intensity", keyword: "DiffuseReflectionIntensity", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,1705)", name: "Specular Reflection Intensity", keyword: "SpecularReflectionIntensity", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,1706)", name: "Shininess", keyword: "Shininess", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,1801)", name: "Presentation State Classification Component Sequence", keyword: "PresentationStateClassificationComponentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0070,1802)", name: "Component Type", keyword: "ComponentType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1803)", name: "Component Input Sequence", keyword: "ComponentInputSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1804)", name: "Volumetric Presentation Input Index", keyword: "VolumetricPresentationInputIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1805)", name: "Presentation State Compositor Component Sequence", keyword: "PresentationStateCompositorComponentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0070,1806)", name: "Weighting Transfer Function Sequence", keyword: "WeightingTransferFunctionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1901)", name: "Volumetric Annotation Sequence", keyword: "VolumetricAnnotationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1903)", name: "Referenced Structured Context Sequence", keyword: "ReferencedStructuredContextSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,1904)", name: "Referenced Content Item", keyword: "ReferencedContentItem", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1905)", name: "Volumetric Presentation Input Annotation Sequence", keyword: "VolumetricPresentationInputAnnotationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1907)", name: "Annotation Clipping", keyword: "AnnotationClipping", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1A01)", name: "Presentation Animation Style", keyword: "PresentationAnimationStyle", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1A03)", name: "Recommended Animation Rate", keyword: "RecommendedAnimationRate", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,1A04)", name: "Animation Curve Sequence", keyword: "AnimationCurveSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1A05)", name: "Animation Step Size", keyword: "AnimationStepSize", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1A06)", name: "Swivel Range", keyword: "SwivelRange", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1A07)", name: "Volumetric Curve Up Directions", keyword: "VolumetricCurveUpDirections", vr: Some(DicomVr::Od), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1A08)", name: "Volume Stream Sequence", keyword: "VolumeStreamSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1A09)", name: "RGBA Transfer Function Description", keyword: "RGBATransferFunctionDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0070,1B01)", name: "Advanced Blending Sequence", keyword: "AdvancedBlendingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1B02)", name: "Blending Input Number", keyword: "BlendingInputNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1B03)", name: "Blending Display Input Sequence", keyword: "BlendingDisplayInputSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1B04)", name: "Blending Display Sequence", keyword: "BlendingDisplaySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1B06)", name: "Blending Mode", keyword: "BlendingMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1B07)", name: "Time Series Blending", keyword: "TimeSeriesBlending", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1B08)", name: "Geometry for Display", keyword: "GeometryForDisplay", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1B11)", name: "Threshold Sequence", keyword: "ThresholdSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0070,1B12)", name: "Threshold Value Sequence", keyword: "ThresholdValueSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1B13)", name: "Threshold Type", keyword: "ThresholdType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0070,1B14)", name: "Threshold Value", keyword: "ThresholdValue", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0002)", name: "Hanging Protocol Name", keyword: "HangingProtocolName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0004)", name: "Hanging Protocol Description", keyword: "HangingProtocolDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0006)", name: "Hanging Protocol Level", keyword: "HangingProtocolLevel", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0008)", name: "Hanging Protocol Creator", keyword: "HangingProtocolCreator", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,000A)", name: "Hanging Protocol Creation DateTime", keyword: "HangingProtocolCreationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,000C)", name: "Hanging Protocol Definition Sequence", keyword: "HangingProtocolDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,000E)", name: "Hanging Protocol User Identification Code Sequence", keyword: "HangingProtocolUserIdentificationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0072,0010)", name: "Hanging Protocol User Group Name", keyword: "HangingProtocolUserGroupName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0012)", name: "Source Hanging Protocol Sequence", keyword: "SourceHangingProtocolSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0014)", name: "Number of Priors Referenced", keyword: "NumberOfPriorsReferenced", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0020)", name: "Image Sets Sequence", keyword: "ImageSetsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0022)", name: "Image Set Selector Sequence", keyword: "ImageSetSelectorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0024)", name: "Image Set Selector Usage Flag", keyword: "ImageSetSelectorUsageFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0026)", name: "Selector Attribute", keyword: "SelectorAttribute", vr: Some(DicomVr::At), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0028)", name: "Selector Value Number", keyword: "SelectorValueNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0030)", name: "Time Based Image Sets Sequence", keyword: "TimeBasedImageSetsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0032)", name: "Image Set Number", keyword: "ImageSetNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0034)", name: "Image Set Selector Category", keyword: "ImageSetSelectorCategory", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0038)", name: "Relative Time", keyword: "RelativeTime", vr: Some(DicomVr::Us), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,003A)", name: "Relative Time Units", keyword: "RelativeTimeUnits", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,003C)", name: "Abstract Prior Value", keyword: "AbstractPriorValue", vr: Some(DicomVr::Ss), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,003E)", name: "Abstract Prior Code Sequence", keyword: "AbstractPriorCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0040)", name: "Image Set Label", keyword: "ImageSetLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0050)", name: "Selector Attribute VR", keyword: "SelectorAttributeVR", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0052)", name: "Selector Sequence Pointer", keyword: "SelectorSequencePointer", vr: Some(DicomVr::At), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0054)", name: "Selector Sequence Pointer Private Creator", keyword: "SelectorSequencePointerPrivateCreator", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0056)", name: "Selector Attribute Private Creator", keyword: "SelectorAttributePrivateCreator", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,005E)", name: "Selector AE Value", keyword: "SelectorAEValue", vr: Some(DicomVr::Ae), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,005F)", name: "Selector AS Value", keyword: "SelectorASValue", vr: Some(DicomVr::As), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0060)", name: "Selector AT Value", keyword: "SelectorATValue", vr: Some(DicomVr::At), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0061)", name: "Selector DA Value", keyword: "SelectorDAValue", vr: Some(DicomVr::Da), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0062)", name: "Selector CS Value", keyword: "SelectorCSValue", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0063)", name: "Selector DT Value", keyword: "SelectorDTValue", vr: Some(DicomVr::Dt), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0064)", name: "Selector IS Value", keyword: "SelectorISValue", vr: Some(DicomVr::Is), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0065)", name: "Selector OB Value", keyword: "SelectorOBValue", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0066)", name: "Selector LO Value", keyword: "SelectorLOValue", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0067)", name: "Selector OF Value", keyword: "SelectorOFValue", vr: Some(DicomVr::Of), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0068)", name: "Selector LT Value", keyword: "SelectorLTValue", vr: Some(DicomVr::Lt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0069)", name: "Selector OW Value", keyword: "SelectorOWValue", vr: Some(DicomVr::Ow), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,006A)", name: "Selector PN Value", keyword: "SelectorPNValue", vr: Some(DicomVr::Pn), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,006B)", name: "Selector TM Value", keyword: "SelectorTMValue", vr: Some(DicomVr::Tm), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,006C)", name: "Selector SH Value", keyword: "SelectorSHValue", vr: Some(DicomVr::Sh), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,006D)", name: "Selector UN Value", keyword: "SelectorUNValue", vr: Some(DicomVr::Un), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,006E)", name: "Selector ST Value", keyword: "SelectorSTValue", vr: Some(DicomVr::St), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,006F)", name: "Selector UC Value", keyword: "SelectorUCValue", vr: Some(DicomVr::Uc), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0070)", name: "Selector UT Value", keyword: "SelectorUTValue", vr: Some(DicomVr::Ut), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0071)", name: "Selector UR Value", keyword: "SelectorURValue", vr: Some(DicomVr::Ur), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0072)", name: "Selector DS Value", keyword: "SelectorDSValue", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0073)", name: "Selector OD Value", keyword: "SelectorODValue", vr: Some(DicomVr::Od), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0074)", name: "Selector FD Value", keyword: "SelectorFDValue", vr: Some(DicomVr::Fd), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0075)", name: "Selector OL Value", keyword: "SelectorOLValue", vr: Some(DicomVr::Ol), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0076)", name: "Selector FL Value", keyword: "SelectorFLValue", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0078)", name: "Selector UL Value", keyword: "SelectorULValue", vr: Some(DicomVr::Ul), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,007A)", name: "Selector US Value", keyword: "SelectorUSValue", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,007C)", name: "Selector SL Value", keyword: "SelectorSLValue", vr: Some(DicomVr::Sl), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,007E)", name: "Selector SS Value", keyword: "SelectorSSValue", vr: Some(DicomVr::Ss), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,007F)", name: "Selector UI Value", keyword: "SelectorUIValue", vr: Some(DicomVr::Ui), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0080)", name: "Selector Code Sequence Value", keyword: "SelectorCodeSequenceValue", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0081)", name: "Selector OV Value", keyword: "SelectorOVValue", vr: Some(DicomVr::Ov), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0082)", name: "Selector SV Value", keyword: "SelectorSVValue", vr: Some(DicomVr::Sv), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0083)", name: "Selector UV Value", keyword: "SelectorUVValue", vr: Some(DicomVr::Uv), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0100)", name: "Number of Screens", keyword: "NumberOfScreens", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0102)", name: "Nominal Screen Definition Sequence", keyword: "NominalScreenDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0104)", name: "Number of Vertical Pixels", keyword: "NumberOfVerticalPixels", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0106)", name: "Number of Horizontal Pixels", keyword: "NumberOfHorizontalPixels", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0108)", name: "Display Environment Spatial Position", keyword: "DisplayEnvironmentSpatialPosition", vr: Some(DicomVr::Fd), vm: "4", attr_type: "1" },
-    DicomAttribute { tag: "(0072,010A)", name: "Screen Minimum Grayscale Bit Depth", keyword: "ScreenMinimumGrayscaleBitDepth", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,010C)", name: "Screen Minimum Color Bit Depth", keyword: "ScreenMinimumColorBitDepth", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,010E)", name: "Application Maximum Repaint Time", keyword: "ApplicationMaximumRepaintTime", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0200)", name: "Display Sets Sequence", keyword: "DisplaySetsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0202)", name: "Display Set Number", keyword: "DisplaySetNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0203)", name: "Display Set Label", keyword: "DisplaySetLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0204)", name: "Display Set Presentation Group", keyword: "DisplaySetPresentationGroup", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0206)", name: "Display Set Presentation Group Description", keyword: "DisplaySetPresentationGroupDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0208)", name: "Partial Data Display Handling", keyword: "PartialDataDisplayHandling", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0072,0210)", name: "Synchronized Scrolling Sequence", keyword: "SynchronizedScrollingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0212)", name: "Display Set Scrolling Group", keyword: "DisplaySetScrollingGroup", vr: Some(DicomVr::Us), vm: "2-n", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0214)", name: "Navigation Indicator Sequence", keyword: "NavigationIndicatorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0216)", name: "Navigation Display Set", keyword: "NavigationDisplaySet", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0218)", name: "Reference Display Sets", keyword: "ReferenceDisplaySets", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0300)", name: "Image Boxes Sequence", keyword: "ImageBoxesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0302)", name: "Image Box Number", keyword: "ImageBoxNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0304)", name: "Image Box Layout Type", keyword: "ImageBoxLayoutType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0306)", name: "Image Box Tile Horizontal Dimension", keyword: "ImageBoxTileHorizontalDimension", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0308)", name: "Image Box Tile Vertical Dimension", keyword: "ImageBoxTileVerticalDimension", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0310)", name: "Image Box Scroll Direction", keyword: "ImageBoxScrollDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0312)", name: "Image Box Small Scroll Type", keyword: "ImageBoxSmallScrollType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0072,0314)", name: "Image Box Small Scroll Amount", keyword: "ImageBoxSmallScrollAmount", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0316)", name: "Image Box Large Scroll Type", keyword: "ImageBoxLargeScrollType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0072,0318)", name: "Image Box Large Scroll Amount", keyword: "ImageBoxLargeScrollAmount", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0320)", name: "Image Box Overlap Priority", keyword: "ImageBoxOverlapPriority", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0330)", name: "Cine Relative to Real-Time", keyword: "CineRelativeToRealTime", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0400)", name: "Filter Operations Sequence", keyword: "FilterOperationsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0072,0402)", name: "Filter-by Category", keyword: "FilterByCategory", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0404)", name: "Filter-by Attribute Presence", keyword: "FilterByAttributePresence", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0406)", name: "Filter-by Operator", keyword: "FilterByOperator", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0420)", name: "Structured Display Background CIELab Value", keyword: "StructuredDisplayBackgroundCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0421)", name: "Empty Image Box CIELab Value", keyword: "EmptyImageBoxCIELabValue", vr: Some(DicomVr::Us), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0422)", name: "Structured Display Image Box Sequence", keyword: "StructuredDisplayImageBoxSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0424)", name: "Structured Display Text Box Sequence", keyword: "StructuredDisplayTextBoxSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0427)", name: "Referenced First Frame Sequence", keyword: "ReferencedFirstFrameSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0072,0430)", name: "Image Box Synchronization Sequence", keyword: "ImageBoxSynchronizationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0432)", name: "Synchronized Image Box List", keyword: "SynchronizedImageBoxList", vr: Some(DicomVr::Us), vm: "2-n", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0434)", name: "Type of Synchronization", keyword: "TypeOfSynchronization", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0500)", name: "Blending Operation Type", keyword: "BlendingOperationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0510)", name: "Reformatting Operation Type", keyword: "ReformattingOperationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0512)", name: "Reformatting Thickness", keyword: "ReformattingThickness", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0514)", name: "Reformatting Interval", keyword: "ReformattingInterval", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0516)", name: "Reformatting Operation Initial View Direction", keyword: "ReformattingOperationInitialViewDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0520)", name: "3D Rendering Type", keyword: "ThreeDRenderingType", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0600)", name: "Sorting Operations Sequence", keyword: "SortingOperationsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0072,0602)", name: "Sort-by Category", keyword: "SortByCategory", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0604)", name: "Sorting Direction", keyword: "SortingDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0072,0700)", name: "Display Set Patient Orientation", keyword: "DisplaySetPatientOrientation", vr: Some(DicomVr::Cs), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0702)", name: "VOI Type", keyword: "VOIType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0704)", name: "Pseudo-Color Type", keyword: "PseudoColorType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0705)", name: "Pseudo-Color Palette Instance Reference Sequence", keyword: "PseudoColorPaletteInstanceReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0072,0706)", name: "Show Grayscale Inverted", keyword: "ShowGrayscaleInverted", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0710)", name: "Show Image True Size Flag", keyword: "ShowImageTrueSizeFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0712)", name: "Show Graphic Annotation Flag", keyword: "ShowGraphicAnnotationFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0714)", name: "Show Patient Demographics Flag", keyword: "ShowPatientDemographicsFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0716)", name: "Show Acquisition Techniques Flag", keyword: "ShowAcquisitionTechniquesFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0717)", name: "Display Set Horizontal Justification", keyword: "DisplaySetHorizontalJustification", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0072,0718)", name: "Display Set Vertical Justification", keyword: "DisplaySetVerticalJustification", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0074,0120)", name: "Continuation Start Meterset", keyword: "ContinuationStartMeterset", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0074,0121)", name: "Continuation End Meterset", keyword: "ContinuationEndMeterset", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0074,1020)", name: "Beam Task Sequence", keyword: "BeamTaskSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0074,1022)", name: "Beam Task Type", keyword: "BeamTaskType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0074,1025)", name: "Autosequence Flag", keyword: "AutosequenceFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0074,1026)", name: "Table Top Vertical Adjusted Position", keyword: "TableTopVerticalAdjustedPosition", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0074,1027)", name: "Table Top Longitudinal Adjusted Position", keyword: "TableTopLongitudinalAdjustedPosition", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0074,1028)", name: "Table Top Lateral Adjusted Position", keyword: "TableTopLateralAdjustedPosition", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0074,102A)", name: "Patient Support Adjusted Angle", keyword: "PatientSupportAdjustedAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0074,102B)", name: "Table Top Eccentric Adjusted Angle", keyword: "TableTopEccentricAdjustedAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0074,102C)", name: "Table Top Pitch Adjusted Angle", keyword: "TableTopPitchAdjustedAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0074,102D)", name: "Table Top Roll Adjusted Angle", keyword: "TableTopRollAdjustedAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0074,1030)", name: "Delivery Verification Image Sequence", keyword: "DeliveryVerificationImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0074,1032)", name: "Verification Image Timing", keyword: "VerificationImageTiming", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0074,1034)", name: "Double Exposure Flag", keyword: "DoubleExposureFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0074,1036)", name: "Double Exposure Ordering", keyword: "DoubleExposureOrdering", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0074,1040)", name: "Related Reference RT Image Sequence", keyword: "RelatedReferenceRTImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0074,1057)", name: "Selector Sequence Pointer Items", keyword: "SelectorSequencePointerItems", vr: Some(DicomVr::Is), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(0074,1212)", name: "Performed Processing Parameters Sequence", keyword: "PerformedProcessingParametersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0074,1324)", name: "Beam Order Index", keyword: "BeamOrderIndex", vr: Some(DicomVr::Ul), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0074,1338)", name: "Double Exposure Meterset", keyword: "DoubleExposureMeterset", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(0074,133A)", name: "Double Exposure Field Delta", keyword: "DoubleExposureFieldDelta", vr: Some(DicomVr::Fd), vm: "4", attr_type: "2C" },
-    DicomAttribute { tag: "(0074,1401)", name: "Brachy Task Sequence", keyword: "BrachyTaskSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0074,1402)", name: "Continuation Start Total Reference Air Kerma", keyword: "ContinuationStartTotalReferenceAirKerma", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0074,1403)", name: "Continuation End Total Reference Air Kerma", keyword: "ContinuationEndTotalReferenceAirKerma", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0074,1404)", name: "Continuation Pulse Number", keyword: "ContinuationPulseNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0074,1405)", name: "Channel Delivery Order Sequence", keyword: "ChannelDeliveryOrderSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0074,1406)", name: "Referenced Channel Number", keyword: "ReferencedChannelNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0074,1407)", name: "Start Cumulative Time Weight", keyword: "StartCumulativeTimeWeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0074,1408)", name: "End Cumulative Time Weight", keyword: "EndCumulativeTimeWeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0074,1409)", name: "Omitted Channel Sequence", keyword: "OmittedChannelSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0074,140A)", name: "Reason for Channel Omission", keyword: "ReasonForChannelOmission", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0074,140B)", name: "Reason for Channel Omission Description", keyword: "ReasonForChannelOmissionDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0074,140C)", name: "Channel Delivery Order Index", keyword: "ChannelDeliveryOrderIndex", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0074,140D)", name: "Channel Delivery Continuation Sequence", keyword: "ChannelDeliveryContinuationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0074,140E)", name: "Omitted Application Setup Sequence", keyword: "OmittedApplicationSetupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0076,0001)", name: "Implant Assembly Template Name", keyword: "ImplantAssemblyTemplateName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0076,0003)", name: "Implant Assembly Template Issuer", keyword: "ImplantAssemblyTemplateIssuer", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0006)", name: "Implant Assembly Template Version", keyword: "ImplantAssemblyTemplateVersion", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0076,0008)", name: "Replaced Implant Assembly Template Sequence", keyword: "ReplacedImplantAssemblyTemplateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0076,000A)", name: "Implant Assembly Template Type", keyword: "ImplantAssemblyTemplateType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,000C)", name: "Original Implant Assembly Template Sequence", keyword: "OriginalImplantAssemblyTemplateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0076,000E)", name: "Derivation Implant Assembly Template Sequence", keyword: "DerivationImplantAssemblyTemplateSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0076,0010)", name: "Implant Assembly Template Target Anatomy Sequence", keyword: "ImplantAssemblyTemplateTargetAnatomySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0020)", name: "Procedure Type Code Sequence", keyword: "ProcedureTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0030)", name: "Surgical Technique", keyword: "SurgicalTechnique", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0076,0032)", name: "Component Types Sequence", keyword: "ComponentTypesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0034)", name: "Component Type Code Sequence", keyword: "ComponentTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0036)", name: "Exclusive Component Type", keyword: "ExclusiveComponentType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0038)", name: "Mandatory Component Type", keyword: "MandatoryComponentType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0040)", name: "Component Sequence", keyword: "ComponentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0055)", name: "Component ID", keyword: "ComponentID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0060)", name: "Component Assembly Sequence", keyword: "ComponentAssemblySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0076,0070)", name: "Component 1 Referenced ID", keyword: "Component1ReferencedID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0080)", name: "Component 1 Referenced Mating Feature Set ID", keyword: "Component1ReferencedMatingFeatureSetID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,0090)", name: "Component 1 Referenced Mating Feature ID", keyword: "Component1ReferencedMatingFeatureID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,00A0)", name: "Component 2 Referenced ID", keyword: "Component2ReferencedID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,00B0)", name: "Component 2 Referenced Mating Feature Set ID", keyword: "Component2ReferencedMatingFeatureSetID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0076,00C0)", name: "Component 2 Referenced Mating Feature ID", keyword: "Component2ReferencedMatingFeatureID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0078,0001)", name: "Implant Template Group Name", keyword: "ImplantTemplateGroupName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0078,0010)", name: "Implant Template Group Description", keyword: "ImplantTemplateGroupDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0078,0020)", name: "Implant Template Group Issuer", keyword: "ImplantTemplateGroupIssuer", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0078,0024)", name: "Implant Template Group Version", keyword: "ImplantTemplateGroupVersion", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0078,0026)", name: "Replaced Implant Template Group Sequence", keyword: "ReplacedImplantTemplateGroupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0078,0028)", name: "Implant Template Group Target Anatomy Sequence", keyword: "ImplantTemplateGroupTargetAnatomySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0078,002A)", name: "Implant Template Group Members Sequence", keyword: "ImplantTemplateGroupMembersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0078,002E)", name: "Implant Template Group Member ID", keyword: "ImplantTemplateGroupMemberID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0078,0050)", name: "3D Implant Template Group Member Matching Point", keyword: "ThreeDImplantTemplateGroupMemberMatchingPoint", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(0078,0060)", name: "3D Implant Template Group Member Matching Axes", keyword: "ThreeDImplantTemplateGroupMemberMatchingAxes", vr: Some(DicomVr::Fd), vm: "9", attr_type: "1C" },
-    DicomAttribute { tag: "(0078,0070)", name: "Implant Template Group Member Matching 2D Coordinates Sequence", keyword: "ImplantTemplateGroupMemberMatching2DCoordinatesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0078,0090)", name: "2D Implant Template Group Member Matching Point", keyword: "TwoDImplantTemplateGroupMemberMatchingPoint", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(0078,00A0)", name: "2D Implant Template Group Member Matching Axes", keyword: "TwoDImplantTemplateGroupMemberMatchingAxes", vr: Some(DicomVr::Fd), vm: "4", attr_type: "1" },
-    DicomAttribute { tag: "(0078,00B0)", name: "Implant Template Group Variation Dimension Sequence", keyword: "ImplantTemplateGroupVariationDimensionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0078,00B2)", name: "Implant Template Group Variation Dimension Name", keyword: "ImplantTemplateGroupVariationDimensionName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0078,00B4)", name: "Implant Template Group Variation Dimension Rank Sequence", keyword: "ImplantTemplateGroupVariationDimensionRankSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0078,00B6)", name: "Referenced Implant Template Group Member ID", keyword: "ReferencedImplantTemplateGroupMemberID", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0078,00B8)", name: "Implant Template Group Variation Dimension Rank", keyword: "ImplantTemplateGroupVariationDimensionRank", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0080,0001)", name: "Surface Scan Acquisition Type Code Sequence", keyword: "SurfaceScanAcquisitionTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0080,0002)", name: "Surface Scan Mode Code Sequence", keyword: "SurfaceScanModeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0080,0003)", name: "Registration Method Code Sequence", keyword: "RegistrationMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0080,0004)", name: "Shot Duration Time", keyword: "ShotDurationTime", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0080,0005)", name: "Shot Offset Time", keyword: "ShotOffsetTime", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0080,0006)", name: "Surface Point Presentation Value Data", keyword: "SurfacePointPresentationValueData", vr: Some(DicomVr::Us), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(0080,0007)", name: "Surface Point Color CIELab Value Data", keyword: "SurfacePointColorCIELabValueData", vr: Some(DicomVr::Us), vm: "3-3n", attr_type: "3" },
-    DicomAttribute { tag: "(0080,0008)", name: "UV Mapping Sequence", keyword: "UVMappingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0080,0009)", name: "Texture Label", keyword: "TextureLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0080,0010)", name: "U Value Data", keyword: "UValueData", vr: Some(DicomVr::Of), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0080,0011)", name: "V Value Data", keyword: "VValueData", vr: Some(DicomVr::Of), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0080,0012)", name: "Referenced Texture Sequence", keyword: "ReferencedTextureSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0080,0013)", name: "Referenced Surface Data Sequence", keyword: "ReferencedSurfaceDataSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0082,0001)", name: "Assessment Summary", keyword: "AssessmentSummary", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,0003)", name: "Assessment Summary Description", keyword: "AssessmentSummaryDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0082,0004)", name: "Assessed SOP Instance Sequence", keyword: "AssessedSOPInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,0005)", name: "Referenced Comparison SOP Instance Sequence", keyword: "ReferencedComparisonSOPInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0082,0006)", name: "Number of Assessment Observations", keyword: "NumberOfAssessmentObservations", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,0007)", name: "Assessment Observations Sequence", keyword: "AssessmentObservationsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0082,0008)", name: "Observation Significance", keyword: "ObservationSignificance", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,000A)", name: "Observation Description", keyword: "ObservationDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,000C)", name: "Structured Constraint Observation Sequence", keyword: "StructuredConstraintObservationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0082,0010)", name: "Assessed Attribute Value Sequence", keyword: "AssessedAttributeValueSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,0016)", name: "Assessment Set ID", keyword: "AssessmentSetID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0082,0017)", name: "Assessment Requester Sequence", keyword: "AssessmentRequesterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0082,0018)", name: "Selector Attribute Name", keyword: "SelectorAttributeName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,0019)", name: "Selector Attribute Keyword", keyword: "SelectorAttributeKeyword", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0082,0021)", name: "Assessment Type Code Sequence", keyword: "AssessmentTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,0022)", name: "Observation Basis Code Sequence", keyword: "ObservationBasisCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,0023)", name: "Assessment Label", keyword: "AssessmentLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,0032)", name: "Constraint Type", keyword: "ConstraintType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0082,0033)", name: "Specification Selection Guidance", keyword: "SpecificationSelectionGuidance", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0082,0034)", name: "Constraint Value Sequence", keyword: "ConstraintValueSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0082,0035)", name: "Recommended Default Value Sequence", keyword: "RecommendedDefaultValueSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0082,0036)", name: "Constraint Violation Significance", keyword: "ConstraintViolationSignificance", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0082,0037)", name: "Constraint Violation Condition", keyword: "ConstraintViolationCondition", vr: Some(DicomVr::Ut), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0082,0038)", name: "Modifiable Constraint Flag", keyword: "ModifiableConstraintFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0088,0130)", name: "Storage Media File-set ID", keyword: "StorageMediaFileSetID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0088,0140)", name: "Storage Media File-set UID", keyword: "StorageMediaFileSetUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0088,0200)", name: "Icon Image Sequence", keyword: "IconImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0100,0410)", name: "SOP Instance Status", keyword: "SOPInstanceStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0100,0420)", name: "SOP Authorization DateTime", keyword: "SOPAuthorizationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0100,0424)", name: "SOP Authorization Comment", keyword: "SOPAuthorizationComment", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0100,0426)", name: "Authorization Equipment Certification Number", keyword: "AuthorizationEquipmentCertificationNumber", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0400,0005)", name: "MAC ID Number", keyword: "MACIDNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0010)", name: "MAC Calculation Transfer Syntax UID", keyword: "MACCalculationTransferSyntaxUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0015)", name: "MAC Algorithm", keyword: "MACAlgorithm", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0020)", name: "Data Elements Signed", keyword: "DataElementsSigned", vr: Some(DicomVr::At), vm: "1-n", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0100)", name: "Digital Signature UID", keyword: "DigitalSignatureUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0105)", name: "Digital Signature DateTime", keyword: "DigitalSignatureDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0110)", name: "Certificate Type", keyword: "CertificateType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0115)", name: "Certificate of Signer", keyword: "CertificateOfSigner", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0120)", name: "Signature", keyword: "Signature", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0305)", name: "Certified Timestamp Type", keyword: "CertifiedTimestampType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0400,0310)", name: "Certified Timestamp", keyword: "CertifiedTimestamp", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0400,0401)", name: "Digital Signature Purpose Code Sequence", keyword: "DigitalSignaturePurposeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0400,0402)", name: "Referenced Digital Signature Sequence", keyword: "ReferencedDigitalSignatureSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0400,0403)", name: "Referenced SOP Instance MAC Sequence", keyword: "ReferencedSOPInstanceMACSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0400,0404)", name: "MAC", keyword: "MAC", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0500)", name: "Encrypted Attributes Sequence", keyword: "EncryptedAttributesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(0400,0510)", name: "Encrypted Content Transfer Syntax UID", keyword: "EncryptedContentTransferSyntaxUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0520)", name: "Encrypted Content", keyword: "EncryptedContent", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0550)", name: "Modified Attributes Sequence", keyword: "ModifiedAttributesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0551)", name: "Nonconforming Modified Attributes Sequence", keyword: "NonconformingModifiedAttributesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0400,0552)", name: "Nonconforming Data Element Value", keyword: "NonconformingDataElementValue", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0561)", name: "Original Attributes Sequence", keyword: "OriginalAttributesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0400,0562)", name: "Attribute Modification DateTime", keyword: "AttributeModificationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0563)", name: "Modifying System", keyword: "ModifyingSystem", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0564)", name: "Source of Previous Values", keyword: "SourceOfPreviousValues", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(0400,0565)", name: "Reason for the Attribute Modification", keyword: "ReasonForTheAttributeModification", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0400,0600)", name: "Instance Origin Status", keyword: "InstanceOriginStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(2010,015E)", name: "Illumination", keyword: "Illumination", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(2010,0160)", name: "Reflected Ambient Light", keyword: "ReflectedAmbientLight", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(2050,0010)", name: "Presentation LUT Sequence", keyword: "PresentationLUTSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(2050,0020)", name: "Presentation LUT Shape", keyword: "PresentationLUTShape", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(2100,0140)", name: "Destination AE", keyword: "DestinationAE", vr: Some(DicomVr::Ae), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(2200,0002)", name: "Label Text", keyword: "LabelText", vr: Some(DicomVr::Ut), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(2200,0005)", name: "Barcode Value", keyword: "BarcodeValue", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0002)", name: "RT Image Label", keyword: "RTImageLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0003)", name: "RT Image Name", keyword: "RTImageName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0004)", name: "RT Image Description", keyword: "RTImageDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,000A)", name: "Reported Values Origin", keyword: "ReportedValuesOrigin", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(3002,000C)", name: "RT Image Plane", keyword: "RTImagePlane", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,000D)", name: "X-Ray Image Receptor Translation", keyword: "XRayImageReceptorTranslation", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(3002,000E)", name: "X-Ray Image Receptor Angle", keyword: "XRayImageReceptorAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3002,0010)", name: "RT Image Orientation", keyword: "RTImageOrientation", vr: Some(DicomVr::Ds), vm: "6", attr_type: "2C" },
-    DicomAttribute { tag: "(3002,0011)", name: "Image Plane Pixel Spacing", keyword: "ImagePlanePixelSpacing", vr: Some(DicomVr::Ds), vm: "2", attr_type: "2" },
-    DicomAttribute { tag: "(3002,0012)", name: "RT Image Position", keyword: "RTImagePosition", vr: Some(DicomVr::Ds), vm: "2", attr_type: "2" },
-    DicomAttribute { tag: "(3002,0020)", name: "Radiation Machine Name", keyword: "RadiationMachineName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3002,0022)", name: "Radiation Machine SAD", keyword: "RadiationMachineSAD", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3002,0024)", name: "Radiation Machine SSD", keyword: "RadiationMachineSSD", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0026)", name: "RT Image SID", keyword: "RTImageSID", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3002,0028)", name: "Source to Reference Object Distance", keyword: "SourceToReferenceObjectDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0029)", name: "Fraction Number", keyword: "FractionNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0030)", name: "Exposure Sequence", keyword: "ExposureSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0032)", name: "Meterset Exposure", keyword: "MetersetExposure", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(3002,0034)", name: "Diaphragm Position", keyword: "DiaphragmPosition", vr: Some(DicomVr::Ds), vm: "4", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0040)", name: "Fluence Map Sequence", keyword: "FluenceMapSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0041)", name: "Fluence Data Source", keyword: "FluenceDataSource", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0042)", name: "Fluence Data Scale", keyword: "FluenceDataScale", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0050)", name: "Primary Fluence Mode Sequence", keyword: "PrimaryFluenceModeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0051)", name: "Fluence Mode", keyword: "FluenceMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0052)", name: "Fluence Mode ID", keyword: "FluenceModeID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0100)", name: "Selected Frame Number", keyword: "SelectedFrameNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0101)", name: "Selected Frame Functional Groups Sequence", keyword: "SelectedFrameFunctionalGroupsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0102)", name: "RT Image Frame General Content Sequence", keyword: "RTImageFrameGeneralContentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0103)", name: "RT Image Frame Context Sequence", keyword: "RTImageFrameContextSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0104)", name: "RT Image Scope Sequence", keyword: "RTImageScopeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0105)", name: "Beam Modifier Coordinates Presence Flag", keyword: "BeamModifierCoordinatesPresenceFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0106)", name: "Start Cumulative Meterset", keyword: "StartCumulativeMeterset", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(3002,0107)", name: "Stop Cumulative Meterset", keyword: "StopCumulativeMeterset", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(3002,0108)", name: "RT Acquisition Patient Position Sequence", keyword: "RTAcquisitionPatientPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3002,0109)", name: "RT Image Frame Imaging Device Position Sequence", keyword: "RTImageFrameImagingDevicePositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,010A)", name: "RT Image Frame kV Radiation Acquisition Sequence", keyword: "RTImageFramekVRadiationAcquisitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,010B)", name: "RT Image Frame MV Radiation Acquisition Sequence", keyword: "RTImageFrameMVRadiationAcquisitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,010C)", name: "RT Image Frame Radiation Acquisition Sequence", keyword: "RTImageFrameRadiationAcquisitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,010D)", name: "Imaging Source Position Sequence", keyword: "ImagingSourcePositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,010E)", name: "Image Receptor Position Sequence", keyword: "ImageReceptorPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,010F)", name: "Device Position to Equipment Mapping Matrix", keyword: "DevicePositionToEquipmentMappingMatrix", vr: Some(DicomVr::Fd), vm: "16", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0110)", name: "Device Position Parameter Sequence", keyword: "DevicePositionParameterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3002,0111)", name: "Imaging Source Location Specification Type", keyword: "ImagingSourceLocationSpecificationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0112)", name: "Imaging Device Location Matrix Sequence", keyword: "ImagingDeviceLocationMatrixSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0113)", name: "Imaging Device Location Parameter Sequence", keyword: "ImagingDeviceLocationParameterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0114)", name: "Imaging Aperture Sequence", keyword: "ImagingApertureSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0115)", name: "Imaging Aperture Specification Type", keyword: "ImagingApertureSpecificationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0116)", name: "Number of Acquisition Devices", keyword: "NumberOfAcquisitionDevices", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0117)", name: "Acquisition Device Sequence", keyword: "AcquisitionDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0118)", name: "Acquisition Task Sequence", keyword: "AcquisitionTaskSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0119)", name: "Acquisition Task Workitem Code Sequence", keyword: "AcquisitionTaskWorkitemCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,011A)", name: "Acquisition Subtask Sequence", keyword: "AcquisitionSubtaskSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,011B)", name: "Subtask Workitem Code Sequence", keyword: "SubtaskWorkitemCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,011C)", name: "Acquisition Task Index", keyword: "AcquisitionTaskIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,011D)", name: "Acquisition Subtask Index", keyword: "AcquisitionSubtaskIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,011E)", name: "Referenced Baseline Parameters RT Radiation Instance Sequence", keyword: "ReferencedBaselineParametersRTRadiationInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,011F)", name: "Position Acquisition Template Identification Sequence", keyword: "PositionAcquisitionTemplateIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0120)", name: "Position Acquisition Template ID", keyword: "PositionAcquisitionTemplateID", vr: Some(DicomVr::St), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0121)", name: "Position Acquisition Template Name", keyword: "PositionAcquisitionTemplateName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,0122)", name: "Position Acquisition Template Code Sequence", keyword: "PositionAcquisitionTemplateCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0123)", name: "Position Acquisition Template Description", keyword: "PositionAcquisitionTemplateDescription", vr: Some(DicomVr::Lt), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3002,0124)", name: "Acquisition Task Applicability Sequence", keyword: "AcquisitionTaskApplicabilitySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0125)", name: "Projection Imaging Acquisition Parameter Sequence", keyword: "ProjectionImagingAcquisitionParameterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0126)", name: "CT Imaging Acquisition Parameter Sequence", keyword: "CTImagingAcquisitionParameterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0127)", name: "KV Imaging Generation Parameters Sequence", keyword: "KVImagingGenerationParametersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0128)", name: "MV Imaging Generation Parameters Sequence", keyword: "MVImagingGenerationParametersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0129)", name: "Acquisition Signal Type", keyword: "AcquisitionSignalType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,012A)", name: "Acquisition Method", keyword: "AcquisitionMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,012B)", name: "Scan Start Position Sequence", keyword: "ScanStartPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,012C)", name: "Scan Stop Position Sequence", keyword: "ScanStopPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3002,012D)", name: "Imaging Source to Beam Modifier Definition Plane Distance", keyword: "ImagingSourceToBeamModifierDefinitionPlaneDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,012E)", name: "Scan Arc Type", keyword: "ScanArcType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,012F)", name: "Detector Positioning Type", keyword: "DetectorPositioningType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0130)", name: "Additional RT Accessory Device Sequence", keyword: "AdditionalRTAccessoryDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0131)", name: "Device-Specific Acquisition Parameter Sequence", keyword: "DeviceSpecificAcquisitionParameterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0132)", name: "Referenced Position Reference Instance Sequence", keyword: "ReferencedPositionReferenceInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0133)", name: "Energy Derivation Code Sequence", keyword: "EnergyDerivationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3002,0134)", name: "Maximum Cumulative Meterset Exposure", keyword: "MaximumCumulativeMetersetExposure", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3002,0135)", name: "Acquisition Initiation Sequence", keyword: "AcquisitionInitiationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3004,0001)", name: "DVH Type", keyword: "DVHType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3004,0002)", name: "Dose Units", keyword: "DoseUnits", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3004,0004)", name: "Dose Type", keyword: "DoseType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3004,0005)", name: "Spatial Transform of Dose", keyword: "SpatialTransformOfDose", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3004,0006)", name: "Dose Comment", keyword: "DoseComment", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3004,0008)", name: "Normalization Point", keyword: "NormalizationPoint", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(3004,000A)", name: "Dose Summation Type", keyword: "DoseSummationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3004,000C)", name: "Grid Frame Offset Vector", keyword: "GridFrameOffsetVector", vr: Some(DicomVr::Ds), vm: "2-n", attr_type: "1C" },
-    DicomAttribute { tag: "(3004,000E)", name: "Dose Grid Scaling", keyword: "DoseGridScaling", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3004,0014)", name: "Tissue Heterogeneity Correction", keyword: "TissueHeterogeneityCorrection", vr: Some(DicomVr::Cs), vm: "1-3", attr_type: "3" },
-    DicomAttribute { tag: "(3004,0040)", name: "DVH Normalization Point", keyword: "DVHNormalizationPoint", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(3004,0042)", name: "DVH Normalization Dose Value", keyword: "DVHNormalizationDoseValue", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3004,0050)", name: "DVH Sequence", keyword: "DVHSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3004,0052)", name: "DVH Dose Scaling", keyword: "DVHDoseScaling", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3004,0054)", name: "DVH Volume Units", keyword: "DVHVolumeUnits", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3004,0056)", name: "DVH Number of Bins", keyword: "DVHNumberOfBins", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3004,0058)", name: "DVH Data", keyword: "DVHData", vr: Some(DicomVr::Ds), vm: "2-2n", attr_type: "1" },
-    DicomAttribute { tag: "(3004,0060)", name: "DVH Referenced ROI Sequence", keyword: "DVHReferencedROISequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3004,0062)", name: "DVH ROI Contribution Type", keyword: "DVHROIContributionType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3004,0070)", name: "DVH Minimum Dose", keyword: "DVHMinimumDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3004,0072)", name: "DVH Maximum Dose", keyword: "DVHMaximumDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3004,0074)", name: "DVH Mean Dose", keyword: "DVHMeanDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0002)", name: "Structure Set Label", keyword: "StructureSetLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0004)", name: "Structure Set Name", keyword: "StructureSetName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0006)", name: "Structure Set Description", keyword: "StructureSetDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0008)", name: "Structure Set Date", keyword: "StructureSetDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3006,0009)", name: "Structure Set Time", keyword: "StructureSetTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3006,0010)", name: "Referenced Frame of Reference Sequence", keyword: "ReferencedFrameOfReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0012)", name: "RT Referenced Study Sequence", keyword: "RTReferencedStudySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0014)", name: "RT Referenced Series Sequence", keyword: "RTReferencedSeriesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0016)", name: "Contour Image Sequence", keyword: "ContourImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0018)", name: "Predecessor Structure Set Sequence", keyword: "PredecessorStructureSetSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0020)", name: "Structure Set ROI Sequence", keyword: "StructureSetROISequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0022)", name: "ROI Number", keyword: "ROINumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0024)", name: "Referenced Frame of Reference UID", keyword: "ReferencedFrameOfReferenceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0026)", name: "ROI Name", keyword: "ROIName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3006,0028)", name: "ROI Description", keyword: "ROIDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,002A)", name: "ROI Display Color", keyword: "ROIDisplayColor", vr: Some(DicomVr::Is), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(3006,002C)", name: "ROI Volume", keyword: "ROIVolume", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,002D)", name: "ROI DateTime", keyword: "ROIDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,002E)", name: "ROI Observation DateTime", keyword: "ROIObservationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0030)", name: "RT Related ROI Sequence", keyword: "RTRelatedROISequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0033)", name: "RT ROI Relationship", keyword: "RTROIRelationship", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0036)", name: "ROI Generation Algorithm", keyword: "ROIGenerationAlgorithm", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3006,0037)", name: "ROI Derivation Algorithm Identification Sequence", keyword: "ROIDerivationAlgorithmIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0038)", name: "ROI Generation Description", keyword: "ROIGenerationDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0039)", name: "ROI Contour Sequence", keyword: "ROIContourSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0040)", name: "Contour Sequence", keyword: "ContourSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0042)", name: "Contour Geometric Type", keyword: "ContourGeometricType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0046)", name: "Number of Contour Points", keyword: "NumberOfContourPoints", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0048)", name: "Contour Number", keyword: "ContourNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,004A)", name: "Source Pixel Planes Characteristics Sequence", keyword: "SourcePixelPlanesCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,004B)", name: "Source Series Sequence", keyword: "SourceSeriesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,004C)", name: "Source Series Information Sequence", keyword: "SourceSeriesInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,004D)", name: "ROI Creator Sequence", keyword: "ROICreatorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,004E)", name: "ROI Interpreter Sequence", keyword: "ROIInterpreterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3006,004F)", name: "ROI Observation Context Code Sequence", keyword: "ROIObservationContextCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0050)", name: "Contour Data", keyword: "ContourData", vr: Some(DicomVr::Ds), vm: "3-3n", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0080)", name: "RT ROI Observations Sequence", keyword: "RTROIObservationsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0082)", name: "Observation Number", keyword: "ObservationNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,0084)", name: "Referenced ROI Number", keyword: "ReferencedROINumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,0086)", name: "RT ROI Identification Code Sequence", keyword: "RTROIIdentificationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,00A0)", name: "Related RT ROI Observations Sequence", keyword: "RelatedRTROIObservationsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,00A4)", name: "RT ROI Interpreted Type", keyword: "RTROIInterpretedType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3006,00A6)", name: "ROI Interpreter", keyword: "ROIInterpreter", vr: Some(DicomVr::Pn), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3006,00B0)", name: "ROI Physical Properties Sequence", keyword: "ROIPhysicalPropertiesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,00B2)", name: "ROI Physical Property", keyword: "ROIPhysicalProperty", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,00B4)", name: "ROI Physical Property Value", keyword: "ROIPhysicalPropertyValue", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,00B6)", name: "ROI Elemental Composition Sequence", keyword: "ROIElementalCompositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3006,00B7)", name: "ROI Elemental Composition Atomic Number", keyword: "ROIElementalCompositionAtomicNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,00B8)", name: "ROI Elemental Composition Atomic Mass Fraction", keyword: "ROIElementalCompositionAtomicMassFraction", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,00C6)", name: "Frame of Reference Transformation Matrix", keyword: "FrameOfReferenceTransformationMatrix", vr: Some(DicomVr::Ds), vm: "16", attr_type: "1" },
-    DicomAttribute { tag: "(3006,00C8)", name: "Frame of Reference Transformation Comment", keyword: "FrameOfReferenceTransformationComment", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3006,00C9)", name: "Patient Location Coordinates Sequence", keyword: "PatientLocationCoordinatesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3006,00CA)", name: "Patient Location Coordinates Code Sequence", keyword: "PatientLocationCoordinatesCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3006,00CB)", name: "Patient Support Position Sequence", keyword: "PatientSupportPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0010)", name: "Measured Dose Reference Sequence", keyword: "MeasuredDoseReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0012)", name: "Measured Dose Description", keyword: "MeasuredDoseDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0014)", name: "Measured Dose Type", keyword: "MeasuredDoseType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0016)", name: "Measured Dose Value", keyword: "MeasuredDoseValue", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0020)", name: "Treatment Session Beam Sequence", keyword: "TreatmentSessionBeamSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0021)", name: "Treatment Session Ion Beam Sequence", keyword: "TreatmentSessionIonBeamSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0022)", name: "Current Fraction Number", keyword: "CurrentFractionNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0024)", name: "Treatment Control Point Date", keyword: "TreatmentControlPointDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0025)", name: "Treatment Control Point Time", keyword: "TreatmentControlPointTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,002A)", name: "Treatment Termination Status", keyword: "TreatmentTerminationStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,002C)", name: "Treatment Verification Status", keyword: "TreatmentVerificationStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0030)", name: "Referenced Treatment Record Sequence", keyword: "ReferencedTreatmentRecordSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0032)", name: "Specified Primary Meterset", keyword: "SpecifiedPrimaryMeterset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0033)", name: "Specified Secondary Meterset", keyword: "SpecifiedSecondaryMeterset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0036)", name: "Delivered Primary Meterset", keyword: "DeliveredPrimaryMeterset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0037)", name: "Delivered Secondary Meterset", keyword: "DeliveredSecondaryMeterset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,003A)", name: "Specified Treatment Time", keyword: "SpecifiedTreatmentTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,003B)", name: "Delivered Treatment Time", keyword: "DeliveredTreatmentTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0040)", name: "Control Point Delivery Sequence", keyword: "ControlPointDeliverySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0041)", name: "Ion Control Point Delivery Sequence", keyword: "IonControlPointDeliverySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0042)", name: "Specified Meterset", keyword: "SpecifiedMeterset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0044)", name: "Delivered Meterset", keyword: "DeliveredMeterset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0045)", name: "Meterset Rate Set", keyword: "MetersetRateSet", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0046)", name: "Meterset Rate Delivered", keyword: "MetersetRateDelivered", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0047)", name: "Scan Spot Metersets Delivered", keyword: "ScanSpotMetersetsDelivered", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0048)", name: "Dose Rate Delivered", keyword: "DoseRateDelivered", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0050)", name: "Treatment Summary Calculated Dose Reference Sequence", keyword: "TreatmentSummaryCalculatedDoseReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0052)", name: "Cumulative Dose to Dose Reference", keyword: "CumulativeDoseToDoseReference", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0054)", name: "First Treatment Date", keyword: "FirstTreatmentDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0056)", name: "Most Recent Treatment Date", keyword: "MostRecentTreatmentDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,005A)", name: "Number of Fractions Delivered", keyword: "NumberOfFractionsDelivered", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0060)", name: "Override Sequence", keyword: "OverrideSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0061)", name: "Parameter Sequence Pointer", keyword: "ParameterSequencePointer", vr: Some(DicomVr::At), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0062)", name: "Override Parameter Pointer", keyword: "OverrideParameterPointer", vr: Some(DicomVr::At), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0063)", name: "Parameter Item Index", keyword: "ParameterItemIndex", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0064)", name: "Measured Dose Reference Number", keyword: "MeasuredDoseReferenceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0065)", name: "Parameter Pointer", keyword: "ParameterPointer", vr: Some(DicomVr::At), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0066)", name: "Override Reason", keyword: "OverrideReason", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0067)", name: "Parameter Value Number", keyword: "ParameterValueNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0068)", name: "Corrected Parameter Sequence", keyword: "CorrectedParameterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,006A)", name: "Correction Value", keyword: "CorrectionValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0070)", name: "Calculated Dose Reference Sequence", keyword: "CalculatedDoseReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0072)", name: "Calculated Dose Reference Number", keyword: "CalculatedDoseReferenceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0074)", name: "Calculated Dose Reference Description", keyword: "CalculatedDoseReferenceDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0076)", name: "Calculated Dose Reference Dose Value", keyword: "CalculatedDoseReferenceDoseValue", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0078)", name: "Start Meterset", keyword: "StartMeterset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,007A)", name: "End Meterset", keyword: "EndMeterset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0080)", name: "Referenced Measured Dose Reference Sequence", keyword: "ReferencedMeasuredDoseReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0082)", name: "Referenced Measured Dose Reference Number", keyword: "ReferencedMeasuredDoseReferenceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0090)", name: "Referenced Calculated Dose Reference Sequence", keyword: "ReferencedCalculatedDoseReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0092)", name: "Referenced Calculated Dose Reference Number", keyword: "ReferencedCalculatedDoseReferenceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,00A0)", name: "Beam Limiting Device Leaf Pairs Sequence", keyword: "BeamLimitingDeviceLeafPairsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,00A1)", name: "Enhanced RT Beam Limiting Device Sequence", keyword: "EnhancedRTBeamLimitingDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,00A2)", name: "Enhanced RT Beam Limiting Opening Sequence", keyword: "EnhancedRTBeamLimitingOpeningSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(3008,00A3)", name: "Enhanced RT Beam Limiting Device Definition Flag", keyword: "EnhancedRTBeamLimitingDeviceDefinitionFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,00A4)", name: "Parallel RT Beam Delimiter Opening Extents", keyword: "ParallelRTBeamDelimiterOpeningExtents", vr: Some(DicomVr::Fd), vm: "2-2n", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,00B0)", name: "Recorded Wedge Sequence", keyword: "RecordedWedgeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,00C0)", name: "Recorded Compensator Sequence", keyword: "RecordedCompensatorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,00D0)", name: "Recorded Block Sequence", keyword: "RecordedBlockSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,00D1)", name: "Recorded Block Slab Sequence", keyword: "RecordedBlockSlabSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,00E0)", name: "Treatment Summary Measured Dose Reference Sequence", keyword: "TreatmentSummaryMeasuredDoseReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,00F0)", name: "Recorded Snout Sequence", keyword: "RecordedSnoutSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,00F2)", name: "Recorded Range Shifter Sequence", keyword: "RecordedRangeShifterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,00F4)", name: "Recorded Lateral Spreading Device Sequence", keyword: "RecordedLateralSpreadingDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,00F6)", name: "Recorded Range Modulator Sequence", keyword: "RecordedRangeModulatorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0100)", name: "Recorded Source Sequence", keyword: "RecordedSourceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0105)", name: "Source Serial Number", keyword: "SourceSerialNumber", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0110)", name: "Treatment Session Application Setup Sequence", keyword: "TreatmentSessionApplicationSetupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0116)", name: "Application Setup Check", keyword: "ApplicationSetupCheck", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0120)", name: "Recorded Brachy Accessory Device Sequence", keyword: "RecordedBrachyAccessoryDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0122)", name: "Referenced Brachy Accessory Device Number", keyword: "ReferencedBrachyAccessoryDeviceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0130)", name: "Recorded Channel Sequence", keyword: "RecordedChannelSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0132)", name: "Specified Channel Total Time", keyword: "SpecifiedChannelTotalTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0134)", name: "Delivered Channel Total Time", keyword: "DeliveredChannelTotalTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0136)", name: "Specified Number of Pulses", keyword: "SpecifiedNumberOfPulses", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0138)", name: "Delivered Number of Pulses", keyword: "DeliveredNumberOfPulses", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,013A)", name: "Specified Pulse Repetition Interval", keyword: "SpecifiedPulseRepetitionInterval", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,013C)", name: "Delivered Pulse Repetition Interval", keyword: "DeliveredPulseRepetitionInterval", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0140)", name: "Recorded Source Applicator Sequence", keyword: "RecordedSourceApplicatorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0142)", name: "Referenced Source Applicator Number", keyword: "ReferencedSourceApplicatorNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0150)", name: "Recorded Channel Shield Sequence", keyword: "RecordedChannelShieldSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0152)", name: "Referenced Channel Shield Number", keyword: "ReferencedChannelShieldNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0160)", name: "Brachy Control Point Delivered Sequence", keyword: "BrachyControlPointDeliveredSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0162)", name: "Safe Position Exit Date", keyword: "SafePositionExitDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0164)", name: "Safe Position Exit Time", keyword: "SafePositionExitTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0166)", name: "Safe Position Return Date", keyword: "SafePositionReturnDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0168)", name: "Safe Position Return Time", keyword: "SafePositionReturnTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3008,0171)", name: "Pulse Specific Brachy Control Point Delivered Sequence", keyword: "PulseSpecificBrachyControlPointDeliveredSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0172)", name: "Pulse Number", keyword: "PulseNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0173)", name: "Brachy Pulse Control Point Delivered Sequence", keyword: "BrachyPulseControlPointDeliveredSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0200)", name: "Current Treatment Status", keyword: "CurrentTreatmentStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0202)", name: "Treatment Status Comment", keyword: "TreatmentStatusComment", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0220)", name: "Fraction Group Summary Sequence", keyword: "FractionGroupSummarySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0223)", name: "Referenced Fraction Number", keyword: "ReferencedFractionNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3008,0224)", name: "Fraction Group Type", keyword: "FractionGroupType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0230)", name: "Beam Stopper Position", keyword: "BeamStopperPosition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0240)", name: "Fraction Status Summary Sequence", keyword: "FractionStatusSummarySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3008,0250)", name: "Treatment Date", keyword: "TreatmentDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3008,0251)", name: "Treatment Time", keyword: "TreatmentTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0002)", name: "RT Plan Label", keyword: "RTPlanLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0003)", name: "RT Plan Name", keyword: "RTPlanName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0004)", name: "RT Plan Description", keyword: "RTPlanDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0006)", name: "RT Plan Date", keyword: "RTPlanDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0007)", name: "RT Plan Time", keyword: "RTPlanTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0009)", name: "Treatment Protocols", keyword: "TreatmentProtocols", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(300A,000A)", name: "Plan Intent", keyword: "PlanIntent", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,000C)", name: "RT Plan Geometry", keyword: "RTPlanGeometry", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,000E)", name: "Prescription Description", keyword: "PrescriptionDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0010)", name: "Dose Reference Sequence", keyword: "DoseReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0012)", name: "Dose Reference Number", keyword: "DoseReferenceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0013)", name: "Dose Reference UID", keyword: "DoseReferenceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0014)", name: "Dose Reference Structure Type", keyword: "DoseReferenceStructureType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0015)", name: "Nominal Beam Energy Unit", keyword: "NominalBeamEnergyUnit", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0016)", name: "Dose Reference Description", keyword: "DoseReferenceDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0018)", name: "Dose Reference Point Coordinates", keyword: "DoseReferencePointCoordinates", vr: Some(DicomVr::Ds), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,001A)", name: "Nominal Prior Dose", keyword: "NominalPriorDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0020)", name: "Dose Reference Type", keyword: "DoseReferenceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0021)", name: "Constraint Weight", keyword: "ConstraintWeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0022)", name: "Delivery Warning Dose", keyword: "DeliveryWarningDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0023)", name: "Delivery Maximum Dose", keyword: "DeliveryMaximumDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0025)", name: "Target Minimum Dose", keyword: "TargetMinimumDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0026)", name: "Target Prescription Dose", keyword: "TargetPrescriptionDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0027)", name: "Target Maximum Dose", keyword: "TargetMaximumDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0028)", name: "Target Underdose Volume Fraction", keyword: "TargetUnderdoseVolumeFraction", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,002A)", name: "Organ at Risk Full-volume Dose", keyword: "OrganAtRiskFullVolumeDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,002B)", name: "Organ at Risk Limit Dose", keyword: "OrganAtRiskLimitDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,002C)", name: "Organ at Risk Maximum Dose", keyword: "OrganAtRiskMaximumDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,002D)", name: "Organ at Risk Overdose Volume Fraction", keyword: "OrganAtRiskOverdoseVolumeFraction", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0040)", name: "Tolerance Table Sequence", keyword: "ToleranceTableSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0042)", name: "Tolerance Table Number", keyword: "ToleranceTableNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0043)", name: "Tolerance Table Label", keyword: "ToleranceTableLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0044)", name: "Gantry Angle Tolerance", keyword: "GantryAngleTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0046)", name: "Beam Limiting Device Angle Tolerance", keyword: "BeamLimitingDeviceAngleTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0048)", name: "Beam Limiting Device Tolerance Sequence", keyword: "BeamLimitingDeviceToleranceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,004A)", name: "Beam Limiting Device Position Tolerance", keyword: "BeamLimitingDevicePositionTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,004B)", name: "Snout Position Tolerance", keyword: "SnoutPositionTolerance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,004C)", name: "Patient Support Angle Tolerance", keyword: "PatientSupportAngleTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,004E)", name: "Table Top Eccentric Angle Tolerance", keyword: "TableTopEccentricAngleTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,004F)", name: "Table Top Pitch Angle Tolerance", keyword: "TableTopPitchAngleTolerance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0050)", name: "Table Top Roll Angle Tolerance", keyword: "TableTopRollAngleTolerance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0051)", name: "Table Top Vertical Position Tolerance", keyword: "TableTopVerticalPositionTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0052)", name: "Table Top Longitudinal Position Tolerance", keyword: "TableTopLongitudinalPositionTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0053)", name: "Table Top Lateral Position Tolerance", keyword: "TableTopLateralPositionTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0055)", name: "RT Plan Relationship", keyword: "RTPlanRelationship", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0070)", name: "Fraction Group Sequence", keyword: "FractionGroupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0071)", name: "Fraction Group Number", keyword: "FractionGroupNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0072)", name: "Fraction Group Description", keyword: "FractionGroupDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0078)", name: "Number of Fractions Planned", keyword: "NumberOfFractionsPlanned", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0079)", name: "Number of Fraction Pattern Digits Per Day", keyword: "NumberOfFractionPatternDigitsPerDay", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,007A)", name: "Repeat Fraction Cycle Length", keyword: "RepeatFractionCycleLength", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,007B)", name: "Fraction Pattern", keyword: "FractionPattern", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0080)", name: "Number of Beams", keyword: "NumberOfBeams", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0083)", name: "Referenced Dose Reference UID", keyword: "ReferencedDoseReferenceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0084)", name: "Beam Dose", keyword: "BeamDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0086)", name: "Beam Meterset", keyword: "BeamMeterset", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0088)", name: "Beam Dose Point Depth", keyword: "BeamDosePointDepth", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0089)", name: "Beam Dose Point Equivalent Depth", keyword: "BeamDosePointEquivalentDepth", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,008A)", name: "Beam Dose Point SSD", keyword: "BeamDosePointSSD", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,008B)", name: "Beam Dose Meaning", keyword: "BeamDoseMeaning", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,008C)", name: "Beam Dose Verification Control Point Sequence", keyword: "BeamDoseVerificationControlPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0090)", name: "Beam Dose Type", keyword: "BeamDoseType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0091)", name: "Alternate Beam Dose", keyword: "AlternateBeamDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0092)", name: "Alternate Beam Dose Type", keyword: "AlternateBeamDoseType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0093)", name: "Depth Value Averaging Flag", keyword: "DepthValueAveragingFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0094)", name: "Beam Dose Point Source to External Contour Distance", keyword: "BeamDosePointSourceToExternalContourDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00A0)", name: "Number of Brachy Application Setups", keyword: "NumberOfBrachyApplicationSetups", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00A2)", name: "Brachy Application Setup Dose Specification Point", keyword: "BrachyApplicationSetupDoseSpecificationPoint", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00A4)", name: "Brachy Application Setup Dose", keyword: "BrachyApplicationSetupDose", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00B0)", name: "Beam Sequence", keyword: "BeamSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00B2)", name: "Treatment Machine Name", keyword: "TreatmentMachineName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00B3)", name: "Primary Dosimeter Unit", keyword: "PrimaryDosimeterUnit", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00B4)", name: "Source-Axis Distance", keyword: "SourceAxisDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00B6)", name: "Beam Limiting Device Sequence", keyword: "BeamLimitingDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00B8)", name: "RT Beam Limiting Device Type", keyword: "RTBeamLimitingDeviceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00BA)", name: "Source to Beam Limiting Device Distance", keyword: "SourceToBeamLimitingDeviceDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00BB)", name: "Isocenter to Beam Limiting Device Distance", keyword: "IsocenterToBeamLimitingDeviceDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00BC)", name: "Number of Leaf/Jaw Pairs", keyword: "NumberOfLeafJawPairs", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00BE)", name: "Leaf Position Boundaries", keyword: "LeafPositionBoundaries", vr: Some(DicomVr::Ds), vm: "3-n", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,00C0)", name: "Beam Number", keyword: "BeamNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00C2)", name: "Beam Name", keyword: "BeamName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00C3)", name: "Beam Description", keyword: "BeamDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00C4)", name: "Beam Type", keyword: "BeamType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00C5)", name: "Beam Delivery Duration Limit", keyword: "BeamDeliveryDurationLimit", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00C6)", name: "Radiation Type", keyword: "RadiationType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00C7)", name: "High-Dose Technique Type", keyword: "HighDoseTechniqueType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,00C8)", name: "Reference Image Number", keyword: "ReferenceImageNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00CA)", name: "Planned Verification Image Sequence", keyword: "PlannedVerificationImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00CC)", name: "Imaging Device-Specific Acquisition Parameters", keyword: "ImagingDeviceSpecificAcquisitionParameters", vr: Some(DicomVr::Lo), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00CE)", name: "Treatment Delivery Type", keyword: "TreatmentDeliveryType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00D0)", name: "Number of Wedges", keyword: "NumberOfWedges", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00D1)", name: "Wedge Sequence", keyword: "WedgeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,00D2)", name: "Wedge Number", keyword: "WedgeNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00D3)", name: "Wedge Type", keyword: "WedgeType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00D4)", name: "Wedge ID", keyword: "WedgeID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00D5)", name: "Wedge Angle", keyword: "WedgeAngle", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00D6)", name: "Wedge Factor", keyword: "WedgeFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00D7)", name: "Total Wedge Tray Water-Equivalent Thickness", keyword: "TotalWedgeTrayWaterEquivalentThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00D8)", name: "Wedge Orientation", keyword: "WedgeOrientation", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00D9)", name: "Isocenter to Wedge Tray Distance", keyword: "IsocenterToWedgeTrayDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00DA)", name: "Source to Wedge Tray Distance", keyword: "SourceToWedgeTrayDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00DB)", name: "Wedge Thin Edge Position", keyword: "WedgeThinEdgePosition", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,00DC)", name: "Bolus ID", keyword: "BolusID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00DD)", name: "Bolus Description", keyword: "BolusDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00DE)", name: "Effective Wedge Angle", keyword: "EffectiveWedgeAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00E0)", name: "Number of Compensators", keyword: "NumberOfCompensators", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00E1)", name: "Material ID", keyword: "MaterialID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00E2)", name: "Total Compensator Tray Factor", keyword: "TotalCompensatorTrayFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00E3)", name: "Compensator Sequence", keyword: "CompensatorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,00E4)", name: "Compensator Number", keyword: "CompensatorNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,00E5)", name: "Compensator ID", keyword: "CompensatorID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00E6)", name: "Source to Compensator Tray Distance", keyword: "SourceToCompensatorTrayDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00E7)", name: "Compensator Rows", keyword: "CompensatorRows", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00E8)", name: "Compensator Columns", keyword: "CompensatorColumns", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00E9)", name: "Compensator Pixel Spacing", keyword: "CompensatorPixelSpacing", vr: Some(DicomVr::Ds), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00EA)", name: "Compensator Position", keyword: "CompensatorPosition", vr: Some(DicomVr::Ds), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00EB)", name: "Compensator Transmission Data", keyword: "CompensatorTransmissionData", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,00EC)", name: "Compensator Thickness Data", keyword: "CompensatorThicknessData", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,00ED)", name: "Number of Boli", keyword: "NumberOfBoli", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00EE)", name: "Compensator Type", keyword: "CompensatorType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00EF)", name: "Compensator Tray ID", keyword: "CompensatorTrayID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00F0)", name: "Number of Blocks", keyword: "NumberOfBlocks", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00F2)", name: "Total Block Tray Factor", keyword: "TotalBlockTrayFactor", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00F3)", name: "Total Block Tray Water-Equivalent Thickness", keyword: "TotalBlockTrayWaterEquivalentThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00F4)", name: "Block Sequence", keyword: "BlockSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,00F5)", name: "Block Tray ID", keyword: "BlockTrayID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00F6)", name: "Source to Block Tray Distance", keyword: "SourceToBlockTrayDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00F7)", name: "Isocenter to Block Tray Distance", keyword: "IsocenterToBlockTrayDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00F8)", name: "Block Type", keyword: "BlockType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00F9)", name: "Accessory Code", keyword: "AccessoryCode", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00FA)", name: "Block Divergence", keyword: "BlockDivergence", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,00FB)", name: "Block Mounting Position", keyword: "BlockMountingPosition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,00FC)", name: "Block Number", keyword: "BlockNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,00FE)", name: "Block Name", keyword: "BlockName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0100)", name: "Block Thickness", keyword: "BlockThickness", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0102)", name: "Block Transmission", keyword: "BlockTransmission", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,0104)", name: "Block Number of Points", keyword: "BlockNumberOfPoints", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0106)", name: "Block Data", keyword: "BlockData", vr: Some(DicomVr::Ds), vm: "2-2n", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0107)", name: "Applicator Sequence", keyword: "ApplicatorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0108)", name: "Applicator ID", keyword: "ApplicatorID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0109)", name: "Applicator Type", keyword: "ApplicatorType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,010A)", name: "Applicator Description", keyword: "ApplicatorDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,010C)", name: "Cumulative Dose Reference Coefficient", keyword: "CumulativeDoseReferenceCoefficient", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,010E)", name: "Final Cumulative Meterset Weight", keyword: "FinalCumulativeMetersetWeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0110)", name: "Number of Control Points", keyword: "NumberOfControlPoints", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0111)", name: "Control Point Sequence", keyword: "ControlPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0112)", name: "Control Point Index", keyword: "ControlPointIndex", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0114)", name: "Nominal Beam Energy", keyword: "NominalBeamEnergy", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0115)", name: "Dose Rate Set", keyword: "DoseRateSet", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0116)", name: "Wedge Position Sequence", keyword: "WedgePositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0118)", name: "Wedge Position", keyword: "WedgePosition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,011A)", name: "Beam Limiting Device Position Sequence", keyword: "BeamLimitingDevicePositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,011C)", name: "Leaf/Jaw Positions", keyword: "LeafJawPositions", vr: Some(DicomVr::Ds), vm: "2-2n", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,011E)", name: "Gantry Angle", keyword: "GantryAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,011F)", name: "Gantry Rotation Direction", keyword: "GantryRotationDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0120)", name: "Beam Limiting Device Angle", keyword: "BeamLimitingDeviceAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0121)", name: "Beam Limiting Device Rotation Direction", keyword: "BeamLimitingDeviceRotationDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0122)", name: "Patient Support Angle", keyword: "PatientSupportAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0123)", name: "Patient Support Rotation Direction", keyword: "PatientSupportRotationDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0124)", name: "Table Top Eccentric Axis Distance", keyword: "TableTopEccentricAxisDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0125)", name: "Table Top Eccentric Angle", keyword: "TableTopEccentricAngle", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0126)", name: "Table Top Eccentric Rotation Direction", keyword: "TableTopEccentricRotationDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0128)", name: "Table Top Vertical Position", keyword: "TableTopVerticalPosition", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0129)", name: "Table Top Longitudinal Position", keyword: "TableTopLongitudinalPosition", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,012A)", name: "Table Top Lateral Position", keyword: "TableTopLateralPosition", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,012C)", name: "Isocenter Position", keyword: "IsocenterPosition", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(300A,012E)", name: "Surface Entry Point", keyword: "SurfaceEntryPoint", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0130)", name: "Source to Surface Distance", keyword: "SourceToSurfaceDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0132)", name: "Source to External Contour Distance", keyword: "SourceToExternalContourDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0133)", name: "External Contour Entry Point", keyword: "ExternalContourEntryPoint", vr: Some(DicomVr::Fl), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0134)", name: "Cumulative Meterset Weight", keyword: "CumulativeMetersetWeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0140)", name: "Table Top Pitch Angle", keyword: "TableTopPitchAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0142)", name: "Table Top Pitch Rotation Direction", keyword: "TableTopPitchRotationDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0144)", name: "Table Top Roll Angle", keyword: "TableTopRollAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0146)", name: "Table Top Roll Rotation Direction", keyword: "TableTopRollRotationDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0148)", name: "Head Fixation Angle", keyword: "HeadFixationAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,014A)", name: "Gantry Pitch Angle", keyword: "GantryPitchAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,014C)", name: "Gantry Pitch Rotation Direction", keyword: "GantryPitchRotationDirection", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,014E)", name: "Gantry Pitch Angle Tolerance", keyword: "GantryPitchAngleTolerance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0150)", name: "Fixation Eye", keyword: "FixationEye", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0151)", name: "Chair Head Frame Position", keyword: "ChairHeadFramePosition", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0152)", name: "Head Fixation Angle Tolerance", keyword: "HeadFixationAngleTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0153)", name: "Chair Head Frame Position Tolerance", keyword: "ChairHeadFramePositionTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0154)", name: "Fixation Light Azimuthal Angle Tolerance", keyword: "FixationLightAzimuthalAngleTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0155)", name: "Fixation Light Polar Angle Tolerance", keyword: "FixationLightPolarAngleTolerance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0180)", name: "Patient Setup Sequence", keyword: "PatientSetupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0182)", name: "Patient Setup Number", keyword: "PatientSetupNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0183)", name: "Patient Setup Label", keyword: "PatientSetupLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0184)", name: "Patient Additional Position", keyword: "PatientAdditionalPosition", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0190)", name: "Fixation Device Sequence", keyword: "FixationDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0192)", name: "Fixation Device Type", keyword: "FixationDeviceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0194)", name: "Fixation Device Label", keyword: "FixationDeviceLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0196)", name: "Fixation Device Description", keyword: "FixationDeviceDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0198)", name: "Fixation Device Position", keyword: "FixationDevicePosition", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0199)", name: "Fixation Device Pitch Angle", keyword: "FixationDevicePitchAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,019A)", name: "Fixation Device Roll Angle", keyword: "FixationDeviceRollAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01A0)", name: "Shielding Device Sequence", keyword: "ShieldingDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01A2)", name: "Shielding Device Type", keyword: "ShieldingDeviceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,01A4)", name: "Shielding Device Label", keyword: "ShieldingDeviceLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,01A6)", name: "Shielding Device Description", keyword: "ShieldingDeviceDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01A8)", name: "Shielding Device Position", keyword: "ShieldingDevicePosition", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01B0)", name: "Setup Technique", keyword: "SetupTechnique", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01B2)", name: "Setup Technique Description", keyword: "SetupTechniqueDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01B4)", name: "Setup Device Sequence", keyword: "SetupDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01B6)", name: "Setup Device Type", keyword: "SetupDeviceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,01B8)", name: "Setup Device Label", keyword: "SetupDeviceLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,01BA)", name: "Setup Device Description", keyword: "SetupDeviceDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01BC)", name: "Setup Device Parameter", keyword: "SetupDeviceParameter", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,01D0)", name: "Setup Reference Description", keyword: "SetupReferenceDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01D2)", name: "Table Top Vertical Setup Displacement", keyword: "TableTopVerticalSetupDisplacement", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01D4)", name: "Table Top Longitudinal Setup Displacement", keyword: "TableTopLongitudinalSetupDisplacement", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,01D6)", name: "Table Top Lateral Setup Displacement", keyword: "TableTopLateralSetupDisplacement", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0200)", name: "Brachy Treatment Technique", keyword: "BrachyTreatmentTechnique", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0202)", name: "Brachy Treatment Type", keyword: "BrachyTreatmentType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0206)", name: "Treatment Machine Sequence", keyword: "TreatmentMachineSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0210)", name: "Source Sequence", keyword: "SourceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0212)", name: "Source Number", keyword: "SourceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0214)", name: "Source Type", keyword: "SourceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0216)", name: "Source Manufacturer", keyword: "SourceManufacturer", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0218)", name: "Active Source Diameter", keyword: "ActiveSourceDiameter", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,021A)", name: "Active Source Length", keyword: "ActiveSourceLength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,021B)", name: "Source Model ID", keyword: "SourceModelID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,021C)", name: "Source Description", keyword: "SourceDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0222)", name: "Source Encapsulation Nominal Thickness", keyword: "SourceEncapsulationNominalThickness", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0224)", name: "Source Encapsulation Nominal Transmission", keyword: "SourceEncapsulationNominalTransmission", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0226)", name: "Source Isotope Name", keyword: "SourceIsotopeName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0228)", name: "Source Isotope Half Life", keyword: "SourceIsotopeHalfLife", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0229)", name: "Source Strength Units", keyword: "SourceStrengthUnits", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,022A)", name: "Reference Air Kerma Rate", keyword: "ReferenceAirKermaRate", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,022B)", name: "Source Strength", keyword: "SourceStrength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,022C)", name: "Source Strength Reference Date", keyword: "SourceStrengthReferenceDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,022E)", name: "Source Strength Reference Time", keyword: "SourceStrengthReferenceTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0230)", name: "Application Setup Sequence", keyword: "ApplicationSetupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0232)", name: "Application Setup Type", keyword: "ApplicationSetupType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0234)", name: "Application Setup Number", keyword: "ApplicationSetupNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0236)", name: "Application Setup Name", keyword: "ApplicationSetupName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0238)", name: "Application Setup Manufacturer", keyword: "ApplicationSetupManufacturer", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0240)", name: "Template Number", keyword: "TemplateNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0242)", name: "Template Type", keyword: "TemplateType", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0244)", name: "Template Name", keyword: "TemplateName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0250)", name: "Total Reference Air Kerma", keyword: "TotalReferenceAirKerma", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0260)", name: "Brachy Accessory Device Sequence", keyword: "BrachyAccessoryDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0262)", name: "Brachy Accessory Device Number", keyword: "BrachyAccessoryDeviceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0263)", name: "Brachy Accessory Device ID", keyword: "BrachyAccessoryDeviceID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0264)", name: "Brachy Accessory Device Type", keyword: "BrachyAccessoryDeviceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0266)", name: "Brachy Accessory Device Name", keyword: "BrachyAccessoryDeviceName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,026A)", name: "Brachy Accessory Device Nominal Thickness", keyword: "BrachyAccessoryDeviceNominalThickness", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,026C)", name: "Brachy Accessory Device Nominal Transmission", keyword: "BrachyAccessoryDeviceNominalTransmission", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0271)", name: "Channel Effective Length", keyword: "ChannelEffectiveLength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0272)", name: "Channel Inner Length", keyword: "ChannelInnerLength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,0273)", name: "Afterloader Channel ID", keyword: "AfterloaderChannelID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0274)", name: "Source Applicator Tip Length", keyword: "SourceApplicatorTipLength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,0280)", name: "Channel Sequence", keyword: "ChannelSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0282)", name: "Channel Number", keyword: "ChannelNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0284)", name: "Channel Length", keyword: "ChannelLength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0286)", name: "Channel Total Time", keyword: "ChannelTotalTime", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0288)", name: "Source Movement Type", keyword: "SourceMovementType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,028A)", name: "Number of Pulses", keyword: "NumberOfPulses", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,028C)", name: "Pulse Repetition Interval", keyword: "PulseRepetitionInterval", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0290)", name: "Source Applicator Number", keyword: "SourceApplicatorNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0291)", name: "Source Applicator ID", keyword: "SourceApplicatorID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,0292)", name: "Source Applicator Type", keyword: "SourceApplicatorType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0294)", name: "Source Applicator Name", keyword: "SourceApplicatorName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0296)", name: "Source Applicator Length", keyword: "SourceApplicatorLength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0298)", name: "Source Applicator Manufacturer", keyword: "SourceApplicatorManufacturer", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,029C)", name: "Source Applicator Wall Nominal Thickness", keyword: "SourceApplicatorWallNominalThickness", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,029E)", name: "Source Applicator Wall Nominal Transmission", keyword: "SourceApplicatorWallNominalTransmission", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02A0)", name: "Source Applicator Step Size", keyword: "SourceApplicatorStepSize", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,02A1)", name: "Applicator Shape Referenced ROI Number", keyword: "ApplicatorShapeReferencedROINumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02A2)", name: "Transfer Tube Number", keyword: "TransferTubeNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,02A4)", name: "Transfer Tube Length", keyword: "TransferTubeLength", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,02B0)", name: "Channel Shield Sequence", keyword: "ChannelShieldSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02B2)", name: "Channel Shield Number", keyword: "ChannelShieldNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,02B3)", name: "Channel Shield ID", keyword: "ChannelShieldID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,02B4)", name: "Channel Shield Name", keyword: "ChannelShieldName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02B8)", name: "Channel Shield Nominal Thickness", keyword: "ChannelShieldNominalThickness", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02BA)", name: "Channel Shield Nominal Transmission", keyword: "ChannelShieldNominalTransmission", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02C8)", name: "Final Cumulative Time Weight", keyword: "FinalCumulativeTimeWeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,02D0)", name: "Brachy Control Point Sequence", keyword: "BrachyControlPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,02D2)", name: "Control Point Relative Position", keyword: "ControlPointRelativePosition", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,02D4)", name: "Control Point 3D Position", keyword: "ControlPoint3DPosition", vr: Some(DicomVr::Ds), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02D6)", name: "Cumulative Time Weight", keyword: "CumulativeTimeWeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,02E0)", name: "Compensator Divergence", keyword: "CompensatorDivergence", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02E1)", name: "Compensator Mounting Position", keyword: "CompensatorMountingPosition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02E2)", name: "Source to Compensator Distance", keyword: "SourceToCompensatorDistance", vr: Some(DicomVr::Ds), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,02E3)", name: "Total Compensator Tray Water-Equivalent Thickness", keyword: "TotalCompensatorTrayWaterEquivalentThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02E4)", name: "Isocenter to Compensator Tray Distance", keyword: "IsocenterToCompensatorTrayDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,02E5)", name: "Compensator Column Offset", keyword: "CompensatorColumnOffset", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,02E6)", name: "Isocenter to Compensator Distances", keyword: "IsocenterToCompensatorDistances", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,02E7)", name: "Compensator Relative Stopping Power Ratio", keyword: "CompensatorRelativeStoppingPowerRatio", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02E8)", name: "Compensator Milling Tool Diameter", keyword: "CompensatorMillingToolDiameter", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,02EA)", name: "Ion Range Compensator Sequence", keyword: "IonRangeCompensatorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,02EB)", name: "Compensator Description", keyword: "CompensatorDescription", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0302)", name: "Radiation Mass Number", keyword: "RadiationMassNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0304)", name: "Radiation Atomic Number", keyword: "RadiationAtomicNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0306)", name: "Radiation Charge State", keyword: "RadiationChargeState", vr: Some(DicomVr::Ss), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0308)", name: "Scan Mode", keyword: "ScanMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0309)", name: "Modulated Scan Mode Type", keyword: "ModulatedScanModeType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,030A)", name: "Virtual Source-Axis Distances", keyword: "VirtualSourceAxisDistances", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(300A,030C)", name: "Snout Sequence", keyword: "SnoutSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,030D)", name: "Snout Position", keyword: "SnoutPosition", vr: Some(DicomVr::Fl), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,030F)", name: "Snout ID", keyword: "SnoutID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0312)", name: "Number of Range Shifters", keyword: "NumberOfRangeShifters", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0314)", name: "Range Shifter Sequence", keyword: "RangeShifterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0316)", name: "Range Shifter Number", keyword: "RangeShifterNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0318)", name: "Range Shifter ID", keyword: "RangeShifterID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0320)", name: "Range Shifter Type", keyword: "RangeShifterType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0322)", name: "Range Shifter Description", keyword: "RangeShifterDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0330)", name: "Number of Lateral Spreading Devices", keyword: "NumberOfLateralSpreadingDevices", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0332)", name: "Lateral Spreading Device Sequence", keyword: "LateralSpreadingDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0334)", name: "Lateral Spreading Device Number", keyword: "LateralSpreadingDeviceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0336)", name: "Lateral Spreading Device ID", keyword: "LateralSpreadingDeviceID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0338)", name: "Lateral Spreading Device Type", keyword: "LateralSpreadingDeviceType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,033A)", name: "Lateral Spreading Device Description", keyword: "LateralSpreadingDeviceDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,033C)", name: "Lateral Spreading Device Water Equivalent Thickness", keyword: "LateralSpreadingDeviceWaterEquivalentThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0340)", name: "Number of Range Modulators", keyword: "NumberOfRangeModulators", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0342)", name: "Range Modulator Sequence", keyword: "RangeModulatorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0344)", name: "Range Modulator Number", keyword: "RangeModulatorNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0346)", name: "Range Modulator ID", keyword: "RangeModulatorID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0348)", name: "Range Modulator Type", keyword: "RangeModulatorType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,034A)", name: "Range Modulator Description", keyword: "RangeModulatorDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,034C)", name: "Beam Current Modulation ID", keyword: "BeamCurrentModulationID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0350)", name: "Patient Support Type", keyword: "PatientSupportType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0352)", name: "Patient Support ID", keyword: "PatientSupportID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0354)", name: "Patient Support Accessory Code", keyword: "PatientSupportAccessoryCode", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0355)", name: "Tray Accessory Code", keyword: "TrayAccessoryCode", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0356)", name: "Fixation Light Azimuthal Angle", keyword: "FixationLightAzimuthalAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0358)", name: "Fixation Light Polar Angle", keyword: "FixationLightPolarAngle", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,035A)", name: "Meterset Rate", keyword: "MetersetRate", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0360)", name: "Range Shifter Settings Sequence", keyword: "RangeShifterSettingsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0362)", name: "Range Shifter Setting", keyword: "RangeShifterSetting", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0364)", name: "Isocenter to Range Shifter Distance", keyword: "IsocenterToRangeShifterDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0366)", name: "Range Shifter Water Equivalent Thickness", keyword: "RangeShifterWaterEquivalentThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0370)", name: "Lateral Spreading Device Settings Sequence", keyword: "LateralSpreadingDeviceSettingsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0372)", name: "Lateral Spreading Device Setting", keyword: "LateralSpreadingDeviceSetting", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0374)", name: "Isocenter to Lateral Spreading Device Distance", keyword: "IsocenterToLateralSpreadingDeviceDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0380)", name: "Range Modulator Settings Sequence", keyword: "RangeModulatorSettingsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0382)", name: "Range Modulator Gating Start Value", keyword: "RangeModulatorGatingStartValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0384)", name: "Range Modulator Gating Stop Value", keyword: "RangeModulatorGatingStopValue", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0386)", name: "Range Modulator Gating Start Water Equivalent Thickness", keyword: "RangeModulatorGatingStartWaterEquivalentThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0388)", name: "Range Modulator Gating Stop Water Equivalent Thickness", keyword: "RangeModulatorGatingStopWaterEquivalentThickness", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,038A)", name: "Isocenter to Range Modulator Distance", keyword: "IsocenterToRangeModulatorDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,038F)", name: "Scan Spot Time Offset", keyword: "ScanSpotTimeOffset", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0390)", name: "Scan Spot Tune ID", keyword: "ScanSpotTuneID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0391)", name: "Scan Spot Prescribed Indices", keyword: "ScanSpotPrescribedIndices", vr: Some(DicomVr::Is), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0392)", name: "Number of Scan Spot Positions", keyword: "NumberOfScanSpotPositions", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0393)", name: "Scan Spot Reordered", keyword: "ScanSpotReordered", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0394)", name: "Scan Spot Position Map", keyword: "ScanSpotPositionMap", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0395)", name: "Scan Spot Reordering Allowed", keyword: "ScanSpotReorderingAllowed", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0396)", name: "Scan Spot Meterset Weights", keyword: "ScanSpotMetersetWeights", vr: Some(DicomVr::Fl), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0398)", name: "Scanning Spot Size", keyword: "ScanningSpotSize", vr: Some(DicomVr::Fl), vm: "2", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0399)", name: "Scan Spot Sizes Delivered", keyword: "ScanSpotSizesDelivered", vr: Some(DicomVr::Fl), vm: "2-2n", attr_type: "3" },
-    DicomAttribute { tag: "(300A,039A)", name: "Number of Paintings", keyword: "NumberOfPaintings", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,03A0)", name: "Ion Tolerance Table Sequence", keyword: "IonToleranceTableSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,03A2)", name: "Ion Beam Sequence", keyword: "IonBeamSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,03A4)", name: "Ion Beam Limiting Device Sequence", keyword: "IonBeamLimitingDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,03A6)", name: "Ion Block Sequence", keyword: "IonBlockSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,03A8)", name: "Ion Control Point Sequence", keyword: "IonControlPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,03AA)", name: "Ion Wedge Sequence", keyword: "IonWedgeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,03AC)", name: "Ion Wedge Position Sequence", keyword: "IonWedgePositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0401)", name: "Referenced Setup Image Sequence", keyword: "ReferencedSetupImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0402)", name: "Setup Image Comment", keyword: "SetupImageComment", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0410)", name: "Motion Synchronization Sequence", keyword: "MotionSynchronizationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0412)", name: "Control Point Orientation", keyword: "ControlPointOrientation", vr: Some(DicomVr::Fl), vm: "3", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0420)", name: "General Accessory Sequence", keyword: "GeneralAccessorySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0421)", name: "General Accessory ID", keyword: "GeneralAccessoryID", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0422)", name: "General Accessory Description", keyword: "GeneralAccessoryDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0423)", name: "General Accessory Type", keyword: "GeneralAccessoryType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0424)", name: "General Accessory Number", keyword: "GeneralAccessoryNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0425)", name: "Source to General Accessory Distance", keyword: "SourceToGeneralAccessoryDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0426)", name: "Isocenter to General Accessory Distance", keyword: "IsocenterToGeneralAccessoryDistance", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0431)", name: "Applicator Geometry Sequence", keyword: "ApplicatorGeometrySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0432)", name: "Applicator Aperture Shape", keyword: "ApplicatorApertureShape", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0433)", name: "Applicator Opening", keyword: "ApplicatorOpening", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0434)", name: "Applicator Opening X", keyword: "ApplicatorOpeningX", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0435)", name: "Applicator Opening Y", keyword: "ApplicatorOpeningY", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0436)", name: "Source to Applicator Mounting Position Distance", keyword: "SourceToApplicatorMountingPositionDistance", vr: Some(DicomVr::Fl), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0440)", name: "Number of Block Slab Items", keyword: "NumberOfBlockSlabItems", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0441)", name: "Block Slab Sequence", keyword: "BlockSlabSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0442)", name: "Block Slab Thickness", keyword: "BlockSlabThickness", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0443)", name: "Block Slab Number", keyword: "BlockSlabNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0450)", name: "Device Motion Control Sequence", keyword: "DeviceMotionControlSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0451)", name: "Device Motion Execution Mode", keyword: "DeviceMotionExecutionMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0452)", name: "Device Motion Observation Mode", keyword: "DeviceMotionObservationMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0453)", name: "Device Motion Parameter Code Sequence", keyword: "DeviceMotionParameterCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0501)", name: "Distal Depth Fraction", keyword: "DistalDepthFraction", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0502)", name: "Distal Depth", keyword: "DistalDepth", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0503)", name: "Nominal Range Modulation Fractions", keyword: "NominalRangeModulationFractions", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0504)", name: "Nominal Range Modulated Region Depths", keyword: "NominalRangeModulatedRegionDepths", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0505)", name: "Depth Dose Parameters Sequence", keyword: "DepthDoseParametersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0506)", name: "Delivered Depth Dose Parameters Sequence", keyword: "DeliveredDepthDoseParametersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0507)", name: "Delivered Distal Depth Fraction", keyword: "DeliveredDistalDepthFraction", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0508)", name: "Delivered Distal Depth", keyword: "DeliveredDistalDepth", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0509)", name: "Delivered Nominal Range Modulation Fractions", keyword: "DeliveredNominalRangeModulationFractions", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0510)", name: "Delivered Nominal Range Modulated Region Depths", keyword: "DeliveredNominalRangeModulatedRegionDepths", vr: Some(DicomVr::Fl), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0511)", name: "Delivered Reference Dose Definition", keyword: "DeliveredReferenceDoseDefinition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0512)", name: "Reference Dose Definition", keyword: "ReferenceDoseDefinition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0600)", name: "RT Control Point Index", keyword: "RTControlPointIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0601)", name: "Radiation Generation Mode Index", keyword: "RadiationGenerationModeIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0602)", name: "Referenced Defined Device Index", keyword: "ReferencedDefinedDeviceIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0603)", name: "Radiation Dose Identification Index", keyword: "RadiationDoseIdentificationIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0604)", name: "Number of RT Control Points", keyword: "NumberOfRTControlPoints", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0605)", name: "Referenced Radiation Generation Mode Index", keyword: "ReferencedRadiationGenerationModeIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0606)", name: "Treatment Position Index", keyword: "TreatmentPositionIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0607)", name: "Referenced Device Index", keyword: "ReferencedDeviceIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0608)", name: "Treatment Position Group Label", keyword: "TreatmentPositionGroupLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0609)", name: "Treatment Position Group UID", keyword: "TreatmentPositionGroupUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,060A)", name: "Treatment Position Group Sequence", keyword: "TreatmentPositionGroupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,060B)", name: "Referenced Treatment Position Index", keyword: "ReferencedTreatmentPositionIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,060C)", name: "Referenced Radiation Dose Identification Index", keyword: "ReferencedRadiationDoseIdentificationIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,060D)", name: "RT Accessory Holder Water-Equivalent Thickness", keyword: "RTAccessoryHolderWaterEquivalentThickness", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,060E)", name: "Referenced RT Accessory Holder Device Index", keyword: "ReferencedRTAccessoryHolderDeviceIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,060F)", name: "RT Accessory Holder Slot Existence Flag", keyword: "RTAccessoryHolderSlotExistenceFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0610)", name: "RT Accessory Holder Slot Sequence", keyword: "RTAccessoryHolderSlotSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0611)", name: "RT Accessory Holder Slot ID", keyword: "RTAccessoryHolderSlotID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,0612)", name: "RT Accessory Holder Slot Distance", keyword: "RTAccessoryHolderSlotDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0613)", name: "RT Accessory Slot Distance", keyword: "RTAccessorySlotDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,0614)", name: "RT Accessory Holder Definition Sequence", keyword: "RTAccessoryHolderDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0615)", name: "RT Accessory Device Slot ID", keyword: "RTAccessoryDeviceSlotID", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,0616)", name: "RT Radiation Sequence", keyword: "RTRadiationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0617)", name: "Radiation Dose Sequence", keyword: "RadiationDoseSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0618)", name: "Radiation Dose Identification Sequence", keyword: "RadiationDoseIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0619)", name: "Radiation Dose Identification Label", keyword: "RadiationDoseIdentificationLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,061A)", name: "Reference Dose Type", keyword: "ReferenceDoseType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,061B)", name: "Primary Dose Value Indicator", keyword: "PrimaryDoseValueIndicator", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,061C)", name: "Dose Values Sequence", keyword: "DoseValuesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,061D)", name: "Dose Value Purpose", keyword: "DoseValuePurpose", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(300A,061E)", name: "Reference Dose Point Coordinates", keyword: "ReferenceDosePointCoordinates", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,061F)", name: "Radiation Dose Values Parameters Sequence", keyword: "RadiationDoseValuesParametersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0620)", name: "Meterset to Dose Mapping Sequence", keyword: "MetersetToDoseMappingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0621)", name: "Expected In-Vivo Measurement Values Sequence", keyword: "ExpectedInVivoMeasurementValuesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0622)", name: "Expected In-Vivo Measurement Value Index", keyword: "ExpectedInVivoMeasurementValueIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0623)", name: "Radiation Dose In-Vivo Measurement Label", keyword: "RadiationDoseInVivoMeasurementLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0624)", name: "Radiation Dose Central Axis Displacement", keyword: "RadiationDoseCentralAxisDisplacement", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0625)", name: "Radiation Dose Value", keyword: "RadiationDoseValue", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0626)", name: "Radiation Dose Source to Skin Distance", keyword: "RadiationDoseSourceToSkinDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0627)", name: "Radiation Dose Measurement Point Coordinates", keyword: "RadiationDoseMeasurementPointCoordinates", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0628)", name: "Radiation Dose Source to External Contour Distance", keyword: "RadiationDoseSourceToExternalContourDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0629)", name: "RT Tolerance Set Sequence", keyword: "RTToleranceSetSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,062A)", name: "RT Tolerance Set Label", keyword: "RTToleranceSetLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,062B)", name: "Attribute Tolerance Values Sequence", keyword: "AttributeToleranceValuesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,062C)", name: "Tolerance Value", keyword: "ToleranceValue", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,062D)", name: "Patient Support Position Tolerance Sequence", keyword: "PatientSupportPositionToleranceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,062E)", name: "Treatment Time Limit", keyword: "TreatmentTimeLimit", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,062F)", name: "C-Arm Photon-Electron Control Point Sequence", keyword: "CArmPhotonElectronControlPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0630)", name: "Referenced RT Radiation Sequence", keyword: "ReferencedRTRadiationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0631)", name: "Referenced RT Instance Sequence", keyword: "ReferencedRTInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0634)", name: "Source to Patient Surface Distance", keyword: "SourceToPatientSurfaceDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,0635)", name: "Treatment Machine Special Mode Code Sequence", keyword: "TreatmentMachineSpecialModeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0636)", name: "Intended Number of Fractions", keyword: "IntendedNumberOfFractions", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0637)", name: "RT Radiation Set Intent", keyword: "RTRadiationSetIntent", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0638)", name: "RT Radiation Physical and Geometric Content Detail Flag", keyword: "RTRadiationPhysicalAndGeometricContentDetailFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0639)", name: "RT Record Flag", keyword: "RTRecordFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,063A)", name: "Treatment Device Identification Sequence", keyword: "TreatmentDeviceIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,063B)", name: "Referenced RT Physician Intent Sequence", keyword: "ReferencedRTPhysicianIntentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,063C)", name: "Cumulative Meterset", keyword: "CumulativeMeterset", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,063D)", name: "Delivery Rate", keyword: "DeliveryRate", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,063E)", name: "Delivery Rate Unit Sequence", keyword: "DeliveryRateUnitSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,063F)", name: "Treatment Position Sequence", keyword: "TreatmentPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0640)", name: "Radiation Source-Axis Distance", keyword: "RadiationSourceAxisDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0641)", name: "Number of RT Beam Limiting Devices", keyword: "NumberOfRTBeamLimitingDevices", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0642)", name: "RT Beam Limiting Device Proximal Distance", keyword: "RTBeamLimitingDeviceProximalDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0643)", name: "RT Beam Limiting Device Distal Distance", keyword: "RTBeamLimitingDeviceDistalDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0644)", name: "Parallel RT Beam Delimiter Device Orientation Label Code Sequence", keyword: "ParallelRTBeamDelimiterDeviceOrientationLabelCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0645)", name: "Beam Modifier Orientation Angle", keyword: "BeamModifierOrientationAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0646)", name: "Fixed RT Beam Delimiter Device Sequence", keyword: "FixedRTBeamDelimiterDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0647)", name: "Parallel RT Beam Delimiter Device Sequence", keyword: "ParallelRTBeamDelimiterDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0648)", name: "Number of Parallel RT Beam Delimiters", keyword: "NumberOfParallelRTBeamDelimiters", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0649)", name: "Parallel RT Beam Delimiter Boundaries", keyword: "ParallelRTBeamDelimiterBoundaries", vr: Some(DicomVr::Fd), vm: "2-n", attr_type: "1" },
-    DicomAttribute { tag: "(300A,064A)", name: "Parallel RT Beam Delimiter Positions", keyword: "ParallelRTBeamDelimiterPositions", vr: Some(DicomVr::Fd), vm: "2-n", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,064B)", name: "RT Beam Limiting Device Offset", keyword: "RTBeamLimitingDeviceOffset", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,064C)", name: "RT Beam Delimiter Geometry Sequence", keyword: "RTBeamDelimiterGeometrySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,064D)", name: "RT Beam Limiting Device Definition Sequence", keyword: "RTBeamLimitingDeviceDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,064E)", name: "Parallel RT Beam Delimiter Opening Mode", keyword: "ParallelRTBeamDelimiterOpeningMode", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,064F)", name: "Parallel RT Beam Delimiter Leaf Mounting Side", keyword: "ParallelRTBeamDelimiterLeafMountingSide", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0651)", name: "Wedge Definition Sequence", keyword: "WedgeDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0652)", name: "Radiation Beam Wedge Angle", keyword: "RadiationBeamWedgeAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0653)", name: "Radiation Beam Wedge Thin Edge Distance", keyword: "RadiationBeamWedgeThinEdgeDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0654)", name: "Radiation Beam Effective Wedge Angle", keyword: "RadiationBeamEffectiveWedgeAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0655)", name: "Number of Wedge Positions", keyword: "NumberOfWedgePositions", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0656)", name: "RT Beam Limiting Device Opening Sequence", keyword: "RTBeamLimitingDeviceOpeningSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0657)", name: "Number of RT Beam Limiting Device Openings", keyword: "NumberOfRTBeamLimitingDeviceOpenings", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0658)", name: "Radiation Dosimeter Unit Sequence", keyword: "RadiationDosimeterUnitSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0659)", name: "RT Device Distance Reference Location Code Sequence", keyword: "RTDeviceDistanceReferenceLocationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,065A)", name: "Radiation Device Configuration and Commissioning Key Sequence", keyword: "RadiationDeviceConfigurationAndCommissioningKeySequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,065B)", name: "Patient Support Position Parameter Sequence", keyword: "PatientSupportPositionParameterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,065C)", name: "Patient Support Position Specification Method", keyword: "PatientSupportPositionSpecificationMethod", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,065D)", name: "Patient Support Position Device Parameter Sequence", keyword: "PatientSupportPositionDeviceParameterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,065E)", name: "Device Order Index", keyword: "DeviceOrderIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,065F)", name: "Patient Support Position Parameter Order Index", keyword: "PatientSupportPositionParameterOrderIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0660)", name: "Patient Support Position Device Tolerance Sequence", keyword: "PatientSupportPositionDeviceToleranceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0661)", name: "Patient Support Position Tolerance Order Index", keyword: "PatientSupportPositionToleranceOrderIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0662)", name: "Compensator Definition Sequence", keyword: "CompensatorDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0663)", name: "Compensator Map Orientation", keyword: "CompensatorMapOrientation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0664)", name: "Compensator Proximal Thickness Map", keyword: "CompensatorProximalThicknessMap", vr: Some(DicomVr::Of), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0665)", name: "Compensator Distal Thickness Map", keyword: "CompensatorDistalThicknessMap", vr: Some(DicomVr::Of), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0666)", name: "Compensator Base Plane Offset", keyword: "CompensatorBasePlaneOffset", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0667)", name: "Compensator Shape Fabrication Code Sequence", keyword: "CompensatorShapeFabricationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0668)", name: "Compensator Shape Sequence", keyword: "CompensatorShapeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0669)", name: "Radiation Beam Compensator Milling Tool Diameter", keyword: "RadiationBeamCompensatorMillingToolDiameter", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,066A)", name: "Block Definition Sequence", keyword: "BlockDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,066B)", name: "Block Edge Data", keyword: "BlockEdgeData", vr: Some(DicomVr::Of), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,066C)", name: "Block Orientation", keyword: "BlockOrientation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,066D)", name: "Radiation Beam Block Thickness", keyword: "RadiationBeamBlockThickness", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,066E)", name: "Radiation Beam Block Slab Thickness", keyword: "RadiationBeamBlockSlabThickness", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,066F)", name: "Block Edge Data Sequence", keyword: "BlockEdgeDataSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0670)", name: "Number of RT Accessory Holders", keyword: "NumberOfRTAccessoryHolders", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0671)", name: "General Accessory Definition Sequence", keyword: "GeneralAccessoryDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0672)", name: "Number of General Accessories", keyword: "NumberOfGeneralAccessories", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0673)", name: "Bolus Definition Sequence", keyword: "BolusDefinitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0674)", name: "Number of Boluses", keyword: "NumberOfBoluses", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0675)", name: "Equipment Frame of Reference UID", keyword: "EquipmentFrameOfReferenceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0676)", name: "Equipment Frame of Reference Description", keyword: "EquipmentFrameOfReferenceDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0677)", name: "Equipment Reference Point Coordinates Sequence", keyword: "EquipmentReferencePointCoordinatesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0678)", name: "Equipment Reference Point Code Sequence", keyword: "EquipmentReferencePointCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0679)", name: "RT Beam Limiting Device Angle", keyword: "RTBeamLimitingDeviceAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,067A)", name: "Source Roll Angle", keyword: "SourceRollAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,067B)", name: "Radiation GenerationMode Sequence", keyword: "RadiationGenerationModeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,067C)", name: "Radiation GenerationMode Label", keyword: "RadiationGenerationModeLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,067D)", name: "Radiation GenerationMode Description", keyword: "RadiationGenerationModeDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,067E)", name: "Radiation GenerationMode Machine Code Sequence", keyword: "RadiationGenerationModeMachineCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,067F)", name: "Radiation Type Code Sequence", keyword: "RadiationTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0680)", name: "Nominal Energy", keyword: "NominalEnergy", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0681)", name: "Minimum Nominal Energy", keyword: "MinimumNominalEnergy", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0682)", name: "Maximum Nominal Energy", keyword: "MaximumNominalEnergy", vr: Some(DicomVr::Ds), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0683)", name: "Radiation Fluence Modifier Code Sequence", keyword: "RadiationFluenceModifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0684)", name: "Energy Unit Code Sequence", keyword: "EnergyUnitCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0685)", name: "Number of Radiation GenerationModes", keyword: "NumberOfRadiationGenerationModes", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0686)", name: "Patient Support Devices Sequence", keyword: "PatientSupportDevicesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0687)", name: "Number of Patient Support Devices", keyword: "NumberOfPatientSupportDevices", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0688)", name: "RT Beam Modifier Definition Distance", keyword: "RTBeamModifierDefinitionDistance", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0689)", name: "Beam Area Limit Sequence", keyword: "BeamAreaLimitSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,068A)", name: "Referenced RT Prescription Sequence", keyword: "ReferencedRTPrescriptionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,068B)", name: "Dose Value Interpretation", keyword: "DoseValueInterpretation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0700)", name: "Treatment Session UID", keyword: "TreatmentSessionUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0701)", name: "RT Radiation Usage", keyword: "RTRadiationUsage", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0702)", name: "Referenced RT Radiation Set Sequence", keyword: "ReferencedRTRadiationSetSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0703)", name: "Referenced RT Radiation Record Sequence", keyword: "ReferencedRTRadiationRecordSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0704)", name: "RT Radiation Set Delivery Number", keyword: "RTRadiationSetDeliveryNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0705)", name: "Clinical Fraction Number", keyword: "ClinicalFractionNumber", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0706)", name: "RT Treatment Fraction Completion Status", keyword: "RTTreatmentFractionCompletionStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0707)", name: "RT Radiation Set Usage", keyword: "RTRadiationSetUsage", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0708)", name: "Treatment Delivery Continuation Flag", keyword: "TreatmentDeliveryContinuationFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0709)", name: "Treatment Record Content Origin", keyword: "TreatmentRecordContentOrigin", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0714)", name: "RT Treatment Termination Status", keyword: "RTTreatmentTerminationStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0715)", name: "RT Treatment Termination Reason Code Sequence", keyword: "RTTreatmentTerminationReasonCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0716)", name: "Machine-Specific Treatment Termination Code Sequence", keyword: "MachineSpecificTreatmentTerminationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0722)", name: "RT Radiation Salvage Record Control Point Sequence", keyword: "RTRadiationSalvageRecordControlPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0723)", name: "Starting Meterset Value Known Flag", keyword: "StartingMetersetValueKnownFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0730)", name: "Treatment Termination Description", keyword: "TreatmentTerminationDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0731)", name: "Treatment Tolerance Violation Sequence", keyword: "TreatmentToleranceViolationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0732)", name: "Treatment Tolerance Violation Category", keyword: "TreatmentToleranceViolationCategory", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0733)", name: "Treatment Tolerance Violation Attribute Sequence", keyword: "TreatmentToleranceViolationAttributeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,0734)", name: "Treatment Tolerance Violation Description", keyword: "TreatmentToleranceViolationDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0735)", name: "Treatment Tolerance Violation Identification", keyword: "TreatmentToleranceViolationIdentification", vr: Some(DicomVr::St), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0736)", name: "Treatment Tolerance Violation DateTime", keyword: "TreatmentToleranceViolationDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,073A)", name: "Recorded RT Control Point DateTime", keyword: "RecordedRTControlPointDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,073B)", name: "Referenced Radiation RT Control Point Index", keyword: "ReferencedRadiationRTControlPointIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,073E)", name: "Alternate Value Sequence", keyword: "AlternateValueSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,073F)", name: "Confirmation Sequence", keyword: "ConfirmationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0740)", name: "Interlock Sequence", keyword: "InterlockSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0741)", name: "Interlock DateTime", keyword: "InterlockDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0742)", name: "Interlock Description", keyword: "InterlockDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0743)", name: "Interlock Originating Device Sequence", keyword: "InterlockOriginatingDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0744)", name: "Interlock Code Sequence", keyword: "InterlockCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0745)", name: "Interlock Resolution Code Sequence", keyword: "InterlockResolutionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0746)", name: "Interlock Resolution User Sequence", keyword: "InterlockResolutionUserSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0760)", name: "Override DateTime", keyword: "OverrideDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0761)", name: "Treatment Tolerance Violation Type Code Sequence", keyword: "TreatmentToleranceViolationTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0762)", name: "Treatment Tolerance Violation Cause Code Sequence", keyword: "TreatmentToleranceViolationCauseCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0772)", name: "Measured Meterset to Dose Mapping Sequence", keyword: "MeasuredMetersetToDoseMappingSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0773)", name: "Referenced Expected In-Vivo Measurement Value Index", keyword: "ReferencedExpectedInVivoMeasurementValueIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0774)", name: "Dose Measurement Device Code Sequence", keyword: "DoseMeasurementDeviceCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0780)", name: "Additional Parameter Recording Instance Sequence", keyword: "AdditionalParameterRecordingInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0783)", name: "Interlock Origin Description", keyword: "InterlockOriginDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0784)", name: "RT Patient Position Scope Sequence", keyword: "RTPatientPositionScopeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0785)", name: "Referenced Treatment Position Group UID", keyword: "ReferencedTreatmentPositionGroupUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0786)", name: "Radiation Order Index", keyword: "RadiationOrderIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0787)", name: "Omitted Radiation Sequence", keyword: "OmittedRadiationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0788)", name: "Reason for Omission Code Sequence", keyword: "ReasonForOmissionCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0789)", name: "RT Delivery Start Patient Position Sequence", keyword: "RTDeliveryStartPatientPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,078A)", name: "RT Treatment Preparation Patient Position Sequence", keyword: "RTTreatmentPreparationPatientPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,078B)", name: "Referenced RT Treatment Preparation Sequence", keyword: "ReferencedRTTreatmentPreparationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,078C)", name: "Referenced Patient Setup Photo Sequence", keyword: "ReferencedPatientSetupPhotoSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,078D)", name: "Patient Treatment Preparation Method Code Sequence", keyword: "PatientTreatmentPreparationMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,078E)", name: "Patient Treatment Preparation Procedure Parameter Description", keyword: "PatientTreatmentPreparationProcedureParameterDescription", vr: Some(DicomVr::Lt), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,078F)", name: "Patient Treatment Preparation Device Sequence", keyword: "PatientTreatmentPreparationDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0790)", name: "Patient Treatment Preparation Procedure Sequence", keyword: "PatientTreatmentPreparationProcedureSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0791)", name: "Patient Treatment Preparation Procedure Code Sequence", keyword: "PatientTreatmentPreparationProcedureCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0792)", name: "Patient Treatment Preparation Method Description", keyword: "PatientTreatmentPreparationMethodDescription", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,0793)", name: "Patient Treatment Preparation Procedure Parameter Sequence", keyword: "PatientTreatmentPreparationProcedureParameterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0794)", name: "Patient Setup Photo Description", keyword: "PatientSetupPhotoDescription", vr: Some(DicomVr::Lt), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,0795)", name: "Patient Treatment Preparation Procedure Index", keyword: "PatientTreatmentPreparationProcedureIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0796)", name: "Referenced Patient Setup Procedure Index", keyword: "ReferencedPatientSetupProcedureIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300A,0797)", name: "RT Radiation Task Sequence", keyword: "RTRadiationTaskSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,0798)", name: "RT Patient Position Displacement Sequence", keyword: "RTPatientPositionDisplacementSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,0799)", name: "RT Patient Position Sequence", keyword: "RTPatientPositionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300A,079A)", name: "Displacement Reference Label", keyword: "DisplacementReferenceLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300A,079B)", name: "Displacement Matrix", keyword: "DisplacementMatrix", vr: Some(DicomVr::Fd), vm: "16", attr_type: "1" },
-    DicomAttribute { tag: "(300A,079C)", name: "Patient Support Displacement Sequence", keyword: "PatientSupportDisplacementSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300A,079D)", name: "Displacement Reference Location Code Sequence", keyword: "DisplacementReferenceLocationCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300A,079E)", name: "RT Radiation Set Delivery Usage", keyword: "RTRadiationSetDeliveryUsage", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0002)", name: "Referenced RT Plan Sequence", keyword: "ReferencedRTPlanSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0004)", name: "Referenced Beam Sequence", keyword: "ReferencedBeamSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,0006)", name: "Referenced Beam Number", keyword: "ReferencedBeamNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0007)", name: "Referenced Reference Image Number", keyword: "ReferencedReferenceImageNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0008)", name: "Start Cumulative Meterset Weight", keyword: "StartCumulativeMetersetWeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0009)", name: "End Cumulative Meterset Weight", keyword: "EndCumulativeMetersetWeight", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,000A)", name: "Referenced Brachy Application Setup Sequence", keyword: "ReferencedBrachyApplicationSetupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,000C)", name: "Referenced Brachy Application Setup Number", keyword: "ReferencedBrachyApplicationSetupNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,000E)", name: "Referenced Source Number", keyword: "ReferencedSourceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0020)", name: "Referenced Fraction Group Sequence", keyword: "ReferencedFractionGroupSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,0022)", name: "Referenced Fraction Group Number", keyword: "ReferencedFractionGroupNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0040)", name: "Referenced Verification Image Sequence", keyword: "ReferencedVerificationImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0042)", name: "Referenced Reference Image Sequence", keyword: "ReferencedReferenceImageSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0050)", name: "Referenced Dose Reference Sequence", keyword: "ReferencedDoseReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0051)", name: "Referenced Dose Reference Number", keyword: "ReferencedDoseReferenceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0055)", name: "Brachy Referenced Dose Reference Sequence", keyword: "BrachyReferencedDoseReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0060)", name: "Referenced Structure Set Sequence", keyword: "ReferencedStructureSetSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,006A)", name: "Referenced Patient Setup Number", keyword: "ReferencedPatientSetupNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0080)", name: "Referenced Dose Sequence", keyword: "ReferencedDoseSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,00A0)", name: "Referenced Tolerance Table Number", keyword: "ReferencedToleranceTableNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,00B0)", name: "Referenced Bolus Sequence", keyword: "ReferencedBolusSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,00C0)", name: "Referenced Wedge Number", keyword: "ReferencedWedgeNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,00D0)", name: "Referenced Compensator Number", keyword: "ReferencedCompensatorNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,00E0)", name: "Referenced Block Number", keyword: "ReferencedBlockNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,00F0)", name: "Referenced Control Point Index", keyword: "ReferencedControlPointIndex", vr: Some(DicomVr::Is), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,00F2)", name: "Referenced Control Point Sequence", keyword: "ReferencedControlPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,00F4)", name: "Referenced Start Control Point Index", keyword: "ReferencedStartControlPointIndex", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,00F6)", name: "Referenced Stop Control Point Index", keyword: "ReferencedStopControlPointIndex", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0100)", name: "Referenced Range Shifter Number", keyword: "ReferencedRangeShifterNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0102)", name: "Referenced Lateral Spreading Device Number", keyword: "ReferencedLateralSpreadingDeviceNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0104)", name: "Referenced Range Modulator Number", keyword: "ReferencedRangeModulatorNumber", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0111)", name: "Omitted Beam Task Sequence", keyword: "OmittedBeamTaskSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0112)", name: "Reason for Omission", keyword: "ReasonForOmission", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0113)", name: "Reason for Omission Description", keyword: "ReasonForOmissionDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0114)", name: "Prescription Overview Sequence", keyword: "PrescriptionOverviewSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(300C,0115)", name: "Total Prescription Dose", keyword: "TotalPrescriptionDose", vr: Some(DicomVr::Fl), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0116)", name: "Plan Overview Sequence", keyword: "PlanOverviewSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,0117)", name: "Plan Overview Index", keyword: "PlanOverviewIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0118)", name: "Referenced Plan Overview Index", keyword: "ReferencedPlanOverviewIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,0119)", name: "Number of Fractions Included", keyword: "NumberOfFractionsIncluded", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,0120)", name: "Dose Calibration Conditions Sequence", keyword: "DoseCalibrationConditionsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,0121)", name: "Absorbed Dose to Meterset Ratio", keyword: "AbsorbedDoseToMetersetRatio", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0122)", name: "Delineated Radiation Field Size", keyword: "DelineatedRadiationFieldSize", vr: Some(DicomVr::Fd), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0123)", name: "Dose Calibration Conditions Verified Flag", keyword: "DoseCalibrationConditionsVerifiedFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0124)", name: "Calibration Reference Point Depth", keyword: "CalibrationReferencePointDepth", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0125)", name: "Gating Beam Hold Transition Sequence", keyword: "GatingBeamHoldTransitionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300C,0126)", name: "Beam Hold Transition", keyword: "BeamHoldTransition", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0127)", name: "Beam Hold Transition DateTime", keyword: "BeamHoldTransitionDateTime", vr: Some(DicomVr::Dt), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300C,0128)", name: "Beam Hold Originating Device Sequence", keyword: "BeamHoldOriginatingDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(300C,0129)", name: "Beam Hold Transition Trigger Source", keyword: "BeamHoldTransitionTriggerSource", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(300E,0002)", name: "Approval Status", keyword: "ApprovalStatus", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(300E,0004)", name: "Review Date", keyword: "ReviewDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300E,0005)", name: "Review Time", keyword: "ReviewTime", vr: Some(DicomVr::Tm), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(300E,0008)", name: "Reviewer Name", keyword: "ReviewerName", vr: Some(DicomVr::Pn), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(3010,0001)", name: "Radiobiological Dose Effect Sequence", keyword: "RadiobiologicalDoseEffectSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0002)", name: "Radiobiological Dose Effect Flag", keyword: "RadiobiologicalDoseEffectFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0003)", name: "Effective Dose Calculation Method Category Code Sequence", keyword: "EffectiveDoseCalculationMethodCategoryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(3010,0004)", name: "Effective Dose Calculation Method Code Sequence", keyword: "EffectiveDoseCalculationMethodCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0005)", name: "Effective Dose Calculation Method Description", keyword: "EffectiveDoseCalculationMethodDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(3010,0006)", name: "Conceptual Volume UID", keyword: "ConceptualVolumeUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0007)", name: "Originating SOP Instance Reference Sequence", keyword: "OriginatingSOPInstanceReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0008)", name: "Conceptual Volume Constituent Sequence", keyword: "ConceptualVolumeConstituentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0009)", name: "Equivalent Conceptual Volume Instance Reference Sequence", keyword: "EquivalentConceptualVolumeInstanceReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,000A)", name: "Equivalent Conceptual Volumes Sequence", keyword: "EquivalentConceptualVolumesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,000B)", name: "Referenced Conceptual Volume UID", keyword: "ReferencedConceptualVolumeUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,000C)", name: "Conceptual Volume Combination Expression", keyword: "ConceptualVolumeCombinationExpression", vr: Some(DicomVr::Ut), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,000D)", name: "Conceptual Volume Constituent Index", keyword: "ConceptualVolumeConstituentIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,000E)", name: "Conceptual Volume Combination Flag", keyword: "ConceptualVolumeCombinationFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,000F)", name: "Conceptual Volume Combination Description", keyword: "ConceptualVolumeCombinationDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(3010,0010)", name: "Conceptual Volume Segmentation Defined Flag", keyword: "ConceptualVolumeSegmentationDefinedFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0011)", name: "Conceptual Volume Segmentation Reference Sequence", keyword: "ConceptualVolumeSegmentationReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0012)", name: "Conceptual Volume Constituent Segmentation Reference Sequence", keyword: "ConceptualVolumeConstituentSegmentationReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0013)", name: "Constituent Conceptual Volume UID", keyword: "ConstituentConceptualVolumeUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0014)", name: "Derivation Conceptual Volume Sequence", keyword: "DerivationConceptualVolumeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0015)", name: "Source Conceptual Volume UID", keyword: "SourceConceptualVolumeUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0016)", name: "Conceptual Volume Derivation Algorithm Sequence", keyword: "ConceptualVolumeDerivationAlgorithmSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0017)", name: "Conceptual Volume Description", keyword: "ConceptualVolumeDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0018)", name: "Source Conceptual Volume Sequence", keyword: "SourceConceptualVolumeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0019)", name: "Author Identification Sequence", keyword: "AuthorIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,001A)", name: "Manufacturer's Model Version", keyword: "ManufacturerModelVersion", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,001B)", name: "Device Alternate Identifier", keyword: "DeviceAlternateIdentifier", vr: Some(DicomVr::Uc), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,001C)", name: "Device Alternate Identifier Type", keyword: "DeviceAlternateIdentifierType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,001D)", name: "Device Alternate Identifier Format", keyword: "DeviceAlternateIdentifierFormat", vr: Some(DicomVr::Lt), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,001E)", name: "Segmentation Creation Template Label", keyword: "SegmentationCreationTemplateLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0020)", name: "Referenced Segment Reference Index", keyword: "ReferencedSegmentReferenceIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0021)", name: "Segment Reference Sequence", keyword: "SegmentReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0022)", name: "Segment Reference Index", keyword: "SegmentReferenceIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0023)", name: "Direct Segment Reference Sequence", keyword: "DirectSegmentReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0024)", name: "Combination Segment Reference Sequence", keyword: "CombinationSegmentReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0025)", name: "Conceptual Volume Sequence", keyword: "ConceptualVolumeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0026)", name: "Segmented RT Accessory Device Sequence", keyword: "SegmentedRTAccessoryDeviceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0027)", name: "Segment Characteristics Sequence", keyword: "SegmentCharacteristicsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0029)", name: "Segment Characteristics Precedence", keyword: "SegmentCharacteristicsPrecedence", vr: Some(DicomVr::Us), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,002A)", name: "RT Segment Annotation Sequence", keyword: "RTSegmentAnnotationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,002B)", name: "Segment Annotation Category Code Sequence", keyword: "SegmentAnnotationCategoryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,002C)", name: "Segment Annotation Type Code Sequence", keyword: "SegmentAnnotationTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,002D)", name: "Device Label", keyword: "DeviceLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,002E)", name: "Device Type Code Sequence", keyword: "DeviceTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,002F)", name: "Segment Annotation Type Modifier Code Sequence", keyword: "SegmentAnnotationTypeModifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0030)", name: "Patient Equipment Relationship Code Sequence", keyword: "PatientEquipmentRelationshipCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0031)", name: "Referenced Fiducials UID", keyword: "ReferencedFiducialsUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0032)", name: "Patient Treatment Orientation Sequence", keyword: "PatientTreatmentOrientationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0033)", name: "User Content Label", keyword: "UserContentLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0034)", name: "User Content Long Label", keyword: "UserContentLongLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0035)", name: "Entity Label", keyword: "EntityLabel", vr: Some(DicomVr::Sh), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0036)", name: "Entity Name", keyword: "EntityName", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0037)", name: "Entity Description", keyword: "EntityDescription", vr: Some(DicomVr::St), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0038)", name: "Entity Long Label", keyword: "EntityLongLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0039)", name: "Device Index", keyword: "DeviceIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,003A)", name: "RT Treatment Phase Index", keyword: "RTTreatmentPhaseIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,003B)", name: "RT Treatment Phase UID", keyword: "RTTreatmentPhaseUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,003C)", name: "RT Prescription Index", keyword: "RTPrescriptionIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,003D)", name: "RT Segment Annotation Index", keyword: "RTSegmentAnnotationIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,003E)", name: "Basis RT Treatment Phase Index", keyword: "BasisRTTreatmentPhaseIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,003F)", name: "Related RT Treatment Phase Index", keyword: "RelatedRTTreatmentPhaseIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0040)", name: "Referenced RT Treatment Phase Index", keyword: "ReferencedRTTreatmentPhaseIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0041)", name: "Referenced RT Prescription Index", keyword: "ReferencedRTPrescriptionIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0042)", name: "Referenced Parent RT Prescription Index", keyword: "ReferencedParentRTPrescriptionIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0043)", name: "Manufacturer's Device Identifier", keyword: "ManufacturerDeviceIdentifier", vr: Some(DicomVr::St), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0044)", name: "Instance-Level Referenced Performed Procedure Step Sequence", keyword: "InstanceLevelReferencedPerformedProcedureStepSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0045)", name: "RT Treatment Phase Intent Presence Flag", keyword: "RTTreatmentPhaseIntentPresenceFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0046)", name: "Radiotherapy Treatment Type", keyword: "RadiotherapyTreatmentType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0047)", name: "Teletherapy Radiation Type", keyword: "TeletherapyRadiationType", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0048)", name: "Brachytherapy Source Type", keyword: "BrachytherapySourceType", vr: Some(DicomVr::Cs), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0049)", name: "Referenced RT Treatment Phase Sequence", keyword: "ReferencedRTTreatmentPhaseSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,004A)", name: "Referenced Direct Segment Instance Sequence", keyword: "ReferencedDirectSegmentInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,004B)", name: "Intended RT Treatment Phase Sequence", keyword: "IntendedRTTreatmentPhaseSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,004C)", name: "Intended Phase Start Date", keyword: "IntendedPhaseStartDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,004D)", name: "Intended Phase End Date", keyword: "IntendedPhaseEndDate", vr: Some(DicomVr::Da), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,004E)", name: "RT Treatment Phase Interval Sequence", keyword: "RTTreatmentPhaseIntervalSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,004F)", name: "Temporal Relationship Interval Anchor", keyword: "TemporalRelationshipIntervalAnchor", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0050)", name: "Minimum Number of Interval Days", keyword: "MinimumNumberOfIntervalDays", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0051)", name: "Maximum Number of Interval Days", keyword: "MaximumNumberOfIntervalDays", vr: Some(DicomVr::Fd), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0052)", name: "Pertinent SOP Classes in Study", keyword: "PertinentSOPClassesInStudy", vr: Some(DicomVr::Ui), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0053)", name: "Pertinent SOP Classes in Series", keyword: "PertinentSOPClassesInSeries", vr: Some(DicomVr::Ui), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0054)", name: "RT Prescription Label", keyword: "RTPrescriptionLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0055)", name: "RT Physician Intent Predecessor Sequence", keyword: "RTPhysicianIntentPredecessorSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0056)", name: "RT Treatment Approach Label", keyword: "RTTreatmentApproachLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0057)", name: "RT Physician Intent Sequence", keyword: "RTPhysicianIntentSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0058)", name: "RT Physician Intent Index", keyword: "RTPhysicianIntentIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0059)", name: "RT Treatment Intent Type", keyword: "RTTreatmentIntentType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,005A)", name: "RT Physician Intent Narrative", keyword: "RTPhysicianIntentNarrative", vr: Some(DicomVr::Ut), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,005B)", name: "RT Protocol Code Sequence", keyword: "RTProtocolCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,005C)", name: "Reason for Superseding", keyword: "ReasonForSuperseding", vr: Some(DicomVr::St), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,005D)", name: "RT Diagnosis Code Sequence", keyword: "RTDiagnosisCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,005E)", name: "Referenced RT Physician Intent Index", keyword: "ReferencedRTPhysicianIntentIndex", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,005F)", name: "RT Physician Intent Input Instance Sequence", keyword: "RTPhysicianIntentInputInstanceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0060)", name: "RT Anatomic Prescription Sequence", keyword: "RTAnatomicPrescriptionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0061)", name: "Prior Treatment Dose Description", keyword: "PriorTreatmentDoseDescription", vr: Some(DicomVr::Ut), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0062)", name: "Prior Treatment Reference Sequence", keyword: "PriorTreatmentReferenceSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0063)", name: "Dosimetric Objective Evaluation Scope", keyword: "DosimetricObjectiveEvaluationScope", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0064)", name: "Therapeutic Role Category Code Sequence", keyword: "TherapeuticRoleCategoryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0065)", name: "Therapeutic Role Type Code Sequence", keyword: "TherapeuticRoleTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0066)", name: "Conceptual Volume Optimization Precedence", keyword: "ConceptualVolumeOptimizationPrecedence", vr: Some(DicomVr::Us), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0067)", name: "Conceptual Volume Category Code Sequence", keyword: "ConceptualVolumeCategoryCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0068)", name: "Conceptual Volume Blocking Constraint", keyword: "ConceptualVolumeBlockingConstraint", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0069)", name: "Conceptual Volume Type Code Sequence", keyword: "ConceptualVolumeTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,006A)", name: "Conceptual Volume Type Modifier Code Sequence", keyword: "ConceptualVolumeTypeModifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,006B)", name: "RT Prescription Sequence", keyword: "RTPrescriptionSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,006C)", name: "Dosimetric Objective Sequence", keyword: "DosimetricObjectiveSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,006D)", name: "Dosimetric Objective Type Code Sequence", keyword: "DosimetricObjectiveTypeCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,006E)", name: "Dosimetric Objective UID", keyword: "DosimetricObjectiveUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,006F)", name: "Referenced Dosimetric Objective UID", keyword: "ReferencedDosimetricObjectiveUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0070)", name: "Dosimetric Objective Parameter Sequence", keyword: "DosimetricObjectiveParameterSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0071)", name: "Referenced Dosimetric Objectives Sequence", keyword: "ReferencedDosimetricObjectivesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0073)", name: "Absolute Dosimetric Objective Flag", keyword: "AbsoluteDosimetricObjectiveFlag", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0074)", name: "Dosimetric Objective Weight", keyword: "DosimetricObjectiveWeight", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0075)", name: "Dosimetric Objective Purpose", keyword: "DosimetricObjectivePurpose", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0076)", name: "Planning Input Information Sequence", keyword: "PlanningInputInformationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0077)", name: "Treatment Site", keyword: "TreatmentSite", vr: Some(DicomVr::Lo), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0078)", name: "Treatment Site Code Sequence", keyword: "TreatmentSiteCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0079)", name: "Fraction Pattern Sequence", keyword: "FractionPatternSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,007A)", name: "Treatment Technique Notes", keyword: "TreatmentTechniqueNotes", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,007B)", name: "Prescription Notes", keyword: "PrescriptionNotes", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,007C)", name: "Number of Interval Fractions", keyword: "NumberOfIntervalFractions", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,007D)", name: "Number of Fractions", keyword: "NumberOfFractions", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,007E)", name: "Intended Delivery Duration", keyword: "IntendedDeliveryDuration", vr: Some(DicomVr::Us), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,007F)", name: "Fractionation Notes", keyword: "FractionationNotes", vr: Some(DicomVr::Ut), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0080)", name: "RT Treatment Technique Code Sequence", keyword: "RTTreatmentTechniqueCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0081)", name: "Prescription Notes Sequence", keyword: "PrescriptionNotesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0082)", name: "Fraction-Based Relationship Sequence", keyword: "FractionBasedRelationshipSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "2" },
-    DicomAttribute { tag: "(3010,0083)", name: "Fraction-Based Relationship Interval Anchor", keyword: "FractionBasedRelationshipIntervalAnchor", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0084)", name: "Minimum Hours between Fractions", keyword: "MinimumHoursBetweenFractions", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0085)", name: "Intended Fraction Start Time", keyword: "IntendedFractionStartTime", vr: Some(DicomVr::Tm), vm: "1-n", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0086)", name: "Intended Start Day of Week", keyword: "IntendedStartDayOfWeek", vr: Some(DicomVr::Lt), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0087)", name: "Weekday Fraction Pattern Sequence", keyword: "WeekdayFractionPatternSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0088)", name: "Delivery Time Structure Code Sequence", keyword: "DeliveryTimeStructureCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0089)", name: "Treatment Site Modifier Code Sequence", keyword: "TreatmentSiteModifierCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(3010,0091)", name: "Robotic Path Node Set Code Sequence", keyword: "RoboticPathNodeSetCodeSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0092)", name: "Robotic Node Identifier", keyword: "RoboticNodeIdentifier", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0093)", name: "RT Treatment Source Coordinates", keyword: "RTTreatmentSourceCoordinates", vr: Some(DicomVr::Fd), vm: "3", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0094)", name: "Radiation Source Coordinate SystemYaw Angle", keyword: "RadiationSourceCoordinateSystemYawAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0095)", name: "Radiation Source Coordinate SystemRoll Angle", keyword: "RadiationSourceCoordinateSystemRollAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0096)", name: "Radiation Source Coordinate System Pitch Angle", keyword: "RadiationSourceCoordinateSystemPitchAngle", vr: Some(DicomVr::Fd), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,0097)", name: "Robotic Path Control Point Sequence", keyword: "RoboticPathControlPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0098)", name: "Tomotherapeutic Control Point Sequence", keyword: "TomotherapeuticControlPointSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(3010,0099)", name: "Tomotherapeutic Leaf Open Durations", keyword: "TomotherapeuticLeafOpenDurations", vr: Some(DicomVr::Fd), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,009A)", name: "Tomotherapeutic Leaf Initial Closed Durations", keyword: "TomotherapeuticLeafInitialClosedDurations", vr: Some(DicomVr::Fd), vm: "1-n", attr_type: "1C" },
-    DicomAttribute { tag: "(3010,00A0)", name: "Conceptual Volume Identification Sequence", keyword: "ConceptualVolumeIdentificationSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(4FFE,0001)", name: "MAC Parameters Sequence", keyword: "MACParametersSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(5200,9229)", name: "Shared Functional Groups Sequence", keyword: "SharedFunctionalGroupsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(5200,9230)", name: "Per-Frame Functional Groups Sequence", keyword: "PerFrameFunctionalGroupsSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(5400,0100)", name: "Waveform Sequence", keyword: "WaveformSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(5400,0110)", name: "Channel Minimum Value", keyword: "ChannelMinimumValue", vr: Some(DicomVr::ObOrOw), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(5400,0112)", name: "Channel Maximum Value", keyword: "ChannelMaximumValue", vr: Some(DicomVr::ObOrOw), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(5400,1004)", name: "Waveform Bits Allocated", keyword: "WaveformBitsAllocated", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(5400,1006)", name: "Waveform Sample Interpretation", keyword: "WaveformSampleInterpretation", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(5400,100A)", name: "Waveform Padding Value", keyword: "WaveformPaddingValue", vr: Some(DicomVr::ObOrOw), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(5400,1010)", name: "Waveform Data", keyword: "WaveformData", vr: Some(DicomVr::ObOrOw), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(5600,0010)", name: "First Order Phase Correction Angle", keyword: "FirstOrderPhaseCorrectionAngle", vr: Some(DicomVr::Of), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(5600,0020)", name: "Spectroscopy Data", keyword: "SpectroscopyData", vr: Some(DicomVr::Of), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(60XX,0010)", name: "Overlay Rows", keyword: "OverlayRows", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(60XX,0011)", name: "Overlay Columns", keyword: "OverlayColumns", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(60XX,0015)", name: "Number of Frames in Overlay", keyword: "NumberOfFramesInOverlay", vr: Some(DicomVr::Is), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(60XX,0022)", name: "Overlay Description", keyword: "OverlayDescription", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(60XX,0040)", name: "Overlay Type", keyword: "OverlayType", vr: Some(DicomVr::Cs), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(60XX,0045)", name: "Overlay Subtype", keyword: "OverlaySubtype", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(60XX,0050)", name: "Overlay Origin", keyword: "OverlayOrigin", vr: Some(DicomVr::Ss), vm: "2", attr_type: "1" },
-    DicomAttribute { tag: "(60XX,0051)", name: "Image Frame Origin", keyword: "ImageFrameOrigin", vr: Some(DicomVr::Us), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(60XX,0100)", name: "Overlay Bits Allocated", keyword: "OverlayBitsAllocated", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(60XX,0102)", name: "Overlay Bit Position", keyword: "OverlayBitPosition", vr: Some(DicomVr::Us), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(60XX,1001)", name: "Overlay Activation Layer", keyword: "OverlayActivationLayer", vr: Some(DicomVr::Cs), vm: "1", attr_type: "2C" },
-    DicomAttribute { tag: "(60XX,1301)", name: "ROI Area", keyword: "ROIArea", vr: Some(DicomVr::Is), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(60XX,1302)", name: "ROI Mean", keyword: "ROIMean", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(60XX,1303)", name: "ROI Standard Deviation", keyword: "ROIStandardDeviation", vr: Some(DicomVr::Ds), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(60XX,1500)", name: "Overlay Label", keyword: "OverlayLabel", vr: Some(DicomVr::Lo), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(60XX,3000)", name: "Overlay Data", keyword: "OverlayData", vr: Some(DicomVr::ObOrOw), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(7FE0,0001)", name: "Extended Offset Table", keyword: "ExtendedOffsetTable", vr: Some(DicomVr::Ov), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(7FE0,0002)", name: "Extended Offset Table Lengths", keyword: "ExtendedOffsetTableLengths", vr: Some(DicomVr::Ov), vm: "1", attr_type: "1C" },
-    DicomAttribute { tag: "(7FE0,0003)", name: "Encapsulated Pixel Data Value Total Length", keyword: "EncapsulatedPixelDataValueTotalLength", vr: Some(DicomVr::Uv), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(7FE0,0008)", name: "Float Pixel Data", keyword: "FloatPixelData", vr: Some(DicomVr::Of), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(7FE0,0009)", name: "Double Float Pixel Data", keyword: "DoubleFloatPixelData", vr: Some(DicomVr::Od), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(7FE0,0010)", name: "Pixel Data", keyword: "PixelData", vr: Some(DicomVr::ObOrOw), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(FFFA,FFFA)", name: "Digital Signatures Sequence", keyword: "DigitalSignaturesSequence", vr: Some(DicomVr::Sq), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0000)", name: "File Meta Information Group Length", keyword: "FileMetaInformationGroupLength", vr: Some(DicomVr::Ul), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0002,0001)", name: "File Meta Information Version", keyword: "FileMetaInformationVersion", vr: Some(DicomVr::Ob), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0002,0002)", name: "Media Storage SOP Class UID", keyword: "MediaStorageSOPClassUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0002,0003)", name: "Media Storage SOP Instance UID", keyword: "MediaStorageSOPInstanceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0002,0010)", name: "Transfer Syntax UID", keyword: "TransferSyntaxUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0002,0012)", name: "Implementation Class UID", keyword: "ImplementationClassUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "1" },
-    DicomAttribute { tag: "(0002,0013)", name: "Implementation Version Name", keyword: "ImplementationVersionName", vr: Some(DicomVr::Sh), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0016)", name: "Source Application Entity Title", keyword: "SourceApplicationEntityTitle", vr: Some(DicomVr::Ae), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0017)", name: "Sending Application Entity Title", keyword: "SendingApplicationEntityTitle", vr: Some(DicomVr::Ae), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0018)", name: "Receiving Application Entity Title", keyword: "ReceivingApplicationEntityTitle", vr: Some(DicomVr::Ae), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0026)", name: "Source Presentation Address", keyword: "SourcePresentationAddress", vr: Some(DicomVr::Ur), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0027)", name: "Sending Presentation Address", keyword: "SendingPresentationAddress", vr: Some(DicomVr::Ur), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0028)", name: "Receiving Presentation Address", keyword: "ReceivingPresentationAddress", vr: Some(DicomVr::Ur), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0031)", name: "RTV Meta Information Version", keyword: "RTVMetaInformationVersion", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0032)", name: "RTV Communication SOP Class UID", keyword: "RTVCommunicationSOPClassUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0033)", name: "RTV Communication SOP Instance UID", keyword: "RTVCommunicationSOPInstanceUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0035)", name: "RTV Source Identifier", keyword: "RTVSourceIdentifier", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0036)", name: "RTV Flow Identifier", keyword: "RTVFlowIdentifier", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0037)", name: "RTV Flow RTP Sampling Rate", keyword: "RTVFlowRTPSamplingRate", vr: Some(DicomVr::Ul), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0038)", name: "RTV Flow Actual Frame Duration", keyword: "RTVFlowActualFrameDuration", vr: Some(DicomVr::Fd), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0100)", name: "Private Information Creator UID", keyword: "PrivateInformationCreatorUID", vr: Some(DicomVr::Ui), vm: "1", attr_type: "3" },
-    DicomAttribute { tag: "(0002,0102)", name: "Private Information", keyword: "PrivateInformation", vr: Some(DicomVr::Ob), vm: "1", attr_type: "3" },
-];
-
-pub static TAG_INDEX: &[(&'static str, usize)] = &[
-    ("(0004,1130)", 0),
-    ("(0004,1141)", 1),
-    ("(0004,1142)", 2),
-    ("(0004,1200)", 3),
-    ("(0004,1202)", 4),
-    ("(0004,1212)", 5),
-    ("(0004,1220)", 6),
-    ("(0004,1400)", 7),
-    ("(0004,1410)", 8),
-    ("(0004,1420)", 9),
-    ("(0004,1430)", 10),
-    ("(0004,1432)", 11),
-    ("(0004,1500)", 12),
-    ("(0004,1510)", 13),
-    ("(0004,1511)", 14),
-    ("(0004,1512)", 15),
-    ("(0004,151A)", 16),
-    ("(0006,0001)", 17),
-    ("(0008,0005)", 18),
-    ("(0008,0006)", 19),
-    ("(0008,0008)", 20),
-    ("(0008,0012)", 21),
-    ("(0008,0013)", 22),
-    ("(0008,0014)", 23),
-    ("(0008,0015)", 24),
-    ("(0008,0016)", 25),
-    ("(0008,0017)", 26),
-    ("(0008,0018)", 27),
-    ("(0008,0019)", 28),
-    ("(0008,001A)", 29),
-    ("(0008,001B)", 30),
-    ("(0008,001C)", 31),
-    ("(0008,0020)", 32),
-    ("(0008,0021)", 33),
-    ("(0008,0022)", 34),
-    ("(0008,0023)", 35),
-    ("(0008,002A)", 36),
-    ("(0008,0030)", 37),
-    ("(0008,0031)", 38),
-    ("(0008,0032)", 39),
-    ("(0008,0033)", 40),
-    ("(0008,0050)", 41),
-    ("(0008,0051)", 42),
-    ("(0008,0052)", 43),
-    ("(0008,0053)", 44),
-    ("(0008,0054)", 45),
-    ("(0008,0055)", 46),
-    ("(0008,0056)", 47),
-    ("(0008,0060)", 48),
-    ("(0008,0061)", 49),
-    ("(0008,0063)", 50),
-    ("(0008,0064)", 51),
-    ("(0008,0068)", 52),
-    ("(0008,0070)", 53),
-    ("(0008,0080)", 54),
-    ("(0008,0081)", 55),
-    ("(0008,0082)", 56),
-    ("(0008,0090)", 57),
-    ("(0008,0096)", 58),
-    ("(0008,009C)", 59),
-    ("(0008,009D)", 60),
-    ("(0008,0100)", 61),
-    ("(0008,0102)", 62),
-    ("(0008,0103)", 63),
-    ("(0008,0104)", 64),
-    ("(0008,0105)", 65),
-    ("(0008,0106)", 66),
-    ("(0008,0107)", 67),
-    ("(0008,0109)", 68),
-    ("(0008,010A)", 69),
-    ("(0008,010B)", 70),
-    ("(0008,010C)", 71),
-    ("(0008,010D)", 72),
-    ("(0008,010E)", 73),
-    ("(0008,010F)", 74),
-    ("(0008,0110)", 75),
-    ("(0008,0112)", 76),
-    ("(0008,0114)", 77),
-    ("(0008,0115)", 78),
-    ("(0008,0116)", 79),
-    ("(0008,0117)", 80),
-    ("(0008,0118)", 81),
-    ("(0008,0119)", 82),
-    ("(0008,0120)", 83),
-    ("(0008,0121)", 84),
-    ("(0008,0122)", 85),
-    ("(0008,0123)", 86),
-    ("(0008,0124)", 87),
-    ("(0008,0201)", 88),
-    ("(0008,0220)", 89),
-    ("(0008,0221)", 90),
-    ("(0008,0222)", 91),
-    ("(0008,0300)", 92),
-    ("(0008,0301)", 93),
-    ("(0008,0302)", 94),
-    ("(0008,0303)", 95),
-    ("(0008,0304)", 96),
-    ("(0008,0305)", 97),
-    ("(0008,0306)", 98),
-    ("(0008,0307)", 99),
-    ("(0008,0308)", 100),
-    ("(0008,0309)", 101),
-    ("(0008,030A)", 102),
-    ("(0008,030B)", 103),
-    ("(0008,030C)", 104),
-    ("(0008,030D)", 105),
-    ("(0008,030E)", 106),
-    ("(0008,030F)", 107),
-    ("(0008,0310)", 108),
-    ("(0008,0400)", 109),
-    ("(0008,0401)", 110),
-    ("(0008,0402)", 111),
-    ("(0008,0403)", 112),
-    ("(0008,0404)", 113),
-    ("(0008,0405)", 114),
-    ("(0008,0406)", 115),
-    ("(0008,0407)", 116),
-    ("(0008,0408)", 117),
-    ("(0008,0409)", 118),
-    ("(0008,040A)", 119),
-    ("(0008,040B)", 120),
-    ("(0008,040C)", 121),
-    ("(0008,040D)", 122),
-    ("(0008,040E)", 123),
-    ("(0008,040F)", 124),
-    ("(0008,0410)", 125),
-    ("(0008,0411)", 126),
-    ("(0008,0412)", 127),
-    ("(0008,0413)", 128),
-    ("(0008,0416)", 129),
-    ("(0008,0419)", 130),
-    ("(0008,041A)", 131),
-    ("(0008,041F)", 132),
-    ("(0008,0420)", 133),
-    ("(0008,0421)", 134),
-    ("(0008,0422)", 135),
-    ("(0008,0423)", 136),
-    ("(0008,0424)", 137),
-    ("(0008,0425)", 138),
-    ("(0008,0426)", 139),
-    ("(0008,0427)", 140),
-    ("(0008,0428)", 141),
-    ("(0008,1010)", 142),
-    ("(0008,1030)", 143),
-    ("(0008,1032)", 144),
-    ("(0008,103E)", 145),
-    ("(0008,103F)", 146),
-    ("(0008,1040)", 147),
-    ("(0008,1041)", 148),
-    ("(0008,1048)", 149),
-    ("(0008,1049)", 150),
-    ("(0008,1050)", 151),
-    ("(0008,1052)", 152),
-    ("(0008,1060)", 153),
-    ("(0008,1062)", 154),
-    ("(0008,1070)", 155),
-    ("(0008,1072)", 156),
-    ("(0008,1080)", 157),
-    ("(0008,1084)", 158),
-    ("(0008,1088)", 159),
-    ("(0008,1090)", 160),
-    ("(0008,1110)", 161),
-    ("(0008,1111)", 162),
-    ("(0008,1115)", 163),
-    ("(0008,1120)", 164),
-    ("(0008,1134)", 165),
-    ("(0008,113A)", 166),
-    ("(0008,1140)", 167),
-    ("(0008,114A)", 168),
-    ("(0008,114B)", 169),
-    ("(0008,1150)", 170),
-    ("(0008,1155)", 171),
-    ("(0008,1156)", 172),
-    ("(0008,1160)", 173),
-    ("(0008,1161)", 174),
-    ("(0008,1162)", 175),
-    ("(0008,1163)", 176),
-    ("(0008,1164)", 177),
-    ("(0008,1167)", 178),
-    ("(0008,1190)", 179),
-    ("(0008,1195)", 180),
-    ("(0008,1199)", 181),
-    ("(0008,1200)", 182),
-    ("(0008,1250)", 183),
-    ("(0008,2111)", 184),
-    ("(0008,2112)", 185),
-    ("(0008,2120)", 186),
-    ("(0008,2122)", 187),
-    ("(0008,2124)", 188),
-    ("(0008,2127)", 189),
-    ("(0008,2128)", 190),
-    ("(0008,2129)", 191),
-    ("(0008,212A)", 192),
-    ("(0008,2130)", 193),
-    ("(0008,2132)", 194),
-    ("(0008,2133)", 195),
-    ("(0008,2134)", 196),
-    ("(0008,2135)", 197),
-    ("(0008,2142)", 198),
-    ("(0008,2143)", 199),
-    ("(0008,2144)", 200),
-    ("(0008,2218)", 201),
-    ("(0008,2220)", 202),
-    ("(0008,2228)", 203),
-    ("(0008,2230)", 204),
-    ("(0008,3001)", 205),
-    ("(0008,3010)", 206),
-    ("(0008,3011)", 207),
-    ("(0008,3012)", 208),
-    ("(0008,9007)", 209),
-    ("(0008,9092)", 210),
-    ("(0008,9121)", 211),
-    ("(0008,9123)", 212),
-    ("(0008,9124)", 213),
-    ("(0008,9154)", 214),
-    ("(0008,9205)", 215),
-    ("(0008,9206)", 216),
-    ("(0008,9207)", 217),
-    ("(0008,9208)", 218),
-    ("(0008,9209)", 219),
-    ("(0008,9215)", 220),
-    ("(0008,9237)", 221),
-    ("(0008,9410)", 222),
-    ("(0008,9458)", 223),
-    ("(0008,9459)", 224),
-    ("(0008,9460)", 225),
-    ("(0010,0010)", 226),
-    ("(0010,0020)", 227),
-    ("(0010,0021)", 228),
-    ("(0010,0022)", 229),
-    ("(0010,0024)", 230),
-    ("(0010,0026)", 231),
-    ("(0010,0027)", 232),
-    ("(0010,0028)", 233),
-    ("(0010,0030)", 234),
-    ("(0010,0032)", 235),
-    ("(0010,0033)", 236),
-    ("(0010,0034)", 237),
-    ("(0010,0035)", 238),
-    ("(0010,0040)", 239),
-    ("(0010,0200)", 240),
-    ("(0010,0212)", 241),
-    ("(0010,0213)", 242),
-    ("(0010,0214)", 243),
-    ("(0010,0215)", 244),
-    ("(0010,0216)", 245),
-    ("(0010,0217)", 246),
-    ("(0010,0218)", 247),
-    ("(0010,0219)", 248),
-    ("(0010,0221)", 249),
-    ("(0010,0222)", 250),
-    ("(0010,0223)", 251),
-    ("(0010,0229)", 252),
-    ("(0010,1001)", 253),
-    ("(0010,1002)", 254),
-    ("(0010,1010)", 255),
-    ("(0010,1020)", 256),
-    ("(0010,1021)", 257),
-    ("(0010,1022)", 258),
-    ("(0010,1023)", 259),
-    ("(0010,1024)", 260),
-    ("(0010,1030)", 261),
-    ("(0010,1100)", 262),
-    ("(0010,2000)", 263),
-    ("(0010,2110)", 264),
-    ("(0010,2160)", 265),
-    ("(0010,2180)", 266),
-    ("(0010,21A0)", 267),
-    ("(0010,21B0)", 268),
-    ("(0010,21C0)", 269),
-    ("(0010,21D0)", 270),
-    ("(0010,2201)", 271),
-    ("(0010,2202)", 272),
-    ("(0010,2203)", 273),
-    ("(0010,2210)", 274),
-    ("(0010,2292)", 275),
-    ("(0010,2293)", 276),
-    ("(0010,2294)", 277),
-    ("(0010,2295)", 278),
-    ("(0010,2296)", 279),
-    ("(0010,2297)", 280),
-    ("(0010,2298)", 281),
-    ("(0010,2299)", 282),
-    ("(0010,4000)", 283),
-    ("(0010,9431)", 284),
-    ("(0012,0010)", 285),
-    ("(0012,0020)", 286),
-    ("(0012,0021)", 287),
-    ("(0012,0022)", 288),
-    ("(0012,0023)", 289),
-    ("(0012,0030)", 290),
-    ("(0012,0031)", 291),
-    ("(0012,0032)", 292),
-    ("(0012,0040)", 293),
-    ("(0012,0041)", 294),
-    ("(0012,0042)", 295),
-    ("(0012,0043)", 296),
-    ("(0012,0050)", 297),
-    ("(0012,0051)", 298),
-    ("(0012,0052)", 299),
-    ("(0012,0053)", 300),
-    ("(0012,0054)", 301),
-    ("(0012,0055)", 302),
-    ("(0012,0060)", 303),
-    ("(0012,0062)", 304),
-    ("(0012,0063)", 305),
-    ("(0012,0064)", 306),
-    ("(0012,0071)", 307),
-    ("(0012,0072)", 308),
-    ("(0012,0073)", 309),
-    ("(0012,0081)", 310),
-    ("(0012,0082)", 311),
-    ("(0012,0083)", 312),
-    ("(0012,0084)", 313),
-    ("(0012,0085)", 314),
-    ("(0012,0086)", 315),
-    ("(0012,0087)", 316),
-    ("(0016,0001)", 317),
-    ("(0016,0002)", 318),
-    ("(0016,0003)", 319),
-    ("(0016,0004)", 320),
-    ("(0016,0005)", 321),
-    ("(0016,0006)", 322),
-    ("(0016,0007)", 323),
-    ("(0016,0008)", 324),
-    ("(0016,0009)", 325),
-    ("(0016,000A)", 326),
-    ("(0016,000B)", 327),
-    ("(0016,000C)", 328),
-    ("(0016,000D)", 329),
-    ("(0016,000E)", 330),
-    ("(0016,000F)", 331),
-    ("(0016,0010)", 332),
-    ("(0016,0011)", 333),
-    ("(0016,0012)", 334),
-    ("(0016,0013)", 335),
-    ("(0016,0014)", 336),
-    ("(0016,0015)", 337),
-    ("(0016,0016)", 338),
-    ("(0016,0017)", 339),
-    ("(0016,0018)", 340),
-    ("(0016,0019)", 341),
-    ("(0016,001A)", 342),
-    ("(0016,001B)", 343),
-    ("(0016,001C)", 344),
-    ("(0016,001D)", 345),
-    ("(0016,001E)", 346),
-    ("(0016,001F)", 347),
-    ("(0016,0020)", 348),
-    ("(0016,0021)", 349),
-    ("(0016,0022)", 350),
-    ("(0016,0023)", 351),
-    ("(0016,0024)", 352),
-    ("(0016,0025)", 353),
-    ("(0016,0026)", 354),
-    ("(0016,0027)", 355),
-    ("(0016,0028)", 356),
-    ("(0016,0029)", 357),
-    ("(0016,002A)", 358),
-    ("(0016,002B)", 359),
-    ("(0016,0030)", 360),
-    ("(0016,0031)", 361),
-    ("(0016,0032)", 362),
-    ("(0016,0033)", 363),
-    ("(0016,0034)", 364),
-    ("(0016,0035)", 365),
-    ("(0016,0036)", 366),
-    ("(0016,0037)", 367),
-    ("(0016,0038)", 368),
-    ("(0016,0039)", 369),
-    ("(0016,003A)", 370),
-    ("(0016,003B)", 371),
-    ("(0016,0041)", 372),
-    ("(0016,0042)", 373),
-    ("(0016,0043)", 374),
-    ("(0016,0044)", 375),
-    ("(0016,0045)", 376),
-    ("(0016,0046)", 377),
-    ("(0016,0047)", 378),
-    ("(0016,0048)", 379),
-    ("(0016,0049)", 380),
-    ("(0016,004A)", 381),
-    ("(0016,004B)", 382),
-    ("(0016,004C)", 383),
-    ("(0016,004D)", 384),
-    ("(0016,004E)", 385),
-    ("(0016,004F)", 386),
-    ("(0016,0050)", 387),
-    ("(0016,0051)", 388),
-    ("(0016,0061)", 389),
-    ("(0016,0062)", 390),
-    ("(0016,0070)", 391),
-    ("(0016,0071)", 392),
-    ("(0016,0072)", 393),
-    ("(0016,0073)", 394),
-    ("(0016,0074)", 395),
-    ("(0016,0075)", 396),
-    ("(0016,0076)", 397),
-    ("(0016,0077)", 398),
-    ("(0016,0078)", 399),
-    ("(0016,0079)", 400),
-    ("(0016,007A)", 401),
-    ("(0016,007B)", 402),
-    ("(0016,007C)", 403),
-    ("(0016,007D)", 404),
-    ("(0016,007E)", 405),
-    ("(0016,007F)", 406),
-    ("(0016,0080)", 407),
-    ("(0016,0081)", 408),
-    ("(0016,0082)", 409),
-    ("(0016,0083)", 410),
-    ("(0016,0084)", 411),
-    ("(0016,0085)", 412),
-    ("(0016,0086)", 413),
-    ("(0016,0087)", 414),
-    ("(0016,0088)", 415),
-    ("(0016,0089)", 416),
-    ("(0016,008A)", 417),
-    ("(0016,008B)", 418),
-    ("(0016,008C)", 419),
-    ("(0016,008D)", 420),
-    ("(0016,008E)", 421),
-    ("(0016,1001)", 422),
-    ("(0016,1002)", 423),
-    ("(0016,1003)", 424),
-    ("(0016,1004)", 425),
-    ("(0016,1005)", 426),
-    ("(0018,0010)", 427),
-    ("(0018,0012)", 428),
-    ("(0018,0013)", 429),
-    ("(0018,0014)", 430),
-    ("(0018,0015)", 431),
-    ("(0018,0020)", 432),
-    ("(0018,0021)", 433),
-    ("(0018,0022)", 434),
-    ("(0018,0023)", 435),
-    ("(0018,0024)", 436),
-    ("(0018,0025)", 437),
-    ("(0018,0026)", 438),
-    ("(0018,0027)", 439),
-    ("(0018,0028)", 440),
-    ("(0018,0029)", 441),
-    ("(0018,002A)", 442),
-    ("(0018,0031)", 443),
-    ("(0018,0034)", 444),
-    ("(0018,0035)", 445),
-    ("(0018,0036)", 446),
-    ("(0018,0038)", 447),
-    ("(0018,003A)", 448),
-    ("(0018,0040)", 449),
-    ("(0018,0042)", 450),
-    ("(0018,0050)", 451),
-    ("(0018,0060)", 452),
-    ("(0018,0070)", 453),
-    ("(0018,0071)", 454),
-    ("(0018,0072)", 455),
-    ("(0018,0073)", 456),
-    ("(0018,0074)", 457),
-    ("(0018,0075)", 458),
-    ("(0018,0080)", 459),
-    ("(0018,0081)", 460),
-    ("(0018,0082)", 461),
-    ("(0018,0083)", 462),
-    ("(0018,0084)", 463),
-    ("(0018,0085)", 464),
-    ("(0018,0086)", 465),
-    ("(0018,0087)", 466),
-    ("(0018,0088)", 467),
-    ("(0018,0089)", 468),
-    ("(0018,0090)", 469),
-    ("(0018,0091)", 470),
-    ("(0018,0093)", 471),
-    ("(0018,0094)", 472),
-    ("(0018,0095)", 473),
-    ("(0018,1000)", 474),
-    ("(0018,1002)", 475),
-    ("(0018,1003)", 476),
-    ("(0018,1004)", 477),
-    ("(0018,1005)", 478),
-    ("(0018,1006)", 479),
-    ("(0018,1007)", 480),
-    ("(0018,1008)", 481),
-    ("(0018,1009)", 482),
-    ("(0018,100A)", 483),
-    ("(0018,100B)", 484),
-    ("(0018,1010)", 485),
-    ("(0018,1012)", 486),
-    ("(0018,1014)", 487),
-    ("(0018,1016)", 488),
-    ("(0018,1018)", 489),
-    ("(0018,1019)", 490),
-    ("(0018,1020)", 491),
-    ("(0018,1022)", 492),
-    ("(0018,1023)", 493),
-    ("(0018,1030)", 494),
-    ("(0018,1040)", 495),
-    ("(0018,1041)", 496),
-    ("(0018,1042)", 497),
-    ("(0018,1043)", 498),
-    ("(0018,1044)", 499),
-    ("(0018,1045)", 500),
-    ("(0018,1046)", 501),
-    ("(0018,1047)", 502),
-    ("(0018,1048)", 503),
-    ("(0018,1049)", 504),
-    ("(0018,1050)", 505),
-    ("(0018,1060)", 506),
-    ("(0018,1061)", 507),
-    ("(0018,1062)", 508),
-    ("(0018,1063)", 509),
-    ("(0018,1064)", 510),
-    ("(0018,1065)", 511),
-    ("(0018,1066)", 512),
-    ("(0018,1067)", 513),
-    ("(0018,1068)", 514),
-    ("(0018,1069)", 515),
-    ("(0018,106A)", 516),
-    ("(0018,106C)", 517),
-    ("(0018,106E)", 518),
-    ("(0018,1070)", 519),
-    ("(0018,1071)", 520),
-    ("(0018,1072)", 521),
-    ("(0018,1073)", 522),
-    ("(0018,1074)", 523),
-    ("(0018,1075)", 524),
-    ("(0018,1076)", 525),
-    ("(0018,1077)", 526),
-    ("(0018,1078)", 527),
-    ("(0018,1079)", 528),
-    ("(0018,1080)", 529),
-    ("(0018,1081)", 530),
-    ("(0018,1082)", 531),
-    ("(0018,1083)", 532),
-    ("(0018,1084)", 533),
-    ("(0018,1085)", 534),
-    ("(0018,1086)", 535),
-    ("(0018,1088)", 536),
-    ("(0018,1090)", 537),
-    ("(0018,1094)", 538),
-    ("(0018,1100)", 539),
-    ("(0018,1110)", 540),
-    ("(0018,1111)", 541),
-    ("(0018,1114)", 542),
-    ("(0018,1120)", 543),
-    ("(0018,1121)", 544),
-    ("(0018,1130)", 545),
-    ("(0018,1131)", 546),
-    ("(0018,1134)", 547),
-    ("(0018,1135)", 548),
-    ("(0018,1136)", 549),
-    ("(0018,1137)", 550),
-    ("(0018,1138)", 551),
-    ("(0018,113A)", 552),
-    ("(0018,1140)", 553),
-    ("(0018,1142)", 554),
-    ("(0018,1143)", 555),
-    ("(0018,1144)", 556),
-    ("(0018,1145)", 557),
-    ("(0018,1147)", 558),
-    ("(0018,1149)", 559),
-    ("(0018,1150)", 560),
-    ("(0018,1151)", 561),
-    ("(0018,1152)", 562),
-    ("(0018,1153)", 563),
-    ("(0018,1154)", 564),
-    ("(0018,1155)", 565),
-    ("(0018,1156)", 566),
-    ("(0018,115A)", 567),
-    ("(0018,115E)", 568),
-    ("(0018,1160)", 569),
-    ("(0018,1161)", 570),
-    ("(0018,1162)", 571),
-    ("(0018,1164)", 572),
-    ("(0018,1166)", 573),
-    ("(0018,1170)", 574),
-    ("(0018,1180)", 575),
-    ("(0018,1181)", 576),
-    ("(0018,1182)", 577),
-    ("(0018,1183)", 578),
-    ("(0018,1184)", 579),
-    ("(0018,1190)", 580),
-    ("(0018,1191)", 581),
-    ("(0018,11A0)", 582),
-    ("(0018,11A2)", 583),
-    ("(0018,11A3)", 584),
-    ("(0018,11A4)", 585),
-    ("(0018,11A5)", 586),
-    ("(0018,11B0)", 587),
-    ("(0018,11B1)", 588),
-    ("(0018,11B2)", 589),
-    ("(0018,11B3)", 590),
-    ("(0018,11B4)", 591),
-    ("(0018,11B5)", 592),
-    ("(0018,11B6)", 593),
-    ("(0018,11B7)", 594),
-    ("(0018,11B8)", 595),
-    ("(0018,11B9)", 596),
-    ("(0018,11BA)", 597),
-    ("(0018,11BB)", 598),
-    ("(0018,11BC)", 599),
-    ("(0018,11BD)", 600),
-    ("(0018,11BE)", 601),
-    ("(0018,11BF)", 602),
-    ("(0018,11C0)", 603),
-    ("(0018,11C1)", 604),
-    ("(0018,1200)", 605),
-    ("(0018,1201)", 606),
-    ("(0018,1203)", 607),
-    ("(0018,1204)", 608),
-    ("(0018,1205)", 609),
-    ("(0018,1210)", 610),
-    ("(0018,1242)", 611),
-    ("(0018,1243)", 612),
-    ("(0018,1244)", 613),
-    ("(0018,1250)", 614),
-    ("(0018,1251)", 615),
-    ("(0018,1260)", 616),
-    ("(0018,1261)", 617),
-    ("(0018,1271)", 618),
-    ("(0018,1272)", 619),
-    ("(0018,1300)", 620),
-    ("(0018,1301)", 621),
-    ("(0018,1302)", 622),
-    ("(0018,1310)", 623),
-    ("(0018,1312)", 624),
-    ("(0018,1314)", 625),
-    ("(0018,1315)", 626),
-    ("(0018,1316)", 627),
-    ("(0018,1318)", 628),
-    ("(0018,1320)", 629),
-    ("(0018,1400)", 630),
-    ("(0018,1401)", 631),
-    ("(0018,1402)", 632),
-    ("(0018,1403)", 633),
-    ("(0018,1404)", 634),
-    ("(0018,1405)", 635),
-    ("(0018,1411)", 636),
-    ("(0018,1412)", 637),
-    ("(0018,1413)", 638),
-    ("(0018,1450)", 639),
-    ("(0018,1460)", 640),
-    ("(0018,1470)", 641),
-    ("(0018,1480)", 642),
-    ("(0018,1490)", 643),
-    ("(0018,1491)", 644),
-    ("(0018,1495)", 645),
-    ("(0018,1500)", 646),
-    ("(0018,1508)", 647),
-    ("(0018,1510)", 648),
-    ("(0018,1511)", 649),
-    ("(0018,1520)", 650),
-    ("(0018,1521)", 651),
-    ("(0018,1530)", 652),
-    ("(0018,1531)", 653),
-    ("(0018,1600)", 654),
-    ("(0018,1602)", 655),
-    ("(0018,1604)", 656),
-    ("(0018,1606)", 657),
-    ("(0018,1608)", 658),
-    ("(0018,1610)", 659),
-    ("(0018,1612)", 660),
-    ("(0018,1620)", 661),
-    ("(0018,1622)", 662),
-    ("(0018,1623)", 663),
-    ("(0018,1624)", 664),
-    ("(0018,1630)", 665),
-    ("(0018,1631)", 666),
-    ("(0018,1632)", 667),
-    ("(0018,1633)", 668),
-    ("(0018,1634)", 669),
-    ("(0018,1635)", 670),
-    ("(0018,1636)", 671),
-    ("(0018,1637)", 672),
-    ("(0018,1638)", 673),
-    ("(0018,1700)", 674),
-    ("(0018,1702)", 675),
-    ("(0018,1704)", 676),
-    ("(0018,1706)", 677),
-    ("(0018,1708)", 678),
-    ("(0018,1710)", 679),
-    ("(0018,1712)", 680),
-    ("(0018,1720)", 681),
-    ("(0018,1800)", 682),
-    ("(0018,1801)", 683),
-    ("(0018,1802)", 684),
-    ("(0018,1803)", 685),
-    ("(0018,2001)", 686),
-    ("(0018,2002)", 687),
-    ("(0018,2003)", 688),
-    ("(0018,2004)", 689),
-    ("(0018,2005)", 690),
-    ("(0018,2006)", 691),
-    ("(0018,2010)", 692),
-    ("(0018,2020)", 693),
-    ("(0018,2030)", 694),
-    ("(0018,2041)", 695),
-    ("(0018,2042)", 696),
-    ("(0018,2043)", 697),
-    ("(0018,2044)", 698),
-    ("(0018,2045)", 699),
-    ("(0018,2046)", 700),
-    ("(0018,3100)", 701),
-    ("(0018,3101)", 702),
-    ("(0018,3102)", 703),
-    ("(0018,3103)", 704),
-    ("(0018,3104)", 705),
-    ("(0018,3105)", 706),
-    ("(0018,5000)", 707),
-    ("(0018,5010)", 708),
-    ("(0018,5011)", 709),
-    ("(0018,5012)", 710),
-    ("(0018,5020)", 711),
-    ("(0018,5022)", 712),
-    ("(0018,5024)", 713),
-    ("(0018,5026)", 714),
-    ("(0018,5027)", 715),
-    ("(0018,5028)", 716),
-    ("(0018,5029)", 717),
-    ("(0018,5050)", 718),
-    ("(0018,5100)", 719),
-    ("(0018,5101)", 720),
-    ("(0018,5104)", 721),
-    ("(0018,6000)", 722),
-    ("(0018,6011)", 723),
-    ("(0018,6012)", 724),
-    ("(0018,6014)", 725),
-    ("(0018,6016)", 726),
-    ("(0018,6018)", 727),
-    ("(0018,601A)", 728),
-    ("(0018,601C)", 729),
-    ("(0018,601E)", 730),
-    ("(0018,6020)", 731),
-    ("(0018,6022)", 732),
-    ("(0018,6024)", 733),
-    ("(0018,6026)", 734),
-    ("(0018,6028)", 735),
-    ("(0018,602A)", 736),
-    ("(0018,602C)", 737),
-    ("(0018,602E)", 738),
-    ("(0018,6030)", 739),
-    ("(0018,6031)", 740),
-    ("(0018,6032)", 741),
-    ("(0018,6034)", 742),
-    ("(0018,6036)", 743),
-    ("(0018,6039)", 744),
-    ("(0018,603B)", 745),
-    ("(0018,603D)", 746),
-    ("(0018,603F)", 747),
-    ("(0018,6041)", 748),
-    ("(0018,6043)", 749),
-    ("(0018,6044)", 750),
-    ("(0018,6046)", 751),
-    ("(0018,6048)", 752),
-    ("(0018,604A)", 753),
-    ("(0018,604C)", 754),
-    ("(0018,604E)", 755),
-    ("(0018,6050)", 756),
-    ("(0018,6052)", 757),
-    ("(0018,6054)", 758),
-    ("(0018,6056)", 759),
-    ("(0018,6058)", 760),
-    ("(0018,605A)", 761),
-    ("(0018,6060)", 762),
-    ("(0018,6070)", 763),
-    ("(0018,7000)", 764),
-    ("(0018,7001)", 765),
-    ("(0018,7004)", 766),
-    ("(0018,7005)", 767),
-    ("(0018,7006)", 768),
-    ("(0018,7008)", 769),
-    ("(0018,700A)", 770),
-    ("(0018,700C)", 771),
-    ("(0018,700E)", 772),
-    ("(0018,7010)", 773),
-    ("(0018,7011)", 774),
-    ("(0018,7012)", 775),
-    ("(0018,7014)", 776),
-    ("(0018,7016)", 777),
-    ("(0018,701A)", 778),
-    ("(0018,7020)", 779),
-    ("(0018,7022)", 780),
-    ("(0018,7024)", 781),
-    ("(0018,7026)", 782),
-    ("(0018,7028)", 783),
-    ("(0018,702A)", 784),
-    ("(0018,702B)", 785),
-    ("(0018,7030)", 786),
-    ("(0018,7032)", 787),
-    ("(0018,7034)", 788),
-    ("(0018,7036)", 789),
-    ("(0018,7038)", 790),
-    ("(0018,7040)", 791),
-    ("(0018,7041)", 792),
-    ("(0018,7042)", 793),
-    ("(0018,7044)", 794),
-    ("(0018,7046)", 795),
-    ("(0018,7048)", 796),
-    ("(0018,704C)", 797),
-    ("(0018,7050)", 798),
-    ("(0018,7052)", 799),
-    ("(0018,7054)", 800),
-    ("(0018,7056)", 801),
-    ("(0018,7058)", 802),
-    ("(0018,7060)", 803),
-    ("(0018,7062)", 804),
-    ("(0018,7064)", 805),
-    ("(0018,7065)", 806),
-    ("(0018,8150)", 807),
-    ("(0018,8151)", 808),
-    ("(0018,9004)", 809),
-    ("(0018,9005)", 810),
-    ("(0018,9006)", 811),
-    ("(0018,9008)", 812),
-    ("(0018,9009)", 813),
-    ("(0018,9010)", 814),
-    ("(0018,9011)", 815),
-    ("(0018,9012)", 816),
-    ("(0018,9014)", 817),
-    ("(0018,9015)", 818),
-    ("(0018,9016)", 819),
-    ("(0018,9017)", 820),
-    ("(0018,9018)", 821),
-    ("(0018,9019)", 822),
-    ("(0018,9020)", 823),
-    ("(0018,9021)", 824),
-    ("(0018,9022)", 825),
-    ("(0018,9024)", 826),
-    ("(0018,9025)", 827),
-    ("(0018,9026)", 828),
-    ("(0018,9027)", 829),
-    ("(0018,9028)", 830),
-    ("(0018,9029)", 831),
-    ("(0018,9030)", 832),
-    ("(0018,9032)", 833),
-    ("(0018,9033)", 834),
-    ("(0018,9034)", 835),
-    ("(0018,9035)", 836),
-    ("(0018,9036)", 837),
-    ("(0018,9037)", 838),
-    ("(0018,9041)", 839),
-    ("(0018,9042)", 840),
-    ("(0018,9043)", 841),
-    ("(0018,9044)", 842),
-    ("(0018,9045)", 843),
-    ("(0018,9046)", 844),
-    ("(0018,9047)", 845),
-    ("(0018,9048)", 846),
-    ("(0018,9049)", 847),
-    ("(0018,9050)", 848),
-    ("(0018,9051)", 849),
-    ("(0018,9052)", 850),
-    ("(0018,9053)", 851),
-    ("(0018,9054)", 852),
-    ("(0018,9058)", 853),
-    ("(0018,9059)", 854),
-    ("(0018,9060)", 855),
-    ("(0018,9061)", 856),
-    ("(0018,9062)", 857),
-    ("(0018,9063)", 858),
-    ("(0018,9064)", 859),
-    ("(0018,9065)", 860),
-    ("(0018,9066)", 861),
-    ("(0018,9067)", 862),
-    ("(0018,9069)", 863),
-    ("(0018,9070)", 864),
-    ("(0018,9073)", 865),
-    ("(0018,9074)", 866),
-    ("(0018,9075)", 867),
-    ("(0018,9076)", 868),
-    ("(0018,9077)", 869),
-    ("(0018,9078)", 870),
-    ("(0018,9079)", 871),
-    ("(0018,9080)", 872),
-    ("(0018,9081)", 873),
-    ("(0018,9082)", 874),
-    ("(0018,9083)", 875),
-    ("(0018,9084)", 876),
-    ("(0018,9085)", 877),
-    ("(0018,9087)", 878),
-    ("(0018,9089)", 879),
-    ("(0018,9090)", 880),
-    ("(0018,9091)", 881),
-    ("(0018,9092)", 882),
-    ("(0018,9093)", 883),
-    ("(0018,9094)", 884),
-    ("(0018,9095)", 885),
-    ("(0018,9098)", 886),
-    ("(0018,9100)", 887),
-    ("(0018,9101)", 888),
-    ("(0018,9103)", 889),
-    ("(0018,9104)", 890),
-    ("(0018,9105)", 891),
-    ("(0018,9106)", 892),
-    ("(0018,9107)", 893),
-    ("(0018,9112)", 894),
-    ("(0018,9114)", 895),
-    ("(0018,9115)", 896),
-    ("(0018,9117)", 897),
-    ("(0018,9118)", 898),
-    ("(0018,9119)", 899),
-    ("(0018,9125)", 900),
-    ("(0018,9126)", 901),
-    ("(0018,9127)", 902),
-    ("(0018,9147)", 903),
-    ("(0018,9151)", 904),
-    ("(0018,9152)", 905),
-    ("(0018,9155)", 906),
-    ("(0018,9159)", 907),
-    ("(0018,9168)", 908),
-    ("(0018,9169)", 909),
-    ("(0018,9170)", 910),
-    ("(0018,9171)", 911),
-    ("(0018,9172)", 912),
-    ("(0018,9173)", 913),
-    ("(0018,9174)", 914),
-    ("(0018,9175)", 915),
-    ("(0018,9176)", 916),
-    ("(0018,9177)", 917),
-    ("(0018,9178)", 918),
-    ("(0018,9179)", 919),
-    ("(0018,9180)", 920),
-    ("(0018,9181)", 921),
-    ("(0018,9182)", 922),
-    ("(0018,9183)", 923),
-    ("(0018,9184)", 924),
-    ("(0018,9185)", 925),
-    ("(0018,9186)", 926),
-    ("(0018,9197)", 927),
-    ("(0018,9198)", 928),
-    ("(0018,9199)", 929),
-    ("(0018,9200)", 930),
-    ("(0018,9214)", 931),
-    ("(0018,9217)", 932),
-    ("(0018,9218)", 933),
-    ("(0018,9219)", 934),
-    ("(0018,9220)", 935),
-    ("(0018,9226)", 936),
-    ("(0018,9227)", 937),
-    ("(0018,9231)", 938),
-    ("(0018,9232)", 939),
-    ("(0018,9234)", 940),
-    ("(0018,9236)", 941),
-    ("(0018,9239)", 942),
-    ("(0018,9240)", 943),
-    ("(0018,9241)", 944),
-    ("(0018,9250)", 945),
-    ("(0018,9251)", 946),
-    ("(0018,9252)", 947),
-    ("(0018,9253)", 948),
-    ("(0018,9254)", 949),
-    ("(0018,9255)", 950),
-    ("(0018,9256)", 951),
-    ("(0018,9257)", 952),
-    ("(0018,9258)", 953),
-    ("(0018,9259)", 954),
-    ("(0018,925A)", 955),
-    ("(0018,925B)", 956),
-    ("(0018,925C)", 957),
-    ("(0018,925D)", 958),
-    ("(0018,925E)", 959),
-    ("(0018,925F)", 960),
-    ("(0018,9260)", 961),
-    ("(0018,9295)", 962),
-    ("(0018,9296)", 963),
-    ("(0018,9297)", 964),
-    ("(0018,9298)", 965),
-    ("(0018,9301)", 966),
-    ("(0018,9302)", 967),
-    ("(0018,9303)", 968),
-    ("(0018,9304)", 969),
-    ("(0018,9305)", 970),
-    ("(0018,9306)", 971),
-    ("(0018,9307)", 972),
-    ("(0018,9308)", 973),
-    ("(0018,9309)", 974),
-    ("(0018,9310)", 975),
-    ("(0018,9311)", 976),
-    ("(0018,9312)", 977),
-    ("(0018,9313)", 978),
-    ("(0018,9314)", 979),
-    ("(0018,9315)", 980),
-    ("(0018,9316)", 981),
-    ("(0018,9317)", 982),
-    ("(0018,9318)", 983),
-    ("(0018,9319)", 984),
-    ("(0018,9320)", 985),
-    ("(0018,9321)", 986),
-    ("(0018,9322)", 987),
-    ("(0018,9323)", 988),
-    ("(0018,9325)", 989),
-    ("(0018,9326)", 990),
-    ("(0018,9327)", 991),
-    ("(0018,9328)", 992),
-    ("(0018,9329)", 993),
-    ("(0018,9330)", 994),
-    ("(0018,9332)", 995),
-    ("(0018,9333)", 996),
-    ("(0018,9334)", 997),
-    ("(0018,9335)", 998),
-    ("(0018,9337)", 999),
-    ("(0018,9338)", 1000),
-    ("(0018,9340)", 1001),
-    ("(0018,9341)", 1002),
-    ("(0018,9342)", 1003),
-    ("(0018,9343)", 1004),
-    ("(0018,9344)", 1005),
-    ("(0018,9345)", 1006),
-    ("(0018,9346)", 1007),
-    ("(0018,9351)", 1008),
-    ("(0018,9352)", 1009),
-    ("(0018,9353)", 1010),
-    ("(0018,9360)", 1011),
-    ("(0018,9361)", 1012),
-    ("(0018,9362)", 1013),
-    ("(0018,9363)", 1014),
-    ("(0018,9364)", 1015),
-    ("(0018,9365)", 1016),
-    ("(0018,9366)", 1017),
-    ("(0018,9367)", 1018),
-    ("(0018,9368)", 1019),
-    ("(0018,9369)", 1020),
-    ("(0018,936A)", 1021),
-    ("(0018,936B)", 1022),
-    ("(0018,936C)", 1023),
-    ("(0018,936D)", 1024),
-    ("(0018,936E)", 1025),
-    ("(0018,936F)", 1026),
-    ("(0018,9370)", 1027),
-    ("(0018,9371)", 1028),
-    ("(0018,9372)", 1029),
-    ("(0018,9373)", 1030),
-    ("(0018,9374)", 1031),
-    ("(0018,9375)", 1032),
-    ("(0018,9376)", 1033),
-    ("(0018,9377)", 1034),
-    ("(0018,9378)", 1035),
-    ("(0018,9379)", 1036),
-    ("(0018,937A)", 1037),
-    ("(0018,937B)", 1038),
-    ("(0018,937C)", 1039),
-    ("(0018,937D)", 1040),
-    ("(0018,937E)", 1041),
-    ("(0018,937F)", 1042),
-    ("(0018,9380)", 1043),
-    ("(0018,9381)", 1044),
-    ("(0018,9382)", 1045),
-    ("(0018,9383)", 1046),
-    ("(0018,9384)", 1047),
-    ("(0018,9401)", 1048),
-    ("(0018,9402)", 1049),
-    ("(0018,9403)", 1050),
-    ("(0018,9404)", 1051),
-    ("(0018,9405)", 1052),
-    ("(0018,9406)", 1053),
-    ("(0018,9407)", 1054),
-    ("(0018,9410)", 1055),
-    ("(0018,9412)", 1056),
-    ("(0018,9417)", 1057),
-    ("(0018,9420)", 1058),
-    ("(0018,9423)", 1059),
-    ("(0018,9424)", 1060),
-    ("(0018,9425)", 1061),
-    ("(0018,9426)", 1062),
-    ("(0018,9427)", 1063),
-    ("(0018,9428)", 1064),
-    ("(0018,9429)", 1065),
-    ("(0018,9430)", 1066),
-    ("(0018,9432)", 1067),
-    ("(0018,9433)", 1068),
-    ("(0018,9434)", 1069),
-    ("(0018,9435)", 1070),
-    ("(0018,9436)", 1071),
-    ("(0018,9437)", 1072),
-    ("(0018,9438)", 1073),
-    ("(0018,9439)", 1074),
-    ("(0018,9440)", 1075),
-    ("(0018,9441)", 1076),
-    ("(0018,9442)", 1077),
-    ("(0018,9447)", 1078),
-    ("(0018,9449)", 1079),
-    ("(0018,9451)", 1080),
-    ("(0018,9452)", 1081),
-    ("(0018,9455)", 1082),
-    ("(0018,9456)", 1083),
-    ("(0018,9457)", 1084),
-    ("(0018,9461)", 1085),
-    ("(0018,9462)", 1086),
-    ("(0018,9463)", 1087),
-    ("(0018,9464)", 1088),
-    ("(0018,9465)", 1089),
-    ("(0018,9466)", 1090),
-    ("(0018,9467)", 1091),
-    ("(0018,9468)", 1092),
-    ("(0018,9469)", 1093),
-    ("(0018,9470)", 1094),
-    ("(0018,9471)", 1095),
-    ("(0018,9472)", 1096),
-    ("(0018,9473)", 1097),
-    ("(0018,9474)", 1098),
-    ("(0018,9476)", 1099),
-    ("(0018,9477)", 1100),
-    ("(0018,9504)", 1101),
-    ("(0018,9506)", 1102),
-    ("(0018,9507)", 1103),
-    ("(0018,9508)", 1104),
-    ("(0018,9509)", 1105),
-    ("(0018,9510)", 1106),
-    ("(0018,9511)", 1107),
-    ("(0018,9514)", 1108),
-    ("(0018,9515)", 1109),
-    ("(0018,9516)", 1110),
-    ("(0018,9517)", 1111),
-    ("(0018,9518)", 1112),
-    ("(0018,9519)", 1113),
-    ("(0018,9524)", 1114),
-    ("(0018,9525)", 1115),
-    ("(0018,9526)", 1116),
-    ("(0018,9527)", 1117),
-    ("(0018,9528)", 1118),
-    ("(0018,9530)", 1119),
-    ("(0018,9531)", 1120),
-    ("(0018,9538)", 1121),
-    ("(0018,9541)", 1122),
-    ("(0018,9542)", 1123),
-    ("(0018,9543)", 1124),
-    ("(0018,9544)", 1125),
-    ("(0018,9545)", 1126),
-    ("(0018,9546)", 1127),
-    ("(0018,9547)", 1128),
-    ("(0018,9548)", 1129),
-    ("(0018,9549)", 1130),
-    ("(0018,9550)", 1131),
-    ("(0018,9551)", 1132),
-    ("(0018,9552)", 1133),
-    ("(0018,9553)", 1134),
-    ("(0018,9554)", 1135),
-    ("(0018,9555)", 1136),
-    ("(0018,9556)", 1137),
-    ("(0018,9557)", 1138),
-    ("(0018,9558)", 1139),
-    ("(0018,9559)", 1140),
-    ("(0018,9601)", 1141),
-    ("(0018,9602)", 1142),
-    ("(0018,9603)", 1143),
-    ("(0018,9604)", 1144),
-    ("(0018,9605)", 1145),
-    ("(0018,9606)", 1146),
-    ("(0018,9607)", 1147),
-    ("(0018,9621)", 1148),
-    ("(0018,9622)", 1149),
-    ("(0018,9623)", 1150),
-    ("(0018,9624)", 1151),
-    ("(0018,9701)", 1152),
-    ("(0018,9715)", 1153),
-    ("(0018,9716)", 1154),
-    ("(0018,9717)", 1155),
-    ("(0018,9718)", 1156),
-    ("(0018,9719)", 1157),
-    ("(0018,9720)", 1158),
-    ("(0018,9721)", 1159),
-    ("(0018,9722)", 1160),
-    ("(0018,9723)", 1161),
-    ("(0018,9724)", 1162),
-    ("(0018,9725)", 1163),
-    ("(0018,9726)", 1164),
-    ("(0018,9727)", 1165),
-    ("(0018,9729)", 1166),
-    ("(0018,9732)", 1167),
-    ("(0018,9733)", 1168),
-    ("(0018,9734)", 1169),
-    ("(0018,9735)", 1170),
-    ("(0018,9736)", 1171),
-    ("(0018,9737)", 1172),
-    ("(0018,9738)", 1173),
-    ("(0018,9739)", 1174),
-    ("(0018,9740)", 1175),
-    ("(0018,9749)", 1176),
-    ("(0018,9751)", 1177),
-    ("(0018,9755)", 1178),
-    ("(0018,9756)", 1179),
-    ("(0018,9758)", 1180),
-    ("(0018,9759)", 1181),
-    ("(0018,9760)", 1182),
-    ("(0018,9761)", 1183),
-    ("(0018,9762)", 1184),
-    ("(0018,9763)", 1185),
-    ("(0018,9764)", 1186),
-    ("(0018,9765)", 1187),
-    ("(0018,9766)", 1188),
-    ("(0018,9767)", 1189),
-    ("(0018,9768)", 1190),
-    ("(0018,9769)", 1191),
-    ("(0018,9770)", 1192),
-    ("(0018,9771)", 1193),
-    ("(0018,9772)", 1194),
-    ("(0018,9801)", 1195),
-    ("(0018,9803)", 1196),
-    ("(0018,9804)", 1197),
-    ("(0018,9805)", 1198),
-    ("(0018,9806)", 1199),
-    ("(0018,9807)", 1200),
-    ("(0018,9808)", 1201),
-    ("(0018,9809)", 1202),
-    ("(0018,980B)", 1203),
-    ("(0018,980C)", 1204),
-    ("(0018,980D)", 1205),
-    ("(0018,980E)", 1206),
-    ("(0018,980F)", 1207),
-    ("(0018,9810)", 1208),
-    ("(0018,9821)", 1209),
-    ("(0018,9822)", 1210),
-    ("(0018,9823)", 1211),
-    ("(0018,9824)", 1212),
-    ("(0018,9825)", 1213),
-    ("(0018,9826)", 1214),
-    ("(0018,9828)", 1215),
-    ("(0018,9829)", 1216),
-    ("(0018,982A)", 1217),
-    ("(0018,982B)", 1218),
-    ("(0018,982C)", 1219),
-    ("(0018,982D)", 1220),
-    ("(0018,982E)", 1221),
-    ("(0018,982F)", 1222),
-    ("(0018,9830)", 1223),
-    ("(0018,9831)", 1224),
-    ("(0018,9832)", 1225),
-    ("(0018,9833)", 1226),
-    ("(0018,9834)", 1227),
-    ("(0018,9835)", 1228),
-    ("(0018,9836)", 1229),
-    ("(0018,9900)", 1230),
-    ("(0018,9901)", 1231),
-    ("(0018,9902)", 1232),
-    ("(0018,9903)", 1233),
-    ("(0018,9904)", 1234),
-    ("(0018,9905)", 1235),
-    ("(0018,9906)", 1236),
-    ("(0018,9907)", 1237),
-    ("(0018,9908)", 1238),
-    ("(0018,9909)", 1239),
-    ("(0018,990A)", 1240),
-    ("(0018,990B)", 1241),
-    ("(0018,990C)", 1242),
-    ("(0018,990D)", 1243),
-    ("(0018,990E)", 1244),
-    ("(0018,990F)", 1245),
-    ("(0018,9910)", 1246),
-    ("(0018,9911)", 1247),
-    ("(0018,9912)", 1248),
-    ("(0018,9913)", 1249),
-    ("(0018,9914)", 1250),
-    ("(0018,9915)", 1251),
-    ("(0018,9916)", 1252),
-    ("(0018,9917)", 1253),
-    ("(0018,9918)", 1254),
-    ("(0018,9919)", 1255),
-    ("(0018,991A)", 1256),
-    ("(0018,991B)", 1257),
-    ("(0018,991C)", 1258),
-    ("(0018,991D)", 1259),
-    ("(0018,991E)", 1260),
-    ("(0018,991F)", 1261),
-    ("(0018,9920)", 1262),
-    ("(0018,9921)", 1263),
-    ("(0018,9922)", 1264),
-    ("(0018,9923)", 1265),
-    ("(0018,9924)", 1266),
-    ("(0018,9930)", 1267),
-    ("(0018,9931)", 1268),
-    ("(0018,9932)", 1269),
-    ("(0018,9933)", 1270),
-    ("(0018,9934)", 1271),
-    ("(0018,9935)", 1272),
-    ("(0018,9936)", 1273),
-    ("(0018,9937)", 1274),
-    ("(0018,9938)", 1275),
-    ("(0018,9939)", 1276),
-    ("(0018,993A)", 1277),
-    ("(0018,993B)", 1278),
-    ("(0018,993C)", 1279),
-    ("(0018,993D)", 1280),
-    ("(0018,993E)", 1281),
-    ("(0018,9941)", 1282),
-    ("(0018,9942)", 1283),
-    ("(0018,9943)", 1284),
-    ("(0018,9944)", 1285),
-    ("(0018,9945)", 1286),
-    ("(0018,9946)", 1287),
-    ("(0018,9947)", 1288),
-    ("(0018,A001)", 1289),
-    ("(0018,A002)", 1290),
-    ("(0018,A003)", 1291),
-    ("(0020,000D)", 1292),
-    ("(0020,000E)", 1293),
-    ("(0020,0010)", 1294),
-    ("(0020,0011)", 1295),
-    ("(0020,0012)", 1296),
-    ("(0020,0013)", 1297),
-    ("(0020,0020)", 1298),
-    ("(0020,0027)", 1299),
-    ("(0020,0032)", 1300),
-    ("(0020,0037)", 1301),
-    ("(0020,0052)", 1302),
-    ("(0020,0060)", 1303),
-    ("(0020,0062)", 1304),
-    ("(0020,0100)", 1305),
-    ("(0020,0105)", 1306),
-    ("(0020,0110)", 1307),
-    ("(0020,0200)", 1308),
-    ("(0020,0242)", 1309),
-    ("(0020,1002)", 1310),
-    ("(0020,103F)", 1311),
-    ("(0020,1040)", 1312),
-    ("(0020,1041)", 1313),
-    ("(0020,1206)", 1314),
-    ("(0020,1208)", 1315),
-    ("(0020,4000)", 1316),
-    ("(0020,9056)", 1317),
-    ("(0020,9057)", 1318),
-    ("(0020,9071)", 1319),
-    ("(0020,9072)", 1320),
-    ("(0020,9111)", 1321),
-    ("(0020,9113)", 1322),
-    ("(0020,9116)", 1323),
-    ("(0020,9128)", 1324),
-    ("(0020,9153)", 1325),
-    ("(0020,9154)", 1326),
-    ("(0020,9155)", 1327),
-    ("(0020,9156)", 1328),
-    ("(0020,9157)", 1329),
-    ("(0020,9158)", 1330),
-    ("(0020,9161)", 1331),
-    ("(0020,9162)", 1332),
-    ("(0020,9163)", 1333),
-    ("(0020,9164)", 1334),
-    ("(0020,9165)", 1335),
-    ("(0020,9167)", 1336),
-    ("(0020,9170)", 1337),
-    ("(0020,9171)", 1338),
-    ("(0020,9172)", 1339),
-    ("(0020,9213)", 1340),
-    ("(0020,9221)", 1341),
-    ("(0020,9222)", 1342),
-    ("(0020,9228)", 1343),
-    ("(0020,9238)", 1344),
-    ("(0020,9241)", 1345),
-    ("(0020,9245)", 1346),
-    ("(0020,9246)", 1347),
-    ("(0020,9247)", 1348),
-    ("(0020,9248)", 1349),
-    ("(0020,9249)", 1350),
-    ("(0020,9250)", 1351),
-    ("(0020,9251)", 1352),
-    ("(0020,9252)", 1353),
-    ("(0020,9253)", 1354),
-    ("(0020,9254)", 1355),
-    ("(0020,9255)", 1356),
-    ("(0020,9256)", 1357),
-    ("(0020,9257)", 1358),
-    ("(0020,9301)", 1359),
-    ("(0020,9302)", 1360),
-    ("(0020,9307)", 1361),
-    ("(0020,9308)", 1362),
-    ("(0020,9309)", 1363),
-    ("(0020,930A)", 1364),
-    ("(0020,930B)", 1365),
-    ("(0020,930C)", 1366),
-    ("(0020,930D)", 1367),
-    ("(0020,930E)", 1368),
-    ("(0020,930F)", 1369),
-    ("(0020,9310)", 1370),
-    ("(0020,9311)", 1371),
-    ("(0020,9312)", 1372),
-    ("(0020,9313)", 1373),
-    ("(0020,9421)", 1374),
-    ("(0020,9450)", 1375),
-    ("(0020,9453)", 1376),
-    ("(0020,9518)", 1377),
-    ("(0020,9529)", 1378),
-    ("(0020,9536)", 1379),
-    ("(0022,0001)", 1380),
-    ("(0022,0002)", 1381),
-    ("(0022,0003)", 1382),
-    ("(0022,0004)", 1383),
-    ("(0022,0005)", 1384),
-    ("(0022,0006)", 1385),
-    ("(0022,0007)", 1386),
-    ("(0022,0008)", 1387),
-    ("(0022,0009)", 1388),
-    ("(0022,000A)", 1389),
-    ("(0022,000B)", 1390),
-    ("(0022,000C)", 1391),
-    ("(0022,000D)", 1392),
-    ("(0022,000E)", 1393),
-    ("(0022,000F)", 1394),
-    ("(0022,0010)", 1395),
-    ("(0022,0011)", 1396),
-    ("(0022,0012)", 1397),
-    ("(0022,0013)", 1398),
-    ("(0022,0014)", 1399),
-    ("(0022,0015)", 1400),
-    ("(0022,0016)", 1401),
-    ("(0022,0017)", 1402),
-    ("(0022,0018)", 1403),
-    ("(0022,0019)", 1404),
-    ("(0022,001A)", 1405),
-    ("(0022,001B)", 1406),
-    ("(0022,001C)", 1407),
-    ("(0022,001D)", 1408),
-    ("(0022,001E)", 1409),
-    ("(0022,0020)", 1410),
-    ("(0022,0021)", 1411),
-    ("(0022,0022)", 1412),
-    ("(0022,0028)", 1413),
-    ("(0022,0030)", 1414),
-    ("(0022,0031)", 1415),
-    ("(0022,0032)", 1416),
-    ("(0022,0035)", 1417),
-    ("(0022,0036)", 1418),
-    ("(0022,0037)", 1419),
-    ("(0022,0038)", 1420),
-    ("(0022,0039)", 1421),
-    ("(0022,0041)", 1422),
-    ("(0022,0042)", 1423),
-    ("(0022,0048)", 1424),
-    ("(0022,0049)", 1425),
-    ("(0022,004E)", 1426),
-    ("(0022,0055)", 1427),
-    ("(0022,0056)", 1428),
-    ("(0022,0057)", 1429),
-    ("(0022,0058)", 1430),
-    ("(0022,1007)", 1431),
-    ("(0022,1008)", 1432),
-    ("(0022,1009)", 1433),
-    ("(0022,1010)", 1434),
-    ("(0022,1012)", 1435),
-    ("(0022,1019)", 1436),
-    ("(0022,1024)", 1437),
-    ("(0022,1025)", 1438),
-    ("(0022,1028)", 1439),
-    ("(0022,1029)", 1440),
-    ("(0022,1033)", 1441),
-    ("(0022,1035)", 1442),
-    ("(0022,1036)", 1443),
-    ("(0022,1037)", 1444),
-    ("(0022,1039)", 1445),
-    ("(0022,1040)", 1446),
-    ("(0022,1044)", 1447),
-    ("(0022,1045)", 1448),
-    ("(0022,1046)", 1449),
-    ("(0022,1047)", 1450),
-    ("(0022,1048)", 1451),
-    ("(0022,1049)", 1452),
-    ("(0022,104A)", 1453),
-    ("(0022,104B)", 1454),
-    ("(0022,1050)", 1455),
-    ("(0022,1053)", 1456),
-    ("(0022,1054)", 1457),
-    ("(0022,1059)", 1458),
-    ("(0022,1065)", 1459),
-    ("(0022,1066)", 1460),
-    ("(0022,1090)", 1461),
-    ("(0022,1092)", 1462),
-    ("(0022,1093)", 1463),
-    ("(0022,1095)", 1464),
-    ("(0022,1096)", 1465),
-    ("(0022,1097)", 1466),
-    ("(0022,1101)", 1467),
-    ("(0022,1103)", 1468),
-    ("(0022,1121)", 1469),
-    ("(0022,1122)", 1470),
-    ("(0022,1125)", 1471),
-    ("(0022,1127)", 1472),
-    ("(0022,1128)", 1473),
-    ("(0022,112A)", 1474),
-    ("(0022,112B)", 1475),
-    ("(0022,112C)", 1476),
-    ("(0022,1130)", 1477),
-    ("(0022,1131)", 1478),
-    ("(0022,1132)", 1479),
-    ("(0022,1133)", 1480),
-    ("(0022,1134)", 1481),
-    ("(0022,1135)", 1482),
-    ("(0022,1140)", 1483),
-    ("(0022,1150)", 1484),
-    ("(0022,1155)", 1485),
-    ("(0022,1159)", 1486),
-    ("(0022,1210)", 1487),
-    ("(0022,1211)", 1488),
-    ("(0022,1212)", 1489),
-    ("(0022,1220)", 1490),
-    ("(0022,1225)", 1491),
-    ("(0022,1230)", 1492),
-    ("(0022,1250)", 1493),
-    ("(0022,1255)", 1494),
-    ("(0022,1257)", 1495),
-    ("(0022,1260)", 1496),
-    ("(0022,1262)", 1497),
-    ("(0022,1300)", 1498),
-    ("(0022,1310)", 1499),
-    ("(0022,1330)", 1500),
-    ("(0022,1415)", 1501),
-    ("(0022,1420)", 1502),
-    ("(0022,1423)", 1503),
-    ("(0022,1436)", 1504),
-    ("(0022,1443)", 1505),
-    ("(0022,1445)", 1506),
-    ("(0022,1450)", 1507),
-    ("(0022,1452)", 1508),
-    ("(0022,1454)", 1509),
-    ("(0022,1458)", 1510),
-    ("(0022,1460)", 1511),
-    ("(0022,1463)", 1512),
-    ("(0022,1465)", 1513),
-    ("(0022,1466)", 1514),
-    ("(0022,1467)", 1515),
-    ("(0022,1468)", 1516),
-    ("(0022,1470)", 1517),
-    ("(0022,1472)", 1518),
-    ("(0022,1512)", 1519),
-    ("(0022,1513)", 1520),
-    ("(0022,1515)", 1521),
-    ("(0022,1517)", 1522),
-    ("(0022,1518)", 1523),
-    ("(0022,1525)", 1524),
-    ("(0022,1526)", 1525),
-    ("(0022,1527)", 1526),
-    ("(0022,1528)", 1527),
-    ("(0022,1529)", 1528),
-    ("(0022,1530)", 1529),
-    ("(0022,1531)", 1530),
-    ("(0022,1612)", 1531),
-    ("(0022,1615)", 1532),
-    ("(0022,1616)", 1533),
-    ("(0022,1618)", 1534),
-    ("(0022,1620)", 1535),
-    ("(0022,1622)", 1536),
-    ("(0022,1624)", 1537),
-    ("(0022,1626)", 1538),
-    ("(0022,1628)", 1539),
-    ("(0022,1630)", 1540),
-    ("(0022,1640)", 1541),
-    ("(0022,1642)", 1542),
-    ("(0022,1643)", 1543),
-    ("(0022,1644)", 1544),
-    ("(0022,1645)", 1545),
-    ("(0022,1646)", 1546),
-    ("(0022,1649)", 1547),
-    ("(0022,1650)", 1548),
-    ("(0022,1658)", 1549),
-    ("(0024,0010)", 1550),
-    ("(0024,0011)", 1551),
-    ("(0024,0012)", 1552),
-    ("(0024,0016)", 1553),
-    ("(0024,0018)", 1554),
-    ("(0024,0020)", 1555),
-    ("(0024,0021)", 1556),
-    ("(0024,0024)", 1557),
-    ("(0024,0025)", 1558),
-    ("(0024,0028)", 1559),
-    ("(0024,0032)", 1560),
-    ("(0024,0033)", 1561),
-    ("(0024,0034)", 1562),
-    ("(0024,0035)", 1563),
-    ("(0024,0036)", 1564),
-    ("(0024,0037)", 1565),
-    ("(0024,0038)", 1566),
-    ("(0024,0039)", 1567),
-    ("(0024,0040)", 1568),
-    ("(0024,0042)", 1569),
-    ("(0024,0044)", 1570),
-    ("(0024,0045)", 1571),
-    ("(0024,0046)", 1572),
-    ("(0024,0048)", 1573),
-    ("(0024,0050)", 1574),
-    ("(0024,0051)", 1575),
-    ("(0024,0052)", 1576),
-    ("(0024,0053)", 1577),
-    ("(0024,0054)", 1578),
-    ("(0024,0055)", 1579),
-    ("(0024,0056)", 1580),
-    ("(0024,0057)", 1581),
-    ("(0024,0058)", 1582),
-    ("(0024,0059)", 1583),
-    ("(0024,0060)", 1584),
-    ("(0024,0061)", 1585),
-    ("(0024,0062)", 1586),
-    ("(0024,0063)", 1587),
-    ("(0024,0064)", 1588),
-    ("(0024,0065)", 1589),
-    ("(0024,0066)", 1590),
-    ("(0024,0067)", 1591),
-    ("(0024,0068)", 1592),
-    ("(0024,0069)", 1593),
-    ("(0024,0070)", 1594),
-    ("(0024,0071)", 1595),
-    ("(0024,0072)", 1596),
-    ("(0024,0073)", 1597),
-    ("(0024,0074)", 1598),
-    ("(0024,0075)", 1599),
-    ("(0024,0076)", 1600),
-    ("(0024,0077)", 1601),
-    ("(0024,0078)", 1602),
-    ("(0024,0079)", 1603),
-    ("(0024,0080)", 1604),
-    ("(0024,0081)", 1605),
-    ("(0024,0083)", 1606),
-    ("(0024,0085)", 1607),
-    ("(0024,0086)", 1608),
-    ("(0024,0087)", 1609),
-    ("(0024,0088)", 1610),
-    ("(0024,0089)", 1611),
-    ("(0024,0090)", 1612),
-    ("(0024,0091)", 1613),
-    ("(0024,0092)", 1614),
-    ("(0024,0093)", 1615),
-    ("(0024,0094)", 1616),
-    ("(0024,0095)", 1617),
-    ("(0024,0096)", 1618),
-    ("(0024,0097)", 1619),
-    ("(0024,0098)", 1620),
-    ("(0024,0100)", 1621),
-    ("(0024,0102)", 1622),
-    ("(0024,0103)", 1623),
-    ("(0024,0104)", 1624),
-    ("(0024,0105)", 1625),
-    ("(0024,0106)", 1626),
-    ("(0024,0107)", 1627),
-    ("(0024,0108)", 1628),
-    ("(0024,0110)", 1629),
-    ("(0024,0112)", 1630),
-    ("(0024,0113)", 1631),
-    ("(0024,0114)", 1632),
-    ("(0024,0115)", 1633),
-    ("(0024,0117)", 1634),
-    ("(0024,0118)", 1635),
-    ("(0024,0120)", 1636),
-    ("(0024,0122)", 1637),
-    ("(0024,0124)", 1638),
-    ("(0024,0126)", 1639),
-    ("(0024,0202)", 1640),
-    ("(0024,0306)", 1641),
-    ("(0024,0307)", 1642),
-    ("(0024,0308)", 1643),
-    ("(0024,0309)", 1644),
-    ("(0024,0317)", 1645),
-    ("(0024,0320)", 1646),
-    ("(0024,0325)", 1647),
-    ("(0024,0338)", 1648),
-    ("(0024,0341)", 1649),
-    ("(0024,0344)", 1650),
-    ("(0028,0002)", 1651),
-    ("(0028,0003)", 1652),
-    ("(0028,0004)", 1653),
-    ("(0028,0006)", 1654),
-    ("(0028,0008)", 1655),
-    ("(0028,0009)", 1656),
-    ("(0028,000A)", 1657),
-    ("(0028,0010)", 1658),
-    ("(0028,0011)", 1659),
-    ("(0028,0014)", 1660),
-    ("(0028,0030)", 1661),
-    ("(0028,0031)", 1662),
-    ("(0028,0032)", 1663),
-    ("(0028,0034)", 1664),
-    ("(0028,0051)", 1665),
-    ("(0028,0100)", 1666),
-    ("(0028,0101)", 1667),
-    ("(0028,0102)", 1668),
-    ("(0028,0103)", 1669),
-    ("(0028,0106)", 1670),
-    ("(0028,0107)", 1671),
-    ("(0028,0108)", 1672),
-    ("(0028,0109)", 1673),
-    ("(0028,0120)", 1674),
-    ("(0028,0121)", 1675),
-    ("(0028,0122)", 1676),
-    ("(0028,0123)", 1677),
-    ("(0028,0124)", 1678),
-    ("(0028,0125)", 1679),
-    ("(0028,0300)", 1680),
-    ("(0028,0301)", 1681),
-    ("(0028,0302)", 1682),
-    ("(0028,0303)", 1683),
-    ("(0028,0304)", 1684),
-    ("(0028,0A02)", 1685),
-    ("(0028,0A04)", 1686),
-    ("(0028,1040)", 1687),
-    ("(0028,1041)", 1688),
-    ("(0028,1050)", 1689),
-    ("(0028,1051)", 1690),
-    ("(0028,1052)", 1691),
-    ("(0028,1053)", 1692),
-    ("(0028,1054)", 1693),
-    ("(0028,1055)", 1694),
-    ("(0028,1056)", 1695),
-    ("(0028,1090)", 1696),
-    ("(0028,1101)", 1697),
-    ("(0028,1102)", 1698),
-    ("(0028,1103)", 1699),
-    ("(0028,1104)", 1700),
-    ("(0028,1199)", 1701),
-    ("(0028,1201)", 1702),
-    ("(0028,1202)", 1703),
-    ("(0028,1203)", 1704),
-    ("(0028,1204)", 1705),
-    ("(0028,1221)", 1706),
-    ("(0028,1222)", 1707),
-    ("(0028,1223)", 1708),
-    ("(0028,1224)", 1709),
-    ("(0028,1230)", 1710),
-    ("(0028,1231)", 1711),
-    ("(0028,1232)", 1712),
-    ("(0028,1300)", 1713),
-    ("(0028,1350)", 1714),
-    ("(0028,1351)", 1715),
-    ("(0028,1352)", 1716),
-    ("(0028,135A)", 1717),
-    ("(0028,1401)", 1718),
-    ("(0028,1402)", 1719),
-    ("(0028,1403)", 1720),
-    ("(0028,1404)", 1721),
-    ("(0028,1405)", 1722),
-    ("(0028,1406)", 1723),
-    ("(0028,1407)", 1724),
-    ("(0028,1408)", 1725),
-    ("(0028,140B)", 1726),
-    ("(0028,140C)", 1727),
-    ("(0028,140D)", 1728),
-    ("(0028,140E)", 1729),
-    ("(0028,140F)", 1730),
-    ("(0028,1410)", 1731),
-    ("(0028,2000)", 1732),
-    ("(0028,2002)", 1733),
-    ("(0028,2110)", 1734),
-    ("(0028,2112)", 1735),
-    ("(0028,2114)", 1736),
-    ("(0028,3000)", 1737),
-    ("(0028,3001)", 1738),
-    ("(0028,3002)", 1739),
-    ("(0028,3003)", 1740),
-    ("(0028,3004)", 1741),
-    ("(0028,3006)", 1742),
-    ("(0028,3010)", 1743),
-    ("(0028,3110)", 1744),
-    ("(0028,6010)", 1745),
-    ("(0028,6020)", 1746),
-    ("(0028,6022)", 1747),
-    ("(0028,6023)", 1748),
-    ("(0028,6040)", 1749),
-    ("(0028,6100)", 1750),
-    ("(0028,6101)", 1751),
-    ("(0028,6102)", 1752),
-    ("(0028,6110)", 1753),
-    ("(0028,6112)", 1754),
-    ("(0028,6114)", 1755),
-    ("(0028,6120)", 1756),
-    ("(0028,6190)", 1757),
-    ("(0028,7FE0)", 1758),
-    ("(0028,9001)", 1759),
-    ("(0028,9002)", 1760),
-    ("(0028,9003)", 1761),
-    ("(0028,9108)", 1762),
-    ("(0028,9110)", 1763),
-    ("(0028,9132)", 1764),
-    ("(0028,9145)", 1765),
-    ("(0028,9235)", 1766),
-    ("(0028,9411)", 1767),
-    ("(0028,9415)", 1768),
-    ("(0028,9416)", 1769),
-    ("(0028,9422)", 1770),
-    ("(0028,9443)", 1771),
-    ("(0028,9444)", 1772),
-    ("(0028,9445)", 1773),
-    ("(0028,9446)", 1774),
-    ("(0028,9454)", 1775),
-    ("(0028,9474)", 1776),
-    ("(0028,9478)", 1777),
-    ("(0028,9501)", 1778),
-    ("(0028,9502)", 1779),
-    ("(0028,9503)", 1780),
-    ("(0028,9505)", 1781),
-    ("(0028,9506)", 1782),
-    ("(0028,9507)", 1783),
-    ("(0028,9520)", 1784),
-    ("(0028,9537)", 1785),
-    ("(0032,1033)", 1786),
-    ("(0032,1034)", 1787),
-    ("(0032,1060)", 1788),
-    ("(0032,1064)", 1789),
-    ("(0032,1066)", 1790),
-    ("(0032,1067)", 1791),
-    ("(0034,0001)", 1792),
-    ("(0034,0002)", 1793),
-    ("(0034,0003)", 1794),
-    ("(0034,0004)", 1795),
-    ("(0034,0005)", 1796),
-    ("(0034,0007)", 1797),
-    ("(0034,0008)", 1798),
-    ("(0034,0009)", 1799),
-    ("(0034,000A)", 1800),
-    ("(0034,000B)", 1801),
-    ("(0034,000C)", 1802),
-    ("(0034,000D)", 1803),
-    ("(0038,0010)", 1804),
-    ("(0038,0014)", 1805),
-    ("(0038,0060)", 1806),
-    ("(0038,0062)", 1807),
-    ("(0038,0064)", 1808),
-    ("(0038,0100)", 1809),
-    ("(0038,0101)", 1810),
-    ("(0038,0102)", 1811),
-    ("(0038,0500)", 1812),
-    ("(003A,0004)", 1813),
-    ("(003A,0005)", 1814),
-    ("(003A,0010)", 1815),
-    ("(003A,001A)", 1816),
-    ("(003A,0020)", 1817),
-    ("(003A,0200)", 1818),
-    ("(003A,0202)", 1819),
-    ("(003A,0203)", 1820),
-    ("(003A,0205)", 1821),
-    ("(003A,0208)", 1822),
-    ("(003A,0209)", 1823),
-    ("(003A,020A)", 1824),
-    ("(003A,020C)", 1825),
-    ("(003A,0210)", 1826),
-    ("(003A,0211)", 1827),
-    ("(003A,0212)", 1828),
-    ("(003A,0213)", 1829),
-    ("(003A,0214)", 1830),
-    ("(003A,0215)", 1831),
-    ("(003A,0218)", 1832),
-    ("(003A,021A)", 1833),
-    ("(003A,0220)", 1834),
-    ("(003A,0221)", 1835),
-    ("(003A,0222)", 1836),
-    ("(003A,0223)", 1837),
-    ("(003A,0230)", 1838),
-    ("(003A,0231)", 1839),
-    ("(003A,0240)", 1840),
-    ("(003A,0241)", 1841),
-    ("(003A,0242)", 1842),
-    ("(003A,0244)", 1843),
-    ("(003A,0245)", 1844),
-    ("(003A,0246)", 1845),
-    ("(003A,0247)", 1846),
-    ("(003A,0248)", 1847),
-    ("(003A,0300)", 1848),
-    ("(003A,0301)", 1849),
-    ("(003A,0302)", 1850),
-    ("(003A,0310)", 1851),
-    ("(003A,0311)", 1852),
-    ("(003A,0312)", 1853),
-    ("(003A,0313)", 1854),
-    ("(003A,0314)", 1855),
-    ("(003A,0315)", 1856),
-    ("(003A,0316)", 1857),
-    ("(003A,0317)", 1858),
-    ("(003A,0318)", 1859),
-    ("(003A,0319)", 1860),
-    ("(003A,0320)", 1861),
-    ("(003A,0321)", 1862),
-    ("(003A,0322)", 1863),
-    ("(003A,0323)", 1864),
-    ("(003A,0324)", 1865),
-    ("(003A,0325)", 1866),
-    ("(003A,0326)", 1867),
-    ("(003A,0327)", 1868),
-    ("(003A,0328)", 1869),
-    ("(003A,0329)", 1870),
-    ("(003A,032A)", 1871),
-    ("(003A,032B)", 1872),
-    ("(003A,032C)", 1873),
-    ("(003A,032D)", 1874),
-    ("(003A,032E)", 1875),
-    ("(0040,0007)", 1876),
-    ("(0040,0008)", 1877),
-    ("(0040,0009)", 1878),
-    ("(0040,000A)", 1879),
-    ("(0040,0026)", 1880),
-    ("(0040,0027)", 1881),
-    ("(0040,0031)", 1882),
-    ("(0040,0032)", 1883),
-    ("(0040,0033)", 1884),
-    ("(0040,0035)", 1885),
-    ("(0040,0036)", 1886),
-    ("(0040,0039)", 1887),
-    ("(0040,003A)", 1888),
-    ("(0040,0244)", 1889),
-    ("(0040,0245)", 1890),
-    ("(0040,0250)", 1891),
-    ("(0040,0251)", 1892),
-    ("(0040,0253)", 1893),
-    ("(0040,0254)", 1894),
-    ("(0040,0260)", 1895),
-    ("(0040,0261)", 1896),
-    ("(0040,0275)", 1897),
-    ("(0040,0280)", 1898),
-    ("(0040,0302)", 1899),
-    ("(0040,0303)", 1900),
-    ("(0040,0306)", 1901),
-    ("(0040,0310)", 1902),
-    ("(0040,0312)", 1903),
-    ("(0040,0314)", 1904),
-    ("(0040,0316)", 1905),
-    ("(0040,0318)", 1906),
-    ("(0040,0440)", 1907),
-    ("(0040,0441)", 1908),
-    ("(0040,0512)", 1909),
-    ("(0040,0513)", 1910),
-    ("(0040,0515)", 1911),
-    ("(0040,0518)", 1912),
-    ("(0040,051A)", 1913),
-    ("(0040,0520)", 1914),
-    ("(0040,0551)", 1915),
-    ("(0040,0554)", 1916),
-    ("(0040,0555)", 1917),
-    ("(0040,0556)", 1918),
-    ("(0040,0560)", 1919),
-    ("(0040,0562)", 1920),
-    ("(0040,059A)", 1921),
-    ("(0040,0600)", 1922),
-    ("(0040,0602)", 1923),
-    ("(0040,0610)", 1924),
-    ("(0040,0612)", 1925),
-    ("(0040,0620)", 1926),
-    ("(0040,0710)", 1927),
-    ("(0040,071A)", 1928),
-    ("(0040,072A)", 1929),
-    ("(0040,073A)", 1930),
-    ("(0040,074A)", 1931),
-    ("(0040,08EA)", 1932),
-    ("(0040,1001)", 1933),
-    ("(0040,1002)", 1934),
-    ("(0040,100A)", 1935),
-    ("(0040,1012)", 1936),
-    ("(0040,1101)", 1937),
-    ("(0040,1102)", 1938),
-    ("(0040,1103)", 1939),
-    ("(0040,1104)", 1940),
-    ("(0040,2016)", 1941),
-    ("(0040,2017)", 1942),
-    ("(0040,4033)", 1943),
-    ("(0040,4071)", 1944),
-    ("(0040,4072)", 1945),
-    ("(0040,4073)", 1946),
-    ("(0040,4074)", 1947),
-    ("(0040,8302)", 1948),
-    ("(0040,8303)", 1949),
-    ("(0040,9092)", 1950),
-    ("(0040,9094)", 1951),
-    ("(0040,9096)", 1952),
-    ("(0040,9098)", 1953),
-    ("(0040,9210)", 1954),
-    ("(0040,9211)", 1955),
-    ("(0040,9212)", 1956),
-    ("(0040,9213)", 1957),
-    ("(0040,9214)", 1958),
-    ("(0040,9216)", 1959),
-    ("(0040,9220)", 1960),
-    ("(0040,9224)", 1961),
-    ("(0040,9225)", 1962),
-    ("(0040,A010)", 1963),
-    ("(0040,A027)", 1964),
-    ("(0040,A030)", 1965),
-    ("(0040,A032)", 1966),
-    ("(0040,A033)", 1967),
-    ("(0040,A040)", 1968),
-    ("(0040,A043)", 1969),
-    ("(0040,A050)", 1970),
-    ("(0040,A073)", 1971),
-    ("(0040,A075)", 1972),
-    ("(0040,A078)", 1973),
-    ("(0040,A07A)", 1974),
-    ("(0040,A07C)", 1975),
-    ("(0040,A080)", 1976),
-    ("(0040,A082)", 1977),
-    ("(0040,A084)", 1978),
-    ("(0040,A088)", 1979),
-    ("(0040,A0B0)", 1980),
-    ("(0040,A120)", 1981),
-    ("(0040,A121)", 1982),
-    ("(0040,A122)", 1983),
-    ("(0040,A123)", 1984),
-    ("(0040,A124)", 1985),
-    ("(0040,A130)", 1986),
-    ("(0040,A132)", 1987),
-    ("(0040,A138)", 1988),
-    ("(0040,A13A)", 1989),
-    ("(0040,A160)", 1990),
-    ("(0040,A161)", 1991),
-    ("(0040,A162)", 1992),
-    ("(0040,A163)", 1993),
-    ("(0040,A168)", 1994),
-    ("(0040,A170)", 1995),
-    ("(0040,A171)", 1996),
-    ("(0040,A180)", 1997),
-    ("(0040,A195)", 1998),
-    ("(0040,A300)", 1999),
-    ("(0040,A301)", 2000),
-    ("(0040,A30A)", 2001),
-    ("(0040,A360)", 2002),
-    ("(0040,A370)", 2003),
-    ("(0040,A372)", 2004),
-    ("(0040,A375)", 2005),
-    ("(0040,A385)", 2006),
-    ("(0040,A390)", 2007),
-    ("(0040,A491)", 2008),
-    ("(0040,A492)", 2009),
-    ("(0040,A493)", 2010),
-    ("(0040,A496)", 2011),
-    ("(0040,A504)", 2012),
-    ("(0040,A525)", 2013),
-    ("(0040,A730)", 2014),
-    ("(0040,A801)", 2015),
-    ("(0040,A802)", 2016),
-    ("(0040,A803)", 2017),
-    ("(0040,A804)", 2018),
-    ("(0040,A805)", 2019),
-    ("(0040,A806)", 2020),
-    ("(0040,A807)", 2021),
-    ("(0040,A808)", 2022),
-    ("(0040,B020)", 2023),
-    ("(0040,DB00)", 2024),
-    ("(0040,DB73)", 2025),
-    ("(0040,E001)", 2026),
-    ("(0040,E008)", 2027),
-    ("(0040,E010)", 2028),
-    ("(0040,E011)", 2029),
-    ("(0040,E020)", 2030),
-    ("(0040,E021)", 2031),
-    ("(0040,E022)", 2032),
-    ("(0040,E023)", 2033),
-    ("(0040,E024)", 2034),
-    ("(0040,E025)", 2035),
-    ("(0040,E030)", 2036),
-    ("(0040,E031)", 2037),
-    ("(0042,0010)", 2038),
-    ("(0042,0011)", 2039),
-    ("(0042,0012)", 2040),
-    ("(0042,0013)", 2041),
-    ("(0042,0014)", 2042),
-    ("(0042,0015)", 2043),
-    ("(0044,0100)", 2044),
-    ("(0044,0101)", 2045),
-    ("(0044,0102)", 2046),
-    ("(0044,0103)", 2047),
-    ("(0044,0104)", 2048),
-    ("(0044,0105)", 2049),
-    ("(0044,0106)", 2050),
-    ("(0044,0107)", 2051),
-    ("(0044,0108)", 2052),
-    ("(0044,0109)", 2053),
-    ("(0044,010A)", 2054),
-    ("(0046,0012)", 2055),
-    ("(0046,0014)", 2056),
-    ("(0046,0015)", 2057),
-    ("(0046,0016)", 2058),
-    ("(0046,0018)", 2059),
-    ("(0046,0028)", 2060),
-    ("(0046,0030)", 2061),
-    ("(0046,0032)", 2062),
-    ("(0046,0034)", 2063),
-    ("(0046,0036)", 2064),
-    ("(0046,0038)", 2065),
-    ("(0046,0040)", 2066),
-    ("(0046,0042)", 2067),
-    ("(0046,0044)", 2068),
-    ("(0046,0046)", 2069),
-    ("(0046,0047)", 2070),
-    ("(0046,0050)", 2071),
-    ("(0046,0052)", 2072),
-    ("(0046,0060)", 2073),
-    ("(0046,0062)", 2074),
-    ("(0046,0063)", 2075),
-    ("(0046,0064)", 2076),
-    ("(0046,0070)", 2077),
-    ("(0046,0071)", 2078),
-    ("(0046,0074)", 2079),
-    ("(0046,0075)", 2080),
-    ("(0046,0076)", 2081),
-    ("(0046,0077)", 2082),
-    ("(0046,0080)", 2083),
-    ("(0046,0092)", 2084),
-    ("(0046,0094)", 2085),
-    ("(0046,0095)", 2086),
-    ("(0046,0097)", 2087),
-    ("(0046,0098)", 2088),
-    ("(0046,0100)", 2089),
-    ("(0046,0101)", 2090),
-    ("(0046,0102)", 2091),
-    ("(0046,0104)", 2092),
-    ("(0046,0106)", 2093),
-    ("(0046,0110)", 2094),
-    ("(0046,0111)", 2095),
-    ("(0046,0112)", 2096),
-    ("(0046,0113)", 2097),
-    ("(0046,0114)", 2098),
-    ("(0046,0115)", 2099),
-    ("(0046,0116)", 2100),
-    ("(0046,0117)", 2101),
-    ("(0046,0118)", 2102),
-    ("(0046,0121)", 2103),
-    ("(0046,0122)", 2104),
-    ("(0046,0123)", 2105),
-    ("(0046,0124)", 2106),
-    ("(0046,0125)", 2107),
-    ("(0046,0135)", 2108),
-    ("(0046,0137)", 2109),
-    ("(0046,0139)", 2110),
-    ("(0046,0145)", 2111),
-    ("(0046,0146)", 2112),
-    ("(0046,0147)", 2113),
-    ("(0046,0201)", 2114),
-    ("(0046,0202)", 2115),
-    ("(0046,0203)", 2116),
-    ("(0046,0204)", 2117),
-    ("(0046,0205)", 2118),
-    ("(0046,0207)", 2119),
-    ("(0046,0208)", 2120),
-    ("(0046,0210)", 2121),
-    ("(0046,0211)", 2122),
-    ("(0046,0212)", 2123),
-    ("(0046,0213)", 2124),
-    ("(0046,0215)", 2125),
-    ("(0046,0218)", 2126),
-    ("(0046,0220)", 2127),
-    ("(0046,0224)", 2128),
-    ("(0046,0227)", 2129),
-    ("(0046,0230)", 2130),
-    ("(0046,0232)", 2131),
-    ("(0046,0234)", 2132),
-    ("(0046,0236)", 2133),
-    ("(0046,0238)", 2134),
-    ("(0046,0242)", 2135),
-    ("(0046,0244)", 2136),
-    ("(0046,0247)", 2137),
-    ("(0046,0248)", 2138),
-    ("(0046,0249)", 2139),
-    ("(0046,0250)", 2140),
-    ("(0046,0251)", 2141),
-    ("(0046,0252)", 2142),
-    ("(0046,0253)", 2143),
-    ("(0048,0001)", 2144),
-    ("(0048,0002)", 2145),
-    ("(0048,0003)", 2146),
-    ("(0048,0006)", 2147),
-    ("(0048,0007)", 2148),
-    ("(0048,0008)", 2149),
-    ("(0048,0010)", 2150),
-    ("(0048,0011)", 2151),
-    ("(0048,0012)", 2152),
-    ("(0048,0013)", 2153),
-    ("(0048,0014)", 2154),
-    ("(0048,0015)", 2155),
-    ("(0048,0100)", 2156),
-    ("(0048,0102)", 2157),
-    ("(0048,0105)", 2158),
-    ("(0048,0106)", 2159),
-    ("(0048,0107)", 2160),
-    ("(0048,0108)", 2161),
-    ("(0048,0110)", 2162),
-    ("(0048,0111)", 2163),
-    ("(0048,0112)", 2164),
-    ("(0048,0113)", 2165),
-    ("(0048,0114)", 2166),
-    ("(0048,0115)", 2167),
-    ("(0048,0116)", 2168),
-    ("(0048,0117)", 2169),
-    ("(0048,0120)", 2170),
-    ("(0048,0207)", 2171),
-    ("(0048,021A)", 2172),
-    ("(0048,021E)", 2173),
-    ("(0048,021F)", 2174),
-    ("(0048,0301)", 2175),
-    ("(0048,0302)", 2176),
-    ("(0048,0303)", 2177),
-    ("(0050,0004)", 2178),
-    ("(0050,0010)", 2179),
-    ("(0050,0012)", 2180),
-    ("(0050,0013)", 2181),
-    ("(0050,0014)", 2182),
-    ("(0050,0015)", 2183),
-    ("(0050,0016)", 2184),
-    ("(0050,0017)", 2185),
-    ("(0050,0018)", 2186),
-    ("(0050,0019)", 2187),
-    ("(0050,001A)", 2188),
-    ("(0050,001B)", 2189),
-    ("(0050,001C)", 2190),
-    ("(0050,001D)", 2191),
-    ("(0050,001E)", 2192),
-    ("(0050,0020)", 2193),
-    ("(0050,0021)", 2194),
-    ("(0052,0001)", 2195),
-    ("(0052,0002)", 2196),
-    ("(0052,0003)", 2197),
-    ("(0052,0004)", 2198),
-    ("(0052,0006)", 2199),
-    ("(0052,0007)", 2200),
-    ("(0052,0008)", 2201),
-    ("(0052,0009)", 2202),
-    ("(0052,0011)", 2203),
-    ("(0052,0012)", 2204),
-    ("(0052,0013)", 2205),
-    ("(0052,0014)", 2206),
-    ("(0052,0016)", 2207),
-    ("(0052,0025)", 2208),
-    ("(0052,0026)", 2209),
-    ("(0052,0027)", 2210),
-    ("(0052,0028)", 2211),
-    ("(0052,0029)", 2212),
-    ("(0052,0030)", 2213),
-    ("(0052,0031)", 2214),
-    ("(0052,0033)", 2215),
-    ("(0052,0034)", 2216),
-    ("(0052,0036)", 2217),
-    ("(0052,0038)", 2218),
-    ("(0052,0039)", 2219),
-    ("(0052,003A)", 2220),
-    ("(0054,0010)", 2221),
-    ("(0054,0011)", 2222),
-    ("(0054,0012)", 2223),
-    ("(0054,0013)", 2224),
-    ("(0054,0014)", 2225),
-    ("(0054,0015)", 2226),
-    ("(0054,0016)", 2227),
-    ("(0054,0017)", 2228),
-    ("(0054,0018)", 2229),
-    ("(0054,0020)", 2230),
-    ("(0054,0021)", 2231),
-    ("(0054,0022)", 2232),
-    ("(0054,0030)", 2233),
-    ("(0054,0031)", 2234),
-    ("(0054,0032)", 2235),
-    ("(0054,0033)", 2236),
-    ("(0054,0036)", 2237),
-    ("(0054,0038)", 2238),
-    ("(0054,0039)", 2239),
-    ("(0054,0050)", 2240),
-    ("(0054,0051)", 2241),
-    ("(0054,0052)", 2242),
-    ("(0054,0053)", 2243),
-    ("(0054,0060)", 2244),
-    ("(0054,0061)", 2245),
-    ("(0054,0062)", 2246),
-    ("(0054,0063)", 2247),
-    ("(0054,0070)", 2248),
-    ("(0054,0071)", 2249),
-    ("(0054,0072)", 2250),
-    ("(0054,0073)", 2251),
-    ("(0054,0080)", 2252),
-    ("(0054,0081)", 2253),
-    ("(0054,0090)", 2254),
-    ("(0054,0100)", 2255),
-    ("(0054,0101)", 2256),
-    ("(0054,0200)", 2257),
-    ("(0054,0202)", 2258),
-    ("(0054,0210)", 2259),
-    ("(0054,0211)", 2260),
-    ("(0054,0220)", 2261),
-    ("(0054,0222)", 2262),
-    ("(0054,0300)", 2263),
-    ("(0054,0302)", 2264),
-    ("(0054,0304)", 2265),
-    ("(0054,0306)", 2266),
-    ("(0054,0308)", 2267),
-    ("(0054,0400)", 2268),
-    ("(0054,0410)", 2269),
-    ("(0054,0412)", 2270),
-    ("(0054,0414)", 2271),
-    ("(0054,0500)", 2272),
-    ("(0054,0501)", 2273),
-    ("(0054,1000)", 2274),
-    ("(0054,1001)", 2275),
-    ("(0054,1002)", 2276),
-    ("(0054,1004)", 2277),
-    ("(0054,1006)", 2278),
-    ("(0054,1100)", 2279),
-    ("(0054,1101)", 2280),
-    ("(0054,1102)", 2281),
-    ("(0054,1103)", 2282),
-    ("(0054,1104)", 2283),
-    ("(0054,1105)", 2284),
-    ("(0054,1200)", 2285),
-    ("(0054,1201)", 2286),
-    ("(0054,1202)", 2287),
-    ("(0054,1203)", 2288),
-    ("(0054,1210)", 2289),
-    ("(0054,1220)", 2290),
-    ("(0054,1300)", 2291),
-    ("(0054,1310)", 2292),
-    ("(0054,1311)", 2293),
-    ("(0054,1320)", 2294),
-    ("(0054,1321)", 2295),
-    ("(0054,1322)", 2296),
-    ("(0054,1323)", 2297),
-    ("(0054,1324)", 2298),
-    ("(0054,1330)", 2299),
-    ("(0060,3000)", 2300),
-    ("(0060,3002)", 2301),
-    ("(0060,3004)", 2302),
-    ("(0060,3006)", 2303),
-    ("(0060,3008)", 2304),
-    ("(0060,3010)", 2305),
-    ("(0060,3020)", 2306),
-    ("(0062,0001)", 2307),
-    ("(0062,0002)", 2308),
-    ("(0062,0003)", 2309),
-    ("(0062,0004)", 2310),
-    ("(0062,0005)", 2311),
-    ("(0062,0006)", 2312),
-    ("(0062,0007)", 2313),
-    ("(0062,0008)", 2314),
-    ("(0062,0009)", 2315),
-    ("(0062,000A)", 2316),
-    ("(0062,000B)", 2317),
-    ("(0062,000C)", 2318),
-    ("(0062,000D)", 2319),
-    ("(0062,000E)", 2320),
-    ("(0062,000F)", 2321),
-    ("(0062,0010)", 2322),
-    ("(0062,0011)", 2323),
-    ("(0062,0012)", 2324),
-    ("(0062,0013)", 2325),
-    ("(0062,0020)", 2326),
-    ("(0062,0021)", 2327),
-    ("(0064,0002)", 2328),
-    ("(0064,0003)", 2329),
-    ("(0064,0005)", 2330),
-    ("(0064,0007)", 2331),
-    ("(0064,0008)", 2332),
-    ("(0064,0009)", 2333),
-    ("(0064,000F)", 2334),
-    ("(0064,0010)", 2335),
-    ("(0066,0001)", 2336),
-    ("(0066,0002)", 2337),
-    ("(0066,0003)", 2338),
-    ("(0066,0004)", 2339),
-    ("(0066,0009)", 2340),
-    ("(0066,000A)", 2341),
-    ("(0066,000B)", 2342),
-    ("(0066,000C)", 2343),
-    ("(0066,000D)", 2344),
-    ("(0066,000E)", 2345),
-    ("(0066,0010)", 2346),
-    ("(0066,0011)", 2347),
-    ("(0066,0012)", 2348),
-    ("(0066,0013)", 2349),
-    ("(0066,0015)", 2350),
-    ("(0066,0016)", 2351),
-    ("(0066,0017)", 2352),
-    ("(0066,0018)", 2353),
-    ("(0066,0019)", 2354),
-    ("(0066,001A)", 2355),
-    ("(0066,001B)", 2356),
-    ("(0066,001C)", 2357),
-    ("(0066,001E)", 2358),
-    ("(0066,001F)", 2359),
-    ("(0066,0020)", 2360),
-    ("(0066,0021)", 2361),
-    ("(0066,0022)", 2362),
-    ("(0066,0026)", 2363),
-    ("(0066,0027)", 2364),
-    ("(0066,0028)", 2365),
-    ("(0066,002A)", 2366),
-    ("(0066,002B)", 2367),
-    ("(0066,002C)", 2368),
-    ("(0066,002D)", 2369),
-    ("(0066,002E)", 2370),
-    ("(0066,002F)", 2371),
-    ("(0066,0030)", 2372),
-    ("(0066,0031)", 2373),
-    ("(0066,0032)", 2374),
-    ("(0066,0034)", 2375),
-    ("(0066,0035)", 2376),
-    ("(0066,0036)", 2377),
-    ("(0066,0037)", 2378),
-    ("(0066,0038)", 2379),
-    ("(0066,0040)", 2380),
-    ("(0066,0041)", 2381),
-    ("(0066,0042)", 2382),
-    ("(0066,0043)", 2383),
-    ("(0066,0101)", 2384),
-    ("(0066,0102)", 2385),
-    ("(0066,0103)", 2386),
-    ("(0066,0104)", 2387),
-    ("(0066,0105)", 2388),
-    ("(0066,0106)", 2389),
-    ("(0066,0107)", 2390),
-    ("(0066,0108)", 2391),
-    ("(0066,0121)", 2392),
-    ("(0066,0124)", 2393),
-    ("(0066,0125)", 2394),
-    ("(0066,0129)", 2395),
-    ("(0066,0130)", 2396),
-    ("(0066,0132)", 2397),
-    ("(0066,0133)", 2398),
-    ("(0066,0134)", 2399),
-    ("(0068,6210)", 2400),
-    ("(0068,6221)", 2401),
-    ("(0068,6222)", 2402),
-    ("(0068,6223)", 2403),
-    ("(0068,6224)", 2404),
-    ("(0068,6225)", 2405),
-    ("(0068,6226)", 2406),
-    ("(0068,6230)", 2407),
-    ("(0068,6260)", 2408),
-    ("(0068,6265)", 2409),
-    ("(0068,6270)", 2410),
-    ("(0068,6280)", 2411),
-    ("(0068,62A0)", 2412),
-    ("(0068,62A5)", 2413),
-    ("(0068,62C0)", 2414),
-    ("(0068,62D0)", 2415),
-    ("(0068,62D5)", 2416),
-    ("(0068,62E0)", 2417),
-    ("(0068,62F0)", 2418),
-    ("(0068,62F2)", 2419),
-    ("(0068,6300)", 2420),
-    ("(0068,6310)", 2421),
-    ("(0068,6320)", 2422),
-    ("(0068,6330)", 2423),
-    ("(0068,6340)", 2424),
-    ("(0068,6345)", 2425),
-    ("(0068,6346)", 2426),
-    ("(0068,6347)", 2427),
-    ("(0068,6350)", 2428),
-    ("(0068,6360)", 2429),
-    ("(0068,6380)", 2430),
-    ("(0068,6390)", 2431),
-    ("(0068,63A0)", 2432),
-    ("(0068,63A4)", 2433),
-    ("(0068,63A8)", 2434),
-    ("(0068,63AC)", 2435),
-    ("(0068,63B0)", 2436),
-    ("(0068,63C0)", 2437),
-    ("(0068,63D0)", 2438),
-    ("(0068,63E0)", 2439),
-    ("(0068,63F0)", 2440),
-    ("(0068,6400)", 2441),
-    ("(0068,6410)", 2442),
-    ("(0068,6420)", 2443),
-    ("(0068,6430)", 2444),
-    ("(0068,6440)", 2445),
-    ("(0068,6450)", 2446),
-    ("(0068,6460)", 2447),
-    ("(0068,6470)", 2448),
-    ("(0068,6490)", 2449),
-    ("(0068,64A0)", 2450),
-    ("(0068,64C0)", 2451),
-    ("(0068,64D0)", 2452),
-    ("(0068,64F0)", 2453),
-    ("(0068,6500)", 2454),
-    ("(0068,6510)", 2455),
-    ("(0068,6520)", 2456),
-    ("(0068,6530)", 2457),
-    ("(0068,6540)", 2458),
-    ("(0068,6545)", 2459),
-    ("(0068,6550)", 2460),
-    ("(0068,6560)", 2461),
-    ("(0068,6590)", 2462),
-    ("(0068,65A0)", 2463),
-    ("(0068,65B0)", 2464),
-    ("(0068,65D0)", 2465),
-    ("(0068,65E0)", 2466),
-    ("(0068,65F0)", 2467),
-    ("(0068,6610)", 2468),
-    ("(0068,6620)", 2469),
-    ("(0068,7001)", 2470),
-    ("(0068,7002)", 2471),
-    ("(0068,7003)", 2472),
-    ("(0068,7004)", 2473),
-    ("(0068,7005)", 2474),
-    ("(006A,0001)", 2475),
-    ("(006A,0002)", 2476),
-    ("(006A,0003)", 2477),
-    ("(006A,0005)", 2478),
-    ("(006A,0006)", 2479),
-    ("(006A,0007)", 2480),
-    ("(006A,0008)", 2481),
-    ("(006A,0009)", 2482),
-    ("(006A,000A)", 2483),
-    ("(006A,000B)", 2484),
-    ("(006A,000C)", 2485),
-    ("(006A,000D)", 2486),
-    ("(006A,000E)", 2487),
-    ("(006A,000F)", 2488),
-    ("(006A,0010)", 2489),
-    ("(006A,0011)", 2490),
-    ("(0070,0001)", 2491),
-    ("(0070,0002)", 2492),
-    ("(0070,0003)", 2493),
-    ("(0070,0004)", 2494),
-    ("(0070,0005)", 2495),
-    ("(0070,0006)", 2496),
-    ("(0070,0008)", 2497),
-    ("(0070,0009)", 2498),
-    ("(0070,0010)", 2499),
-    ("(0070,0011)", 2500),
-    ("(0070,0012)", 2501),
-    ("(0070,0014)", 2502),
-    ("(0070,0015)", 2503),
-    ("(0070,0020)", 2504),
-    ("(0070,0021)", 2505),
-    ("(0070,0022)", 2506),
-    ("(0070,0023)", 2507),
-    ("(0070,0024)", 2508),
-    ("(0070,0041)", 2509),
-    ("(0070,0042)", 2510),
-    ("(0070,0052)", 2511),
-    ("(0070,0053)", 2512),
-    ("(0070,005A)", 2513),
-    ("(0070,0060)", 2514),
-    ("(0070,0062)", 2515),
-    ("(0070,0066)", 2516),
-    ("(0070,0068)", 2517),
-    ("(0070,0080)", 2518),
-    ("(0070,0081)", 2519),
-    ("(0070,0082)", 2520),
-    ("(0070,0083)", 2521),
-    ("(0070,0084)", 2522),
-    ("(0070,0086)", 2523),
-    ("(0070,0087)", 2524),
-    ("(0070,0100)", 2525),
-    ("(0070,0101)", 2526),
-    ("(0070,0102)", 2527),
-    ("(0070,0103)", 2528),
-    ("(0070,0207)", 2529),
-    ("(0070,0208)", 2530),
-    ("(0070,0209)", 2531),
-    ("(0070,0226)", 2532),
-    ("(0070,0227)", 2533),
-    ("(0070,0228)", 2534),
-    ("(0070,0229)", 2535),
-    ("(0070,0230)", 2536),
-    ("(0070,0231)", 2537),
-    ("(0070,0232)", 2538),
-    ("(0070,0233)", 2539),
-    ("(0070,0234)", 2540),
-    ("(0070,0241)", 2541),
-    ("(0070,0242)", 2542),
-    ("(0070,0243)", 2543),
-    ("(0070,0244)", 2544),
-    ("(0070,0245)", 2545),
-    ("(0070,0246)", 2546),
-    ("(0070,0247)", 2547),
-    ("(0070,0248)", 2548),
-    ("(0070,0249)", 2549),
-    ("(0070,0250)", 2550),
-    ("(0070,0251)", 2551),
-    ("(0070,0252)", 2552),
-    ("(0070,0253)", 2553),
-    ("(0070,0254)", 2554),
-    ("(0070,0255)", 2555),
-    ("(0070,0256)", 2556),
-    ("(0070,0257)", 2557),
-    ("(0070,0258)", 2558),
-    ("(0070,0261)", 2559),
-    ("(0070,0262)", 2560),
-    ("(0070,0273)", 2561),
-    ("(0070,0274)", 2562),
-    ("(0070,0278)", 2563),
-    ("(0070,0279)", 2564),
-    ("(0070,0282)", 2565),
-    ("(0070,0284)", 2566),
-    ("(0070,0285)", 2567),
-    ("(0070,0287)", 2568),
-    ("(0070,0288)", 2569),
-    ("(0070,0289)", 2570),
-    ("(0070,0294)", 2571),
-    ("(0070,0295)", 2572),
-    ("(0070,0306)", 2573),
-    ("(0070,0308)", 2574),
-    ("(0070,0309)", 2575),
-    ("(0070,030A)", 2576),
-    ("(0070,030B)", 2577),
-    ("(0070,030C)", 2578),
-    ("(0070,030D)", 2579),
-    ("(0070,030F)", 2580),
-    ("(0070,0310)", 2581),
-    ("(0070,0311)", 2582),
-    ("(0070,0312)", 2583),
-    ("(0070,0314)", 2584),
-    ("(0070,0315)", 2585),
-    ("(0070,0318)", 2586),
-    ("(0070,031A)", 2587),
-    ("(0070,031B)", 2588),
-    ("(0070,031C)", 2589),
-    ("(0070,031E)", 2590),
-    ("(0070,031F)", 2591),
-    ("(0070,0401)", 2592),
-    ("(0070,0402)", 2593),
-    ("(0070,0403)", 2594),
-    ("(0070,0404)", 2595),
-    ("(0070,0405)", 2596),
-    ("(0070,1101)", 2597),
-    ("(0070,1102)", 2598),
-    ("(0070,1103)", 2599),
-    ("(0070,1104)", 2600),
-    ("(0070,1201)", 2601),
-    ("(0070,1202)", 2602),
-    ("(0070,1203)", 2603),
-    ("(0070,1204)", 2604),
-    ("(0070,1205)", 2605),
-    ("(0070,1207)", 2606),
-    ("(0070,1209)", 2607),
-    ("(0070,120A)", 2608),
-    ("(0070,120B)", 2609),
-    ("(0070,120C)", 2610),
-    ("(0070,120D)", 2611),
-    ("(0070,1301)", 2612),
-    ("(0070,1302)", 2613),
-    ("(0070,1303)", 2614),
-    ("(0070,1304)", 2615),
-    ("(0070,1305)", 2616),
-    ("(0070,1306)", 2617),
-    ("(0070,1309)", 2618),
-    ("(0070,1501)", 2619),
-    ("(0070,1502)", 2620),
-    ("(0070,1503)", 2621),
-    ("(0070,1505)", 2622),
-    ("(0070,1507)", 2623),
-    ("(0070,1508)", 2624),
-    ("(0070,150C)", 2625),
-    ("(0070,150D)", 2626),
-    ("(0070,1511)", 2627),
-    ("(0070,1512)", 2628),
-    ("(0070,1602)", 2629),
-    ("(0070,1603)", 2630),
-    ("(0070,1604)", 2631),
-    ("(0070,1605)", 2632),
-    ("(0070,1606)", 2633),
-    ("(0070,1607)", 2634),
-    ("(0070,1701)", 2635),
-    ("(0070,1702)", 2636),
-    ("(0070,1703)", 2637),
-    ("(0070,1704)", 2638),
-    ("(0070,1705)", 2639),
-    ("(0070,1706)", 2640),
-    ("(0070,1801)", 2641),
-    ("(0070,1802)", 2642),
-    ("(0070,1803)", 2643),
-    ("(0070,1804)", 2644),
-    ("(0070,1805)", 2645),
-    ("(0070,1806)", 2646),
-    ("(0070,1901)", 2647),
-    ("(0070,1903)", 2648),
-    ("(0070,1904)", 2649),
-    ("(0070,1905)", 2650),
-    ("(0070,1907)", 2651),
-    ("(0070,1A01)", 2652),
-    ("(0070,1A03)", 2653),
-    ("(0070,1A04)", 2654),
-    ("(0070,1A05)", 2655),
-    ("(0070,1A06)", 2656),
-    ("(0070,1A07)", 2657),
-    ("(0070,1A08)", 2658),
-    ("(0070,1A09)", 2659),
-    ("(0070,1B01)", 2660),
-    ("(0070,1B02)", 2661),
-    ("(0070,1B03)", 2662),
-    ("(0070,1B04)", 2663),
-    ("(0070,1B06)", 2664),
-    ("(0070,1B07)", 2665),
-    ("(0070,1B08)", 2666),
-    ("(0070,1B11)", 2667),
-    ("(0070,1B12)", 2668),
-    ("(0070,1B13)", 2669),
-    ("(0070,1B14)", 2670),
-    ("(0072,0002)", 2671),
-    ("(0072,0004)", 2672),
-    ("(0072,0006)", 2673),
-    ("(0072,0008)", 2674),
-    ("(0072,000A)", 2675),
-    ("(0072,000C)", 2676),
-    ("(0072,000E)", 2677),
-    ("(0072,0010)", 2678),
-    ("(0072,0012)", 2679),
-    ("(0072,0014)", 2680),
-    ("(0072,0020)", 2681),
-    ("(0072,0022)", 2682),
-    ("(0072,0024)", 2683),
-    ("(0072,0026)", 2684),
-    ("(0072,0028)", 2685),
-    ("(0072,0030)", 2686),
-    ("(0072,0032)", 2687),
-    ("(0072,0034)", 2688),
-    ("(0072,0038)", 2689),
-    ("(0072,003A)", 2690),
-    ("(0072,003C)", 2691),
-    ("(0072,003E)", 2692),
-    ("(0072,0040)", 2693),
-    ("(0072,0050)", 2694),
-    ("(0072,0052)", 2695),
-    ("(0072,0054)", 2696),
-    ("(0072,0056)", 2697),
-    ("(0072,005E)", 2698),
-    ("(0072,005F)", 2699),
-    ("(0072,0060)", 2700),
-    ("(0072,0061)", 2701),
-    ("(0072,0062)", 2702),
-    ("(0072,0063)", 2703),
-    ("(0072,0064)", 2704),
-    ("(0072,0065)", 2705),
-    ("(0072,0066)", 2706),
-    ("(0072,0067)", 2707),
-    ("(0072,0068)", 2708),
-    ("(0072,0069)", 2709),
-    ("(0072,006A)", 2710),
-    ("(0072,006B)", 2711),
-    ("(0072,006C)", 2712),
-    ("(0072,006D)", 2713),
-    ("(0072,006E)", 2714),
-    ("(0072,006F)", 2715),
-    ("(0072,0070)", 2716),
-    ("(0072,0071)", 2717),
-    ("(0072,0072)", 2718),
-    ("(0072,0073)", 2719),
-    ("(0072,0074)", 2720),
-    ("(0072,0075)", 2721),
-    ("(0072,0076)", 2722),
-    ("(0072,0078)", 2723),
-    ("(0072,007A)", 2724),
-    ("(0072,007C)", 2725),
-    ("(0072,007E)", 2726),
-    ("(0072,007F)", 2727),
-    ("(0072,0080)", 2728),
-    ("(0072,0081)", 2729),
-    ("(0072,0082)", 2730),
-    ("(0072,0083)", 2731),
-    ("(0072,0100)", 2732),
-    ("(0072,0102)", 2733),
-    ("(0072,0104)", 2734),
-    ("(0072,0106)", 2735),
-    ("(0072,0108)", 2736),
-    ("(0072,010A)", 2737),
-    ("(0072,010C)", 2738),
-    ("(0072,010E)", 2739),
-    ("(0072,0200)", 2740),
-    ("(0072,0202)", 2741),
-    ("(0072,0203)", 2742),
-    ("(0072,0204)", 2743),
-    ("(0072,0206)", 2744),
-    ("(0072,0208)", 2745),
-    ("(0072,0210)", 2746),
-    ("(0072,0212)", 2747),
-    ("(0072,0214)", 2748),
-    ("(0072,0216)", 2749),
-    ("(0072,0218)", 2750),
-    ("(0072,0300)", 2751),
-    ("(0072,0302)", 2752),
-    ("(0072,0304)", 2753),
-    ("(0072,0306)", 2754),
-    ("(0072,0308)", 2755),
-    ("(0072,0310)", 2756),
-    ("(0072,0312)", 2757),
-    ("(0072,0314)", 2758),
-    ("(0072,0316)", 2759),
-    ("(0072,0318)", 2760),
-    ("(0072,0320)", 2761),
-    ("(0072,0330)", 2762),
-    ("(0072,0400)", 2763),
-    ("(0072,0402)", 2764),
-    ("(0072,0404)", 2765),
-    ("(0072,0406)", 2766),
-    ("(0072,0420)", 2767),
-    ("(0072,0421)", 2768),
-    ("(0072,0422)", 2769),
-    ("(0072,0424)", 2770),
-    ("(0072,0427)", 2771),
-    ("(0072,0430)", 2772),
-    ("(0072,0432)", 2773),
-    ("(0072,0434)", 2774),
-    ("(0072,0500)", 2775),
-    ("(0072,0510)", 2776),
-    ("(0072,0512)", 2777),
-    ("(0072,0514)", 2778),
-    ("(0072,0516)", 2779),
-    ("(0072,0520)", 2780),
-    ("(0072,0600)", 2781),
-    ("(0072,0602)", 2782),
-    ("(0072,0604)", 2783),
-    ("(0072,0700)", 2784),
-    ("(0072,0702)", 2785),
-    ("(0072,0704)", 2786),
-    ("(0072,0705)", 2787),
-    ("(0072,0706)", 2788),
-    ("(0072,0710)", 2789),
-    ("(0072,0712)", 2790),
-    ("(0072,0714)", 2791),
-    ("(0072,0716)", 2792),
-    ("(0072,0717)", 2793),
-    ("(0072,0718)", 2794),
-    ("(0074,0120)", 2795),
-    ("(0074,0121)", 2796),
-    ("(0074,1020)", 2797),
-    ("(0074,1022)", 2798),
-    ("(0074,1025)", 2799),
-    ("(0074,1026)", 2800),
-    ("(0074,1027)", 2801),
-    ("(0074,1028)", 2802),
-    ("(0074,102A)", 2803),
-    ("(0074,102B)", 2804),
-    ("(0074,102C)", 2805),
-    ("(0074,102D)", 2806),
-    ("(0074,1030)", 2807),
-    ("(0074,1032)", 2808),
-    ("(0074,1034)", 2809),
-    ("(0074,1036)", 2810),
-    ("(0074,1040)", 2811),
-    ("(0074,1057)", 2812),
-    ("(0074,1212)", 2813),
-    ("(0074,1324)", 2814),
-    ("(0074,1338)", 2815),
-    ("(0074,133A)", 2816),
-    ("(0074,1401)", 2817),
-    ("(0074,1402)", 2818),
-    ("(0074,1403)", 2819),
-    ("(0074,1404)", 2820),
-    ("(0074,1405)", 2821),
-    ("(0074,1406)", 2822),
-    ("(0074,1407)", 2823),
-    ("(0074,1408)", 2824),
-    ("(0074,1409)", 2825),
-    ("(0074,140A)", 2826),
-    ("(0074,140B)", 2827),
-    ("(0074,140C)", 2828),
-    ("(0074,140D)", 2829),
-    ("(0074,140E)", 2830),
-    ("(0076,0001)", 2831),
-    ("(0076,0003)", 2832),
-    ("(0076,0006)", 2833),
-    ("(0076,0008)", 2834),
-    ("(0076,000A)", 2835),
-    ("(0076,000C)", 2836),
-    ("(0076,000E)", 2837),
-    ("(0076,0010)", 2838),
-    ("(0076,0020)", 2839),
-    ("(0076,0030)", 2840),
-    ("(0076,0032)", 2841),
-    ("(0076,0034)", 2842),
-    ("(0076,0036)", 2843),
-    ("(0076,0038)", 2844),
-    ("(0076,0040)", 2845),
-    ("(0076,0055)", 2846),
-    ("(0076,0060)", 2847),
-    ("(0076,0070)", 2848),
-    ("(0076,0080)", 2849),
-    ("(0076,0090)", 2850),
-    ("(0076,00A0)", 2851),
-    ("(0076,00B0)", 2852),
-    ("(0076,00C0)", 2853),
-    ("(0078,0001)", 2854),
-    ("(0078,0010)", 2855),
-    ("(0078,0020)", 2856),
-    ("(0078,0024)", 2857),
-    ("(0078,0026)", 2858),
-    ("(0078,0028)", 2859),
-    ("(0078,002A)", 2860),
-    ("(0078,002E)", 2861),
-    ("(0078,0050)", 2862),
-    ("(0078,0060)", 2863),
-    ("(0078,0070)", 2864),
-    ("(0078,0090)", 2865),
-    ("(0078,00A0)", 2866),
-    ("(0078,00B0)", 2867),
-    ("(0078,00B2)", 2868),
-    ("(0078,00B4)", 2869),
-    ("(0078,00B6)", 2870),
-    ("(0078,00B8)", 2871),
-    ("(0080,0001)", 2872),
-    ("(0080,0002)", 2873),
-    ("(0080,0003)", 2874),
-    ("(0080,0004)", 2875),
-    ("(0080,0005)", 2876),
-    ("(0080,0006)", 2877),
-    ("(0080,0007)", 2878),
-    ("(0080,0008)", 2879),
-    ("(0080,0009)", 2880),
-    ("(0080,0010)", 2881),
-    ("(0080,0011)", 2882),
-    ("(0080,0012)", 2883),
-    ("(0080,0013)", 2884),
-    ("(0082,0001)", 2885),
-    ("(0082,0003)", 2886),
-    ("(0082,0004)", 2887),
-    ("(0082,0005)", 2888),
-    ("(0082,0006)", 2889),
-    ("(0082,0007)", 2890),
-    ("(0082,0008)", 2891),
-    ("(0082,000A)", 2892),
-    ("(0082,000C)", 2893),
-    ("(0082,0010)", 2894),
-    ("(0082,0016)", 2895),
-    ("(0082,0017)", 2896),
-    ("(0082,0018)", 2897),
-    ("(0082,0019)", 2898),
-    ("(0082,0021)", 2899),
-    ("(0082,0022)", 2900),
-    ("(0082,0023)", 2901),
-    ("(0082,0032)", 2902),
-    ("(0082,0033)", 2903),
-    ("(0082,0034)", 2904),
-    ("(0082,0035)", 2905),
-    ("(0082,0036)", 2906),
-    ("(0082,0037)", 2907),
-    ("(0082,0038)", 2908),
-    ("(0088,0130)", 2909),
-    ("(0088,0140)", 2910),
-    ("(0088,0200)", 2911),
-    ("(0100,0410)", 2912),
-    ("(0100,0420)", 2913),
-    ("(0100,0424)", 2914),
-    ("(0100,0426)", 2915),
-    ("(0400,0005)", 2916),
-    ("(0400,0010)", 2917),
-    ("(0400,0015)", 2918),
-    ("(0400,0020)", 2919),
-    ("(0400,0100)", 2920),
-    ("(0400,0105)", 2921),
-    ("(0400,0110)", 2922),
-    ("(0400,0115)", 2923),
-    ("(0400,0120)", 2924),
-    ("(0400,0305)", 2925),
-    ("(0400,0310)", 2926),
-    ("(0400,0401)", 2927),
-    ("(0400,0402)", 2928),
-    ("(0400,0403)", 2929),
-    ("(0400,0404)", 2930),
-    ("(0400,0500)", 2931),
-    ("(0400,0510)", 2932),
-    ("(0400,0520)", 2933),
-    ("(0400,0550)", 2934),
-    ("(0400,0551)", 2935),
-    ("(0400,0552)", 2936),
-    ("(0400,0561)", 2937),
-    ("(0400,0562)", 2938),
-    ("(0400,0563)", 2939),
-    ("(0400,0564)", 2940),
-    ("(0400,0565)", 2941),
-    ("(0400,0600)", 2942),
-    ("(2010,015E)", 2943),
-    ("(2010,0160)", 2944),
-    ("(2050,0010)", 2945),
-    ("(2050,0020)", 2946),
-    ("(2100,0140)", 2947),
-    ("(2200,0002)", 2948),
-    ("(2200,0005)", 2949),
-    ("(3002,0002)", 2950),
-    ("(3002,0003)", 2951),
-    ("(3002,0004)", 2952),
-    ("(3002,000A)", 2953),
-    ("(3002,000C)", 2954),
-    ("(3002,000D)", 2955),
-    ("(3002,000E)", 2956),
-    ("(3002,0010)", 2957),
-    ("(3002,0011)", 2958),
-    ("(3002,0012)", 2959),
-    ("(3002,0020)", 2960),
-    ("(3002,0022)", 2961),
-    ("(3002,0024)", 2962),
-    ("(3002,0026)", 2963),
-    ("(3002,0028)", 2964),
-    ("(3002,0029)", 2965),
-    ("(3002,0030)", 2966),
-    ("(3002,0032)", 2967),
-    ("(3002,0034)", 2968),
-    ("(3002,0040)", 2969),
-    ("(3002,0041)", 2970),
-    ("(3002,0042)", 2971),
-    ("(3002,0050)", 2972),
-    ("(3002,0051)", 2973),
-    ("(3002,0052)", 2974),
-    ("(3002,0100)", 2975),
-    ("(3002,0101)", 2976),
-    ("(3002,0102)", 2977),
-    ("(3002,0103)", 2978),
-    ("(3002,0104)", 2979),
-    ("(3002,0105)", 2980),
-    ("(3002,0106)", 2981),
-    ("(3002,0107)", 2982),
-    ("(3002,0108)", 2983),
-    ("(3002,0109)", 2984),
-    ("(3002,010A)", 2985),
-    ("(3002,010B)", 2986),
-    ("(3002,010C)", 2987),
-    ("(3002,010D)", 2988),
-    ("(3002,010E)", 2989),
-    ("(3002,010F)", 2990),
-    ("(3002,0110)", 2991),
-    ("(3002,0111)", 2992),
-    ("(3002,0112)", 2993),
-    ("(3002,0113)", 2994),
-    ("(3002,0114)", 2995),
-    ("(3002,0115)", 2996),
-    ("(3002,0116)", 2997),
-    ("(3002,0117)", 2998),
-    ("(3002,0118)", 2999),
-    ("(3002,0119)", 3000),
-    ("(3002,011A)", 3001),
-    ("(3002,011B)", 3002),
-    ("(3002,011C)", 3003),
-    ("(3002,011D)", 3004),
-    ("(3002,011E)", 3005),
-    ("(3002,011F)", 3006),
-    ("(3002,0120)", 3007),
-    ("(3002,0121)", 3008),
-    ("(3002,0122)", 3009),
-    ("(3002,0123)", 3010),
-    ("(3002,0124)", 3011),
-    ("(3002,0125)", 3012),
-    ("(3002,0126)", 3013),
-    ("(3002,0127)", 3014),
-    ("(3002,0128)", 3015),
-    ("(3002,0129)", 3016),
-    ("(3002,012A)", 3017),
-    ("(3002,012B)", 3018),
-    ("(3002,012C)", 3019),
-    ("(3002,012D)", 3020),
-    ("(3002,012E)", 3021),
-    ("(3002,012F)", 3022),
-    ("(3002,0130)", 3023),
-    ("(3002,0131)", 3024),
-    ("(3002,0132)", 3025),
-    ("(3002,0133)", 3026),
-    ("(3002,0134)", 3027),
-    ("(3002,0135)", 3028),
-    ("(3004,0001)", 3029),
-    ("(3004,0002)", 3030),
-    ("(3004,0004)", 3031),
-    ("(3004,0005)", 3032),
-    ("(3004,0006)", 3033),
-    ("(3004,0008)", 3034),
-    ("(3004,000A)", 3035),
-    ("(3004,000C)", 3036),
-    ("(3004,000E)", 3037),
-    ("(3004,0014)", 3038),
-    ("(3004,0040)", 3039),
-    ("(3004,0042)", 3040),
-    ("(3004,0050)", 3041),
-    ("(3004,0052)", 3042),
-    ("(3004,0054)", 3043),
-    ("(3004,0056)", 3044),
-    ("(3004,0058)", 3045),
-    ("(3004,0060)", 3046),
-    ("(3004,0062)", 3047),
-    ("(3004,0070)", 3048),
-    ("(3004,0072)", 3049),
-    ("(3004,0074)", 3050),
-    ("(3006,0002)", 3051),
-    ("(3006,0004)", 3052),
-    ("(3006,0006)", 3053),
-    ("(3006,0008)", 3054),
-    ("(3006,0009)", 3055),
-    ("(3006,0010)", 3056),
-    ("(3006,0012)", 3057),
-    ("(3006,0014)", 3058),
-    ("(3006,0016)", 3059),
-    ("(3006,0018)", 3060),
-    ("(3006,0020)", 3061),
-    ("(3006,0022)", 3062),
-    ("(3006,0024)", 3063),
-    ("(3006,0026)", 3064),
-    ("(3006,0028)", 3065),
-    ("(3006,002A)", 3066),
-    ("(3006,002C)", 3067),
-    ("(3006,002D)", 3068),
-    ("(3006,002E)", 3069),
-    ("(3006,0030)", 3070),
-    ("(3006,0033)", 3071),
-    ("(3006,0036)", 3072),
-    ("(3006,0037)", 3073),
-    ("(3006,0038)", 3074),
-    ("(3006,0039)", 3075),
-    ("(3006,0040)", 3076),
-    ("(3006,0042)", 3077),
-    ("(3006,0046)", 3078),
-    ("(3006,0048)", 3079),
-    ("(3006,004A)", 3080),
-    ("(3006,004B)", 3081),
-    ("(3006,004C)", 3082),
-    ("(3006,004D)", 3083),
-    ("(3006,004E)", 3084),
-    ("(3006,004F)", 3085),
-    ("(3006,0050)", 3086),
-    ("(3006,0080)", 3087),
-    ("(3006,0082)", 3088),
-    ("(3006,0084)", 3089),
-    ("(3006,0086)", 3090),
-    ("(3006,00A0)", 3091),
-    ("(3006,00A4)", 3092),
-    ("(3006,00A6)", 3093),
-    ("(3006,00B0)", 3094),
-    ("(3006,00B2)", 3095),
-    ("(3006,00B4)", 3096),
-    ("(3006,00B6)", 3097),
-    ("(3006,00B7)", 3098),
-    ("(3006,00B8)", 3099),
-    ("(3006,00C6)", 3100),
-    ("(3006,00C8)", 3101),
-    ("(3006,00C9)", 3102),
-    ("(3006,00CA)", 3103),
-    ("(3006,00CB)", 3104),
-    ("(3008,0010)", 3105),
-    ("(3008,0012)", 3106),
-    ("(3008,0014)", 3107),
-    ("(3008,0016)", 3108),
-    ("(3008,0020)", 3109),
-    ("(3008,0021)", 3110),
-    ("(3008,0022)", 3111),
-    ("(3008,0024)", 3112),
-    ("(3008,0025)", 3113),
-    ("(3008,002A)", 3114),
-    ("(3008,002C)", 3115),
-    ("(3008,0030)", 3116),
-    ("(3008,0032)", 3117),
-    ("(3008,0033)", 3118),
-    ("(3008,0036)", 3119),
-    ("(3008,0037)", 3120),
-    ("(3008,003A)", 3121),
-    ("(3008,003B)", 3122),
-    ("(3008,0040)", 3123),
-    ("(3008,0041)", 3124),
-    ("(3008,0042)", 3125),
-    ("(3008,0044)", 3126),
-    ("(3008,0045)", 3127),
-    ("(3008,0046)", 3128),
-    ("(3008,0047)", 3129),
-    ("(3008,0048)", 3130),
-    ("(3008,0050)", 3131),
-    ("(3008,0052)", 3132),
-    ("(3008,0054)", 3133),
-    ("(3008,0056)", 3134),
-    ("(3008,005A)", 3135),
-    ("(3008,0060)", 3136),
-    ("(3008,0061)", 3137),
-    ("(3008,0062)", 3138),
-    ("(3008,0063)", 3139),
-    ("(3008,0064)", 3140),
-    ("(3008,0065)", 3141),
-    ("(3008,0066)", 3142),
-    ("(3008,0067)", 3143),
-    ("(3008,0068)", 3144),
-    ("(3008,006A)", 3145),
-    ("(3008,0070)", 3146),
-    ("(3008,0072)", 3147),
-    ("(3008,0074)", 3148),
-    ("(3008,0076)", 3149),
-    ("(3008,0078)", 3150),
-    ("(3008,007A)", 3151),
-    ("(3008,0080)", 3152),
-    ("(3008,0082)", 3153),
-    ("(3008,0090)", 3154),
-    ("(3008,0092)", 3155),
-    ("(3008,00A0)", 3156),
-    ("(3008,00A1)", 3157),
-    ("(3008,00A2)", 3158),
-    ("(3008,00A3)", 3159),
-    ("(3008,00A4)", 3160),
-    ("(3008,00B0)", 3161),
-    ("(3008,00C0)", 3162),
-    ("(3008,00D0)", 3163),
-    ("(3008,00D1)", 3164),
-    ("(3008,00E0)", 3165),
-    ("(3008,00F0)", 3166),
-    ("(3008,00F2)", 3167),
-    ("(3008,00F4)", 3168),
-    ("(3008,00F6)", 3169),
-    ("(3008,0100)", 3170),
-    ("(3008,0105)", 3171),
-    ("(3008,0110)", 3172),
-    ("(3008,0116)", 3173),
-    ("(3008,0120)", 3174),
-    ("(3008,0122)", 3175),
-    ("(3008,0130)", 3176),
-    ("(3008,0132)", 3177),
-    ("(3008,0134)", 3178),
-    ("(3008,0136)", 3179),
-    ("(3008,0138)", 3180),
-    ("(3008,013A)", 3181),
-    ("(3008,013C)", 3182),
-    ("(3008,0140)", 3183),
-    ("(3008,0142)", 3184),
-    ("(3008,0150)", 3185),
-    ("(3008,0152)", 3186),
-    ("(3008,0160)", 3187),
-    ("(3008,0162)", 3188),
-    ("(3008,0164)", 3189),
-    ("(3008,0166)", 3190),
-    ("(3008,0168)", 3191),
-    ("(3008,0171)", 3192),
-    ("(3008,0172)", 3193),
-    ("(3008,0173)", 3194),
-    ("(3008,0200)", 3195),
-    ("(3008,0202)", 3196),
-    ("(3008,0220)", 3197),
-    ("(3008,0223)", 3198),
-    ("(3008,0224)", 3199),
-    ("(3008,0230)", 3200),
-    ("(3008,0240)", 3201),
-    ("(3008,0250)", 3202),
-    ("(3008,0251)", 3203),
-    ("(300A,0002)", 3204),
-    ("(300A,0003)", 3205),
-    ("(300A,0004)", 3206),
-    ("(300A,0006)", 3207),
-    ("(300A,0007)", 3208),
-    ("(300A,0009)", 3209),
-    ("(300A,000A)", 3210),
-    ("(300A,000C)", 3211),
-    ("(300A,000E)", 3212),
-    ("(300A,0010)", 3213),
-    ("(300A,0012)", 3214),
-    ("(300A,0013)", 3215),
-    ("(300A,0014)", 3216),
-    ("(300A,0015)", 3217),
-    ("(300A,0016)", 3218),
-    ("(300A,0018)", 3219),
-    ("(300A,001A)", 3220),
-    ("(300A,0020)", 3221),
-    ("(300A,0021)", 3222),
-    ("(300A,0022)", 3223),
-    ("(300A,0023)", 3224),
-    ("(300A,0025)", 3225),
-    ("(300A,0026)", 3226),
-    ("(300A,0027)", 3227),
-    ("(300A,0028)", 3228),
-    ("(300A,002A)", 3229),
-    ("(300A,002B)", 3230),
-    ("(300A,002C)", 3231),
-    ("(300A,002D)", 3232),
-    ("(300A,0040)", 3233),
-    ("(300A,0042)", 3234),
-    ("(300A,0043)", 3235),
-    ("(300A,0044)", 3236),
-    ("(300A,0046)", 3237),
-    ("(300A,0048)", 3238),
-    ("(300A,004A)", 3239),
-    ("(300A,004B)", 3240),
-    ("(300A,004C)", 3241),
-    ("(300A,004E)", 3242),
-    ("(300A,004F)", 3243),
-    ("(300A,0050)", 3244),
-    ("(300A,0051)", 3245),
-    ("(300A,0052)", 3246),
-    ("(300A,0053)", 3247),
-    ("(300A,0055)", 3248),
-    ("(300A,0070)", 3249),
-    ("(300A,0071)", 3250),
-    ("(300A,0072)", 3251),
-    ("(300A,0078)", 3252),
-    ("(300A,0079)", 3253),
-    ("(300A,007A)", 3254),
-    ("(300A,007B)", 3255),
-    ("(300A,0080)", 3256),
-    ("(300A,0083)", 3257),
-    ("(300A,0084)", 3258),
-    ("(300A,0086)", 3259),
-    ("(300A,0088)", 3260),
-    ("(300A,0089)", 3261),
-    ("(300A,008A)", 3262),
-    ("(300A,008B)", 3263),
-    ("(300A,008C)", 3264),
-    ("(300A,0090)", 3265),
-    ("(300A,0091)", 3266),
-    ("(300A,0092)", 3267),
-    ("(300A,0093)", 3268),
-    ("(300A,0094)", 3269),
-    ("(300A,00A0)", 3270),
-    ("(300A,00A2)", 3271),
-    ("(300A,00A4)", 3272),
-    ("(300A,00B0)", 3273),
-    ("(300A,00B2)", 3274),
-    ("(300A,00B3)", 3275),
-    ("(300A,00B4)", 3276),
-    ("(300A,00B6)", 3277),
-    ("(300A,00B8)", 3278),
-    ("(300A,00BA)", 3279),
-    ("(300A,00BB)", 3280),
-    ("(300A,00BC)", 3281),
-    ("(300A,00BE)", 3282),
-    ("(300A,00C0)", 3283),
-    ("(300A,00C2)", 3284),
-    ("(300A,00C3)", 3285),
-    ("(300A,00C4)", 3286),
-    ("(300A,00C5)", 3287),
-    ("(300A,00C6)", 3288),
-    ("(300A,00C7)", 3289),
-    ("(300A,00C8)", 3290),
-    ("(300A,00CA)", 3291),
-    ("(300A,00CC)", 3292),
-    ("(300A,00CE)", 3293),
-    ("(300A,00D0)", 3294),
-    ("(300A,00D1)", 3295),
-    ("(300A,00D2)", 3296),
-    ("(300A,00D3)", 3297),
-    ("(300A,00D4)", 3298),
-    ("(300A,00D5)", 3299),
-    ("(300A,00D6)", 3300),
-    ("(300A,00D7)", 3301),
-    ("(300A,00D8)", 3302),
-    ("(300A,00D9)", 3303),
-    ("(300A,00DA)", 3304),
-    ("(300A,00DB)", 3305),
-    ("(300A,00DC)", 3306),
-    ("(300A,00DD)", 3307),
-    ("(300A,00DE)", 3308),
-    ("(300A,00E0)", 3309),
-    ("(300A,00E1)", 3310),
-    ("(300A,00E2)", 3311),
-    ("(300A,00E3)", 3312),
-    ("(300A,00E4)", 3313),
-    ("(300A,00E5)", 3314),
-    ("(300A,00E6)", 3315),
-    ("(300A,00E7)", 3316),
-    ("(300A,00E8)", 3317),
-    ("(300A,00E9)", 3318),
-    ("(300A,00EA)", 3319),
-    ("(300A,00EB)", 3320),
-    ("(300A,00EC)", 3321),
-    ("(300A,00ED)", 3322),
-    ("(300A,00EE)", 3323),
-    ("(300A,00EF)", 3324),
-    ("(300A,00F0)", 3325),
-    ("(300A,00F2)", 3326),
-    ("(300A,00F3)", 3327),
-    ("(300A,00F4)", 3328),
-    ("(300A,00F5)", 3329),
-    ("(300A,00F6)", 3330),
-    ("(300A,00F7)", 3331),
-    ("(300A,00F8)", 3332),
-    ("(300A,00F9)", 3333),
-    ("(300A,00FA)", 3334),
-    ("(300A,00FB)", 3335),
-    ("(300A,00FC)", 3336),
-    ("(300A,00FE)", 3337),
-    ("(300A,0100)", 3338),
-    ("(300A,0102)", 3339),
-    ("(300A,0104)", 3340),
-    ("(300A,0106)", 3341),
-    ("(300A,0107)", 3342),
-    ("(300A,0108)", 3343),
-    ("(300A,0109)", 3344),
-    ("(300A,010A)", 3345),
-    ("(300A,010C)", 3346),
-    ("(300A,010E)", 3347),
-    ("(300A,0110)", 3348),
-    ("(300A,0111)", 3349),
-    ("(300A,0112)", 3350),
-    ("(300A,0114)", 3351),
-    ("(300A,0115)", 3352),
-    ("(300A,0116)", 3353),
-    ("(300A,0118)", 3354),
-    ("(300A,011A)", 3355),
-    ("(300A,011C)", 3356),
-    ("(300A,011E)", 3357),
-    ("(300A,011F)", 3358),
-    ("(300A,0120)", 3359),
-    ("(300A,0121)", 3360),
-    ("(300A,0122)", 3361),
-    ("(300A,0123)", 3362),
-    ("(300A,0124)", 3363),
-    ("(300A,0125)", 3364),
-    ("(300A,0126)", 3365),
-    ("(300A,0128)", 3366),
-    ("(300A,0129)", 3367),
-    ("(300A,012A)", 3368),
-    ("(300A,012C)", 3369),
-    ("(300A,012E)", 3370),
-    ("(300A,0130)", 3371),
-    ("(300A,0132)", 3372),
-    ("(300A,0133)", 3373),
-    ("(300A,0134)", 3374),
-    ("(300A,0140)", 3375),
-    ("(300A,0142)", 3376),
-    ("(300A,0144)", 3377),
-    ("(300A,0146)", 3378),
-    ("(300A,0148)", 3379),
-    ("(300A,014A)", 3380),
-    ("(300A,014C)", 3381),
-    ("(300A,014E)", 3382),
-    ("(300A,0150)", 3383),
-    ("(300A,0151)", 3384),
-    ("(300A,0152)", 3385),
-    ("(300A,0153)", 3386),
-    ("(300A,0154)", 3387),
-    ("(300A,0155)", 3388),
-    ("(300A,0180)", 3389),
-    ("(300A,0182)", 3390),
-    ("(300A,0183)", 3391),
-    ("(300A,0184)", 3392),
-    ("(300A,0190)", 3393),
-    ("(300A,0192)", 3394),
-    ("(300A,0194)", 3395),
-    ("(300A,0196)", 3396),
-    ("(300A,0198)", 3397),
-    ("(300A,0199)", 3398),
-    ("(300A,019A)", 3399),
-    ("(300A,01A0)", 3400),
-    ("(300A,01A2)", 3401),
-    ("(300A,01A4)", 3402),
-    ("(300A,01A6)", 3403),
-    ("(300A,01A8)", 3404),
-    ("(300A,01B0)", 3405),
-    ("(300A,01B2)", 3406),
-    ("(300A,01B4)", 3407),
-    ("(300A,01B6)", 3408),
-    ("(300A,01B8)", 3409),
-    ("(300A,01BA)", 3410),
-    ("(300A,01BC)", 3411),
-    ("(300A,01D0)", 3412),
-    ("(300A,01D2)", 3413),
-    ("(300A,01D4)", 3414),
-    ("(300A,01D6)", 3415),
-    ("(300A,0200)", 3416),
-    ("(300A,0202)", 3417),
-    ("(300A,0206)", 3418),
-    ("(300A,0210)", 3419),
-    ("(300A,0212)", 3420),
-    ("(300A,0214)", 3421),
-    ("(300A,0216)", 3422),
-    ("(300A,0218)", 3423),
-    ("(300A,021A)", 3424),
-    ("(300A,021B)", 3425),
-    ("(300A,021C)", 3426),
-    ("(300A,0222)", 3427),
-    ("(300A,0224)", 3428),
-    ("(300A,0226)", 3429),
-    ("(300A,0228)", 3430),
-    ("(300A,0229)", 3431),
-    ("(300A,022A)", 3432),
-    ("(300A,022B)", 3433),
-    ("(300A,022C)", 3434),
-    ("(300A,022E)", 3435),
-    ("(300A,0230)", 3436),
-    ("(300A,0232)", 3437),
-    ("(300A,0234)", 3438),
-    ("(300A,0236)", 3439),
-    ("(300A,0238)", 3440),
-    ("(300A,0240)", 3441),
-    ("(300A,0242)", 3442),
-    ("(300A,0244)", 3443),
-    ("(300A,0250)", 3444),
-    ("(300A,0260)", 3445),
-    ("(300A,0262)", 3446),
-    ("(300A,0263)", 3447),
-    ("(300A,0264)", 3448),
-    ("(300A,0266)", 3449),
-    ("(300A,026A)", 3450),
-    ("(300A,026C)", 3451),
-    ("(300A,0271)", 3452),
-    ("(300A,0272)", 3453),
-    ("(300A,0273)", 3454),
-    ("(300A,0274)", 3455),
-    ("(300A,0280)", 3456),
-    ("(300A,0282)", 3457),
-    ("(300A,0284)", 3458),
-    ("(300A,0286)", 3459),
-    ("(300A,0288)", 3460),
-    ("(300A,028A)", 3461),
-    ("(300A,028C)", 3462),
-    ("(300A,0290)", 3463),
-    ("(300A,0291)", 3464),
-    ("(300A,0292)", 3465),
-    ("(300A,0294)", 3466),
-    ("(300A,0296)", 3467),
-    ("(300A,0298)", 3468),
-    ("(300A,029C)", 3469),
-    ("(300A,029E)", 3470),
-    ("(300A,02A0)", 3471),
-    ("(300A,02A1)", 3472),
-    ("(300A,02A2)", 3473),
-    ("(300A,02A4)", 3474),
-    ("(300A,02B0)", 3475),
-    ("(300A,02B2)", 3476),
-    ("(300A,02B3)", 3477),
-    ("(300A,02B4)", 3478),
-    ("(300A,02B8)", 3479),
-    ("(300A,02BA)", 3480),
-    ("(300A,02C8)", 3481),
-    ("(300A,02D0)", 3482),
-    ("(300A,02D2)", 3483),
-    ("(300A,02D4)", 3484),
-    ("(300A,02D6)", 3485),
-    ("(300A,02E0)", 3486),
-    ("(300A,02E1)", 3487),
-    ("(300A,02E2)", 3488),
-    ("(300A,02E3)", 3489),
-    ("(300A,02E4)", 3490),
-    ("(300A,02E5)", 3491),
-    ("(300A,02E6)", 3492),
-    ("(300A,02E7)", 3493),
-    ("(300A,02E8)", 3494),
-    ("(300A,02EA)", 3495),
-    ("(300A,02EB)", 3496),
-    ("(300A,0302)", 3497),
-    ("(300A,0304)", 3498),
-    ("(300A,0306)", 3499),
-    ("(300A,0308)", 3500),
-    ("(300A,0309)", 3501),
-    ("(300A,030A)", 3502),
-    ("(300A,030C)", 3503),
-    ("(300A,030D)", 3504),
-    ("(300A,030F)", 3505),
-    ("(300A,0312)", 3506),
-    ("(300A,0314)", 3507),
-    ("(300A,0316)", 3508),
-    ("(300A,0318)", 3509),
-    ("(300A,0320)", 3510),
-    ("(300A,0322)", 3511),
-    ("(300A,0330)", 3512),
-    ("(300A,0332)", 3513),
-    ("(300A,0334)", 3514),
-    ("(300A,0336)", 3515),
-    ("(300A,0338)", 3516),
-    ("(300A,033A)", 3517),
-    ("(300A,033C)", 3518),
-    ("(300A,0340)", 3519),
-    ("(300A,0342)", 3520),
-    ("(300A,0344)", 3521),
-    ("(300A,0346)", 3522),
-    ("(300A,0348)", 3523),
-    ("(300A,034A)", 3524),
-    ("(300A,034C)", 3525),
-    ("(300A,0350)", 3526),
-    ("(300A,0352)", 3527),
-    ("(300A,0354)", 3528),
-    ("(300A,0355)", 3529),
-    ("(300A,0356)", 3530),
-    ("(300A,0358)", 3531),
-    ("(300A,035A)", 3532),
-    ("(300A,0360)", 3533),
-    ("(300A,0362)", 3534),
-    ("(300A,0364)", 3535),
-    ("(300A,0366)", 3536),
-    ("(300A,0370)", 3537),
-    ("(300A,0372)", 3538),
-    ("(300A,0374)", 3539),
-    ("(300A,0380)", 3540),
-    ("(300A,0382)", 3541),
-    ("(300A,0384)", 3542),
-    ("(300A,0386)", 3543),
-    ("(300A,0388)", 3544),
-    ("(300A,038A)", 3545),
-    ("(300A,038F)", 3546),
-    ("(300A,0390)", 3547),
-    ("(300A,0391)", 3548),
-    ("(300A,0392)", 3549),
-    ("(300A,0393)", 3550),
-    ("(300A,0394)", 3551),
-    ("(300A,0395)", 3552),
-    ("(300A,0396)", 3553),
-    ("(300A,0398)", 3554),
-    ("(300A,0399)", 3555),
-    ("(300A,039A)", 3556),
-    ("(300A,03A0)", 3557),
-    ("(300A,03A2)", 3558),
-    ("(300A,03A4)", 3559),
-    ("(300A,03A6)", 3560),
-    ("(300A,03A8)", 3561),
-    ("(300A,03AA)", 3562),
-    ("(300A,03AC)", 3563),
-    ("(300A,0401)", 3564),
-    ("(300A,0402)", 3565),
-    ("(300A,0410)", 3566),
-    ("(300A,0412)", 3567),
-    ("(300A,0420)", 3568),
-    ("(300A,0421)", 3569),
-    ("(300A,0422)", 3570),
-    ("(300A,0423)", 3571),
-    ("(300A,0424)", 3572),
-    ("(300A,0425)", 3573),
-    ("(300A,0426)", 3574),
-    ("(300A,0431)", 3575),
-    ("(300A,0432)", 3576),
-    ("(300A,0433)", 3577),
-    ("(300A,0434)", 3578),
-    ("(300A,0435)", 3579),
-    ("(300A,0436)", 3580),
-    ("(300A,0440)", 3581),
-    ("(300A,0441)", 3582),
-    ("(300A,0442)", 3583),
-    ("(300A,0443)", 3584),
-    ("(300A,0450)", 3585),
-    ("(300A,0451)", 3586),
-    ("(300A,0452)", 3587),
-    ("(300A,0453)", 3588),
-    ("(300A,0501)", 3589),
-    ("(300A,0502)", 3590),
-    ("(300A,0503)", 3591),
-    ("(300A,0504)", 3592),
-    ("(300A,0505)", 3593),
-    ("(300A,0506)", 3594),
-    ("(300A,0507)", 3595),
-    ("(300A,0508)", 3596),
-    ("(300A,0509)", 3597),
-    ("(300A,0510)", 3598),
-    ("(300A,0511)", 3599),
-    ("(300A,0512)", 3600),
-    ("(300A,0600)", 3601),
-    ("(300A,0601)", 3602),
-    ("(300A,0602)", 3603),
-    ("(300A,0603)", 3604),
-    ("(300A,0604)", 3605),
-    ("(300A,0605)", 3606),
-    ("(300A,0606)", 3607),
-    ("(300A,0607)", 3608),
-    ("(300A,0608)", 3609),
-    ("(300A,0609)", 3610),
-    ("(300A,060A)", 3611),
-    ("(300A,060B)", 3612),
-    ("(300A,060C)", 3613),
-    ("(300A,060D)", 3614),
-    ("(300A,060E)", 3615),
-    ("(300A,060F)", 3616),
-    ("(300A,0610)", 3617),
-    ("(300A,0611)", 3618),
-    ("(300A,0612)", 3619),
-    ("(300A,0613)", 3620),
-    ("(300A,0614)", 3621),
-    ("(300A,0615)", 3622),
-    ("(300A,0616)", 3623),
-    ("(300A,0617)", 3624),
-    ("(300A,0618)", 3625),
-    ("(300A,0619)", 3626),
-    ("(300A,061A)", 3627),
-    ("(300A,061B)", 3628),
-    ("(300A,061C)", 3629),
-    ("(300A,061D)", 3630),
-    ("(300A,061E)", 3631),
-    ("(300A,061F)", 3632),
-    ("(300A,0620)", 3633),
-    ("(300A,0621)", 3634),
-    ("(300A,0622)", 3635),
-    ("(300A,0623)", 3636),
-    ("(300A,0624)", 3637),
-    ("(300A,0625)", 3638),
-    ("(300A,0626)", 3639),
-    ("(300A,0627)", 3640),
-    ("(300A,0628)", 3641),
-    ("(300A,0629)", 3642),
-    ("(300A,062A)", 3643),
-    ("(300A,062B)", 3644),
-    ("(300A,062C)", 3645),
-    ("(300A,062D)", 3646),
-    ("(300A,062E)", 3647),
-    ("(300A,062F)", 3648),
-    ("(300A,0630)", 3649),
-    ("(300A,0631)", 3650),
-    ("(300A,0634)", 3651),
-    ("(300A,0635)", 3652),
-    ("(300A,0636)", 3653),
-    ("(300A,0637)", 3654),
-    ("(300A,0638)", 3655),
-    ("(300A,0639)", 3656),
-    ("(300A,063A)", 3657),
-    ("(300A,063B)", 3658),
-    ("(300A,063C)", 3659),
-    ("(300A,063D)", 3660),
-    ("(300A,063E)", 3661),
-    ("(300A,063F)", 3662),
-    ("(300A,0640)", 3663),
-    ("(300A,0641)", 3664),
-    ("(300A,0642)", 3665),
-    ("(300A,0643)", 3666),
-    ("(300A,0644)", 3667),
-    ("(300A,0645)", 3668),
-    ("(300A,0646)", 3669),
-    ("(300A,0647)", 3670),
-    ("(300A,0648)", 3671),
-    ("(300A,0649)", 3672),
-    ("(300A,064A)", 3673),
-    ("(300A,064B)", 3674),
-    ("(300A,064C)", 3675),
-    ("(300A,064D)", 3676),
-    ("(300A,064E)", 3677),
-    ("(300A,064F)", 3678),
-    ("(300A,0651)", 3679),
-    ("(300A,0652)", 3680),
-    ("(300A,0653)", 3681),
-    ("(300A,0654)", 3682),
-    ("(300A,0655)", 3683),
-    ("(300A,0656)", 3684),
-    ("(300A,0657)", 3685),
-    ("(300A,0658)", 3686),
-    ("(300A,0659)", 3687),
-    ("(300A,065A)", 3688),
-    ("(300A,065B)", 3689),
-    ("(300A,065C)", 3690),
-    ("(300A,065D)", 3691),
-    ("(300A,065E)", 3692),
-    ("(300A,065F)", 3693),
-    ("(300A,0660)", 3694),
-    ("(300A,0661)", 3695),
-    ("(300A,0662)", 3696),
-    ("(300A,0663)", 3697),
-    ("(300A,0664)", 3698),
-    ("(300A,0665)", 3699),
-    ("(300A,0666)", 3700),
-    ("(300A,0667)", 3701),
-    ("(300A,0668)", 3702),
-    ("(300A,0669)", 3703),
-    ("(300A,066A)", 3704),
-    ("(300A,066B)", 3705),
-    ("(300A,066C)", 3706),
-    ("(300A,066D)", 3707),
-    ("(300A,066E)", 3708),
-    ("(300A,066F)", 3709),
-    ("(300A,0670)", 3710),
-    ("(300A,0671)", 3711),
-    ("(300A,0672)", 3712),
-    ("(300A,0673)", 3713),
-    ("(300A,0674)", 3714),
-    ("(300A,0675)", 3715),
-    ("(300A,0676)", 3716),
-    ("(300A,0677)", 3717),
-    ("(300A,0678)", 3718),
-    ("(300A,0679)", 3719),
-    ("(300A,067A)", 3720),
-    ("(300A,067B)", 3721),
-    ("(300A,067C)", 3722),
-    ("(300A,067D)", 3723),
-    ("(300A,067E)", 3724),
-    ("(300A,067F)", 3725),
-    ("(300A,0680)", 3726),
-    ("(300A,0681)", 3727),
-    ("(300A,0682)", 3728),
-    ("(300A,0683)", 3729),
-    ("(300A,0684)", 3730),
-    ("(300A,0685)", 3731),
-    ("(300A,0686)", 3732),
-    ("(300A,0687)", 3733),
-    ("(300A,0688)", 3734),
-    ("(300A,0689)", 3735),
-    ("(300A,068A)", 3736),
-    ("(300A,068B)", 3737),
-    ("(300A,0700)", 3738),
-    ("(300A,0701)", 3739),
-    ("(300A,0702)", 3740),
-    ("(300A,0703)", 3741),
-    ("(300A,0704)", 3742),
-    ("(300A,0705)", 3743),
-    ("(300A,0706)", 3744),
-    ("(300A,0707)", 3745),
-    ("(300A,0708)", 3746),
-    ("(300A,0709)", 3747),
-    ("(300A,0714)", 3748),
-    ("(300A,0715)", 3749),
-    ("(300A,0716)", 3750),
-    ("(300A,0722)", 3751),
-    ("(300A,0723)", 3752),
-    ("(300A,0730)", 3753),
-    ("(300A,0731)", 3754),
-    ("(300A,0732)", 3755),
-    ("(300A,0733)", 3756),
-    ("(300A,0734)", 3757),
-    ("(300A,0735)", 3758),
-    ("(300A,0736)", 3759),
-    ("(300A,073A)", 3760),
-    ("(300A,073B)", 3761),
-    ("(300A,073E)", 3762),
-    ("(300A,073F)", 3763),
-    ("(300A,0740)", 3764),
-    ("(300A,0741)", 3765),
-    ("(300A,0742)", 3766),
-    ("(300A,0743)", 3767),
-    ("(300A,0744)", 3768),
-    ("(300A,0745)", 3769),
-    ("(300A,0746)", 3770),
-    ("(300A,0760)", 3771),
-    ("(300A,0761)", 3772),
-    ("(300A,0762)", 3773),
-    ("(300A,0772)", 3774),
-    ("(300A,0773)", 3775),
-    ("(300A,0774)", 3776),
-    ("(300A,0780)", 3777),
-    ("(300A,0783)", 3778),
-    ("(300A,0784)", 3779),
-    ("(300A,0785)", 3780),
-    ("(300A,0786)", 3781),
-    ("(300A,0787)", 3782),
-    ("(300A,0788)", 3783),
-    ("(300A,0789)", 3784),
-    ("(300A,078A)", 3785),
-    ("(300A,078B)", 3786),
-    ("(300A,078C)", 3787),
-    ("(300A,078D)", 3788),
-    ("(300A,078E)", 3789),
-    ("(300A,078F)", 3790),
-    ("(300A,0790)", 3791),
-    ("(300A,0791)", 3792),
-    ("(300A,0792)", 3793),
-    ("(300A,0793)", 3794),
-    ("(300A,0794)", 3795),
-    ("(300A,0795)", 3796),
-    ("(300A,0796)", 3797),
-    ("(300A,0797)", 3798),
-    ("(300A,0798)", 3799),
-    ("(300A,0799)", 3800),
-    ("(300A,079A)", 3801),
-    ("(300A,079B)", 3802),
-    ("(300A,079C)", 3803),
-    ("(300A,079D)", 3804),
-    ("(300A,079E)", 3805),
-    ("(300C,0002)", 3806),
-    ("(300C,0004)", 3807),
-    ("(300C,0006)", 3808),
-    ("(300C,0007)", 3809),
-    ("(300C,0008)", 3810),
-    ("(300C,0009)", 3811),
-    ("(300C,000A)", 3812),
-    ("(300C,000C)", 3813),
-    ("(300C,000E)", 3814),
-    ("(300C,0020)", 3815),
-    ("(300C,0022)", 3816),
-    ("(300C,0040)", 3817),
-    ("(300C,0042)", 3818),
-    ("(300C,0050)", 3819),
-    ("(300C,0051)", 3820),
-    ("(300C,0055)", 3821),
-    ("(300C,0060)", 3822),
-    ("(300C,006A)", 3823),
-    ("(300C,0080)", 3824),
-    ("(300C,00A0)", 3825),
-    ("(300C,00B0)", 3826),
-    ("(300C,00C0)", 3827),
-    ("(300C,00D0)", 3828),
-    ("(300C,00E0)", 3829),
-    ("(300C,00F0)", 3830),
-    ("(300C,00F2)", 3831),
-    ("(300C,00F4)", 3832),
-    ("(300C,00F6)", 3833),
-    ("(300C,0100)", 3834),
-    ("(300C,0102)", 3835),
-    ("(300C,0104)", 3836),
-    ("(300C,0111)", 3837),
-    ("(300C,0112)", 3838),
-    ("(300C,0113)", 3839),
-    ("(300C,0114)", 3840),
-    ("(300C,0115)", 3841),
-    ("(300C,0116)", 3842),
-    ("(300C,0117)", 3843),
-    ("(300C,0118)", 3844),
-    ("(300C,0119)", 3845),
-    ("(300C,0120)", 3846),
-    ("(300C,0121)", 3847),
-    ("(300C,0122)", 3848),
-    ("(300C,0123)", 3849),
-    ("(300C,0124)", 3850),
-    ("(300C,0125)", 3851),
-    ("(300C,0126)", 3852),
-    ("(300C,0127)", 3853),
-    ("(300C,0128)", 3854),
-    ("(300C,0129)", 3855),
-    ("(300E,0002)", 3856),
-    ("(300E,0004)", 3857),
-    ("(300E,0005)", 3858),
-    ("(300E,0008)", 3859),
-    ("(3010,0001)", 3860),
-    ("(3010,0002)", 3861),
-    ("(3010,0003)", 3862),
-    ("(3010,0004)", 3863),
-    ("(3010,0005)", 3864),
-    ("(3010,0006)", 3865),
-    ("(3010,0007)", 3866),
-    ("(3010,0008)", 3867),
-    ("(3010,0009)", 3868),
-    ("(3010,000A)", 3869),
-    ("(3010,000B)", 3870),
-    ("(3010,000C)", 3871),
-    ("(3010,000D)", 3872),
-    ("(3010,000E)", 3873),
-    ("(3010,000F)", 3874),
-    ("(3010,0010)", 3875),
-    ("(3010,0011)", 3876),
-    ("(3010,0012)", 3877),
-    ("(3010,0013)", 3878),
-    ("(3010,0014)", 3879),
-    ("(3010,0015)", 3880),
-    ("(3010,0016)", 3881),
-    ("(3010,0017)", 3882),
-    ("(3010,0018)", 3883),
-    ("(3010,0019)", 3884),
-    ("(3010,001A)", 3885),
-    ("(3010,001B)", 3886),
-    ("(3010,001C)", 3887),
-    ("(3010,001D)", 3888),
-    ("(3010,001E)", 3889),
-    ("(3010,0020)", 3890),
-    ("(3010,0021)", 3891),
-    ("(3010,0022)", 3892),
-    ("(3010,0023)", 3893),
-    ("(3010,0024)", 3894),
-    ("(3010,0025)", 3895),
-    ("(3010,0026)", 3896),
-    ("(3010,0027)", 3897),
-    ("(3010,0029)", 3898),
-    ("(3010,002A)", 3899),
-    ("(3010,002B)", 3900),
-    ("(3010,002C)", 3901),
-    ("(3010,002D)", 3902),
-    ("(3010,002E)", 3903),
-    ("(3010,002F)", 3904),
-    ("(3010,0030)", 3905),
-    ("(3010,0031)", 3906),
-    ("(3010,0032)", 3907),
-    ("(3010,0033)", 3908),
-    ("(3010,0034)", 3909),
-    ("(3010,0035)", 3910),
-    ("(3010,0036)", 3911),
-    ("(3010,0037)", 3912),
-    ("(3010,0038)", 3913),
-    ("(3010,0039)", 3914),
-    ("(3010,003A)", 3915),
-    ("(3010,003B)", 3916),
-    ("(3010,003C)", 3917),
-    ("(3010,003D)", 3918),
-    ("(3010,003E)", 3919),
-    ("(3010,003F)", 3920),
-    ("(3010,0040)", 3921),
-    ("(3010,0041)", 3922),
-    ("(3010,0042)", 3923),
-    ("(3010,0043)", 3924),
-    ("(3010,0044)", 3925),
-    ("(3010,0045)", 3926),
-    ("(3010,0046)", 3927),
-    ("(3010,0047)", 3928),
-    ("(3010,0048)", 3929),
-    ("(3010,0049)", 3930),
-    ("(3010,004A)", 3931),
-    ("(3010,004B)", 3932),
-    ("(3010,004C)", 3933),
-    ("(3010,004D)", 3934),
-    ("(3010,004E)", 3935),
-    ("(3010,004F)", 3936),
-    ("(3010,0050)", 3937),
-    ("(3010,0051)", 3938),
-    ("(3010,0052)", 3939),
-    ("(3010,0053)", 3940),
-    ("(3010,0054)", 3941),
-    ("(3010,0055)", 3942),
-    ("(3010,0056)", 3943),
-    ("(3010,0057)", 3944),
-    ("(3010,0058)", 3945),
-    ("(3010,0059)", 3946),
-    ("(3010,005A)", 3947),
-    ("(3010,005B)", 3948),
-    ("(3010,005C)", 3949),
-    ("(3010,005D)", 3950),
-    ("(3010,005E)", 3951),
-    ("(3010,005F)", 3952),
-    ("(3010,0060)", 3953),
-    ("(3010,0061)", 3954),
-    ("(3010,0062)", 3955),
-    ("(3010,0063)", 3956),
-    ("(3010,0064)", 3957),
-    ("(3010,0065)", 3958),
-    ("(3010,0066)", 3959),
-    ("(3010,0067)", 3960),
-    ("(3010,0068)", 3961),
-    ("(3010,0069)", 3962),
-    ("(3010,006A)", 3963),
-    ("(3010,006B)", 3964),
-    ("(3010,006C)", 3965),
-    ("(3010,006D)", 3966),
-    ("(3010,006E)", 3967),
-    ("(3010,006F)", 3968),
-    ("(3010,0070)", 3969),
-    ("(3010,0071)", 3970),
-    ("(3010,0073)", 3971),
-    ("(3010,0074)", 3972),
-    ("(3010,0075)", 3973),
-    ("(3010,0076)", 3974),
-    ("(3010,0077)", 3975),
-    ("(3010,0078)", 3976),
-    ("(3010,0079)", 3977),
-    ("(3010,007A)", 3978),
-    ("(3010,007B)", 3979),
-    ("(3010,007C)", 3980),
-    ("(3010,007D)", 3981),
-    ("(3010,007E)", 3982),
-    ("(3010,007F)", 3983),
-    ("(3010,0080)", 3984),
-    ("(3010,0081)", 3985),
-    ("(3010,0082)", 3986),
-    ("(3010,0083)", 3987),
-    ("(3010,0084)", 3988),
-    ("(3010,0085)", 3989),
-    ("(3010,0086)", 3990),
-    ("(3010,0087)", 3991),
-    ("(3010,0088)", 3992),
-    ("(3010,0089)", 3993),
-    ("(3010,0091)", 3994),
-    ("(3010,0092)", 3995),
-    ("(3010,0093)", 3996),
-    ("(3010,0094)", 3997),
-    ("(3010,0095)", 3998),
-    ("(3010,0096)", 3999),
-    ("(3010,0097)", 4000),
-    ("(3010,0098)", 4001),
-    ("(3010,0099)", 4002),
-    ("(3010,009A)", 4003),
-    ("(3010,00A0)", 4004),
-    ("(4FFE,0001)", 4005),
-    ("(5200,9229)", 4006),
-    ("(5200,9230)", 4007),
-    ("(5400,0100)", 4008),
-    ("(5400,0110)", 4009),
-    ("(5400,0112)", 4010),
-    ("(5400,1004)", 4011),
-    ("(5400,1006)", 4012),
-    ("(5400,100A)", 4013),
-    ("(5400,1010)", 4014),
-    ("(5600,0010)", 4015),
-    ("(5600,0020)", 4016),
-    ("(60XX,0010)", 4017),
-    ("(60XX,0011)", 4018),
-    ("(60XX,0015)", 4019),
-    ("(60XX,0022)", 4020),
-    ("(60XX,0040)", 4021),
-    ("(60XX,0045)", 4022),
-    ("(60XX,0050)", 4023),
-    ("(60XX,0051)", 4024),
-    ("(60XX,0100)", 4025),
-    ("(60XX,0102)", 4026),
-    ("(60XX,1001)", 4027),
-    ("(60XX,1301)", 4028),
-    ("(60XX,1302)", 4029),
-    ("(60XX,1303)", 4030),
-    ("(60XX,1500)", 4031),
-    ("(60XX,3000)", 4032),
-    ("(7FE0,0001)", 4033),
-    ("(7FE0,0002)", 4034),
-    ("(7FE0,0003)", 4035),
-    ("(7FE0,0008)", 4036),
-    ("(7FE0,0009)", 4037),
-    ("(7FE0,0010)", 4038),
-    ("(FFFA,FFFA)", 4039),
-    ("(0002,0000)", 4040),
-    ("(0002,0001)", 4041),
-    ("(0002,0002)", 4042),
-    ("(0002,0003)", 4043),
-    ("(0002,0010)", 4044),
-    ("(0002,0012)", 4045),
-    ("(0002,0013)", 4046),
-    ("(0002,0016)", 4047),
-    ("(0002,0017)", 4048),
-    ("(0002,0018)", 4049),
-    ("(0002,0026)", 4050),
-    ("(0002,0027)", 4051),
-    ("(0002,0028)", 4052),
-    ("(0002,0031)", 4053),
-    ("(0002,0032)", 4054),
-    ("(0002,0033)", 4055),
-    ("(0002,0035)", 4056),
-    ("(0002,0036)", 4057),
-    ("(0002,0037)", 4058),
-    ("(0002,0038)", 4059),
-    ("(0002,0100)", 4060),
-    ("(0002,0102)", 4061),
-];
-
-pub static KEYWORD_INDEX: &[(&'static str, usize)] = &[
-    ("ALinePixelSpacing", 2206),
-    ("ALineRate", 2203),
-    ("ALinesPerFrame", 2204),
-    ("ASLBolusCutoffDelayTime", 960),
-    ("ASLBolusCutoffFlag", 957),
-    ("ASLBolusCutoffTechnique", 959),
-    ("ASLBolusCutoffTimingSequence", 958),
-    ("ASLContext", 952),
-    ("ASLCrusherDescription", 956),
-    ("ASLCrusherFlag", 954),
-    ("ASLCrusherFlowLimit", 955),
-    ("ASLMidSlabPosition", 951),
-    ("ASLPulseTrainDuration", 953),
-    ("ASLSlabNumber", 948),
-    ("ASLSlabOrientation", 950),
-    ("ASLSlabSequence", 961),
-    ("ASLSlabThickness", 949),
-    ("ASLTechniqueDescription", 947),
-    ("AbsoluteChannelDisplayScale", 1847),
-    ("AbsoluteDosimetricObjectiveFlag", 3971),
-    ("AbsorbedDoseToMetersetRatio", 3847),
-    ("AbstractPriorCodeSequence", 2692),
-    ("AbstractPriorValue", 2691),
-    ("Acceleration", 364),
-    ("AccessionNumber", 41),
-    ("AccessoryCode", 3333),
-    ("AcousticCouplingMediumCodeSequence", 1217),
-    ("AcousticCouplingMediumFlag", 1216),
-    ("AcousticCouplingMediumSoundSpeed", 1227),
-    ("AcousticCouplingMediumTemperature", 1218),
-    ("AcquiredImageAreaDoseProduct", 1097),
-    ("AcquiredSubtractionMaskFlag", 589),
-    ("AcquisitionContextDescription", 1918),
-    ("AcquisitionContextSequence", 1917),
-    ("AcquisitionContrast", 219),
-    ("AcquisitionDate", 34),
-    ("AcquisitionDateTime", 36),
-    ("AcquisitionDeviceProcessingCode", 631),
-    ("AcquisitionDeviceProcessingDescription", 630),
-    ("AcquisitionDeviceSequence", 2998),
-    ("AcquisitionDeviceTypeCodeSequence", 1400),
-    ("AcquisitionDuration", 865),
-    ("AcquisitionEndLocationSequence", 1269),
-    ("AcquisitionFieldOfViewLabel", 598),
-    ("AcquisitionIndex", 1377),
-    ("AcquisitionInitiationSequence", 3028),
-    ("AcquisitionMatrix", 623),
-    ("AcquisitionMethod", 3017),
-    ("AcquisitionMethodAlgorithmSequence", 1503),
-    ("AcquisitionMethodCodeSequence", 1502),
-    ("AcquisitionMode", 587),
-    ("AcquisitionMotion", 1267),
-    ("AcquisitionNumber", 1296),
-    ("AcquisitionProtocolDescription", 1060),
-    ("AcquisitionProtocolElementSequence", 1262),
-    ("AcquisitionProtocolElementSpecificationSequence", 1261),
-    ("AcquisitionProtocolName", 1059),
-    ("AcquisitionSignalType", 3016),
-    ("AcquisitionStartCondition", 456),
-    ("AcquisitionStartConditionData", 457),
-    ("AcquisitionStartLocationSequence", 1268),
-    ("AcquisitionSubtaskIndex", 3004),
-    ("AcquisitionSubtaskSequence", 3001),
-    ("AcquisitionTaskApplicabilitySequence", 3011),
-    ("AcquisitionTaskIndex", 3003),
-    ("AcquisitionTaskSequence", 2999),
-    ("AcquisitionTaskWorkitemCodeSequence", 3000),
-    ("AcquisitionTerminationCondition", 454),
-    ("AcquisitionTerminationConditionData", 458),
-    ("AcquisitionTime", 39),
-    ("AcquisitionTimeSynchronized", 682),
-    ("AcquisitionType", 967),
-    ("AcquisitionUID", 26),
-    ("AcrossScanSpatialResolution", 1424),
-    ("ActiveImageAreaOverlayGroup", 763),
-    ("ActiveSourceDiameter", 3423),
-    ("ActiveSourceLength", 3424),
-    ("ActualCardiacTriggerDelayTime", 1353),
-    ("ActualCardiacTriggerTimePriorToRPeak", 1327),
-    ("ActualFrameDuration", 611),
-    ("ActualRespiratoryTriggerDelayTime", 1358),
-    ("AddIntermediateSequence", 2090),
-    ("AddNearSequence", 2089),
-    ("AddOtherSequence", 2091),
-    ("AddPower", 2092),
-    ("AdditionalDrugSequence", 442),
-    ("AdditionalParameterRecordingInstanceSequence", 3777),
-    ("AdditionalPatientHistory", 268),
-    ("AdditionalRTAccessoryDeviceSequence", 3023),
-    ("AdministrationRouteCodeSequence", 2264),
-    ("AdmissionID", 1804),
-    ("AdmittingDiagnosesCodeSequence", 158),
-    ("AdmittingDiagnosesDescription", 157),
-    ("AdvancedBlendingSequence", 2660),
-    ("AfterloaderChannelID", 3454),
-    ("AgeCorrectedSensitivityDeviationAlgorithmSequence", 1589),
-    ("AgeCorrectedSensitivityDeviationProbabilityValue", 1621),
-    ("AgeCorrectedSensitivityDeviationValue", 1614),
-    ("AlgorithmDescription", 1118),
-    ("AlgorithmFamilyCodeSequence", 2371),
-    ("AlgorithmName", 2377),
-    ("AlgorithmNameCodeSequence", 2372),
-    ("AlgorithmParameters", 2374),
-    ("AlgorithmSource", 1640),
-    ("AlgorithmType", 1117),
-    ("AlgorithmVersion", 2373),
-    ("AliasedDataType", 1203),
-    ("Allergies", 264),
-    ("AlongScanSpatialResolution", 1419),
-    ("AlphaLUTTransferFunction", 1731),
-    ("AlphaPaletteColorLookupTableData", 1705),
-    ("AlphaPaletteColorLookupTableDescriptor", 1700),
-    ("AlternateBeamDose", 3266),
-    ("AlternateBeamDoseType", 3267),
-    ("AlternateContainerIdentifierSequence", 1911),
-    ("AlternateContentDescriptionSequence", 2524),
-    ("AlternateRepresentationSequence", 205),
-    ("AlternateValueSequence", 3762),
-    ("AmbientReflectionIntensity", 2636),
-    ("AnalogFilterCharacteristicsSequence", 1864),
-    ("AnalogFilterRollOff", 1865),
-    ("AnalogFilterType", 1866),
-    ("AnalyzedArea", 2129),
-    ("AnatomicRegionModifierSequence", 202),
-    ("AnatomicRegionSequence", 201),
-    ("AnatomicRegionsInStudyCodeSequence", 50),
-    ("AnatomicStructureReferencePoint", 1512),
-    ("AnatomicalOrientationType", 274),
-    ("AnchorPoint", 2502),
-    ("AnchorPointAnnotationUnits", 2494),
-    ("AnchorPointVisibility", 2503),
-    ("AngioFlag", 437),
-    ("AngularStep", 556),
-    ("AngularViewVector", 2254),
-    ("AnimationCurveSequence", 2654),
-    ("AnimationStepSize", 2655),
-    ("AnnotationAppliesToAllOpticalPaths", 2486),
-    ("AnnotationAppliesToAllZPlanes", 2488),
-    ("AnnotationClipping", 2651),
-    ("AnnotationCoordinateType", 2475),
-    ("AnnotationGroupAlgorithmIdentificationSequence", 2481),
-    ("AnnotationGroupDescription", 2479),
-    ("AnnotationGroupGenerationType", 2480),
-    ("AnnotationGroupLabel", 2478),
-    ("AnnotationGroupNumber", 1997),
-    ("AnnotationGroupSequence", 2476),
-    ("AnnotationGroupUID", 2477),
-    ("AnnotationIndexList", 2490),
-    ("AnnotationPropertyCategoryCodeSequence", 2482),
-    ("AnnotationPropertyTypeCodeSequence", 2483),
-    ("AnnotationPropertyTypeModifierCodeSequence", 2484),
-    ("AnodeTargetMaterial", 581),
-    ("AnteriorChamberDepth", 1478),
-    ("AnteriorChamberDepthDefinitionCodeSequence", 1471),
-    ("AnteriorChamberDepthSequence", 1473),
-    ("ApertureValue", 350),
-    ("ApexPosition", 1362),
-    ("ApplicableFrameRange", 1752),
-    ("ApplicableSafetyStandardAgency", 914),
-    ("ApplicableSafetyStandardDescription", 915),
-    ("ApplicationManufacturer", 1116),
-    ("ApplicationMaximumRepaintTime", 2739),
-    ("ApplicationName", 1114),
-    ("ApplicationSetupCheck", 3173),
-    ("ApplicationSetupManufacturer", 3440),
-    ("ApplicationSetupName", 3439),
-    ("ApplicationSetupNumber", 3438),
-    ("ApplicationSetupSequence", 3436),
-    ("ApplicationSetupType", 3437),
-    ("ApplicationVersion", 1115),
-    ("ApplicatorApertureShape", 3576),
-    ("ApplicatorDescription", 3345),
-    ("ApplicatorGeometrySequence", 3575),
-    ("ApplicatorID", 3343),
-    ("ApplicatorOpening", 3577),
-    ("ApplicatorOpeningX", 3578),
-    ("ApplicatorOpeningY", 3579),
-    ("ApplicatorSequence", 3342),
-    ("ApplicatorShapeReferencedROINumber", 3472),
-    ("ApplicatorType", 3344),
-    ("AppliedMaskSubtractionFlag", 603),
-    ("ApprovalSequence", 2044),
-    ("ApprovalStatus", 3856),
-    ("ApprovalSubjectSequence", 2053),
-    ("ArterialSpinLabelingContrast", 945),
-    ("AscanRate", 1547),
-    ("AsserterIdentificationSequence", 2047),
-    ("AssertionCodeSequence", 2045),
-    ("AssertionComments", 2050),
-    ("AssertionDateTime", 2048),
-    ("AssertionExpirationDateTime", 2049),
-    ("AssertionUID", 2046),
-    ("AssessedAttributeValueSequence", 2894),
-    ("AssessedSOPInstanceSequence", 2887),
-    ("AssessmentLabel", 2901),
-    ("AssessmentObservationsSequence", 2890),
-    ("AssessmentRequesterSequence", 2896),
-    ("AssessmentSetID", 2895),
-    ("AssessmentSummary", 2885),
-    ("AssessmentSummaryDescription", 2886),
-    ("AssessmentTypeCodeSequence", 2899),
-    ("AssigningAgencyOrDepartmentCodeSequence", 1888),
-    ("AssigningFacilitySequence", 1886),
-    ("AssigningJurisdictionCodeSequence", 1887),
-    ("AttenuationCorrected", 1181),
-    ("AttenuationCorrectionMethod", 2280),
-    ("AttenuationCorrectionSource", 1173),
-    ("AttenuationCorrectionTemporalRelationship", 1192),
-    ("AttributeModificationDateTime", 2938),
-    ("AttributeToleranceValuesSequence", 3644),
-    ("AuthorIdentificationSequence", 3884),
-    ("AuthorObserverSequence", 1973),
-    ("AuthorizationEquipmentCertificationNumber", 2915),
-    ("AutoKVPLowerBound", 1287),
-    ("AutoKVPSelectionType", 1285),
-    ("AutoKVPUpperBound", 1286),
-    ("AutorefractionLeftEyeSequence", 2072),
-    ("AutorefractionRightEyeSequence", 2071),
-    ("AutosequenceFlag", 2799),
-    ("AverageCornealPower", 2127),
-    ("AveragePulseWidth", 564),
-    ("AxialAcceptance", 2285),
-    ("AxialDetectorDimension", 1165),
-    ("AxialLengthOfTheEye", 1414),
-    ("AxialMash", 2286),
-    ("AxialPower", 2139),
-    ("AxialResolution", 2201),
-    ("AxisOfRotation", 2356),
-    ("B1rms", 629),
-    ("BackgroundColor", 2084),
-    ("BackgroundIlluminationColorCodeSequence", 1557),
-    ("BackgroundLuminance", 1555),
-    ("BarcodeValue", 2949),
-    ("BaselineCorrection", 862),
-    ("BasisRTTreatmentPhaseIndex", 3919),
-    ("BatteryLevel", 319),
-    ("BeamAngle", 1079),
-    ("BeamAreaLimitSequence", 3735),
-    ("BeamCurrentModulationID", 3525),
-    ("BeamDeliveryDurationLimit", 3287),
-    ("BeamDescription", 3285),
-    ("BeamDose", 3258),
-    ("BeamDoseMeaning", 3263),
-    ("BeamDosePointDepth", 3260),
-    ("BeamDosePointEquivalentDepth", 3261),
-    ("BeamDosePointSSD", 3262),
-    ("BeamDosePointSourceToExternalContourDistance", 3269),
-    ("BeamDoseType", 3265),
-    ("BeamDoseVerificationControlPointSequence", 3264),
-    ("BeamHoldOriginatingDeviceSequence", 3854),
-    ("BeamHoldTransition", 3852),
-    ("BeamHoldTransitionDateTime", 3853),
-    ("BeamHoldTransitionTriggerSource", 3855),
-    ("BeamLimitingDeviceAngle", 3359),
-    ("BeamLimitingDeviceAngleTolerance", 3237),
-    ("BeamLimitingDeviceLeafPairsSequence", 3156),
-    ("BeamLimitingDevicePositionSequence", 3355),
-    ("BeamLimitingDevicePositionTolerance", 3239),
-    ("BeamLimitingDeviceRotationDirection", 3360),
-    ("BeamLimitingDeviceSequence", 3277),
-    ("BeamLimitingDeviceToleranceSequence", 3238),
-    ("BeamMeterset", 3259),
-    ("BeamModifierCoordinatesPresenceFlag", 2980),
-    ("BeamModifierOrientationAngle", 3668),
-    ("BeamName", 3284),
-    ("BeamNumber", 3283),
-    ("BeamOrderIndex", 2814),
-    ("BeamSequence", 3273),
-    ("BeamSpotSize", 2197),
-    ("BeamStopperPosition", 3200),
-    ("BeamTaskSequence", 2797),
-    ("BeamTaskType", 2798),
-    ("BeamType", 3286),
-    ("BeatRejectionFlag", 529),
-    ("BiopsyTargetSequence", 695),
-    ("BitsAllocated", 1666),
-    ("BitsMappedToColorLookupTable", 1720),
-    ("BitsStored", 1667),
-    ("BlendingDisplayInputSequence", 2662),
-    ("BlendingDisplaySequence", 2663),
-    ("BlendingInputNumber", 2661),
-    ("BlendingLUT1Sequence", 1721),
-    ("BlendingLUT1TransferFunction", 1722),
-    ("BlendingLUT2Sequence", 1727),
-    ("BlendingLUT2TransferFunction", 1728),
-    ("BlendingLookupTableData", 1725),
-    ("BlendingLookupTableDescriptor", 1724),
-    ("BlendingMode", 2664),
-    ("BlendingOperationType", 2775),
-    ("BlendingPosition", 2596),
-    ("BlendingSequence", 2593),
-    ("BlendingWeightConstant", 1723),
-    ("BlindSpotLocalized", 1626),
-    ("BlindSpotXCoordinate", 1627),
-    ("BlindSpotYCoordinate", 1628),
-    ("BlockData", 3341),
-    ("BlockDefinitionSequence", 3704),
-    ("BlockDivergence", 3334),
-    ("BlockEdgeData", 3705),
-    ("BlockEdgeDataSequence", 3709),
-    ("BlockIdentifyingInformationStatus", 95),
-    ("BlockMountingPosition", 3335),
-    ("BlockName", 3337),
-    ("BlockNumber", 3336),
-    ("BlockNumberOfPoints", 3340),
-    ("BlockOrientation", 3706),
-    ("BlockSequence", 3328),
-    ("BlockSlabNumber", 3584),
-    ("BlockSlabSequence", 3582),
-    ("BlockSlabThickness", 3583),
-    ("BlockThickness", 3338),
-    ("BlockTransmission", 3339),
-    ("BlockTrayID", 3329),
-    ("BlockType", 3332),
-    ("BloodSignalNulling", 825),
-    ("BluePaletteColorLookupTableData", 1704),
-    ("BluePaletteColorLookupTableDescriptor", 1699),
-    ("BodyPartExamined", 431),
-    ("BodyPartThickness", 582),
-    ("Bold", 2549),
-    ("BolusDefinitionSequence", 3713),
-    ("BolusDescription", 3307),
-    ("BolusID", 3306),
-    ("BoneThermalIndex", 713),
-    ("BoundingBoxAnnotationUnits", 2493),
-    ("BoundingBoxBottomRightHandCorner", 2500),
-    ("BoundingBoxCrop", 2614),
-    ("BoundingBoxTextHorizontalJustification", 2501),
-    ("BoundingBoxTopLeftHandCorner", 2499),
-    ("BoundingRectangle", 2427),
-    ("BrachyAccessoryDeviceID", 3447),
-    ("BrachyAccessoryDeviceName", 3449),
-    ("BrachyAccessoryDeviceNominalThickness", 3450),
-    ("BrachyAccessoryDeviceNominalTransmission", 3451),
-    ("BrachyAccessoryDeviceNumber", 3446),
-    ("BrachyAccessoryDeviceSequence", 3445),
-    ("BrachyAccessoryDeviceType", 3448),
-    ("BrachyApplicationSetupDose", 3272),
-    ("BrachyApplicationSetupDoseSpecificationPoint", 3271),
-    ("BrachyControlPointDeliveredSequence", 3187),
-    ("BrachyControlPointSequence", 3482),
-    ("BrachyPulseControlPointDeliveredSequence", 3194),
-    ("BrachyReferencedDoseReferenceSequence", 3821),
-    ("BrachyTaskSequence", 2817),
-    ("BrachyTreatmentTechnique", 3416),
-    ("BrachyTreatmentType", 3417),
-    ("BrachytherapySourceType", 3929),
-    ("BreastImplantPresent", 1713),
-    ("BreastSupportIsocenterPrimaryAngle", 1126),
-    ("BreastSupportIsocenterSecondaryAngle", 1127),
-    ("BreastSupportXPositionToIsocenter", 1128),
-    ("BreastSupportYPositionToIsocenter", 1129),
-    ("BreastSupportZPositionToIsocenter", 1130),
-    ("BreedRegistrationNumber", 278),
-    ("BreedRegistrationSequence", 277),
-    ("BreedRegistryCodeSequence", 279),
-    ("BrightnessValue", 351),
-    ("BscanCycleTime", 1545),
-    ("BscanCycleTimeVector", 1546),
-    ("BscanRate", 1548),
-    ("BscanSlabThickness", 1543),
-    ("BulkMotionCompensationTechnique", 912),
-    ("BulkMotionSignalSource", 913),
-    ("BurnedInAnnotation", 1681),
-    ("CArmPhotonElectronControlPointSequence", 3648),
-    ("CArmPositionerTabletopRelationship", 1098),
-    ("CSSFontName", 2535),
-    ("CTAcquisitionDetailsSequence", 969),
-    ("CTAcquisitionTypeSequence", 966),
-    ("CTAdditionalXRaySourceSequence", 1011),
-    ("CTDIPhantomTypeCodeSequence", 1007),
-    ("CTDIvol", 1006),
-    ("CTDIvolNotificationTrigger", 1283),
-    ("CTExposureSequence", 986),
-    ("CTGeometrySequence", 977),
-    ("CTImageFrameTypeSequence", 993),
-    ("CTImagingAcquisitionParameterSequence", 3013),
-    ("CTPositionSequence", 990),
-    ("CTReconstructionSequence", 979),
-    ("CTTableDynamicsSequence", 973),
-    ("CTXRayDetailsSequence", 989),
-    ("CalciumScoringMassFactorDevice", 1009),
-    ("CalciumScoringMassFactorPatient", 1008),
-    ("CalculatedAnatomyThickness", 1081),
-    ("CalculatedDoseReferenceDescription", 3148),
-    ("CalculatedDoseReferenceDoseValue", 3149),
-    ("CalculatedDoseReferenceNumber", 3147),
-    ("CalculatedDoseReferenceSequence", 3146),
-    ("CalculatedFrameList", 175),
-    ("CalculatedTargetPosition", 698),
-    ("CalculationComment", 1476),
-    ("CalculationCommentSequence", 1474),
-    ("CalculationCommentType", 1475),
-    ("CalibrationDataSequence", 2266),
-    ("CalibrationDateTime", 607),
-    ("CalibrationImage", 2178),
-    ("CalibrationReferencePointDepth", 3850),
-    ("CalibrationSequence", 1082),
-    ("CameraAngleOfView", 1409),
-    ("CameraElevationAngle", 365),
-    ("CameraOwnerName", 384),
-    ("CameraPositionGroupSequence", 1801),
-    ("CardiacBeatRejectionTechnique", 909),
-    ("CardiacCyclePosition", 941),
-    ("CardiacFramingType", 510),
-    ("CardiacNumberOfImages", 537),
-    ("CardiacRRIntervalSpecified", 864),
-    ("CardiacSignalSource", 877),
-    ("CardiacSynchronizationSequence", 898),
-    ("CardiacSynchronizationTechnique", 838),
-    ("CassetteID", 480),
-    ("CassetteOrientation", 632),
-    ("CassetteSize", 633),
-    ("CatchTrialsDataFlag", 1579),
-    ("CatheterDirectionOfRotation", 2214),
-    ("CatheterRotationalRate", 2205),
-    ("CellValuesSequence", 2022),
-    ("CenterFrequency", 1220),
-    ("CenterOfCircularCollimator", 679),
-    ("CenterOfCircularExposureControlSensingRegion", 1075),
-    ("CenterOfCircularOutline", 670),
-    ("CenterOfCircularShutter", 659),
-    ("CenterOfRotation", 2357),
-    ("CenterOfRotationOffset", 557),
-    ("CertificateOfSigner", 2923),
-    ("CertificateType", 2922),
-    ("CertifiedTimestamp", 2926),
-    ("CertifiedTimestampType", 2925),
-    ("ChairHeadFramePosition", 3384),
-    ("ChairHeadFramePositionTolerance", 3386),
-    ("ChannelBaseline", 1829),
-    ("ChannelDefinitionSequence", 1818),
-    ("ChannelDeliveryContinuationSequence", 2829),
-    ("ChannelDeliveryOrderIndex", 2828),
-    ("ChannelDeliveryOrderSequence", 2821),
-    ("ChannelDerivationDescription", 1825),
-    ("ChannelDescriptionCodeSequence", 1405),
-    ("ChannelDisplaySequence", 1842),
-    ("ChannelEffectiveLength", 3452),
-    ("ChannelIdentificationCode", 1849),
-    ("ChannelImpedanceSequence", 1853),
-    ("ChannelInnerLength", 3453),
-    ("ChannelLabel", 1820),
-    ("ChannelLength", 3458),
-    ("ChannelMaximumValue", 4010),
-    ("ChannelMinimumValue", 4009),
-    ("ChannelMode", 1850),
-    ("ChannelNumber", 3457),
-    ("ChannelOffset", 1832),
-    ("ChannelPosition", 1844),
-    ("ChannelRecommendedDisplayCIELabValue", 1843),
-    ("ChannelSampleSkew", 1831),
-    ("ChannelSensitivity", 1826),
-    ("ChannelSensitivityCorrectionFactor", 1828),
-    ("ChannelSensitivityUnitsSequence", 1827),
-    ("ChannelSequence", 3456),
-    ("ChannelShieldID", 3477),
-    ("ChannelShieldName", 3478),
-    ("ChannelShieldNominalThickness", 3479),
-    ("ChannelShieldNominalTransmission", 3480),
-    ("ChannelShieldNumber", 3476),
-    ("ChannelShieldSequence", 3475),
-    ("ChannelSourceModifiersSequence", 1823),
-    ("ChannelSourceSequence", 1822),
-    ("ChannelStatus", 1821),
-    ("ChannelTimeSkew", 1830),
-    ("ChannelTotalTime", 3459),
-    ("ChannelWidth", 2067),
-    ("ChemicalShiftMaximumIntegrationLimitInppm", 963),
-    ("ChemicalShiftMinimumIntegrationLimitInppm", 962),
-    ("ChemicalShiftReference", 851),
-    ("ChemicalShiftSequence", 876),
-    ("CineRate", 449),
-    ("CineRelativeToRealTime", 2762),
-    ("ClinicalFractionNumber", 3743),
-    ("ClinicalTrialCoordinatingCenterName", 303),
-    ("ClinicalTrialProtocolEthicsCommitteeApprovalNumber", 311),
-    ("ClinicalTrialProtocolEthicsCommitteeName", 310),
-    ("ClinicalTrialProtocolID", 286),
-    ("ClinicalTrialProtocolName", 287),
-    ("ClinicalTrialSeriesDescription", 308),
-    ("ClinicalTrialSeriesID", 307),
-    ("ClinicalTrialSiteID", 290),
-    ("ClinicalTrialSiteName", 291),
-    ("ClinicalTrialSponsorName", 285),
-    ("ClinicalTrialSubjectID", 293),
-    ("ClinicalTrialSubjectReadingID", 295),
-    ("ClinicalTrialTimePointDescription", 298),
-    ("ClinicalTrialTimePointID", 297),
-    ("ClinicalTrialTimePointTypeCodeSequence", 301),
-    ("CoatingMaterialsCodeSequence", 2433),
-    ("CodeMeaning", 64),
-    ("CodeValue", 61),
-    ("CodingSchemeDesignator", 62),
-    ("CodingSchemeExternalID", 77),
-    ("CodingSchemeIdentificationSequence", 75),
-    ("CodingSchemeName", 78),
-    ("CodingSchemeRegistry", 76),
-    ("CodingSchemeResourcesSequence", 68),
-    ("CodingSchemeResponsibleOrganization", 79),
-    ("CodingSchemeUID", 71),
-    ("CodingSchemeURL", 73),
-    ("CodingSchemeURLType", 69),
-    ("CodingSchemeVersion", 63),
-    ("CoincidenceWindowWidth", 2289),
-    ("CollimatorGridName", 575),
-    ("CollimatorLeftVerticalEdge", 675),
-    ("CollimatorLowerHorizontalEdge", 678),
-    ("CollimatorRightVerticalEdge", 676),
-    ("CollimatorShape", 674),
-    ("CollimatorShapeSequence", 1054),
-    ("CollimatorType", 576),
-    ("CollimatorUpperHorizontalEdge", 677),
-    ("ColorFilterArrayPatternColumns", 331),
-    ("ColorFilterArrayPatternRows", 330),
-    ("ColorFilterArrayPatternValues", 332),
-    ("ColorSpace", 1733),
-    ("ColumnAngulation", 639),
-    ("ColumnAngulationPatient", 1078),
-    ("ColumnPositionInTotalImagePixelMatrix", 2173),
-    ("Columns", 1659),
-    ("CombinationSegmentReferenceSequence", 3894),
-    ("CommentsOnPatientPerformanceOfVisualField", 1570),
-    ("CommentsOnRadiationDose", 1902),
-    ("CommentsOnThePerformedProcedureStep", 1898),
-    ("CommonZCoordinateValue", 2489),
-    ("CompensatorBasePlaneOffset", 3700),
-    ("CompensatorColumnOffset", 3491),
-    ("CompensatorColumns", 3317),
-    ("CompensatorDefinitionSequence", 3696),
-    ("CompensatorDescription", 3496),
-    ("CompensatorDistalThicknessMap", 3699),
-    ("CompensatorDivergence", 3486),
-    ("CompensatorID", 3314),
-    ("CompensatorMapOrientation", 3697),
-    ("CompensatorMillingToolDiameter", 3494),
-    ("CompensatorMountingPosition", 3487),
-    ("CompensatorNumber", 3313),
-    ("CompensatorPixelSpacing", 3318),
-    ("CompensatorPosition", 3319),
-    ("CompensatorProximalThicknessMap", 3698),
-    ("CompensatorRelativeStoppingPowerRatio", 3493),
-    ("CompensatorRows", 3316),
-    ("CompensatorSequence", 3312),
-    ("CompensatorShapeFabricationCodeSequence", 3701),
-    ("CompensatorShapeSequence", 3702),
-    ("CompensatorThicknessData", 3321),
-    ("CompensatorTransmissionData", 3320),
-    ("CompensatorTrayID", 3324),
-    ("CompensatorType", 3323),
-    ("CompletionFlag", 2008),
-    ("CompletionFlagDescription", 2009),
-    ("ComplexImageComponent", 218),
-    ("Component1ReferencedID", 2848),
-    ("Component1ReferencedMatingFeatureID", 2850),
-    ("Component1ReferencedMatingFeatureSetID", 2849),
-    ("Component2ReferencedID", 2851),
-    ("Component2ReferencedMatingFeatureID", 2853),
-    ("Component2ReferencedMatingFeatureSetID", 2852),
-    ("ComponentAssemblySequence", 2847),
-    ("ComponentID", 2846),
-    ("ComponentInputSequence", 2643),
-    ("ComponentSequence", 2845),
-    ("ComponentType", 2642),
-    ("ComponentTypeCodeSequence", 2842),
-    ("ComponentTypesSequence", 2841),
-    ("CompoundGraphicInstanceID", 2532),
-    ("CompoundGraphicSequence", 2531),
-    ("CompoundGraphicType", 2571),
-    ("CompoundGraphicUnits", 2565),
-    ("CompressionContactArea", 586),
-    ("CompressionForce", 583),
-    ("CompressionPressure", 584),
-    ("ConcatenationFrameOffsetNumber", 1343),
-    ("ConcatenationUID", 1331),
-    ("ConceptCodeSequence", 1994),
-    ("ConceptNameCodeSequence", 1969),
-    ("ConceptualVolumeBlockingConstraint", 3961),
-    ("ConceptualVolumeCategoryCodeSequence", 3960),
-    ("ConceptualVolumeCombinationDescription", 3874),
-    ("ConceptualVolumeCombinationExpression", 3871),
-    ("ConceptualVolumeCombinationFlag", 3873),
-    ("ConceptualVolumeConstituentIndex", 3872),
-    ("ConceptualVolumeConstituentSegmentationReferenceSequence", 3877),
-    ("ConceptualVolumeConstituentSequence", 3867),
-    ("ConceptualVolumeDerivationAlgorithmSequence", 3881),
-    ("ConceptualVolumeDescription", 3882),
-    ("ConceptualVolumeIdentificationSequence", 4004),
-    ("ConceptualVolumeOptimizationPrecedence", 3959),
-    ("ConceptualVolumeSegmentationDefinedFlag", 3875),
-    ("ConceptualVolumeSegmentationReferenceSequence", 3876),
-    ("ConceptualVolumeSequence", 3895),
-    ("ConceptualVolumeTypeCodeSequence", 3962),
-    ("ConceptualVolumeTypeModifierCodeSequence", 3963),
-    ("ConceptualVolumeUID", 3865),
-    ("CondenserLensPower", 2163),
-    ("ConfirmationSequence", 3763),
-    ("ConfocalMicroscopyImageFrameTypeSequence", 2168),
-    ("ConfocalMode", 2166),
-    ("ConsentForClinicalTrialUseSequence", 312),
-    ("ConsentForDistributionFlag", 314),
-    ("ConstantVolumeFlag", 996),
-    ("ConstituentConceptualVolumeUID", 3878),
-    ("ConstraintType", 2902),
-    ("ConstraintValueSequence", 2904),
-    ("ConstraintViolationCondition", 2907),
-    ("ConstraintViolationSignificance", 2906),
-    ("ConstraintWeight", 3222),
-    ("ConsultingPhysicianIdentificationSequence", 60),
-    ("ConsultingPhysicianName", 59),
-    ("ContactMethod", 424),
-    ("ContainerComponentDescription", 2192),
-    ("ContainerComponentDiameter", 2191),
-    ("ContainerComponentID", 2189),
-    ("ContainerComponentLength", 2190),
-    ("ContainerComponentMaterial", 2188),
-    ("ContainerComponentSequence", 1914),
-    ("ContainerComponentThickness", 2181),
-    ("ContainerComponentTypeCodeSequence", 2180),
-    ("ContainerComponentWidth", 2183),
-    ("ContainerDescription", 1913),
-    ("ContainerFileType", 119),
-    ("ContainerIdentifier", 1909),
-    ("ContainerTypeCodeSequence", 1912),
-    ("ContentCreatorIdentificationCodeSequence", 2523),
-    ("ContentCreatorName", 2522),
-    ("ContentDate", 35),
-    ("ContentDescription", 2519),
-    ("ContentItemModifierSequence", 1908),
-    ("ContentLabel", 2518),
-    ("ContentQualification", 809),
-    ("ContentSequence", 2014),
-    ("ContentTemplateSequence", 2012),
-    ("ContentTime", 40),
-    ("ContextGroupExtensionCreatorUID", 72),
-    ("ContextGroupExtensionFlag", 70),
-    ("ContextGroupIdentificationSequence", 86),
-    ("ContextGroupLocalVersion", 67),
-    ("ContextGroupVersion", 66),
-    ("ContextIdentifier", 74),
-    ("ContextUID", 80),
-    ("ContinuationEndMeterset", 2796),
-    ("ContinuationEndTotalReferenceAirKerma", 2819),
-    ("ContinuationPulseNumber", 2820),
-    ("ContinuationStartMeterset", 2795),
-    ("ContinuationStartTotalReferenceAirKerma", 2818),
-    ("ContinuityOfContent", 1970),
-    ("ContourData", 3086),
-    ("ContourGeometricType", 3077),
-    ("ContourImageSequence", 3059),
-    ("ContourNumber", 3079),
-    ("ContourSequence", 3076),
-    ("ContourUncertaintyRadius", 2583),
-    ("ContraindicationsCodeSequence", 1241),
-    ("Contrast", 379),
-    ("ContrastAdministrationProfileSequence", 1001),
-    ("ContrastBolusAdministrationRouteSequence", 430),
-    ("ContrastBolusAgent", 427),
-    ("ContrastBolusAgentAdministered", 1003),
-    ("ContrastBolusAgentDetected", 1004),
-    ("ContrastBolusAgentNumber", 999),
-    ("ContrastBolusAgentPhase", 1005),
-    ("ContrastBolusAgentSequence", 428),
-    ("ContrastBolusAutoInjectionTriggerFlag", 593),
-    ("ContrastBolusIngredient", 503),
-    ("ContrastBolusIngredientCodeSequence", 1000),
-    ("ContrastBolusIngredientConcentration", 504),
-    ("ContrastBolusIngredientOpaque", 1061),
-    ("ContrastBolusIngredientPercentByVolume", 2195),
-    ("ContrastBolusInjectionDelay", 594),
-    ("ContrastBolusRoute", 495),
-    ("ContrastBolusStartTime", 497),
-    ("ContrastBolusStopTime", 498),
-    ("ContrastBolusT1Relaxivity", 429),
-    ("ContrastBolusTotalDose", 499),
-    ("ContrastBolusUsageSequence", 1002),
-    ("ContrastBolusVolume", 496),
-    ("ContrastFlowDuration", 502),
-    ("ContrastFlowRate", 501),
-    ("ContrastFrameAveraging", 1754),
-    ("ContributingEquipmentSequence", 1289),
-    ("ContributingSOPInstancesReferenceSequence", 1378),
-    ("ContributingSourcesSequence", 1102),
-    ("ContributionDateTime", 1290),
-    ("ContributionDescription", 1291),
-    ("ControlPoint3DPosition", 3484),
-    ("ControlPointDeliverySequence", 3123),
-    ("ControlPointIndex", 3350),
-    ("ControlPointOrientation", 3567),
-    ("ControlPointRelativePosition", 3483),
-    ("ControlPointSequence", 3349),
-    ("ConversionSourceAttributesSequence", 1339),
-    ("ConversionType", 51),
-    ("ConvolutionKernel", 610),
-    ("ConvolutionKernelGroup", 981),
-    ("CorneaMeasurementMethodCodeSequence", 2100),
-    ("CorneaMeasurementsSequence", 2094),
-    ("CornealAxis", 2099),
-    ("CornealEccentricityIndex", 2132),
-    ("CornealISValue", 2128),
-    ("CornealPointEstimated", 2138),
-    ("CornealPointLocation", 2137),
-    ("CornealPower", 2098),
-    ("CornealSize", 2069),
-    ("CornealSizeSequence", 2070),
-    ("CornealTopographyMapQualityEvaluation", 2135),
-    ("CornealTopographyMapTypeCodeSequence", 2119),
-    ("CornealTopographyMappingNormalsSequence", 2121),
-    ("CornealTopographySurface", 2114),
-    ("CornealVertexLocation", 2115),
-    ("CornealWavefront", 2143),
-    ("CorrectedImage", 1665),
-    ("CorrectedLocalizedDeviationFromNormal", 1603),
-    ("CorrectedLocalizedDeviationFromNormalCalculated", 1602),
-    ("CorrectedLocalizedDeviationFromNormalProbability", 1605),
-    ("CorrectedLocalizedDeviationFromNormalProbabilityCalculated", 1604),
-    ("CorrectedParameterSequence", 3144),
-    ("CorrectionValue", 3145),
-    ("CountLossNormalizationCorrected", 1186),
-    ("CountRate", 612),
-    ("CountsAccumulated", 453),
-    ("CountsSource", 2276),
-    ("CoverageOfKSpace", 884),
-    ("CranialThermalIndex", 714),
-    ("CreatorVersionUID", 212),
-    ("Crop", 2604),
-    ("CroppingSpecificationIndex", 2605),
-    ("CroppingSpecificationNumber", 2618),
-    ("CumulativeDoseReferenceCoefficient", 3346),
-    ("CumulativeDoseToDoseReference", 3132),
-    ("CumulativeMeterset", 3659),
-    ("CumulativeMetersetWeight", 3374),
-    ("CumulativeTimeWeight", 3485),
-    ("CurrentFractionNumber", 3111),
-    ("CurrentFrameFunctionalGroupsSequence", 17),
-    ("CurrentRequestedProcedureEvidenceSequence", 2005),
-    ("CurrentTreatmentStatus", 3195),
-    ("CustodialOrganizationSequence", 1975),
-    ("CustomRendered", 372),
-    ("CylinderAxis", 1388),
-    ("CylinderLensPower", 1387),
-    ("CylinderPower", 2113),
-    ("CylinderSequence", 2059),
-    ("DICOMMediaRetrievalSequence", 2032),
-    ("DICOMRetrievalSequence", 2031),
-    ("DICOMStorageSequence", 1944),
-    ("DLPNotificationTrigger", 1284),
-    ("DVHData", 3045),
-    ("DVHDoseScaling", 3042),
-    ("DVHMaximumDose", 3049),
-    ("DVHMeanDose", 3050),
-    ("DVHMinimumDose", 3048),
-    ("DVHNormalizationDoseValue", 3040),
-    ("DVHNormalizationPoint", 3039),
-    ("DVHNumberOfBins", 3044),
-    ("DVHROIContributionType", 3047),
-    ("DVHReferencedROISequence", 3046),
-    ("DVHSequence", 3041),
-    ("DVHType", 3029),
-    ("DVHVolumeUnits", 3043),
-    ("DataCollectionCenterPatient", 978),
-    ("DataCollectionDiameter", 469),
-    ("DataElementsSigned", 2919),
-    ("DataFrameAssignmentSequence", 1718),
-    ("DataInformationSequence", 2247),
-    ("DataObservationSequence", 1647),
-    ("DataPathAssignment", 1719),
-    ("DataPathID", 1729),
-    ("DataPointColumns", 1760),
-    ("DataPointRows", 1759),
-    ("DataRepresentation", 1762),
-    ("DataSetDescription", 1644),
-    ("DataSetName", 1641),
-    ("DataSetSource", 1643),
-    ("DataSetVersion", 1642),
-    ("DataType", 1201),
-    ("Date", 1982),
-    ("DateOfInstallation", 609),
-    ("DateOfLastCalibration", 605),
-    ("DateOfLastDetectorCalibration", 771),
-    ("DateOfManufacture", 608),
-    ("DateOfSecondaryCapture", 486),
-    ("DateTime", 1981),
-    ("DeadTimeCorrected", 1183),
-    ("DeadTimeFactor", 2298),
-    ("DecayCorrected", 1180),
-    ("DecayCorrection", 2281),
-    ("DecayCorrectionDateTime", 1152),
-    ("DecayFactor", 2295),
-    ("DecimalPotentialVisualAcuity", 2134),
-    ("DecimalVisualAcuity", 2109),
-    ("DecompositionAlgorithmIdentificationSequence", 1043),
-    ("DecompositionDescription", 1042),
-    ("DecompositionMaterialSequence", 1044),
-    ("DecompositionMethod", 1041),
-    ("DecoupledNucleus", 855),
-    ("Decoupling", 854),
-    ("DecouplingChemicalShiftReference", 858),
-    ("DecouplingFrequency", 856),
-    ("DecouplingMethod", 857),
-    ("DefinitionSourceSequence", 172),
-    ("DeformableRegistrationGridSequence", 2330),
-    ("DeformableRegistrationSequence", 2328),
-    ("DegreeOfDilation", 1393),
-    ("DegreeOfFreedomID", 2442),
-    ("DegreeOfFreedomType", 2443),
-    ("DeidentificationAction", 99),
-    ("DeidentificationActionSequence", 97),
-    ("DeidentificationMethod", 305),
-    ("DeidentificationMethodCodeSequence", 306),
-    ("DelineatedRadiationFieldSize", 3848),
-    ("DeliveredChannelTotalTime", 3178),
-    ("DeliveredDepthDoseParametersSequence", 3594),
-    ("DeliveredDistalDepth", 3596),
-    ("DeliveredDistalDepthFraction", 3595),
-    ("DeliveredMeterset", 3126),
-    ("DeliveredNominalRangeModulatedRegionDepths", 3598),
-    ("DeliveredNominalRangeModulationFractions", 3597),
-    ("DeliveredNumberOfPulses", 3180),
-    ("DeliveredPrimaryMeterset", 3119),
-    ("DeliveredPulseRepetitionInterval", 3182),
-    ("DeliveredReferenceDoseDefinition", 3599),
-    ("DeliveredSecondaryMeterset", 3120),
-    ("DeliveredTreatmentTime", 3122),
-    ("DeliveryMaximumDose", 3224),
-    ("DeliveryRate", 3660),
-    ("DeliveryRateUnitSequence", 3661),
-    ("DeliveryTimeStructureCodeSequence", 3992),
-    ("DeliveryVerificationImageSequence", 2807),
-    ("DeliveryWarningDose", 3223),
-    ("DepthDoseParametersSequence", 3593),
-    ("DepthOfScanField", 718),
-    ("DepthOfTransverseImage", 1422),
-    ("DepthSpatialResolution", 1417),
-    ("DepthValueAveragingFlag", 3268),
-    ("DepthsOfFocus", 1195),
-    ("DerivationAlgorithmSequence", 1531),
-    ("DerivationCodeSequence", 220),
-    ("DerivationConceptualVolumeSequence", 3879),
-    ("DerivationDescription", 184),
-    ("DerivationImageSequence", 213),
-    ("DerivationImplantAssemblyTemplateSequence", 2837),
-    ("DerivationImplantTemplateSequence", 2404),
-    ("DestinationAE", 2947),
-    ("DetectorActivationOffsetFromExposure", 777),
-    ("DetectorActiveAreaOrientation", 1139),
-    ("DetectorActiveAreaTLHCPosition", 1138),
-    ("DetectorActiveDimensions", 782),
-    ("DetectorActiveOrigin", 783),
-    ("DetectorActiveShape", 781),
-    ("DetectorActiveTime", 776),
-    ("DetectorBinning", 778),
-    ("DetectorConditionsNominalFlag", 764),
-    ("DetectorConfiguration", 767),
-    ("DetectorDescription", 768),
-    ("DetectorElementPhysicalSize", 779),
-    ("DetectorElementSize", 2288),
-    ("DetectorElementSpacing", 780),
-    ("DetectorGeometry", 1163),
-    ("DetectorID", 770),
-    ("DetectorInformationSequence", 2232),
-    ("DetectorIsocenterPrimaryAngle", 1131),
-    ("DetectorIsocenterSecondaryAngle", 1132),
-    ("DetectorLinesOfResponseUsed", 2283),
-    ("DetectorManufacturerModelName", 785),
-    ("DetectorManufacturerName", 784),
-    ("DetectorMode", 769),
-    ("DetectorNormalizationCorrection", 1190),
-    ("DetectorPositionSequence", 1122),
-    ("DetectorPositioningType", 3022),
-    ("DetectorPrimaryAngle", 652),
-    ("DetectorSecondaryAngle", 653),
-    ("DetectorTemperature", 765),
-    ("DetectorTimeSinceLastExposure", 775),
-    ("DetectorType", 766),
-    ("DetectorVector", 2230),
-    ("DetectorXPositionToIsocenter", 1133),
-    ("DetectorYPositionToIsocenter", 1134),
-    ("DetectorZPositionToIsocenter", 1135),
-    ("DeviationIndex", 638),
-    ("DeviceAlternateIdentifier", 3886),
-    ("DeviceAlternateIdentifierFormat", 3888),
-    ("DeviceAlternateIdentifierType", 3887),
-    ("DeviceDescription", 2193),
-    ("DeviceDiameter", 2184),
-    ("DeviceDiameterUnits", 2185),
-    ("DeviceID", 476),
-    ("DeviceIndex", 3914),
-    ("DeviceLabel", 3902),
-    ("DeviceLength", 2182),
-    ("DeviceMotionControlSequence", 3585),
-    ("DeviceMotionExecutionMode", 3586),
-    ("DeviceMotionObservationMode", 3587),
-    ("DeviceMotionParameterCodeSequence", 3588),
-    ("DeviceOrderIndex", 3692),
-    ("DevicePositionParameterSequence", 2991),
-    ("DevicePositionToEquipmentMappingMatrix", 2990),
-    ("DeviceSequence", 2179),
-    ("DeviceSerialNumber", 474),
-    ("DeviceSettingDescription", 382),
-    ("DeviceSpecificAcquisitionParameterSequence", 3024),
-    ("DeviceTypeCodeSequence", 3903),
-    ("DeviceUID", 475),
-    ("DeviceVolume", 2186),
-    ("DiameterOfCircularOutline", 671),
-    ("DiameterOfVisibility", 2560),
-    ("DiaphragmPosition", 2968),
-    ("DiffuseReflectionIntensity", 2638),
-    ("DiffusionAcquisitionCodeSequence", 2398),
-    ("DiffusionAnisotropyType", 903),
-    ("DiffusionBMatrixSequence", 1141),
-    ("DiffusionBValue", 878),
-    ("DiffusionBValueXX", 1142),
-    ("DiffusionBValueXY", 1143),
-    ("DiffusionBValueXZ", 1144),
-    ("DiffusionBValueYY", 1145),
-    ("DiffusionBValueYZ", 1146),
-    ("DiffusionBValueZZ", 1147),
-    ("DiffusionDirectionality", 867),
-    ("DiffusionGradientDirectionSequence", 868),
-    ("DiffusionGradientOrientation", 879),
-    ("DiffusionModelCodeSequence", 2399),
-    ("DigitalFilterCharacteristicsSequence", 1867),
-    ("DigitalFilterOrder", 1868),
-    ("DigitalFilterTypeCodeSequence", 1869),
-    ("DigitalImageFormatAcquired", 493),
-    ("DigitalSignatureDateTime", 2921),
-    ("DigitalSignaturePurposeCodeSequence", 2927),
-    ("DigitalSignatureUID", 2920),
-    ("DigitalSignaturesSequence", 4039),
-    ("DigitalZoomRatio", 375),
-    ("DigitizingDeviceTransportDirection", 693),
-    ("DimensionDescriptionLabel", 1374),
-    ("DimensionIndexPointer", 1335),
-    ("DimensionIndexPrivateCreator", 1340),
-    ("DimensionIndexSequence", 1342),
-    ("DimensionIndexValues", 1329),
-    ("DimensionOrganizationSequence", 1341),
-    ("DimensionOrganizationType", 1371),
-    ("DimensionOrganizationUID", 1334),
-    ("DirectSegmentReferenceSequence", 3893),
-    ("DirectoryRecordSequence", 6),
-    ("DirectoryRecordType", 10),
-    ("DisplacementMatrix", 3802),
-    ("DisplacementReferenceLabel", 3801),
-    ("DisplacementReferenceLocationCodeSequence", 3804),
-    ("DisplayEnvironmentSpatialPosition", 2736),
-    ("DisplayFilterPercentage", 1767),
-    ("DisplaySetHorizontalJustification", 2793),
-    ("DisplaySetLabel", 2742),
-    ("DisplaySetNumber", 2741),
-    ("DisplaySetPatientOrientation", 2784),
-    ("DisplaySetPresentationGroup", 2743),
-    ("DisplaySetPresentationGroupDescription", 2744),
-    ("DisplaySetScrollingGroup", 2747),
-    ("DisplaySetVerticalJustification", 2794),
-    ("DisplaySetsSequence", 2740),
-    ("DisplayShadingFlag", 1845),
-    ("DisplayWindowLabelVector", 691),
-    ("DisplayedAreaBottomRightHandCorner", 2512),
-    ("DisplayedAreaSelectionSequence", 2513),
-    ("DisplayedAreaTopLeftHandCorner", 2511),
-    ("DisplayedZValue", 700),
-    ("DistalDepth", 3590),
-    ("DistalDepthFraction", 3589),
-    ("DistanceBetweenBscanSlabs", 1544),
-    ("DistanceBetweenFocalPlanes", 2154),
-    ("DistanceObjectToTableTop", 1050),
-    ("DistancePupillaryDistance", 2073),
-    ("DistanceReceptorPlaneToDetectorHousing", 1062),
-    ("DistanceSourceToDataCollectionCenter", 998),
-    ("DistanceSourceToDetector", 540),
-    ("DistanceSourceToEntrance", 1901),
-    ("DistanceSourceToIsocenter", 1049),
-    ("DistanceSourceToPatient", 541),
-    ("DistributionType", 313),
-    ("DocumentClassCodeSequence", 2027),
-    ("DocumentTitle", 2038),
-    ("DopplerCorrectionAngle", 742),
-    ("DopplerSampleVolumeXPosition", 744),
-    ("DopplerSampleVolumeYPosition", 745),
-    ("DoseCalibrationConditionsSequence", 3846),
-    ("DoseCalibrationConditionsVerifiedFlag", 3849),
-    ("DoseCalibrationFactor", 2296),
-    ("DoseComment", 3033),
-    ("DoseGridScaling", 3037),
-    ("DoseMeasurementDeviceCodeSequence", 3776),
-    ("DoseModeName", 588),
-    ("DoseRateDelivered", 3130),
-    ("DoseRateSet", 3352),
-    ("DoseReferenceDescription", 3218),
-    ("DoseReferenceNumber", 3214),
-    ("DoseReferencePointCoordinates", 3219),
-    ("DoseReferenceSequence", 3213),
-    ("DoseReferenceStructureType", 3216),
-    ("DoseReferenceType", 3221),
-    ("DoseReferenceUID", 3215),
-    ("DoseSummationType", 3035),
-    ("DoseType", 3031),
-    ("DoseUnits", 3030),
-    ("DoseValueInterpretation", 3737),
-    ("DoseValuePurpose", 3630),
-    ("DoseValuesSequence", 3629),
-    ("DosimetricObjectiveEvaluationScope", 3956),
-    ("DosimetricObjectiveParameterSequence", 3969),
-    ("DosimetricObjectivePurpose", 3973),
-    ("DosimetricObjectiveSequence", 3965),
-    ("DosimetricObjectiveTypeCodeSequence", 3966),
-    ("DosimetricObjectiveUID", 3967),
-    ("DosimetricObjectiveWeight", 3972),
-    ("DoubleExposureFieldDelta", 2816),
-    ("DoubleExposureFlag", 2809),
-    ("DoubleExposureMeterset", 2815),
-    ("DoubleExposureOrdering", 2810),
-    ("DoubleFloatPixelData", 4037),
-    ("DoubleFloatPixelPaddingRangeLimit", 1679),
-    ("DoubleFloatPixelPaddingValue", 1677),
-    ("DoubleFloatRealWorldValueFirstValueMapped", 1958),
-    ("DoubleFloatRealWorldValueLastValueMapped", 1957),
-    ("DoublePointCoordinatesData", 2362),
-    ("EXIFVersion", 348),
-    ("EchoNumbers", 465),
-    ("EchoPeakPosition", 965),
-    ("EchoPlanarPulseSequence", 821),
-    ("EchoPulseSequence", 812),
-    ("EchoTime", 460),
-    ("EchoTrainLength", 470),
-    ("EffectiveBinEnergy", 1025),
-    ("EffectiveDateTime", 2406),
-    ("EffectiveDoseCalculationMethodCategoryCodeSequence", 3862),
-    ("EffectiveDoseCalculationMethodCodeSequence", 3863),
-    ("EffectiveDoseCalculationMethodDescription", 3864),
-    ("EffectiveDuration", 455),
-    ("EffectiveEchoTime", 874),
-    ("EffectiveRefractiveIndex", 2198),
-    ("EffectiveWedgeAngle", 3308),
-    ("EmitterColorTemperature", 423),
-    ("EmmetropicMagnification", 1389),
-    ("EmptyImageBoxCIELabValue", 2768),
-    ("EmptyValueMatchingSequence", 127),
-    ("EncapsulatedDocument", 2039),
-    ("EncapsulatedDocumentLength", 2043),
-    ("EncapsulatedPixelDataValueTotalLength", 4035),
-    ("EncryptedAttributesSequence", 2931),
-    ("EncryptedContent", 2933),
-    ("EncryptedContentTransferSyntaxUID", 2932),
-    ("EndAcquisitionDateTime", 1111),
-    ("EndCumulativeMetersetWeight", 3811),
-    ("EndCumulativeTimeWeight", 2824),
-    ("EndMeterset", 3151),
-    ("EndingRespiratoryAmplitude", 1349),
-    ("EndingRespiratoryPhase", 1350),
-    ("EnergyDerivationCodeSequence", 3026),
-    ("EnergyUnitCodeSequence", 3730),
-    ("EnergyWeightingFactor", 1010),
-    ("EnergyWindowInformationSequence", 2223),
-    ("EnergyWindowLowerLimit", 2225),
-    ("EnergyWindowName", 2229),
-    ("EnergyWindowNumber", 2267),
-    ("EnergyWindowRangeSequence", 2224),
-    ("EnergyWindowUpperLimit", 2226),
-    ("EnergyWindowVector", 2221),
-    ("EnhancedPaletteColorLookupTableSequence", 1726),
-    ("EnhancedRTBeamLimitingDeviceDefinitionFlag", 3159),
-    ("EnhancedRTBeamLimitingDeviceSequence", 3157),
-    ("EnhancedRTBeamLimitingOpeningSequence", 3158),
-    ("EntityDescription", 3912),
-    ("EntityLabel", 3910),
-    ("EntityLongLabel", 3913),
-    ("EntityName", 3911),
-    ("EntranceDose", 1899),
-    ("EntranceDoseDerivation", 1949),
-    ("EntranceDoseInmGy", 1948),
-    ("EquipmentCoordinateSystemIdentification", 1785),
-    ("EquipmentFrameOfReferenceDescription", 3716),
-    ("EquipmentFrameOfReferenceUID", 3715),
-    ("EquipmentModality", 90),
-    ("EquipmentReferencePointCodeSequence", 3718),
-    ("EquipmentReferencePointCoordinatesSequence", 3717),
-    ("EquivalentCodeSequence", 84),
-    ("EquivalentConceptualVolumeInstanceReferenceSequence", 3868),
-    ("EquivalentConceptualVolumesSequence", 3869),
-    ("EquivalentPupilRadius", 2118),
-    ("EstimatedRadiographicMagnificationFactor", 542),
-    ("EthicsCommitteeApprovalEffectivenessEndDate", 316),
-    ("EthicsCommitteeApprovalEffectivenessStartDate", 315),
-    ("EthnicGroup", 265),
-    ("EventCodeSequence", 197),
-    ("EventElapsedTimes", 193),
-    ("EventTimeOffset", 196),
-    ("EventTimerNames", 194),
-    ("EventTimerSequence", 195),
-    ("ExaminedBodyThickness", 284),
-    ("ExcessiveFalseNegatives", 1576),
-    ("ExcessiveFalseNegativesDataFlag", 1575),
-    ("ExcessiveFalsePositives", 1586),
-    ("ExcessiveFalsePositivesDataFlag", 1585),
-    ("ExcessiveFixationLosses", 1568),
-    ("ExcessiveFixationLossesDataFlag", 1567),
-    ("ExcitationEnergy", 1211),
-    ("ExcitationPulseDuration", 1212),
-    ("ExcitationSpectralWidth", 1210),
-    ("ExcitationWavelength", 1214),
-    ("ExcitationWavelengthSequence", 1213),
-    ("ExcludedIntervalsSequence", 1196),
-    ("ExclusionDuration", 1198),
-    ("ExclusionStartDateTime", 1197),
-    ("ExclusiveComponentType", 2843),
-    ("ExpectedInVivoMeasurementValueIndex", 3635),
-    ("ExpectedInVivoMeasurementValuesSequence", 3634),
-    ("ExpirationDateTime", 129),
-    ("ExposedArea", 1900),
-    ("Exposure", 562),
-    ("ExposureBiasValue", 352),
-    ("ExposureControlMode", 803),
-    ("ExposureControlModeDescription", 804),
-    ("ExposureControlSensingRegionLeftVerticalEdge", 1071),
-    ("ExposureControlSensingRegionLowerHorizontalEdge", 1074),
-    ("ExposureControlSensingRegionRightVerticalEdge", 1072),
-    ("ExposureControlSensingRegionShape", 1070),
-    ("ExposureControlSensingRegionUpperHorizontalEdge", 1073),
-    ("ExposureControlSensingRegionsSequence", 1069),
-    ("ExposureIndex", 636),
-    ("ExposureInmAs", 995),
-    ("ExposureInuAs", 563),
-    ("ExposureMode", 373),
-    ("ExposureModulationType", 988),
-    ("ExposureProgram", 338),
-    ("ExposureSequence", 2966),
-    ("ExposureStatus", 805),
-    ("ExposureTime", 560),
-    ("ExposureTimeInSeconds", 320),
-    ("ExposureTimeInms", 992),
-    ("ExposureTimeInuS", 807),
-    ("ExposuresOnDetectorSinceLastCalibration", 773),
-    ("ExposuresOnDetectorSinceManufactured", 774),
-    ("ExposuresOnPlate", 634),
-    ("ExtendedDepthOfField", 2152),
-    ("ExtendedMatchingMechanisms", 124),
-    ("ExtendedOffsetTable", 4033),
-    ("ExtendedOffsetTableLengths", 4034),
-    ("ExternalContourEntryPoint", 3373),
-    ("FNumber", 321),
-    ("FacetSequence", 2375),
-    ("FalseNegativesEstimate", 1572),
-    ("FalseNegativesEstimateFlag", 1571),
-    ("FalseNegativesQuantity", 1574),
-    ("FalsePositivesEstimate", 1578),
-    ("FalsePositivesEstimateFlag", 1577),
-    ("FalsePositivesQuantity", 1584),
-    ("FiducialDescription", 2580),
-    ("FiducialIdentifier", 2581),
-    ("FiducialIdentifierCodeSequence", 2582),
-    ("FiducialSequence", 2590),
-    ("FiducialSetSequence", 2589),
-    ("FiducialUID", 2587),
-    ("FiducialsPropertyCategoryCodeSequence", 2591),
-    ("FieldOfViewDescription", 1068),
-    ("FieldOfViewDimensions", 559),
-    ("FieldOfViewDimensionsInFloat", 1085),
-    ("FieldOfViewHorizontalFlip", 788),
-    ("FieldOfViewOrigin", 786),
-    ("FieldOfViewRotation", 787),
-    ("FieldOfViewSequence", 1067),
-    ("FieldOfViewShape", 558),
-    ("FileAccessSequence", 131),
-    ("FileAccessURI", 118),
-    ("FileLengthInContainer", 122),
-    ("FileMetaInformationGroupLength", 4040),
-    ("FileMetaInformationVersion", 4041),
-    ("FileOffsetInContainer", 121),
-    ("FileSetAccessSequence", 130),
-    ("FileSetConsistencyFlag", 5),
-    ("FileSetDescriptorFileID", 1),
-    ("FileSetID", 0),
-    ("FileSource", 370),
-    ("FilenameInContainer", 120),
-    ("FillMode", 2557),
-    ("FillPattern", 2556),
-    ("FillStyleSequence", 2539),
-    ("FillerOrderNumberImagingServiceRequest", 1942),
-    ("FilterBeamPathLengthMaximum", 802),
-    ("FilterBeamPathLengthMinimum", 801),
-    ("FilterByAttributePresence", 2765),
-    ("FilterByCategory", 2764),
-    ("FilterByOperator", 2766),
-    ("FilterHighFrequency", 1835),
-    ("FilterHighFrequencyCharacteristicsSequence", 1860),
-    ("FilterLookupTableData", 1875),
-    ("FilterLookupTableDescription", 1872),
-    ("FilterLookupTableSequence", 1871),
-    ("FilterLowFrequency", 1834),
-    ("FilterLowFrequencyCharacteristicsSequence", 1859),
-    ("FilterMaterial", 798),
-    ("FilterOperationsSequence", 2763),
-    ("FilterThicknessMaximum", 800),
-    ("FilterThicknessMinimum", 799),
-    ("FilterType", 569),
-    ("FinalCumulativeMetersetWeight", 3347),
-    ("FinalCumulativeTimeWeight", 3481),
-    ("FiniteVolume", 2345),
-    ("FirstALineLocation", 2216),
-    ("FirstOrderPhaseCorrection", 928),
-    ("FirstOrderPhaseCorrectionAngle", 4015),
-    ("FirstTreatmentDate", 3133),
-    ("FixationCheckedQuantity", 1563),
-    ("FixationDeviceDescription", 3396),
-    ("FixationDeviceLabel", 3395),
-    ("FixationDevicePitchAngle", 3398),
-    ("FixationDevicePosition", 3397),
-    ("FixationDeviceRollAngle", 3399),
-    ("FixationDeviceSequence", 3393),
-    ("FixationDeviceType", 3394),
-    ("FixationEye", 3383),
-    ("FixationLightAzimuthalAngle", 3530),
-    ("FixationLightAzimuthalAngleTolerance", 3387),
-    ("FixationLightPolarAngle", 3531),
-    ("FixationLightPolarAngleTolerance", 3388),
-    ("FixationMethodCodeSequence", 2435),
-    ("FixationMonitoringCodeSequence", 1561),
-    ("FixationSequence", 1560),
-    ("FixedRTBeamDelimiterDeviceSequence", 3669),
-    ("FlashEnergy", 366),
-    ("FlashFiringStatus", 333),
-    ("FlashFunctionPresent", 336),
-    ("FlashMode", 335),
-    ("FlashRedEyeMode", 337),
-    ("FlashReturnStatus", 334),
-    ("FlatCornealAxisSequence", 2097),
-    ("FlatKeratometricAxisSequence", 2083),
-    ("FlipAngle", 625),
-    ("FloatPixelData", 4036),
-    ("FloatPixelPaddingRangeLimit", 1678),
-    ("FloatPixelPaddingValue", 1676),
-    ("FloatingPointValue", 1991),
-    ("FloatingPointValues", 2394),
-    ("FlowCompensation", 814),
-    ("FlowCompensationDirection", 923),
-    ("FlowIdentifier", 1793),
-    ("FlowIdentifierSequence", 1792),
-    ("FlowRTPSamplingRate", 1795),
-    ("FlowTransferSyntaxUID", 1794),
-    ("FluenceDataScale", 2971),
-    ("FluenceDataSource", 2970),
-    ("FluenceMapSequence", 2969),
-    ("FluenceMode", 2973),
-    ("FluenceModeID", 2974),
-    ("FluoroscopyFlag", 997),
-    ("FluoroscopyLastImageHoldPersistenceFlag", 591),
-    ("FluoroscopyPersistenceFlag", 590),
-    ("FocalDistance", 577),
-    ("FocalLength", 357),
-    ("FocalLengthIn35mmFilm", 376),
-    ("FocalSpots", 580),
-    ("FocusDepth", 710),
-    ("FocusMethod", 2151),
-    ("FolderAccessURI", 117),
-    ("FontName", 2533),
-    ("FontNameType", 2534),
-    ("FovealPointNormativeDataFlag", 1634),
-    ("FovealPointProbabilityValue", 1635),
-    ("FovealSensitivity", 1609),
-    ("FovealSensitivityMeasured", 1608),
-    ("FractionBasedRelationshipIntervalAnchor", 3987),
-    ("FractionBasedRelationshipSequence", 3986),
-    ("FractionGroupDescription", 3251),
-    ("FractionGroupNumber", 3250),
-    ("FractionGroupSequence", 3249),
-    ("FractionGroupSummarySequence", 3197),
-    ("FractionGroupType", 3199),
-    ("FractionNumber", 2965),
-    ("FractionPattern", 3255),
-    ("FractionPatternSequence", 3977),
-    ("FractionStatusSummarySequence", 3201),
-    ("FractionalBandwidth", 1221),
-    ("FractionalChannelDisplayScale", 1846),
-    ("FractionationNotes", 3983),
-    ("FrameAcquisitionDateTime", 866),
-    ("FrameAcquisitionDuration", 935),
-    ("FrameAcquisitionNumber", 1328),
-    ("FrameAcquisitionSequence", 1057),
-    ("FrameAnatomySequence", 1319),
-    ("FrameComments", 1330),
-    ("FrameContentSequence", 1321),
-    ("FrameDelay", 512),
-    ("FrameDetectorParametersSequence", 1080),
-    ("FrameDimensionPointer", 1657),
-    ("FrameDisplaySequence", 223),
-    ("FrameDisplayShutterSequence", 1096),
-    ("FrameExtractionSequence", 177),
-    ("FrameIncrementPointer", 1656),
-    ("FrameLabel", 1376),
-    ("FrameLabelVector", 687),
-    ("FrameLaterality", 1320),
-    ("FrameNumbersOfInterest", 1746),
-    ("FrameOfInterestDescription", 1747),
-    ("FrameOfInterestType", 1748),
-    ("FrameOfReferenceToDisplayedCoordinateSystemTransformationMatrix", 2577),
-    ("FrameOfReferenceTransformationComment", 3101),
-    ("FrameOfReferenceTransformationMatrix", 3100),
-    ("FrameOfReferenceTransformationMatrixType", 2578),
-    ("FrameOfReferenceUID", 1302),
-    ("FrameOriginTimestamp", 1797),
-    ("FramePixelDataPropertiesSequence", 1771),
-    ("FramePixelShiftSequence", 1768),
-    ("FramePrimaryAngleVector", 688),
-    ("FrameReferenceDateTime", 904),
-    ("FrameReferenceTime", 2291),
-    ("FrameSecondaryAngleVector", 689),
-    ("FrameTime", 509),
-    ("FrameTimeVector", 511),
-    ("FrameType", 209),
-    ("FrameUsefulnessGroupSequence", 1799),
-    ("FrameVOILUTSequence", 1764),
-    ("FrequencyCorrection", 888),
-    ("FrequencyEncodingCodeSequence", 1873),
-    ("FunctionalGroupPointer", 1336),
-    ("FunctionalGroupPrivateCreator", 1344),
-    ("FunctionalMRSequence", 1148),
-    ("FunctionalSettlingPhaseFramesPresent", 1149),
-    ("FunctionalSyncPulse", 1150),
-    ("GPSAltitude", 397),
-    ("GPSAltitudeRef", 396),
-    ("GPSAreaInformation", 419),
-    ("GPSDOP", 402),
-    ("GPSDateStamp", 420),
-    ("GPSDestBearing", 415),
-    ("GPSDestBearingRef", 414),
-    ("GPSDestDistance", 417),
-    ("GPSDestDistanceRef", 416),
-    ("GPSDestLatitude", 411),
-    ("GPSDestLatitudeRef", 410),
-    ("GPSDestLongitude", 413),
-    ("GPSDestLongitudeRef", 412),
-    ("GPSDifferential", 421),
-    ("GPSImgDirection", 408),
-    ("GPSImgDirectionRef", 407),
-    ("GPSLatitude", 393),
-    ("GPSLatitudeRef", 392),
-    ("GPSLongitude", 395),
-    ("GPSLongitudeRef", 394),
-    ("GPSMapDatum", 409),
-    ("GPSMeasureMode", 401),
-    ("GPSProcessingMethod", 418),
-    ("GPSSatellites", 399),
-    ("GPSSpeed", 404),
-    ("GPSSpeedRef", 403),
-    ("GPSStatus", 400),
-    ("GPSTimeStamp", 398),
-    ("GPSTrack", 406),
-    ("GPSTrackRef", 405),
-    ("GPSVersionID", 391),
-    ("GainControl", 378),
-    ("GantryAngle", 3357),
-    ("GantryAngleTolerance", 3236),
-    ("GantryDetectorSlew", 544),
-    ("GantryDetectorTilt", 543),
-    ("GantryID", 481),
-    ("GantryMotionCorrected", 1184),
-    ("GantryPitchAngle", 3380),
-    ("GantryPitchAngleTolerance", 3382),
-    ("GantryPitchRotationDirection", 3381),
-    ("GantryRotationDirection", 3358),
-    ("GapLength", 2559),
-    ("GatedInformationSequence", 2246),
-    ("GatingBeamHoldTransitionSequence", 3851),
-    ("GeneralAccessoryDefinitionSequence", 3711),
-    ("GeneralAccessoryDescription", 3570),
-    ("GeneralAccessoryID", 3569),
-    ("GeneralAccessoryNumber", 3572),
-    ("GeneralAccessorySequence", 3568),
-    ("GeneralAccessoryType", 3571),
-    ("GeneralMatchingSequence", 128),
-    ("GeneralizedDefectCorrectedSensitivityDeviationFlag", 1622),
-    ("GeneralizedDefectCorrectedSensitivityDeviationProbabilityValue", 1624),
-    ("GeneralizedDefectCorrectedSensitivityDeviationValue", 1623),
-    ("GeneralizedDefectSensitivityDeviationAlgorithmSequence", 1591),
-    ("GeneratorID", 478),
-    ("GeneratorPower", 574),
-    ("GeneticModificationsCodeSequence", 252),
-    ("GeneticModificationsDescription", 250),
-    ("GeneticModificationsNomenclature", 251),
-    ("GeneticModificationsSequence", 249),
-    ("GeometricMaximumDistortion", 1773),
-    ("GeometricalProperties", 1772),
-    ("GeometryForDisplay", 2666),
-    ("GeometryOfKSpaceTraversal", 833),
-    ("GlobalCrop", 2609),
-    ("GlobalCroppingSpecificationIndex", 2610),
-    ("GlobalDeviationFromNormal", 1590),
-    ("GlobalDeviationProbability", 1595),
-    ("GlobalDeviationProbabilityNormalsFlag", 1583),
-    ("GlobalDeviationProbabilitySequence", 1606),
-    ("GradientEchoTrainLength", 944),
-    ("GradientOutput", 922),
-    ("GradientOutputType", 920),
-    ("GraphicAnnotationSequence", 2491),
-    ("GraphicAnnotationUnits", 2495),
-    ("GraphicCoordinatesDataSequence", 2586),
-    ("GraphicData", 2506),
-    ("GraphicDimensions", 2504),
-    ("GraphicFilled", 2508),
-    ("GraphicGroupDescription", 2530),
-    ("GraphicGroupID", 2572),
-    ("GraphicGroupLabel", 2529),
-    ("GraphicGroupSequence", 2540),
-    ("GraphicLayer", 2492),
-    ("GraphicLayerDescription", 2517),
-    ("GraphicLayerOrder", 2515),
-    ("GraphicLayerRecommendedDisplayCIELabValue", 2592),
-    ("GraphicLayerRecommendedDisplayGrayscaleValue", 2516),
-    ("GraphicLayerSequence", 2514),
-    ("GraphicObjectSequence", 2498),
-    ("GraphicType", 2507),
-    ("GreenPaletteColorLookupTableData", 1703),
-    ("GreenPaletteColorLookupTableDescriptor", 1698),
-    ("Grid", 573),
-    ("GridAbsorbingMaterial", 791),
-    ("GridAspectRatio", 795),
-    ("GridDimensions", 2331),
-    ("GridFocalDistance", 797),
-    ("GridFrameOffsetVector", 3036),
-    ("GridID", 479),
-    ("GridPeriod", 796),
-    ("GridPitch", 794),
-    ("GridResolution", 2332),
-    ("GridSpacingMaterial", 792),
-    ("GridThickness", 793),
-    ("GroupOfPatientsIdentificationSequence", 232),
-    ("HL7InstanceIdentifier", 2026),
-    ("HL7StructuredDocumentReferenceSequence", 2007),
-    ("HPGLContourPenNumber", 2421),
-    ("HPGLDocument", 2420),
-    ("HPGLDocumentID", 2415),
-    ("HPGLDocumentLabel", 2416),
-    ("HPGLDocumentScaling", 2419),
-    ("HPGLDocumentSequence", 2414),
-    ("HPGLPenDescription", 2425),
-    ("HPGLPenLabel", 2424),
-    ("HPGLPenNumber", 2423),
-    ("HPGLPenSequence", 2422),
-    ("HalfValueLayer", 1904),
-    ("HangingProtocolCreationDateTime", 2675),
-    ("HangingProtocolCreator", 2674),
-    ("HangingProtocolDefinitionSequence", 2676),
-    ("HangingProtocolDescription", 2672),
-    ("HangingProtocolLevel", 2673),
-    ("HangingProtocolName", 2671),
-    ("HangingProtocolUserGroupName", 2678),
-    ("HangingProtocolUserIdentificationCodeSequence", 2677),
-    ("HeadFixationAngle", 3379),
-    ("HeadFixationAngleTolerance", 3385),
-    ("HeartRate", 536),
-    ("HighBit", 1668),
-    ("HighDoseTechniqueType", 3289),
-    ("HighRRValue", 531),
-    ("HistogramBinWidth", 2304),
-    ("HistogramData", 2306),
-    ("HistogramExplanation", 2305),
-    ("HistogramFirstBinValue", 2302),
-    ("HistogramLastBinValue", 2303),
-    ("HistogramNumberOfBins", 2301),
-    ("HistogramSequence", 2300),
-    ("HomeCommunityID", 2037),
-    ("HorizontalAlignment", 2542),
-    ("HorizontalFieldOfView", 1391),
-    ("HorizontalPrismBase", 2062),
-    ("HorizontalPrismPower", 2061),
-    ("Humidity", 361),
-    ("ICCProfile", 1732),
-    ("IOLFormulaCodeSequence", 1439),
-    ("IOLFormulaDetail", 1440),
-    ("IOLManufacturer", 1463),
-    ("IOLPower", 1456),
-    ("IOLPowerForExactEmmetropia", 1469),
-    ("IOLPowerForExactTargetRefraction", 1470),
-    ("IOLPowerSequence", 1461),
-    ("ISOSpeed", 345),
-    ("ISOSpeedLatitudeyyy", 346),
-    ("ISOSpeedLatitudezzz", 347),
-    ("IVUSAcquisition", 701),
-    ("IVUSGatedRate", 703),
-    ("IVUSPullbackRate", 702),
-    ("IVUSPullbackStartFrameNumber", 704),
-    ("IVUSPullbackStopFrameNumber", 705),
-    ("IconImageSequence", 2911),
-    ("IdenticalDocumentsSequence", 2013),
-    ("IdentifierTypeCode", 1885),
-    ("IdentifyingPrivateElements", 98),
-    ("Illumination", 2943),
-    ("IlluminationBandwidth", 1429),
-    ("IlluminationColorCodeSequence", 2161),
-    ("IlluminationPower", 1428),
-    ("IlluminationTranslationFlag", 1215),
-    ("IlluminationTypeCodeSequence", 1401),
-    ("IlluminationWaveLength", 1427),
-    ("IlluminatorTypeCodeSequence", 2156),
-    ("ImageAcquisitionDepth", 2169),
-    ("ImageAndFluoroscopyAreaDoseProduct", 568),
-    ("ImageBoxLargeScrollAmount", 2760),
-    ("ImageBoxLargeScrollType", 2759),
-    ("ImageBoxLayoutType", 2753),
-    ("ImageBoxNumber", 2752),
-    ("ImageBoxOverlapPriority", 2761),
-    ("ImageBoxScrollDirection", 2756),
-    ("ImageBoxSmallScrollAmount", 2758),
-    ("ImageBoxSmallScrollType", 2757),
-    ("ImageBoxSynchronizationSequence", 2772),
-    ("ImageBoxTileHorizontalDimension", 2754),
-    ("ImageBoxTileVerticalDimension", 2755),
-    ("ImageBoxesSequence", 2751),
-    ("ImageCenterPointCoordinatesSequence", 1928),
-    ("ImageComments", 1316),
-    ("ImageDataTypeCodeSequence", 1229),
-    ("ImageDataTypeSequence", 1200),
-    ("ImageFilter", 985),
-    ("ImageFilterDescription", 1282),
-    ("ImageFilterDetailsSequence", 602),
-    ("ImageFrameOrigin", 4024),
-    ("ImageHorizontalFlip", 2509),
-    ("ImageID", 2268),
-    ("ImageIndex", 2299),
-    ("ImageLaterality", 1304),
-    ("ImageOrientationPatient", 1301),
-    ("ImageOrientationSlide", 2157),
-    ("ImageOrientationVolume", 1360),
-    ("ImagePathFilterPassBand", 1383),
-    ("ImagePathFilterPassThroughWavelength", 1382),
-    ("ImagePathFilterTypeStackCodeSequence", 1403),
-    ("ImagePlanePixelSpacing", 2958),
-    ("ImagePositionPatient", 1300),
-    ("ImagePositionVolume", 1359),
-    ("ImageProcessingApplied", 1774),
-    ("ImageReceptorPositionSequence", 2989),
-    ("ImageRotation", 2510),
-    ("ImageSetLabel", 2693),
-    ("ImageSetNumber", 2687),
-    ("ImageSetSelectorCategory", 2688),
-    ("ImageSetSelectorSequence", 2682),
-    ("ImageSetSelectorUsageFlag", 2683),
-    ("ImageSetsSequence", 2681),
-    ("ImageToEquipmentMappingMatrix", 1784),
-    ("ImageTriggerDelay", 513),
-    ("ImageType", 20),
-    ("ImagedNucleus", 464),
-    ("ImagedVolumeDepth", 2146),
-    ("ImagedVolumeHeight", 2145),
-    ("ImagedVolumeWidth", 2144),
-    ("ImagerPixelSpacing", 572),
-    ("ImagesInAcquisition", 1310),
-    ("ImagingApertureSequence", 2995),
-    ("ImagingApertureSpecificationType", 2996),
-    ("ImagingDeviceLocationMatrixSequence", 2993),
-    ("ImagingDeviceLocationParameterSequence", 2994),
-    ("ImagingDeviceSpecificAcquisitionParameters", 3292),
-    ("ImagingFrequency", 463),
-    ("ImagingSourceLocationSpecificationType", 2992),
-    ("ImagingSourcePositionSequence", 2988),
-    ("ImagingSourceToBeamModifierDefinitionPlaneDistance", 3020),
-    ("ImmersionMedia", 425),
-    ("ImpedanceMeasurementCurrentType", 1857),
-    ("ImpedanceMeasurementDateTime", 1855),
-    ("ImpedanceMeasurementFrequency", 1856),
-    ("ImpedanceValue", 1854),
-    ("ImplantAssemblyTemplateIssuer", 2832),
-    ("ImplantAssemblyTemplateName", 2831),
-    ("ImplantAssemblyTemplateTargetAnatomySequence", 2838),
-    ("ImplantAssemblyTemplateType", 2835),
-    ("ImplantAssemblyTemplateVersion", 2833),
-    ("ImplantName", 1464),
-    ("ImplantPartNumber", 1466),
-    ("ImplantRegulatoryDisapprovalCodeSequence", 2412),
-    ("ImplantSize", 2400),
-    ("ImplantTargetAnatomySequence", 2407),
-    ("ImplantTemplate3DModelSurfaceNumber", 2428),
-    ("ImplantTemplateGroupDescription", 2855),
-    ("ImplantTemplateGroupIssuer", 2856),
-    ("ImplantTemplateGroupMemberID", 2861),
-    ("ImplantTemplateGroupMemberMatching2DCoordinatesSequence", 2864),
-    ("ImplantTemplateGroupMembersSequence", 2860),
-    ("ImplantTemplateGroupName", 2854),
-    ("ImplantTemplateGroupTargetAnatomySequence", 2859),
-    ("ImplantTemplateGroupVariationDimensionName", 2868),
-    ("ImplantTemplateGroupVariationDimensionRank", 2871),
-    ("ImplantTemplateGroupVariationDimensionRankSequence", 2869),
-    ("ImplantTemplateGroupVariationDimensionSequence", 2867),
-    ("ImplantTemplateGroupVersion", 2857),
-    ("ImplantTemplateVersion", 2401),
-    ("ImplantType", 2403),
-    ("ImplantTypeCodeSequence", 2434),
-    ("ImplementationClassUID", 4045),
-    ("ImplementationVersionName", 4046),
-    ("InConcatenationNumber", 1332),
-    ("InConcatenationTotalNumber", 1333),
-    ("InPlanePhaseEncodingDirection", 624),
-    ("InStackPositionNumber", 1318),
-    ("IncludesImagingSubject", 1798),
-    ("IncludesInformation", 1802),
-    ("IncorporatedInventoryInstanceSequence", 135),
-    ("IndexNormalsFlag", 1648),
-    ("IndexProbability", 1649),
-    ("IndexProbabilitySequence", 1650),
-    ("InformationFromManufacturerSequence", 2408),
-    ("InformationIssueDateTime", 2410),
-    ("InformationSummary", 2411),
-    ("InitialCineRunState", 450),
-    ("InputSequencePositionIndex", 2603),
-    ("InstanceAvailability", 47),
-    ("InstanceCoercionDateTime", 24),
-    ("InstanceCreationDate", 21),
-    ("InstanceCreationTime", 22),
-    ("InstanceCreatorUID", 23),
-    ("InstanceLevelReferencedPerformedProcedureStepSequence", 3925),
-    ("InstanceNumber", 1297),
-    ("InstanceOriginStatus", 2942),
-    ("InstitutionAddress", 55),
-    ("InstitutionCodeSequence", 56),
-    ("InstitutionName", 54),
-    ("InstitutionalDepartmentName", 147),
-    ("InstitutionalDepartmentTypeCodeSequence", 148),
-    ("InstructionDescription", 1253),
-    ("InstructionIndex", 1251),
-    ("InstructionPerformanceComment", 1256),
-    ("InstructionPerformedDateTime", 1255),
-    ("InstructionPerformedFlag", 1254),
-    ("InstructionSequence", 1250),
-    ("InstructionText", 1252),
-    ("IntendedDeliveryDuration", 3982),
-    ("IntendedFractionStartTime", 3989),
-    ("IntendedNumberOfFractions", 3653),
-    ("IntendedPhaseEndDate", 3934),
-    ("IntendedPhaseStartDate", 3933),
-    ("IntendedRTTreatmentPhaseSequence", 3932),
-    ("IntendedStartDayOfWeek", 3990),
-    ("IntensifierActiveDimensions", 1064),
-    ("IntensifierActiveShape", 1063),
-    ("IntensifierSize", 571),
-    ("InterMarkerDistance", 2187),
-    ("InterlockCodeSequence", 3768),
-    ("InterlockDateTime", 3765),
-    ("InterlockDescription", 3766),
-    ("InterlockOriginDescription", 3778),
-    ("InterlockOriginatingDeviceSequence", 3767),
-    ("InterlockResolutionCodeSequence", 3769),
-    ("InterlockResolutionUserSequence", 3770),
-    ("InterlockSequence", 3764),
-    ("IntermediatePupillaryDistance", 2075),
-    ("InteroperabilityIndex", 389),
-    ("InteroperabilityVersion", 390),
-    ("InterpolationType", 2219),
-    ("IntervalsAcquired", 532),
-    ("IntervalsRejected", 533),
-    ("InterventionDescription", 448),
-    ("InterventionDrugCodeSequence", 441),
-    ("InterventionDrugDose", 440),
-    ("InterventionDrugInformationSequence", 438),
-    ("InterventionDrugName", 444),
-    ("InterventionDrugStartTime", 445),
-    ("InterventionDrugStopTime", 439),
-    ("InterventionSequence", 446),
-    ("InterventionStatus", 447),
-    ("IntraOcularPressure", 1390),
-    ("IntraocularLensCalculationsLeftEyeSequence", 1499),
-    ("IntraocularLensCalculationsRightEyeSequence", 1498),
-    ("IntravascularFrameContentSequence", 2210),
-    ("IntravascularLongitudinalDistance", 2211),
-    ("IntravascularOCTFrameContentSequence", 2212),
-    ("IntravascularOCTFrameTypeSequence", 2208),
-    ("InventoriedInstancesSequence", 138),
-    ("InventoriedSeriesSequence", 137),
-    ("InventoriedStudiesSequence", 136),
-    ("InventoryAccessEndPointsSequence", 133),
-    ("InventoryCompletionStatus", 139),
-    ("InventoryInstanceDescription", 111),
-    ("InventoryLevel", 112),
-    ("InventoryPurpose", 110),
-    ("InversionRecovery", 813),
-    ("InversionTime", 461),
-    ("InversionTimes", 871),
-    ("IonBeamLimitingDeviceSequence", 3559),
-    ("IonBeamSequence", 3558),
-    ("IonBlockSequence", 3560),
-    ("IonControlPointDeliverySequence", 3124),
-    ("IonControlPointSequence", 3561),
-    ("IonRangeCompensatorSequence", 3495),
-    ("IonToleranceTableSequence", 3557),
-    ("IonWedgePositionSequence", 3563),
-    ("IonWedgeSequence", 3562),
-    ("IrradiationEventIdentificationSequence", 1100),
-    ("IrradiationEventUID", 206),
-    ("IsocenterPosition", 3369),
-    ("IsocenterReferenceSystemSequence", 1086),
-    ("IsocenterToBeamLimitingDeviceDistance", 3280),
-    ("IsocenterToBlockTrayDistance", 3331),
-    ("IsocenterToCompensatorDistances", 3492),
-    ("IsocenterToCompensatorTrayDistance", 3490),
-    ("IsocenterToGeneralAccessoryDistance", 3574),
-    ("IsocenterToLateralSpreadingDeviceDistance", 3539),
-    ("IsocenterToRangeModulatorDistance", 3545),
-    ("IsocenterToRangeShifterDistance", 3535),
-    ("IsocenterToWedgeTrayDistance", 3303),
-    ("IssuerOfAccessionNumberSequence", 42),
-    ("IssuerOfAdmissionIDSequence", 1805),
-    ("IssuerOfClinicalTrialProtocolID", 288),
-    ("IssuerOfClinicalTrialSeriesID", 309),
-    ("IssuerOfClinicalTrialSiteID", 292),
-    ("IssuerOfClinicalTrialSubjectID", 294),
-    ("IssuerOfClinicalTrialSubjectReadingID", 296),
-    ("IssuerOfClinicalTrialTimePointID", 302),
-    ("IssuerOfPatientID", 228),
-    ("IssuerOfPatientIDQualifiersSequence", 230),
-    ("IssuerOfServiceEpisodeIDSequence", 1808),
-    ("IssuerOfTheContainerIdentifierSequence", 1910),
-    ("IssuerOfTheSpecimenIdentifierSequence", 1920),
-    ("Italic", 2550),
-    ("ItemInventoryDateTime", 113),
-    ("IterativeReconstructionMethod", 1191),
-    ("KSpaceFiltering", 859),
-    ("KVImagingGenerationParametersSequence", 3014),
-    ("KVP", 452),
-    ("KeratoconusPredictionIndex", 2133),
-    ("KeratometerIndex", 1441),
-    ("KeratometricAxis", 2082),
-    ("KeratometricPower", 2081),
-    ("KeratometryLeftEyeSequence", 2078),
-    ("KeratometryMeasurementTypeCodeSequence", 1465),
-    ("KeratometryRightEyeSequence", 2077),
-    ("LUTData", 1742),
-    ("LUTDescriptor", 1739),
-    ("LUTExplanation", 1740),
-    ("LUTFrameRange", 1783),
-    ("LUTFunction", 1776),
-    ("LUTLabel", 1954),
-    ("LabelText", 2948),
-    ("LanguageCodeSequence", 19),
-    ("LargestImagePixelValue", 1671),
-    ("LargestPixelValueInSeries", 1673),
-    ("LastMenstrualDate", 270),
-    ("LateralSpreadingDeviceDescription", 3517),
-    ("LateralSpreadingDeviceID", 3515),
-    ("LateralSpreadingDeviceNumber", 3514),
-    ("LateralSpreadingDeviceSequence", 3513),
-    ("LateralSpreadingDeviceSetting", 3538),
-    ("LateralSpreadingDeviceSettingsSequence", 3537),
-    ("LateralSpreadingDeviceType", 3516),
-    ("LateralSpreadingDeviceWaterEquivalentThickness", 3518),
-    ("Laterality", 1303),
-    ("LeafJawPositions", 3356),
-    ("LeafPositionBoundaries", 3282),
-    ("LeftImageSequence", 1411),
-    ("LeftLensSequence", 2057),
-    ("LensConstantSequence", 1462),
-    ("LensDescription", 2055),
-    ("LensMake", 386),
-    ("LensModel", 387),
-    ("LensSegmentType", 2065),
-    ("LensSerialNumber", 388),
-    ("LensSpecification", 385),
-    ("LensStatusCodeSequence", 1437),
-    ("LensStatusDescription", 1459),
-    ("LensThickness", 1477),
-    ("LensThicknessSequence", 1472),
-    ("LensesCodeSequence", 1404),
-    ("LesionNumber", 706),
-    ("LightDirection", 2637),
-    ("LightPathFilterPassBand", 1381),
-    ("LightPathFilterPassThroughWavelength", 1380),
-    ("LightPathFilterTypeStackCodeSequence", 1402),
-    ("LightSource", 356),
-    ("LightSourcePolarization", 422),
-    ("LineDashingStyle", 2554),
-    ("LinePattern", 2555),
-    ("LineSequence", 2365),
-    ("LineStyleSequence", 2538),
-    ("LineThickness", 2553),
-    ("ListOfMIMETypes", 2042),
-    ("ListOfUIDMatchingSequence", 126),
-    ("LocalDeviationProbabilityNormalsFlag", 1596),
-    ("LocalNamespaceEntityID", 1882),
-    ("LocalizedDeviationFromNormal", 1592),
-    ("LocalizedDeviationProbability", 1597),
-    ("LocalizedDeviationProbabilitySequence", 1607),
-    ("LocalizingCursorPosition", 697),
-    ("LongCodeValue", 82),
-    ("LongDeviceDescription", 2194),
-    ("LongEdgePointIndexList", 2382),
-    ("LongPrimitivePointIndexList", 2380),
-    ("LongTrianglePointIndexList", 2381),
-    ("LongVertexPointIndexList", 2383),
-    ("LongitudinalTemporalEventType", 300),
-    ("LongitudinalTemporalInformationModified", 1683),
-    ("LongitudinalTemporalOffsetFromEvent", 299),
-    ("LossyImageCompression", 1734),
-    ("LossyImageCompressionMethod", 1736),
-    ("LossyImageCompressionRatio", 1735),
-    ("LowRRValue", 530),
-    ("LowerCutoffFrequency", 1222),
-    ("MAC", 2930),
-    ("MACAlgorithm", 2918),
-    ("MACCalculationTransferSyntaxUID", 2917),
-    ("MACIDNumber", 2916),
-    ("MACParametersSequence", 4005),
-    ("MIMETypeOfEncapsulatedDocument", 2040),
-    ("MPRSlabThickness", 2621),
-    ("MPRThicknessType", 2620),
-    ("MPRTopLeftHandCorner", 2622),
-    ("MPRViewHeight", 2628),
-    ("MPRViewHeightDirection", 2627),
-    ("MPRViewWidth", 2624),
-    ("MPRViewWidthDirection", 2623),
-    ("MRAcquisitionFrequencyEncodingSteps", 853),
-    ("MRAcquisitionPhaseEncodingStepsInPlane", 938),
-    ("MRAcquisitionPhaseEncodingStepsOutOfPlane", 939),
-    ("MRAcquisitionType", 435),
-    ("MRArterialSpinLabelingSequence", 946),
-    ("MRAveragesSequence", 899),
-    ("MRDiffusionSequence", 897),
-    ("MREchoSequence", 895),
-    ("MRFOVGeometrySequence", 900),
-    ("MRImageFrameTypeSequence", 936),
-    ("MRImagingModifierSequence", 811),
-    ("MRMetaboliteMapSequence", 905),
-    ("MRModifierSequence", 896),
-    ("MRReceiveCoilSequence", 840),
-    ("MRSpatialSaturationSequence", 893),
-    ("MRSpectroscopyAcquisitionType", 930),
-    ("MRSpectroscopyFOVGeometrySequence", 889),
-    ("MRSpectroscopyFrameTypeSequence", 937),
-    ("MRTimingAndRelatedParametersSequence", 894),
-    ("MRTransmitCoilSequence", 847),
-    ("MRVelocityEncodingSequence", 927),
-    ("MVImagingGenerationParametersSequence", 3015),
-    ("MachineSpecificTreatmentTerminationCodeSequence", 3750),
-    ("MagneticFieldStrength", 466),
-    ("MagnetizationTransfer", 823),
-    ("MagnitudeEncodingCodeSequence", 1874),
-    ("MajorTicksSequence", 2568),
-    ("MakerNote", 359),
-    ("MandatoryComponentType", 2844),
-    ("Manifold", 2346),
-    ("Manufacturer", 53),
-    ("ManufacturerDeviceClassUID", 484),
-    ("ManufacturerDeviceIdentifier", 3924),
-    ("ManufacturerModelName", 160),
-    ("ManufacturerModelVersion", 3885),
-    ("ManufacturerRelatedModelGroup", 91),
-    ("MappedPixelValue", 1508),
-    ("MappingResource", 65),
-    ("MappingResourceIdentificationSequence", 87),
-    ("MappingResourceName", 85),
-    ("MappingResourceUID", 81),
-    ("MaskFrameNumbers", 1753),
-    ("MaskOperation", 1751),
-    ("MaskOperationExplanation", 1757),
-    ("MaskSelectionMode", 1775),
-    ("MaskSubPixelShift", 1755),
-    ("MaskSubtractionSequence", 1750),
-    ("MaskVisibilityPercentage", 1777),
-    ("MaterialAttenuationSequence", 1045),
-    ("MaterialCodeSequence", 1040),
-    ("MaterialID", 3310),
-    ("MaterialsCodeSequence", 2432),
-    ("MatingFeatureDegreeOfFreedomSequence", 2441),
-    ("MatingFeatureID", 2440),
-    ("MatingFeatureSequence", 2439),
-    ("MatingFeatureSetID", 2437),
-    ("MatingFeatureSetLabel", 2438),
-    ("MatingFeatureSetsSequence", 2436),
-    ("MatrixRegistrationSequence", 2575),
-    ("MatrixSequence", 2576),
-    ("MaxApertureValue", 353),
-    ("MaximumAcrossScanDistortion", 1425),
-    ("MaximumAlongScanDistortion", 1420),
-    ("MaximumCornealCurvature", 2123),
-    ("MaximumCornealCurvatureLocation", 2124),
-    ("MaximumCornealCurvatureSequence", 2122),
-    ("MaximumCumulativeMetersetExposure", 3027),
-    ("MaximumDepthDistortion", 1418),
-    ("MaximumFractionalValue", 2320),
-    ("MaximumNominalEnergy", 3728),
-    ("MaximumNumberOfIntervalDays", 3938),
-    ("MaximumPointDistance", 2354),
-    ("MaximumStimulusLuminance", 1554),
-    ("MaximumStoredValueMapped", 1712),
-    ("MeanPointDistance", 2353),
-    ("MeasuredAPDimension", 259),
-    ("MeasuredDoseDescription", 3106),
-    ("MeasuredDoseReferenceNumber", 3140),
-    ("MeasuredDoseReferenceSequence", 3105),
-    ("MeasuredDoseType", 3107),
-    ("MeasuredDoseValue", 3108),
-    ("MeasuredLateralDimension", 260),
-    ("MeasuredMetersetToDoseMappingSequence", 3774),
-    ("MeasuredValueSequence", 1999),
-    ("MeasurementLaterality", 1631),
-    ("MeasurementUnitsCodeSequence", 1932),
-    ("MeasurementValuesSequence", 2397),
-    ("MeasurementsSequence", 2392),
-    ("MechanicalIndex", 712),
-    ("MediaStorageSOPClassUID", 4042),
-    ("MediaStorageSOPInstanceUID", 4043),
-    ("MedicalAlerts", 263),
-    ("MetaboliteMapCodeSequence", 875),
-    ("MetaboliteMapDescription", 872),
-    ("MeteringMode", 355),
-    ("MetersetExposure", 2967),
-    ("MetersetRate", 3532),
-    ("MetersetRateDelivered", 3128),
-    ("MetersetRateSet", 3127),
-    ("MetersetToDoseMappingSequence", 3633),
-    ("MidSlabPosition", 892),
-    ("MinimumHoursBetweenFractions", 3988),
-    ("MinimumKeratometricSequence", 2125),
-    ("MinimumNominalEnergy", 3727),
-    ("MinimumNumberOfIntervalDays", 3937),
-    ("MinimumSensitivityValue", 1625),
-    ("MinimumStoredValueMapped", 1711),
-    ("ModalitiesInStudy", 49),
-    ("Modality", 48),
-    ("ModalityLUTSequence", 1737),
-    ("ModalityLUTType", 1741),
-    ("ModeOfPercutaneousAccessSequence", 2207),
-    ("ModelGroupUID", 2473),
-    ("ModelMirroring", 2471),
-    ("ModelModification", 2470),
-    ("ModelSpecificationSequence", 1248),
-    ("ModelUsageCodeSequence", 2472),
-    ("ModifiableConstraintFlag", 2908),
-    ("ModifiedAttributesSequence", 2934),
-    ("ModifierCodeSequence", 1998),
-    ("ModifyingSystem", 2939),
-    ("ModulatedScanModeType", 3501),
-    ("MonoenergeticEnergyEquivalent", 1039),
-    ("MostRecentTreatmentDate", 3134),
-    ("MotionSynchronizationSequence", 3566),
-    ("MultiCoilConfiguration", 844),
-    ("MultiCoilDefinitionSequence", 843),
-    ("MultiCoilElementName", 845),
-    ("MultiCoilElementUsed", 846),
-    ("MultiFramePresentationSequence", 1781),
-    ("MultiFrameSourceSOPInstanceUID", 178),
-    ("MultiPlanarExcitation", 816),
-    ("MultiPlanarReconstructionStyle", 2619),
-    ("MultienergyAcquisitionDescription", 1038),
-    ("MultienergyCTAcquisition", 1012),
-    ("MultienergyCTAcquisitionSequence", 1013),
-    ("MultienergyCTCharacteristicsSequence", 1015),
-    ("MultienergyCTPathIndex", 1037),
-    ("MultienergyCTPathSequence", 1036),
-    ("MultienergyCTProcessingSequence", 1014),
-    ("MultienergyCTXRayDetectorSequence", 1026),
-    ("MultienergyCTXRaySourceSequence", 1016),
-    ("MultienergyDetectorType", 1029),
-    ("MultienergySourceTechnique", 1019),
-    ("MultipleSpinEcho", 815),
-    ("MultiplexGroupLabel", 1817),
-    ("MultiplexGroupTimeOffset", 514),
-    ("MultiplexGroupUID", 1851),
-    ("MultiplexedAudioChannelsDescriptionCodeSequence", 1848),
-    ("MydriaticAgentCodeSequence", 1407),
-    ("MydriaticAgentConcentration", 1426),
-    ("MydriaticAgentConcentrationUnitsSequence", 1423),
-    ("MydriaticAgentSequence", 1430),
-    ("NTPSourceAddress", 685),
-    ("NameOfPhysiciansReadingStudy", 153),
-    ("NavigationDisplaySet", 2749),
-    ("NavigationIndicatorSequence", 2748),
-    ("NearPupillaryDistance", 2074),
-    ("NegativeCatchTrialsQuantity", 1573),
-    ("NominalBeamEnergy", 3351),
-    ("NominalBeamEnergyUnit", 3217),
-    ("NominalCardiacTriggerDelayTime", 1325),
-    ("NominalCardiacTriggerTimePriorToRPeak", 1326),
-    ("NominalEnergy", 3726),
-    ("NominalInterval", 508),
-    ("NominalMaxEnergy", 1031),
-    ("NominalMinEnergy", 1032),
-    ("NominalPercentageOfCardiacPhase", 1345),
-    ("NominalPercentageOfRespiratoryPhase", 1346),
-    ("NominalPriorDose", 3220),
-    ("NominalRangeModulatedRegionDepths", 3592),
-    ("NominalRangeModulationFractions", 3591),
-    ("NominalRespiratoryTriggerDelayTime", 1356),
-    ("NominalScannedPixelSpacing", 692),
-    ("NominalScreenDefinitionSequence", 2733),
-    ("NonUniformRadialSamplingCorrected", 1188),
-    ("NonconformingDataElementValue", 2936),
-    ("NonconformingModifiedAttributesSequence", 2935),
-    ("NonidentifyingPrivateElements", 96),
-    ("NormalizationPoint", 3034),
-    ("NotchFilterBandwidth", 1837),
-    ("NotchFilterCharacteristicsSequence", 1862),
-    ("NotchFilterFrequency", 1836),
-    ("NotificationFromManufacturerSequence", 2409),
-    ("NumberOfAcquisitionDevices", 2997),
-    ("NumberOfAnnotations", 2485),
-    ("NumberOfAssessmentObservations", 2889),
-    ("NumberOfAverages", 462),
-    ("NumberOfBeams", 3256),
-    ("NumberOfBlockSlabItems", 3581),
-    ("NumberOfBlocks", 3325),
-    ("NumberOfBoli", 3322),
-    ("NumberOfBoluses", 3714),
-    ("NumberOfBrachyApplicationSetups", 3270),
-    ("NumberOfBscansPerFrame", 1542),
-    ("NumberOfCompensators", 3309),
-    ("NumberOfContourPoints", 3078),
-    ("NumberOfControlPoints", 3348),
-    ("NumberOfDetectors", 2231),
-    ("NumberOfEnergyWindows", 2222),
-    ("NumberOfEventTimers", 191),
-    ("NumberOfFocalPlanes", 2153),
-    ("NumberOfFractionPatternDigitsPerDay", 3253),
-    ("NumberOfFractions", 3981),
-    ("NumberOfFractionsDelivered", 3135),
-    ("NumberOfFractionsIncluded", 3845),
-    ("NumberOfFractionsPlanned", 3252),
-    ("NumberOfFrames", 1655),
-    ("NumberOfFramesInOverlay", 4019),
-    ("NumberOfFramesInPhase", 2236),
-    ("NumberOfFramesInRotation", 2243),
-    ("NumberOfGeneralAccessories", 3712),
-    ("NumberOfGraphicPoints", 2505),
-    ("NumberOfHorizontalPixels", 2735),
-    ("NumberOfIntervalFractions", 3980),
-    ("NumberOfIterations", 1174),
-    ("NumberOfKSpaceTrajectories", 883),
-    ("NumberOfLateralSpreadingDevices", 3512),
-    ("NumberOfLeafJawPairs", 3281),
-    ("NumberOfMapPoints", 1529),
-    ("NumberOfOpticalPaths", 2176),
-    ("NumberOfPaddedALines", 2218),
-    ("NumberOfPaintings", 3556),
-    ("NumberOfParallelRTBeamDelimiters", 3671),
-    ("NumberOfPatientSupportDevices", 3733),
-    ("NumberOfPhaseEncodingSteps", 468),
-    ("NumberOfPhases", 2234),
-    ("NumberOfPolygonalVertices", 672),
-    ("NumberOfPriorsReferenced", 2680),
-    ("NumberOfPulses", 3461),
-    ("NumberOfRRIntervals", 2245),
-    ("NumberOfRTAccessoryHolders", 3710),
-    ("NumberOfRTBeamLimitingDeviceOpenings", 3685),
-    ("NumberOfRTBeamLimitingDevices", 3664),
-    ("NumberOfRTControlPoints", 3605),
-    ("NumberOfRadiationGenerationModes", 3731),
-    ("NumberOfRangeModulators", 3519),
-    ("NumberOfRangeShifters", 3506),
-    ("NumberOfRotations", 2241),
-    ("NumberOfScanSpotPositions", 3549),
-    ("NumberOfScreens", 2732),
-    ("NumberOfSlices", 2253),
-    ("NumberOfStages", 188),
-    ("NumberOfStudyRecordsInInstance", 140),
-    ("NumberOfStudyRelatedInstances", 1315),
-    ("NumberOfStudyRelatedSeries", 1314),
-    ("NumberOfSubsets", 1175),
-    ("NumberOfSurfacePoints", 2350),
-    ("NumberOfSurfaces", 2336),
-    ("NumberOfTableBreakPoints", 756),
-    ("NumberOfTableColumns", 2017),
-    ("NumberOfTableEntries", 759),
-    ("NumberOfTableRows", 2016),
-    ("NumberOfTemporalPositions", 1306),
-    ("NumberOfTimeSlices", 2256),
-    ("NumberOfTimeSlots", 2249),
-    ("NumberOfTomosynthesisSourceImages", 645),
-    ("NumberOfTriggersInPhase", 2260),
-    ("NumberOfVectors", 2358),
-    ("NumberOfVerticalPixels", 2734),
-    ("NumberOfViewsInStage", 192),
-    ("NumberOfVisualStimuli", 1566),
-    ("NumberOfVolumetricCurvePoints", 2625),
-    ("NumberOfWaveformChannels", 1814),
-    ("NumberOfWaveformSamples", 1815),
-    ("NumberOfWedgePositions", 3683),
-    ("NumberOfWedges", 3294),
-    ("NumberOfZeroFills", 861),
-    ("NumericValue", 2001),
-    ("NumericValueQualifierCodeSequence", 2000),
-    ("OCTAcquisitionDomain", 2199),
-    ("OCTBscanAnalysisAcquisitionParametersSequence", 1541),
-    ("OCTFocalDistance", 2196),
-    ("OCTOpticalCenterWavelength", 2200),
-    ("OCTZOffsetApplied", 2209),
-    ("OCTZOffsetCorrection", 2213),
-    ("OECFColumnNames", 324),
-    ("OECFColumns", 323),
-    ("OECFRows", 322),
-    ("OECFValues", 325),
-    ("ObjectPixelSpacingInCenterOfBeam", 1051),
-    ("ObjectSoundSpeed", 1226),
-    ("ObjectThicknessSequence", 1083),
-    ("ObjectiveLensNumericalAperture", 2165),
-    ("ObjectiveLensPower", 2164),
-    ("ObliqueCroppingPlaneSequence", 2615),
-    ("ObservationBasisCodeSequence", 2900),
-    ("ObservationDateTime", 1966),
-    ("ObservationDescription", 2892),
-    ("ObservationNumber", 3088),
-    ("ObservationSignificance", 2891),
-    ("ObservationStartDateTime", 1967),
-    ("ObservationUID", 1996),
-    ("ObserverType", 1978),
-    ("Occupation", 266),
-    ("OffsetDirection", 1235),
-    ("OffsetDistance", 1234),
-    ("OffsetOfReferencedLowerLevelDirectoryEntity", 9),
-    ("OffsetOfTheFirstDirectoryRecordOfTheRootDirectoryEntity", 3),
-    ("OffsetOfTheLastDirectoryRecordOfTheRootDirectoryEntity", 4),
-    ("OffsetOfTheNextDirectoryRecord", 7),
-    ("OmittedApplicationSetupSequence", 2830),
-    ("OmittedBeamTaskSequence", 3837),
-    ("OmittedChannelSequence", 2825),
-    ("OmittedRadiationSequence", 3782),
-    ("OperatingMode", 918),
-    ("OperatingModeSequence", 916),
-    ("OperatingModeType", 917),
-    ("OperatorIdentificationSequence", 156),
-    ("OperatorsName", 155),
-    ("OphthalmicAnatomicReferencePointXCoordinate", 1537),
-    ("OphthalmicAnatomicReferencePointYCoordinate", 1538),
-    ("OphthalmicAxialLength", 1436),
-    ("OphthalmicAxialLengthDataSourceCodeSequence", 1484),
-    ("OphthalmicAxialLengthDataSourceDescription", 1486),
-    ("OphthalmicAxialLengthMeasurementModified", 1483),
-    ("OphthalmicAxialLengthMeasurementsLengthSummationSequence", 1489),
-    ("OphthalmicAxialLengthMeasurementsSegmentNameCodeSequence", 1467),
-    ("OphthalmicAxialLengthMeasurementsSegmentalLengthSequence", 1488),
-    ("OphthalmicAxialLengthMeasurementsSequence", 1455),
-    ("OphthalmicAxialLengthMeasurementsTotalLengthSequence", 1487),
-    ("OphthalmicAxialLengthMeasurementsType", 1434),
-    ("OphthalmicAxialLengthMethod", 1521),
-    ("OphthalmicAxialLengthQualityMetricSequence", 1497),
-    ("OphthalmicAxialLengthSelectionMethodCodeSequence", 1493),
-    ("OphthalmicAxialLengthSequence", 1435),
-    ("OphthalmicAxialLengthVelocity", 1458),
-    ("OphthalmicAxialMeasurementsDeviceType", 1433),
-    ("OphthalmicAxialMeasurementsLeftEyeSequence", 1432),
-    ("OphthalmicAxialMeasurementsRightEyeSequence", 1431),
-    ("OphthalmicEnFaceImageQualityRatingSequence", 1539),
-    ("OphthalmicFOV", 1522),
-    ("OphthalmicFrameLocationSequence", 1415),
-    ("OphthalmicImageOrientation", 1421),
-    ("OphthalmicImageTypeCodeSequence", 1532),
-    ("OphthalmicImageTypeDescription", 1533),
-    ("OphthalmicMappingDeviceType", 1501),
-    ("OphthalmicPatientClinicalInformationLeftEyeSequence", 1632),
-    ("OphthalmicPatientClinicalInformationRightEyeSequence", 1633),
-    ("OphthalmicThicknessMapQualityRatingSequence", 1517),
-    ("OphthalmicThicknessMapQualityThresholdSequence", 1510),
-    ("OphthalmicThicknessMapThresholdQualityRating", 1511),
-    ("OphthalmicThicknessMapTypeCodeSequence", 1504),
-    ("OphthalmicThicknessMappingNormalsSequence", 1505),
-    ("OphthalmicUltrasoundMethodCodeSequence", 1447),
-    ("OphthalmicVolumetricPropertiesFlag", 1536),
-    ("OpticalMagnificationFactor", 426),
-    ("OpticalOphthalmicAxialLengthMeasurementsSequence", 1491),
-    ("OpticalPathDescription", 2160),
-    ("OpticalPathIdentificationSequence", 2171),
-    ("OpticalPathIdentifier", 2159),
-    ("OpticalPathSequence", 2158),
-    ("OpticalSelectedOphthalmicAxialLengthSequence", 1494),
-    ("OpticalTransmittance", 2066),
-    ("Optotype", 2085),
-    ("OptotypeDetailedDefinition", 2110),
-    ("OptotypePresentation", 2086),
-    ("OrderFillerIdentifierSequence", 1881),
-    ("OrderPlacerIdentifierSequence", 1880),
-    ("OrganAtRiskFullVolumeDose", 3229),
-    ("OrganAtRiskLimitDose", 3230),
-    ("OrganAtRiskMaximumDose", 3231),
-    ("OrganAtRiskOverdoseVolumeFraction", 3232),
-    ("OrganDose", 1905),
-    ("OrganExposed", 1906),
-    ("OrganizationalRoleCodeSequence", 2054),
-    ("OriginalAttributesSequence", 2937),
-    ("OriginalImplantAssemblyTemplateSequence", 2836),
-    ("OriginalImplantTemplateSequence", 2405),
-    ("OriginalSpecializedSOPClassUID", 30),
-    ("OriginatingSOPInstanceReferenceSequence", 3866),
-    ("OtherClinicalTrialProtocolIDsSequence", 289),
-    ("OtherPatientIDsSequence", 254),
-    ("OtherPatientNames", 253),
-    ("OtherPupillaryDistance", 2076),
-    ("OutlineLeftVerticalEdge", 666),
-    ("OutlineLowerHorizontalEdge", 669),
-    ("OutlineRightVerticalEdge", 667),
-    ("OutlineShapeType", 665),
-    ("OutlineUpperHorizontalEdge", 668),
-    ("OutputInformationSequence", 1943),
-    ("OutputPower", 707),
-    ("OverallTemplateSpatialTolerance", 2413),
-    ("OverlayActivationLayer", 4027),
-    ("OverlayBitPosition", 4026),
-    ("OverlayBitsAllocated", 4025),
-    ("OverlayColumns", 4018),
-    ("OverlayData", 4032),
-    ("OverlayDescription", 4020),
-    ("OverlayLabel", 4031),
-    ("OverlayOrigin", 4023),
-    ("OverlayRows", 4017),
-    ("OverlaySubtype", 4022),
-    ("OverlayType", 4021),
-    ("OverrideDateTime", 3771),
-    ("OverrideParameterPointer", 3138),
-    ("OverrideReason", 3142),
-    ("OverrideSequence", 3136),
-    ("OversamplingPhase", 831),
-    ("PETDetectorMotionDetailsSequence", 1168),
-    ("PETFrameAcquisitionSequence", 1167),
-    ("PETFrameCorrectionFactorsSequence", 1171),
-    ("PETFrameTypeSequence", 1177),
-    ("PETPositionSequence", 1170),
-    ("PETReconstructionSequence", 1176),
-    ("PETTableDynamicsSequence", 1169),
-    ("PVCRejection", 534),
-    ("PaddleDescription", 585),
-    ("PageNumberVector", 686),
-    ("PaletteColorLookupTableSequence", 2170),
-    ("PaletteColorLookupTableUID", 1701),
-    ("ParallelAcquisition", 869),
-    ("ParallelAcquisitionTechnique", 870),
-    ("ParallelRTBeamDelimiterBoundaries", 3672),
-    ("ParallelRTBeamDelimiterDeviceOrientationLabelCodeSequence", 3667),
-    ("ParallelRTBeamDelimiterDeviceSequence", 3670),
-    ("ParallelRTBeamDelimiterLeafMountingSide", 3678),
-    ("ParallelRTBeamDelimiterOpeningExtents", 3160),
-    ("ParallelRTBeamDelimiterOpeningMode", 3677),
-    ("ParallelRTBeamDelimiterPositions", 3673),
-    ("ParallelReductionFactorInPlane", 863),
-    ("ParallelReductionFactorOutOfPlane", 906),
-    ("ParallelReductionFactorSecondInPlane", 908),
-    ("ParameterItemIndex", 3139),
-    ("ParameterPointer", 3141),
-    ("ParameterSequencePointer", 3137),
-    ("ParameterValueNumber", 3143),
-    ("ParametersSpecificationSequence", 1249),
-    ("ParametricMapFrameTypeSequence", 1950),
-    ("PartialDataDisplayHandling", 2745),
-    ("PartialFourier", 873),
-    ("PartialFourierDirection", 837),
-    ("PartialView", 1714),
-    ("PartialViewCodeSequence", 1716),
-    ("PartialViewDescription", 1715),
-    ("ParticipantSequence", 1974),
-    ("ParticipationDateTime", 1977),
-    ("ParticipationType", 1976),
-    ("PatientAdditionalPosition", 3392),
-    ("PatientAge", 255),
-    ("PatientAlternativeCalendar", 238),
-    ("PatientBirthDate", 234),
-    ("PatientBirthDateInAlternativeCalendar", 236),
-    ("PatientBirthTime", 235),
-    ("PatientBodyMassIndex", 258),
-    ("PatientBreedCodeSequence", 276),
-    ("PatientBreedDescription", 275),
-    ("PatientComments", 283),
-    ("PatientDeathDateInAlternativeCalendar", 237),
-    ("PatientEquipmentRelationshipCodeSequence", 3905),
-    ("PatientEyeMovementCommandCodeSequence", 1385),
-    ("PatientEyeMovementCommanded", 1384),
-    ("PatientFrameOfReferenceSource", 1366),
-    ("PatientGantryRelationshipCodeSequence", 2271),
-    ("PatientID", 227),
-    ("PatientIdentityRemoved", 304),
-    ("PatientLocationCoordinatesCodeSequence", 3103),
-    ("PatientLocationCoordinatesSequence", 3102),
-    ("PatientMotionCorrected", 1185),
-    ("PatientName", 226),
-    ("PatientNotProperlyFixatedQuantity", 1564),
-    ("PatientOrientation", 1298),
-    ("PatientOrientationCodeSequence", 2269),
-    ("PatientOrientationInFrameSequence", 1375),
-    ("PatientOrientationModifierCodeSequence", 2270),
-    ("PatientPhysiologicalStateCodeSequence", 1194),
-    ("PatientPhysiologicalStateSequence", 1193),
-    ("PatientPosition", 719),
-    ("PatientPositioningInstructionSequence", 1257),
-    ("PatientReliabilityIndicator", 1593),
-    ("PatientSetupLabel", 3391),
-    ("PatientSetupNumber", 3390),
-    ("PatientSetupPhotoDescription", 3795),
-    ("PatientSetupSequence", 3389),
-    ("PatientSex", 239),
-    ("PatientSexNeutered", 273),
-    ("PatientSize", 256),
-    ("PatientSizeCodeSequence", 257),
-    ("PatientSpeciesCodeSequence", 272),
-    ("PatientSpeciesDescription", 271),
-    ("PatientSpecificationSequence", 1247),
-    ("PatientState", 1812),
-    ("PatientSupportAccessoryCode", 3528),
-    ("PatientSupportAdjustedAngle", 2803),
-    ("PatientSupportAngle", 3361),
-    ("PatientSupportAngleTolerance", 3241),
-    ("PatientSupportDevicesSequence", 3732),
-    ("PatientSupportDisplacementSequence", 3803),
-    ("PatientSupportID", 3527),
-    ("PatientSupportPositionDeviceParameterSequence", 3691),
-    ("PatientSupportPositionDeviceToleranceSequence", 3694),
-    ("PatientSupportPositionParameterOrderIndex", 3693),
-    ("PatientSupportPositionParameterSequence", 3689),
-    ("PatientSupportPositionSequence", 3104),
-    ("PatientSupportPositionSpecificationMethod", 3690),
-    ("PatientSupportPositionToleranceOrderIndex", 3695),
-    ("PatientSupportPositionToleranceSequence", 3646),
-    ("PatientSupportRotationDirection", 3362),
-    ("PatientSupportType", 3526),
-    ("PatientTreatmentOrientationSequence", 3907),
-    ("PatientTreatmentPreparationDeviceSequence", 3790),
-    ("PatientTreatmentPreparationMethodCodeSequence", 3788),
-    ("PatientTreatmentPreparationMethodDescription", 3793),
-    ("PatientTreatmentPreparationProcedureCodeSequence", 3792),
-    ("PatientTreatmentPreparationProcedureIndex", 3796),
-    ("PatientTreatmentPreparationProcedureParameterDescription", 3789),
-    ("PatientTreatmentPreparationProcedureParameterSequence", 3794),
-    ("PatientTreatmentPreparationProcedureSequence", 3791),
-    ("PatientWeight", 261),
-    ("PatternOffColorCIELabValue", 2552),
-    ("PatternOffOpacity", 2567),
-    ("PatternOnColorCIELabValue", 2551),
-    ("PatternOnOpacity", 2566),
-    ("PauseBetweenFrames", 2238),
-    ("PerFrameFunctionalGroupsSequence", 4007),
-    ("PerProjectionAcquisitionSequence", 1121),
-    ("PercentPhaseFieldOfView", 472),
-    ("PercentSampling", 471),
-    ("PerformedProcedureCodeSequence", 2004),
-    ("PerformedProcedureStepDescription", 1894),
-    ("PerformedProcedureStepEndDate", 1891),
-    ("PerformedProcedureStepEndTime", 1892),
-    ("PerformedProcedureStepID", 1893),
-    ("PerformedProcedureStepStartDate", 1889),
-    ("PerformedProcedureStepStartTime", 1890),
-    ("PerformedProcessingParametersSequence", 2813),
-    ("PerformedProtocolCodeSequence", 1895),
-    ("PerformedProtocolType", 1896),
-    ("PerformingPhysicianIdentificationSequence", 152),
-    ("PerformingPhysicianName", 151),
-    ("PersonAddress", 1938),
-    ("PersonIdentificationCodeSequence", 1937),
-    ("PersonName", 1984),
-    ("PersonTelecomInformation", 1940),
-    ("PersonTelephoneNumbers", 1939),
-    ("PertinentDocumentsSequence", 1809),
-    ("PertinentOtherEvidenceSequence", 2006),
-    ("PertinentResourcesSequence", 1810),
-    ("PertinentSOPClassesInSeries", 3940),
-    ("PertinentSOPClassesInStudy", 3939),
-    ("PhaseContrast", 817),
-    ("PhaseDelay", 2237),
-    ("PhaseDescription", 2239),
-    ("PhaseInformationSequence", 2235),
-    ("PhaseVector", 2233),
-    ("PhosphorType", 617),
-    ("PhotoacousticExcitationCharacteristicsSequence", 1209),
-    ("PhotoacousticImageFrameTypeSequence", 1228),
-    ("PhotographicExposureIndex", 368),
-    ("PhotographicSensitivity", 340),
-    ("PhotometricInterpretation", 1653),
-    ("PhotonEnergy", 1046),
-    ("PhototimerSetting", 806),
-    ("PhysicalDeltaX", 737),
-    ("PhysicalDeltaY", 738),
-    ("PhysicalDetectorSize", 1065),
-    ("PhysicalUnitsXDirection", 733),
-    ("PhysicalUnitsYDirection", 734),
-    ("PhysiciansOfRecord", 149),
-    ("PhysiciansOfRecordIdentificationSequence", 150),
-    ("PhysiciansReadingStudyIdentificationSequence", 154),
-    ("PixelAspectRatio", 1664),
-    ("PixelBandwidth", 473),
-    ("PixelComponentDataType", 755),
-    ("PixelComponentMask", 751),
-    ("PixelComponentOrganization", 750),
-    ("PixelComponentPhysicalUnits", 754),
-    ("PixelComponentRangeStart", 752),
-    ("PixelComponentRangeStop", 753),
-    ("PixelData", 4038),
-    ("PixelDataAreaOriginRelativeToFOV", 789),
-    ("PixelDataAreaRotationAngleRelativeToFOV", 790),
-    ("PixelDataProviderURL", 1758),
-    ("PixelIntensityRelationship", 1687),
-    ("PixelIntensityRelationshipLUTSequence", 1770),
-    ("PixelIntensityRelationshipSign", 1688),
-    ("PixelMeasuresSequence", 1763),
-    ("PixelOriginInterpretation", 2175),
-    ("PixelPaddingRangeLimit", 1675),
-    ("PixelPaddingValue", 1674),
-    ("PixelPresentation", 215),
-    ("PixelRepresentation", 1669),
-    ("PixelShiftFrameRange", 1782),
-    ("PixelShiftSequence", 1778),
-    ("PixelSpacing", 1661),
-    ("PixelSpacingCalibrationDescription", 1686),
-    ("PixelSpacingCalibrationType", 1685),
-    ("PixelValueMappingCodeSequence", 1953),
-    ("PixelValueMappingExplanation", 1509),
-    ("PixelValueMappingToCodedConceptSequence", 1507),
-    ("PixelValueTransformationSequence", 1765),
-    ("PlacerOrderNumberImagingServiceRequest", 1941),
-    ("PlanIntent", 3210),
-    ("PlanOverviewIndex", 3843),
-    ("PlanOverviewSequence", 3842),
-    ("PlanarConfiguration", 1654),
-    ("Plane", 2616),
-    ("PlaneIdentification", 1084),
-    ("PlaneNormal", 2617),
-    ("PlaneOrientationSequence", 1323),
-    ("PlaneOrientationVolumeSequence", 1369),
-    ("PlanePositionSequence", 1322),
-    ("PlanePositionSlideSequence", 2172),
-    ("PlanePositionVolumeSequence", 1368),
-    ("PlanesInAcquisition", 1055),
-    ("PlannedVerificationImageSequence", 3291),
-    ("PlanningInputInformationSequence", 3974),
-    ("PlanningLandmarkDescription", 2458),
-    ("PlanningLandmarkID", 2457),
-    ("PlanningLandmarkIdentificationCodeSequence", 2459),
-    ("PlanningLandmarkLineSequence", 2455),
-    ("PlanningLandmarkPlaneSequence", 2456),
-    ("PlanningLandmarkPointSequence", 2454),
-    ("PlateID", 477),
-    ("PlateType", 616),
-    ("PointCoordinatesData", 2351),
-    ("PointPositionAccuracy", 2352),
-    ("PointsBoundingBoxCoordinates", 2355),
-    ("PositionAcquisitionTemplateCodeSequence", 3009),
-    ("PositionAcquisitionTemplateDescription", 3010),
-    ("PositionAcquisitionTemplateID", 3007),
-    ("PositionAcquisitionTemplateIdentificationSequence", 3006),
-    ("PositionAcquisitionTemplateName", 3008),
-    ("PositionMeasuringDeviceUsed", 1204),
-    ("PositionOfIsocenterProjection", 1066),
-    ("PositionReferenceIndicator", 1312),
-    ("PositionerIsocenterDetectorRotationAngle", 1089),
-    ("PositionerIsocenterPrimaryAngle", 1087),
-    ("PositionerIsocenterSecondaryAngle", 1088),
-    ("PositionerMotion", 646),
-    ("PositionerPositionSequence", 1052),
-    ("PositionerPrimaryAngle", 648),
-    ("PositionerPrimaryAngleDirection", 1140),
-    ("PositionerPrimaryAngleIncrement", 650),
-    ("PositionerSecondaryAngle", 649),
-    ("PositionerSecondaryAngleIncrement", 651),
-    ("PositionerType", 647),
-    ("PositioningLandmarkSequence", 1259),
-    ("PositioningMethodCodeSequence", 1258),
-    ("PositiveCatchTrialsQuantity", 1580),
-    ("PostDeformationMatrixRegistrationSequence", 2335),
-    ("PotentialDiagnosticTasks", 1240),
-    ("PotentialReasonsForProcedure", 1238),
-    ("PotentialReasonsForProcedureCodeSequence", 1239),
-    ("PotentialRequestedProcedureCodeSequence", 1237),
-    ("PotentialScheduledProtocolCodeSequence", 1236),
-    ("PowerlineFrequency", 1852),
-    ("PreDeformationMatrixRegistrationSequence", 2334),
-    ("PreSelectedForImplantation", 1452),
-    ("PredecessorDocumentsSequence", 2002),
-    ("PredecessorProtocolSequence", 1244),
-    ("PredecessorStructureSetSequence", 3060),
-    ("PredictedRefractiveError", 1457),
-    ("PredictedToricErrorSequence", 1451),
-    ("PreferredPlaybackSequencing", 613),
-    ("PregnancyStatus", 269),
-    ("PreliminaryFlag", 2011),
-    ("PrescriptionDescription", 3212),
-    ("PrescriptionNotes", 3979),
-    ("PrescriptionNotesSequence", 3985),
-    ("PrescriptionOverviewSequence", 3840),
-    ("PresentationAnimationStyle", 2652),
-    ("PresentationCreationDate", 2520),
-    ("PresentationCreationTime", 2521),
-    ("PresentationDisplayCollectionUID", 2597),
-    ("PresentationGroupNumber", 1841),
-    ("PresentationInputType", 2602),
-    ("PresentationIntentType", 52),
-    ("PresentationLUTSequence", 2945),
-    ("PresentationLUTShape", 2946),
-    ("PresentationPixelAspectRatio", 2527),
-    ("PresentationPixelMagnificationRatio", 2528),
-    ("PresentationPixelSpacing", 2526),
-    ("PresentationSequenceCollectionUID", 2598),
-    ("PresentationSequencePositionIndex", 2599),
-    ("PresentationSizeMode", 2525),
-    ("PresentationStateClassificationComponentSequence", 2641),
-    ("PresentationStateCompositorComponentSequence", 2645),
-    ("PresentedVisualStimuliDataFlag", 1565),
-    ("Pressure", 362),
-    ("PrimaryAnatomicStructureModifierSequence", 204),
-    ("PrimaryAnatomicStructureSequence", 203),
-    ("PrimaryChromaticities", 318),
-    ("PrimaryDoseValueIndicator", 3628),
-    ("PrimaryDosimeterUnit", 3275),
-    ("PrimaryFluenceModeSequence", 2972),
-    ("PrimaryPositionerIncrement", 1108),
-    ("PrimaryPositionerIncrementSign", 1112),
-    ("PrimaryPositionerScanArc", 1104),
-    ("PrimaryPositionerScanStartAngle", 1106),
-    ("PrimaryPromptsCountsAccumulated", 2292),
-    ("PriorTreatmentDoseDescription", 3954),
-    ("PriorTreatmentReferenceSequence", 3955),
-    ("PrismSequence", 2060),
-    ("PrivateCreatorReference", 94),
-    ("PrivateDataElement", 100),
-    ("PrivateDataElementCharacteristicsSequence", 92),
-    ("PrivateDataElementDefinitionSequence", 108),
-    ("PrivateDataElementDescription", 106),
-    ("PrivateDataElementEncoding", 107),
-    ("PrivateDataElementKeyword", 105),
-    ("PrivateDataElementName", 104),
-    ("PrivateDataElementNumberOfItems", 103),
-    ("PrivateDataElementValueMultiplicity", 101),
-    ("PrivateDataElementValueRepresentation", 102),
-    ("PrivateGroupReference", 93),
-    ("PrivateInformation", 4061),    ("PrivateInformationCreatorUID", 4060),
-    ("PrivateRecordUID", 11),
-    ("ProcedureCodeSequence", 144),
-    ("ProcedureTypeCodeSequence", 2839),
-    ("ProcessingFunction", 711),
-    ("ProjectionEponymousNameCodeSequence", 721),
-    ("ProjectionImagingAcquisitionParameterSequence", 3012),
-    ("ProjectionPixelCalibrationSequence", 1048),
-    ("ProtocolContextSequence", 1907),
-    ("ProtocolDefinedPatientPosition", 1288),
-    ("ProtocolDesignRationale", 1246),
-    ("ProtocolElementCharacteristicsSummary", 1265),
-    ("ProtocolElementName", 1264),
-    ("ProtocolElementNumber", 1263),
-    ("ProtocolElementPurpose", 1266),
-    ("ProtocolName", 494),
-    ("ProtocolPlanningInformation", 1245),
-    ("PseudoColorPaletteInstanceReferenceSequence", 2787),
-    ("PseudoColorType", 2786),
-    ("PulseNumber", 3193),
-    ("PulseRepetitionFrequency", 741),
-    ("PulseRepetitionInterval", 3462),
-    ("PulseSequenceName", 810),
-    ("PulseSpecificBrachyControlPointDeliveredSequence", 3192),
-    ("PupilCentroidXCoordinate", 2116),
-    ("PupilCentroidYCoordinate", 2117),
-    ("PupilDilated", 1392),
-    ("PupilSize", 2068),
-    ("PurposeOfReferenceCodeSequence", 1995),
-    ("PyramidDescription", 159),
-    ("PyramidLabel", 1299),
-    ("PyramidUID", 28),
-    ("QuadratureReceiveCoil", 842),
-    ("QualityControlImage", 1680),
-    ("QualityControlSubject", 240),
-    ("QualityThreshold", 1540),
-    ("QuantifiedDefect", 1620),
-    ("QuantityDefinitionSequence", 1960),
-    ("QueryRetrieveLevel", 43),
-    ("QueryRetrieveView", 44),
-    ("RFEchoTrainLength", 943),
-    ("RGBATransferFunctionDescription", 2659),
-    ("RGBLUTTransferFunction", 1730),
-    ("ROIArea", 4028),
-    ("ROIContourSequence", 3075),
-    ("ROICreatorSequence", 3083),
-    ("ROIDateTime", 3068),
-    ("ROIDerivationAlgorithmIdentificationSequence", 3073),
-    ("ROIDescription", 3065),
-    ("ROIDisplayColor", 3066),
-    ("ROIElementalCompositionAtomicMassFraction", 3099),
-    ("ROIElementalCompositionAtomicNumber", 3098),
-    ("ROIElementalCompositionSequence", 3097),
-    ("ROIGenerationAlgorithm", 3072),
-    ("ROIGenerationDescription", 3074),
-    ("ROIInterpreter", 3093),
-    ("ROIInterpreterSequence", 3084),
-    ("ROIMean", 4029),
-    ("ROIName", 3064),
-    ("ROINumber", 3062),
-    ("ROIObservationContextCodeSequence", 3085),
-    ("ROIObservationDateTime", 3069),
-    ("ROIPhysicalPropertiesSequence", 3094),
-    ("ROIPhysicalProperty", 3095),
-    ("ROIPhysicalPropertyValue", 3096),
-    ("ROIStandardDeviation", 4030),
-    ("ROIVolume", 3067),
-    ("RRIntervalTimeNominal", 1352),
-    ("RRIntervalVector", 2244),
-    ("RTAccessoryDeviceSlotID", 3622),
-    ("RTAccessoryHolderDefinitionSequence", 3621),
-    ("RTAccessoryHolderSlotDistance", 3619),
-    ("RTAccessoryHolderSlotExistenceFlag", 3616),
-    ("RTAccessoryHolderSlotID", 3618),
-    ("RTAccessoryHolderSlotSequence", 3617),
-    ("RTAccessoryHolderWaterEquivalentThickness", 3614),
-    ("RTAccessorySlotDistance", 3620),
-    ("RTAcquisitionPatientPositionSequence", 2983),
-    ("RTAnatomicPrescriptionSequence", 3953),
-    ("RTBeamDelimiterGeometrySequence", 3675),
-    ("RTBeamLimitingDeviceAngle", 3719),
-    ("RTBeamLimitingDeviceDefinitionSequence", 3676),
-    ("RTBeamLimitingDeviceDistalDistance", 3666),
-    ("RTBeamLimitingDeviceOffset", 3674),
-    ("RTBeamLimitingDeviceOpeningSequence", 3684),
-    ("RTBeamLimitingDeviceProximalDistance", 3665),
-    ("RTBeamLimitingDeviceType", 3278),
-    ("RTBeamModifierDefinitionDistance", 3734),
-    ("RTControlPointIndex", 3601),
-    ("RTDeliveryStartPatientPositionSequence", 3784),
-    ("RTDeviceDistanceReferenceLocationCodeSequence", 3687),
-    ("RTDiagnosisCodeSequence", 3950),
-    ("RTImageDescription", 2952),
-    ("RTImageFrameContextSequence", 2978),
-    ("RTImageFrameGeneralContentSequence", 2977),
-    ("RTImageFrameImagingDevicePositionSequence", 2984),
-    ("RTImageFrameMVRadiationAcquisitionSequence", 2986),
-    ("RTImageFrameRadiationAcquisitionSequence", 2987),
-    ("RTImageFramekVRadiationAcquisitionSequence", 2985),
-    ("RTImageLabel", 2950),
-    ("RTImageName", 2951),
-    ("RTImageOrientation", 2957),
-    ("RTImagePlane", 2954),
-    ("RTImagePosition", 2959),
-    ("RTImageSID", 2963),
-    ("RTImageScopeSequence", 2979),
-    ("RTPatientPositionDisplacementSequence", 3799),
-    ("RTPatientPositionScopeSequence", 3779),
-    ("RTPatientPositionSequence", 3800),
-    ("RTPhysicianIntentIndex", 3945),
-    ("RTPhysicianIntentInputInstanceSequence", 3952),
-    ("RTPhysicianIntentNarrative", 3947),
-    ("RTPhysicianIntentPredecessorSequence", 3942),
-    ("RTPhysicianIntentSequence", 3944),
-    ("RTPlanDate", 3207),
-    ("RTPlanDescription", 3206),
-    ("RTPlanGeometry", 3211),
-    ("RTPlanLabel", 3204),
-    ("RTPlanName", 3205),
-    ("RTPlanRelationship", 3248),
-    ("RTPlanTime", 3208),
-    ("RTPrescriptionIndex", 3917),
-    ("RTPrescriptionLabel", 3941),
-    ("RTPrescriptionSequence", 3964),
-    ("RTProtocolCodeSequence", 3948),
-    ("RTROIIdentificationCodeSequence", 3090),
-    ("RTROIInterpretedType", 3092),
-    ("RTROIObservationsSequence", 3087),
-    ("RTROIRelationship", 3071),
-    ("RTRadiationPhysicalAndGeometricContentDetailFlag", 3655),
-    ("RTRadiationSalvageRecordControlPointSequence", 3751),
-    ("RTRadiationSequence", 3623),
-    ("RTRadiationSetDeliveryNumber", 3742),
-    ("RTRadiationSetDeliveryUsage", 3805),
-    ("RTRadiationSetIntent", 3654),
-    ("RTRadiationSetUsage", 3745),
-    ("RTRadiationTaskSequence", 3798),
-    ("RTRadiationUsage", 3739),
-    ("RTRecordFlag", 3656),
-    ("RTReferencedSeriesSequence", 3058),
-    ("RTReferencedStudySequence", 3057),
-    ("RTRelatedROISequence", 3070),
-    ("RTSegmentAnnotationIndex", 3918),
-    ("RTSegmentAnnotationSequence", 3899),
-    ("RTToleranceSetLabel", 3643),
-    ("RTToleranceSetSequence", 3642),
-    ("RTTreatmentApproachLabel", 3943),
-    ("RTTreatmentFractionCompletionStatus", 3744),
-    ("RTTreatmentIntentType", 3946),
-    ("RTTreatmentPhaseIndex", 3915),
-    ("RTTreatmentPhaseIntentPresenceFlag", 3926),
-    ("RTTreatmentPhaseIntervalSequence", 3935),
-    ("RTTreatmentPhaseUID", 3916),
-    ("RTTreatmentPreparationPatientPositionSequence", 3785),
-    ("RTTreatmentSourceCoordinates", 3996),
-    ("RTTreatmentTechniqueCodeSequence", 3984),
-    ("RTTreatmentTerminationReasonCodeSequence", 3749),
-    ("RTTreatmentTerminationStatus", 3748),
-    ("RTVCommunicationSOPClassUID", 4054),
-    ("RTVCommunicationSOPInstanceUID", 4055),
-    ("RTVFlowActualFrameDuration", 4059),
-    ("RTVFlowIdentifier", 4057),
-    ("RTVFlowRTPSamplingRate", 4058),
-    ("RTVMetaInformationVersion", 4053),
-    ("RTVSourceIdentifier", 4056),
-    ("RWavePointer", 1749),
-    ("RWaveTimeVector", 762),
-    ("RadialPosition", 554),
-    ("RadiationAtomicNumber", 3498),
-    ("RadiationBeamBlockSlabThickness", 3708),
-    ("RadiationBeamBlockThickness", 3707),
-    ("RadiationBeamCompensatorMillingToolDiameter", 3703),
-    ("RadiationBeamEffectiveWedgeAngle", 3682),
-    ("RadiationBeamWedgeAngle", 3680),
-    ("RadiationBeamWedgeThinEdgeDistance", 3681),
-    ("RadiationChargeState", 3499),
-    ("RadiationDeviceConfigurationAndCommissioningKeySequence", 3688),
-    ("RadiationDoseCentralAxisDisplacement", 3637),
-    ("RadiationDoseIdentificationIndex", 3604),
-    ("RadiationDoseIdentificationLabel", 3626),
-    ("RadiationDoseIdentificationSequence", 3625),
-    ("RadiationDoseInVivoMeasurementLabel", 3636),
-    ("RadiationDoseMeasurementPointCoordinates", 3640),
-    ("RadiationDoseSequence", 3624),
-    ("RadiationDoseSourceToExternalContourDistance", 3641),
-    ("RadiationDoseSourceToSkinDistance", 3639),
-    ("RadiationDoseValue", 3638),
-    ("RadiationDoseValuesParametersSequence", 3632),
-    ("RadiationDosimeterUnitSequence", 3686),
-    ("RadiationFluenceModifierCodeSequence", 3729),
-    ("RadiationGenerationModeDescription", 3723),
-    ("RadiationGenerationModeIndex", 3602),
-    ("RadiationGenerationModeLabel", 3722),
-    ("RadiationGenerationModeMachineCodeSequence", 3724),
-    ("RadiationGenerationModeSequence", 3721),
-    ("RadiationMachineName", 2960),
-    ("RadiationMachineSAD", 2961),
-    ("RadiationMachineSSD", 2962),
-    ("RadiationMassNumber", 3497),
-    ("RadiationMode", 567),
-    ("RadiationOrderIndex", 3781),
-    ("RadiationSetting", 565),
-    ("RadiationSourceAxisDistance", 3663),
-    ("RadiationSourceCoordinateSystemPitchAngle", 3999),
-    ("RadiationSourceCoordinateSystemRollAngle", 3998),
-    ("RadiationSourceCoordinateSystemYawAngle", 3997),
-    ("RadiationType", 3288),
-    ("RadiationTypeCodeSequence", 3725),
-    ("RadiobiologicalDoseEffectFlag", 3861),
-    ("RadiobiologicalDoseEffectSequence", 3860),
-    ("RadionuclideCodeSequence", 2263),
-    ("RadionuclideHalfLife", 524),
-    ("RadionuclidePositronFraction", 525),
-    ("RadionuclideTotalDose", 523),
-    ("Radiopharmaceutical", 443),
-    ("RadiopharmaceuticalAdministrationEventUID", 208),
-    ("RadiopharmaceuticalAgentNumber", 1166),
-    ("RadiopharmaceuticalCodeSequence", 2265),
-    ("RadiopharmaceuticalInformationSequence", 2227),
-    ("RadiopharmaceuticalRoute", 519),
-    ("RadiopharmaceuticalSpecificActivity", 526),
-    ("RadiopharmaceuticalStartDateTime", 527),
-    ("RadiopharmaceuticalStartTime", 521),
-    ("RadiopharmaceuticalStopDateTime", 528),
-    ("RadiopharmaceuticalStopTime", 522),
-    ("RadiopharmaceuticalUsageSequence", 1172),
-    ("RadiopharmaceuticalVolume", 520),
-    ("RadiotherapyTreatmentType", 3927),
-    ("RadiusOfCircularCollimator", 680),
-    ("RadiusOfCircularExposureControlSensingRegion", 1076),
-    ("RadiusOfCircularShutter", 660),
-    ("RadiusOfCurvature", 2080),
-    ("RandomsCorrected", 1187),
-    ("RandomsCorrectionMethod", 2279),
-    ("RangeMatchingSequence", 125),
-    ("RangeModulatorDescription", 3524),
-    ("RangeModulatorGatingStartValue", 3541),
-    ("RangeModulatorGatingStartWaterEquivalentThickness", 3543),
-    ("RangeModulatorGatingStopValue", 3542),
-    ("RangeModulatorGatingStopWaterEquivalentThickness", 3544),
-    ("RangeModulatorID", 3522),
-    ("RangeModulatorNumber", 3521),
-    ("RangeModulatorSequence", 3520),
-    ("RangeModulatorSettingsSequence", 3540),
-    ("RangeModulatorType", 3523),
-    ("RangeOfFreedom", 2450),
-    ("RangeShifterDescription", 3511),
-    ("RangeShifterID", 3509),
-    ("RangeShifterNumber", 3508),
-    ("RangeShifterSequence", 3507),
-    ("RangeShifterSetting", 3534),
-    ("RangeShifterSettingsSequence", 3533),
-    ("RangeShifterType", 3510),
-    ("RangeShifterWaterEquivalentThickness", 3536),
-    ("RangingDepth", 2202),
-    ("RationalDenominatorValue", 1993),
-    ("RationalNumeratorValue", 1992),
-    ("RealTimeBulkDataFlowSequence", 1800),
-    ("RealWorldValueFirstValueMapped", 1959),
-    ("RealWorldValueIntercept", 1961),
-    ("RealWorldValueLUTData", 1956),
-    ("RealWorldValueLastValueMapped", 1955),
-    ("RealWorldValueMappingSequence", 1952),
-    ("RealWorldValueSlope", 1962),
-    ("ReasonForChannelOmission", 2826),
-    ("ReasonForChannelOmissionDescription", 2827),
-    ("ReasonForOmission", 3838),
-    ("ReasonForOmissionCodeSequence", 3783),
-    ("ReasonForOmissionDescription", 3839),
-    ("ReasonForPerformedProcedureCodeSequence", 1936),
-    ("ReasonForRemovalCodeSequence", 115),
-    ("ReasonForRequestedProcedureCodeSequence", 1935),
-    ("ReasonForSuperseding", 3949),
-    ("ReasonForTheAttributeModification", 2941),
-    ("ReasonForTheRequestedProcedure", 1934),
-    ("ReasonForVisit", 1790),
-    ("ReasonForVisitCodeSequence", 1791),
-    ("ReceiveCoilManufacturerName", 839),
-    ("ReceiveCoilName", 614),
-    ("ReceiveCoilType", 841),
-    ("ReceivingApplicationEntityTitle", 4049),
-    ("ReceivingPresentationAddress", 4052),
-    ("RecognizableVisualFeatures", 1682),
-    ("RecommendedAbsentPixelCIELabValue", 2155),
-    ("RecommendedAnimationRate", 2653),
-    ("RecommendedDefaultValueSequence", 2905),
-    ("RecommendedDisplayCIELabValue", 2319),
-    ("RecommendedDisplayCIELabValueList", 2386),
-    ("RecommendedDisplayFrameRate", 200),
-    ("RecommendedDisplayFrameRateInFloat", 224),
-    ("RecommendedDisplayGrayscaleValue", 2318),
-    ("RecommendedExposureIndex", 344),
-    ("RecommendedLineThickness", 2379),
-    ("RecommendedPointRadius", 2378),
-    ("RecommendedPresentationOpacity", 2343),
-    ("RecommendedPresentationType", 2344),
-    ("RecommendedRotationPoint", 2426),
-    ("RecommendedViewingMode", 1696),
-    ("ReconstructionAlgorithm", 980),
-    ("ReconstructionAlgorithmSequence", 1280),
-    ("ReconstructionAngle", 984),
-    ("ReconstructionDescription", 1120),
-    ("ReconstructionDiameter", 539),
-    ("ReconstructionEndLocationSequence", 1279),
-    ("ReconstructionFieldOfView", 982),
-    ("ReconstructionIndex", 1379),
-    ("ReconstructionMethod", 2282),
-    ("ReconstructionPipelineType", 601),
-    ("ReconstructionPixelSpacing", 987),
-    ("ReconstructionProtocolElementSequence", 1271),
-    ("ReconstructionProtocolElementSpecificationSequence", 1270),
-    ("ReconstructionStartLocationSequence", 1278),
-    ("ReconstructionTargetCenterLocationSequence", 1281),
-    ("ReconstructionTargetCenterPatient", 983),
-    ("ReconstructionType", 1179),
-    ("RecordInUseFlag", 8),
-    ("RecordedBlockSequence", 3163),
-    ("RecordedBlockSlabSequence", 3164),
-    ("RecordedBrachyAccessoryDeviceSequence", 3174),
-    ("RecordedChannelSequence", 3176),
-    ("RecordedChannelShieldSequence", 3185),
-    ("RecordedCompensatorSequence", 3162),
-    ("RecordedLateralSpreadingDeviceSequence", 3168),
-    ("RecordedRTControlPointDateTime", 3760),
-    ("RecordedRangeModulatorSequence", 3169),
-    ("RecordedRangeShifterSequence", 3167),
-    ("RecordedSnoutSequence", 3166),
-    ("RecordedSourceApplicatorSequence", 3183),
-    ("RecordedSourceSequence", 3170),
-    ("RecordedWedgeSequence", 3161),
-    ("RectificationType", 566),
-    ("RectilinearPhaseEncodeReordering", 835),
-    ("RedPaletteColorLookupTableData", 1702),
-    ("RedPaletteColorLookupTableDescriptor", 1697),
-    ("ReferenceAirKermaRate", 3432),
-    ("ReferenceBasisCodeSequence", 1232),
-    ("ReferenceCoordinates", 1416),
-    ("ReferenceDisplaySets", 2750),
-    ("ReferenceDoseDefinition", 3600),
-    ("ReferenceDosePointCoordinates", 3631),
-    ("ReferenceDoseType", 3627),
-    ("ReferenceGeometryCodeSequence", 1233),
-    ("ReferenceImageNumber", 3290),
-    ("ReferenceLocationDescription", 1231),
-    ("ReferenceLocationLabel", 1230),
-    ("ReferencePixelPhysicalValueX", 735),
-    ("ReferencePixelPhysicalValueY", 736),
-    ("ReferencePixelX0", 731),
-    ("ReferencePixelY0", 732),
-    ("ReferencedAssertionUID", 2052),
-    ("ReferencedBaselineParametersRTRadiationInstanceSequence", 3005),
-    ("ReferencedBeamNumber", 3808),
-    ("ReferencedBeamSequence", 3807),
-    ("ReferencedBlockNumber", 3829),
-    ("ReferencedBolusSequence", 3826),
-    ("ReferencedBrachyAccessoryDeviceNumber", 3175),
-    ("ReferencedBrachyApplicationSetupNumber", 3813),
-    ("ReferencedBrachyApplicationSetupSequence", 3812),
-    ("ReferencedCalculatedDoseReferenceNumber", 3155),
-    ("ReferencedCalculatedDoseReferenceSequence", 3154),
-    ("ReferencedChannelNumber", 2822),
-    ("ReferencedChannelShieldNumber", 3186),
-    ("ReferencedColorPaletteInstanceUID", 1684),
-    ("ReferencedComparisonSOPInstanceSequence", 2888),
-    ("ReferencedCompensatorNumber", 3828),
-    ("ReferencedConceptualVolumeUID", 3870),
-    ("ReferencedContentItem", 2649),
-    ("ReferencedContentItemIdentifier", 2025),
-    ("ReferencedControlPointIndex", 3830),
-    ("ReferencedControlPointSequence", 3831),
-    ("ReferencedDateTime", 1989),
-    ("ReferencedDefinedDeviceIndex", 3603),
-    ("ReferencedDefinedProtocolSequence", 1242),
-    ("ReferencedDeviceIndex", 3608),
-    ("ReferencedDigitalSignatureSequence", 2928),
-    ("ReferencedDirectSegmentInstanceSequence", 3931),
-    ("ReferencedDoseReferenceNumber", 3820),
-    ("ReferencedDoseReferenceSequence", 3819),
-    ("ReferencedDoseReferenceUID", 3257),
-    ("ReferencedDoseSequence", 3824),
-    ("ReferencedDosimetricObjectiveUID", 3968),
-    ("ReferencedDosimetricObjectivesSequence", 3970),
-    ("ReferencedExpectedInVivoMeasurementValueIndex", 3775),
-    ("ReferencedFiducialUID", 2588),
-    ("ReferencedFiducialsUID", 3906),
-    ("ReferencedFileID", 12),
-    ("ReferencedFirstFrameSequence", 2771),
-    ("ReferencedFractionGroupNumber", 3816),
-    ("ReferencedFractionGroupSequence", 3815),
-    ("ReferencedFractionNumber", 3198),
-    ("ReferencedFrameNumber", 173),
-    ("ReferencedFrameOfReferenceSequence", 3056),
-    ("ReferencedFrameOfReferenceUID", 3063),
-    ("ReferencedHPGLDocumentID", 2445),
-    ("ReferencedImageEvidenceSequence", 210),
-    ("ReferencedImageRealWorldValueMappingSequence", 1951),
-    ("ReferencedImageSequence", 167),
-    ("ReferencedImplantTemplateGroupMemberID", 2870),
-    ("ReferencedInstanceSequence", 168),
-    ("ReferencedLateralSpreadingDeviceNumber", 3835),
-    ("ReferencedMeasuredDoseReferenceNumber", 3153),
-    ("ReferencedMeasuredDoseReferenceSequence", 3152),
-    ("ReferencedOphthalmicAxialLengthMeasurementQCImageSequence", 1500),
-    ("ReferencedOpticalPathIdentifier", 2487),
-    ("ReferencedOtherPlaneSequence", 222),
-    ("ReferencedParentRTPrescriptionIndex", 3923),
-    ("ReferencedPathIndex", 1035),
-    ("ReferencedPatientPhotoSequence", 262),
-    ("ReferencedPatientSequence", 164),
-    ("ReferencedPatientSetupNumber", 3823),
-    ("ReferencedPatientSetupPhotoSequence", 3787),
-    ("ReferencedPatientSetupProcedureIndex", 3797),
-    ("ReferencedPerformedProcedureStepSequence", 162),
-    ("ReferencedPerformedProtocolSequence", 1243),
-    ("ReferencedPlanOverviewIndex", 3844),
-    ("ReferencedPositionReferenceInstanceSequence", 3025),
-    ("ReferencedPresentationStateSequence", 221),
-    ("ReferencedROINumber", 3089),
-    ("ReferencedRTAccessoryHolderDeviceIndex", 3615),
-    ("ReferencedRTInstanceSequence", 3650),
-    ("ReferencedRTPhysicianIntentIndex", 3951),
-    ("ReferencedRTPhysicianIntentSequence", 3658),
-    ("ReferencedRTPlanSequence", 3806),
-    ("ReferencedRTPrescriptionIndex", 3922),
-    ("ReferencedRTPrescriptionSequence", 3736),
-    ("ReferencedRTRadiationRecordSequence", 3741),
-    ("ReferencedRTRadiationSequence", 3649),
-    ("ReferencedRTRadiationSetSequence", 3740),
-    ("ReferencedRTTreatmentPhaseIndex", 3921),
-    ("ReferencedRTTreatmentPhaseSequence", 3930),
-    ("ReferencedRTTreatmentPreparationSequence", 3786),
-    ("ReferencedRadiationDoseIdentificationIndex", 3613),
-    ("ReferencedRadiationGenerationModeIndex", 3606),
-    ("ReferencedRadiationRTControlPointIndex", 3761),
-    ("ReferencedRangeModulatorNumber", 3836),
-    ("ReferencedRangeShifterNumber", 3834),
-    ("ReferencedRawDataSequence", 211),
-    ("ReferencedRealWorldValueMappingInstanceSequence", 169),
-    ("ReferencedReferenceImageNumber", 3809),
-    ("ReferencedReferenceImageSequence", 3818),
-    ("ReferencedRefractiveMeasurementsSequence", 2111),
-    ("ReferencedRelatedGeneralSOPClassUIDInFile", 16),
-    ("ReferencedRequestSequence", 2003),
-    ("ReferencedSOPClassUID", 170),
-    ("ReferencedSOPClassUIDInFile", 13),
-    ("ReferencedSOPInstanceMACSequence", 2929),
-    ("ReferencedSOPInstanceUID", 171),
-    ("ReferencedSOPInstanceUIDInFile", 14),
-    ("ReferencedSOPSequence", 181),
-    ("ReferencedSamplePositions", 1987),
-    ("ReferencedSegmentNumber", 2317),
-    ("ReferencedSegmentReferenceIndex", 3890),
-    ("ReferencedSeriesSequence", 163),
-    ("ReferencedSetupImageSequence", 3564),
-    ("ReferencedSourceApplicatorNumber", 3184),
-    ("ReferencedSourceNumber", 3814),
-    ("ReferencedSpatialRegistrationSequence", 2595),
-    ("ReferencedStartControlPointIndex", 3832),
-    ("ReferencedStereometricInstanceSequence", 165),
-    ("ReferencedStopControlPointIndex", 3833),
-    ("ReferencedStructureSetSequence", 3822),
-    ("ReferencedStructuredContextSequence", 2648),
-    ("ReferencedStudySequence", 161),
-    ("ReferencedSurfaceDataSequence", 2884),
-    ("ReferencedSurfaceMeshIdentificationSequence", 1535),
-    ("ReferencedSurfaceNumber", 2368),
-    ("ReferencedSurfaceSequence", 2367),
-    ("ReferencedTextureSequence", 2883),
-    ("ReferencedTimeOffsets", 1988),
-    ("ReferencedToleranceTableNumber", 3825),
-    ("ReferencedTransferSyntaxUIDInFile", 15),
-    ("ReferencedTreatmentPositionGroupUID", 3780),
-    ("ReferencedTreatmentPositionIndex", 3612),
-    ("ReferencedTreatmentRecordSequence", 3116),
-    ("ReferencedVerificationImageSequence", 3817),
-    ("ReferencedWaveformChannels", 1980),
-    ("ReferencedWaveformSequence", 166),
-    ("ReferencedWedgeNumber", 3827),
-    ("ReferencedXRayDetectorIndex", 1033),
-    ("ReferencedXRaySourceIndex", 1034),
-    ("ReferringPhysicianIdentificationSequence", 58),
-    ("ReferringPhysicianName", 57),
-    ("ReflectedAmbientLight", 2944),
-    ("ReformattingInterval", 2778),
-    ("ReformattingOperationInitialViewDirection", 2779),
-    ("ReformattingOperationType", 2776),
-    ("ReformattingThickness", 2777),
-    ("RefractiveErrorBeforeRefractiveSurgeryCodeSequence", 1468),
-    ("RefractiveIndexApplied", 2220),
-    ("RefractiveIndexOfAqueousHumor", 2102),
-    ("RefractiveIndexOfCornea", 2101),
-    ("RefractiveParametersUsedOnPatientSequence", 1630),
-    ("RefractivePower", 2141),
-    ("RefractiveProcedureOccurred", 1445),
-    ("RefractiveStateSequence", 1406),
-    ("RefractiveSurgeryTypeCodeSequence", 1446),
-    ("RegionDataType", 725),
-    ("RegionFlags", 726),
-    ("RegionLocationMaxX1", 729),
-    ("RegionLocationMaxY1", 730),
-    ("RegionLocationMinX0", 727),
-    ("RegionLocationMinY0", 728),
-    ("RegionPixelShiftSequence", 1779),
-    ("RegionSpatialFormat", 724),
-    ("RegisteredLocalizerBottomRightHandCorner", 1516),
-    ("RegisteredLocalizerTopLeftHandCorner", 1515),
-    ("RegisteredLocalizerUnits", 1514),
-    ("RegistrationMethodCodeSequence", 2874),
-    ("RegistrationSequence", 2574),
-    ("RegistrationToLocalizerSequence", 1513),
-    ("RegistrationTypeCodeSequence", 2579),
-    ("RelatedAssertionSequence", 2051),
-    ("RelatedGeneralSOPClassUID", 29),
-    ("RelatedRTROIObservationsSequence", 3091),
-    ("RelatedRTTreatmentPhaseIndex", 3920),
-    ("RelatedReferenceRTImageSequence", 2811),
-    ("RelatedSeriesSequence", 183),
-    ("RelationshipType", 1963),
-    ("RelativeElevation", 2142),
-    ("RelativeImagePositionCodeSequence", 1408),
-    ("RelativeOpacity", 2594),
-    ("RelativeTime", 2689),
-    ("RelativeTimeUnits", 2690),
-    ("RelativeURIReferenceWithinEncapsulatedDocument", 2474),
-    ("RelativeXRayExposure", 635),
-    ("RelevantOPTAttributesSequence", 1518),
-    ("RemovedFromOperationalUse", 114),
-    ("RenderFieldOfView", 2633),
-    ("RenderProjection", 2629),
-    ("RenderedImageReferenceSequence", 2600),
-    ("RenderingMethod", 2611),
-    ("RepeatFractionCycleLength", 3254),
-    ("RepetitionTime", 459),
-    ("ReplacedImplantAssemblyTemplateSequence", 2834),
-    ("ReplacedImplantTemplateGroupSequence", 2858),
-    ("ReplacedImplantTemplateSequence", 2402),
-    ("ReportedValuesOrigin", 2953),
-    ("RepositoryUniqueID", 2036),
-    ("RepresentativeFrameNumber", 1745),
-    ("ReprojectionMethod", 2277),
-    ("RequestAttributesSequence", 1897),
-    ("RequestedProcedureCodeSequence", 1789),
-    ("RequestedProcedureDescription", 1788),
-    ("RequestedProcedureID", 1933),
-    ("RequestedSeriesDescription", 1274),
-    ("RequestedSeriesDescriptionCodeSequence", 604),
-    ("RequestingService", 1786),
-    ("RequestingServiceCodeSequence", 1787),
-    ("RescaleIntercept", 1691),
-    ("RescaleSlope", 1692),
-    ("RescaleType", 1693),
-    ("ResidualSyringeCounts", 2228),
-    ("ResonantNucleus", 887),
-    ("ResourceDescription", 1811),
-    ("RespiratoryCyclePosition", 931),
-    ("RespiratoryIntervalTime", 1355),
-    ("RespiratoryMotionCompensationTechnique", 910),
-    ("RespiratoryMotionCompensationTechniqueDescription", 925),
-    ("RespiratorySignalSource", 911),
-    ("RespiratorySignalSourceID", 926),
-    ("RespiratorySynchronizationSequence", 1354),
-    ("RespiratoryTriggerDelayThreshold", 1357),
-    ("RespiratoryTriggerType", 1351),
-    ("ResponsibleGroupCodeSequence", 89),
-    ("ResponsibleOrganization", 282),
-    ("ResponsiblePerson", 280),
-    ("ResponsiblePersonRole", 281),
-    ("ResultsNormalsSequence", 1588),
-    ("RetestSensitivityValue", 1618),
-    ("RetestStimulusSeen", 1617),
-    ("RetinalThicknessDefinitionCodeSequence", 1506),
-    ("RetrieveAETitle", 45),
-    ("RetrieveLocationUID", 2029),
-    ("RetrieveURI", 2028),
-    ("RetrieveURL", 179),
-    ("ReviewDate", 3857),
-    ("ReviewTime", 3858),
-    ("ReviewerName", 3859),
-    ("RevolutionTime", 970),
-    ("RightImageSequence", 1412),
-    ("RightLensSequence", 2056),
-    ("RoboticNodeIdentifier", 3995),
-    ("RoboticPathControlPointSequence", 4000),
-    ("RoboticPathNodeSetCodeSequence", 3994),
-    ("RotationAngle", 2536),
-    ("RotationDirection", 553),
-    ("RotationInformationSequence", 2242),
-    ("RotationOfScannedFilm", 694),
-    ("RotationPoint", 2561),
-    ("RotationVector", 2240),
-    ("RowPositionInTotalImagePixelMatrix", 2174),
-    ("Rows", 1658),
-    ("SAR", 627),
-    ("SOPAuthorizationComment", 2914),
-    ("SOPAuthorizationDateTime", 2913),
-    ("SOPClassUID", 25),
-    ("SOPInstanceStatus", 2912),
-    ("SOPInstanceUID", 27),
-    ("SOPInstanceUIDOfConcatenationSource", 1309),
-    ("STOWRSStorageSequence", 1945),
-    ("SUVType", 2278),
-    ("SafePositionExitDate", 3188),
-    ("SafePositionExitTime", 3189),
-    ("SafePositionReturnDate", 3190),
-    ("SafePositionReturnTime", 3191),
-    ("SamplesPerPixel", 1651),
-    ("SamplesPerPixelUsed", 1652),
-    ("SamplingFrequency", 1816),
-    ("SamplingStepSize", 2634),
-    ("Saturation", 380),
-    ("SaturationRecovery", 826),
-    ("ScanArc", 555),
-    ("ScanArcType", 3021),
-    ("ScanLength", 622),
-    ("ScanMode", 3500),
-    ("ScanOptions", 434),
-    ("ScanPatternTypeCodeSequence", 1534),
-    ("ScanProgressionDirection", 2273),
-    ("ScanSpotMetersetWeights", 3553),
-    ("ScanSpotMetersetsDelivered", 3129),
-    ("ScanSpotPositionMap", 3551),
-    ("ScanSpotPrescribedIndices", 3548),
-    ("ScanSpotReordered", 3550),
-    ("ScanSpotReorderingAllowed", 3552),
-    ("ScanSpotSizesDelivered", 3555),
-    ("ScanSpotTimeOffset", 3546),
-    ("ScanSpotTuneID", 3547),
-    ("ScanStartPositionSequence", 3018),
-    ("ScanStopPositionSequence", 3019),
-    ("ScanVelocity", 620),
-    ("ScanningSequence", 432),
-    ("ScanningSpotSize", 3554),
-    ("ScatterCorrected", 1182),
-    ("ScatterCorrectionMethod", 2284),
-    ("ScatterFractionFactor", 2297),
-    ("SceneCaptureType", 377),
-    ("SceneType", 371),
-    ("ScheduledProcedureStepDescription", 1876),
-    ("ScheduledProcedureStepID", 1878),
-    ("ScheduledProtocolCodeSequence", 1877),
-    ("ScopeOfInventorySequence", 109),
-    ("ScreenMinimumColorBitDepth", 2738),
-    ("ScreenMinimumGrayscaleBitDepth", 2737),
-    ("ScreeningBaselineMeasured", 1636),
-    ("ScreeningBaselineMeasuredSequence", 1637),
-    ("ScreeningBaselineType", 1638),
-    ("ScreeningBaselineValue", 1639),
-    ("ScreeningTestModeCodeSequence", 1553),
-    ("SeamLineIndex", 2217),
-    ("SeamLineLocation", 2215),
-    ("SecondaryCaptureDeviceID", 485),
-    ("SecondaryCaptureDeviceManufacturer", 488),
-    ("SecondaryCaptureDeviceManufacturerModelName", 489),
-    ("SecondaryCaptureDeviceSoftwareVersions", 490),
-    ("SecondaryCountsAccumulated", 2293),
-    ("SecondaryCountsType", 2290),
-    ("SecondaryPositionerIncrement", 1109),
-    ("SecondaryPositionerIncrementSign", 1113),
-    ("SecondaryPositionerScanArc", 1105),
-    ("SecondaryPositionerScanStartAngle", 1107),
-    ("SegmentAlgorithmName", 2315),
-    ("SegmentAlgorithmType", 2314),
-    ("SegmentAnnotationCategoryCodeSequence", 3900),
-    ("SegmentAnnotationTypeCodeSequence", 3901),
-    ("SegmentAnnotationTypeModifierCodeSequence", 3904),
-    ("SegmentCharacteristicsPrecedence", 3898),
-    ("SegmentCharacteristicsSequence", 3897),
-    ("SegmentDescription", 2312),
-    ("SegmentIdentificationSequence", 2316),
-    ("SegmentLabel", 2311),
-    ("SegmentNumber", 2310),
-    ("SegmentReferenceIndex", 3892),
-    ("SegmentReferenceSequence", 3891),
-    ("SegmentSequence", 2308),
-    ("SegmentSurfaceGenerationAlgorithmIdentificationSequence", 2369),
-    ("SegmentSurfaceSourceInstanceSequence", 2370),
-    ("SegmentationAlgorithmIdentificationSequence", 2313),
-    ("SegmentationCreationTemplateLabel", 3889),
-    ("SegmentationFractionalType", 2322),
-    ("SegmentationType", 2307),
-    ("SegmentedAlphaPaletteColorLookupTableData", 1709),
-    ("SegmentedBluePaletteColorLookupTableData", 1708),
-    ("SegmentedGreenPaletteColorLookupTableData", 1707),
-    ("SegmentedKSpaceTraversal", 834),
-    ("SegmentedPropertyCategoryCodeSequence", 2309),
-    ("SegmentedPropertyTypeCodeSequence", 2321),
-    ("SegmentedPropertyTypeModifierCodeSequence", 2323),
-    ("SegmentedRTAccessoryDeviceSequence", 3896),
-    ("SegmentedRedPaletteColorLookupTableData", 1706),
-    ("SegmentsOverlap", 2325),
-    ("SelectedFrameFunctionalGroupsSequence", 2976),
-    ("SelectedFrameNumber", 2975),
-    ("SelectedSegmentalOphthalmicAxialLengthSequence", 1495),
-    ("SelectedTotalOphthalmicAxialLengthSequence", 1496),
-    ("SelectorAEValue", 2698),
-    ("SelectorASValue", 2699),
-    ("SelectorATValue", 2700),
-    ("SelectorAttribute", 2684),
-    ("SelectorAttributeKeyword", 2898),
-    ("SelectorAttributeName", 2897),
-    ("SelectorAttributePrivateCreator", 2697),
-    ("SelectorAttributeVR", 2694),
-    ("SelectorCSValue", 2702),
-    ("SelectorCodeSequenceValue", 2728),
-    ("SelectorDAValue", 2701),
-    ("SelectorDSValue", 2718),
-    ("SelectorDTValue", 2703),
-    ("SelectorFDValue", 2720),
-    ("SelectorFLValue", 2722),
-    ("SelectorISValue", 2704),
-    ("SelectorLOValue", 2706),
-    ("SelectorLTValue", 2708),
-    ("SelectorOBValue", 2705),
-    ("SelectorODValue", 2719),
-    ("SelectorOFValue", 2707),
-    ("SelectorOLValue", 2721),
-    ("SelectorOVValue", 2729),
-    ("SelectorOWValue", 2709),
-    ("SelectorPNValue", 2710),
-    ("SelectorSHValue", 2712),
-    ("SelectorSLValue", 2725),
-    ("SelectorSSValue", 2726),
-    ("SelectorSTValue", 2714),
-    ("SelectorSVValue", 2730),
-    ("SelectorSequencePointer", 2695),
-    ("SelectorSequencePointerItems", 2812),
-    ("SelectorSequencePointerPrivateCreator", 2696),
-    ("SelectorTMValue", 2711),
-    ("SelectorUCValue", 2715),
-    ("SelectorUIValue", 2727),
-    ("SelectorULValue", 2723),
-    ("SelectorUNValue", 2713),
-    ("SelectorURValue", 2717),
-    ("SelectorUSValue", 2724),
-    ("SelectorUTValue", 2716),
-    ("SelectorUVValue", 2731),
-    ("SelectorValueNumber", 2685),
-    ("SelfTimerMode", 341),
-    ("SendingApplicationEntityTitle", 4048),
-    ("SendingPresentationAddress", 4051),
-    ("SensingMethod", 369),
-    ("Sensitivity", 722),
-    ("SensitivityCalibrated", 1189),
-    ("SensitivityType", 342),
-    ("SensitivityValue", 1616),
-    ("SequenceName", 436),
-    ("SequenceOfUltrasoundRegions", 723),
-    ("SequenceVariant", 433),
-    ("SeriesDate", 33),
-    ("SeriesDescription", 145),
-    ("SeriesDescriptionCodeSequence", 146),
-    ("SeriesInstanceUID", 1293),
-    ("SeriesNumber", 1295),
-    ("SeriesTime", 38),
-    ("SeriesType", 2274),
-    ("ServiceEpisodeDescription", 1807),
-    ("ServiceEpisodeID", 1806),
-    ("SettlingPhaseFrame", 1151),
-    ("SetupDeviceDescription", 3410),
-    ("SetupDeviceLabel", 3409),
-    ("SetupDeviceParameter", 3411),
-    ("SetupDeviceSequence", 3407),
-    ("SetupDeviceType", 3408),
-    ("SetupImageComment", 3565),
-    ("SetupReferenceDescription", 3412),
-    ("SetupTechnique", 3405),
-    ("SetupTechniqueDescription", 3406),
-    ("ShadingStyle", 2635),
-    ("ShadowColorCIELabValue", 2547),
-    ("ShadowOffsetX", 2545),
-    ("ShadowOffsetY", 2546),
-    ("ShadowOpacity", 2558),
-    ("ShadowStyle", 2544),
-    ("ShapeType", 2573),
-    ("SharedFunctionalGroupsSequence", 4006),
-    ("Sharpness", 381),
-    ("ShieldingDeviceDescription", 3403),
-    ("ShieldingDeviceLabel", 3402),
-    ("ShieldingDevicePosition", 3404),
-    ("ShieldingDeviceSequence", 3400),
-    ("ShieldingDeviceType", 3401),
-    ("Shininess", 2640),
-    ("ShortTermFluctuation", 1599),
-    ("ShortTermFluctuationCalculated", 1598),
-    ("ShortTermFluctuationProbability", 1601),
-    ("ShortTermFluctuationProbabilityCalculated", 1600),
-    ("ShotDurationTime", 2875),
-    ("ShotOffsetTime", 2876),
-    ("ShowAcquisitionTechniquesFlag", 2792),
-    ("ShowGraphicAnnotationFlag", 2790),
-    ("ShowGrayscaleInverted", 2788),
-    ("ShowImageTrueSizeFlag", 2789),
-    ("ShowPatientDemographicsFlag", 2791),
-    ("ShowTickLabel", 2563),
-    ("ShutterLeftVerticalEdge", 655),
-    ("ShutterLowerHorizontalEdge", 658),
-    ("ShutterOverlayGroup", 663),
-    ("ShutterPresentationColorCIELabValue", 664),
-    ("ShutterPresentationValue", 662),
-    ("ShutterRightVerticalEdge", 656),
-    ("ShutterShape", 654),
-    ("ShutterSpeedValue", 349),
-    ("ShutterUpperHorizontalEdge", 657),
-    ("SignalDomainColumns", 1761),
-    ("SignalDomainRows", 1766),
-    ("SignalToNoiseRatio", 1485),
-    ("Signature", 2924),
-    ("SimpleFrameList", 174),
-    ("SimulatedKeratometricCylinderSequence", 2126),
-    ("SingleCollimationWidth", 971),
-    ("SkipBeats", 535),
-    ("SkipFrameRangeFlag", 225),
-    ("SlabOrientation", 891),
-    ("SlabThickness", 890),
-    ("SliceLocation", 1313),
-    ("SliceLocationVector", 690),
-    ("SliceProgressionDirection", 2272),
-    ("SliceSensitivityFactor", 2294),
-    ("SliceThickness", 451),
-    ("SliceVector", 2252),
-    ("SmallestImagePixelValue", 1670),
-    ("SmallestPixelValueInSeries", 1672),
-    ("SmokingStatus", 267),
-    ("SnoutID", 3505),
-    ("SnoutPosition", 3504),
-    ("SnoutPositionTolerance", 3240),
-    ("SnoutSequence", 3503),
-    ("SoftTissueFocusThermalIndex", 716),
-    ("SoftTissueSurfaceThermalIndex", 717),
-    ("SoftTissueThermalIndex", 715),
-    ("SoftcopyVOILUTSequence", 1744),
-    ("SoftwareVersions", 491),
-    ("SortByCategory", 2782),
-    ("SortingDirection", 2783),
-    ("SortingOperationsSequence", 2781),
-    ("SoundSpeedCorrectionMechanismCodeSequence", 1225),
-    ("SourceAcquisitionBeamNumber", 1276),
-    ("SourceAcquisitionProtocolElementNumber", 1275),
-    ("SourceApplicationEntityTitle", 4047),
-    ("SourceApplicatorID", 3464),
-    ("SourceApplicatorLength", 3467),
-    ("SourceApplicatorManufacturer", 3468),
-    ("SourceApplicatorName", 3466),
-    ("SourceApplicatorNumber", 3463),
-    ("SourceApplicatorStepSize", 3471),
-    ("SourceApplicatorTipLength", 3455),
-    ("SourceApplicatorType", 3465),
-    ("SourceApplicatorWallNominalThickness", 3469),
-    ("SourceApplicatorWallNominalTransmission", 3470),
-    ("SourceAxisDistance", 3276),
-    ("SourceConceptualVolumeSequence", 3883),
-    ("SourceConceptualVolumeUID", 3880),
-    ("SourceDescription", 3426),
-    ("SourceEncapsulationNominalThickness", 3427),
-    ("SourceEncapsulationNominalTransmission", 3428),
-    ("SourceEndDateTime", 1021),
-    ("SourceFrameOfReferenceUID", 2329),
-    ("SourceHangingProtocolSequence", 2679),
-    ("SourceIdentifier", 1796),
-    ("SourceImageCornealProcessedDataSequence", 2136),
-    ("SourceImageEvidenceSequence", 214),
-    ("SourceImageSequence", 185),
-    ("SourceInstanceSequence", 2041),
-    ("SourceIrradiationEventSequence", 207),
-    ("SourceIsotopeHalfLife", 3430),
-    ("SourceIsotopeName", 3429),
-    ("SourceManufacturer", 3422),
-    ("SourceModelID", 3425),
-    ("SourceMovementType", 3460),
-    ("SourceNumber", 3420),
-    ("SourceOfAnteriorChamberDepthDataCodeSequence", 1480),
-    ("SourceOfCorneaMeasurementDataCodeSequence", 2095),
-    ("SourceOfCornealSizeDataCodeSequence", 1443),
-    ("SourceOfLensThicknessDataCodeSequence", 1479),
-    ("SourceOfOphthalmicAxialLengthCodeSequence", 1442),
-    ("SourceOfPreviousValues", 2940),
-    ("SourceOfRefractiveMeasurementsCodeSequence", 1482),
-    ("SourceOfRefractiveMeasurementsSequence", 1481),
-    ("SourcePatientGroupIdentificationSequence", 231),
-    ("SourcePixelPlanesCharacteristicsSequence", 3080),
-    ("SourcePresentationAddress", 4050),
-    ("SourceReconstructionProtocolElementNumber", 1277),
-    ("SourceRollAngle", 3720),
-    ("SourceSequence", 3419),
-    ("SourceSerialNumber", 3171),
-    ("SourceSeriesInformationSequence", 3082),
-    ("SourceSeriesSequence", 3081),
-    ("SourceStartDateTime", 1020),
-    ("SourceStrength", 3433),
-    ("SourceStrengthReferenceDate", 3434),
-    ("SourceStrengthReferenceTime", 3435),
-    ("SourceStrengthUnits", 3431),
-    ("SourceToApplicatorMountingPositionDistance", 3580),
-    ("SourceToBeamLimitingDeviceDistance", 3279),
-    ("SourceToBlockTrayDistance", 3330),
-    ("SourceToCompensatorDistance", 3488),
-    ("SourceToCompensatorTrayDistance", 3315),
-    ("SourceToExternalContourDistance", 3372),
-    ("SourceToGeneralAccessoryDistance", 3573),
-    ("SourceToPatientSurfaceDistance", 3651),
-    ("SourceToReferenceObjectDistance", 2964),
-    ("SourceToSurfaceDistance", 3371),
-    ("SourceToWedgeTrayDistance", 3304),
-    ("SourceType", 3421),
-    ("SourceWaveformSequence", 1824),
-    ("SpacingBetweenSlices", 467),
-    ("SpatialFrequencyResponseColumnNames", 328),
-    ("SpatialFrequencyResponseColumns", 327),
-    ("SpatialFrequencyResponseRows", 326),
-    ("SpatialFrequencyResponseValues", 329),
-    ("SpatialLocationsPreserved", 1717),
-    ("SpatialPresaturation", 829),
-    ("SpatialResolution", 505),
-    ("SpatialTransformOfDose", 3032),
-    ("SpecificAbsorptionRateDefinition", 919),
-    ("SpecificAbsorptionRateSequence", 942),
-    ("SpecificAbsorptionRateValue", 921),
-    ("SpecificCharacterSet", 18),
-    ("SpecificCharacterSetOfFileSetDescriptorFile", 2),
-    ("SpecificationSelectionGuidance", 2903),
-    ("SpecifiedChannelTotalTime", 3177),
-    ("SpecifiedMeterset", 3125),
-    ("SpecifiedNumberOfPulses", 3179),
-    ("SpecifiedPrimaryMeterset", 3117),
-    ("SpecifiedPulseRepetitionInterval", 3181),
-    ("SpecifiedSecondaryMeterset", 3118),
-    ("SpecifiedTreatmentTime", 3121),
-    ("SpecimenDescriptionSequence", 1919),
-    ("SpecimenDetailedDescription", 1923),
-    ("SpecimenIdentifier", 1915),
-    ("SpecimenLabelInImage", 2150),
-    ("SpecimenLocalizationContentItemSequence", 1926),
-    ("SpecimenPreparationSequence", 1924),
-    ("SpecimenPreparationStepContentItemSequence", 1925),
-    ("SpecimenReferenceSequence", 2162),
-    ("SpecimenShortDescription", 1922),
-    ("SpecimenTypeCodeSequence", 1921),
-    ("SpecimenUID", 1916),
-    ("SpectralSensitivity", 339),
-    ("SpectralWidth", 850),
-    ("SpectrallySelectedExcitation", 828),
-    ("SpectrallySelectedSuppression", 827),
-    ("SpectroscopyAcquisitionDataColumns", 902),
-    ("SpectroscopyAcquisitionOutOfPlanePhaseSteps", 907),
-    ("SpectroscopyAcquisitionPhaseColumns", 940),
-    ("SpectroscopyAcquisitionPhaseRows", 885),
-    ("SpectroscopyData", 4016),
-    ("SpecularReflectionIntensity", 2639),
-    ("SpherePower", 2112),
-    ("SphericalLensPower", 1386),
-    ("SpiralPitchFactor", 976),
-    ("Spoiling", 819),
-    ("StackID", 1317),
-    ("StageCodeSequence", 1879),
-    ("StageName", 186),
-    ("StageNumber", 187),
-    ("StandardOutputSensitivity", 343),
-    ("StartAcquisitionDateTime", 1110),
-    ("StartAngle", 2257),
-    ("StartCardiacTriggerCountThreshold", 1155),
-    ("StartCumulativeMeterset", 2981),
-    ("StartCumulativeMetersetWeight", 3810),
-    ("StartCumulativeTimeWeight", 2823),
-    ("StartDensityThreshold", 1153),
-    ("StartMeterset", 3150),
-    ("StartRelativeDensityDifferenceThreshold", 1154),
-    ("StartRespiratoryTriggerCountThreshold", 1156),
-    ("StartTrim", 198),
-    ("StartingMetersetValueKnownFlag", 3752),
-    ("StartingRespiratoryAmplitude", 1347),
-    ("StartingRespiratoryPhase", 1348),
-    ("StationAETitle", 46),
-    ("StationName", 142),
-    ("SteadyStatePulseSequence", 820),
-    ("SteepCornealAxisSequence", 2096),
-    ("SteepKeratometricAxisSequence", 2079),
-    ("SteeringAngle", 743),
-    ("StereoBaselineAngle", 1395),
-    ("StereoBaselineDisplacement", 1396),
-    ("StereoHorizontalPixelOffset", 1397),
-    ("StereoPairsPresent", 1413),
-    ("StereoPairsSequence", 1410),
-    ("StereoRotation", 1399),
-    ("StereoVerticalPixelOffset", 1398),
-    ("StimuliRetestingQuantity", 1569),
-    ("StimulusArea", 1558),
-    ("StimulusColorCodeSequence", 1556),
-    ("StimulusPresentationTime", 1559),
-    ("StimulusResults", 1615),
-    ("StopCumulativeMeterset", 2982),
-    ("StopTrim", 199),
-    ("StorageMediaFileSetID", 2909),
-    ("StorageMediaFileSetUID", 2910),
-    ("StorageProtocolElementSequence", 1273),
-    ("StorageProtocolElementSpecificationSequence", 1272),
-    ("StorageURL", 1946),
-    ("StoredInstanceBaseURI", 116),
-    ("StoredInstanceTransferSyntaxUID", 123),
-    ("StoredValueColorRangeSequence", 1710),
-    ("StrainAdditionalInformation", 247),
-    ("StrainCodeSequence", 248),
-    ("StrainDescription", 241),
-    ("StrainNomenclature", 242),
-    ("StrainSource", 246),
-    ("StrainSourceRegistryCodeSequence", 244),
-    ("StrainStockNumber", 243),
-    ("StrainStockSequence", 245),
-    ("StructureSetDate", 3054),
-    ("StructureSetDescription", 3053),
-    ("StructureSetLabel", 3051),
-    ("StructureSetName", 3052),
-    ("StructureSetROISequence", 3061),
-    ("StructureSetTime", 3055),
-    ("StructuredConstraintObservationSequence", 2893),
-    ("StructuredDisplayBackgroundCIELabValue", 2767),
-    ("StructuredDisplayImageBoxSequence", 2769),
-    ("StructuredDisplayTextBoxSequence", 2770),
-    ("StudiesContainingOtherReferencedInstancesSequence", 182),
-    ("StudyAccessEndPointsSequence", 134),
-    ("StudyDate", 32),
-    ("StudyDescription", 143),
-    ("StudyID", 1294),
-    ("StudyInstanceUID", 1292),
-    ("StudyTime", 37),
-    ("StudyUpdateDateTime", 132),
-    ("SubjectArea", 358),
-    ("SubjectDistance", 354),
-    ("SubjectDistanceRange", 383),
-    ("SubjectLocation", 367),
-    ("SubjectRelativePositionInImage", 233),
-    ("SubjectiveRefractionLeftEyeSequence", 2088),
-    ("SubjectiveRefractionRightEyeSequence", 2087),
-    ("SubtaskWorkitemCodeSequence", 3002),
-    ("SubtractionItemID", 1769),
-    ("SummarizedFilterLookupTable", 1861),
-    ("SurfaceAsymmetryIndex", 2131),
-    ("SurfaceComments", 2339),
-    ("SurfaceCount", 2366),
-    ("SurfaceEntryPoint", 3370),
-    ("SurfaceMeshPrimitivesSequence", 2349),
-    ("SurfaceMeshZPixelOffset", 1549),
-    ("SurfaceModelDescriptionSequence", 2429),
-    ("SurfaceModelLabel", 2430),
-    ("SurfaceModelScalingFactor", 2431),
-    ("SurfaceNumber", 2338),
-    ("SurfacePointColorCIELabValueData", 2878),
-    ("SurfacePointPresentationValueData", 2877),
-    ("SurfacePointsNormalsSequence", 2348),
-    ("SurfacePointsSequence", 2347),
-    ("SurfaceProcessing", 2340),
-    ("SurfaceProcessingAlgorithmIdentificationSequence", 2376),
-    ("SurfaceProcessingDescription", 2342),
-    ("SurfaceProcessingRatio", 2341),
-    ("SurfaceRegularityIndex", 2130),
-    ("SurfaceScanAcquisitionTypeCodeSequence", 2872),
-    ("SurfaceScanModeCodeSequence", 2873),
-    ("SurfaceSequence", 2337),
-    ("SurgicalTechnique", 2840),
-    ("SurgicallyInducedAstigmatismSequence", 1448),
-    ("SwitchingPhaseNominalDuration", 1023),
-    ("SwitchingPhaseNumber", 1022),
-    ("SwitchingPhaseTransitionDuration", 1024),
-    ("SwivelRange", 2656),
-    ("SynchronizationChannel", 517),
-    ("SynchronizationFrameOfReferenceUID", 1308),
-    ("SynchronizationTrigger", 516),
-    ("SynchronizedImageBoxList", 2773),
-    ("SynchronizedScrollingSequence", 2746),
-    ("SyntheticData", 31),
-    ("SyringeCounts", 500),
-    ("T2Preparation", 824),
-    ("TIDOffset", 1756),
-    ("TMLinePositionX0", 746),
-    ("TMLinePositionX1", 748),
-    ("TMLinePositionY0", 747),
-    ("TMLinePositionY1", 749),
-    ("TableAngle", 551),
-    ("TableColumnDefinitionSequence", 2021),
-    ("TableColumnNumber", 2019),
-    ("TableCradleTiltAngle", 1095),
-    ("TableFeedPerRotation", 975),
-    ("TableFrameOfReferenceUID", 1373),
-    ("TableHeadTiltAngle", 1094),
-    ("TableHeight", 545),
-    ("TableHorizontalRotationAngle", 1093),
-    ("TableLateralIncrement", 549),
-    ("TableLongitudinalIncrement", 550),
-    ("TableMotion", 547),
-    ("TableOfParameterValues", 761),
-    ("TableOfPixelValues", 760),
-    ("TableOfXBreakPoints", 757),
-    ("TableOfYBreakPoints", 758),
-    ("TablePosition", 991),
-    ("TablePositionSequence", 1053),
-    ("TableRowDefinitionSequence", 2020),
-    ("TableRowNumber", 2018),
-    ("TableSpeed", 974),
-    ("TableTopEccentricAdjustedAngle", 2804),
-    ("TableTopEccentricAngle", 3364),
-    ("TableTopEccentricAngleTolerance", 3242),
-    ("TableTopEccentricAxisDistance", 3363),
-    ("TableTopEccentricRotationDirection", 3365),
-    ("TableTopLateralAdjustedPosition", 2802),
-    ("TableTopLateralPosition", 3368),
-    ("TableTopLateralPositionTolerance", 3247),
-    ("TableTopLateralSetupDisplacement", 3415),
-    ("TableTopLongitudinalAdjustedPosition", 2801),
-    ("TableTopLongitudinalPosition", 3367),
-    ("TableTopLongitudinalPositionTolerance", 3246),
-    ("TableTopLongitudinalSetupDisplacement", 3414),
-    ("TableTopPitchAdjustedAngle", 2805),
-    ("TableTopPitchAngle", 3375),
-    ("TableTopPitchAngleTolerance", 3243),
-    ("TableTopPitchRotationDirection", 3376),
-    ("TableTopRollAdjustedAngle", 2806),
-    ("TableTopRollAngle", 3377),
-    ("TableTopRollAngleTolerance", 3244),
-    ("TableTopRollRotationDirection", 3378),
-    ("TableTopVerticalAdjustedPosition", 2800),
-    ("TableTopVerticalPosition", 3366),
-    ("TableTopVerticalPositionTolerance", 3245),
-    ("TableTopVerticalSetupDisplacement", 3413),
-    ("TableTraverse", 546),
-    ("TableType", 552),
-    ("TableVerticalIncrement", 548),
-    ("TableXPositionToIsocenter", 1090),
-    ("TableYPositionToIsocenter", 1091),
-    ("TableZPositionToIsocenter", 1092),
-    ("TabulatedValuesSequence", 2015),
-    ("TagAngleFirstAxis", 822),
-    ("TagAngleSecondAxis", 934),
-    ("TagSpacingFirstDimension", 832),
-    ("TagSpacingSecondDimension", 933),
-    ("TagThickness", 836),
-    ("Tagging", 830),
-    ("TaggingDelay", 924),
-    ("TangentialPower", 2140),
-    ("TargetExposureIndex", 637),
-    ("TargetFrameOfReferenceUID", 1260),
-    ("TargetLabel", 699),
-    ("TargetMaximumDose", 3227),
-    ("TargetMinimumDose", 3225),
-    ("TargetPositionReferenceIndicator", 1311),
-    ("TargetPrescriptionDose", 3226),
-    ("TargetRefraction", 1444),
-    ("TargetUID", 696),
-    ("TargetUnderdoseVolumeFraction", 3228),
-    ("TeletherapyRadiationType", 3928),
-    ("Temperature", 360),
-    ("TemplateIdentifier", 2024),
-    ("TemplateName", 3443),
-    ("TemplateNumber", 3441),
-    ("TemplateType", 3442),
-    ("TemporalPositionIdentifier", 1305),
-    ("TemporalPositionIndex", 1324),
-    ("TemporalPositionSequence", 1370),
-    ("TemporalPositionTimeOffset", 1367),
-    ("TemporalRangeType", 1986),
-    ("TemporalRelationshipIntervalAnchor", 3936),
-    ("TemporalResolution", 1307),
-    ("TerminationCardiacTriggerCountThreshold", 1161),
-    ("TerminationCountsThreshold", 1157),
-    ("TerminationDensityThreshold", 1158),
-    ("TerminationRelativeDensityThreshold", 1159),
-    ("TerminationRespiratoryTriggerCountThreshold", 1162),
-    ("TerminationTimeThreshold", 1160),
-    ("TestPointNormalsDataFlag", 1581),
-    ("TestPointNormalsSequence", 1582),
-    ("TextColorCIELabValue", 2541),
-    ("TextObjectSequence", 2497),
-    ("TextStyleSequence", 2537),
-    ("TextValue", 1990),
-    ("TextureLabel", 2880),
-    ("TherapeuticRoleCategoryCodeSequence", 3957),
-    ("TherapeuticRoleTypeCodeSequence", 3958),
-    ("ThreeDDegreeOfFreedomAxis", 2449),
-    ("ThreeDImplantTemplateGroupMemberMatchingAxes", 2863),
-    ("ThreeDImplantTemplateGroupMemberMatchingPoint", 2862),
-    ("ThreeDLineCoordinates", 2465),
-    ("ThreeDMatingAxes", 2452),
-    ("ThreeDMatingPoint", 2451),
-    ("ThreeDPlaneNormal", 2469),
-    ("ThreeDPlaneOrigin", 2468),
-    ("ThreeDPointCoordinates", 2462),
-    ("ThreeDRenderingType", 2780),
-    ("ThresholdSequence", 2667),
-    ("ThresholdType", 2669),
-    ("ThresholdValue", 2670),
-    ("ThresholdValueSequence", 2668),
-    ("TickAlignment", 2562),
-    ("TickLabel", 2570),
-    ("TickLabelAlignment", 2564),
-    ("TickPosition", 2569),
-    ("Time", 1983),
-    ("TimeBasedImageSetsSequence", 2686),
-    ("TimeDistributionProtocol", 684),
-    ("TimeDomainFiltering", 860),
-    ("TimeOfFlightContrast", 818),
-    ("TimeOfFlightInformationUsed", 1178),
-    ("TimeOfFrameGroupSequence", 1803),
-    ("TimeOfLastCalibration", 606),
-    ("TimeOfLastDetectorCalibration", 772),
-    ("TimeOfSecondaryCapture", 487),
-    ("TimeRange", 176),
-    ("TimeSeriesBlending", 2665),
-    ("TimeSliceVector", 2255),
-    ("TimeSlotInformationSequence", 2250),
-    ("TimeSlotTime", 2251),
-    ("TimeSlotVector", 2248),
-    ("TimeSource", 683),
-    ("TimezoneOffsetFromUTC", 88),
-    ("TissueHeterogeneityCorrection", 3038),
-    ("TissueLocation", 2167),
-    ("ToleranceTableLabel", 3235),
-    ("ToleranceTableNumber", 3234),
-    ("ToleranceTableSequence", 3233),
-    ("ToleranceValue", 3645),
-    ("TomoAngle", 641),
-    ("TomoClass", 644),
-    ("TomoLayerHeight", 640),
-    ("TomoTime", 642),
-    ("TomoType", 643),
-    ("TomotherapeuticControlPointSequence", 4001),
-    ("TomotherapeuticLeafInitialClosedDurations", 4003),
-    ("TomotherapeuticLeafOpenDurations", 4002),
-    ("ToricIOLPowerForExactEmmetropiaSequence", 1453),
-    ("ToricIOLPowerForExactTargetRefractionSequence", 1454),
-    ("ToricIOLPowerSequence", 1450),
-    ("TotalBlockTrayFactor", 3326),
-    ("TotalBlockTrayWaterEquivalentThickness", 3327),
-    ("TotalCollimationWidth", 972),
-    ("TotalCompensatorTrayFactor", 3311),
-    ("TotalCompensatorTrayWaterEquivalentThickness", 3489),
-    ("TotalNumberOfStudyRecords", 141),
-    ("TotalPixelMatrixColumns", 2147),
-    ("TotalPixelMatrixFocalPlanes", 2177),
-    ("TotalPixelMatrixOriginSequence", 2149),
-    ("TotalPixelMatrixRows", 2148),
-    ("TotalPrescriptionDose", 3841),
-    ("TotalReferenceAirKerma", 3444),
-    ("TotalWedgeTrayWaterEquivalentThickness", 3301),
-    ("TrackPointIndexList", 2395),
-    ("TrackSequence", 2385),
-    ("TrackSetAnatomicalTypeCodeSequence", 2391),
-    ("TrackSetDescription", 2390),
-    ("TrackSetLabel", 2389),
-    ("TrackSetNumber", 2388),
-    ("TrackSetSequence", 2384),
-    ("TrackSetStatisticsSequence", 2393),
-    ("TrackStatisticsSequence", 2396),
-    ("TrackingAlgorithmIdentificationSequence", 2387),
-    ("TrackingID", 2326),
-    ("TrackingUID", 2327),
-    ("TransactionUID", 180),
-    ("TransducerApplicationCodeSequence", 1207),
-    ("TransducerBeamSteeringCodeSequence", 1206),
-    ("TransducerData", 708),
-    ("TransducerFrequency", 739),
-    ("TransducerGeometryCodeSequence", 1205),
-    ("TransducerIdentificationSequence", 709),
-    ("TransducerResponseSequence", 1219),
-    ("TransducerScanPatternCodeSequence", 1202),
-    ("TransducerTechnologySequence", 1224),
-    ("TransducerType", 740),
-    ("TransferSyntaxUID", 4044),
-    ("TransferTubeLength", 3474),
-    ("TransferTubeNumber", 3473),
-    ("TransformationAlgorithmSequence", 1520),
-    ("TransformationMethodCodeSequence", 1519),
-    ("TransmitCoilManufacturerName", 848),
-    ("TransmitCoilName", 615),
-    ("TransmitCoilType", 849),
-    ("TransmitterFrequency", 886),
-    ("TransverseDetectorSeparation", 1164),
-    ("TransverseMash", 2287),
-    ("TrayAccessoryCode", 3529),
-    ("TreatmentControlPointDate", 3112),
-    ("TreatmentControlPointTime", 3113),
-    ("TreatmentDate", 3202),
-    ("TreatmentDeliveryContinuationFlag", 3746),
-    ("TreatmentDeliveryType", 3293),
-    ("TreatmentDeviceIdentificationSequence", 3657),
-    ("TreatmentMachineName", 3274),
-    ("TreatmentMachineSequence", 3418),
-    ("TreatmentMachineSpecialModeCodeSequence", 3652),
-    ("TreatmentPositionGroupLabel", 3609),
-    ("TreatmentPositionGroupSequence", 3611),
-    ("TreatmentPositionGroupUID", 3610),
-    ("TreatmentPositionIndex", 3607),
-    ("TreatmentPositionSequence", 3662),
-    ("TreatmentProtocols", 3209),
-    ("TreatmentRecordContentOrigin", 3747),
-    ("TreatmentSessionApplicationSetupSequence", 3172),
-    ("TreatmentSessionBeamSequence", 3109),
-    ("TreatmentSessionIonBeamSequence", 3110),
-    ("TreatmentSessionUID", 3738),
-    ("TreatmentSite", 3975),
-    ("TreatmentSiteCodeSequence", 3976),
-    ("TreatmentSiteModifierCodeSequence", 3993),
-    ("TreatmentStatusComment", 3196),
-    ("TreatmentSummaryCalculatedDoseReferenceSequence", 3131),
-    ("TreatmentSummaryMeasuredDoseReferenceSequence", 3165),
-    ("TreatmentTechniqueNotes", 3978),
-    ("TreatmentTerminationDescription", 3753),
-    ("TreatmentTerminationStatus", 3114),
-    ("TreatmentTime", 3203),
-    ("TreatmentTimeLimit", 3647),
-    ("TreatmentToleranceViolationAttributeSequence", 3756),
-    ("TreatmentToleranceViolationCategory", 3755),
-    ("TreatmentToleranceViolationCauseCodeSequence", 3773),
-    ("TreatmentToleranceViolationDateTime", 3759),
-    ("TreatmentToleranceViolationDescription", 3757),
-    ("TreatmentToleranceViolationIdentification", 3758),
-    ("TreatmentToleranceViolationSequence", 3754),
-    ("TreatmentToleranceViolationTypeCodeSequence", 3772),
-    ("TreatmentVerificationStatus", 3115),
-    ("TriangleFanSequence", 2364),
-    ("TriangleStripSequence", 2363),
-    ("TriggerSamplePosition", 518),
-    ("TriggerSourceOrType", 507),
-    ("TriggerTime", 506),
-    ("TriggerTimeOffset", 515),
-    ("TriggerVector", 2259),
-    ("TriggerWindow", 538),
-    ("TubeAngle", 968),
-    ("TwoDDegreeOfFreedomAxis", 2453),
-    ("TwoDDegreeOfFreedomSequence", 2448),
-    ("TwoDImplantTemplateGroupMemberMatchingAxes", 2866),
-    ("TwoDImplantTemplateGroupMemberMatchingPoint", 2865),
-    ("TwoDLineCoordinates", 2464),
-    ("TwoDLineCoordinatesSequence", 2463),
-    ("TwoDMatingAxes", 2447),
-    ("TwoDMatingFeatureCoordinatesSequence", 2444),
-    ("TwoDMatingPoint", 2446),
-    ("TwoDPlaneCoordinatesSequence", 2466),
-    ("TwoDPlaneIntersection", 2467),
-    ("TwoDPointCoordinates", 2461),
-    ("TwoDPointCoordinatesSequence", 2460),
-    ("TwoDimensionalToThreeDimensionalMapData", 1530),
-    ("TwoDimensionalToThreeDimensionalMapSequence", 1523),
-    ("TypeOfDetectorMotion", 2258),
-    ("TypeOfFilters", 570),
-    ("TypeOfInstances", 2030),
-    ("TypeOfOpticalCorrection", 1449),
-    ("TypeOfPatientID", 229),
-    ("TypeOfSynchronization", 2774),
-    ("UDISequence", 483),
-    ("UID", 1985),
-    ("URNCodeValue", 83),
-    ("USImageDescriptionSequence", 1199),
-    ("UVMappingSequence", 2879),
-    ("UValueData", 2881),
-    ("UltrasoundAcquisitionGeometry", 1361),
-    ("UltrasoundColorDataPresent", 1660),
-    ("UltrasoundOphthalmicAxialLengthMeasurementsSequence", 1490),
-    ("UltrasoundSelectedOphthalmicAxialLengthSequence", 1492),
-    ("UnassignedPerFrameConvertedAttributesSequence", 1338),
-    ("UnassignedSharedConvertedAttributesSequence", 1337),
-    ("Underlined", 2548),
-    ("UnformattedTextValue", 2496),
-    ("UniqueDeviceIdentifier", 482),
-    ("Units", 2275),
-    ("UniversalEntityID", 1883),
-    ("UniversalEntityIDType", 1884),
-    ("UnspecifiedLateralityLensSequence", 2058),
-    ("UpperCutoffFrequency", 1223),
-    ("UpperLimitNumberOfPersistentFluoroscopyFrames", 592),
-    ("UsedFiducialsSequence", 2584),
-    ("UsedRTStructureSetROISequence", 2585),
-    ("UsedSegmentsSequence", 2324),
-    ("UserContentLabel", 3908),
-    ("UserContentLongLabel", 3909),
-    ("VOILUTFunction", 1695),
-    ("VOILUTSequence", 1743),
-    ("VOIType", 2785),
-    ("VValueData", 2882),
-    ("ValueType", 1968),
-    ("VariableFlipAngleFlag", 626),
-    ("VariableModalityLUTSequence", 1738),
-    ("VectorAccuracy", 2360),
-    ("VectorCoordinateData", 2361),
-    ("VectorDimensionality", 2359),
-    ("VectorGridData", 2333),
-    ("VelocityEncodingAcquisitionSequence", 882),
-    ("VelocityEncodingDirection", 880),
-    ("VelocityEncodingMaximumValue", 932),
-    ("VelocityEncodingMinimumValue", 881),
-    ("VerificationDateTime", 1965),
-    ("VerificationFlag", 2010),
-    ("VerificationImageTiming", 2808),
-    ("VerifyingObserverIdentificationCodeSequence", 1979),
-    ("VerifyingObserverName", 1972),
-    ("VerifyingObserverSequence", 1971),
-    ("VerifyingOrganization", 1964),
-    ("VertexDistance", 1394),
-    ("VerticalAlignment", 2543),
-    ("VerticalPrismBase", 2064),
-    ("VerticalPrismPower", 2063),
-    ("VerticesOfTheOutlineOfPupil", 2120),
-    ("VerticesOfThePolygonalCollimator", 681),
-    ("VerticesOfThePolygonalExposureControlSensingRegion", 1077),
-    ("VerticesOfThePolygonalOutline", 673),
-    ("VerticesOfThePolygonalShutter", 661),
-    ("VerticesOfTheRegion", 1780),
-    ("VideoImageFormatAcquired", 492),
-    ("ViewCodeSequence", 2261),
-    ("ViewModifierCodeSequence", 2262),
-    ("ViewName", 189),
-    ("ViewNumber", 190),
-    ("ViewOrientationCodeSequence", 2417),
-    ("ViewOrientationModifierCodeSequence", 2418),
-    ("ViewPosition", 720),
-    ("ViewingDistance", 2093),
-    ("ViewingDistanceType", 2107),
-    ("ViewpointLookAtPoint", 2631),
-    ("ViewpointPosition", 2630),
-    ("ViewpointUpDirection", 2632),
-    ("VirtualSourceAxisDistances", 3502),
-    ("VisualAcuityBothEyesOpenSequence", 2106),
-    ("VisualAcuityLeftEyeSequence", 2105),
-    ("VisualAcuityMeasurementSequence", 1629),
-    ("VisualAcuityModifiers", 2108),
-    ("VisualAcuityRightEyeSequence", 2104),
-    ("VisualAcuityTypeCodeSequence", 2103),
-    ("VisualFieldCatchTrialSequence", 1562),
-    ("VisualFieldGlobalResultsIndexSequence", 1646),
-    ("VisualFieldHorizontalExtent", 1550),
-    ("VisualFieldMeanSensitivity", 1594),
-    ("VisualFieldShape", 1552),
-    ("VisualFieldTestDuration", 1610),
-    ("VisualFieldTestNormalsFlag", 1587),
-    ("VisualFieldTestPointNormalsSequence", 1619),
-    ("VisualFieldTestPointSequence", 1611),
-    ("VisualFieldTestPointXCoordinate", 1612),
-    ("VisualFieldTestPointYCoordinate", 1613),
-    ("VisualFieldTestReliabilityGlobalIndexSequence", 1645),
-    ("VisualFieldVerticalExtent", 1551),
-    ("VitreousStatusCodeSequence", 1438),
-    ("VitreousStatusDescription", 1460),
-    ("VolumeBasedCalculationTechnique", 217),
-    ("VolumeCroppingMethod", 2613),
-    ("VolumeCroppingSequence", 2612),
-    ("VolumeFrameOfReferenceUID", 1372),
-    ("VolumeLocalizationSequence", 901),
-    ("VolumeLocalizationTechnique", 852),
-    ("VolumeStreamSequence", 2658),
-    ("VolumeToTableMappingMatrix", 1364),
-    ("VolumeToTransducerMappingMatrix", 1363),
-    ("VolumeToTransducerRelationship", 1365),
-    ("VolumetricAnnotationSequence", 2647),
-    ("VolumetricCurvePoints", 2626),
-    ("VolumetricCurveUpDirections", 2657),
-    ("VolumetricPresentationInputAnnotationSequence", 2650),
-    ("VolumetricPresentationInputIndex", 2644),
-    ("VolumetricPresentationInputNumber", 2606),
-    ("VolumetricPresentationInputSetSequence", 2608),
-    ("VolumetricPresentationInputSetUID", 2607),
-    ("VolumetricPresentationStateInputSequence", 2601),
-    ("VolumetricProperties", 216),
-    ("WADORSRetrievalSequence", 2035),
-    ("WADORetrievalSequence", 2033),
-    ("WaterDepth", 363),
-    ("WaterEquivalentDiameter", 618),
-    ("WaterEquivalentDiameterCalculationMethodCodeSequence", 619),
-    ("WaterReferenceAcquisition", 964),
-    ("WaterReferencedPhaseCorrection", 929),
-    ("WaveformAmplifierType", 1858),
-    ("WaveformAnnotationSequence", 2023),
-    ("WaveformBitsAllocated", 4011),
-    ("WaveformBitsStored", 1833),
-    ("WaveformChannelNumber", 1819),
-    ("WaveformData", 4014),
-    ("WaveformDataDisplayScale", 1838),
-    ("WaveformDisplayBackgroundCIELabValue", 1839),
-    ("WaveformFilterDescription", 1870),
-    ("WaveformFilterType", 1863),
-    ("WaveformOriginality", 1813),
-    ("WaveformPaddingValue", 4013),
-    ("WaveformPresentationGroupSequence", 1840),
-    ("WaveformSampleInterpretation", 4012),
-    ("WaveformSequence", 4008),
-    ("WedgeAngle", 3299),
-    ("WedgeDefinitionSequence", 3679),
-    ("WedgeFactor", 3300),
-    ("WedgeID", 3298),
-    ("WedgeNumber", 3296),
-    ("WedgeOrientation", 3302),
-    ("WedgePosition", 3354),
-    ("WedgePositionSequence", 3353),
-    ("WedgeSequence", 3295),
-    ("WedgeThinEdgePosition", 3305),
-    ("WedgeType", 3297),
-    ("WeekdayFractionPatternSequence", 3991),
-    ("WeightingTransferFunctionSequence", 2646),
-    ("WhiteBalance", 374),
-    ("WhitePoint", 317),
-    ("WholeBodyTechnique", 621),
-    ("WholeSlideMicroscopyImageFrameTypeSequence", 1927),
-    ("WideFieldOphthalmicPhotographyQualityRatingSequence", 1524),
-    ("WideFieldOphthalmicPhotographyQualityThresholdSequence", 1525),
-    ("WideFieldOphthalmicPhotographyThresholdQualityRating", 1526),
-    ("WindowCenter", 1689),
-    ("WindowCenterWidthExplanation", 1694),
-    ("WindowWidth", 1690),
-    ("XAAcquisitionDuration", 600),
-    ("XAAcquisitionFrameRate", 596),
-    ("XAAcquisitionPhaseDetailsSequence", 595),
-    ("XAPlaneDetailsSequence", 597),
-    ("XAXRFFrameCharacteristicsSequence", 1056),
-    ("XCoordinatesCenterPixelViewAngle", 1527),
-    ("XDSRetrievalSequence", 2034),
-    ("XDSStorageSequence", 1947),
-    ("XFocusCenter", 578),
-    ("XOffsetInSlideCoordinateSystem", 1929),
-    ("XRay3DAcquisitionSequence", 1103),
-    ("XRay3DFrameTypeSequence", 1101),
-    ("XRay3DReconstructionSequence", 1119),
-    ("XRayAcquisitionDoseSequence", 1123),
-    ("XRayDetectorID", 1028),
-    ("XRayDetectorIndex", 1027),
-    ("XRayDetectorLabel", 1030),
-    ("XRayFilterDetailsSequence", 599),
-    ("XRayFilterSequence", 1137),
-    ("XRayGeometrySequence", 1099),
-    ("XRayGridSequence", 1136),
-    ("XRayImageReceptorAngle", 2956),
-    ("XRayImageReceptorTranslation", 2955),
-    ("XRayMassAttenuationCoefficient", 1047),
-    ("XRayOutput", 1903),
-    ("XRayReceptorType", 1058),
-    ("XRaySourceID", 1018),
-    ("XRaySourceIndex", 1017),
-    ("XRaySourceIsocenterPrimaryAngle", 1124),
-    ("XRaySourceIsocenterSecondaryAngle", 1125),
-    ("XRayTubeCurrent", 561),
-    ("XRayTubeCurrentInmA", 994),
-    ("XRayTubeCurrentInuA", 808),
-    ("YCoordinatesCenterPixelViewAngle", 1528),
-    ("YFocusCenter", 579),
-    ("YOffsetInSlideCoordinateSystem", 1930),
-    ("ZOffsetInSlideCoordinateSystem", 1931),
-    ("ZeroVelocityPixelValue", 1208),
-    ("ZoomCenter", 1663),
-    ("ZoomFactor", 1662),
-    ("dBdt", 628),
-];
\ No newline at end of file
+include!(concat!(env!("OUT_DIR"), "/dicts.rs"));
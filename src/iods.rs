@@ -0,0 +1,160 @@
+use crate::dataelem::{attribute_by_tag_number, DicomAttribute};
+
+/// Attribute type within a module (PS3.5 Section 7.4): whether the attribute
+/// must be present and whether it may be empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttributeType {
+    Type1,
+    Type1C,
+    Type2,
+    Type2C,
+    Type3,
+}
+
+impl AttributeType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttributeType::Type1 => "1",
+            AttributeType::Type1C => "1C",
+            AttributeType::Type2 => "2",
+            AttributeType::Type2C => "2C",
+            AttributeType::Type3 => "3",
+        }
+    }
+
+    /// Present only when the condition in the module description holds
+    pub fn is_conditional(&self) -> bool {
+        matches!(self, AttributeType::Type1C | AttributeType::Type2C)
+    }
+
+    /// Present whenever the module is, regardless of any condition
+    pub fn is_required(&self) -> bool {
+        matches!(self, AttributeType::Type1 | AttributeType::Type2)
+    }
+
+    /// Must have a value when present
+    pub fn requires_value(&self) -> bool {
+        matches!(self, AttributeType::Type1 | AttributeType::Type1C)
+    }
+}
+
+/// How an IOD uses a module (PS3.3 Section A.1.3)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleUsage {
+    Mandatory,
+    /// Required under the condition given, e.g. "Required if contrast media
+    /// was used in this image"
+    Conditional(&'static str),
+    UserOption,
+}
+
+/// An attribute row of a module table, with the rows nested under it when it
+/// is a sequence. Macros included by the table are expanded in place.
+#[derive(Debug)]
+pub struct ModuleAttribute {
+    /// Tag as (group << 16 | element); repeating groups such as (60xx,3000)
+    /// have their xx digits zeroed
+    pub tag: u32,
+    pub name: &'static str,
+    pub attr_type: AttributeType,
+    pub items: &'static [ModuleAttribute],
+}
+
+impl ModuleAttribute {
+    /// Data dictionary entry of the attribute
+    pub fn attribute(&self) -> Option<&'static DicomAttribute> {
+        attribute_by_tag_number(self.tag)
+    }
+}
+
+#[derive(Debug)]
+pub struct Module {
+    pub name: &'static str,
+    /// PS3.3 section defining the module, e.g. "C.7.1.1"
+    pub section: &'static str,
+    /// False when the vendored table is an excerpt holding only some of
+    /// the module's attributes
+    pub complete: bool,
+    pub attributes: &'static [ModuleAttribute],
+}
+
+impl Module {
+    /// Top-level attribute of the module with the given tag
+    pub fn attribute(&self, tag: u32) -> Option<&'static ModuleAttribute> {
+        self.attributes.iter().find(|a| a.tag == tag)
+    }
+}
+
+#[derive(Debug)]
+pub struct IodModule {
+    /// Information Entity the module belongs to, e.g. "Series"
+    pub information_entity: &'static str,
+    pub module: &'static Module,
+    pub usage: ModuleUsage,
+}
+
+/// Information Object Definition with its modules in PS3.3 table order
+#[derive(Debug)]
+pub struct Iod {
+    pub name: &'static str,
+    /// PS3.3 section defining the IOD, e.g. "A.4"
+    pub section: &'static str,
+    /// Storage SOP Classes using this IOD (PS3.4 Table B.5-1)
+    pub sop_classes: &'static [&'static str],
+    /// False when the vendored table is an excerpt leaving out some of the
+    /// IOD's modules
+    pub complete: bool,
+    pub modules: &'static [IodModule],
+}
+
+impl Iod {
+    pub fn module(&self, name: &str) -> Option<&'static IodModule> {
+        self.modules.iter().find(|m| m.module.name == name)
+    }
+
+    /// Top-level attributes of every module of the IOD, paired with the
+    /// module they come from. Attributes defined by several modules, such as
+    /// Instance Number, appear once per module.
+    pub fn attributes(&self) -> impl Iterator<Item = (&'static IodModule, &'static ModuleAttribute)> + use<> {
+        let modules: &'static [IodModule] = self.modules;
+        modules
+            .iter()
+            .flat_map(|m| m.module.attributes.iter().map(move |a| (m, a)))
+    }
+
+    /// Type 1 and Type 2 attributes of the mandatory modules: what every
+    /// instance of the IOD has to contain. None when the IOD table or one of
+    /// its mandatory module tables is an excerpt, as the list would miss
+    /// some.
+    pub fn required_attributes(&self) -> Option<impl Iterator<Item = &'static ModuleAttribute> + use<>> {
+        let mandatory = || self.modules.iter().filter(|m| m.usage == ModuleUsage::Mandatory);
+        if !self.complete || mandatory().any(|m| !m.module.complete) {
+            return None;
+        }
+        Some(
+            self.attributes()
+                .filter(|(m, a)| m.usage == ModuleUsage::Mandatory && a.attr_type.is_required())
+                .map(|(_, a)| a),
+        )
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/iods.rs"));
+
+/// IOD of a Storage SOP Class, or None for one the vendored PS3.4 Table
+/// B.5-1 does not list.
+pub fn iod_by_sop_class(uid: &str) -> Option<&'static Iod> {
+    let uid = uid.trim_end_matches(['\0', ' ']);
+    IOD_SOP_CLASS_INDEX
+        .binary_search_by(|entry| entry.0.cmp(uid))
+        .ok()
+        .map(|idx| &IODS[IOD_SOP_CLASS_INDEX[idx].1])
+}
+
+pub fn iod_by_name(name: &str) -> Option<&'static Iod> {
+    IODS.iter().find(|iod| iod.name == name)
+}
+
+pub fn module_by_name(name: &str) -> Option<&'static Module> {
+    MODULES.iter().copied().find(|m| m.name == name)
+}
@@ -2,6 +2,11 @@ pub mod modules;
 pub mod dataelem;
 pub mod dicts;
 pub mod dataset;
+pub mod uids;
+pub mod byteswap;
+pub mod iods;
+pub mod codes;
+pub mod search;
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
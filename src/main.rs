@@ -15,5 +15,6 @@ fn main() {
     if let Some(e) = ds.get("ImplementationClassUID") { println!("ImplementationClassUID: {}", e); }
     if let Some(e) = ds.get("ImplementationVersionName") { println!("ImplementationVersionName: {}", e); }
     if let Some(e) = ds.get("PatientName") { println!("PatientName: {}", e); }
+    if let Some(e) = ds.get("SOPClassUID") { println!("SOPClassUID: {}", e); }
     if let Some(e) = ds.get("Modality") { println!("Modality: {}", e); }
 }
@@ -0,0 +1,328 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::dataelem::{attribute_by_tag_number, DataElement, DataElementValue};
+use crate::dataset::Dataset;
+use crate::iods::{iod_by_sop_class, module_by_name, AttributeType, ModuleUsage};
+use crate::modules::pixel::{PixelArray, PixelBuffer};
+use crate::uids::{generate_uid, EXPLICIT_VR_LITTLE_ENDIAN};
+
+pub const SECONDARY_CAPTURE_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.7";
+pub const MULTI_FRAME_GRAYSCALE_BYTE_SC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.7.2";
+pub const MULTI_FRAME_GRAYSCALE_WORD_SC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.7.3";
+pub const MULTI_FRAME_TRUE_COLOR_SC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.7.4";
+
+/// Modules whose attributes are copied from a reference dataset
+const REFERENCE_MODULES: [&str; 3] = ["Patient", "General Study", "Patient Study"];
+
+/// Builds Secondary Capture instances (PS3.3 Sections A.8.1 to A.8.5) from
+/// 8-bit grayscale or RGB pixels, or 16-bit grayscale ones. One frame makes
+/// an SC Image; more make the matching Multi-frame SC Image.
+#[derive(Debug, Clone)]
+pub struct SecondaryCaptureBuilder {
+    image: PixelArray,
+    bits_stored: Option<u16>,
+    conversion_type: String,
+    reference: Option<Dataset>,
+    attributes: Vec<DataElement>,
+}
+
+impl SecondaryCaptureBuilder {
+    pub fn new(image: PixelArray) -> Self {
+        Self {
+            image,
+            bits_stored: None,
+            conversion_type: "WSD".to_string(),
+            reference: None,
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Copies the Patient, General Study and Patient Study attributes of
+    /// the reference, and anything else in group 0010, so the capture joins
+    /// the reference's study.
+    pub fn with_reference(mut self, reference: &Dataset) -> Self {
+        self.reference = Some(reference.clone());
+        self
+    }
+
+    /// Bits Stored for 16-bit samples; defaults to all 16
+    pub fn with_bits_stored(mut self, bits: u16) -> Self {
+        self.bits_stored = Some(bits);
+        self
+    }
+
+    /// Conversion Type (0008,0064); defaults to WSD, workstation
+    pub fn with_conversion_type(mut self, conversion_type: &str) -> Self {
+        self.conversion_type = conversion_type.to_string();
+        self
+    }
+
+    /// Sets an attribute after everything else, e.g. a Series Instance UID
+    /// shared by several captures or a Series Description.
+    pub fn with_attribute(mut self, elem: DataElement) -> Self {
+        self.attributes.push(elem);
+        self
+    }
+
+    /// SOP Class for the image, or None for pixels no Secondary Capture
+    /// IOD accepts
+    pub fn sop_class(&self) -> Option<&'static str> {
+        let image = &self.image;
+        if image.frames == 1 {
+            return match (&image.data, image.samples) {
+                (PixelBuffer::U8(_), 1 | 3) | (PixelBuffer::U16(_), 1) => Some(SECONDARY_CAPTURE_IMAGE_STORAGE),
+                _ => None,
+            };
+        }
+        match (&image.data, image.samples) {
+            (PixelBuffer::U8(_), 1) => Some(MULTI_FRAME_GRAYSCALE_BYTE_SC_IMAGE_STORAGE),
+            (PixelBuffer::U16(_), 1) => Some(MULTI_FRAME_GRAYSCALE_WORD_SC_IMAGE_STORAGE),
+            (PixelBuffer::U8(_), 3) => Some(MULTI_FRAME_TRUE_COLOR_SC_IMAGE_STORAGE),
+            _ => None,
+        }
+    }
+
+    /// Dataset with new Study (unless a reference gives one), Series and
+    /// SOP Instance UIDs and File Meta Information for Explicit VR Little
+    /// Endian, ready for write_dicom. Type 2 attributes with no value are
+    /// present and empty.
+    pub fn build(&self) -> Option<Dataset> {
+        let image = &self.image;
+        let Some(sop_class) = self.sop_class() else {
+            eprintln!("Secondary Capture needs unsigned 8-bit grayscale or RGB samples, or 16-bit grayscale ones");
+            return None;
+        };
+        if image.data.len() != image.frames * image.frame_len() || image.frame_len() == 0 {
+            eprintln!("Pixel buffer does not hold {} frames of {}x{}x{}", image.frames, image.rows, image.columns, image.samples);
+            return None;
+        }
+        let (Ok(rows), Ok(columns)) = (u16::try_from(image.rows), u16::try_from(image.columns)) else {
+            eprintln!("Image of {}x{} does not fit the 16-bit Rows and Columns", image.columns, image.rows);
+            return None;
+        };
+        let bits_allocated: u16 = if matches!(image.data, PixelBuffer::U8(_)) { 8 } else { 16 };
+        let bits_stored = self.bits_stored.filter(|_| bits_allocated == 16).unwrap_or(bits_allocated);
+        // The Multi-frame Grayscale Word IOD asks for 9 to 16 bits
+        let least = if sop_class == MULTI_FRAME_GRAYSCALE_WORD_SC_IMAGE_STORAGE { 9 } else { 1 };
+        if !(least..=bits_allocated).contains(&bits_stored) {
+            eprintln!("Bits Stored {bits_stored} does not suit {bits_allocated}-bit samples");
+            return None;
+        }
+        let gray = image.samples == 1;
+        let (date, time) = now();
+
+        let mut ds = Dataset::new();
+        let string = |v: &str| DataElementValue::String(v.to_string());
+        if let Some(reference) = &self.reference {
+            let tags: Vec<u32> = REFERENCE_MODULES
+                .iter()
+                .filter_map(|name| module_by_name(name))
+                .flat_map(|module| module.attributes.iter().map(|a| a.tag))
+                .chain([0x0008_0005])
+                .collect();
+            // Group 0010 is all Patient level, so it is copied whole rather
+            // than trusting the module tables to list every attribute
+            let copied = |e: &&DataElement| e.tag >> 16 == 0x0010 || tags.contains(&e.tag);
+            for elem in reference.elements().iter().filter(copied) {
+                ds.set(elem.clone());
+            }
+        }
+        if ds.get("StudyInstanceUID").is_none() {
+            ds.set(DataElement::new("StudyInstanceUID", string(&generate_uid()))?);
+        }
+        let mut attributes = vec![
+            ("SOPClassUID", string(sop_class)),
+            ("SOPInstanceUID", string(&generate_uid())),
+            ("SeriesInstanceUID", string(&generate_uid())),
+            ("Modality", string("OT")),
+            ("ConversionType", string(&self.conversion_type)),
+            ("DateOfSecondaryCapture", string(&date)),
+            ("TimeOfSecondaryCapture", string(&time)),
+            ("InstanceCreationDate", string(&date)),
+            ("InstanceCreationTime", string(&time)),
+            ("ContentDate", string(&date)),
+            ("ContentTime", string(&time)),
+            ("BurnedInAnnotation", string("YES")),
+            ("SamplesPerPixel", DataElementValue::UInt16(image.samples as u16)),
+            ("PhotometricInterpretation", string(if gray { "MONOCHROME2" } else { "RGB" })),
+            ("Rows", DataElementValue::UInt16(rows)),
+            ("Columns", DataElementValue::UInt16(columns)),
+            ("BitsAllocated", DataElementValue::UInt16(bits_allocated)),
+            ("BitsStored", DataElementValue::UInt16(bits_stored)),
+            ("HighBit", DataElementValue::UInt16(bits_stored - 1)),
+            ("PixelRepresentation", DataElementValue::UInt16(0)),
+        ];
+        if !gray {
+            attributes.push(("PlanarConfiguration", DataElementValue::UInt16(0)));
+        }
+        if sop_class != SECONDARY_CAPTURE_IMAGE_STORAGE {
+            // SC Multi-frame Image and Vector modules (PS3.3 C.8.6.3 and
+            // C.8.6.4): frames are pages, grayscale values are unscaled
+            let pages: Vec<String> = (1..=image.frames).map(|n| n.to_string()).collect();
+            attributes.push(("NumberOfFrames", string(&image.frames.to_string())));
+            attributes.push(("FrameIncrementPointer", DataElementValue::Tag(0x0018, 0x2001)));
+            attributes.push(("PageNumberVector", string(&pages.join("\\"))));
+            if gray {
+                attributes.push(("PresentationLUTShape", string("IDENTITY")));
+                attributes.push(("RescaleIntercept", string("0")));
+                attributes.push(("RescaleSlope", string("1")));
+                attributes.push(("RescaleType", string("US")));
+            }
+        }
+        for (keyword, value) in attributes {
+            ds.set(DataElement::new(keyword, value)?);
+        }
+        // Patient Orientation is Type 2C; captures have no patient axes
+        ds.set(empty_element(0x0020_0020)?);
+        for elem in &self.attributes {
+            ds.set(elem.clone());
+        }
+
+        // Whatever the IOD tables list as required and is still missing
+        let listed = iod_by_sop_class(sop_class).into_iter().flat_map(|iod| iod.attributes());
+        let required = listed.filter(|(m, a)| m.usage == ModuleUsage::Mandatory && a.attr_type.is_required());
+        for (_, attribute) in required {
+            if ds.elements().iter().any(|e| e.tag == attribute.tag) {
+                continue;
+            }
+            if attribute.attr_type == AttributeType::Type1 {
+                eprintln!("Secondary Capture is missing Type 1 attribute {}", attribute.name);
+                return None;
+            }
+            ds.set(empty_element(attribute.tag)?);
+        }
+        let bytes = image.data.to_le_bytes();
+        ds.set_pixel_data(bytes);
+
+        let meta = vec![
+            DataElement::new("FileMetaInformationVersion", DataElementValue::Data(vec![0, 1]))?,
+            DataElement::new("MediaStorageSOPClassUID", string(sop_class))?,
+            DataElement::new("MediaStorageSOPInstanceUID", ds.get("SOPInstanceUID")?.value.clone()?)?,
+            DataElement::new("TransferSyntaxUID", string(EXPLICIT_VR_LITTLE_ENDIAN))?,
+        ];
+        ds.set_file_meta(meta);
+        Some(ds)
+    }
+}
+
+/// Present, zero-length element for a Type 2 attribute
+fn empty_element(tag: u32) -> Option<DataElement> {
+    let attribute = attribute_by_tag_number(tag)?;
+    let vr = DataElement::new(attribute.tag, DataElementValue::String(String::new()))?.vr;
+    Some(DataElement { attribute, tag, vr, value: None })
+}
+
+/// Current UTC date and time as DA and TM values
+fn now() -> (String, String) {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, secs) = ((seconds / 86400) as i64, seconds % 86400);
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    (
+        format!("{year:04}{month:02}{day:02}"),
+        format!("{:02}{:02}{:02}", secs / 3600, secs / 60 % 60, secs % 60),
+    )
+}
+
+/// Pixels of a PNG image: gray or RGB, 8 or 16 bits. Palette images and
+/// bit depths below 8 are expanded; alpha is dropped.
+#[cfg(feature = "import")]
+pub fn decode_png(bytes: &[u8]) -> Option<PixelArray> {
+    let mut decoder = png::Decoder::new(std::io::Cursor::new(bytes));
+    decoder.set_transformations(png::Transformations::EXPAND);
+    let mut reader = match decoder.read_info() {
+        Ok(reader) => reader,
+        Err(e) => {
+            eprintln!("Invalid PNG image: {e}");
+            return None;
+        }
+    };
+    let mut buffer = vec![0; reader.output_buffer_size()?];
+    let info = match reader.next_frame(&mut buffer) {
+        Ok(info) => info,
+        Err(e) => {
+            eprintln!("PNG decoding failed: {e}");
+            return None;
+        }
+    };
+    let (channels, samples) = match info.color_type {
+        png::ColorType::Grayscale => (1, 1),
+        png::ColorType::GrayscaleAlpha => (2, 1),
+        png::ColorType::Rgb => (3, 3),
+        png::ColorType::Rgba => (4, 3),
+        png::ColorType::Indexed => {
+            eprintln!("PNG palette was not expanded");
+            return None;
+        }
+    };
+    let wide = info.bit_depth == png::BitDepth::Sixteen;
+    let size = if wide { 2 } else { 1 };
+    let row_bytes = info.line_size;
+    // Rows may be padded; keep the color channels of each pixel
+    let kept = (0..info.height as usize).flat_map(|y| {
+        let row = &buffer[y * row_bytes..];
+        (0..info.width as usize).flat_map(move |x| (0..samples).map(move |c| &row[(x * channels + c) * size..][..size]))
+    });
+    let data = if wide {
+        PixelBuffer::U16(kept.map(|s| u16::from_be_bytes([s[0], s[1]])).collect())
+    } else {
+        PixelBuffer::U8(kept.map(|s| s[0]).collect())
+    };
+    Some(PixelArray { frames: 1, rows: info.height as usize, columns: info.width as usize, samples, data })
+}
+
+/// Pixels of a baseline or extended JPEG image, decoded with the codec used
+/// for JPEG Pixel Data; color comes back as RGB.
+#[cfg(feature = "import")]
+pub fn decode_jpeg(bytes: &[u8]) -> Option<PixelArray> {
+    use crate::modules::codecs::jpeg;
+    use crate::modules::pixel::PixelDescription;
+
+    let mut header = None;
+    let _ = jpeg::walk(bytes, |segment| {
+        if let jpeg::Segment::Frame(frame) = segment {
+            header = Some(frame);
+            // Stop at the frame header; nothing else is needed
+            return None;
+        }
+        Some(0)
+    });
+    let Some(header) = header else {
+        eprintln!("JPEG image has no frame header");
+        return None;
+    };
+    let samples = header.components.len();
+    if samples != 1 && samples != 3 {
+        eprintln!("JPEG image has {samples} components; expected 1 or 3");
+        return None;
+    }
+    let precision = header.precision as u16;
+    let bits_allocated = if precision > 8 { 16 } else { 8 };
+    let desc = PixelDescription {
+        rows: header.lines,
+        columns: header.samples_per_line,
+        samples_per_pixel: samples,
+        bits_allocated,
+        bits_stored: precision,
+        high_bit: precision - 1,
+        signed: false,
+        planar: false,
+        number_of_frames: 1,
+        photometric_interpretation: if samples == 1 { "MONOCHROME2" } else { "YBR_FULL_422" }.to_string(),
+    };
+    let decoded = jpeg::decode_frame(&desc, bytes)?;
+    let data = if bits_allocated == 8 {
+        PixelBuffer::U8(decoded)
+    } else {
+        PixelBuffer::U16(decoded.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect())
+    };
+    Some(PixelArray { frames: 1, rows: desc.rows, columns: desc.columns, samples, data })
+}
@@ -0,0 +1,681 @@
+//! JPEG Baseline (Process 1) and Extended (Process 2 & 4) decoding
+//! (ITU-T T.81), for 1.2.840.10008.1.2.4.50 and .51. Only the sequential
+//! Huffman DCT processes are supported; progressive and arithmetic coded
+//! streams are rejected.
+
+use crate::modules::pixel::PixelDescription;
+
+pub(crate) const SOI: u8 = 0xD8;
+pub(crate) const EOI: u8 = 0xD9;
+pub(crate) const SOS: u8 = 0xDA;
+pub(crate) const DHT: u8 = 0xC4;
+pub(crate) const DRI: u8 = 0xDD;
+pub(crate) const SOF55: u8 = 0xF7;
+const LSE: u8 = 0xF8;
+const DQT: u8 = 0xDB;
+const APP0: u8 = 0xE0;
+const APP14: u8 = 0xEE;
+
+/// Natural (row-major) index of each zig-zag position
+const ZIGZAG: [usize; 64] = [
+    0, 1, 8, 16, 9, 2, 3, 10, 17, 24, 32, 25, 18, 11, 4, 5, 12, 19, 26, 33, 40, 48, 41, 34, 27, 20, 13, 6, 7, 14, 21,
+    28, 35, 42, 49, 56, 57, 50, 43, 36, 29, 22, 15, 23, 30, 37, 44, 51, 58, 59, 52, 45, 38, 31, 39, 46, 53, 60, 61,
+    54, 47, 55, 62, 63,
+];
+
+/// Huffman table in the form of T.81 Annex F.2.2.3 (MAXCODE, VALPTR,
+/// MINCODE), plus an 8-bit lookahead table for the common short codes.
+#[derive(Debug, Clone)]
+pub(crate) struct HuffmanTable {
+    maxcode: [i32; 18],
+    valptr: [i32; 17],
+    mincode: [i32; 17],
+    values: Vec<u8>,
+    /// (code length, value) for every 8-bit prefix; length 0 when the code
+    /// is longer than 8 bits
+    lookup: Vec<(u8, u8)>,
+}
+
+impl HuffmanTable {
+    pub(crate) fn new(counts: &[u8; 16], values: &[u8]) -> Option<Self> {
+        let mut maxcode = [-1i32; 18];
+        let mut valptr = [0i32; 17];
+        let mut mincode = [0i32; 17];
+        let mut lookup = vec![(0u8, 0u8); 256];
+        let mut code = 0i32;
+        let mut k = 0usize;
+        for len in 1..=16 {
+            let n = counts[len - 1] as usize;
+            if n > 0 {
+                valptr[len] = k as i32;
+                mincode[len] = code;
+                for _ in 0..n {
+                    if code >= 1 << len {
+                        eprintln!("JPEG Huffman table has more codes of length {len} than fit");
+                        return None;
+                    }
+                    let value = *values.get(k)?;
+                    if len <= 8 {
+                        let shift = 8 - len;
+                        let first = (code as usize) << shift;
+                        for entry in &mut lookup[first..first + (1 << shift)] {
+                            *entry = (len as u8, value);
+                        }
+                    }
+                    code += 1;
+                    k += 1;
+                }
+                maxcode[len] = code - 1;
+            }
+            code <<= 1;
+        }
+        // Sentinel so decoding of corrupt data terminates
+        maxcode[17] = i32::MAX;
+        Some(Self { maxcode, valptr, mincode, values: values[..k].to_vec(), lookup })
+    }
+
+    pub(crate) fn decode(&self, bits: &mut BitReader) -> Option<u8> {
+        let peek = bits.peek(8);
+        let (len, value) = self.lookup[peek as usize];
+        if len > 0 {
+            bits.consume(len as u32);
+            return Some(value);
+        }
+        let mut code = bits.bit() as i32;
+        let mut len = 1;
+        while code > self.maxcode[len] {
+            len += 1;
+            if len > 16 {
+                return None;
+            }
+            code = (code << 1) | bits.bit() as i32;
+        }
+        let index = self.valptr[len] + code - self.mincode[len];
+        self.values.get(index as usize).copied()
+    }
+}
+
+/// Entropy-coded segment reader: removes stuffed zero bytes and stops at
+/// markers, after which it supplies zero bits.
+pub(crate) struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    acc: u64,
+    count: u32,
+    /// Marker found in the stream, if decoding ran into one
+    pub(crate) marker: Option<u8>,
+}
+
+impl<'a> BitReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0, acc: 0, count: 0, marker: None }
+    }
+
+    fn fill(&mut self) {
+        while self.count <= 56 {
+            let mut byte = 0u8;
+            if self.marker.is_none() && self.pos < self.data.len() {
+                byte = self.data[self.pos];
+                if byte == 0xFF {
+                    let next = self.data.get(self.pos + 1).copied().unwrap_or(0);
+                    if next == 0x00 {
+                        self.pos += 2;
+                    } else {
+                        self.marker = Some(next);
+                        byte = 0;
+                    }
+                } else {
+                    self.pos += 1;
+                }
+            }
+            self.acc |= (byte as u64) << (56 - self.count);
+            self.count += 8;
+        }
+    }
+
+    pub(crate) fn peek(&mut self, n: u32) -> u32 {
+        if self.count < n {
+            self.fill();
+        }
+        (self.acc >> (64 - n)) as u32
+    }
+
+    pub(crate) fn consume(&mut self, n: u32) {
+        self.acc <<= n;
+        self.count -= n;
+    }
+
+    pub(crate) fn bit(&mut self) -> u32 {
+        let b = self.peek(1);
+        self.consume(1);
+        b
+    }
+
+    pub(crate) fn receive(&mut self, n: u32) -> u32 {
+        if n == 0 {
+            return 0;
+        }
+        let v = self.peek(n);
+        self.consume(n);
+        v
+    }
+
+    /// RECEIVE followed by EXTEND (T.81 Figure F.12)
+    pub(crate) fn receive_extend(&mut self, n: u32) -> i32 {
+        if n == 0 {
+            return 0;
+        }
+        if n >= 16 {
+            // Lossless difference category 16 is always 32768 with no bits
+            return 32768;
+        }
+        let v = self.receive(n) as i32;
+        if v < 1 << (n - 1) { v - (1 << n) + 1 } else { v }
+    }
+
+    /// Skips to the next RSTn marker, dropping any bits left in the
+    /// current byte.
+    pub(crate) fn restart(&mut self) -> bool {
+        self.acc = 0;
+        self.count = 0;
+        if let Some(m) = self.marker.take() {
+            self.pos += 2;
+            return (0xD0..=0xD7).contains(&m);
+        }
+        // Scan for the marker when the reader had not reached it yet
+        while self.pos + 1 < self.data.len() {
+            if self.data[self.pos] == 0xFF && (0xD0..=0xD7).contains(&self.data[self.pos + 1]) {
+                self.pos += 2;
+                return true;
+            }
+            self.pos += 1;
+        }
+        false
+    }
+
+    /// Bytes consumed so far, including any stuffing
+    pub(crate) fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+/// Component of a frame header (SOF)
+#[derive(Debug, Clone)]
+pub(crate) struct FrameComponent {
+    pub(crate) id: u8,
+    pub(crate) h: usize,
+    pub(crate) v: usize,
+    pub(crate) tq: usize,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct FrameHeader {
+    /// SOF marker, e.g. 0xC0 for baseline or 0xC3 for lossless
+    pub(crate) process: u8,
+    pub(crate) precision: u8,
+    pub(crate) lines: usize,
+    pub(crate) samples_per_line: usize,
+    pub(crate) components: Vec<FrameComponent>,
+}
+
+impl FrameHeader {
+    fn parse(process: u8, seg: &[u8]) -> Option<Self> {
+        if seg.len() < 6 {
+            return None;
+        }
+        let precision = seg[0];
+        let lines = u16::from_be_bytes([seg[1], seg[2]]) as usize;
+        let samples_per_line = u16::from_be_bytes([seg[3], seg[4]]) as usize;
+        let n = seg[5] as usize;
+        if seg.len() < 6 + 3 * n || n == 0 {
+            return None;
+        }
+        let components = (0..n)
+            .map(|i| {
+                let c = &seg[6 + 3 * i..9 + 3 * i];
+                FrameComponent { id: c[0], h: (c[1] >> 4).max(1) as usize, v: (c[1] & 15).max(1) as usize, tq: (c[2] & 3) as usize }
+            })
+            .collect();
+        Some(Self { process, precision, lines, samples_per_line, components })
+    }
+
+    /// Checks the frame size, component count and sample precision against
+    /// the dataset, so nothing is allocated for a frame the dataset does not
+    /// describe and no sample is truncated to fit BitsAllocated.
+    pub(crate) fn matches(&self, desc: &PixelDescription) -> bool {
+        let matches = self.lines == desc.rows
+            && self.samples_per_line == desc.columns
+            && self.components.len() == desc.samples_per_pixel;
+        if !matches {
+            eprintln!(
+                "JPEG frame is {}x{} with {} components, expected {}x{} with {}",
+                self.samples_per_line,
+                self.lines,
+                self.components.len(),
+                desc.columns,
+                desc.rows,
+                desc.samples_per_pixel
+            );
+            return false;
+        }
+        if self.precision as u16 > desc.bits_allocated {
+            eprintln!("JPEG precision {} exceeds BitsAllocated {}", self.precision, desc.bits_allocated);
+            return false;
+        }
+        if self.precision as u16 != desc.bits_stored {
+            eprintln!("JPEG precision {} differs from BitsStored {}", self.precision, desc.bits_stored);
+        }
+        true
+    }
+}
+
+/// Scan header (SOS): component indices into the frame with their table
+/// selectors, and the spectral selection / predictor fields.
+#[derive(Debug, Clone)]
+pub(crate) struct ScanHeader {
+    pub(crate) components: Vec<(usize, usize, usize)>,
+    /// Ss: predictor selection in lossless mode, NEAR in JPEG-LS
+    pub(crate) ss: u8,
+    /// Se: interleave mode in JPEG-LS
+    pub(crate) se: u8,
+    /// Al: point transform in lossless mode
+    pub(crate) al: u8,
+}
+
+/// A marker segment or scan found while walking a JPEG stream
+pub(crate) enum Segment<'a> {
+    Frame(FrameHeader),
+    Huffman { class: u8, id: usize, table: HuffmanTable },
+    Quantization { id: usize, table: [u16; 64] },
+    RestartInterval(usize),
+    /// Adobe APP14 colour transform flag
+    Adobe(u8),
+    Jfif,
+    /// JPEG-LS preset coding parameters (LSE, ID 1); zero means default
+    LsParameters { maxval: u16, t1: u16, t2: u16, t3: u16, reset: u16 },
+    /// SOS with the entropy-coded data that follows it
+    Scan(ScanHeader, &'a [u8]),
+}
+
+/// Walks the marker segments of a stream, handing each to `visit`. Scans
+/// are handed over with the rest of the stream, and `visit` returns how
+/// many bytes of it the scan used.
+pub(crate) fn walk<'a>(data: &'a [u8], mut visit: impl FnMut(Segment<'a>) -> Option<usize>) -> Option<()> {
+    if data.len() < 2 || data[0] != 0xFF || data[1] != SOI {
+        eprintln!("JPEG stream does not start with SOI");
+        return None;
+    }
+    let mut pos = 2;
+    loop {
+        // Skip fill bytes before a marker
+        while pos < data.len() && data[pos] != 0xFF {
+            pos += 1;
+        }
+        while pos < data.len() && data[pos] == 0xFF {
+            pos += 1;
+        }
+        let Some(&marker) = data.get(pos) else {
+            // Some encoders leave out EOI
+            return Some(());
+        };
+        pos += 1;
+        if marker == EOI {
+            return Some(());
+        }
+        if (0xD0..=0xD7).contains(&marker) || marker == 0x01 {
+            continue;
+        }
+        let len = u16::from_be_bytes([*data.get(pos)?, *data.get(pos + 1)?]) as usize;
+        let seg = data.get(pos + 2..pos + len)?;
+        pos += len;
+        match marker {
+            0xC0..=0xCF | SOF55 if marker != DHT && marker != 0xC8 && marker != 0xCC => {
+                visit(Segment::Frame(FrameHeader::parse(marker, seg)?))?;
+            }
+            DHT => {
+                let mut p = 0;
+                while p + 17 <= seg.len() {
+                    let class = seg[p] >> 4;
+                    let id = (seg[p] & 15) as usize;
+                    let mut counts = [0u8; 16];
+                    counts.copy_from_slice(&seg[p + 1..p + 17]);
+                    let total: usize = counts.iter().map(|&c| c as usize).sum();
+                    let values = seg.get(p + 17..p + 17 + total)?;
+                    visit(Segment::Huffman { class, id, table: HuffmanTable::new(&counts, values)? })?;
+                    p += 17 + total;
+                }
+            }
+            DQT => {
+                let mut p = 0;
+                while p < seg.len() {
+                    let wide = seg[p] >> 4 == 1;
+                    let id = (seg[p] & 3) as usize;
+                    let mut table = [0u16; 64];
+                    for (k, q) in table.iter_mut().enumerate() {
+                        *q = if wide {
+                            u16::from_be_bytes([*seg.get(p + 1 + 2 * k)?, *seg.get(p + 2 + 2 * k)?])
+                        } else {
+                            *seg.get(p + 1 + k)? as u16
+                        };
+                    }
+                    visit(Segment::Quantization { id, table })?;
+                    p += if wide { 129 } else { 65 };
+                }
+            }
+            DRI => {
+                visit(Segment::RestartInterval(u16::from_be_bytes([*seg.first()?, *seg.get(1)?]) as usize))?;
+            }
+            APP0 if seg.starts_with(b"JFIF\0") => {
+                visit(Segment::Jfif)?;
+            }
+            LSE if seg.first() == Some(&1) && seg.len() >= 11 => {
+                let field = |i: usize| u16::from_be_bytes([seg[i], seg[i + 1]]);
+                visit(Segment::LsParameters { maxval: field(1), t1: field(3), t2: field(5), t3: field(7), reset: field(9) })?;
+            }
+            APP14 if seg.starts_with(b"Adobe") && seg.len() >= 12 => {
+                visit(Segment::Adobe(seg[11]))?;
+            }
+            SOS => {
+                let n = *seg.first()? as usize;
+                if n == 0 {
+                    return None;
+                }
+                let components = (0..n)
+                    .map(|i| Some((*seg.get(1 + 2 * i)? as usize, (seg.get(2 + 2 * i)? >> 4) as usize, (seg[2 + 2 * i] & 15) as usize)))
+                    .collect::<Option<Vec<_>>>()?;
+                let tail = seg.get(1 + 2 * n..1 + 2 * n + 3)?;
+                let header = ScanHeader { components, ss: tail[0], se: tail[1], al: tail[2] & 15 };
+                pos += visit(Segment::Scan(header, &data[pos..]))?;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Decodes one JPEG Baseline or Extended frame into interleaved little
+/// endian samples. Three-component images come back as RGB.
+pub fn decode_frame(desc: &PixelDescription, data: &[u8]) -> Option<Vec<u8>> {
+    let mut frame: Option<FrameHeader> = None;
+    let mut dc_tables: [Option<HuffmanTable>; 4] = Default::default();
+    let mut ac_tables: [Option<HuffmanTable>; 4] = Default::default();
+    let mut quant = [[0u16; 64]; 4];
+    let mut restart_interval = 0;
+    let mut adobe: Option<u8> = None;
+    let mut jfif = false;
+    let mut planes: Vec<Vec<i32>> = Vec::new();
+
+    walk(data, |segment| {
+        match segment {
+            Segment::Frame(header) => {
+                match header.process {
+                    0xC0 | 0xC1 => {}
+                    0xC2 | 0xC6 | 0xCA | 0xCE => {
+                        eprintln!("Progressive JPEG is not supported");
+                        return None;
+                    }
+                    p => {
+                        eprintln!("JPEG process with SOF marker FF{p:02X} is not supported by the DCT decoder");
+                        return None;
+                    }
+                }
+                if header.precision != 8 && header.precision != 12 {
+                    eprintln!("JPEG precision {} is not 8 or 12", header.precision);
+                    return None;
+                }
+                if !header.matches(desc) {
+                    return None;
+                }
+                let layout = BlockLayout::new(&header);
+                planes = header
+                    .components
+                    .iter()
+                    .map(|c| vec![0; layout.plane_width(c) * layout.plane_height(c)])
+                    .collect();
+                frame = Some(header);
+            }
+            Segment::Huffman { class, id, table } => {
+                let tables = if class == 0 { &mut dc_tables } else { &mut ac_tables };
+                *tables.get_mut(id)? = Some(table);
+            }
+            Segment::Quantization { id, table } => quant[id] = table,
+            Segment::RestartInterval(n) => restart_interval = n,
+            Segment::Adobe(transform) => adobe = Some(transform),
+            Segment::Jfif => jfif = true,
+            Segment::LsParameters { .. } => {}
+            Segment::Scan(scan, rest) => {
+                let header = frame.as_ref()?;
+                let tables = ScanTables { dc: &dc_tables, ac: &ac_tables, quant: &quant };
+                return decode_scan(header, &scan, &tables, restart_interval, rest, &mut planes);
+            }
+        }
+        Some(0)
+    })?;
+
+    let header = match frame {
+        Some(h) => h,
+        None => {
+            eprintln!("JPEG stream has no frame header");
+            return None;
+        }
+    };
+    // Colour transform: APP14 wins, then JFIF, then component ids, then the
+    // Photometric Interpretation of the dataset
+    let ycbcr = header.components.len() == 3
+        && match adobe {
+            Some(transform) => transform == 1,
+            None if jfif => true,
+            None if header.components.iter().map(|c| c.id).eq(*b"RGB") => false,
+            None => desc.photometric_interpretation.starts_with("YBR"),
+        };
+    Some(assemble(&header, &planes, ycbcr, desc))
+}
+
+pub(crate) struct ScanTables<'t> {
+    dc: &'t [Option<HuffmanTable>; 4],
+    ac: &'t [Option<HuffmanTable>; 4],
+    quant: &'t [[u16; 64]; 4],
+}
+
+/// Block grid of a frame: the MCU size follows the largest sampling factors
+struct BlockLayout {
+    hmax: usize,
+    vmax: usize,
+    mcus_x: usize,
+    mcus_y: usize,
+}
+
+impl BlockLayout {
+    fn new(header: &FrameHeader) -> Self {
+        let hmax = header.components.iter().map(|c| c.h).max().unwrap_or(1);
+        let vmax = header.components.iter().map(|c| c.v).max().unwrap_or(1);
+        Self {
+            hmax,
+            vmax,
+            mcus_x: header.samples_per_line.div_ceil(8 * hmax),
+            mcus_y: header.lines.div_ceil(8 * vmax),
+        }
+    }
+
+    fn plane_width(&self, c: &FrameComponent) -> usize {
+        self.mcus_x * c.h * 8
+    }
+
+    fn plane_height(&self, c: &FrameComponent) -> usize {
+        self.mcus_y * c.v * 8
+    }
+}
+
+fn decode_scan(
+    header: &FrameHeader,
+    scan: &ScanHeader,
+    tables: &ScanTables,
+    restart_interval: usize,
+    data: &[u8],
+    planes: &mut [Vec<i32>],
+) -> Option<usize> {
+    if scan.ss != 0 || scan.se != 63 || scan.al != 0 {
+        eprintln!("JPEG scan is not sequential (Ss {}, Se {}, Al {})", scan.ss, scan.se, scan.al);
+        return None;
+    }
+    let layout = BlockLayout::new(header);
+    let mut bits = BitReader::new(data);
+    let mut preds = vec![0i32; scan.components.len()];
+    let level_shift = 1i32 << (header.precision - 1);
+    let max = (1i32 << header.precision) - 1;
+    // Largest magnitude categories a DCT coefficient of this precision can
+    // have (T.81 Tables F.1 and F.2)
+    let (dc_max, ac_max) = (header.precision as u32 + 3, header.precision as u32 + 2);
+    let mut coefs = [0i32; 64];
+    let mut out = [0i32; 64];
+
+    // A single-component scan codes its blocks one at a time in raster order
+    // rather than in MCUs (T.81 Section A.2.2)
+    let single = scan.components.len() == 1;
+    let units: Vec<(usize, usize)> = if single {
+        let ci = component_index(header, scan.components[0].0)?;
+        let c = &header.components[ci];
+        let bw = (header.samples_per_line * c.h).div_ceil(layout.hmax).div_ceil(8);
+        let bh = (header.lines * c.v).div_ceil(layout.vmax).div_ceil(8);
+        (0..bh).flat_map(|y| (0..bw).map(move |x| (x, y))).collect()
+    } else {
+        (0..layout.mcus_y).flat_map(|my| (0..layout.mcus_x).map(move |mx| (mx, my))).collect()
+    };
+
+    for (n, &(ux, uy)) in units.iter().enumerate() {
+        if restart_interval > 0 && n > 0 && n % restart_interval == 0 {
+            if !bits.restart() {
+                eprintln!("Missing JPEG restart marker");
+                return None;
+            }
+            preds.iter_mut().for_each(|p| *p = 0);
+        }
+        for (si, &(cid, td, ta)) in scan.components.iter().enumerate() {
+            let ci = component_index(header, cid)?;
+            let c = &header.components[ci];
+            let blocks: Vec<(usize, usize)> = if single {
+                vec![(ux, uy)]
+            } else {
+                (0..c.v).flat_map(|by| (0..c.h).map(move |bx| (ux * c.h + bx, uy * c.v + by))).collect()
+            };
+            let dc = tables.dc.get(td)?.as_ref()?;
+            let ac = tables.ac.get(ta)?.as_ref()?;
+            let q = &tables.quant[c.tq];
+            let width = layout.plane_width(c);
+            for (bx, by) in blocks {
+                coefs.fill(0);
+                let t = dc.decode(&mut bits)? as u32;
+                if t > dc_max {
+                    eprintln!("JPEG DC difference category {t} is out of range");
+                    return None;
+                }
+                // Corrupt data can still walk the prediction out of range;
+                // wrap rather than overflow
+                preds[si] = preds[si].wrapping_add(bits.receive_extend(t));
+                coefs[0] = preds[si].wrapping_mul(q[0] as i32);
+                let mut k = 1;
+                while k < 64 {
+                    let rs = ac.decode(&mut bits)?;
+                    let (r, s) = ((rs >> 4) as usize, (rs & 15) as u32);
+                    if s == 0 {
+                        if r != 15 {
+                            break;
+                        }
+                        k += 16;
+                        continue;
+                    }
+                    if s > ac_max {
+                        eprintln!("JPEG AC coefficient category {s} is out of range");
+                        return None;
+                    }
+                    k += r;
+                    if k > 63 {
+                        break;
+                    }
+                    coefs[ZIGZAG[k]] = bits.receive_extend(s) * q[k] as i32;
+                    k += 1;
+                }
+                idct(&coefs, &mut out);
+                let plane = &mut planes[ci];
+                for y in 0..8 {
+                    let row = (by * 8 + y) * width + bx * 8;
+                    if row + 8 > plane.len() {
+                        break;
+                    }
+                    for x in 0..8 {
+                        plane[row + x] = (out[y * 8 + x] + level_shift).clamp(0, max);
+                    }
+                }
+            }
+        }
+    }
+    Some(bits.position())
+}
+
+pub(crate) fn component_index(header: &FrameHeader, id: usize) -> Option<usize> {
+    header.components.iter().position(|c| c.id as usize == id)
+}
+
+/// Separable floating point inverse DCT (T.81 Section A.3.3)
+fn idct(input: &[i32; 64], output: &mut [i32; 64]) {
+    use std::sync::OnceLock;
+    static COS: OnceLock<[[f32; 8]; 8]> = OnceLock::new();
+    let cos = COS.get_or_init(|| {
+        let mut t = [[0f32; 8]; 8];
+        for (x, row) in t.iter_mut().enumerate() {
+            for (u, v) in row.iter_mut().enumerate() {
+                let cu = if u == 0 { std::f32::consts::FRAC_1_SQRT_2 } else { 1.0 };
+                *v = cu * ((2 * x + 1) as f32 * u as f32 * std::f32::consts::PI / 16.0).cos() / 2.0;
+            }
+        }
+        t
+    });
+    let mut tmp = [0f32; 64];
+    for y in 0..8 {
+        for x in 0..8 {
+            tmp[y * 8 + x] = (0..8).map(|u| cos[x][u] * input[y * 8 + u] as f32).sum();
+        }
+    }
+    for x in 0..8 {
+        for y in 0..8 {
+            let v: f32 = (0..8).map(|v| cos[y][v] * tmp[v * 8 + x]).sum();
+            output[y * 8 + x] = v.round() as i32;
+        }
+    }
+}
+
+/// Upsamples the component planes to full size and interleaves them,
+/// converting YCbCr to RGB when needed.
+fn assemble(header: &FrameHeader, planes: &[Vec<i32>], ycbcr: bool, desc: &PixelDescription) -> Vec<u8> {
+    let layout = BlockLayout::new(header);
+    let (w, h) = (header.samples_per_line, header.lines);
+    let n = header.components.len();
+    let wide = desc.bits_allocated > 8;
+    let max = (1i32 << header.precision) - 1;
+    let center = 1i32 << (header.precision - 1);
+    let mut out = Vec::with_capacity(w * h * n * if wide { 2 } else { 1 });
+    let mut pixel = vec![0i32; n];
+    for y in 0..h {
+        for x in 0..w {
+            for (ci, c) in header.components.iter().enumerate() {
+                let sx = x * c.h / layout.hmax;
+                let sy = y * c.v / layout.vmax;
+                pixel[ci] = planes[ci][sy * layout.plane_width(c) + sx];
+            }
+            if ycbcr {
+                let (yy, cb, cr) = (pixel[0] as f32, (pixel[1] - center) as f32, (pixel[2] - center) as f32);
+                pixel[0] = (yy + 1.402 * cr).round() as i32;
+                pixel[1] = (yy - 0.344136 * cb - 0.714136 * cr).round() as i32;
+                pixel[2] = (yy + 1.772 * cb).round() as i32;
+            }
+            for &v in &pixel {
+                let v = v.clamp(0, max);
+                if wide {
+                    out.extend_from_slice(&(v as u16).to_le_bytes());
+                } else {
+                    out.push(v as u8);
+                }
+            }
+        }
+    }
+    out
+}
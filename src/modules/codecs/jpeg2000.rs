@@ -0,0 +1,310 @@
+//! JPEG 2000 (1.2.840.10008.1.2.4.90 and .91) and High-Throughput JPEG 2000
+//! (1.2.840.10008.1.2.4.201 to .203) decoding.
+//!
+//! Part 1 codestreams are decoded in pure Rust by hayro-jpeg2000. The
+//! `openjpeg` feature switches to OpenJPEG, compiled from the bundled
+//! sources, which also decodes the HT block coder. Either way the inverse
+//! multi-component transform (RCT or ICT) is applied, so YBR_RCT and
+//! YBR_ICT images come back as RGB.
+
+use crate::modules::pixel::PixelDescription;
+
+/// Frame decoded at a lower resolution level
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReducedFrame {
+    pub rows: usize,
+    pub columns: usize,
+    /// Interleaved little endian samples
+    pub data: Vec<u8>,
+}
+
+/// Decoded components, one plane per component
+struct Components {
+    width: usize,
+    height: usize,
+    planes: Vec<Vec<i32>>,
+}
+
+/// Image and component geometry from the SIZ marker (ISO/IEC 15444-1
+/// Section A.5.1), the fewest decomposition levels given by the COD and COC
+/// markers of the main header, and whether it has a CAP marker, which
+/// announces Part 15 (HT) block coding.
+#[cfg_attr(feature = "openjpeg", allow(dead_code))]
+struct CodestreamInfo {
+    width: u32,
+    height: u32,
+    /// (precision, signed) of each component
+    components: Vec<(u8, bool)>,
+    levels: u8,
+    capabilities: bool,
+}
+
+const SOC: [u8; 2] = [0xFF, 0x4F];
+const SIZ: u16 = 0xFF51;
+const CAP: u16 = 0xFF50;
+const COD: u16 = 0xFF52;
+const COC: u16 = 0xFF53;
+const SOT: u16 = 0xFF90;
+
+impl CodestreamInfo {
+    fn parse(data: &[u8]) -> Option<Self> {
+        // Skip a JP2 wrapper, if any, by looking for the codestream itself
+        let start = data.windows(4).position(|w| w[..2] == SOC && w[2..] == [0xFF, 0x51])?;
+        let mut pos = start + 2;
+        let mut info: Option<Self> = None;
+        let mut capabilities = false;
+        let mut levels = u8::MAX;
+        while pos + 4 <= data.len() {
+            let marker = u16::from_be_bytes([data[pos], data[pos + 1]]);
+            let len = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+            let seg = data.get(pos + 4..pos + 2 + len)?;
+            match marker {
+                SIZ if seg.len() >= 36 => {
+                    let u32_at = |i: usize| u32::from_be_bytes([seg[i], seg[i + 1], seg[i + 2], seg[i + 3]]);
+                    let count = u16::from_be_bytes([seg[34], seg[35]]) as usize;
+                    let components = (0..count)
+                        .map(|c| seg.get(36 + 3 * c).map(|&s| ((s & 0x7F) + 1, s & 0x80 != 0)))
+                        .collect::<Option<Vec<_>>>()?;
+                    info = Some(Self {
+                        width: u32_at(2).saturating_sub(u32_at(10)),
+                        height: u32_at(6).saturating_sub(u32_at(14)),
+                        components,
+                        levels: 0,
+                        capabilities: false,
+                    });
+                }
+                COD if seg.len() > 5 => levels = levels.min(seg[5]),
+                COC => {
+                    // Ccoc takes two bytes when there are more than 256 components
+                    let wide = info.as_ref().is_some_and(|info| info.components.len() > 256);
+                    if let Some(&n) = seg.get(if wide { 3 } else { 2 }) {
+                        levels = levels.min(n);
+                    }
+                }
+                CAP => capabilities = true,
+                SOT => break,
+                _ => {}
+            }
+            pos += 2 + len;
+        }
+        info.map(|info| Self { levels: levels.min(32), capabilities, ..info })
+    }
+}
+
+/// Decodes one frame at full resolution.
+pub fn decode_frame(desc: &PixelDescription, data: &[u8]) -> Option<Vec<u8>> {
+    decode_frame_reduced(desc, data, 0).map(|frame| frame.data)
+}
+
+/// Decodes one frame with `levels` resolution levels discarded, halving
+/// each dimension per level; useful for thumbnails. The number of levels is
+/// capped at the decomposition levels of the codestream.
+pub fn decode_frame_reduced(desc: &PixelDescription, data: &[u8], levels: u8) -> Option<ReducedFrame> {
+    let info = match CodestreamInfo::parse(data) {
+        Some(info) => info,
+        None => {
+            eprintln!("No JPEG 2000 codestream header found");
+            return None;
+        }
+    };
+    let size = (desc.columns as u32, desc.rows as u32);
+    if (info.width, info.height) != size || info.components.len() != desc.samples_per_pixel {
+        eprintln!(
+            "JPEG 2000 image is {}x{} with {} components, expected {}x{} with {}",
+            info.width,
+            info.height,
+            info.components.len(),
+            desc.columns,
+            desc.rows,
+            desc.samples_per_pixel
+        );
+        return None;
+    }
+    // Samples are truncated to BitsAllocated, so wider ones cannot be kept
+    for &(precision, signed) in &info.components {
+        if precision as u16 > desc.bits_allocated {
+            eprintln!("JPEG 2000 precision {precision} exceeds BitsAllocated {}", desc.bits_allocated);
+            return None;
+        }
+        if signed != desc.signed {
+            eprintln!("JPEG 2000 signed flag {signed} does not match Pixel Representation {}", desc.signed as u8);
+            return None;
+        }
+        if precision as u16 != desc.bits_stored {
+            eprintln!("JPEG 2000 precision {precision} differs from BitsStored {}", desc.bits_stored);
+        }
+    }
+    let levels = levels.min(info.levels);
+    let decoded = decode_components(data, &info, levels)?;
+    if levels == 0 && (decoded.width, decoded.height) != (desc.columns, desc.rows) {
+        eprintln!("Decoded JPEG 2000 frame is {}x{}, expected {}x{}", decoded.width, decoded.height, desc.columns, desc.rows);
+        return None;
+    }
+    let pixels = decoded.width * decoded.height;
+    if decoded.planes.len() != desc.samples_per_pixel || decoded.planes.iter().any(|p| p.len() != pixels) {
+        eprintln!(
+            "JPEG 2000 frame has {} components, or subsampled ones; expected {} full size components",
+            decoded.planes.len(),
+            desc.samples_per_pixel
+        );
+        return None;
+    }
+    let bytes = (desc.bits_allocated as usize).div_ceil(8);
+    let mut out = Vec::with_capacity(pixels * decoded.planes.len() * bytes);
+    for i in 0..pixels {
+        for plane in &decoded.planes {
+            // Two's complement, truncated to the sample size
+            out.extend_from_slice(&plane[i].to_le_bytes()[..bytes.min(4)]);
+        }
+    }
+    Some(ReducedFrame { rows: decoded.height, columns: decoded.width, data: out })
+}
+
+#[cfg(not(feature = "openjpeg"))]
+fn decode_components(data: &[u8], info: &CodestreamInfo, levels: u8) -> Option<Components> {
+    use hayro_jpeg2000::{DecodeSettings, DecoderContext, Image};
+
+    if info.capabilities {
+        eprintln!("The codestream uses HTJ2K block coding; build with the openjpeg feature to decode it");
+        return None;
+    }
+    // The decoder picks its resolution from a target size
+    let target = (levels > 0).then(|| ((info.width >> levels).max(1), (info.height >> levels).max(1)));
+    let settings = DecodeSettings { target_resolution: target, ..Default::default() };
+    let image = match Image::new(data, &settings) {
+        Ok(image) => image,
+        Err(e) => {
+            eprintln!("Invalid JPEG 2000 codestream: {e:?}");
+            return None;
+        }
+    };
+    let mut context = DecoderContext::default();
+    let decoded = match image.decode(&mut context) {
+        Ok(decoded) => decoded,
+        Err(e) => {
+            eprintln!("JPEG 2000 decoding failed: {e:?}");
+            return None;
+        }
+    };
+    let planes = decoded
+        .components()
+        .iter()
+        .zip(&info.components)
+        .map(|(component, &(precision, signed))| {
+            // Samples come back level shifted even for signed components
+            let shift = 1i32 << (precision - 1);
+            let (min, max) = if signed { (-shift, shift - 1) } else { (0, 2 * shift - 1) };
+            component
+                .samples()
+                .iter()
+                .map(|&v| {
+                    let v = v.round() as i32;
+                    if signed { v - shift } else { v }.clamp(min, max)
+                })
+                .collect()
+        })
+        .collect();
+    Some(Components { width: image.width() as usize, height: image.height() as usize, planes })
+}
+
+#[cfg(feature = "openjpeg")]
+fn decode_components(data: &[u8], _info: &CodestreamInfo, levels: u8) -> Option<Components> {
+    use openjpeg_sys as opj;
+    use std::ffi::{c_char, c_void, CStr};
+
+    struct Source<'a> {
+        data: &'a [u8],
+        pos: usize,
+    }
+
+    unsafe extern "C" fn read(buffer: *mut c_void, size: usize, user: *mut c_void) -> usize {
+        // SAFETY: user is the Source passed to opj_stream_set_user_data and
+        // buffer has room for size bytes
+        let source = unsafe { &mut *(user as *mut Source) };
+        let n = size.min(source.data.len() - source.pos);
+        if n == 0 {
+            return usize::MAX;
+        }
+        unsafe { std::ptr::copy_nonoverlapping(source.data[source.pos..].as_ptr(), buffer as *mut u8, n) };
+        source.pos += n;
+        n
+    }
+
+    unsafe extern "C" fn skip(n: i64, user: *mut c_void) -> i64 {
+        let source = unsafe { &mut *(user as *mut Source) };
+        let n = n.clamp(0, (source.data.len() - source.pos) as i64);
+        source.pos += n as usize;
+        n
+    }
+
+    unsafe extern "C" fn seek(n: i64, user: *mut c_void) -> i32 {
+        let source = unsafe { &mut *(user as *mut Source) };
+        if n < 0 || n as usize > source.data.len() {
+            return 0;
+        }
+        source.pos = n as usize;
+        1
+    }
+
+    unsafe extern "C" fn report(message: *const c_char, _user: *mut c_void) {
+        let message = unsafe { CStr::from_ptr(message) };
+        eprint!("OpenJPEG: {}", message.to_string_lossy());
+    }
+
+    let format = if data.starts_with(&SOC) { opj::OPJ_CODEC_FORMAT::OPJ_CODEC_J2K } else { opj::OPJ_CODEC_FORMAT::OPJ_CODEC_JP2 };
+    let mut source = Source { data, pos: 0 };
+    // SAFETY: every OpenJPEG object created here is destroyed before
+    // returning, and source outlives the stream that points to it
+    unsafe {
+        let codec = opj::opj_create_decompress(format);
+        if codec.is_null() {
+            eprintln!("OpenJPEG could not create a decoder");
+            return None;
+        }
+        opj::opj_set_error_handler(codec, Some(report), std::ptr::null_mut());
+        let mut params: opj::opj_dparameters_t = std::mem::zeroed();
+        opj::opj_set_default_decoder_parameters(&mut params);
+        params.cp_reduce = levels as u32;
+        let stream = opj::opj_stream_create(data.len(), 1);
+        if stream.is_null() {
+            eprintln!("OpenJPEG could not create a stream");
+            opj::opj_destroy_codec(codec);
+            return None;
+        }
+        opj::opj_stream_set_read_function(stream, Some(read));
+        opj::opj_stream_set_skip_function(stream, Some(skip));
+        opj::opj_stream_set_seek_function(stream, Some(seek));
+        opj::opj_stream_set_user_data(stream, &mut source as *mut Source as *mut c_void, None);
+        opj::opj_stream_set_user_data_length(stream, data.len() as u64);
+
+        let mut image: *mut opj::opj_image_t = std::ptr::null_mut();
+        let ok = opj::opj_setup_decoder(codec, &mut params) != 0
+            && opj::opj_read_header(stream, codec, &mut image) != 0
+            && opj::opj_decode(codec, stream, image) != 0
+            && opj::opj_end_decompress(codec, stream) != 0;
+        let comps = if ok && !image.is_null() && !(*image).comps.is_null() {
+            std::slice::from_raw_parts((*image).comps, (*image).numcomps as usize)
+        } else {
+            &[]
+        };
+        let result = match comps.first() {
+            Some(first) if comps.iter().all(|c| !c.data.is_null()) => {
+                let planes = comps
+                    .iter()
+                    .map(|c| std::slice::from_raw_parts(c.data, c.w as usize * c.h as usize).to_vec())
+                    .collect();
+                Some(Components { width: first.w as usize, height: first.h as usize, planes })
+            }
+            _ => {
+                eprintln!("JPEG 2000 decoding failed");
+                None
+            }
+        };
+        if !image.is_null() {
+            opj::opj_image_destroy(image);
+        }
+        opj::opj_stream_destroy(stream);
+        opj::opj_destroy_codec(codec);
+        result
+    }
+}
@@ -0,0 +1,375 @@
+//! JPEG Lossless Non-Hierarchical (Process 14, ITU-T T.81 Annex H), for
+//! 1.2.840.10008.1.2.4.57 and .70. The decoder takes any predictor, 2 to 16
+//! bit precision and interleaved or one-component-per-scan data; the
+//! encoder writes a single interleaved scan with Selection Value 1.
+
+use super::jpeg::{component_index, walk, BitReader, FrameHeader, HuffmanTable, ScanHeader, Segment, DHT, EOI, SOI, SOS};
+use crate::modules::pixel::PixelDescription;
+
+const SOF3: u8 = 0xC3;
+
+/// Decodes one lossless frame into interleaved little endian samples.
+pub fn decode_frame(desc: &PixelDescription, data: &[u8]) -> Option<Vec<u8>> {
+    let mut frame: Option<FrameHeader> = None;
+    let mut tables: [Option<HuffmanTable>; 4] = Default::default();
+    let mut restart_interval = 0;
+    let mut planes: Vec<Vec<u16>> = Vec::new();
+
+    walk(data, |segment| {
+        match segment {
+            Segment::Frame(header) => {
+                if header.process != SOF3 {
+                    eprintln!("JPEG process with SOF marker FF{:02X} is not lossless Huffman", header.process);
+                    return None;
+                }
+                if !(2..=16).contains(&header.precision) {
+                    eprintln!("JPEG Lossless precision {} is out of range", header.precision);
+                    return None;
+                }
+                if !header.matches(desc) {
+                    return None;
+                }
+                planes = (0..header.components.len())
+                    .map(|ci| {
+                        let (w, h) = plane_size(&header, ci);
+                        vec![0; w * h]
+                    })
+                    .collect();
+                frame = Some(header);
+            }
+            Segment::Huffman { class: 0, id, table } => *tables.get_mut(id)? = Some(table),
+            Segment::RestartInterval(n) => restart_interval = n,
+            Segment::Scan(scan, rest) => {
+                let header = frame.as_ref()?;
+                return decode_scan(header, &scan, &tables, restart_interval, rest, &mut planes);
+            }
+            _ => {}
+        }
+        Some(0)
+    })?;
+
+    let header = match frame {
+        Some(h) => h,
+        None => {
+            eprintln!("JPEG stream has no frame header");
+            return None;
+        }
+    };
+    if (0..header.components.len()).any(|ci| plane_size(&header, ci) != (header.samples_per_line, header.lines)) {
+        eprintln!("Subsampled JPEG Lossless components are not supported");
+        return None;
+    }
+    let wide = desc.bits_allocated > 8;
+    let pixels = header.samples_per_line * header.lines;
+    let mut out = Vec::with_capacity(pixels * planes.len() * if wide { 2 } else { 1 });
+    for i in 0..pixels {
+        for plane in &planes {
+            if wide {
+                out.extend_from_slice(&plane[i].to_le_bytes());
+            } else {
+                out.push(plane[i] as u8);
+            }
+        }
+    }
+    Some(out)
+}
+
+/// Width and height of a component after subsampling
+fn plane_size(header: &FrameHeader, ci: usize) -> (usize, usize) {
+    let hmax = header.components.iter().map(|c| c.h).max().unwrap_or(1);
+    let vmax = header.components.iter().map(|c| c.v).max().unwrap_or(1);
+    let c = &header.components[ci];
+    ((header.samples_per_line * c.h).div_ceil(hmax), (header.lines * c.v).div_ceil(vmax))
+}
+
+fn decode_scan(
+    header: &FrameHeader,
+    scan: &ScanHeader,
+    tables: &[Option<HuffmanTable>; 4],
+    restart_interval: usize,
+    data: &[u8],
+    planes: &mut [Vec<u16>],
+) -> Option<usize> {
+    let predictor = scan.ss;
+    if !(1..=7).contains(&predictor) {
+        eprintln!("JPEG Lossless predictor {predictor} is not supported");
+        return None;
+    }
+    let components = scan
+        .components
+        .iter()
+        .map(|&(id, td, _)| Some((component_index(header, id)?, tables.get(td)?.as_ref()?)))
+        .collect::<Option<Vec<_>>>()?;
+    if components.len() > 1 && components.iter().any(|&(ci, _)| plane_size(header, ci) != (header.samples_per_line, header.lines)) {
+        eprintln!("Interleaved JPEG Lossless scans with subsampling are not supported");
+        return None;
+    }
+    let (width, height) = plane_size(header, components.first()?.0);
+    let pt = scan.al as u32;
+    let precision = header.precision as u32;
+    if pt >= precision {
+        eprintln!("JPEG Lossless point transform {pt} exceeds the precision {precision}");
+        return None;
+    }
+    let default = 1i32 << (precision - pt - 1);
+    let mask = (1i32 << (precision - pt)) - 1;
+
+    let mut bits = BitReader::new(data);
+    // Position where the current restart interval started, where prediction
+    // starts over as on the first line (T.81 Section H.1.2.1)
+    let mut interval_start = (0, 0);
+    let mut n = 0;
+    for y in 0..height {
+        for x in 0..width {
+            if restart_interval > 0 && n > 0 && n % restart_interval == 0 {
+                if !bits.restart() {
+                    eprintln!("Missing JPEG restart marker");
+                    return None;
+                }
+                interval_start = (y, x);
+            }
+            n += 1;
+            for &(ci, table) in &components {
+                let plane = &mut planes[ci];
+                let at = |yy: usize, xx: usize| plane[yy * width + xx] as i32;
+                let prediction = if (y, x) == interval_start {
+                    default
+                } else if y == interval_start.0 {
+                    at(y, x - 1)
+                } else if x == 0 {
+                    at(y - 1, x)
+                } else {
+                    let (ra, rb, rc) = (at(y, x - 1), at(y - 1, x), at(y - 1, x - 1));
+                    match predictor {
+                        1 => ra,
+                        2 => rb,
+                        3 => rc,
+                        4 => ra + rb - rc,
+                        5 => ra + ((rb - rc) >> 1),
+                        6 => rb + ((ra - rc) >> 1),
+                        _ => (ra + rb) >> 1,
+                    }
+                };
+                let category = table.decode(&mut bits)? as u32;
+                let diff = bits.receive_extend(category);
+                plane[y * width + x] = ((prediction + diff) & 0xFFFF & mask) as u16;
+            }
+        }
+    }
+    if pt > 0 {
+        for &(ci, _) in &components {
+            planes[ci].iter_mut().for_each(|v| *v <<= pt);
+        }
+    }
+    Some(bits.position())
+}
+
+/// Encodes one frame of interleaved little endian samples with predictor 1
+/// and a Huffman table fitted to the frame. The precision is Bits Stored.
+pub fn encode_frame(desc: &PixelDescription, native: &[u8]) -> Option<Vec<u8>> {
+    if desc.bits_allocated > 16 || desc.samples_per_pixel > 4 {
+        eprintln!(
+            "JPEG Lossless cannot encode {} samples of {} bits",
+            desc.samples_per_pixel, desc.bits_allocated
+        );
+        return None;
+    }
+    let precision = desc.bits_stored.clamp(2, 16) as u32;
+    let mask = ((1u32 << precision) - 1) as i32;
+    let samples: Vec<i32> = if desc.bits_allocated > 8 {
+        native.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]]) as i32 & mask).collect()
+    } else {
+        native.iter().map(|&b| b as i32 & mask).collect()
+    };
+    let (w, h, n) = (desc.columns, desc.rows, desc.samples_per_pixel);
+    if samples.len() < w * h * n {
+        eprintln!("Frame has {} samples, expected {}", samples.len(), w * h * n);
+        return None;
+    }
+
+    // Differences against the left neighbour, the one above at the start of
+    // a row, and the default prediction for the first sample
+    let mut diffs = Vec::with_capacity(w * h * n);
+    for y in 0..h {
+        for x in 0..w {
+            for c in 0..n {
+                let at = |yy: usize, xx: usize| samples[(yy * w + xx) * n + c];
+                let prediction = match (y, x) {
+                    (0, 0) => 1 << (precision - 1),
+                    (_, 0) => at(y - 1, 0),
+                    _ => at(y, x - 1),
+                };
+                let mut diff = (at(y, x) - prediction).rem_euclid(65536);
+                if diff > 32768 {
+                    diff -= 65536;
+                }
+                diffs.push(diff);
+            }
+        }
+    }
+    let category = |d: i32| 32 - d.unsigned_abs().leading_zeros();
+    let mut freq = [0u32; 17];
+    for &d in &diffs {
+        freq[category(d) as usize] += 1;
+    }
+    let (counts, values) = optimal_table(&freq);
+    let codes = canonical_codes(&counts, &values);
+
+    let mut out = vec![0xFF, SOI];
+    let sof_len = 8 + 3 * n as u16;
+    out.extend([0xFF, SOF3]);
+    out.extend(sof_len.to_be_bytes());
+    out.push(precision as u8);
+    out.extend((h as u16).to_be_bytes());
+    out.extend((w as u16).to_be_bytes());
+    out.push(n as u8);
+    for c in 0..n {
+        out.extend([c as u8 + 1, 0x11, 0]);
+    }
+    out.extend([0xFF, DHT]);
+    out.extend((19 + values.len() as u16).to_be_bytes());
+    out.push(0x00);
+    out.extend(counts);
+    out.extend(&values);
+    out.extend([0xFF, SOS]);
+    out.extend((6 + 2 * n as u16).to_be_bytes());
+    out.push(n as u8);
+    for c in 0..n {
+        out.extend([c as u8 + 1, 0x00]);
+    }
+    // Ss = predictor 1, Se = 0, Ah/Al = 0
+    out.extend([1, 0, 0]);
+
+    let mut bits = BitWriter::new(&mut out);
+    for &d in &diffs {
+        let s = category(d);
+        let (code, len) = codes[s as usize];
+        bits.write(code, len);
+        if s > 0 && s < 16 {
+            let extra = if d < 0 { d + (1 << s) - 1 } else { d };
+            bits.write(extra as u32, s);
+        }
+    }
+    bits.flush();
+    out.extend([0xFF, EOI]);
+    Some(out)
+}
+
+/// Huffman code lengths for the symbol frequencies, limited to 16 bits
+/// (T.81 Annex K.2). Returns the DHT counts and symbols.
+fn optimal_table(freq: &[u32; 17]) -> ([u8; 16], Vec<u8>) {
+    // One reserved symbol keeps any code from being all ones
+    let mut f: Vec<u64> = freq.iter().map(|&v| v as u64).chain([1]).collect();
+    let mut size = vec![0usize; f.len()];
+    let mut others = vec![None::<usize>; f.len()];
+    loop {
+        let smallest = |skip: Option<usize>| {
+            (0..f.len())
+                .filter(|&i| f[i] > 0 && Some(i) != skip)
+                .min_by_key(|&i| (f[i], std::cmp::Reverse(i)))
+        };
+        let Some(mut c1) = smallest(None) else { break };
+        let Some(mut c2) = smallest(Some(c1)) else { break };
+        f[c1] += f[c2];
+        f[c2] = 0;
+        size[c1] += 1;
+        while let Some(next) = others[c1] {
+            c1 = next;
+            size[c1] += 1;
+        }
+        others[c1] = Some(c2);
+        size[c2] += 1;
+        while let Some(next) = others[c2] {
+            c2 = next;
+            size[c2] += 1;
+        }
+    }
+    let mut bits = [0usize; 33];
+    for &s in &size {
+        if s > 0 {
+            bits[s.min(32)] += 1;
+        }
+    }
+    for i in (17..=32).rev() {
+        while bits[i] > 0 {
+            let mut j = i - 2;
+            while bits[j] == 0 {
+                j -= 1;
+            }
+            bits[i] -= 2;
+            bits[i - 1] += 1;
+            bits[j + 1] += 2;
+            bits[j] -= 1;
+        }
+    }
+    let mut longest = 16;
+    while bits[longest] == 0 {
+        longest -= 1;
+    }
+    bits[longest] -= 1;
+
+    let mut counts = [0u8; 16];
+    for (i, count) in counts.iter_mut().enumerate() {
+        *count = bits[i + 1] as u8;
+    }
+    let mut symbols: Vec<usize> = (0..freq.len()).filter(|&s| size[s] > 0).collect();
+    symbols.sort_by_key(|&s| (size[s], s));
+    (counts, symbols.into_iter().map(|s| s as u8).collect())
+}
+
+/// (code, length) of each of the 17 difference categories
+fn canonical_codes(counts: &[u8; 16], values: &[u8]) -> [(u32, u32); 17] {
+    let mut codes = [(0, 0); 17];
+    let mut code = 0u32;
+    let mut k = 0;
+    for len in 1..=16 {
+        for _ in 0..counts[len as usize - 1] {
+            codes[values[k] as usize] = (code, len);
+            code += 1;
+            k += 1;
+        }
+        code <<= 1;
+    }
+    codes
+}
+
+/// Entropy-coded segment writer: stuffs a zero byte after each 0xFF and pads
+/// the last byte with ones.
+struct BitWriter<'a> {
+    out: &'a mut Vec<u8>,
+    acc: u32,
+    count: u32,
+}
+
+impl<'a> BitWriter<'a> {
+    fn new(out: &'a mut Vec<u8>) -> Self {
+        Self { out, acc: 0, count: 0 }
+    }
+
+    fn write(&mut self, value: u32, len: u32) {
+        for i in (0..len).rev() {
+            self.acc = (self.acc << 1) | ((value >> i) & 1);
+            self.count += 1;
+            if self.count == 8 {
+                self.push();
+            }
+        }
+    }
+
+    fn push(&mut self) {
+        let byte = self.acc as u8;
+        self.out.push(byte);
+        if byte == 0xFF {
+            self.out.push(0x00);
+        }
+        self.acc = 0;
+        self.count = 0;
+    }
+
+    fn flush(&mut self) {
+        if self.count > 0 {
+            let pad = 8 - self.count;
+            self.write((1 << pad) - 1, pad);
+        }
+    }
+}
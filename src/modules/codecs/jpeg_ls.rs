@@ -0,0 +1,701 @@
+//! JPEG-LS (ITU-T T.87) lossless and near-lossless coding, for
+//! 1.2.840.10008.1.2.4.80 and .81: context modelling with the MED predictor,
+//! run mode, the NEAR parameter, all three interleave modes and 2 to 16 bit
+//! samples. Mapping tables and restart intervals are not supported.
+
+use super::jpeg::{walk, FrameHeader, ScanHeader, Segment, EOI, SOF55, SOI, SOS};
+use crate::modules::pixel::PixelDescription;
+
+/// Run length order for each RUNindex (T.87 Table A.2)
+const J: [u32; 32] = [
+    0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+];
+
+/// Default threshold bases for 8-bit samples (T.87 Section C.2.4.1.1)
+const BASIC_T1: i32 = 3;
+const BASIC_T2: i32 = 7;
+const BASIC_T3: i32 = 21;
+
+/// Regular contexts, followed by the two run interruption contexts
+const CONTEXTS: usize = 367;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interleave {
+    None,
+    Line,
+    Sample,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JpegLsOptions {
+    /// Largest allowed error per sample; 0 is lossless
+    pub near: u16,
+    /// How a scan holds the components of a color image
+    pub interleave: Interleave,
+}
+
+impl Default for JpegLsOptions {
+    fn default() -> Self {
+        Self { near: 0, interleave: Interleave::Line }
+    }
+}
+
+/// Coding parameters of a scan (T.87 Section A.2 and C.2.4.1.1)
+#[derive(Debug, Clone, Copy)]
+struct Params {
+    maxval: i32,
+    near: i32,
+    range: i32,
+    qbpp: u32,
+    limit: u32,
+    t1: i32,
+    t2: i32,
+    t3: i32,
+    reset: i32,
+}
+
+/// Default thresholds T1, T2 and T3 for MAXVAL and NEAR (T.87 Section
+/// C.2.4.1.1.1)
+pub fn default_thresholds(maxval: i32, near: i32) -> [i32; 3] {
+    thresholds(maxval, near, [0; 3])
+}
+
+/// Thresholds from an LSE marker, with the defaults standing in for zeros.
+/// Each default is clamped between the threshold before it and MAXVAL.
+fn thresholds(maxval: i32, near: i32, preset: [u16; 3]) -> [i32; 3] {
+    let clamp = |i: i32, j: i32| if i > maxval || i < j { j } else { i };
+    let pick = |preset: u16, default: i32| if preset > 0 { preset as i32 } else { default };
+    if maxval >= 128 {
+        let factor = (maxval.min(4095) + 128) / 256;
+        let t1 = pick(preset[0], clamp(factor * (BASIC_T1 - 2) + 2 + 3 * near, near + 1));
+        let t2 = pick(preset[1], clamp(factor * (BASIC_T2 - 3) + 3 + 5 * near, t1));
+        [t1, t2, pick(preset[2], clamp(factor * (BASIC_T3 - 4) + 4 + 7 * near, t2))]
+    } else {
+        let factor = 256 / (maxval + 1);
+        let t1 = pick(preset[0], clamp((BASIC_T1 / factor + 3 * near).max(2), near + 1));
+        let t2 = pick(preset[1], clamp((BASIC_T2 / factor + 5 * near).max(3), t1));
+        [t1, t2, pick(preset[2], clamp((BASIC_T3 / factor + 7 * near).max(4), t2))]
+    }
+}
+
+impl Params {
+    fn new(precision: u8, near: i32, preset: Option<[u16; 5]>) -> Self {
+        let [maxval, t1, t2, t3, reset] = preset.unwrap_or_default();
+        let maxval = if maxval > 0 { maxval as i32 } else { (1 << precision) - 1 };
+        let range = (maxval + 2 * near) / (2 * near + 1) + 1;
+        let qbpp = 32 - (range as u32 - 1).leading_zeros();
+        let bpp = (32 - (maxval as u32).leading_zeros()).max(2);
+        let limit = 2 * (bpp + bpp.max(8));
+
+        let [t1, t2, t3] = thresholds(maxval, near, [t1, t2, t3]);
+        let reset = if reset > 0 { reset as i32 } else { 64 };
+        Self { maxval, near, range, qbpp, limit, t1, t2, t3, reset }
+    }
+
+    fn quantize_gradient(&self, d: i32) -> i32 {
+        match d {
+            d if d <= -self.t3 => -4,
+            d if d <= -self.t2 => -3,
+            d if d <= -self.t1 => -2,
+            d if d < -self.near => -1,
+            d if d <= self.near => 0,
+            d if d < self.t1 => 1,
+            d if d < self.t2 => 2,
+            d if d < self.t3 => 3,
+            _ => 4,
+        }
+    }
+
+    /// Context index and sign from the local gradients (T.87 Section A.3)
+    fn context(&self, ra: i32, rb: i32, rc: i32, rd: i32) -> (usize, i32) {
+        let mut q = [self.quantize_gradient(rd - rb), self.quantize_gradient(rb - rc), self.quantize_gradient(rc - ra)];
+        let sign = match q.iter().find(|&&v| v != 0) {
+            Some(&v) if v < 0 => -1,
+            _ => 1,
+        };
+        if sign < 0 {
+            q.iter_mut().for_each(|v| *v = -*v);
+        }
+        ((81 * q[0] + 9 * q[1] + q[2]) as usize, sign)
+    }
+
+    fn quantize_error(&self, e: i32) -> i32 {
+        let step = 2 * self.near + 1;
+        match e {
+            _ if self.near == 0 => e,
+            e if e > 0 => (e + self.near) / step,
+            e => -((self.near - e) / step),
+        }
+    }
+
+    fn reduce_error(&self, mut e: i32) -> i32 {
+        if e < 0 {
+            e += self.range;
+        }
+        if e >= (self.range + 1) / 2 {
+            e -= self.range;
+        }
+        e
+    }
+
+    fn reconstruct(&self, prediction: i32, e: i32) -> i32 {
+        let step = 2 * self.near + 1;
+        let mut rx = prediction + e * step;
+        if rx < -self.near {
+            rx += self.range * step;
+        } else if rx > self.maxval + self.near {
+            rx -= self.range * step;
+        }
+        rx.clamp(0, self.maxval)
+    }
+}
+
+/// Bit reader for a JPEG-LS scan: after an 0xFF byte only seven bits
+/// follow, and a set high bit there means a marker.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    byte: u8,
+    left: u32,
+    after_ff: bool,
+}
+
+impl Reader<'_> {
+    fn bit(&mut self) -> u32 {
+        if self.left == 0 {
+            let b = self.data.get(self.pos).copied().unwrap_or(0xFF);
+            if self.after_ff && b & 0x80 != 0 {
+                // End of the scan; pad with zeros without consuming the marker
+                self.byte = 0;
+                self.left = 8;
+            } else {
+                self.pos += 1;
+                self.byte = b;
+                self.left = if self.after_ff { 7 } else { 8 };
+                self.after_ff = b == 0xFF;
+            }
+        }
+        self.left -= 1;
+        (self.byte as u32 >> self.left) & 1
+    }
+}
+
+struct Writer {
+    out: Vec<u8>,
+    byte: u8,
+    count: u32,
+    after_ff: bool,
+}
+
+impl Writer {
+    fn bit(&mut self, b: u32) {
+        self.byte = (self.byte << 1) | b as u8;
+        self.count += 1;
+        if self.count == if self.after_ff { 7 } else { 8 } {
+            self.out.push(self.byte);
+            self.after_ff = self.byte == 0xFF;
+            self.byte = 0;
+            self.count = 0;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        while self.count > 0 {
+            self.bit(0);
+        }
+        if self.after_ff {
+            self.out.push(0);
+        }
+        self.out
+    }
+}
+
+/// The two directions share one implementation of the coding process: when
+/// writing, the given value goes out and comes back; when reading it is
+/// ignored and the decoded value is returned.
+enum Bits<'a> {
+    Read(Reader<'a>),
+    Write(Writer),
+}
+
+impl Bits<'_> {
+    fn bits(&mut self, value: u32, n: u32) -> u32 {
+        match self {
+            Bits::Read(r) => (0..n).fold(0, |acc, _| (acc << 1) | r.bit()),
+            Bits::Write(w) => {
+                for i in (0..n).rev() {
+                    w.bit((value >> i) & 1);
+                }
+                value
+            }
+        }
+    }
+
+    /// Limited length Golomb code (T.87 Section A.5.3)
+    fn golomb(&mut self, value: i32, k: u32, limit: u32, qbpp: u32) -> i32 {
+        let escape = limit - qbpp - 1;
+        match self {
+            Bits::Read(r) => {
+                let mut zeros = 0;
+                while r.bit() == 0 {
+                    zeros += 1;
+                    if zeros > escape {
+                        break;
+                    }
+                }
+                if zeros < escape {
+                    ((zeros << k) | self.bits(0, k)) as i32
+                } else {
+                    self.bits(0, qbpp) as i32 + 1
+                }
+            }
+            Bits::Write(w) => {
+                let high = value as u32 >> k;
+                let zeros = high.min(escape);
+                (0..zeros).for_each(|_| w.bit(0));
+                w.bit(1);
+                if high < escape {
+                    self.bits(value as u32, k);
+                } else {
+                    self.bits(value as u32 - 1, qbpp);
+                }
+                value
+            }
+        }
+    }
+}
+
+/// State of one scan: parameters, context counters and the bit stream
+struct Coder<'a> {
+    params: Params,
+    a: [i32; CONTEXTS],
+    b: [i32; CONTEXTS],
+    c: [i32; CONTEXTS],
+    n: [i32; CONTEXTS],
+    nn: [i32; CONTEXTS],
+    bits: Bits<'a>,
+}
+
+impl<'a> Coder<'a> {
+    fn new(params: Params, bits: Bits<'a>) -> Self {
+        let a0 = ((params.range + 32) / 64).max(2);
+        Self {
+            params,
+            a: [a0; CONTEXTS],
+            b: [0; CONTEXTS],
+            c: [0; CONTEXTS],
+            n: [1; CONTEXTS],
+            nn: [0; CONTEXTS],
+            bits,
+        }
+    }
+
+    fn encoding(&self) -> bool {
+        matches!(self.bits, Bits::Write(_))
+    }
+
+    /// Regular mode sample (T.87 Sections A.4 to A.6); ix is ignored when
+    /// decoding. Returns the reconstructed value.
+    fn regular(&mut self, q: usize, sign: i32, ra: i32, rb: i32, rc: i32, ix: i32) -> i32 {
+        let p = self.params;
+        let med = if rc >= ra.max(rb) {
+            ra.min(rb)
+        } else if rc <= ra.min(rb) {
+            ra.max(rb)
+        } else {
+            ra + rb - rc
+        };
+        let px = (med + sign * self.c[q]).clamp(0, p.maxval);
+        let mut k = 0;
+        while self.n[q] << k < self.a[q] {
+            k += 1;
+        }
+        let special = p.near == 0 && k == 0 && 2 * self.b[q] <= -self.n[q];
+        let mut errval = p.reduce_error(p.quantize_error(sign * (ix - px)));
+        let mapped = match (special, errval >= 0) {
+            (true, true) => 2 * errval + 1,
+            (true, false) => -2 * (errval + 1),
+            (false, true) => 2 * errval,
+            (false, false) => -2 * errval - 1,
+        };
+        let mapped = self.bits.golomb(mapped, k, p.limit, p.qbpp);
+        if !self.encoding() {
+            errval = match (special, mapped & 1 == 1) {
+                (true, true) => (mapped - 1) / 2,
+                (true, false) => -(mapped / 2) - 1,
+                (false, false) => mapped / 2,
+                (false, true) => -(mapped + 1) / 2,
+            };
+        }
+
+        self.b[q] += errval * (2 * p.near + 1);
+        self.a[q] += errval.abs();
+        if self.n[q] == p.reset {
+            self.a[q] >>= 1;
+            self.b[q] = if self.b[q] >= 0 { self.b[q] >> 1 } else { -((1 - self.b[q]) >> 1) };
+            self.n[q] >>= 1;
+        }
+        self.n[q] += 1;
+        if self.b[q] <= -self.n[q] {
+            self.b[q] += self.n[q];
+            if self.c[q] > -128 {
+                self.c[q] -= 1;
+            }
+            if self.b[q] <= -self.n[q] {
+                self.b[q] = -self.n[q] + 1;
+            }
+        } else if self.b[q] > 0 {
+            self.b[q] -= self.n[q];
+            if self.c[q] < 127 {
+                self.c[q] += 1;
+            }
+            if self.b[q] > 0 {
+                self.b[q] = 0;
+            }
+        }
+        p.reconstruct(px, sign * errval)
+    }
+
+    /// Run length of a run starting with `remaining` samples left on the
+    /// line (T.87 Section A.7.1). Returns the length and whether the run
+    /// reached the end of the line.
+    fn run_length(&mut self, run_index: &mut usize, count: usize, remaining: usize) -> (usize, bool) {
+        if self.encoding() {
+            let mut left = count;
+            while left >= 1 << J[*run_index] {
+                self.bits.bits(1, 1);
+                left -= 1 << J[*run_index];
+                *run_index = (*run_index + 1).min(31);
+            }
+            if count == remaining {
+                if left > 0 {
+                    self.bits.bits(1, 1);
+                }
+            } else {
+                self.bits.bits(0, 1);
+                self.bits.bits(left as u32, J[*run_index]);
+            }
+            return (count, count == remaining);
+        }
+        let mut count = 0;
+        loop {
+            if self.bits.bits(0, 1) == 1 {
+                let block = 1 << J[*run_index];
+                let step = block.min(remaining - count);
+                count += step;
+                if step == block {
+                    *run_index = (*run_index + 1).min(31);
+                }
+                if count == remaining {
+                    return (count, true);
+                }
+            } else {
+                count += self.bits.bits(0, J[*run_index]) as usize;
+                return (count.min(remaining - 1), false);
+            }
+        }
+    }
+
+    /// Run interruption sample (T.87 Section A.7.2). The caller lowers
+    /// RUNindex afterwards.
+    fn interruption(&mut self, ra: i32, rb: i32, ritype: bool, run_index: usize, ix: i32) -> i32 {
+        let p = self.params;
+        let ri = ritype as i32;
+        let q = 365 + ritype as usize;
+        let (px, sign) = if ritype { (ra, 1) } else { (rb, if ra > rb { -1 } else { 1 }) };
+        let temp = if ritype { self.a[q] + (self.n[q] >> 1) } else { self.a[q] };
+        let mut k = 0;
+        while self.n[q] << k < temp {
+            k += 1;
+        }
+        let negative_map = k != 0 || 2 * self.nn[q] >= self.n[q];
+        let mut errval = p.reduce_error(p.quantize_error(sign * (ix - px)));
+        let map = if errval < 0 { negative_map } else { errval > 0 && !negative_map };
+        let em = 2 * errval.abs() - ri - map as i32;
+        let em = self.bits.golomb(em, k, p.limit - J[run_index] - 1, p.qbpp);
+        if !self.encoding() {
+            let t = em + ri;
+            let magnitude = (t + (t & 1)) / 2;
+            errval = if negative_map == (t & 1 == 1) { -magnitude } else { magnitude };
+        }
+
+        if errval < 0 {
+            self.nn[q] += 1;
+        }
+        self.a[q] += (em + 1 - ri) >> 1;
+        if self.n[q] == p.reset {
+            self.a[q] >>= 1;
+            self.n[q] >>= 1;
+            self.nn[q] >>= 1;
+        }
+        self.n[q] += 1;
+        p.reconstruct(px, sign * errval)
+    }
+
+    /// One line of a single component. `prev` and `cur` carry one extra
+    /// sample on each side; `src` holds the samples when encoding.
+    fn line(&mut self, prev: &[i32], cur: &mut [i32], src: Option<&[i32]>, run_index: &mut usize) {
+        let w = cur.len() - 2;
+        let ix = |x: usize| src.map_or(0, |s| s[x]);
+        let mut x = 0;
+        while x < w {
+            let (ra, rb, rc, rd) = (cur[x], prev[x + 1], prev[x], prev[x + 2]);
+            let (q, sign) = self.params.context(ra, rb, rc, rd);
+            if q != 0 {
+                cur[x + 1] = self.regular(q, sign, ra, rb, rc, ix(x));
+                x += 1;
+                continue;
+            }
+            let near = self.params.near;
+            let count = match src {
+                Some(s) => s[x..].iter().take_while(|&&v| (v - ra).abs() <= near).count(),
+                None => 0,
+            };
+            let (count, eol) = self.run_length(run_index, count, w - x);
+            cur[x + 1..x + 1 + count].fill(ra);
+            x += count;
+            if !eol {
+                let (ra, rb) = (cur[x], prev[x + 1]);
+                cur[x + 1] = self.interruption(ra, rb, (ra - rb).abs() <= near, *run_index, ix(x));
+                *run_index = run_index.saturating_sub(1);
+                x += 1;
+            }
+        }
+    }
+
+    /// One line of all components, sample interleaved: run mode needs every
+    /// component to qualify, and interruptions use the RItype 0 context.
+    fn sample_line(&mut self, prev: &[Vec<i32>], cur: &mut [Vec<i32>], src: Option<&[&[i32]]>, run_index: &mut usize) {
+        let w = cur[0].len() - 2;
+        let ix = |c: usize, x: usize| src.map_or(0, |s| s[c][x]);
+        let mut x = 0;
+        while x < w {
+            let contexts: Vec<(usize, i32)> = (0..cur.len())
+                .map(|c| self.params.context(cur[c][x], prev[c][x + 1], prev[c][x], prev[c][x + 2]))
+                .collect();
+            if contexts.iter().any(|&(q, _)| q != 0) {
+                for (c, &(q, sign)) in contexts.iter().enumerate() {
+                    cur[c][x + 1] = self.regular(q, sign, cur[c][x], prev[c][x + 1], prev[c][x], ix(c, x));
+                }
+                x += 1;
+                continue;
+            }
+            let near = self.params.near;
+            let run: Vec<i32> = cur.iter().map(|line| line[x]).collect();
+            let count = match src {
+                Some(s) => (x..w).take_while(|&i| run.iter().zip(s).all(|(&v, line)| (line[i] - v).abs() <= near)).count(),
+                None => 0,
+            };
+            let (count, eol) = self.run_length(run_index, count, w - x);
+            for (line, &v) in cur.iter_mut().zip(&run) {
+                line[x + 1..x + 1 + count].fill(v);
+            }
+            x += count;
+            if !eol {
+                for c in 0..cur.len() {
+                    cur[c][x + 1] = self.interruption(cur[c][x], prev[c][x + 1], false, *run_index, ix(c, x));
+                }
+                *run_index = run_index.saturating_sub(1);
+                x += 1;
+            }
+        }
+    }
+
+    /// Codes a whole scan of `planes` components and returns the
+    /// reconstructed samples of each.
+    fn scan(&mut self, w: usize, h: usize, planes: usize, interleave: Interleave, src: Option<&[Vec<i32>]>) -> Vec<Vec<i32>> {
+        let mut prev = vec![vec![0i32; w + 2]; planes];
+        let mut cur = vec![vec![0i32; w + 2]; planes];
+        let mut run_index = vec![0usize; planes];
+        let mut out = vec![Vec::with_capacity(w * h); planes];
+        for y in 0..h {
+            for c in 0..planes {
+                // Edge samples: Ra at the start is the sample above it, Rd at
+                // the end repeats the last sample above
+                cur[c][0] = prev[c][1];
+                prev[c][w + 1] = prev[c][w];
+            }
+            let rows: Option<Vec<&[i32]>> = src.map(|s| s.iter().map(|plane| &plane[y * w..(y + 1) * w]).collect());
+            if interleave == Interleave::Sample && planes > 1 {
+                self.sample_line(&prev, &mut cur, rows.as_deref(), &mut run_index[0]);
+            } else {
+                for c in 0..planes {
+                    self.line(&prev[c], &mut cur[c], rows.as_ref().map(|r| r[c]), &mut run_index[c]);
+                }
+            }
+            for c in 0..planes {
+                out[c].extend_from_slice(&cur[c][1..=w]);
+                std::mem::swap(&mut prev[c], &mut cur[c]);
+            }
+        }
+        out
+    }
+}
+
+/// Decodes one JPEG-LS frame into interleaved little endian samples.
+pub fn decode_frame(desc: &PixelDescription, data: &[u8]) -> Option<Vec<u8>> {
+    let mut frame: Option<FrameHeader> = None;
+    let mut preset: Option<[u16; 5]> = None;
+    let mut planes: Vec<Vec<i32>> = Vec::new();
+
+    walk(data, |segment| {
+        match segment {
+            Segment::Frame(header) => {
+                if header.process != SOF55 {
+                    eprintln!("SOF marker FF{:02X} is not JPEG-LS", header.process);
+                    return None;
+                }
+                if !(2..=16).contains(&header.precision) || header.components.iter().any(|c| c.h != 1 || c.v != 1) {
+                    eprintln!("Unsupported JPEG-LS frame: precision {}, or subsampled components", header.precision);
+                    return None;
+                }
+                if !header.matches(desc) {
+                    return None;
+                }
+                planes = vec![Vec::new(); header.components.len()];
+                frame = Some(header);
+            }
+            Segment::LsParameters { maxval, t1, t2, t3, reset } => preset = Some([maxval, t1, t2, t3, reset]),
+            Segment::RestartInterval(n) if n > 0 => {
+                eprintln!("JPEG-LS restart intervals are not supported");
+                return None;
+            }
+            Segment::Scan(scan, rest) => {
+                let header = frame.as_ref()?;
+                return decode_scan(header, &scan, preset, rest, &mut planes);
+            }
+            _ => {}
+        }
+        Some(0)
+    })?;
+
+    let header = match frame {
+        Some(h) => h,
+        None => {
+            eprintln!("JPEG-LS stream has no frame header");
+            return None;
+        }
+    };
+    let pixels = header.samples_per_line * header.lines;
+    if planes.iter().any(|p| p.len() != pixels) {
+        eprintln!("JPEG-LS stream does not cover every component");
+        return None;
+    }
+    let wide = desc.bits_allocated > 8;
+    let mut out = Vec::with_capacity(pixels * planes.len() * if wide { 2 } else { 1 });
+    for i in 0..pixels {
+        for plane in &planes {
+            if wide {
+                out.extend_from_slice(&(plane[i] as u16).to_le_bytes());
+            } else {
+                out.push(plane[i] as u8);
+            }
+        }
+    }
+    Some(out)
+}
+
+fn decode_scan(
+    header: &FrameHeader,
+    scan: &ScanHeader,
+    preset: Option<[u16; 5]>,
+    data: &[u8],
+    planes: &mut [Vec<i32>],
+) -> Option<usize> {
+    let interleave = match scan.se {
+        0 => Interleave::None,
+        1 => Interleave::Line,
+        2 => Interleave::Sample,
+        ilv => {
+            eprintln!("Invalid JPEG-LS interleave mode {ilv}");
+            return None;
+        }
+    };
+    if scan.al != 0 || scan.components.iter().any(|&(_, td, ta)| td != 0 || ta != 0) {
+        eprintln!("JPEG-LS point transforms and mapping tables are not supported");
+        return None;
+    }
+    let indices = scan
+        .components
+        .iter()
+        .map(|&(id, _, _)| header.components.iter().position(|c| c.id as usize == id))
+        .collect::<Option<Vec<_>>>()?;
+    let params = Params::new(header.precision, scan.ss as i32, preset);
+    let reader = Reader { data, pos: 0, byte: 0, left: 0, after_ff: false };
+    let mut coder = Coder::new(params, Bits::Read(reader));
+    let decoded = coder.scan(header.samples_per_line, header.lines, indices.len(), interleave, None);
+    for (ci, plane) in indices.into_iter().zip(decoded) {
+        planes[ci] = plane;
+    }
+    match coder.bits {
+        Bits::Read(reader) => Some(reader.pos),
+        Bits::Write(_) => None,
+    }
+}
+
+/// Encodes one frame of interleaved little endian samples. The precision is
+/// Bits Stored and the default coding parameters are used.
+pub fn encode_frame(desc: &PixelDescription, native: &[u8], options: &JpegLsOptions) -> Option<Vec<u8>> {
+    if desc.bits_allocated > 16 || desc.samples_per_pixel > 4 {
+        eprintln!("JPEG-LS cannot encode {} samples of {} bits", desc.samples_per_pixel, desc.bits_allocated);
+        return None;
+    }
+    let precision = desc.bits_stored.clamp(2, 16) as u8;
+    let mask = (1i32 << precision) - 1;
+    let samples: Vec<i32> = if desc.bits_allocated > 8 {
+        native.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]]) as i32 & mask).collect()
+    } else {
+        native.iter().map(|&b| b as i32 & mask).collect()
+    };
+    let (w, h, n) = (desc.columns, desc.rows, desc.samples_per_pixel);
+    if samples.len() < w * h * n {
+        eprintln!("Frame has {} samples, expected {}", samples.len(), w * h * n);
+        return None;
+    }
+    // The SOS header has one byte for NEAR (T.87 Annex C.2.3)
+    let near = options.near as i32;
+    if near > 255.min(mask / 2) {
+        eprintln!("NEAR {near} is too large for {precision}-bit samples");
+        return None;
+    }
+    let planes: Vec<Vec<i32>> = (0..n).map(|c| samples.iter().skip(c).step_by(n).take(w * h).copied().collect()).collect();
+    let interleave = if n == 1 { Interleave::None } else { options.interleave };
+
+    let mut out = vec![0xFF, SOI, 0xFF, SOF55];
+    out.extend((8 + 3 * n as u16).to_be_bytes());
+    out.push(precision);
+    out.extend((h as u16).to_be_bytes());
+    out.extend((w as u16).to_be_bytes());
+    out.push(n as u8);
+    for c in 0..n {
+        out.extend([c as u8 + 1, 0x11, 0]);
+    }
+    // One scan per component without interleaving, otherwise a single scan
+    let scans: Vec<Vec<usize>> = match interleave {
+        Interleave::None => (0..n).map(|c| vec![c]).collect(),
+        _ => vec![(0..n).collect()],
+    };
+    let ilv = match interleave {
+        Interleave::None => 0,
+        Interleave::Line => 1,
+        Interleave::Sample => 2,
+    };
+    for components in scans {
+        out.extend([0xFF, SOS]);
+        out.extend((6 + 2 * components.len() as u16).to_be_bytes());
+        out.push(components.len() as u8);
+        for &c in &components {
+            out.extend([c as u8 + 1, 0]);
+        }
+        out.extend([near as u8, ilv, 0]);
+        let src: Vec<Vec<i32>> = components.iter().map(|&c| planes[c].clone()).collect();
+        let writer = Writer { out: Vec::new(), byte: 0, count: 0, after_ff: false };
+        let mut coder = Coder::new(Params::new(precision, near, None), Bits::Write(writer));
+        coder.scan(w, h, components.len(), interleave, Some(&src));
+        if let Bits::Write(writer) = coder.bits {
+            out.extend(writer.finish());
+        }
+    }
+    out.extend([0xFF, EOI]);
+    Some(out)
+}
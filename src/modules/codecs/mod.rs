@@ -0,0 +1,137 @@
+//! Codecs for encapsulated transfer syntaxes. Decoders turn one frame's
+//! fragments into the bytes a native little endian frame would have, with
+//! color samples interleaved, so the pixel layer treats both alike.
+
+pub mod jpeg;
+pub mod jpeg2000;
+pub mod jpeg_lossless;
+pub mod jpeg_ls;
+pub mod rle;
+
+use crate::dataelem::{DataElement, DataElementValue};
+use crate::dataset::{Dataset, EncapsulatedPixelData};
+use crate::modules::pixel::{decode_frame, is_float_pixel_data, PixelDescription};
+use crate::uids::TransferSyntax;
+
+pub const RLE_LOSSLESS: &str = "1.2.840.10008.1.2.5";
+pub const JPEG_BASELINE: &str = "1.2.840.10008.1.2.4.50";
+pub const JPEG_EXTENDED: &str = "1.2.840.10008.1.2.4.51";
+pub const JPEG_LOSSLESS: &str = "1.2.840.10008.1.2.4.57";
+pub const JPEG_LOSSLESS_SV1: &str = "1.2.840.10008.1.2.4.70";
+pub const JPEG_LS_LOSSLESS: &str = "1.2.840.10008.1.2.4.80";
+pub const JPEG_LS_NEAR_LOSSLESS: &str = "1.2.840.10008.1.2.4.81";
+pub const JPEG_2000_LOSSLESS: &str = "1.2.840.10008.1.2.4.90";
+pub const JPEG_2000: &str = "1.2.840.10008.1.2.4.91";
+pub const HTJ2K_LOSSLESS: &str = "1.2.840.10008.1.2.4.201";
+pub const HTJ2K_LOSSLESS_RPCL: &str = "1.2.840.10008.1.2.4.202";
+pub const HTJ2K: &str = "1.2.840.10008.1.2.4.203";
+
+/// NEAR used when encoding JPEG-LS near-lossless through `encode`; call
+/// `jpeg_ls::encode_frame` to choose another.
+pub const JPEG_LS_DEFAULT_NEAR: u16 = 2;
+
+/// Decodes one compressed frame.
+pub fn decode(ts: &TransferSyntax, desc: &PixelDescription, data: &[u8]) -> Option<Vec<u8>> {
+    match ts.entry.uid {
+        RLE_LOSSLESS => rle::decode_frame(desc, data),
+        JPEG_BASELINE | JPEG_EXTENDED => jpeg::decode_frame(desc, data),
+        JPEG_LOSSLESS | JPEG_LOSSLESS_SV1 => jpeg_lossless::decode_frame(desc, data),
+        JPEG_LS_LOSSLESS | JPEG_LS_NEAR_LOSSLESS => jpeg_ls::decode_frame(desc, data),
+        uid if is_jpeg2000(uid) => jpeg2000::decode_frame(desc, data),
+        uid => {
+            eprintln!("No decoder for transfer syntax {uid} ({})", ts.entry.name);
+            None
+        }
+    }
+}
+
+/// True for the JPEG 2000 and HTJ2K transfer syntaxes
+pub fn is_jpeg2000(uid: &str) -> bool {
+    matches!(uid, JPEG_2000_LOSSLESS | JPEG_2000 | HTJ2K_LOSSLESS | HTJ2K_LOSSLESS_RPCL | HTJ2K)
+}
+
+/// Photometric Interpretation of the frames a decoder hands back. The lossy
+/// JPEG decoder undoes the color transform, so YBR color comes back as RGB,
+/// and so do JPEG 2000 frames coded with a multi-component transform.
+pub fn decoded_photometric_interpretation(ts: &TransferSyntax, desc: &PixelDescription) -> String {
+    let pi = desc.photometric_interpretation.as_str();
+    match ts.entry.uid {
+        JPEG_BASELINE | JPEG_EXTENDED if desc.samples_per_pixel == 3 => "RGB".to_string(),
+        uid if is_jpeg2000(uid) && matches!(pi, "YBR_RCT" | "YBR_ICT") => "RGB".to_string(),
+        _ => pi.to_string(),
+    }
+}
+
+/// Encodes one frame of interleaved little endian samples.
+pub fn encode(ts_uid: &str, desc: &PixelDescription, native: &[u8]) -> Option<Vec<u8>> {
+    match ts_uid {
+        RLE_LOSSLESS => rle::encode_frame(desc, native),
+        // Selection Value 1 is also valid for Process 14 with any predictor
+        JPEG_LOSSLESS | JPEG_LOSSLESS_SV1 => jpeg_lossless::encode_frame(desc, native),
+        JPEG_LS_LOSSLESS => jpeg_ls::encode_frame(desc, native, &jpeg_ls::JpegLsOptions::default()),
+        JPEG_LS_NEAR_LOSSLESS => {
+            let options = jpeg_ls::JpegLsOptions { near: JPEG_LS_DEFAULT_NEAR, ..Default::default() };
+            jpeg_ls::encode_frame(desc, native, &options)
+        }
+        uid => {
+            eprintln!("No encoder for transfer syntax {uid}");
+            None
+        }
+    }
+}
+
+/// Compresses every frame of the dataset's pixel data with the given
+/// transfer syntax, one fragment per frame. Frames are encoded as decoded,
+/// so the returned description is the one the fragments hold: samples in
+/// the low bits (HighBit is BitsStored - 1), interleaved, and YBR_FULL for
+/// YBR_FULL_422 frames, which are upsampled. Use `encode_dataset` to get a
+/// dataset with those attributes set.
+pub fn encode_pixel_data(ds: &Dataset, ts_uid: &str) -> Option<(EncapsulatedPixelData, PixelDescription)> {
+    let source = PixelDescription::from_dataset(ds)?;
+    if source.bits_allocated == 1 || is_float_pixel_data(ds) {
+        eprintln!("BitsAllocated {} Pixel Data cannot be encapsulated", source.bits_allocated);
+        return None;
+    }
+    let photometric = match ds.transfer_syntax().filter(|ts| ts.encapsulated) {
+        Some(ts) => decoded_photometric_interpretation(&ts, &source),
+        None => source.photometric_interpretation.clone(),
+    };
+    let desc = PixelDescription {
+        high_bit: source.bits_stored - 1,
+        // Decoded frames are always interleaved and full size
+        planar: false,
+        photometric_interpretation: match photometric.as_str() {
+            "YBR_FULL_422" => "YBR_FULL".to_string(),
+            _ => photometric,
+        },
+        ..source
+    };
+    let frames = (0..desc.number_of_frames)
+        .map(|i| encode(ts_uid, &desc, &decode_frame(ds, i)?.data.to_le_bytes()))
+        .collect::<Option<Vec<_>>>()?;
+    Some((EncapsulatedPixelData::from_frames(frames), desc))
+}
+
+/// Copy of the dataset with its pixel data compressed with the given
+/// transfer syntax, and HighBit, Planar Configuration, Photometric
+/// Interpretation and Transfer Syntax UID updated to match.
+pub fn encode_dataset(ds: &Dataset, ts_uid: &str) -> Option<Dataset> {
+    let (encapsulated, desc) = encode_pixel_data(ds, ts_uid)?;
+    let mut out = ds.clone();
+    out.set(DataElement::new("HighBit", DataElementValue::UInt16(desc.high_bit))?);
+    if desc.samples_per_pixel > 1 {
+        out.set(DataElement::new("PlanarConfiguration", DataElementValue::UInt16(0))?);
+    }
+    let photometric = DataElementValue::String(desc.photometric_interpretation);
+    out.set(DataElement::new("PhotometricInterpretation", photometric)?);
+    let uid = DataElement::new("TransferSyntaxUID", DataElementValue::String(ts_uid.to_string()))?;
+    let mut meta = out.file_meta().to_vec();
+    match meta.iter().position(|e| e.tag >= uid.tag) {
+        Some(i) if meta[i].tag == uid.tag => meta[i] = uid,
+        Some(i) => meta.insert(i, uid),
+        None => meta.push(uid),
+    }
+    out.set_file_meta(meta);
+    out.set_encapsulated_pixel_data(encapsulated);
+    Some(out)
+}
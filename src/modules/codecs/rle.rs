@@ -0,0 +1,144 @@
+//! RLE Lossless (PS3.5 Annex G): each frame is a 64-byte header followed by
+//! up to 15 PackBits segments, one per byte plane of each sample, most
+//! significant byte first.
+
+use crate::modules::pixel::PixelDescription;
+
+const HEADER_LEN: usize = 64;
+const MAX_SEGMENTS: usize = 15;
+
+/// Decodes one RLE frame into interleaved little endian samples.
+pub fn decode_frame(desc: &PixelDescription, data: &[u8]) -> Option<Vec<u8>> {
+    if data.len() < HEADER_LEN {
+        eprintln!("RLE frame of {} bytes is shorter than its header", data.len());
+        return None;
+    }
+    let header: Vec<usize> = data[..HEADER_LEN]
+        .chunks_exact(4)
+        .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]) as usize)
+        .collect();
+    let segments = header[0];
+    let bytes_per_sample = (desc.bits_allocated as usize).div_ceil(8);
+    let expected = desc.samples_per_pixel * bytes_per_sample;
+    if segments != expected || segments > MAX_SEGMENTS {
+        eprintln!("RLE frame has {segments} segments, expected {expected}");
+        return None;
+    }
+    let pixels = desc.rows * desc.columns;
+    // A two-byte replicate run is the most PackBits can expand, 128 bytes,
+    // so short segments are refused before the frame is allocated
+    let mut bounds = Vec::with_capacity(segments);
+    for segment in 0..segments {
+        let start = header[1 + segment];
+        let end = if segment + 1 < segments { header[2 + segment] } else { data.len() };
+        if start < HEADER_LEN || start > end || end > data.len() {
+            eprintln!("RLE segment {segment} offsets {start}..{end} are out of range");
+            return None;
+        }
+        if (end - start) / 2 * 128 < pixels {
+            eprintln!("RLE segment {segment} of {} bytes cannot hold {pixels} pixels", end - start);
+            return None;
+        }
+        bounds.push(start..end);
+    }
+    let mut out = vec![0u8; pixels * expected];
+    for (segment, range) in bounds.into_iter().enumerate() {
+        let plane = unpack_bits(&data[range], pixels)?;
+        // Segment order is sample by sample, most significant byte first
+        let sample = segment / bytes_per_sample;
+        let byte = bytes_per_sample - 1 - segment % bytes_per_sample;
+        for (p, &value) in plane.iter().enumerate() {
+            out[(p * desc.samples_per_pixel + sample) * bytes_per_sample + byte] = value;
+        }
+    }
+    Some(out)
+}
+
+/// Encodes interleaved little endian samples as one RLE frame.
+pub fn encode_frame(desc: &PixelDescription, native: &[u8]) -> Option<Vec<u8>> {
+    let bytes_per_sample = (desc.bits_allocated as usize).div_ceil(8);
+    let segments = desc.samples_per_pixel * bytes_per_sample;
+    if segments > MAX_SEGMENTS {
+        eprintln!("RLE allows at most {MAX_SEGMENTS} segments, {segments} needed");
+        return None;
+    }
+    let pixels = desc.rows * desc.columns;
+    if native.len() < pixels * segments {
+        eprintln!("Frame is {} bytes, expected {}", native.len(), pixels * segments);
+        return None;
+    }
+    let mut out = vec![0u8; HEADER_LEN];
+    out[..4].copy_from_slice(&(segments as u32).to_le_bytes());
+    let mut plane = vec![0u8; pixels];
+    for segment in 0..segments {
+        let offset = out.len() as u32;
+        out[4 + 4 * segment..8 + 4 * segment].copy_from_slice(&offset.to_le_bytes());
+        let sample = segment / bytes_per_sample;
+        let byte = bytes_per_sample - 1 - segment % bytes_per_sample;
+        for (p, value) in plane.iter_mut().enumerate() {
+            *value = native[(p * desc.samples_per_pixel + sample) * bytes_per_sample + byte];
+        }
+        // Runs never cross a row boundary (PS3.5 Section G.3.1)
+        for row in plane.chunks(desc.columns) {
+            pack_bits(row, &mut out);
+        }
+        if out.len() % 2 == 1 {
+            out.push(0);
+        }
+    }
+    Some(out)
+}
+
+/// Expands a PackBits segment until `len` bytes are produced.
+fn unpack_bits(src: &[u8], len: usize) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(len);
+    let mut i = 0;
+    while out.len() < len && i < src.len() {
+        let n = src[i] as i8;
+        i += 1;
+        if n >= 0 {
+            let count = n as usize + 1;
+            let literal = src.get(i..i + count)?;
+            out.extend_from_slice(literal);
+            i += count;
+        } else if n != -128 {
+            let value = *src.get(i)?;
+            out.resize(out.len() + (1 - n as isize) as usize, value);
+            i += 1;
+        }
+    }
+    if out.len() < len {
+        eprintln!("RLE segment decoded to {} bytes, expected {len}", out.len());
+        return None;
+    }
+    out.truncate(len);
+    Some(out)
+}
+
+/// PackBits: runs of three or more equal bytes become replicate runs, the
+/// rest literal runs, each at most 128 bytes.
+fn pack_bits(src: &[u8], out: &mut Vec<u8>) {
+    let mut i = 0;
+    while i < src.len() {
+        let mut run = 1;
+        while i + run < src.len() && run < 128 && src[i + run] == src[i] {
+            run += 1;
+        }
+        if run >= 3 {
+            out.push((1 - run as isize) as u8);
+            out.push(src[i]);
+            i += run;
+            continue;
+        }
+        // Literal run up to the next run of three
+        let start = i;
+        while i < src.len() && i - start < 128 {
+            if i + 2 < src.len() && src[i] == src[i + 1] && src[i] == src[i + 2] {
+                break;
+            }
+            i += 1;
+        }
+        out.push((i - start - 1) as u8);
+        out.extend_from_slice(&src[start..i]);
+    }
+}
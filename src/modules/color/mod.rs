@@ -0,0 +1,379 @@
+use std::collections::HashMap;
+
+use crate::dataelem::{DataElement, DataElementValue};
+use crate::dataset::Dataset;
+use crate::modules::codecs::decoded_photometric_interpretation;
+use crate::modules::lut::{DisplayDepth, Lut};
+use crate::modules::pixel::{decode_frame, encode_native, PixelArray, PixelBuffer, PixelDescription};
+use crate::uids::EXPLICIT_VR_LITTLE_ENDIAN;
+
+/// Red, green and blue Palette Color Lookup Tables (PS3.3 Section C.7.6.3.1.5)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    pub red: Lut,
+    pub green: Lut,
+    pub blue: Lut,
+}
+
+impl Palette {
+    /// Reads the palette from the dataset, from either the Palette Color
+    /// Lookup Table Data or the Segmented Palette Color Lookup Table Data.
+    pub fn from_dataset(ds: &Dataset) -> Option<Self> {
+        let signed = ds.get_int("PixelRepresentation") == Some(1);
+        let channel = |color: &str| {
+            let descriptor = match ds.get(&format!("{color}PaletteColorLookupTableDescriptor")).and_then(|e| e.value.as_ref()) {
+                Some(value) => value.to_u16s(),
+                None => {
+                    eprintln!("Dataset has no {color} Palette Color Lookup Table Descriptor");
+                    return None;
+                }
+            };
+            if let Some(data) = ds.get(&format!("{color}PaletteColorLookupTableData")).and_then(|e| e.value.as_ref()) {
+                return Lut::from_descriptor(&descriptor, data, signed);
+            }
+            let segments = match ds.get(&format!("Segmented{color}PaletteColorLookupTableData")).and_then(|e| e.value.as_ref()) {
+                Some(value) => value.to_u16s(),
+                None => {
+                    eprintln!("Dataset has no {color} Palette Color Lookup Table Data");
+                    return None;
+                }
+            };
+            if descriptor.len() != 3 {
+                eprintln!("LUT Descriptor has {} values, expected 3", descriptor.len());
+                return None;
+            }
+            let entries = if descriptor[0] == 0 { 65536 } else { descriptor[0] as usize };
+            let first_mapped = if signed { descriptor[1] as i16 as i32 } else { descriptor[1] as i32 };
+            let data = expand_segmented_lut(&segments, entries)?;
+            Some(Lut { first_mapped, bits: descriptor[2], data })
+        };
+        Some(Self { red: channel("Red")?, green: channel("Green")?, blue: channel("Blue")? })
+    }
+
+    /// Bits of each color sample: 8, or 16 when any table has wider entries
+    pub fn bits(&self) -> u16 {
+        if [&self.red, &self.green, &self.blue].iter().all(|lut| lut.bits <= 8) { 8 } else { 16 }
+    }
+
+    /// Number of palette entries; the smallest of the three tables
+    pub fn len(&self) -> usize {
+        self.red.data.len().min(self.green.data.len()).min(self.blue.data.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn lookup(&self, index: f64) -> [u16; 3] {
+        [self.red.lookup(index), self.green.lookup(index), self.blue.lookup(index)]
+    }
+
+    /// Index of the entry closest to the color, whose samples have the
+    /// given number of bits.
+    pub fn nearest(&self, rgb: [u16; 3], bits: u16) -> i32 {
+        let scale = ((1u32 << bits) - 1) as f64 / ((1u32 << self.bits()) - 1) as f64;
+        let distance = |i: usize| {
+            let entry = [self.red.data[i], self.green.data[i], self.blue.data[i]];
+            (0..3).map(|c| (entry[c] as f64 * scale - rgb[c] as f64).powi(2)).sum::<f64>()
+        };
+        let best = (0..self.len()).min_by(|&a, &b| distance(a).total_cmp(&distance(b))).unwrap_or(0);
+        self.red.first_mapped + best as i32
+    }
+}
+
+/// Expands Segmented Palette Color Lookup Table Data (PS3.3 Section
+/// C.7.9.2) into `entries` values: discrete, linear and indirect segments.
+pub fn expand_segmented_lut(segments: &[u16], entries: usize) -> Option<Vec<u16>> {
+    let mut out = Vec::with_capacity(entries);
+    let mut pos = 0;
+    while pos < segments.len() && out.len() < entries {
+        pos = expand_segment(segments, pos, &mut out, entries, true)?;
+    }
+    if out.len() < entries {
+        eprintln!("Segmented LUT expands to {} entries, the descriptor announces {entries}", out.len());
+        return None;
+    }
+    out.truncate(entries);
+    Some(out)
+}
+
+/// Appends one segment to `out` and returns the position after it. Nothing
+/// is appended past `entries` values, however many an indirect segment
+/// repeats.
+fn expand_segment(segments: &[u16], pos: usize, out: &mut Vec<u16>, entries: usize, indirect: bool) -> Option<usize> {
+    let (opcode, length) = match segments.get(pos..pos + 2) {
+        Some(header) => (header[0], header[1] as usize),
+        None => {
+            eprintln!("Segmented LUT ends inside a segment header");
+            return None;
+        }
+    };
+    match opcode {
+        // Discrete: the values themselves
+        0 => {
+            let values = segments.get(pos + 2..pos + 2 + length)?;
+            out.extend_from_slice(values);
+            Some(pos + 2 + length)
+        }
+        // Linear: a ramp from the previous value to the given one
+        1 => {
+            let (start, end) = match (out.last(), segments.get(pos + 2)) {
+                (Some(&start), Some(&end)) => (start as f64, end as f64),
+                _ => {
+                    eprintln!("Linear segment without a preceding value");
+                    return None;
+                }
+            };
+            let ramp = (1..=length).map(|i| (start + (end - start) * i as f64 / length as f64).round() as u16);
+            out.extend(ramp.take(entries.saturating_sub(out.len())));
+            Some(pos + 3)
+        }
+        // Indirect: repeats `length` segments found at a byte offset
+        2 if indirect => {
+            let words = segments.get(pos + 2..pos + 4)?;
+            let offset = (words[0] as usize | (words[1] as usize) << 16) / 2;
+            let mut at = offset;
+            for _ in 0..length {
+                if out.len() >= entries {
+                    break;
+                }
+                at = expand_segment(segments, at, out, entries, false)?;
+            }
+            Some(pos + 4)
+        }
+        _ => {
+            eprintln!("Unsupported segment type {opcode} in Segmented LUT");
+            None
+        }
+    }
+}
+
+/// Converts decoded frames to interleaved RGB. `desc` gives the Photometric
+/// Interpretation and bit depth of the frames; PALETTE COLOR needs the
+/// palette. YBR_FULL_422 frames are expected at full size, as the pixel
+/// layer returns them. YBR_ICT uses the YBR_FULL equations it shares, and
+/// YBR_RCT the reversible transform with chroma offset by half the range;
+/// JPEG 2000 frames already come back as RGB.
+pub fn to_rgb(array: &PixelArray, desc: &PixelDescription, palette: Option<&Palette>) -> Option<PixelArray> {
+    let pi = desc.photometric_interpretation.as_str();
+    if pi == "PALETTE COLOR" {
+        let palette = match palette {
+            Some(palette) => palette,
+            None => {
+                eprintln!("PALETTE COLOR needs a palette");
+                return None;
+            }
+        };
+        if array.samples != 1 {
+            eprintln!("PALETTE COLOR needs one sample per pixel, got {}", array.samples);
+            return None;
+        }
+        let colors = (0..array.data.len()).flat_map(|i| palette.lookup(array.data.get(i)));
+        let data = if palette.bits() == 8 {
+            PixelBuffer::U8(colors.map(|v| v as u8).collect())
+        } else {
+            PixelBuffer::U16(colors.collect())
+        };
+        return Some(PixelArray { frames: array.frames, rows: array.rows, columns: array.columns, samples: 3, data });
+    }
+    let convert: fn([f64; 3], f64) -> [f64; 3] = match pi {
+        "RGB" if array.samples == 3 => return Some(array.clone()),
+        "YBR_FULL" | "YBR_FULL_422" | "YBR_ICT" => ybr_full_to_rgb,
+        "YBR_RCT" => ybr_rct_to_rgb,
+        _ => {
+            eprintln!("Cannot convert {pi} to RGB");
+            return None;
+        }
+    };
+    transform(array, desc.bits_stored, convert)
+}
+
+/// Converts interleaved RGB frames whose samples have `bits` bits to the
+/// given Photometric Interpretation. YBR_FULL_422 comes back at full size;
+/// encode_native packs the pixel pairs. YBR_RCT chroma that does not fit
+/// the sample range is clamped. PALETTE COLOR maps each color to the
+/// nearest palette entry.
+pub fn from_rgb(array: &PixelArray, bits: u16, photometric: &str, palette: Option<&Palette>) -> Option<PixelArray> {
+    if array.samples != 3 {
+        eprintln!("RGB needs three samples per pixel, got {}", array.samples);
+        return None;
+    }
+    if photometric == "PALETTE COLOR" {
+        let palette = match palette {
+            Some(palette) => palette,
+            None => {
+                eprintln!("PALETTE COLOR needs a palette");
+                return None;
+            }
+        };
+        let mut cache: HashMap<[u16; 3], i32> = HashMap::new();
+        let indices: Vec<i32> = (0..array.data.len() / 3)
+            .map(|p| {
+                let rgb = [0, 1, 2].map(|c| array.data.get(3 * p + c) as u16);
+                *cache.entry(rgb).or_insert_with(|| palette.nearest(rgb, bits))
+            })
+            .collect();
+        let data = if palette.red.first_mapped + palette.len() as i32 <= 256 {
+            PixelBuffer::U8(indices.into_iter().map(|i| i as u8).collect())
+        } else {
+            PixelBuffer::U16(indices.into_iter().map(|i| i as u16).collect())
+        };
+        return Some(PixelArray { frames: array.frames, rows: array.rows, columns: array.columns, samples: 1, data });
+    }
+    let convert: fn([f64; 3], f64) -> [f64; 3] = match photometric {
+        "RGB" => return Some(array.clone()),
+        "YBR_FULL" | "YBR_FULL_422" | "YBR_ICT" => rgb_to_ybr_full,
+        "YBR_RCT" => rgb_to_ybr_rct,
+        _ => {
+            eprintln!("Cannot convert RGB to {photometric}");
+            return None;
+        }
+    };
+    transform(array, bits, convert)
+}
+
+/// Applies a per-pixel color transform to unsigned 8 or 16-bit samples,
+/// rounding and clamping to the range of `bits`.
+fn transform(array: &PixelArray, bits: u16, convert: fn([f64; 3], f64) -> [f64; 3]) -> Option<PixelArray> {
+    let half = (1u32 << (bits - 1)) as f64;
+    let max = 2.0 * half - 1.0;
+    let values = |get: &dyn Fn(usize) -> f64| -> Vec<f64> {
+        (0..array.data.len() / 3)
+            .flat_map(|p| convert([get(3 * p), get(3 * p + 1), get(3 * p + 2)], half))
+            .map(|v| v.round().clamp(0.0, max))
+            .collect()
+    };
+    let data = match &array.data {
+        PixelBuffer::U8(v) => PixelBuffer::U8(values(&|i| v[i] as f64).into_iter().map(|s| s as u8).collect()),
+        PixelBuffer::U16(v) => PixelBuffer::U16(values(&|i| v[i] as f64).into_iter().map(|s| s as u16).collect()),
+        _ => {
+            eprintln!("Color conversion needs unsigned 8 or 16-bit samples");
+            return None;
+        }
+    };
+    Some(PixelArray { frames: array.frames, rows: array.rows, columns: array.columns, samples: 3, data })
+}
+
+/// PS3.3 Section C.7.6.3.1.2, inverted
+fn ybr_full_to_rgb([y, cb, cr]: [f64; 3], half: f64) -> [f64; 3] {
+    let (cb, cr) = (cb - half, cr - half);
+    [y + 1.402 * cr, y - 0.344136 * cb - 0.714136 * cr, y + 1.772 * cb]
+}
+
+fn rgb_to_ybr_full([r, g, b]: [f64; 3], half: f64) -> [f64; 3] {
+    [
+        0.299 * r + 0.587 * g + 0.114 * b,
+        -0.168736 * r - 0.331264 * g + 0.5 * b + half,
+        0.5 * r - 0.418688 * g - 0.081312 * b + half,
+    ]
+}
+
+/// Reversible color transform of ISO/IEC 15444-1 Annex G.2
+fn ybr_rct_to_rgb([y, cb, cr]: [f64; 3], half: f64) -> [f64; 3] {
+    let (cb, cr) = (cb - half, cr - half);
+    let g = y - ((cb + cr) / 4.0).floor();
+    [cr + g, g, cb + g]
+}
+
+fn rgb_to_ybr_rct([r, g, b]: [f64; 3], half: f64) -> [f64; 3] {
+    [((r + 2.0 * g + b) / 4.0).floor(), b - g + half, r - g + half]
+}
+
+/// Decodes one frame as interleaved RGB, whatever its Photometric
+/// Interpretation and Planar Configuration.
+pub fn decode_rgb_frame(ds: &Dataset, frame: usize) -> Option<PixelArray> {
+    let (desc, palette) = decoded_color(ds)?;
+    to_rgb(&decode_frame(ds, frame)?, &desc, palette.as_ref())
+}
+
+/// Description of the frames the pixel layer decodes from the dataset, with
+/// the palette when they are PALETTE COLOR
+fn decoded_color(ds: &Dataset) -> Option<(PixelDescription, Option<Palette>)> {
+    let mut desc = PixelDescription::from_dataset(ds)?;
+    if let Some(ts) = ds.transfer_syntax().filter(|ts| ts.encapsulated) {
+        desc.photometric_interpretation = decoded_photometric_interpretation(&ts, &desc);
+    }
+    let palette = match desc.photometric_interpretation.as_str() {
+        "PALETTE COLOR" => Some(Palette::from_dataset(ds)?),
+        _ => None,
+    };
+    Some((desc, palette))
+}
+
+/// RGB display values of the given depth for color frames decoded from the
+/// dataset, possibly at a reduced resolution. Samples are scaled from the
+/// palette's bits or Bits Stored to the full display range.
+pub fn render_rgb(ds: &Dataset, array: &PixelArray, depth: DisplayDepth) -> Option<PixelArray> {
+    let (desc, palette) = decoded_color(ds)?;
+    let bits = palette.as_ref().map_or(desc.bits_stored, |p| p.bits());
+    let rgb = to_rgb(array, &desc, palette.as_ref())?;
+    let scale = |max: f64| move |v: f64| (v * max / ((1u64 << bits) - 1) as f64).round();
+    let values = (0..rgb.data.len()).map(|i| rgb.data.get(i));
+    let data = match depth {
+        DisplayDepth::Bits8 => PixelBuffer::U8(values.map(scale(u8::MAX as f64)).map(|v| v as u8).collect()),
+        DisplayDepth::Bits16 => PixelBuffer::U16(values.map(scale(u16::MAX as f64)).map(|v| v as u16).collect()),
+    };
+    Some(PixelArray { data, ..rgb })
+}
+
+/// Copy of a color dataset with its Pixel Data converted to another
+/// Photometric Interpretation and Planar Configuration, stored as native
+/// little endian. Converting to PALETTE COLOR uses the palette attributes
+/// already in the dataset.
+pub fn convert_photometric(ds: &Dataset, photometric: &str, planar: bool) -> Option<Dataset> {
+    let desc = PixelDescription::from_dataset(ds)?;
+    let palette = match photometric {
+        "PALETTE COLOR" => Some(Palette::from_dataset(ds)?),
+        _ => None,
+    };
+    let bits = match desc.photometric_interpretation.as_str() {
+        "PALETTE COLOR" => Palette::from_dataset(ds)?.bits(),
+        _ => desc.bits_stored,
+    };
+    let (samples, bits_allocated, bits_stored) = match &palette {
+        Some(p) if p.red.first_mapped + p.len() as i32 <= 256 => (1, 8, 8),
+        Some(_) => (1, 16, 16),
+        None => (3, bits.next_multiple_of(8).max(8), bits),
+    };
+    let target = PixelDescription {
+        samples_per_pixel: samples,
+        bits_allocated,
+        bits_stored,
+        high_bit: bits_stored - 1,
+        signed: false,
+        planar: planar && samples == 3,
+        photometric_interpretation: photometric.to_string(),
+        ..desc.clone()
+    };
+    let mut native = Vec::with_capacity(target.stored_frame_bytes()?.checked_mul(desc.number_of_frames)?);
+    for frame in 0..desc.number_of_frames {
+        let rgb = decode_rgb_frame(ds, frame)?;
+        native.extend(encode_native(&from_rgb(&rgb, bits, photometric, palette.as_ref())?, &target)?);
+    }
+
+    let mut out = ds.clone();
+    let mut attributes = vec![
+        ("SamplesPerPixel", samples as u16),
+        ("BitsAllocated", bits_allocated),
+        ("BitsStored", bits_stored),
+        ("HighBit", bits_stored - 1),
+        ("PixelRepresentation", 0),
+    ];
+    if samples == 3 {
+        attributes.push(("PlanarConfiguration", target.planar as u16));
+    }
+    for (keyword, value) in attributes {
+        out.set(DataElement::new(keyword, DataElementValue::UInt16(value))?);
+    }
+    out.set(DataElement::new("PhotometricInterpretation", DataElementValue::String(photometric.to_string()))?);
+    // Native Pixel Data needs a transfer syntax that is not encapsulated
+    if ds.transfer_syntax().is_some_and(|ts| ts.encapsulated) {
+        let mut meta = out.file_meta().to_vec();
+        for elem in meta.iter_mut().filter(|e| e.attribute.keyword == "TransferSyntaxUID") {
+            elem.value = Some(DataElementValue::String(EXPLICIT_VR_LITTLE_ENDIAN.to_string()));
+        }
+        out.set_file_meta(meta);
+    }
+    out.set_pixel_data(native);
+    Some(out)
+}
@@ -0,0 +1,203 @@
+use std::io::Cursor;
+use std::path::Path;
+
+use crate::dataset::Dataset;
+use crate::modules::color::render_rgb;
+use crate::modules::lut::{render_frame, DisplayDepth, Window};
+use crate::modules::pixel::{decode_frame, PixelArray, PixelBuffer, PixelDescription};
+
+/// What gets written for each pixel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportMode {
+    /// Grayscale through the Modality, VOI and Presentation LUTs, with the
+    /// dataset's window unless one is given; color as RGB scaled to the
+    /// depth
+    Display { window: Option<Window>, depth: DisplayDepth },
+    /// Stored values, unchanged
+    Raw,
+}
+
+impl Default for ExportMode {
+    fn default() -> Self {
+        ExportMode::Display { window: None, depth: DisplayDepth::Bits8 }
+    }
+}
+
+/// One frame ready to be written: display values, or the decoded stored
+/// values in raw mode.
+pub fn export_array(ds: &Dataset, frame: usize, mode: ExportMode) -> Option<PixelArray> {
+    let (window, depth) = match mode {
+        ExportMode::Raw => return decode_frame(ds, frame),
+        ExportMode::Display { window, depth } => (window, depth),
+    };
+    let desc = PixelDescription::from_dataset(ds)?;
+    if desc.samples_per_pixel == 1 && desc.photometric_interpretation != "PALETTE COLOR" {
+        return render_frame(ds, frame, window, depth);
+    }
+    render_rgb(ds, &decode_frame(ds, frame)?, depth)
+}
+
+/// Samples of one frame of the array
+fn frame_range(array: &PixelArray, frame: usize) -> std::ops::Range<usize> {
+    frame * array.frame_len()..(frame + 1) * array.frame_len()
+}
+
+/// PNG of the array's first frame: 8 or 16-bit, gray or RGB
+pub fn encode_png(array: &PixelArray) -> Option<Vec<u8>> {
+    let color = match array.samples {
+        1 => png::ColorType::Grayscale,
+        3 => png::ColorType::Rgb,
+        n => {
+            eprintln!("PNG export needs 1 or 3 samples per pixel, got {n}");
+            return None;
+        }
+    };
+    let range = frame_range(array, 0);
+    // PNG stores 16-bit samples big endian
+    let (depth, bytes) = match &array.data {
+        PixelBuffer::U8(v) => (png::BitDepth::Eight, v[range].to_vec()),
+        PixelBuffer::U16(v) => (png::BitDepth::Sixteen, v[range].iter().flat_map(|s| s.to_be_bytes()).collect()),
+        _ => {
+            eprintln!("PNG export needs unsigned 8 or 16-bit samples");
+            return None;
+        }
+    };
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, array.columns as u32, array.rows as u32);
+    encoder.set_color(color);
+    encoder.set_depth(depth);
+    let written = encoder.write_header().and_then(|mut writer| {
+        writer.write_image_data(&bytes)?;
+        writer.finish()
+    });
+    if let Err(e) = written {
+        eprintln!("PNG encoding failed: {e}");
+        return None;
+    }
+    Some(out)
+}
+
+/// Binary PGM (gray) or PPM (RGB) of the array's first frame
+pub fn encode_pnm(array: &PixelArray) -> Option<Vec<u8>> {
+    let magic = match array.samples {
+        1 => "P5",
+        3 => "P6",
+        n => {
+            eprintln!("PGM/PPM export needs 1 or 3 samples per pixel, got {n}");
+            return None;
+        }
+    };
+    let range = frame_range(array, 0);
+    // Samples above 255 take two bytes, most significant first
+    let (max, bytes) = match &array.data {
+        PixelBuffer::U8(v) => (u8::MAX as u32, v[range].to_vec()),
+        PixelBuffer::U16(v) => (u16::MAX as u32, v[range].iter().flat_map(|s| s.to_be_bytes()).collect()),
+        _ => {
+            eprintln!("PGM/PPM export needs unsigned 8 or 16-bit samples");
+            return None;
+        }
+    };
+    let mut out = format!("{magic}\n{} {}\n{max}\n", array.columns, array.rows).into_bytes();
+    out.extend(bytes);
+    Some(out)
+}
+
+/// TIFF with one page per frame of the array. Gray frames may have any
+/// sample type; RGB frames need unsigned 8 or 16-bit samples.
+pub fn encode_tiff(array: &PixelArray) -> Option<Vec<u8>> {
+    use tiff::encoder::{colortype, TiffEncoder};
+
+    let mut out = Cursor::new(Vec::new());
+    let mut encoder = match TiffEncoder::new(&mut out) {
+        Ok(encoder) => encoder,
+        Err(e) => {
+            eprintln!("TIFF encoding failed: {e}");
+            return None;
+        }
+    };
+    let (w, h) = (array.columns as u32, array.rows as u32);
+    for frame in 0..array.frames {
+        let range = frame_range(array, frame);
+        let written = match (&array.data, array.samples) {
+            (PixelBuffer::U8(v), 1) => encoder.write_image::<colortype::Gray8>(w, h, &v[range]),
+            (PixelBuffer::I8(v), 1) => encoder.write_image::<colortype::GrayI8>(w, h, &v[range]),
+            (PixelBuffer::U16(v), 1) => encoder.write_image::<colortype::Gray16>(w, h, &v[range]),
+            (PixelBuffer::I16(v), 1) => encoder.write_image::<colortype::GrayI16>(w, h, &v[range]),
+            (PixelBuffer::U32(v), 1) => encoder.write_image::<colortype::Gray32>(w, h, &v[range]),
+            (PixelBuffer::I32(v), 1) => encoder.write_image::<colortype::GrayI32>(w, h, &v[range]),
+            (PixelBuffer::F32(v), 1) => encoder.write_image::<colortype::Gray32Float>(w, h, &v[range]),
+            (PixelBuffer::F64(v), 1) => encoder.write_image::<colortype::Gray64Float>(w, h, &v[range]),
+            (PixelBuffer::U8(v), 3) => encoder.write_image::<colortype::RGB8>(w, h, &v[range]),
+            (PixelBuffer::U16(v), 3) => encoder.write_image::<colortype::RGB16>(w, h, &v[range]),
+            _ => {
+                eprintln!("TIFF export needs gray samples, or unsigned 8 or 16-bit RGB");
+                return None;
+            }
+        };
+        if let Err(e) = written {
+            eprintln!("TIFF encoding failed: {e}");
+            return None;
+        }
+    }
+    Some(out.into_inner())
+}
+
+fn write_file(path: &Path, bytes: Vec<u8>) -> Option<()> {
+    match std::fs::write(path, bytes) {
+        Ok(()) => Some(()),
+        Err(e) => {
+            eprintln!("Failed to write {}: {e}", path.display());
+            None
+        }
+    }
+}
+
+/// Writes one frame in the format named by the file extension: .png, .pgm,
+/// .ppm, .pnm, .tif or .tiff.
+pub fn export_frame<P: AsRef<Path>>(ds: &Dataset, frame: usize, path: P, mode: ExportMode) -> Option<()> {
+    let path = path.as_ref();
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_ascii_lowercase();
+    let array = export_array(ds, frame, mode)?;
+    let bytes = match extension.as_str() {
+        "png" => encode_png(&array)?,
+        "pgm" | "ppm" | "pnm" => encode_pnm(&array)?,
+        "tif" | "tiff" => encode_tiff(&array)?,
+        _ => {
+            eprintln!("Unknown image format for {}", path.display());
+            return None;
+        }
+    };
+    write_file(path, bytes)
+}
+
+/// Writes every frame as the pages of a TIFF file.
+pub fn export_tiff<P: AsRef<Path>>(ds: &Dataset, path: P, mode: ExportMode) -> Option<()> {
+    let frames = PixelDescription::from_dataset(ds)?.number_of_frames;
+    let arrays = (0..frames).map(|f| export_array(ds, f, mode)).collect::<Option<Vec<_>>>()?;
+    let data = concat_buffers(&arrays)?;
+    let first = &arrays[0];
+    let all = PixelArray { frames, rows: first.rows, columns: first.columns, samples: first.samples, data };
+    write_file(path.as_ref(), encode_tiff(&all)?)
+}
+
+/// Joins single frame arrays of the same sample type.
+fn concat_buffers(arrays: &[PixelArray]) -> Option<PixelBuffer> {
+    let mut data = arrays.first()?.data.clone();
+    for array in &arrays[1..] {
+        match (&mut data, &array.data) {
+            (PixelBuffer::U8(a), PixelBuffer::U8(b)) => a.extend_from_slice(b),
+            (PixelBuffer::I8(a), PixelBuffer::I8(b)) => a.extend_from_slice(b),
+            (PixelBuffer::U16(a), PixelBuffer::U16(b)) => a.extend_from_slice(b),
+            (PixelBuffer::I16(a), PixelBuffer::I16(b)) => a.extend_from_slice(b),
+            (PixelBuffer::U32(a), PixelBuffer::U32(b)) => a.extend_from_slice(b),
+            (PixelBuffer::I32(a), PixelBuffer::I32(b)) => a.extend_from_slice(b),
+            (PixelBuffer::F32(a), PixelBuffer::F32(b)) => a.extend_from_slice(b),
+            (PixelBuffer::F64(a), PixelBuffer::F64(b)) => a.extend_from_slice(b),
+            _ => {
+                eprintln!("Frames have different sample types");
+                return None;
+            }
+        }
+    }
+    Some(data)
+}
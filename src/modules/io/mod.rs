@@ -1,91 +1,37 @@
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
-use crate::dataset::Dataset;
-use crate::dataelem::{attribute_by_tag, DataElement, DataElementValue, DicomVr};
-
-#[derive(Clone, Copy, Debug)]
-enum Endianness {
-    Little,
-    Big,
-}
-
-#[derive(Clone, Copy, Debug)]
-enum VrMode {
-    Explicit,
-    Implicit,
-}
-
-#[derive(Clone, Copy, Debug)]
-struct TransferSyntax {
-    endian: Endianness,
-    vr_mode: VrMode,
-}
-
-fn ts_from_uid(uid: &str) -> TransferSyntax {
-    match uid {
-        // Implicit VR Little Endian
-        "1.2.840.10008.1.2" => TransferSyntax {
-            endian: Endianness::Little,
-            vr_mode: VrMode::Implicit,
-        },
-        // Explicit VR Little Endian
-        "1.2.840.10008.1.2.1" => TransferSyntax {
-            endian: Endianness::Little,
-            vr_mode: VrMode::Explicit,
-        },
-        // Explicit VR Big Endian (rare)
-        "1.2.840.10008.1.2.2" => TransferSyntax {
-            endian: Endianness::Big,
-            vr_mode: VrMode::Explicit,
-        },
-        // Encapsulated transfer syntaxes are still Explicit Little for tags
-        _ => TransferSyntax {
-            endian: Endianness::Little,
-            vr_mode: VrMode::Explicit,
-        },
-    }
-}
+use crate::byteswap::convert_in_place;
+use crate::dataset::{Dataset, EncapsulatedPixelData};
+use crate::dataelem::{attribute_by_tag_number, is_private_tag, DataElement, DataElementValue, DicomVr, PRIVATE_ATTRIBUTE};
+use crate::uids::{
+    transfer_syntax, Endianness, TransferSyntax, VrMode, EXPLICIT_VR_LITTLE_ENDIAN, IMPLEMENTATION_CLASS_UID,
+    IMPLEMENTATION_VERSION_NAME, IMPLICIT_VR_LITTLE_ENDIAN,
+};
 
 fn read_u16(buf: &[u8], off: &mut usize, e: Endianness) -> Option<u16> {
-    if *off + 2 > buf.len() {
-        return None;
-    }
-    let v = match e {
-        Endianness::Little => u16::from_le_bytes([buf[*off], buf[*off + 1]]),
-        Endianness::Big => u16::from_be_bytes([buf[*off], buf[*off + 1]]),
-    };
+    let bytes: [u8; 2] = buf.get(*off..*off + 2)?.try_into().ok()?;
     *off += 2;
-    Some(v)
+    Some(match e {
+        Endianness::Little => u16::from_le_bytes(bytes),
+        Endianness::Big => u16::from_be_bytes(bytes),
+    })
 }
 
 fn read_u32(buf: &[u8], off: &mut usize, e: Endianness) -> Option<u32> {
-    if *off + 4 > buf.len() {
-        return None;
-    }
-    let v = match e {
-        Endianness::Little => u32::from_le_bytes([
-            buf[*off],
-            buf[*off + 1],
-            buf[*off + 2],
-            buf[*off + 3],
-        ]),
-        Endianness::Big => u32::from_be_bytes([
-            buf[*off],
-            buf[*off + 1],
-            buf[*off + 2],
-            buf[*off + 3],
-        ]),
-    };
+    let bytes: [u8; 4] = buf.get(*off..*off + 4)?.try_into().ok()?;
     *off += 4;
-    Some(v)
+    Some(match e {
+        Endianness::Little => u32::from_le_bytes(bytes),
+        Endianness::Big => u32::from_be_bytes(bytes),
+    })
 }
 
 #[derive(Debug)]
 struct ElemHeader {
     group: u16,
     element: u16,
-    _vr: Option<[u8; 2]>,
+    vr: Option<[u8; 2]>,
     len: u32,
 }
 
@@ -104,7 +50,11 @@ fn read_elem_header(
             }
             let vr = [buf[*off], buf[*off + 1]];
             *off += 2;
-            let is_long = matches!(&vr, b"OB" | b"OW" | b"OF" | b"SQ" | b"UT" | b"UN");
+            // VRs with a 2-byte reserved field and a 32-bit length (PS3.5 Table 7.1-1)
+            let is_long = matches!(
+                &vr,
+                b"OB" | b"OD" | b"OF" | b"OL" | b"OV" | b"OW" | b"SQ" | b"SV" | b"UC" | b"UN" | b"UR" | b"UT" | b"UV"
+            );
             if is_long {
                 // skip 2 reserved bytes
                 if *off + 2 > buf.len() {
@@ -115,26 +65,15 @@ fn read_elem_header(
                 Some(ElemHeader {
                     group,
                     element,
-                    _vr: Some(vr),
+                    vr: Some(vr),
                     len,
                 })
             } else {
-                if *off + 2 > buf.len() {
-                    return None;
-                }
-                let l = match endian {
-                    Endianness::Little => {
-                        u16::from_le_bytes([buf[*off], buf[*off + 1]]) as u32
-                    }
-                    Endianness::Big => {
-                        u16::from_be_bytes([buf[*off], buf[*off + 1]]) as u32
-                    }
-                };
-                *off += 2;
+                let l = read_u16(buf, off, endian)? as u32;
                 Some(ElemHeader {
                     group,
                     element,
-                    _vr: Some(vr),
+                    vr: Some(vr),
                     len: l,
                 })
             }
@@ -144,13 +83,21 @@ fn read_elem_header(
             Some(ElemHeader {
                 group,
                 element,
-                _vr: None,
+                vr: None,
                 len,
             })
         }
     }
 }
 
+/// Binary value in little endian order, swapped in bulk from the file's
+/// byte order
+fn binary_value(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> DataElementValue {
+    let mut bytes = val.to_vec();
+    convert_in_place(&mut bytes, value_width(vr.unwrap_or(DicomVr::Un)), endian, Endianness::Little);
+    DataElementValue::Data(bytes)
+}
+
 fn parse_value_by_vr(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> Option<DataElementValue> {
     match vr {
         Some(DicomVr::Ae)
@@ -181,7 +128,7 @@ fn parse_value_by_vr(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> Opt
                     Endianness::Big => u16::from_be_bytes([val[0], val[1]]),
                 }))
             } else {
-                Some(DataElementValue::Data(val.to_vec()))
+                Some(binary_value(vr, val, endian))
             }
         }
         Some(DicomVr::Ss) => {
@@ -191,7 +138,7 @@ fn parse_value_by_vr(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> Opt
                     Endianness::Big => i16::from_be_bytes([val[0], val[1]]),
                 }))
             } else {
-                Some(DataElementValue::Data(val.to_vec()))
+                Some(binary_value(vr, val, endian))
             }
         }
         Some(DicomVr::Ul) => {
@@ -201,7 +148,7 @@ fn parse_value_by_vr(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> Opt
                     Endianness::Big => u32::from_be_bytes([val[0], val[1], val[2], val[3]]),
                 }))
             } else {
-                Some(DataElementValue::Data(val.to_vec()))
+                Some(binary_value(vr, val, endian))
             }
         }
         Some(DicomVr::Sl) => {
@@ -211,7 +158,7 @@ fn parse_value_by_vr(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> Opt
                     Endianness::Big => i32::from_be_bytes([val[0], val[1], val[2], val[3]]),
                 }))
             } else {
-                Some(DataElementValue::Data(val.to_vec()))
+                Some(binary_value(vr, val, endian))
             }
         }
         Some(DicomVr::Uv) => {
@@ -225,7 +172,7 @@ fn parse_value_by_vr(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> Opt
                     ]),
                 }))
             } else {
-                Some(DataElementValue::Data(val.to_vec()))
+                Some(binary_value(vr, val, endian))
             }
         }
         Some(DicomVr::Sv) => {
@@ -239,7 +186,7 @@ fn parse_value_by_vr(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> Opt
                     ]),
                 }))
             } else {
-                Some(DataElementValue::Data(val.to_vec()))
+                Some(binary_value(vr, val, endian))
             }
         }
         Some(DicomVr::Fd) => {
@@ -253,7 +200,7 @@ fn parse_value_by_vr(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> Opt
                     ]),
                 }))
             } else {
-                Some(DataElementValue::Data(val.to_vec()))
+                Some(binary_value(vr, val, endian))
             }
         }
         Some(DicomVr::Fl) => {
@@ -263,7 +210,7 @@ fn parse_value_by_vr(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> Opt
                     Endianness::Big => f32::from_be_bytes([val[0], val[1], val[2], val[3]]),
                 }))
             } else {
-                Some(DataElementValue::Data(val.to_vec()))
+                Some(binary_value(vr, val, endian))
             }
         }
         Some(DicomVr::At) => {
@@ -278,16 +225,16 @@ fn parse_value_by_vr(vr: Option<DicomVr>, val: &[u8], endian: Endianness) -> Opt
                 };
                 Some(DataElementValue::Tag(g, e))
             } else {
-                Some(DataElementValue::Data(val.to_vec()))
+                Some(binary_value(vr, val, endian))
             }
         }
-        _ => Some(DataElementValue::Data(val.to_vec())),
+        _ => Some(binary_value(vr, val, endian)),
     }
 }
 
 // Parse File Meta (group 0002) in Explicit Little Endian starting at off.
-// Returns (transfer_syntax, new_offset)
-fn parse_file_meta(buf: &[u8], mut off: usize) -> Option<(TransferSyntax, usize, Vec<DataElement>)> {
+// Returns (transfer_syntax, new_offset), or why the group cannot be used
+fn parse_file_meta(buf: &[u8], mut off: usize) -> Result<(TransferSyntax, usize, Vec<DataElement>), String> {
     // File Meta starts immediately after "DICM"
     // It must be Explicit Little regardless of dataset TS
     let endian = Endianness::Little;
@@ -300,7 +247,7 @@ fn parse_file_meta(buf: &[u8], mut off: usize) -> Option<(TransferSyntax, usize,
     // loop until we encounter a tag with group != 0x0002.
     loop {
         let save = off;
-        let h: ElemHeader = read_elem_header(buf, &mut off, endian, vr_mode)?;
+        let h: ElemHeader = read_elem_header(buf, &mut off, endian, vr_mode).ok_or(META_TRUNCATED)?;
         if h.group != 0x0002 {
             // rewind to start of this element; it's part of the main dataset
             off = save;
@@ -308,17 +255,17 @@ fn parse_file_meta(buf: &[u8], mut off: usize) -> Option<(TransferSyntax, usize,
         }
         // eprintln!("[file_meta] header ({:04X},{:04X}) len={} off={}", h.group, h.element, h.len, off);
         if off + (h.len as usize) > buf.len() {
-            return None;
+            return Err(META_TRUNCATED.to_string());
         }
         let val = &buf[off..off + (h.len as usize)];
         // Record meta element
-        let tag_str = format!("({:04X},{:04X})", h.group, h.element);
-        if let Some(attr) = attribute_by_tag(&tag_str) {
-            let parsed = parse_value_by_vr(attr.vr, val, Endianness::Little);
-            meta_elems.push(DataElement { attribute: attr, value: parsed });
+        if let Some(attr) = attribute_by_tag_number(((h.group as u32) << 16) | h.element as u32) {
+            let vr = h.vr.and_then(|code| DicomVr::from_code(&code)).unwrap_or(DicomVr::Un);
+            let parsed = parse_value_by_vr(Some(vr), val, Endianness::Little);
+            meta_elems.push(DataElement { attribute: attr, tag: attr.tag_number(), vr, value: parsed });
             // eprintln!("[file_meta] parsed {} {}", attr.tag, attr.keyword);
         } else {
-            // eprintln!("[file_meta] unknown tag {}", tag_str);
+            // eprintln!("[file_meta] unknown tag ({:04X},{:04X})", h.group, h.element);
         }
         // Capture TransferSyntaxUID specifically
         if h.group == 0x0002 && h.element == 0x0010 {
@@ -329,14 +276,38 @@ fn parse_file_meta(buf: &[u8], mut off: usize) -> Option<(TransferSyntax, usize,
         off += h.len as usize;
     }
 
-    let ts = if ts_uid.is_empty() {
+    let ts_uid = if ts_uid.is_empty() {
         // Fallback if missing: Implicit Little
-        ts_from_uid("1.2.840.10008.1.2")
+        IMPLICIT_VR_LITTLE_ENDIAN
     } else {
-        ts_from_uid(&ts_uid)
+        ts_uid.as_str()
+    };
+    // Refuse to guess the encoding of a syntax we don't know
+    let ts = match transfer_syntax(ts_uid) {
+        Some(ts) => ts,
+        None => return Err(format!("Unknown transfer syntax {ts_uid}")),
     };
+    if ts.deflated {
+        return Err(format!("Deflated transfer syntax {} is not supported", ts.entry.name));
+    }
 
-    Some((ts, off, meta_elems))
+    Ok((ts, off, meta_elems))
+}
+
+const META_TRUNCATED: &str = "File Meta Information is truncated";
+
+/// End of the File Meta Information group, found by walking its element
+/// headers; None when the buffer ends first.
+fn file_meta_end(buf: &[u8]) -> Option<usize> {
+    let mut off = 132;
+    loop {
+        let start = off;
+        let h = read_elem_header(buf, &mut off, Endianness::Little, VrMode::Explicit)?;
+        if h.group != 0x0002 {
+            return Some(start);
+        }
+        off = off.checked_add(h.len as usize).filter(|&end| end <= buf.len())?;
+    }
 }
 
 pub fn read_dicom<P: AsRef<Path>>(path: P) -> Dataset {
@@ -347,6 +318,140 @@ pub fn read_dicom<P: AsRef<Path>>(path: P) -> Dataset {
         Err(_) => return Dataset::new(),
     };
     if file.read_to_end(&mut buffer).is_err() { return Dataset::new(); }
+    read_dicom_bytes(&buffer)
+}
+
+/// Reads the File Meta Information and the dataset up to Pixel Data,
+/// without reading the Pixel Data from disk: the file is read in growing
+/// chunks until the header is complete. Files that are not Part 10, or
+/// whose File Meta Information cannot be used, give an empty dataset, as
+/// read_dicom does; None means the file could not be read.
+pub fn read_dicom_header<P: AsRef<Path>>(path: P) -> Option<Dataset> {
+    let path = path.as_ref();
+    match read_file_header(path)? {
+        FileHeader::Part10(ds) => Some(ds),
+        FileHeader::NotDicom => Some(Dataset::new()),
+        FileHeader::BadMeta(reason) => {
+            eprintln!("{}: {reason}", path.display());
+            Some(Dataset::new())
+        }
+    }
+}
+
+/// What the start of a file says about it
+pub(crate) enum FileHeader {
+    /// Part 10 file, parsed up to its Pixel Data
+    Part10(Dataset),
+    /// No "DICM" after the preamble
+    NotDicom,
+    /// Part 10 file whose File Meta Information cannot be used, and why
+    BadMeta(String),
+}
+
+/// Reads a file in growing chunks until its header is complete, or until
+/// the preamble or a complete File Meta group shows it cannot be parsed.
+/// None means the file could not be read.
+pub(crate) fn read_file_header(path: &Path) -> Option<FileHeader> {
+    let mut file = match File::open(path) {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("Failed to open {}: {e}", path.display());
+            return None;
+        }
+    };
+    let mut buffer = Vec::new();
+    let mut chunk = 64 * 1024;
+    loop {
+        let read = match (&mut file).take(chunk).read_to_end(&mut buffer) {
+            Ok(read) => read,
+            Err(e) => {
+                eprintln!("Failed to read {}: {e}", path.display());
+                return None;
+            }
+        };
+        // Less than asked for means the whole file is in memory
+        let whole = read < chunk as usize;
+        if buffer.len() < 132 || &buffer[128..132] != b"DICM" {
+            return Some(FileHeader::NotDicom);
+        }
+        if let Err(reason) = parse_file_meta(&buffer, 132) {
+            if whole || file_meta_end(&buffer).is_some() {
+                return Some(FileHeader::BadMeta(reason));
+            }
+        } else if whole {
+            return Some(FileHeader::Part10(read_dicom_header_bytes(&buffer)));
+        } else if let Some(end) = header_length(&buffer) {
+            return Some(FileHeader::Part10(read_dicom_bytes(&buffer[..end])));
+        }
+        chunk *= 2;
+    }
+}
+
+/// Parses the part of a Part 10 file before Pixel Data.
+fn read_dicom_header_bytes(buffer: &[u8]) -> Dataset {
+    read_dicom_bytes(&buffer[..header_length(buffer).unwrap_or(buffer.len())])
+}
+
+/// Length of a Part 10 file up to its top level Pixel Data, found by walking
+/// element headers and skipping values. None when the buffer ends first,
+/// or the file is not one the walk can follow.
+fn header_length(buffer: &[u8]) -> Option<usize> {
+    if buffer.len() < 132 || &buffer[128..132] != b"DICM" {
+        return None;
+    }
+    let (ts, mut off, _) = parse_file_meta(buffer, 132).ok()?;
+    skip_elements(buffer, &mut off, ts.endian, ts.vr_mode, true)?;
+    Some(off)
+}
+
+/// Skips elements up to the delimiter ending an item of undefined length,
+/// or, at the top level, up to Pixel Data, leaving `off` on its tag.
+fn skip_elements(buffer: &[u8], off: &mut usize, endian: Endianness, vr_mode: VrMode, top_level: bool) -> Option<()> {
+    loop {
+        let start = *off;
+        let group = read_u16(buffer, off, endian)?;
+        let element = read_u16(buffer, off, endian)?;
+        let tag = ((group as u32) << 16) | element as u32;
+        if tag == ITEM_DELIMITATION || tag == SEQUENCE_DELIMITATION {
+            read_u32(buffer, off, endian)?;
+            return (!top_level).then_some(());
+        }
+        if top_level && matches!(tag, 0x7FE0_0008 | 0x7FE0_0009 | 0x7FE0_0010) {
+            *off = start;
+            return Some(());
+        }
+        *off = start;
+        let hdr = read_elem_header(buffer, off, endian, vr_mode)?;
+        if hdr.len == UNDEFINED_LENGTH {
+            // UN of undefined length holds Implicit VR items (PS3.5 6.2.2)
+            let item_vr_mode = if hdr.vr == Some(*b"UN") { VrMode::Implicit } else { vr_mode };
+            skip_items(buffer, off, endian, item_vr_mode)?;
+        } else {
+            *off = off.checked_add(hdr.len as usize).filter(|&end| end <= buffer.len())?;
+        }
+    }
+}
+
+/// Skips the items of a sequence of undefined length and its delimiter.
+fn skip_items(buffer: &[u8], off: &mut usize, endian: Endianness, vr_mode: VrMode) -> Option<()> {
+    loop {
+        let group = read_u16(buffer, off, endian)?;
+        let element = read_u16(buffer, off, endian)?;
+        let len = read_u32(buffer, off, endian)?;
+        match ((group as u32) << 16) | element as u32 {
+            SEQUENCE_DELIMITATION => return Some(()),
+            ITEM if len == UNDEFINED_LENGTH => skip_elements(buffer, off, endian, vr_mode, false)?,
+            ITEM => *off = off.checked_add(len as usize).filter(|&end| end <= buffer.len())?,
+            _ => return None,
+        }
+    }
+}
+
+/// Parses a Part 10 file already held in memory. Binary values kept as
+/// bytes (OW, OL, OF, OD, multi-valued numbers and native Pixel Data) are
+/// swapped in bulk to little endian as they are read, so datasets hold the
+/// same bytes whatever the file's byte order.
+pub fn read_dicom_bytes(buffer: &[u8]) -> Dataset {
     if buffer.len() < 132 { return Dataset::new(); }
 
     // Check Part 10 preamble
@@ -358,55 +463,542 @@ pub fn read_dicom<P: AsRef<Path>>(path: P) -> Dataset {
     }
 
     // Parse File Meta (Explicit Little)
-    let (ts, mut off, file_meta) = match parse_file_meta(&buffer, 132) {
-        Some(v) => v,
-        None => return Dataset::new(),
+    let (ts, mut off, file_meta) = match parse_file_meta(buffer, 132) {
+        Ok(v) => v,
+        Err(reason) => {
+            eprintln!("{reason}");
+            return Dataset::new();
+        }
     };
 
     // Iterate dataset
     let mut ds = Dataset::new();
     ds.set_file_meta(file_meta);
+    let mut ctx = VrContext::default();
+    parse_elements(buffer, &mut off, buffer.len(), ts.endian, ts.vr_mode, &mut ctx, &mut ds);
+    ds
+}
+
+const ITEM: u32 = 0xFFFE_E000;
+const ITEM_DELIMITATION: u32 = 0xFFFE_E00D;
+const SEQUENCE_DELIMITATION: u32 = 0xFFFE_E0DD;
+const UNDEFINED_LENGTH: u32 = 0xFFFF_FFFF;
+
+/// Values seen so far that decide the ambiguous dictionary VRs. Items start
+/// from a copy of their parent's context.
+#[derive(Clone, Copy, Debug, Default)]
+struct VrContext {
+    pixel_representation: Option<u16>,
+    bits_allocated: Option<u16>,
+    /// Number of entries from the LUT Descriptor (0028,3002) of this item
+    lut_entries: Option<u16>,
+}
+
+impl VrContext {
+    fn record(&mut self, tag: u32, val: &[u8], endian: Endianness) {
+        let first = || {
+            let mut off = 0;
+            read_u16(val, &mut off, endian)
+        };
+        match tag {
+            0x0028_0103 => self.pixel_representation = first(),
+            0x0028_0100 => self.bits_allocated = first(),
+            0x0028_3002 => self.lut_entries = first(),
+            _ => {}
+        }
+    }
+
+    /// Resolves "US or SS", "OB or OW" and "US or OW" as PS3.5 Section 6.2.2
+    /// describes for Implicit VR: signedness follows Pixel Representation,
+    /// OB/OW follows Bits Allocated, and LUT Data is US only when the LUT
+    /// Descriptor announces a single entry.
+    fn resolve(&self, vr: DicomVr) -> DicomVr {
+        match vr {
+            DicomVr::UsOrSs => {
+                if self.pixel_representation == Some(1) { DicomVr::Ss } else { DicomVr::Us }
+            }
+            DicomVr::ObOrOw => match self.bits_allocated {
+                Some(bits) if bits <= 8 => DicomVr::Ob,
+                _ => DicomVr::Ow,
+            },
+            DicomVr::UsOrOw => {
+                if self.lut_entries == Some(1) { DicomVr::Us } else { DicomVr::Ow }
+            }
+            DicomVr::UsOrSsOrOw => DicomVr::Ow,
+            other => other,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ParseEnd {
+    /// Reached the end of the byte range
+    End,
+    /// Hit an item or sequence delimitation item
+    Delimiter,
+    /// Malformed or unsupported data; nothing after it can be trusted
+    Stop,
+}
+
+fn parse_elements(
+    buffer: &[u8],
+    off: &mut usize,
+    end: usize,
+    endian: Endianness,
+    vr_mode: VrMode,
+    ctx: &mut VrContext,
+    ds: &mut Dataset,
+) -> ParseEnd {
     loop {
-        if off + 8 > buffer.len() {
-            break;
+        if *off + 8 > end {
+            return ParseEnd::End;
         }
-        let hdr = match read_elem_header(&buffer, &mut off, ts.endian, ts.vr_mode) {
+        let hdr = match read_elem_header(buffer, off, endian, vr_mode) {
             Some(h) => h,
-            None => break,
+            None => return ParseEnd::Stop,
+        };
+        let tag = ((hdr.group as u32) << 16) | hdr.element as u32;
+        if tag == ITEM_DELIMITATION || tag == SEQUENCE_DELIMITATION {
+            return ParseEnd::Delimiter;
+        }
+        let attr = attribute_by_tag_number(tag).or_else(|| is_private_tag(tag).then_some(&PRIVATE_ATTRIBUTE));
+        let vr = match hdr.vr {
+            Some(code) => DicomVr::from_code(&code).unwrap_or(DicomVr::Un),
+            // Private Creator elements (gggg,0010-00FF) are always LO
+            None if is_private_tag(tag) && (0x0010..=0x00FF).contains(&(tag & 0xFFFF)) => DicomVr::Lo,
+            None => attr.and_then(|a| a.vr).map(|vr| ctx.resolve(vr)).unwrap_or(DicomVr::Un),
         };
 
-        // Undefined length: 0xFFFFFFFF. Usually for SQ/OB/OW.
-        if hdr.len == 0xFFFF_FFFF {
-            eprintln!(
-                "Encountered undefined length at ({:04X},{:04X}); stop for now",
-                hdr.group, hdr.element
-            );
-            break;
+        // Only sequences and encapsulated Pixel Data have undefined length;
+        // UN of undefined length is a sequence in Implicit VR (PS3.5 6.2.2)
+        let undefined = hdr.len == UNDEFINED_LENGTH;
+        if vr == DicomVr::Sq || (undefined && tag != 0x7FE0_0010) {
+            let item_vr_mode = if vr == DicomVr::Un { VrMode::Implicit } else { vr_mode };
+            let items = match parse_sequence(buffer, off, end, hdr.len, endian, item_vr_mode, ctx) {
+                Some(items) => items,
+                None => {
+                    eprintln!("Malformed sequence at ({:04X},{:04X})", hdr.group, hdr.element);
+                    return ParseEnd::Stop;
+                }
+            };
+            if let Some(attr) = attr {
+                ds.push(DataElement { attribute: attr, tag, vr: DicomVr::Sq, value: Some(DataElementValue::Sequence(items)) });
+            }
+            continue;
+        }
+        if undefined {
+            // Encapsulated Pixel Data
+            let Some(encapsulated) = parse_fragments(buffer, off, end, endian) else {
+                eprintln!("Malformed encapsulated Pixel Data");
+                return ParseEnd::Stop;
+            };
+            ds.set_encapsulated_pixel_data(encapsulated);
+            if let Some(attr) = attr {
+                ds.push(DataElement { attribute: attr, tag, vr, value: None });
+            }
+            continue;
         }
 
         // Bounds check value
-        if off + (hdr.len as usize) > buffer.len() {
+        if *off + (hdr.len as usize) > end {
             eprintln!("Truncated value at ({:04X},{:04X})", hdr.group, hdr.element);
-            return ds;
+            return ParseEnd::Stop;
         }
-        let val = &buffer[off..off + (hdr.len as usize)];
-        off += hdr.len as usize;
+        let val = &buffer[*off..*off + (hdr.len as usize)];
+        *off += hdr.len as usize;
+        ctx.record(tag, val, endian);
 
         // Build dataset entries
-        let tag_str = format!("({:04X},{:04X})", hdr.group, hdr.element);
-        if hdr.group == 0x7FE0 && hdr.element == 0x0010 {
-            // Pixel Data: keep attribute entry without duplicating bytes
-            ds.set_pixel_data(val.to_vec());
-            if let Some(attr) = attribute_by_tag(&tag_str) {
-                ds.push(DataElement { attribute: attr, value: None });
+        if matches!(tag, 0x7FE0_0008 | 0x7FE0_0009 | 0x7FE0_0010) {
+            // Pixel Data, Float Pixel Data or Double Float Pixel Data: keep
+            // the attribute entry without duplicating bytes. Samples are
+            // swapped by sample above BitsAllocated 8, and by 16-bit word
+            // for 8 and 1-bit samples kept as OW.
+            let mut data = val.to_vec();
+            let width = match ctx.bits_allocated {
+                Some(bits) if bits > 8 => (bits as usize).div_ceil(8),
+                _ => value_width(vr),
+            };
+            convert_in_place(&mut data, width, endian, Endianness::Little);
+            ds.set_pixel_data(data);
+            if let Some(attr) = attr {
+                ds.push(DataElement { attribute: attr, tag, vr, value: None });
             }
             continue;
         }
-        if let Some(attr) = attribute_by_tag(&tag_str) {
-            let parsed_value = parse_value_by_vr(attr.vr, val, ts.endian);
-            ds.push(DataElement { attribute: attr, value: parsed_value });
+        if let Some(attr) = attr {
+            let parsed_value = parse_value_by_vr(Some(vr), val, endian);
+            ds.push(DataElement { attribute: attr, tag, vr, value: parsed_value });
         }
     }
+}
 
-    ds
+/// Reads the items of encapsulated Pixel Data up to the sequence delimiter:
+/// the Basic Offset Table, then one item per fragment.
+fn parse_fragments(buffer: &[u8], off: &mut usize, end: usize, endian: Endianness) -> Option<EncapsulatedPixelData> {
+    let mut items: Vec<&[u8]> = Vec::new();
+    loop {
+        if *off + 8 > end {
+            return None;
+        }
+        let group = read_u16(buffer, off, endian)?;
+        let element = read_u16(buffer, off, endian)?;
+        let len = read_u32(buffer, off, endian)?;
+        let tag = ((group as u32) << 16) | element as u32;
+        if tag == SEQUENCE_DELIMITATION {
+            break;
+        }
+        if tag != ITEM || len == UNDEFINED_LENGTH || *off + len as usize > end {
+            return None;
+        }
+        items.push(&buffer[*off..*off + len as usize]);
+        *off += len as usize;
+    }
+    let (offset_table, fragments) = items.split_first()?;
+    let offset_table = offset_table
+        .chunks_exact(4)
+        .map(|c| {
+            let mut o = 0;
+            read_u32(c, &mut o, endian).unwrap_or(0)
+        })
+        .collect();
+    Some(EncapsulatedPixelData {
+        offset_table,
+        fragments: fragments.iter().map(|f| f.to_vec()).collect(),
+    })
+}
+
+/// Parses the items of a sequence of defined or undefined length.
+fn parse_sequence(
+    buffer: &[u8],
+    off: &mut usize,
+    end: usize,
+    len: u32,
+    endian: Endianness,
+    vr_mode: VrMode,
+    parent: &VrContext,
+) -> Option<Vec<Dataset>> {
+    let seq_end = if len == UNDEFINED_LENGTH {
+        end
+    } else {
+        let seq_end = *off + len as usize;
+        if seq_end > end {
+            return None;
+        }
+        seq_end
+    };
+    let mut items = Vec::new();
+    while *off + 8 <= seq_end {
+        // Item headers are tag + 32-bit length in every transfer syntax
+        let group = read_u16(buffer, off, endian)?;
+        let element = read_u16(buffer, off, endian)?;
+        let item_len = read_u32(buffer, off, endian)?;
+        let tag = ((group as u32) << 16) | element as u32;
+        if tag == SEQUENCE_DELIMITATION {
+            return Some(items);
+        }
+        if tag != ITEM {
+            return None;
+        }
+        let mut item = Dataset::new();
+        let mut ctx = *parent;
+        let outcome = if item_len == UNDEFINED_LENGTH {
+            match parse_elements(buffer, off, seq_end, endian, vr_mode, &mut ctx, &mut item) {
+                ParseEnd::Delimiter => ParseEnd::End,
+                _ => ParseEnd::Stop,
+            }
+        } else {
+            let item_end = *off + item_len as usize;
+            if item_end > seq_end {
+                return None;
+            }
+            let outcome = parse_elements(buffer, off, item_end, endian, vr_mode, &mut ctx, &mut item);
+            *off = item_end;
+            outcome
+        };
+        if outcome != ParseEnd::End {
+            return None;
+        }
+        items.push(item);
+    }
+    if len == UNDEFINED_LENGTH {
+        // Ran out of data before the sequence delimiter
+        return None;
+    }
+    *off = seq_end;
+    Some(items)
+}
+
+fn write_u16(out: &mut Vec<u8>, v: u16, e: Endianness) {
+    match e {
+        Endianness::Little => out.extend_from_slice(&v.to_le_bytes()),
+        Endianness::Big => out.extend_from_slice(&v.to_be_bytes()),
+    }
+}
+
+fn write_u32(out: &mut Vec<u8>, v: u32, e: Endianness) {
+    match e {
+        Endianness::Little => out.extend_from_slice(&v.to_le_bytes()),
+        Endianness::Big => out.extend_from_slice(&v.to_be_bytes()),
+    }
+}
+
+fn write_u64(out: &mut Vec<u8>, v: u64, e: Endianness) {
+    match e {
+        Endianness::Little => out.extend_from_slice(&v.to_le_bytes()),
+        Endianness::Big => out.extend_from_slice(&v.to_be_bytes()),
+    }
+}
+
+/// Explicit VR code of an element; ambiguous dictionary VRs that were never
+/// settled are written as their word-sized choice
+fn vr_code(vr: DicomVr) -> &'static str {
+    match vr {
+        DicomVr::UsOrSs => "US",
+        DicomVr::ObOrOw | DicomVr::UsOrOw | DicomVr::UsOrSsOrOw => "OW",
+        other => other.as_str(),
+    }
+}
+
+fn write_elem_header(out: &mut Vec<u8>, tag: u32, vr: DicomVr, len: u32, endian: Endianness, vr_mode: VrMode) -> Option<()> {
+    write_u16(out, (tag >> 16) as u16, endian);
+    write_u16(out, tag as u16, endian);
+    match vr_mode {
+        VrMode::Explicit => {
+            let code = vr_code(vr);
+            out.extend_from_slice(code.as_bytes());
+            if matches!(code, "OB" | "OD" | "OF" | "OL" | "OV" | "OW" | "SQ" | "SV" | "UC" | "UN" | "UR" | "UT" | "UV") {
+                out.extend_from_slice(&[0, 0]);
+                write_u32(out, len, endian);
+            } else if len > u16::MAX as u32 {
+                eprintln!("Value of ({:04X},{:04X}) is too long for VR {code}", tag >> 16, tag & 0xFFFF);
+                return None;
+            } else {
+                write_u16(out, len as u16, endian);
+            }
+        }
+        VrMode::Implicit => write_u32(out, len, endian),
+    }
+    Some(())
+}
+
+/// Bytes per value of the binary VRs whose values have a byte order; 1 for
+/// the rest
+fn value_width(vr: DicomVr) -> usize {
+    match vr {
+        DicomVr::At | DicomVr::Ss | DicomVr::Us | DicomVr::Ow => 2,
+        DicomVr::UsOrSs | DicomVr::ObOrOw | DicomVr::UsOrOw | DicomVr::UsOrSsOrOw => 2,
+        DicomVr::Fl | DicomVr::Of | DicomVr::Ol | DicomVr::Sl | DicomVr::Ul => 4,
+        DicomVr::Fd | DicomVr::Od | DicomVr::Ov | DicomVr::Sv | DicomVr::Uv => 8,
+        _ => 1,
+    }
+}
+
+/// Value bytes padded to even length: UIDs with NUL, other strings with
+/// spaces and binary values with zero. Binary values are held in little
+/// endian and swapped when writing big endian.
+fn encode_value(vr: DicomVr, value: &DataElementValue, endian: Endianness) -> Vec<u8> {
+    let mut out = Vec::new();
+    match value {
+        DataElementValue::String(s) => {
+            out.extend_from_slice(s.as_bytes());
+            if out.len() % 2 == 1 {
+                out.push(if vr == DicomVr::Ui { 0 } else { b' ' });
+            }
+        }
+        DataElementValue::Data(bytes) => {
+            out.extend_from_slice(bytes);
+            convert_in_place(&mut out, value_width(vr), Endianness::Little, endian);
+            if out.len() % 2 == 1 {
+                out.push(0);
+            }
+        }
+        DataElementValue::Int16(v) => write_u16(&mut out, *v as u16, endian),
+        DataElementValue::UInt16(v) => write_u16(&mut out, *v, endian),
+        DataElementValue::Int32(v) => write_u32(&mut out, *v as u32, endian),
+        DataElementValue::UInt32(v) => write_u32(&mut out, *v, endian),
+        DataElementValue::Float(v) => write_u32(&mut out, v.to_bits(), endian),
+        DataElementValue::Int64(v) => write_u64(&mut out, *v as u64, endian),
+        DataElementValue::UInt64(v) => write_u64(&mut out, *v, endian),
+        DataElementValue::Double(v) => write_u64(&mut out, v.to_bits(), endian),
+        DataElementValue::Tag(g, e) => {
+            write_u16(&mut out, *g, endian);
+            write_u16(&mut out, *e, endian);
+        }
+        // Sequences are written item by item by write_elements
+        DataElementValue::Sequence(_) => {}
+    }
+    out
+}
+
+/// Writes the elements of a dataset or item in ascending tag order. Pixel
+/// Data comes from the dataset's native or encapsulated pixel data, with a
+/// (7FE0,0010) element added when the dataset has pixel data but no
+/// element for it.
+fn write_elements(ds: &Dataset, out: &mut Vec<u8>, endian: Endianness, vr_mode: VrMode) -> Option<()> {
+    let mut elements: Vec<DataElement> = ds.elements().iter().filter(|e| e.tag & 0xFFFF != 0).cloned().collect();
+    let has_pixel_data = ds.pixel_data().is_some() || ds.encapsulated_pixel_data().is_some();
+    if has_pixel_data && !elements.iter().any(|e| matches!(e.tag, 0x7FE0_0008 | 0x7FE0_0009 | 0x7FE0_0010)) {
+        let wide = ds.encapsulated_pixel_data().is_none() && ds.get_int("BitsAllocated").is_some_and(|bits| bits > 8);
+        let attribute = attribute_by_tag_number(0x7FE0_0010)?;
+        let vr = if wide { DicomVr::Ow } else { DicomVr::Ob };
+        elements.push(DataElement { attribute, tag: 0x7FE0_0010, vr, value: None });
+    }
+    elements.sort_by_key(|e| e.tag);
+
+    for elem in &elements {
+        if matches!(elem.tag, 0x7FE0_0008 | 0x7FE0_0009 | 0x7FE0_0010) && elem.value.is_none() {
+            write_pixel_data(ds, elem, out, endian, vr_mode)?;
+            continue;
+        }
+        match &elem.value {
+            Some(DataElementValue::Sequence(items)) => {
+                let mut body = Vec::new();
+                for item in items {
+                    let mut content = Vec::new();
+                    write_elements(item, &mut content, endian, vr_mode)?;
+                    write_u16(&mut body, (ITEM >> 16) as u16, endian);
+                    write_u16(&mut body, ITEM as u16, endian);
+                    write_u32(&mut body, content.len() as u32, endian);
+                    body.extend(content);
+                }
+                write_elem_header(out, elem.tag, DicomVr::Sq, body.len() as u32, endian, vr_mode)?;
+                out.extend(body);
+            }
+            Some(value) => {
+                let bytes = encode_value(elem.vr, value, endian);
+                write_elem_header(out, elem.tag, elem.vr, bytes.len() as u32, endian, vr_mode)?;
+                out.extend(bytes);
+            }
+            None => write_elem_header(out, elem.tag, elem.vr, 0, endian, vr_mode)?,
+        }
+    }
+    Some(())
+}
+
+/// Native Pixel Data as one value, or encapsulated Pixel Data as the Basic
+/// Offset Table and fragment items up to a sequence delimiter. Native
+/// samples are held in little endian and swapped when writing big endian:
+/// by sample above BitsAllocated 8, by word for smaller samples in OW.
+fn write_pixel_data(ds: &Dataset, elem: &DataElement, out: &mut Vec<u8>, endian: Endianness, vr_mode: VrMode) -> Option<()> {
+    if let Some(encapsulated) = ds.encapsulated_pixel_data() {
+        write_elem_header(out, elem.tag, DicomVr::Ob, UNDEFINED_LENGTH, endian, vr_mode)?;
+        let mut offset_table = Vec::with_capacity(encapsulated.offset_table.len() * 4);
+        for &offset in &encapsulated.offset_table {
+            write_u32(&mut offset_table, offset, endian);
+        }
+        for item in std::iter::once(&offset_table).chain(&encapsulated.fragments) {
+            let len = item.len().next_multiple_of(2);
+            write_u16(out, (ITEM >> 16) as u16, endian);
+            write_u16(out, ITEM as u16, endian);
+            write_u32(out, len as u32, endian);
+            out.extend_from_slice(item);
+            out.resize(out.len() + len - item.len(), 0);
+        }
+        write_u16(out, (SEQUENCE_DELIMITATION >> 16) as u16, endian);
+        write_u16(out, SEQUENCE_DELIMITATION as u16, endian);
+        write_u32(out, 0, endian);
+        return Some(());
+    }
+    let data = ds.pixel_data().unwrap_or(&[]);
+    let len = data.len().next_multiple_of(2);
+    write_elem_header(out, elem.tag, elem.vr, len as u32, endian, vr_mode)?;
+    let start = out.len();
+    out.extend_from_slice(data);
+    let bits = ds.get_int("BitsAllocated").unwrap_or(8) as usize;
+    let width = if bits > 8 { bits.div_ceil(8) } else { value_width(elem.vr) };
+    convert_in_place(&mut out[start..], width, Endianness::Little, endian);
+    out.resize(out.len() + len - data.len(), 0);
+    Some(())
+}
+
+/// File Meta Information for the dataset: the elements it already has, with
+/// the SOP Class and Instance taken from the dataset, the transfer syntax
+/// it is written in and this library named as the implementation.
+fn file_meta_for(ds: &Dataset, ts_uid: &str) -> Option<Vec<DataElement>> {
+    let mut meta: Vec<DataElement> = ds.file_meta().iter().filter(|e| e.tag != 0x0002_0000).cloned().collect();
+    let mut set = |keyword: &str, value: DataElementValue| -> Option<()> {
+        let elem = DataElement::new(keyword, value)?;
+        match meta.iter_mut().find(|e| e.tag == elem.tag) {
+            Some(existing) => *existing = elem,
+            None => meta.push(elem),
+        }
+        Some(())
+    };
+    set("FileMetaInformationVersion", DataElementValue::Data(vec![0, 1]))?;
+    for (from, to) in [("SOPClassUID", "MediaStorageSOPClassUID"), ("SOPInstanceUID", "MediaStorageSOPInstanceUID")] {
+        if let Some(uid) = ds.elements().iter().find(|e| e.attribute.keyword == from).and_then(|e| e.value.clone()) {
+            set(to, uid)?;
+        }
+    }
+    set("TransferSyntaxUID", DataElementValue::String(ts_uid.to_string()))?;
+    set("ImplementationClassUID", DataElementValue::String(IMPLEMENTATION_CLASS_UID.to_string()))?;
+    set("ImplementationVersionName", DataElementValue::String(IMPLEMENTATION_VERSION_NAME.to_string()))?;
+    meta.sort_by_key(|e| e.tag);
+    Some(meta)
+}
+
+/// Encodes the dataset as a Part 10 file in its own transfer syntax, or
+/// Explicit VR Little Endian when it has none, so a dataset read from a
+/// file is written back in that file's syntax.
+pub fn write_dicom_bytes(ds: &Dataset) -> Option<Vec<u8>> {
+    let ts_uid = ds.transfer_syntax().map_or(EXPLICIT_VR_LITTLE_ENDIAN, |ts| ts.entry.uid);
+    write_dicom_bytes_as(ds, ts_uid)
+}
+
+/// Encodes the dataset as a Part 10 file in the given transfer syntax. A
+/// native dataset can be written in any native syntax, e.g. a big endian
+/// file as Explicit VR Little Endian: binary values and Pixel Data are held
+/// in little endian and swapped in bulk when writing big endian.
+/// Encapsulated Pixel Data stays in the syntax it was compressed with.
+pub fn write_dicom_bytes_as(ds: &Dataset, ts_uid: &str) -> Option<Vec<u8>> {
+    let Some(ts) = transfer_syntax(ts_uid) else {
+        eprintln!("Unknown transfer syntax {ts_uid}");
+        return None;
+    };
+    if ts.deflated {
+        eprintln!("Writing deflated transfer syntax {} is not supported", ts.entry.name);
+        return None;
+    }
+    if ts.encapsulated && ds.encapsulated_pixel_data().is_none() && ds.pixel_data().is_some() {
+        eprintln!("Transfer syntax {} needs encapsulated Pixel Data", ts.entry.name);
+        return None;
+    }
+    if !ts.encapsulated && ds.encapsulated_pixel_data().is_some() {
+        eprintln!("Transfer syntax {} cannot carry encapsulated Pixel Data", ts.entry.name);
+        return None;
+    }
+    if ds.encapsulated_pixel_data().is_some() && ds.transfer_syntax().is_none_or(|own| own.entry.uid != ts_uid) {
+        eprintln!("Encapsulated Pixel Data cannot be written as {}", ts.entry.name);
+        return None;
+    }
+
+    // Group length first, then the rest of group 0002 in Explicit VR Little
+    // Endian whatever the transfer syntax
+    let mut meta = Dataset::new();
+    for elem in file_meta_for(ds, ts_uid)? {
+        meta.push(elem);
+    }
+    let mut meta_bytes = Vec::new();
+    write_elements(&meta, &mut meta_bytes, Endianness::Little, VrMode::Explicit)?;
+    let mut out = vec![0u8; 128];
+    out.extend_from_slice(b"DICM");
+    write_elem_header(&mut out, 0x0002_0000, DicomVr::Ul, 4, Endianness::Little, VrMode::Explicit)?;
+    write_u32(&mut out, meta_bytes.len() as u32, Endianness::Little);
+    out.extend(meta_bytes);
+
+    write_elements(ds, &mut out, ts.endian, ts.vr_mode)?;
+    Some(out)
+}
+
+/// Writes the dataset as a Part 10 file; see write_dicom_bytes.
+pub fn write_dicom<P: AsRef<Path>>(ds: &Dataset, path: P) -> Option<()> {
+    let bytes = write_dicom_bytes(ds)?;
+    match std::fs::write(path.as_ref(), bytes) {
+        Ok(()) => Some(()),
+        Err(e) => {
+            eprintln!("Failed to write {}: {e}", path.as_ref().display());
+            None
+        }
+    }
 }
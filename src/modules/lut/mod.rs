@@ -0,0 +1,397 @@
+use crate::codes::{code_sequence, Code};
+use crate::dataelem::DataElementValue;
+use crate::dataset::Dataset;
+use crate::modules::pixel::{decode_frame, PixelArray, PixelBuffer};
+
+/// Lookup table given by a LUT Descriptor and LUT Data (PS3.3 Section
+/// C.11.1.1.1): values below the first mapped value use the first entry,
+/// values past the end use the last.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lut {
+    pub first_mapped: i32,
+    /// Bits per entry, from the third value of the descriptor
+    pub bits: u16,
+    pub data: Vec<u16>,
+}
+
+impl Lut {
+    /// Reads a LUT from a Modality LUT or VOI LUT Sequence item. The first
+    /// mapped value is signed when the pixel data is.
+    pub fn from_item(item: &Dataset, signed: bool) -> Option<Self> {
+        let descriptor = item.get("LUTDescriptor")?.value.as_ref()?.to_u16s();
+        let data = item.get("LUTData")?.value.as_ref()?;
+        Self::from_descriptor(&descriptor, data, signed)
+    }
+
+    pub fn from_descriptor(descriptor: &[u16], data: &DataElementValue, signed: bool) -> Option<Self> {
+        if descriptor.len() != 3 {
+            eprintln!("LUT Descriptor has {} values, expected 3", descriptor.len());
+            return None;
+        }
+        // An entry count of 0 means 65536
+        let entries = if descriptor[0] == 0 { 65536 } else { descriptor[0] as usize };
+        let first_mapped = if signed { descriptor[1] as i16 as i32 } else { descriptor[1] as i32 };
+        let bits = descriptor[2];
+        let data = match data {
+            // Some writers pack 8-bit entries one per byte
+            DataElementValue::Data(bytes) if bits <= 8 && bytes.len() == entries => {
+                bytes.iter().map(|&b| b as u16).collect()
+            }
+            other => other.to_u16s(),
+        };
+        if data.len() < entries {
+            eprintln!("LUT Data has {} entries, the descriptor announces {entries}", data.len());
+            return None;
+        }
+        Some(Self { first_mapped, bits, data: data[..entries].to_vec() })
+    }
+
+    pub fn lookup(&self, value: f64) -> u16 {
+        let index = (value.floor() as i64 - self.first_mapped as i64).clamp(0, self.data.len() as i64 - 1);
+        self.data[index as usize]
+    }
+
+    /// Largest value an entry can hold
+    pub fn max_output(&self) -> f64 {
+        ((1u32 << self.bits.min(16)) - 1) as f64
+    }
+}
+
+/// Modality LUT stage: stored values to modality units such as HU
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModalityTransform {
+    Rescale { slope: f64, intercept: f64 },
+    Lut(Lut),
+}
+
+impl ModalityTransform {
+    pub fn apply(&self, value: f64) -> f64 {
+        match self {
+            ModalityTransform::Rescale { slope, intercept } => value * slope + intercept,
+            ModalityTransform::Lut(lut) => lut.lookup(value) as f64,
+        }
+    }
+}
+
+/// VOI LUT Function (0028,1056)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiFunction {
+    Linear,
+    LinearExact,
+    Sigmoid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Window {
+    pub center: f64,
+    pub width: f64,
+    pub function: VoiFunction,
+}
+
+impl Window {
+    pub fn new(center: f64, width: f64) -> Self {
+        Self { center, width, function: VoiFunction::Linear }
+    }
+
+    /// Maps a modality value into 0.0..=1.0 with the formulas of PS3.3
+    /// Section C.11.2.1.2.
+    pub fn apply(&self, x: f64) -> f64 {
+        let (c, w) = (self.center, self.width);
+        match self.function {
+            VoiFunction::Linear => {
+                let w = w.max(1.0);
+                if x <= c - 0.5 - (w - 1.0) / 2.0 {
+                    0.0
+                } else if x > c - 0.5 + (w - 1.0) / 2.0 {
+                    1.0
+                } else {
+                    (x - (c - 0.5)) / (w - 1.0) + 0.5
+                }
+            }
+            VoiFunction::LinearExact => {
+                let w = w.max(f64::MIN_POSITIVE);
+                if x <= c - w / 2.0 {
+                    0.0
+                } else if x > c + w / 2.0 {
+                    1.0
+                } else {
+                    (x - c) / w + 0.5
+                }
+            }
+            VoiFunction::Sigmoid => 1.0 / (1.0 + (-4.0 * (x - c) / w.max(f64::MIN_POSITIVE)).exp()),
+        }
+    }
+}
+
+/// VOI LUT stage: modality values to display intensities
+#[derive(Debug, Clone, PartialEq)]
+pub enum VoiTransform {
+    Window(Window),
+    Lut(Lut),
+    /// Neither a window nor a VOI LUT; the frame's own range is stretched
+    /// to the output
+    Auto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayDepth {
+    Bits8,
+    Bits16,
+}
+
+/// Grayscale Standard Display pipeline for a dataset: Modality LUT, VOI LUT
+/// and Presentation LUT (PS3.4 Section N.2).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrayscalePipeline {
+    pub modality: ModalityTransform,
+    pub voi: VoiTransform,
+    /// Lowest input should be brightest: Presentation LUT Shape INVERSE, or
+    /// MONOCHROME1 without a Presentation LUT Shape
+    pub invert: bool,
+}
+
+impl GrayscalePipeline {
+    /// Builds the pipeline from the dataset, using the first window or VOI
+    /// LUT when several are given.
+    pub fn from_dataset(ds: &Dataset) -> Self {
+        let signed = ds.get_int("PixelRepresentation") == Some(1);
+        let first_item = |keyword: &str| match ds.get(keyword).and_then(|e| e.value.as_ref()) {
+            Some(DataElementValue::Sequence(items)) => items.first(),
+            _ => None,
+        };
+
+        let modality = match first_item("ModalityLUTSequence").and_then(|item| Lut::from_item(item, signed)) {
+            Some(lut) => ModalityTransform::Lut(lut),
+            None => ModalityTransform::Rescale {
+                slope: ds.get_float("RescaleSlope").unwrap_or(1.0),
+                intercept: ds.get_float("RescaleIntercept").unwrap_or(0.0),
+            },
+        };
+
+        let centers = ds.get("WindowCenter").and_then(|e| e.value.as_ref()).map(|v| v.to_floats());
+        let widths = ds.get("WindowWidth").and_then(|e| e.value.as_ref()).map(|v| v.to_floats());
+        let function = match ds.get_str("VOILUTFunction") {
+            Some("LINEAR_EXACT") => VoiFunction::LinearExact,
+            Some("SIGMOID") => VoiFunction::Sigmoid,
+            _ => VoiFunction::Linear,
+        };
+        let window = match (centers, widths) {
+            (Some(c), Some(w)) if !c.is_empty() && !w.is_empty() => Some(Window { center: c[0], width: w[0], function }),
+            _ => None,
+        };
+        // The VOI LUT input is the Modality LUT output, which is unsigned
+        // when a Modality LUT is used
+        let voi_signed = signed && matches!(modality, ModalityTransform::Rescale { .. });
+        let voi = match (window, first_item("VOILUTSequence")) {
+            (Some(window), _) => VoiTransform::Window(window),
+            (None, Some(item)) => Lut::from_item(item, voi_signed).map_or(VoiTransform::Auto, VoiTransform::Lut),
+            (None, None) => VoiTransform::Auto,
+        };
+
+        let invert = match ds.get_str("PresentationLUTShape") {
+            Some(shape) => shape == "INVERSE",
+            None => ds.get_str("PhotometricInterpretation") == Some("MONOCHROME1"),
+        };
+        Self { modality, voi, invert }
+    }
+
+    /// Replaces the dataset's VOI stage with the given window.
+    pub fn with_window(mut self, window: Window) -> Self {
+        self.voi = VoiTransform::Window(window);
+        self
+    }
+
+    /// Renders the samples of a grayscale PixelArray as display values of
+    /// the given depth, keeping its shape.
+    pub fn render(&self, array: &PixelArray, depth: DisplayDepth) -> Option<PixelArray> {
+        if array.samples != 1 {
+            eprintln!("The grayscale pipeline needs one sample per pixel, got {}", array.samples);
+            return None;
+        }
+        let (min, max) = match self.voi {
+            VoiTransform::Auto => modality_range(&self.modality, &array.data)?,
+            _ => (0.0, 0.0),
+        };
+        let to_unit = |stored: f64| {
+            let x = self.modality.apply(stored);
+            let y = match &self.voi {
+                VoiTransform::Window(window) => window.apply(x),
+                VoiTransform::Lut(lut) => lut.lookup(x) as f64 / lut.max_output(),
+                VoiTransform::Auto if max > min => (x - min) / (max - min),
+                VoiTransform::Auto => 0.0,
+            };
+            if self.invert { 1.0 - y } else { y }
+        };
+        let out_max = match depth {
+            DisplayDepth::Bits8 => u8::MAX as f64,
+            DisplayDepth::Bits16 => u16::MAX as f64,
+        };
+        let to_display = |stored: f64| (to_unit(stored).clamp(0.0, 1.0) * out_max).round() as u16;
+
+        // Integer inputs of up to 16 bits go through a table over their
+        // whole range instead of evaluating the pipeline per pixel
+        let table: Option<(i32, Vec<u16>)> = match &array.data {
+            PixelBuffer::U8(_) => Some((0, (0..=255).map(|v| to_display(v as f64)).collect())),
+            PixelBuffer::I8(_) => Some((-128, (-128..=127).map(|v| to_display(v as f64)).collect())),
+            PixelBuffer::U16(_) => Some((0, (0..=65535).map(|v| to_display(v as f64)).collect())),
+            PixelBuffer::I16(_) => Some((-32768, (-32768..=32767).map(|v| to_display(v as f64)).collect())),
+            _ => None,
+        };
+        let values: Vec<u16> = match (&array.data, &table) {
+            (PixelBuffer::U8(v), Some((_, t))) => v.iter().map(|&s| t[s as usize]).collect(),
+            (PixelBuffer::I8(v), Some((o, t))) => v.iter().map(|&s| t[(s as i32 - o) as usize]).collect(),
+            (PixelBuffer::U16(v), Some((_, t))) => v.iter().map(|&s| t[s as usize]).collect(),
+            (PixelBuffer::I16(v), Some((o, t))) => v.iter().map(|&s| t[(s as i32 - o) as usize]).collect(),
+            (data, _) => (0..data.len()).map(|i| to_display(data.get(i))).collect(),
+        };
+        let data = match depth {
+            DisplayDepth::Bits8 => PixelBuffer::U8(values.into_iter().map(|v| v as u8).collect()),
+            DisplayDepth::Bits16 => PixelBuffer::U16(values),
+        };
+        Some(PixelArray {
+            frames: array.frames,
+            rows: array.rows,
+            columns: array.columns,
+            samples: array.samples,
+            data,
+        })
+    }
+}
+
+/// Smallest and largest modality value of the samples
+fn modality_range(modality: &ModalityTransform, data: &PixelBuffer) -> Option<(f64, f64)> {
+    (0..data.len())
+        .map(|i| modality.apply(data.get(i)))
+        .filter(|v| v.is_finite())
+        .fold(None, |range, v| match range {
+            None => Some((v, v)),
+            Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+        })
+}
+
+/// Decodes one frame and renders it for display, optionally overriding the
+/// dataset's window.
+pub fn render_frame(ds: &Dataset, frame: usize, window: Option<Window>, depth: DisplayDepth) -> Option<PixelArray> {
+    let array = decode_frame(ds, frame)?;
+    let mut pipeline = GrayscalePipeline::from_dataset(ds);
+    if let Some(window) = window {
+        pipeline = pipeline.with_window(window);
+    }
+    pipeline.render(&array, depth)
+}
+
+/// Stored values to real world values, linearly or through a table
+#[derive(Debug, Clone, PartialEq)]
+pub enum RealWorldTransform {
+    Linear { slope: f64, intercept: f64 },
+    /// One entry per stored value from the first value mapped on
+    Lut(Vec<f64>),
+}
+
+/// Real World Value Mapping Sequence item (PS3.3 Section C.7.6.16.2.11):
+/// maps the stored values in first_mapped..=last_mapped to a quantity
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealWorldValueMapping {
+    pub first_mapped: f64,
+    pub last_mapped: f64,
+    pub transform: RealWorldTransform,
+    pub label: Option<String>,
+    pub units: Option<Code>,
+}
+
+impl RealWorldValueMapping {
+    /// Reads an item. Float pixel data gives its range with the Double Float
+    /// attributes; integer ranges are signed when the pixel data is.
+    pub fn from_item(item: &Dataset, signed: bool) -> Option<Self> {
+        let double_range = (
+            item.get_float("DoubleFloatRealWorldValueFirstValueMapped"),
+            item.get_float("DoubleFloatRealWorldValueLastValueMapped"),
+        );
+        let (first_mapped, last_mapped) = match double_range {
+            (Some(first), Some(last)) => (first, last),
+            _ => {
+                let value = |keyword: &str| {
+                    let v = item.get_int(keyword)?;
+                    Some(if signed && v > i16::MAX as i64 { v as u16 as i16 as f64 } else { v as f64 })
+                };
+                match (value("RealWorldValueFirstValueMapped"), value("RealWorldValueLastValueMapped")) {
+                    (Some(first), Some(last)) => (first, last),
+                    _ => {
+                        eprintln!("Real World Value Mapping item has no first and last value mapped");
+                        return None;
+                    }
+                }
+            }
+        };
+        let lut = item.get("RealWorldValueLUTData").and_then(|e| e.value.as_ref()).map(|v| v.to_f64s());
+        let transform = match (lut, item.get_float("RealWorldValueSlope"), item.get_float("RealWorldValueIntercept")) {
+            (Some(table), _, _) if !table.is_empty() => RealWorldTransform::Lut(table),
+            (_, Some(slope), Some(intercept)) => RealWorldTransform::Linear { slope, intercept },
+            _ => {
+                eprintln!("Real World Value Mapping item has neither a LUT nor a slope and intercept");
+                return None;
+            }
+        };
+        Some(Self {
+            first_mapped,
+            last_mapped,
+            transform,
+            label: item.get_str("LUTLabel").map(str::to_string),
+            units: code_sequence(item, "MeasurementUnitsCodeSequence").into_iter().next(),
+        })
+    }
+
+    pub fn covers(&self, stored: f64) -> bool {
+        (self.first_mapped..=self.last_mapped).contains(&stored)
+    }
+
+    pub fn apply(&self, stored: f64) -> f64 {
+        match &self.transform {
+            RealWorldTransform::Linear { slope, intercept } => stored * slope + intercept,
+            RealWorldTransform::Lut(table) => {
+                let index = (stored - self.first_mapped).round().clamp(0.0, (table.len() - 1) as f64);
+                table[index as usize]
+            }
+        }
+    }
+}
+
+/// Real World Value Mappings of a frame: from its Per-frame Functional
+/// Groups item, else the Shared Functional Groups, else the top level.
+pub fn real_world_value_mappings(ds: &Dataset, frame: usize) -> Vec<RealWorldValueMapping> {
+    let signed = ds.get_int("PixelRepresentation") == Some(1);
+    fn items<'a>(ds: &'a Dataset, keyword: &str) -> &'a [Dataset] {
+        match ds.get(keyword).and_then(|e| e.value.as_ref()) {
+            Some(DataElementValue::Sequence(items)) => items,
+            _ => &[],
+        }
+    }
+    let per_frame = items(ds, "PerFrameFunctionalGroupsSequence");
+    let shared = items(ds, "SharedFunctionalGroupsSequence");
+    let sources = per_frame.get(frame).into_iter().chain(shared.first()).chain(std::iter::once(ds));
+    for source in sources {
+        let mappings = items(source, "RealWorldValueMappingSequence");
+        if !mappings.is_empty() {
+            return mappings.iter().filter_map(|item| RealWorldValueMapping::from_item(item, signed)).collect();
+        }
+    }
+    Vec::new()
+}
+
+/// Decodes one frame as real world values. Each stored value goes through
+/// the first mapping whose range covers it, and becomes NaN when none
+/// does; without any mapping the stored values are returned as they are.
+pub fn real_world_frame(ds: &Dataset, frame: usize) -> Option<PixelArray> {
+    let array = decode_frame(ds, frame)?;
+    let mappings = real_world_value_mappings(ds, frame);
+    let data = (0..array.data.len())
+        .map(|i| {
+            let stored = array.data.get(i);
+            if mappings.is_empty() {
+                return stored;
+            }
+            mappings.iter().find(|m| m.covers(stored)).map_or(f64::NAN, |m| m.apply(stored))
+        })
+        .collect();
+    Some(PixelArray { data: PixelBuffer::F64(data), ..array })
+}
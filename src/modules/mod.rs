@@ -1 +1,12 @@
-pub mod io;
\ No newline at end of file
+pub mod capture;
+pub mod codecs;
+pub mod color;
+#[cfg(feature = "export")]
+pub mod export;
+pub mod io;
+pub mod lut;
+pub mod mosaic;
+pub mod overlay;
+pub mod pixel;
+pub mod scan;
+pub mod thumbnail;
@@ -0,0 +1,270 @@
+use crate::dataelem::{DataElement, DataElementValue};
+use crate::dataset::Dataset;
+use crate::modules::pixel::{decode_frame, encode_native, map_samples, PixelArray, PixelBuffer, PixelDescription};
+use crate::uids::{generate_uid, EXPLICIT_VR_LITTLE_ENDIAN};
+
+/// Private Creator of the Siemens MR header block in group 0019
+pub const SIEMENS_MR_HEADER: &str = "SIEMENS MR HEADER";
+/// Private Creator of the CSA header block in group 0029
+pub const SIEMENS_CSA_HEADER: &str = "SIEMENS CSA HEADER";
+
+/// One entry of a Siemens CSA header, with its values as text
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsaElement {
+    pub name: String,
+    pub vr: String,
+    pub values: Vec<String>,
+}
+
+fn read_i32(bytes: &[u8], pos: usize) -> Option<i32> {
+    Some(i32::from_le_bytes(bytes.get(pos..pos + 4)?.try_into().ok()?))
+}
+
+/// Text up to the first NUL, without padding
+fn c_string(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).trim().to_string()
+}
+
+/// Parses a CSA2 header ("SV10" signature), as kept in CSA Image Header Info
+/// (0029,xx10) and CSA Series Header Info (0029,xx20). Every entry is a
+/// 64-byte name, VM, 4-byte VR, SyngoDT, item count and a check value,
+/// followed by items of four 32-bit words and a value padded to 4 bytes.
+pub fn parse_csa_header(bytes: &[u8]) -> Option<Vec<CsaElement>> {
+    if !bytes.starts_with(b"SV10") {
+        eprintln!("Only CSA2 headers (SV10) are supported");
+        return None;
+    }
+    let count = read_i32(bytes, 8)?;
+    if !(1..=128).contains(&count) {
+        eprintln!("CSA header has {count} entries");
+        return None;
+    }
+    let mut pos = 16;
+    let mut elements = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let name = c_string(bytes.get(pos..pos + 64)?);
+        let vm = read_i32(bytes, pos + 64)?;
+        let vr = c_string(bytes.get(pos + 68..pos + 72)?);
+        let items = read_i32(bytes, pos + 76)?;
+        pos += 84;
+        if !(0..=512).contains(&items) {
+            eprintln!("CSA entry {name} has {items} items");
+            return None;
+        }
+        // Items beyond the VM are allocated but empty; VM 0 means all
+        let used = if vm > 0 { vm.min(items) } else { items };
+        let mut values = Vec::new();
+        for item in 0..items {
+            let length = read_i32(bytes, pos + 4)?;
+            pos += 16;
+            let Some(value) = usize::try_from(length).ok().and_then(|n| bytes.get(pos..pos + n)) else {
+                eprintln!("CSA entry {name} runs past the end of the header");
+                return None;
+            };
+            if item < used {
+                values.push(c_string(value));
+            }
+            pos += value.len().next_multiple_of(4);
+        }
+        elements.push(CsaElement { name, vr, values });
+    }
+    Some(elements)
+}
+
+/// Numeric values of the named CSA entry; empty values are skipped.
+pub fn csa_floats(elements: &[CsaElement], name: &str) -> Vec<f64> {
+    elements
+        .iter()
+        .find(|e| e.name == name)
+        .map(|e| e.values.iter().filter_map(|v| v.parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Entries of the CSA Image Header Info (0029,xx10), if present and valid
+pub fn csa_image_header(ds: &Dataset) -> Option<Vec<CsaElement>> {
+    match &ds.get_private(0x0029, SIEMENS_CSA_HEADER, 0x10)?.value {
+        Some(DataElementValue::Data(bytes)) => parse_csa_header(bytes),
+        _ => None,
+    }
+}
+
+/// Image Type (0008,0008) has the MOSAIC value
+pub fn is_mosaic(ds: &Dataset) -> bool {
+    ds.get_str("ImageType").is_some_and(|s| s.split('\\').any(|v| v.trim() == "MOSAIC"))
+}
+
+/// NumberOfImagesInMosaic from the Siemens MR header (0019,xx0A), or else
+/// from the CSA Image Header Info.
+pub fn images_in_mosaic(ds: &Dataset) -> Option<usize> {
+    let private = ds
+        .get_private(0x0019, SIEMENS_MR_HEADER, 0x0A)
+        .and_then(|e| e.value.as_ref())
+        .and_then(|v| v.to_u16s().first().copied());
+    let count = match private {
+        Some(n) => n as usize,
+        None => *csa_floats(&csa_image_header(ds)?, "NumberOfImagesInMosaic").first()? as usize,
+    };
+    (count > 0).then_some(count)
+}
+
+/// Slice layout of a mosaic: `images` tiles of `rows` by `columns`, laid out
+/// row by row on a square grid of `tiles` per side
+#[derive(Debug, Clone, PartialEq)]
+pub struct MosaicInfo {
+    pub images: usize,
+    pub tiles: usize,
+    pub rows: usize,
+    pub columns: usize,
+    /// Image Position (Patient) of each slice
+    pub positions: Vec<[f64; 3]>,
+    /// Unit normal the slices are stacked along
+    pub normal: [f64; 3],
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Reads the mosaic layout and slice geometry. Siemens puts the Image
+/// Position (Patient) of the whole mosaic where a slice-sized image centered
+/// on it would have its top left pixel, so the first slice's position is
+/// moved back by half the difference in size. The slices then follow along
+/// the CSA SliceNormalVector, or the row and column cross product, at
+/// Spacing Between Slices (or Slice Thickness).
+pub fn mosaic_info(ds: &Dataset) -> Option<MosaicInfo> {
+    if !is_mosaic(ds) {
+        eprintln!("Image Type has no MOSAIC value");
+        return None;
+    }
+    let Some(images) = images_in_mosaic(ds) else {
+        eprintln!("Mosaic has no NumberOfImagesInMosaic");
+        return None;
+    };
+    let desc = PixelDescription::from_dataset(ds)?;
+    let tiles = (images as f64).sqrt().ceil() as usize;
+    if desc.rows % tiles != 0 || desc.columns % tiles != 0 {
+        eprintln!("{}x{} mosaic does not split into {tiles}x{tiles} tiles", desc.rows, desc.columns);
+        return None;
+    }
+    let (rows, columns) = (desc.rows / tiles, desc.columns / tiles);
+
+    let floats = |keyword: &str| ds.get(keyword).and_then(|e| e.value.as_ref()).map(|v| v.to_floats()).unwrap_or_default();
+    let (position, orientation, spacing) = (floats("ImagePositionPatient"), floats("ImageOrientationPatient"), floats("PixelSpacing"));
+    if position.len() != 3 || orientation.len() != 6 || spacing.len() != 2 {
+        eprintln!("Mosaic needs Image Position, Image Orientation and Pixel Spacing");
+        return None;
+    }
+    let row = [orientation[0], orientation[1], orientation[2]];
+    let column = [orientation[3], orientation[4], orientation[5]];
+    // Pixel Spacing is the row spacing (between rows) then the column spacing
+    let shift_along_row = spacing[1] * (desc.columns - columns) as f64 / 2.0;
+    let shift_along_column = spacing[0] * (desc.rows - rows) as f64 / 2.0;
+    let first: [f64; 3] = std::array::from_fn(|i| position[i] + row[i] * shift_along_row + column[i] * shift_along_column);
+
+    let csa_normal = csa_image_header(ds).map(|csa| csa_floats(&csa, "SliceNormalVector")).unwrap_or_default();
+    let normal = match csa_normal.as_slice() {
+        &[x, y, z] => [x, y, z],
+        _ => cross(row, column),
+    };
+    let length = dot(normal, normal).sqrt();
+    if length == 0.0 {
+        eprintln!("Mosaic slice normal has zero length");
+        return None;
+    }
+    let normal = normal.map(|v| v / length);
+    let step = ds.get_float("SpacingBetweenSlices").or_else(|| ds.get_float("SliceThickness")).unwrap_or(1.0);
+    let positions = (0..images).map(|k| std::array::from_fn(|i| first[i] + k as f64 * step * normal[i])).collect();
+    Some(MosaicInfo { images, tiles, rows, columns, positions, normal })
+}
+
+/// Copies the slice with its top left pixel at (`top`, `left`) out of a
+/// mosaic whose rows are `stride` samples long
+fn tile<T: Copy>(data: &[T], stride: usize, top: usize, left: usize, info: &MosaicInfo, samples: usize) -> Vec<T> {
+    (top..top + info.rows)
+        .flat_map(|y| {
+            let start = y * stride + left * samples;
+            data[start..start + info.columns * samples].iter().copied()
+        })
+        .collect()
+}
+
+/// Decimal String of at most 16 characters
+fn decimal_string(value: f64) -> String {
+    (0..=6)
+        .rev()
+        .map(|digits| {
+            let s = format!("{value:.digits$}");
+            let s = if s.contains('.') { s.trim_end_matches('0').trim_end_matches('.').to_string() } else { s };
+            if s == "-0" { "0".to_string() } else { s }
+        })
+        .find(|s| s.len() <= 16)
+        .unwrap_or_else(|| format!("{value:.0}"))
+}
+
+/// Splits a Siemens mosaic into one dataset per slice, in acquisition
+/// order, so they can be handled like the images of a normal series. Each
+/// slice gets its own SOP Instance UID, size, Image Position (Patient) and
+/// Slice Location, native little endian Pixel Data and no MOSAIC in its
+/// Image Type.
+pub fn unpack_mosaic(ds: &Dataset) -> Option<Vec<Dataset>> {
+    let info = mosaic_info(ds)?;
+    let mosaic = decode_frame(ds, 0)?;
+    let desc = PixelDescription::from_dataset(ds)?;
+    let slice_desc = PixelDescription {
+        rows: info.rows,
+        columns: info.columns,
+        high_bit: desc.bits_stored - 1,
+        number_of_frames: 1,
+        ..desc
+    };
+    let image_type = ds
+        .get_str("ImageType")
+        .map(|s| s.split('\\').filter(|v| v.trim() != "MOSAIC").collect::<Vec<_>>().join("\\"));
+    let encapsulated = ds.transfer_syntax().is_some_and(|ts| ts.encapsulated);
+
+    let mut slices = Vec::with_capacity(info.images);
+    for (k, position) in info.positions.iter().enumerate() {
+        let (top, left) = (k / info.tiles * info.rows, k % info.tiles * info.columns);
+        let data = map_samples!(&mosaic.data, v => tile(v, mosaic.columns * mosaic.samples, top, left, &info, mosaic.samples));
+        let array = PixelArray { frames: 1, rows: info.rows, columns: info.columns, samples: mosaic.samples, data };
+        let native = encode_native(&array, &slice_desc)?;
+
+        let mut slice = ds.clone();
+        let string = |s: String| DataElementValue::String(s);
+        let uid = generate_uid();
+        let mut attributes = vec![
+            ("SOPInstanceUID", string(uid.clone())),
+            ("Rows", DataElementValue::UInt16(info.rows as u16)),
+            ("Columns", DataElementValue::UInt16(info.columns as u16)),
+            ("HighBit", DataElementValue::UInt16(slice_desc.high_bit)),
+            ("ImagePositionPatient", string(position.map(decimal_string).join("\\"))),
+            ("SliceLocation", string(decimal_string(dot(*position, info.normal)))),
+        ];
+        if let Some(image_type) = &image_type {
+            attributes.push(("ImageType", string(image_type.clone())));
+        }
+        for (keyword, value) in attributes {
+            slice.set(DataElement::new(keyword, value)?);
+        }
+        let mut meta = slice.file_meta().to_vec();
+        for elem in meta.iter_mut() {
+            match elem.attribute.keyword {
+                "MediaStorageSOPInstanceUID" => elem.value = Some(string(uid.clone())),
+                // Native Pixel Data needs a transfer syntax that is not encapsulated
+                "TransferSyntaxUID" if encapsulated => {
+                    elem.value = Some(string(EXPLICIT_VR_LITTLE_ENDIAN.to_string()))
+                }
+                _ => {}
+            }
+        }
+        slice.set_file_meta(meta);
+        slice.set_pixel_data(native);
+        slices.push(slice);
+    }
+    Some(slices)
+}
@@ -0,0 +1,214 @@
+use crate::byteswap;
+use crate::dataelem::DataElementValue;
+use crate::dataset::Dataset;
+use crate::modules::lut::{render_frame, DisplayDepth, Window};
+use crate::modules::pixel::{PixelArray, PixelBuffer, PixelDescription};
+use crate::uids::Endianness;
+
+/// Overlay Type (60xx,0040)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayType {
+    Graphics,
+    Roi,
+}
+
+/// Overlay Plane (PS3.3 Section C.9.2) decoded to one bitmap per overlay
+/// frame
+#[derive(Debug, Clone, PartialEq)]
+pub struct Overlay {
+    /// Repeating group, 0x6000 to 0x601E
+    pub group: u16,
+    pub rows: usize,
+    pub columns: usize,
+    /// Image row and column of the overlay's top left pixel, 1-based; may be
+    /// negative or beyond the image
+    pub origin: (i32, i32),
+    pub kind: OverlayType,
+    /// Image frame the first overlay frame applies to, 0-based
+    pub first_frame: usize,
+    pub frames: usize,
+    pub label: Option<String>,
+    pub description: Option<String>,
+    /// Row-major bits of every overlay frame
+    pub data: Vec<bool>,
+}
+
+/// Overlay groups present in the dataset, in ascending order. A group
+/// counts when it has Overlay Rows.
+pub fn overlay_groups(ds: &Dataset) -> Vec<u16> {
+    let mut groups: Vec<u16> = ds
+        .elements()
+        .iter()
+        .map(|e| e.tag)
+        .filter(|&tag| tag & 0xFFFF == 0x0010)
+        .map(|tag| (tag >> 16) as u16)
+        .filter(|group| (0x6000..=0x601E).contains(group) && group % 2 == 0)
+        .collect();
+    groups.sort_unstable();
+    groups.dedup();
+    groups
+}
+
+/// Every overlay of the dataset; groups that fail to decode are skipped.
+pub fn read_overlays(ds: &Dataset) -> Vec<Overlay> {
+    overlay_groups(ds).into_iter().filter_map(|group| read_overlay(ds, group)).collect()
+}
+
+/// Decodes the overlay in the given group, from Overlay Data or, for
+/// retired embedded overlays, from the bit of Pixel Data at Overlay Bit
+/// Position.
+pub fn read_overlay(ds: &Dataset, group: u16) -> Option<Overlay> {
+    let get = |element: u16| ds.get(&format!("({group:04X},{element:04X})"));
+    let int = |element: u16| get(element).and_then(|e| e.value.as_ref()).and_then(|v| v.to_int());
+    let string = |element: u16| match get(element).and_then(|e| e.value.as_ref()) {
+        Some(DataElementValue::String(s)) if !s.is_empty() => Some(s.clone()),
+        _ => None,
+    };
+    let (rows, columns) = match (int(0x0010), int(0x0011)) {
+        (Some(rows), Some(columns)) if rows > 0 && columns > 0 => (rows as usize, columns as usize),
+        _ => {
+            eprintln!("Overlay group {group:04X} has no valid Overlay Rows and Columns");
+            return None;
+        }
+    };
+    let origin = match get(0x0050).and_then(|e| e.value.as_ref()).map(|v| v.to_u16s()) {
+        Some(values) if values.len() >= 2 => (values[0] as i16 as i32, values[1] as i16 as i32),
+        _ => (1, 1),
+    };
+    let kind = match string(0x0040).as_deref() {
+        Some("R") => OverlayType::Roi,
+        _ => OverlayType::Graphics,
+    };
+    let frames = int(0x0015).unwrap_or(1).max(1) as usize;
+    let first_frame = int(0x0051).unwrap_or(1).max(1) as usize - 1;
+    let Some(bits) = rows.checked_mul(columns).and_then(|pixels| pixels.checked_mul(frames)) else {
+        eprintln!("Overlay group {group:04X} of {rows}x{columns} with {frames} frames does not fit in memory");
+        return None;
+    };
+
+    let data = match get(0x3000) {
+        Some(elem) => {
+            // OW words were swapped to little endian when read, which puts
+            // the bit stream back in byte order
+            let bytes = match &elem.value {
+                Some(DataElementValue::Data(bytes)) => bytes.as_slice(),
+                _ => &[],
+            };
+            if bytes.len() * 8 < bits {
+                eprintln!("Overlay Data of group {group:04X} has {} bits, expected {bits}", bytes.len() * 8);
+                return None;
+            }
+            (0..bits).map(|i| bytes[i / 8] >> (i % 8) & 1 == 1).collect()
+        }
+        None => embedded_bits(ds, int(0x0102), rows, columns, frames, first_frame)?,
+    };
+    Some(Overlay {
+        group,
+        rows,
+        columns,
+        origin,
+        kind,
+        first_frame,
+        frames,
+        label: string(0x1500),
+        description: string(0x0022),
+        data,
+    })
+}
+
+/// Overlay bits kept in an unused high bit of native Pixel Data
+fn embedded_bits(
+    ds: &Dataset,
+    bit_position: Option<i64>,
+    rows: usize,
+    columns: usize,
+    frames: usize,
+    first_frame: usize,
+) -> Option<Vec<bool>> {
+    let desc = PixelDescription::from_dataset(ds)?;
+    let bit = match bit_position {
+        Some(bit) if bit > desc.high_bit as i64 && bit < desc.bits_allocated as i64 => bit as u32,
+        _ => {
+            eprintln!("Overlay has neither Overlay Data nor a Bit Position above High Bit");
+            return None;
+        }
+    };
+    let raw = match ds.pixel_data() {
+        Some(raw) if ds.transfer_syntax().is_none_or(|ts| !ts.encapsulated) => raw,
+        _ => {
+            eprintln!("Embedded overlays need native Pixel Data");
+            return None;
+        }
+    };
+    if (rows, columns) != (desc.rows, desc.columns) || desc.samples_per_pixel != 1 || desc.bits_allocated != 16 {
+        eprintln!("Embedded overlays need 16-bit single sample Pixel Data of the overlay's size");
+        return None;
+    }
+    // Two bytes per cell; the end bounds the start, so only it is checked
+    let cell_bytes = rows * columns * 2;
+    let end = first_frame.checked_add(frames).and_then(|end| end.checked_mul(cell_bytes));
+    let Some(cells) = end.and_then(|end| raw.get(first_frame * cell_bytes..end)) else {
+        eprintln!("Pixel Data does not hold overlay frames {first_frame} to {}", first_frame.saturating_add(frames));
+        return None;
+    };
+    Some(byteswap::u16s(cells, Endianness::Little).into_iter().map(|cell| cell >> bit & 1 == 1).collect())
+}
+
+impl Overlay {
+    /// Bitmap applying to the given image frame, if the overlay covers it
+    pub fn frame(&self, image_frame: usize) -> Option<&[bool]> {
+        let index = image_frame.checked_sub(self.first_frame).filter(|&i| i < self.frames)?;
+        let size = self.rows * self.columns;
+        Some(&self.data[index * size..(index + 1) * size])
+    }
+
+    /// Bitmap of the given image frame as 0/1 bytes
+    pub fn frame_mask(&self, image_frame: usize) -> Option<Vec<u8>> {
+        Some(self.frame(image_frame)?.iter().map(|&b| b as u8).collect())
+    }
+
+    /// Paints the overlay's set bits into the first frame of a rendered
+    /// array, which shows the given image frame, with the largest value of
+    /// its sample type. Bits falling outside the image are dropped.
+    pub fn composite(&self, rendered: &mut PixelArray, image_frame: usize) {
+        let Some(bits) = self.frame(image_frame) else {
+            return;
+        };
+        let (rows, columns, samples) = (rendered.rows as i64, rendered.columns as i64, rendered.samples);
+        for i in (0..bits.len()).filter(|&i| bits[i]) {
+            let row = self.origin.0 as i64 - 1 + (i / self.columns) as i64;
+            let column = self.origin.1 as i64 - 1 + (i % self.columns) as i64;
+            if !(0..rows).contains(&row) || !(0..columns).contains(&column) {
+                continue;
+            }
+            let first = (row * columns + column) as usize * samples;
+            for index in first..first + samples {
+                match &mut rendered.data {
+                    PixelBuffer::U8(v) => v[index] = u8::MAX,
+                    PixelBuffer::I8(v) => v[index] = i8::MAX,
+                    PixelBuffer::U16(v) => v[index] = u16::MAX,
+                    PixelBuffer::I16(v) => v[index] = i16::MAX,
+                    PixelBuffer::U32(v) => v[index] = u32::MAX,
+                    PixelBuffer::I32(v) => v[index] = i32::MAX,
+                    PixelBuffer::F32(v) => v[index] = 1.0,
+                    PixelBuffer::F64(v) => v[index] = 1.0,
+                }
+            }
+        }
+    }
+}
+
+/// Renders one frame for display, as lut::render_frame does, with every
+/// overlay covering the frame burnt in.
+pub fn render_frame_with_overlays(
+    ds: &Dataset,
+    frame: usize,
+    window: Option<Window>,
+    depth: DisplayDepth,
+) -> Option<PixelArray> {
+    let mut rendered = render_frame(ds, frame, window, depth)?;
+    for overlay in read_overlays(ds) {
+        overlay.composite(&mut rendered, frame);
+    }
+    Some(rendered)
+}
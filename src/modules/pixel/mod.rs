@@ -0,0 +1,676 @@
+use std::ops::Range;
+
+use crate::byteswap;
+use crate::dataset::{Dataset, EncapsulatedPixelData};
+use crate::modules::codecs;
+use crate::uids::{Endianness, TransferSyntax};
+
+/// Rebuilds a PixelBuffer of the same sample type from an expression over
+/// its samples.
+macro_rules! map_samples {
+    ($data:expr, $v:ident => $body:expr) => {
+        match $data {
+            PixelBuffer::U8($v) => PixelBuffer::U8($body),
+            PixelBuffer::I8($v) => PixelBuffer::I8($body),
+            PixelBuffer::U16($v) => PixelBuffer::U16($body),
+            PixelBuffer::I16($v) => PixelBuffer::I16($body),
+            PixelBuffer::U32($v) => PixelBuffer::U32($body),
+            PixelBuffer::I32($v) => PixelBuffer::I32($body),
+            PixelBuffer::F32($v) => PixelBuffer::F32($body),
+            PixelBuffer::F64($v) => PixelBuffer::F64($body),
+        }
+    };
+}
+pub(crate) use map_samples;
+
+/// Image Pixel Module attributes that decide how Pixel Data is laid out
+/// (PS3.3 Section C.7.6.3)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelDescription {
+    pub rows: usize,
+    pub columns: usize,
+    pub samples_per_pixel: usize,
+    pub bits_allocated: u16,
+    pub bits_stored: u16,
+    pub high_bit: u16,
+    /// Pixel Representation is 1 (two's complement)
+    pub signed: bool,
+    /// Planar Configuration is 1 (color-by-plane)
+    pub planar: bool,
+    pub number_of_frames: usize,
+    pub photometric_interpretation: String,
+}
+
+impl PixelDescription {
+    /// Reads the description; Rows, Columns and Bits Allocated are required,
+    /// the rest fall back to their single-frame grayscale defaults.
+    pub fn from_dataset(ds: &Dataset) -> Option<Self> {
+        let rows = ds.get_int("Rows")?;
+        let columns = ds.get_int("Columns")?;
+        let bits_allocated = ds.get_int("BitsAllocated")?;
+        let bits_stored = ds.get_int("BitsStored").unwrap_or(bits_allocated);
+        let high_bit = ds.get_int("HighBit").unwrap_or(bits_stored - 1);
+        let samples_per_pixel = ds.get_int("SamplesPerPixel").unwrap_or(1);
+        let number_of_frames = ds.get_int("NumberOfFrames").unwrap_or(1);
+        // Rows, Columns and Samples per Pixel are US, whatever VR the file used
+        let us = 1..=u16::MAX as i64;
+        if !us.contains(&rows) || !us.contains(&columns) || !us.contains(&samples_per_pixel) || number_of_frames <= 0 {
+            eprintln!("Invalid image size {rows}x{columns}x{samples_per_pixel}, {number_of_frames} frames");
+            return None;
+        }
+        if !(1..=64).contains(&bits_allocated)
+            || bits_stored < 1
+            || bits_stored > bits_allocated
+            || high_bit >= bits_allocated
+            || high_bit + 1 < bits_stored
+        {
+            eprintln!("Inconsistent BitsAllocated {bits_allocated}, BitsStored {bits_stored}, HighBit {high_bit}");
+            return None;
+        }
+        let desc = Self {
+            rows: rows as usize,
+            columns: columns as usize,
+            samples_per_pixel: samples_per_pixel as usize,
+            bits_allocated: bits_allocated as u16,
+            bits_stored: bits_stored as u16,
+            high_bit: high_bit as u16,
+            signed: ds.get_int("PixelRepresentation") == Some(1),
+            planar: ds.get_int("PlanarConfiguration") == Some(1),
+            number_of_frames: number_of_frames as usize,
+            photometric_interpretation: ds
+                .get_str("PhotometricInterpretation")
+                .unwrap_or("MONOCHROME2")
+                .to_string(),
+        };
+        if desc.frame_bytes().is_none() || desc.stored_frame_bytes().is_none() {
+            eprintln!("A frame of {rows}x{columns}x{samples_per_pixel} {bits_allocated}-bit samples does not fit in memory");
+            return None;
+        }
+        Some(desc)
+    }
+
+    /// Samples in one frame, or None when the count overflows
+    pub fn frame_samples(&self) -> Option<usize> {
+        self.rows.checked_mul(self.columns)?.checked_mul(self.samples_per_pixel)
+    }
+
+    /// Bytes of one decoded frame, or None when the count overflows
+    pub fn frame_bytes(&self) -> Option<usize> {
+        Some(self.frame_samples()?.checked_mul(self.bits_allocated as usize)?.div_ceil(8))
+    }
+
+    /// Native YBR_FULL_422 stores each horizontal pixel pair as Y1 Y2 Cb Cr
+    /// (PS3.3 Section C.7.6.3.1.2); compressed frames decode to full size.
+    pub fn is_subsampled(&self) -> bool {
+        self.photometric_interpretation == "YBR_FULL_422" && self.samples_per_pixel == 3
+    }
+
+    /// Bytes of one frame as stored in native Pixel Data, or None when the
+    /// count overflows
+    pub fn stored_frame_bytes(&self) -> Option<usize> {
+        if self.is_subsampled() {
+            let bits = self.rows.checked_mul(self.columns.div_ceil(2) * 4)?.checked_mul(self.bits_allocated as usize)?;
+            Some(bits.div_ceil(8))
+        } else {
+            self.frame_bytes()
+        }
+    }
+}
+
+/// Decoded samples in the smallest type that holds the stored values
+#[derive(Debug, Clone, PartialEq)]
+pub enum PixelBuffer {
+    U8(Vec<u8>),
+    I8(Vec<i8>),
+    U16(Vec<u16>),
+    I16(Vec<i16>),
+    U32(Vec<u32>),
+    I32(Vec<i32>),
+    F32(Vec<f32>),
+    F64(Vec<f64>),
+}
+
+impl PixelBuffer {
+    pub fn len(&self) -> usize {
+        match self {
+            PixelBuffer::U8(v) => v.len(),
+            PixelBuffer::I8(v) => v.len(),
+            PixelBuffer::U16(v) => v.len(),
+            PixelBuffer::I16(v) => v.len(),
+            PixelBuffer::U32(v) => v.len(),
+            PixelBuffer::I32(v) => v.len(),
+            PixelBuffer::F32(v) => v.len(),
+            PixelBuffer::F64(v) => v.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Samples as native little endian bytes
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        match self {
+            PixelBuffer::U8(v) => v.clone(),
+            PixelBuffer::I8(v) => v.iter().map(|&s| s as u8).collect(),
+            PixelBuffer::U16(v) => v.iter().flat_map(|s| s.to_le_bytes()).collect(),
+            PixelBuffer::I16(v) => v.iter().flat_map(|s| s.to_le_bytes()).collect(),
+            PixelBuffer::U32(v) => v.iter().flat_map(|s| s.to_le_bytes()).collect(),
+            PixelBuffer::I32(v) => v.iter().flat_map(|s| s.to_le_bytes()).collect(),
+            PixelBuffer::F32(v) => v.iter().flat_map(|s| s.to_le_bytes()).collect(),
+            PixelBuffer::F64(v) => v.iter().flat_map(|s| s.to_le_bytes()).collect(),
+        }
+    }
+
+    /// Sample at a flat index, widened to f64
+    pub fn get(&self, index: usize) -> f64 {
+        match self {
+            PixelBuffer::U8(v) => v[index] as f64,
+            PixelBuffer::I8(v) => v[index] as f64,
+            PixelBuffer::U16(v) => v[index] as f64,
+            PixelBuffer::I16(v) => v[index] as f64,
+            PixelBuffer::U32(v) => v[index] as f64,
+            PixelBuffer::I32(v) => v[index] as f64,
+            PixelBuffer::F32(v) => v[index] as f64,
+            PixelBuffer::F64(v) => v[index],
+        }
+    }
+}
+
+/// Frames of an image with shape (frames, rows, columns, samples); color
+/// samples are always interleaved, whatever the Planar Configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelArray {
+    pub frames: usize,
+    pub rows: usize,
+    pub columns: usize,
+    pub samples: usize,
+    pub data: PixelBuffer,
+}
+
+impl PixelArray {
+    pub fn shape(&self) -> (usize, usize, usize, usize) {
+        (self.frames, self.rows, self.columns, self.samples)
+    }
+
+    /// Samples per frame
+    pub fn frame_len(&self) -> usize {
+        self.rows * self.columns * self.samples
+    }
+
+    pub fn index(&self, frame: usize, row: usize, column: usize, sample: usize) -> usize {
+        ((frame * self.rows + row) * self.columns + column) * self.samples + sample
+    }
+
+    pub fn value(&self, frame: usize, row: usize, column: usize, sample: usize) -> f64 {
+        self.data.get(self.index(frame, row, column, sample))
+    }
+}
+
+/// Decodes every frame of the Pixel Data.
+pub fn decode_pixel_data(ds: &Dataset) -> Option<PixelArray> {
+    let desc = PixelDescription::from_dataset(ds)?;
+    decode_frames(ds, &desc, 0, desc.number_of_frames)
+}
+
+/// Decodes a single frame of the Pixel Data.
+pub fn decode_frame(ds: &Dataset, frame: usize) -> Option<PixelArray> {
+    let desc = PixelDescription::from_dataset(ds)?;
+    if frame >= desc.number_of_frames {
+        eprintln!("Frame {frame} out of range; the image has {} frames", desc.number_of_frames);
+        return None;
+    }
+    decode_frames(ds, &desc, frame, 1)
+}
+
+/// Decodes a single frame with `level` resolution levels discarded, halving
+/// Rows and Columns per level. Only JPEG 2000 and HTJ2K codestreams carry
+/// resolution levels; other transfer syntaxes, and level 0, decode the full
+/// frame.
+pub fn decode_frame_at_resolution(ds: &Dataset, frame: usize, level: u8) -> Option<PixelArray> {
+    if level == 0 || !ds.transfer_syntax().is_some_and(|ts| codecs::is_jpeg2000(ts.entry.uid)) {
+        return decode_frame(ds, frame);
+    }
+    let desc = PixelDescription::from_dataset(ds)?;
+    if frame >= desc.number_of_frames {
+        eprintln!("Frame {frame} out of range; the image has {} frames", desc.number_of_frames);
+        return None;
+    }
+    let encapsulated = match ds.encapsulated_pixel_data() {
+        Some(e) => e,
+        None => {
+            eprintln!("Dataset has no encapsulated Pixel Data");
+            return None;
+        }
+    };
+    let ranges = encapsulated.frame_fragments(desc.number_of_frames)?;
+    let data = encapsulated.fragments[ranges[frame].clone()].concat();
+    let reduced = codecs::jpeg2000::decode_frame_reduced(&desc, &data, level)?;
+    let desc = PixelDescription { rows: reduced.rows, columns: reduced.columns, number_of_frames: 1, ..desc };
+    let frame_bytes = desc.frame_bytes()?;
+    if reduced.data.len() < frame_bytes {
+        eprintln!("Decoded frame is {} bytes, expected {frame_bytes}", reduced.data.len());
+        return None;
+    }
+    let data = decode_native(&desc, &reduced.data[..frame_bytes])?;
+    Some(PixelArray { frames: 1, rows: desc.rows, columns: desc.columns, samples: desc.samples_per_pixel, data })
+}
+
+fn decode_frames(ds: &Dataset, desc: &PixelDescription, first: usize, count: usize) -> Option<PixelArray> {
+    if let Some(ts) = ds.transfer_syntax().filter(|ts| ts.encapsulated) {
+        return decode_encapsulated(ds, &ts, desc, first, count);
+    }
+    let raw = match ds.pixel_data() {
+        Some(raw) => raw,
+        None => {
+            eprintln!("Dataset has no Pixel Data");
+            return None;
+        }
+    };
+    if desc.bits_allocated == 1 {
+        // Frames follow each other bit by bit, not byte by byte
+        let pixels = desc.frame_samples()?;
+        let bits = frames_span(first, count, pixels)?;
+        let data = unpack_bits(raw, bits.start, bits.len())?;
+        return Some(PixelArray {
+            frames: count,
+            rows: desc.rows,
+            columns: desc.columns,
+            samples: desc.samples_per_pixel,
+            data: PixelBuffer::U8(data),
+        });
+    }
+    if !desc.bits_allocated.is_multiple_of(8) {
+        eprintln!("BitsAllocated {} is not supported", desc.bits_allocated);
+        return None;
+    }
+    let Range { start, end } = frames_span(first, count, desc.stored_frame_bytes()?)?;
+    if raw.len() < end {
+        eprintln!("Pixel Data is {} bytes, expected at least {end}", raw.len());
+        return None;
+    }
+    let bytes = &raw[start..end];
+    let mut data = if is_float_pixel_data(ds) {
+        decode_float(desc, bytes)?
+    } else {
+        decode_native(desc, bytes)?
+    };
+    if desc.is_subsampled() {
+        data = map_samples!(data, v => upsample_422(v, desc.columns));
+    } else if desc.planar && desc.samples_per_pixel > 1 {
+        data = map_samples!(data, v => interleave_planes(v, desc.samples_per_pixel, desc.rows * desc.columns));
+    }
+    Some(PixelArray {
+        frames: count,
+        rows: desc.rows,
+        columns: desc.columns,
+        samples: desc.samples_per_pixel,
+        data,
+    })
+}
+
+fn decode_encapsulated(
+    ds: &Dataset,
+    ts: &TransferSyntax,
+    desc: &PixelDescription,
+    first: usize,
+    count: usize,
+) -> Option<PixelArray> {
+    // Check the fragments, then the first frame's codestream, before
+    // allocating for the frames they claim
+    let (encapsulated, ranges) = encapsulated_frames(ds, desc, first..first + count)?;
+    let total = frames_span(0, count, desc.frame_bytes()?)?.end;
+    let mut native = Vec::new();
+    if let Some((head, rest)) = ranges.split_first() {
+        let frame = decode_encapsulated_frame(encapsulated, ts, desc, head)?;
+        native.reserve_exact(total);
+        native.extend_from_slice(&frame);
+        native.resize(total, 0);
+        decode_frames_into(encapsulated, ts, desc, rest, &mut native[frame.len()..])?;
+    }
+    // Codecs hand back interleaved little endian samples
+    let data = decode_native(desc, &native)?;
+    Some(PixelArray {
+        frames: count,
+        rows: desc.rows,
+        columns: desc.columns,
+        samples: desc.samples_per_pixel,
+        data,
+    })
+}
+
+/// Byte (or bit) range of `count` frames of `size` each from frame `first`,
+/// or None when it overflows
+fn frames_span(first: usize, count: usize, size: usize) -> Option<Range<usize>> {
+    let span = first.checked_mul(size).and_then(|start| Some(start..start.checked_add(count.checked_mul(size)?)?));
+    if span.is_none() {
+        eprintln!("{count} frames of {size} from frame {first} do not fit in memory");
+    }
+    span
+}
+
+/// Encapsulated Pixel Data and the fragments of each frame in `frames`
+fn encapsulated_frames<'a>(
+    ds: &'a Dataset,
+    desc: &PixelDescription,
+    frames: Range<usize>,
+) -> Option<(&'a EncapsulatedPixelData, Vec<Range<usize>>)> {
+    let encapsulated = match ds.encapsulated_pixel_data() {
+        Some(e) => e,
+        None => {
+            eprintln!("Dataset has no encapsulated Pixel Data");
+            return None;
+        }
+    };
+    let ranges = encapsulated.frame_fragments(desc.number_of_frames)?;
+    match ranges.get(frames.clone()) {
+        Some(ranges) => Some((encapsulated, ranges.to_vec())),
+        None => {
+            eprintln!("Pixel Data has {} frames, expected frames {frames:?}", ranges.len());
+            None
+        }
+    }
+}
+
+/// Decodes the encapsulated frame made of the fragments in `range`, cut to
+/// frame_bytes()
+fn decode_encapsulated_frame(
+    encapsulated: &EncapsulatedPixelData,
+    ts: &TransferSyntax,
+    desc: &PixelDescription,
+    range: &Range<usize>,
+) -> Option<Vec<u8>> {
+    let frame_bytes = desc.frame_bytes()?;
+    let mut decoded = codecs::decode(ts, desc, &encapsulated.fragments[range.clone()].concat())?;
+    if decoded.len() < frame_bytes {
+        eprintln!("Decoded frame is {} bytes, expected {frame_bytes}", decoded.len());
+        return None;
+    }
+    decoded.truncate(frame_bytes);
+    Some(decoded)
+}
+
+/// Decodes encapsulated frames, the fragments of each given by `ranges`,
+/// into `out`, one frame_bytes() slot per frame in order. With the
+/// `parallel` feature the frames are spread over the rayon thread pool;
+/// each worker holds one compressed and one decoded frame at a time.
+fn decode_frames_into(
+    encapsulated: &EncapsulatedPixelData,
+    ts: &TransferSyntax,
+    desc: &PixelDescription,
+    ranges: &[Range<usize>],
+    out: &mut [u8],
+) -> Option<()> {
+    let frame_bytes = desc.frame_bytes()?;
+    let decode_into = |(slot, range): (&mut [u8], &Range<usize>)| {
+        slot.copy_from_slice(&decode_encapsulated_frame(encapsulated, ts, desc, range)?);
+        Some(())
+    };
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        out.par_chunks_mut(frame_bytes).zip(ranges).try_for_each(decode_into)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        out.chunks_mut(frame_bytes).zip(ranges).try_for_each(decode_into)
+    }
+}
+
+/// Decodes the encapsulated frames in `frames` into `out`, which must hold
+/// exactly `frames.len()` times PixelDescription::frame_bytes(): interleaved
+/// little endian samples, as codecs hand them back, in frame order. With the
+/// `parallel` feature the frames are decoded in parallel, so a large
+/// multi-frame object needs no more memory than the output and one frame
+/// per worker.
+pub fn decode_frame_range_into(ds: &Dataset, frames: Range<usize>, out: &mut [u8]) -> Option<()> {
+    let desc = PixelDescription::from_dataset(ds)?;
+    if frames.start > frames.end || frames.end > desc.number_of_frames {
+        eprintln!("Frames {frames:?} out of range; the image has {} frames", desc.number_of_frames);
+        return None;
+    }
+    let expected = frames_span(0, frames.len(), desc.frame_bytes()?)?.end;
+    if out.len() != expected {
+        eprintln!("Output buffer is {} bytes, expected {expected}", out.len());
+        return None;
+    }
+    match ds.transfer_syntax() {
+        Some(ts) if ts.encapsulated => {
+            let (encapsulated, ranges) = encapsulated_frames(ds, &desc, frames)?;
+            decode_frames_into(encapsulated, &ts, &desc, &ranges, out)
+        }
+        _ => {
+            eprintln!("Pixel Data is not encapsulated; use decode_frame_range");
+            None
+        }
+    }
+}
+
+/// Decodes the frames in `frames`, in parallel for encapsulated Pixel Data
+/// with the `parallel` feature.
+pub fn decode_frame_range(ds: &Dataset, frames: Range<usize>) -> Option<PixelArray> {
+    let desc = PixelDescription::from_dataset(ds)?;
+    if frames.start > frames.end || frames.end > desc.number_of_frames {
+        eprintln!("Frames {frames:?} out of range; the image has {} frames", desc.number_of_frames);
+        return None;
+    }
+    decode_frames(ds, &desc, frames.start, frames.len())
+}
+
+/// Serializes decoded frames as native little endian Pixel Data in the
+/// layout the description gives: color-by-plane when planar, pixel pairs
+/// for YBR_FULL_422. Samples must sit in the low bits, i.e. HighBit is
+/// BitsStored - 1.
+pub fn encode_native(array: &PixelArray, desc: &PixelDescription) -> Option<Vec<u8>> {
+    if desc.high_bit + 1 != desc.bits_stored {
+        eprintln!("HighBit {} is not BitsStored - 1", desc.high_bit);
+        return None;
+    }
+    if (array.rows, array.columns, array.samples) != (desc.rows, desc.columns, desc.samples_per_pixel) {
+        eprintln!(
+            "Frames are {}x{}x{}, the description says {}x{}x{}",
+            array.rows, array.columns, array.samples, desc.rows, desc.columns, desc.samples_per_pixel
+        );
+        return None;
+    }
+    let data = if desc.is_subsampled() {
+        match &array.data {
+            PixelBuffer::U8(v) => PixelBuffer::U8(subsample_422(v, desc.columns, |f| f as u8)),
+            PixelBuffer::U16(v) => PixelBuffer::U16(subsample_422(v, desc.columns, |f| f as u16)),
+            _ => {
+                eprintln!("YBR_FULL_422 needs unsigned 8 or 16-bit samples");
+                return None;
+            }
+        }
+    } else if desc.planar && desc.samples_per_pixel > 1 {
+        map_samples!(&array.data, v => separate_planes(v, desc.samples_per_pixel, desc.rows * desc.columns))
+    } else {
+        array.data.clone()
+    };
+    let bytes = data.to_le_bytes();
+    if Some(bytes.len()) != desc.stored_frame_bytes()?.checked_mul(array.frames) {
+        eprintln!("Samples do not fit BitsAllocated {}", desc.bits_allocated);
+        return None;
+    }
+    Some(bytes)
+}
+
+/// Reads `count` bits from `first_bit` on, least significant bit first as
+/// PS3.5 Section 8.1.1 packs BitsAllocated 1, as 0/1 bytes.
+pub fn unpack_bits(packed: &[u8], first_bit: usize, count: usize) -> Option<Vec<u8>> {
+    let end = first_bit + count;
+    if packed.len() * 8 < end {
+        eprintln!("Pixel Data has {} bits, expected at least {end}", packed.len() * 8);
+        return None;
+    }
+    Some((first_bit..end).map(|i| packed[i / 8] >> (i % 8) & 1).collect())
+}
+
+/// Packs masks, nonzero meaning set, one bit per value and least
+/// significant bit first, padded to an even number of bytes.
+pub fn pack_bits(values: &[u8]) -> Vec<u8> {
+    let mut packed = vec![0u8; values.len().div_ceil(8).next_multiple_of(2)];
+    for (i, &value) in values.iter().enumerate() {
+        if value != 0 {
+            packed[i / 8] |= 1 << (i % 8);
+        }
+    }
+    packed
+}
+
+/// Decodes a frame as a mask: true where the sample is nonzero. Suits
+/// binary segmentations, whatever their BitsAllocated.
+pub fn decode_mask(ds: &Dataset, frame: usize) -> Option<Vec<bool>> {
+    let array = decode_frame(ds, frame)?;
+    Some((0..array.data.len()).map(|i| array.data.get(i) != 0.0).collect())
+}
+
+/// Overwrites one frame of 1-bit native Pixel Data with a mask, leaving the
+/// bits of neighbouring frames that share its first or last byte intact.
+pub fn set_mask_frame(ds: &mut Dataset, frame: usize, mask: &[bool]) -> Option<()> {
+    let desc = PixelDescription::from_dataset(ds)?;
+    if desc.bits_allocated != 1 {
+        eprintln!("Masks can only be written into BitsAllocated 1 Pixel Data, not {}", desc.bits_allocated);
+        return None;
+    }
+    if ds.transfer_syntax().is_some_and(|ts| ts.encapsulated) {
+        eprintln!("Masks can only be written into native Pixel Data");
+        return None;
+    }
+    let pixels = desc.frame_samples()?;
+    if mask.len() != pixels || frame >= desc.number_of_frames {
+        eprintln!("Mask of {} values for frame {frame}; frames have {pixels} of {}", mask.len(), desc.number_of_frames);
+        return None;
+    }
+    let size = (pixels * desc.number_of_frames).div_ceil(8).next_multiple_of(2);
+    let mut packed = ds.pixel_data().unwrap_or_default().to_vec();
+    packed.resize(packed.len().max(size), 0);
+    for (i, &set) in mask.iter().enumerate() {
+        let bit = frame * pixels + i;
+        if set {
+            packed[bit / 8] |= 1 << (bit % 8);
+        } else {
+            packed[bit / 8] &= !(1 << (bit % 8));
+        }
+    }
+    ds.set_pixel_data(packed);
+    Some(())
+}
+
+/// True when the pixels are in Float Pixel Data (7FE0,0008) or Double Float
+/// Pixel Data (7FE0,0009) rather than Pixel Data (7FE0,0010)
+pub fn is_float_pixel_data(ds: &Dataset) -> bool {
+    ds.get("(7FE0,0008)").is_some() || ds.get("(7FE0,0009)").is_some()
+}
+
+/// IEEE 754 samples of little endian Float or Double Float Pixel Data
+fn decode_float(desc: &PixelDescription, bytes: &[u8]) -> Option<PixelBuffer> {
+    Some(match desc.bits_allocated {
+        32 => PixelBuffer::F32(byteswap::f32s(bytes, Endianness::Little)),
+        64 => PixelBuffer::F64(byteswap::f64s(bytes, Endianness::Little)),
+        bits => {
+            eprintln!("Float Pixel Data needs BitsAllocated 32 or 64, got {bits}");
+            return None;
+        }
+    })
+}
+
+/// Extracts the stored bits of each little endian sample (PS3.5 Section
+/// 8.1.1): shifts out the bits below the stored range, masks off those above
+/// High Bit and sign-extends when the representation is signed.
+fn decode_native(desc: &PixelDescription, bytes: &[u8]) -> Option<PixelBuffer> {
+    let shift = (desc.high_bit + 1 - desc.bits_stored) as u32;
+    let stored = desc.bits_stored as u32;
+    let unsigned = |raw: u32| mask(raw >> shift, stored);
+    let signed = |raw: u32| sign_extend(mask(raw >> shift, stored), stored);
+    Some(match (desc.bits_allocated, desc.signed) {
+        (8, false) => PixelBuffer::U8(bytes.iter().map(|&b| unsigned(b as u32) as u8).collect()),
+        (8, true) => PixelBuffer::I8(bytes.iter().map(|&b| signed(b as u32) as i8).collect()),
+        (16, s) => {
+            let raw = byteswap::u16s(bytes, Endianness::Little).into_iter().map(|v| v as u32);
+            if s {
+                PixelBuffer::I16(raw.map(|r| signed(r) as i16).collect())
+            } else {
+                PixelBuffer::U16(raw.map(|r| unsigned(r) as u16).collect())
+            }
+        }
+        (32, s) => {
+            let raw = byteswap::u32s(bytes, Endianness::Little).into_iter();
+            if s {
+                PixelBuffer::I32(raw.map(signed).collect())
+            } else {
+                PixelBuffer::U32(raw.map(unsigned).collect())
+            }
+        }
+        (bits, _) => {
+            eprintln!("BitsAllocated {bits} is not supported");
+            return None;
+        }
+    })
+}
+
+fn mask(value: u32, bits: u32) -> u32 {
+    if bits >= 32 { value } else { value & ((1 << bits) - 1) }
+}
+
+fn sign_extend(value: u32, bits: u32) -> i32 {
+    if bits >= 32 {
+        value as i32
+    } else {
+        ((value << (32 - bits)) as i32) >> (32 - bits)
+    }
+}
+
+/// Reorders color-by-plane frames (RRR..GGG..BBB..) into interleaved samples.
+fn interleave_planes<T: Copy>(v: Vec<T>, samples: usize, pixels: usize) -> Vec<T> {
+    let frame = samples * pixels;
+    let mut out = Vec::with_capacity(v.len());
+    for planes in v.chunks_exact(frame) {
+        for p in 0..pixels {
+            for s in 0..samples {
+                out.push(planes[s * pixels + p]);
+            }
+        }
+    }
+    out
+}
+
+/// Splits interleaved frames into color-by-plane order.
+fn separate_planes<T: Copy>(v: &[T], samples: usize, pixels: usize) -> Vec<T> {
+    let mut out = Vec::with_capacity(v.len());
+    for frame in v.chunks_exact(samples * pixels) {
+        for s in 0..samples {
+            out.extend(frame.iter().skip(s).step_by(samples));
+        }
+    }
+    out
+}
+
+/// Expands native YBR_FULL_422 frames (Y1 Y2 Cb Cr per pixel pair) to
+/// three samples per pixel, both pixels of a pair sharing the chroma.
+fn upsample_422<T: Copy>(v: Vec<T>, columns: usize) -> Vec<T> {
+    let mut out = Vec::with_capacity(v.len() / 2 * 3);
+    for row in v.chunks_exact(columns.div_ceil(2) * 4) {
+        for (pair, s) in row.chunks_exact(4).enumerate() {
+            out.extend([s[0], s[2], s[3]]);
+            if 2 * pair + 1 < columns {
+                out.extend([s[1], s[2], s[3]]);
+            }
+        }
+    }
+    out
+}
+
+/// Packs full size YBR frames into the native YBR_FULL_422 layout,
+/// averaging the chroma of each pixel pair.
+fn subsample_422<T: Copy + Into<f64>>(v: &[T], columns: usize, from_f64: impl Fn(f64) -> T) -> Vec<T> {
+    let mut out = Vec::with_capacity(v.len() / 3 * 2 + 4);
+    for row in v.chunks_exact(columns * 3) {
+        for pair in row.chunks(6) {
+            let second = if pair.len() == 6 { &pair[3..] } else { &pair[..3] };
+            let mean = |a: T, b: T| from_f64(((a.into() + b.into()) / 2.0).round());
+            out.extend([pair[0], second[0], mean(pair[1], second[1]), mean(pair[2], second[2])]);
+        }
+    }
+    out
+}
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::dataset::Dataset;
+use crate::modules::io::{read_file_header, FileHeader};
+
+/// One SOP Instance and the file it was read from
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instance {
+    pub sop_instance_uid: String,
+    pub sop_class_uid: Option<String>,
+    pub instance_number: Option<i64>,
+    pub transfer_syntax_uid: Option<String>,
+    pub path: PathBuf,
+}
+
+/// A series and its instances, ordered by Instance Number
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub series_instance_uid: String,
+    pub modality: Option<String>,
+    pub series_number: Option<i64>,
+    pub series_description: Option<String>,
+    pub instances: Vec<Instance>,
+}
+
+/// A study and its series, ordered by Series Number
+#[derive(Debug, Clone, PartialEq)]
+pub struct Study {
+    pub study_instance_uid: String,
+    pub study_date: Option<String>,
+    pub study_time: Option<String>,
+    pub study_description: Option<String>,
+    pub accession_number: Option<String>,
+    pub series: Vec<Series>,
+}
+
+/// A patient and their studies, ordered by Study Date. Instances without a
+/// Patient ID share the patient with an empty ID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Patient {
+    pub patient_id: String,
+    pub patient_name: Option<String>,
+    pub patient_birth_date: Option<String>,
+    pub patient_sex: Option<String>,
+    pub studies: Vec<Study>,
+}
+
+/// A file whose SOP Instance UID was already taken by another file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Duplicate {
+    pub sop_instance_uid: String,
+    /// File kept in the tree; the first in path order
+    pub kept: PathBuf,
+    pub duplicate: PathBuf,
+}
+
+/// Files of a directory sorted into Patient, Study, Series and Instance
+/// levels, with the files that could not be placed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DicomTree {
+    pub patients: Vec<Patient>,
+    pub duplicates: Vec<Duplicate>,
+    /// Files without "DICM" after the preamble
+    pub non_dicom: Vec<PathBuf>,
+    /// Files that could not be read, Part 10 files whose File Meta
+    /// Information cannot be used, and files lacking the UIDs that place them
+    pub errors: Vec<(PathBuf, String)>,
+}
+
+impl Series {
+    pub fn number_of_instances(&self) -> usize {
+        self.instances.len()
+    }
+}
+
+impl Study {
+    pub fn number_of_series(&self) -> usize {
+        self.series.len()
+    }
+
+    pub fn number_of_instances(&self) -> usize {
+        self.series.iter().map(Series::number_of_instances).sum()
+    }
+
+    /// Distinct modalities of the study's series, like Modalities in Study
+    pub fn modalities(&self) -> Vec<&str> {
+        let mut modalities: Vec<&str> = self.series.iter().filter_map(|s| s.modality.as_deref()).collect();
+        modalities.sort_unstable();
+        modalities.dedup();
+        modalities
+    }
+}
+
+impl Patient {
+    pub fn number_of_studies(&self) -> usize {
+        self.studies.len()
+    }
+
+    pub fn number_of_instances(&self) -> usize {
+        self.studies.iter().map(Study::number_of_instances).sum()
+    }
+}
+
+impl DicomTree {
+    pub fn patient(&self, patient_id: &str) -> Option<&Patient> {
+        self.patients.iter().find(|p| p.patient_id == patient_id)
+    }
+
+    pub fn studies(&self) -> impl Iterator<Item = &Study> {
+        self.patients.iter().flat_map(|p| &p.studies)
+    }
+
+    pub fn series(&self) -> impl Iterator<Item = &Series> {
+        self.studies().flat_map(|s| &s.series)
+    }
+
+    pub fn instances(&self) -> impl Iterator<Item = &Instance> {
+        self.series().flat_map(|s| &s.instances)
+    }
+
+    pub fn study(&self, study_instance_uid: &str) -> Option<&Study> {
+        self.studies().find(|s| s.study_instance_uid == study_instance_uid)
+    }
+
+    pub fn find_series(&self, series_instance_uid: &str) -> Option<&Series> {
+        self.series().find(|s| s.series_instance_uid == series_instance_uid)
+    }
+
+    pub fn instance(&self, sop_instance_uid: &str) -> Option<&Instance> {
+        self.instances().find(|i| i.sop_instance_uid == sop_instance_uid)
+    }
+
+    pub fn number_of_instances(&self) -> usize {
+        self.patients.iter().map(Patient::number_of_instances).sum()
+    }
+}
+
+/// What reading one file's header gave
+enum Scanned {
+    Placed(Box<Record>),
+    NotDicom,
+    Failed(String),
+}
+
+/// Header attributes of one file, level by level
+struct Record {
+    patient: Patient,
+    study: Study,
+    series: Series,
+    instance: Instance,
+}
+
+/// A patient's studies keyed by Study Instance UID while files are grouped
+struct PatientGroup {
+    patient: Patient,
+    studies: HashMap<String, StudyGroup>,
+}
+
+/// A study's series keyed by Series Instance UID while files are grouped
+struct StudyGroup {
+    study: Study,
+    series: HashMap<String, Series>,
+}
+
+fn text(ds: &Dataset, keyword: &str) -> Option<String> {
+    ds.get_str(keyword).map(str::trim).filter(|s| !s.is_empty()).map(str::to_string)
+}
+
+fn scan_file(path: &Path) -> Scanned {
+    let ds = match read_file_header(path) {
+        Some(FileHeader::Part10(ds)) => ds,
+        Some(FileHeader::NotDicom) => return Scanned::NotDicom,
+        Some(FileHeader::BadMeta(reason)) => return Scanned::Failed(reason),
+        None => return Scanned::Failed("cannot be read".to_string()),
+    };
+    let uid = |keyword: &str| text(&ds, keyword).ok_or_else(|| format!("no {keyword}"));
+    let uids = uid("StudyInstanceUID").and_then(|study| Ok((study, uid("SeriesInstanceUID")?, uid("SOPInstanceUID")?)));
+    let (study_uid, series_uid, sop_uid) = match uids {
+        Ok(uids) => uids,
+        Err(reason) => return Scanned::Failed(reason),
+    };
+    Scanned::Placed(Box::new(Record {
+        patient: Patient {
+            patient_id: text(&ds, "PatientID").unwrap_or_default(),
+            patient_name: text(&ds, "PatientName"),
+            patient_birth_date: text(&ds, "PatientBirthDate"),
+            patient_sex: text(&ds, "PatientSex"),
+            studies: Vec::new(),
+        },
+        study: Study {
+            study_instance_uid: study_uid,
+            study_date: text(&ds, "StudyDate"),
+            study_time: text(&ds, "StudyTime"),
+            study_description: text(&ds, "StudyDescription"),
+            accession_number: text(&ds, "AccessionNumber"),
+            series: Vec::new(),
+        },
+        series: Series {
+            series_instance_uid: series_uid,
+            modality: text(&ds, "Modality"),
+            series_number: ds.get_int("SeriesNumber"),
+            series_description: text(&ds, "SeriesDescription"),
+            instances: Vec::new(),
+        },
+        instance: Instance {
+            sop_instance_uid: sop_uid,
+            sop_class_uid: text(&ds, "SOPClassUID"),
+            instance_number: ds.get_int("InstanceNumber"),
+            transfer_syntax_uid: ds.transfer_syntax().map(|ts| ts.entry.uid.to_string()),
+            path: path.to_path_buf(),
+        },
+    }))
+}
+
+/// Every file under the directory, in path order; unreadable directories are
+/// reported as errors.
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>, errors: &mut Vec<(PathBuf, String)>) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            errors.push((dir.to_path_buf(), format!("cannot be listed: {e}")));
+            return;
+        }
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match entry.file_type() {
+            Ok(kind) if kind.is_dir() => collect_files(&path, files, errors),
+            Ok(kind) if kind.is_file() => files.push(path),
+            _ => {}
+        }
+    }
+}
+
+/// Reads the headers of every file under the directory, in parallel with
+/// the `parallel` feature, and sorts them into a tree.
+pub fn scan_directory<P: AsRef<Path>>(dir: P) -> DicomTree {
+    let mut files = Vec::new();
+    let mut errors = Vec::new();
+    collect_files(dir.as_ref(), &mut files, &mut errors);
+    let mut tree = scan_files(&files);
+    errors.append(&mut tree.errors);
+    tree.errors = errors;
+    tree
+}
+
+/// Reads the headers of the given files and sorts them into a tree. The
+/// result does not depend on the order of the files or on the threads.
+pub fn scan_files(files: &[PathBuf]) -> DicomTree {
+    let mut files = files.to_vec();
+    files.sort();
+    files.dedup();
+    #[cfg(feature = "parallel")]
+    let scanned: Vec<Scanned> = {
+        use rayon::prelude::*;
+        files.par_iter().map(|path| scan_file(path)).collect()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let scanned: Vec<Scanned> = files.iter().map(|path| scan_file(path)).collect();
+
+    let mut tree = DicomTree::default();
+    let mut groups: HashMap<String, PatientGroup> = HashMap::new();
+    let mut seen: HashMap<String, PathBuf> = HashMap::new();
+    for (path, result) in files.into_iter().zip(scanned) {
+        match result {
+            Scanned::NotDicom => tree.non_dicom.push(path),
+            Scanned::Failed(reason) => tree.errors.push((path, reason)),
+            Scanned::Placed(record) => {
+                if let Some(kept) = seen.get(&record.instance.sop_instance_uid) {
+                    tree.duplicates.push(Duplicate {
+                        sop_instance_uid: record.instance.sop_instance_uid.clone(),
+                        kept: kept.clone(),
+                        duplicate: path,
+                    });
+                    continue;
+                }
+                seen.insert(record.instance.sop_instance_uid.clone(), path);
+                insert(&mut groups, *record);
+            }
+        }
+    }
+    tree.patients = sort(groups);
+    tree
+}
+
+/// Places the record's instance, adding its patient, study and series when
+/// they are new. Summary attributes come from the first file of each level.
+fn insert(patients: &mut HashMap<String, PatientGroup>, record: Record) {
+    let Record { patient, study, series, instance } = record;
+    let patient = patients
+        .entry(patient.patient_id.clone())
+        .or_insert_with(|| PatientGroup { patient, studies: HashMap::new() });
+    let study = patient
+        .studies
+        .entry(study.study_instance_uid.clone())
+        .or_insert_with(|| StudyGroup { study, series: HashMap::new() });
+    study.series.entry(series.series_instance_uid.clone()).or_insert(series).instances.push(instance);
+}
+
+/// Turns the groups into the tree's levels and orders every level; numbers
+/// and dates first, UIDs to break ties
+fn sort(groups: HashMap<String, PatientGroup>) -> Vec<Patient> {
+    let mut patients: Vec<Patient> = groups
+        .into_values()
+        .map(|group| Patient {
+            studies: group
+                .studies
+                .into_values()
+                .map(|group| Study { series: group.series.into_values().collect(), ..group.study })
+                .collect(),
+            ..group.patient
+        })
+        .collect();
+    patients.sort_by(|a, b| a.patient_id.cmp(&b.patient_id));
+    for patient in &mut patients {
+        patient.studies.sort_by(|a, b| {
+            (&a.study_date, &a.study_time, &a.study_instance_uid).cmp(&(&b.study_date, &b.study_time, &b.study_instance_uid))
+        });
+        for study in &mut patient.studies {
+            study.series.sort_by(|a, b| (a.series_number, &a.series_instance_uid).cmp(&(b.series_number, &b.series_instance_uid)));
+            for series in &mut study.series {
+                series.instances.sort_by(|a, b| {
+                    (a.instance_number, &a.sop_instance_uid).cmp(&(b.instance_number, &b.sop_instance_uid))
+                });
+            }
+        }
+    }
+    patients
+}
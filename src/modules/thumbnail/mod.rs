@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+use crate::dataelem::{DataElement, DataElementValue};
+use crate::dataset::Dataset;
+use crate::modules::color::{render_rgb, Palette};
+use crate::modules::lut::{DisplayDepth, GrayscalePipeline, Lut};
+use crate::modules::pixel::{decode_frame_at_resolution, PixelArray, PixelBuffer, PixelDescription};
+
+/// Largest Rows and Columns of an icon image (PS3.3 Section F.7)
+pub const ICON_MAX_SIZE: usize = 128;
+/// Icon size the standard recommends
+pub const ICON_RECOMMENDED_SIZE: usize = 64;
+
+/// Size that fits within `max_size` on the longer side with the same aspect
+/// ratio. Images already small enough keep their size.
+pub fn thumbnail_size(rows: usize, columns: usize, max_size: usize) -> (usize, usize) {
+    let longest = rows.max(columns);
+    if longest <= max_size {
+        return (rows, columns);
+    }
+    let fit = |n: usize| ((n * max_size) as f64 / longest as f64).round().max(1.0) as usize;
+    (fit(rows), fit(columns))
+}
+
+/// Lanczos kernel with three lobes
+fn lanczos3(x: f64) -> f64 {
+    if x == 0.0 {
+        1.0
+    } else if x.abs() < 3.0 {
+        let px = PI * x;
+        3.0 * px.sin() * (px / 3.0).sin() / (px * px)
+    } else {
+        0.0
+    }
+}
+
+/// For each output position, the first input position it reads and the
+/// normalized weights from there on. When shrinking, the kernel is
+/// stretched by the scale factor so every input sample contributes.
+fn filter_weights(input: usize, output: usize) -> Vec<(usize, Vec<f64>)> {
+    let scale = input as f64 / output as f64;
+    let stretch = scale.max(1.0);
+    let support = 3.0 * stretch;
+    (0..output)
+        .map(|i| {
+            let center = (i as f64 + 0.5) * scale;
+            let first = (center - support).floor().max(0.0) as usize;
+            let last = ((center + support).ceil() as usize).min(input);
+            let mut weights: Vec<f64> = (first..last).map(|j| lanczos3((j as f64 + 0.5 - center) / stretch)).collect();
+            let sum: f64 = weights.iter().sum();
+            weights.iter_mut().for_each(|w| *w /= sum);
+            (first, weights)
+        })
+        .collect()
+}
+
+/// Resamples the first frame of an 8-bit array to the given size with a
+/// separable Lanczos-3 filter.
+pub fn resize(array: &PixelArray, rows: usize, columns: usize) -> Option<PixelArray> {
+    let PixelBuffer::U8(data) = &array.data else {
+        eprintln!("Resizing needs 8-bit samples");
+        return None;
+    };
+    if rows == 0 || columns == 0 || array.frame_len() == 0 {
+        eprintln!("Cannot resize a {}x{} image to {rows}x{columns}", array.rows, array.columns);
+        return None;
+    }
+    let samples = array.samples;
+    // Columns first, keeping full precision for the second pass
+    let horizontal = filter_weights(array.columns, columns);
+    let mut wide = vec![0.0; array.rows * columns * samples];
+    for y in 0..array.rows {
+        let row = &data[y * array.columns * samples..];
+        for (x, (first, weights)) in horizontal.iter().enumerate() {
+            for c in 0..samples {
+                let value: f64 = weights.iter().enumerate().map(|(k, w)| w * row[(first + k) * samples + c] as f64).sum();
+                wide[(y * columns + x) * samples + c] = value;
+            }
+        }
+    }
+    let vertical = filter_weights(array.rows, rows);
+    let mut out = vec![0u8; rows * columns * samples];
+    for (y, (first, weights)) in vertical.iter().enumerate() {
+        for i in 0..columns * samples {
+            let value: f64 = weights.iter().enumerate().map(|(k, w)| w * wide[(first + k) * columns * samples + i]).sum();
+            out[y * columns * samples + i] = value.round().clamp(0.0, 255.0) as u8;
+        }
+    }
+    Some(PixelArray { frames: 1, rows, columns, samples, data: PixelBuffer::U8(out) })
+}
+
+/// 8-bit thumbnail of one frame no larger than `max_size` on either side:
+/// grayscale through the Modality, VOI and Presentation LUTs, color as RGB.
+/// JPEG 2000 frames are decoded at the lowest resolution level that is
+/// still large enough.
+pub fn thumbnail(ds: &Dataset, frame: usize, max_size: usize) -> Option<PixelArray> {
+    let desc = PixelDescription::from_dataset(ds)?;
+    let longest = desc.rows.max(desc.columns);
+    let mut level = 0u8;
+    while level < 32 && longest >> (level + 1) >= max_size {
+        level += 1;
+    }
+    let decoded = decode_frame_at_resolution(ds, frame, level)?;
+    let display = if desc.samples_per_pixel == 1 && desc.photometric_interpretation != "PALETTE COLOR" {
+        GrayscalePipeline::from_dataset(ds).render(&decoded, DisplayDepth::Bits8)?
+    } else {
+        render_rgb(ds, &decoded, DisplayDepth::Bits8)?
+    };
+    let (rows, columns) = thumbnail_size(display.rows, display.columns, max_size);
+    resize(&display, rows, columns)
+}
+
+/// Icon Image Sequence item for one frame (PS3.3 Section F.7): 8-bit
+/// MONOCHROME2 for grayscale images, or PALETTE COLOR with a palette of
+/// the icon's most common colors. `max_size` is capped at 128.
+pub fn icon_image(ds: &Dataset, frame: usize, max_size: usize) -> Option<Dataset> {
+    let icon = thumbnail(ds, frame, max_size.min(ICON_MAX_SIZE))?;
+    let mut item = Dataset::new();
+    let attributes = [
+        ("SamplesPerPixel", 1),
+        ("Rows", icon.rows as u16),
+        ("Columns", icon.columns as u16),
+        ("BitsAllocated", 8),
+        ("BitsStored", 8),
+        ("HighBit", 7),
+        ("PixelRepresentation", 0),
+    ];
+    for (keyword, value) in attributes {
+        item.push(DataElement::new(keyword, DataElementValue::UInt16(value))?);
+    }
+    let PixelBuffer::U8(data) = icon.data else {
+        return None;
+    };
+    if icon.samples == 1 {
+        item.push(DataElement::new("PhotometricInterpretation", DataElementValue::String("MONOCHROME2".to_string()))?);
+        item.set_pixel_data(data);
+        return Some(item);
+    }
+
+    let palette = icon_palette(&data);
+    let indices = data
+        .chunks_exact(3)
+        .map(|rgb| palette.nearest([rgb[0] as u16, rgb[1] as u16, rgb[2] as u16], 8) as u8)
+        .collect();
+    item.push(DataElement::new("PhotometricInterpretation", DataElementValue::String("PALETTE COLOR".to_string()))?);
+    // Table values are held in little endian, the writer swaps them if needed
+    let words = |values: &[u16]| DataElementValue::Data(values.iter().flat_map(|v| v.to_le_bytes()).collect());
+    for (color, lut) in [("Red", &palette.red), ("Green", &palette.green), ("Blue", &palette.blue)] {
+        let descriptor = words(&[lut.data.len() as u16, 0, 16]);
+        item.push(DataElement::new(&format!("{color}PaletteColorLookupTableDescriptor"), descriptor)?);
+        item.push(DataElement::new(&format!("{color}PaletteColorLookupTableData"), words(&lut.data))?);
+    }
+    item.set_pixel_data(indices);
+    Some(item)
+}
+
+/// Up to 256 16-bit palette entries: the mean colors of the most populated
+/// cells after cutting RGB to 5 bits per sample.
+fn icon_palette(rgb: &[u8]) -> Palette {
+    let mut cells: HashMap<u16, (usize, [usize; 3])> = HashMap::new();
+    for pixel in rgb.chunks_exact(3) {
+        let key = (pixel[0] as u16 >> 3) << 10 | (pixel[1] as u16 >> 3) << 5 | pixel[2] as u16 >> 3;
+        let (count, sums) = cells.entry(key).or_default();
+        *count += 1;
+        for c in 0..3 {
+            sums[c] += pixel[c] as usize;
+        }
+    }
+    let mut cells: Vec<(u16, (usize, [usize; 3]))> = cells.into_iter().collect();
+    // Most populated first; the key breaks ties so the palette is stable
+    cells.sort_unstable_by(|a, b| b.1.0.cmp(&a.1.0).then(a.0.cmp(&b.0)));
+    cells.truncate(256);
+    let channel = |c: usize| Lut {
+        first_mapped: 0,
+        bits: 16,
+        data: cells.iter().map(|(_, (count, sums))| ((sums[c] + count / 2) / count * 257) as u16).collect(),
+    };
+    Palette { red: channel(0), green: channel(1), blue: channel(2) }
+}
+
+/// Puts an icon of the first frame in the Icon Image Sequence (0088,0200),
+/// replacing any icon already there.
+pub fn set_icon_image(ds: &mut Dataset, max_size: usize) -> Option<()> {
+    let item = icon_image(ds, 0, max_size)?;
+    ds.set(DataElement::new("IconImageSequence", DataElementValue::Sequence(vec![item]))?);
+    Some(())
+}
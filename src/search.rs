@@ -0,0 +1,116 @@
+use crate::dataelem::{DicomAttribute, DicomVr};
+use crate::dicts::ATTRIBUTES;
+
+/// Restricts a dictionary search. The default matches every non-retired
+/// attribute.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AttributeFilter {
+    pub vr: Option<DicomVr>,
+    /// Group number; repeating groups such as 60XX match any of their groups
+    pub group: Option<u16>,
+    pub include_retired: bool,
+}
+
+impl AttributeFilter {
+    pub fn matches(&self, attr: &DicomAttribute) -> bool {
+        if attr.retired && !self.include_retired {
+            return false;
+        }
+        if let Some(vr) = self.vr
+            && attr.vr != Some(vr)
+        {
+            return false;
+        }
+        match self.group {
+            Some(group) => group_matches(attr.tag, group),
+            None => true,
+        }
+    }
+}
+
+/// Compares the "(gggg," part of a dictionary tag digit by digit, X matching
+/// anything.
+fn group_matches(tag: &str, group: u16) -> bool {
+    let digits = tag.trim_start_matches('(').chars().take(4);
+    digits.enumerate().all(|(i, c)| {
+        let nibble = (group >> (12 - 4 * i)) & 0xF;
+        c == 'X' || c.to_digit(16) == Some(nibble as u32)
+    })
+}
+
+/// Attributes whose name or keyword contains the query, ignoring case, in
+/// tag order.
+pub fn find_by_substring(query: &str, filter: &AttributeFilter) -> Vec<&'static DicomAttribute> {
+    let query = query.to_lowercase();
+    ATTRIBUTES
+        .iter()
+        .filter(|a| filter.matches(a))
+        .filter(|a| a.name.to_lowercase().contains(&query) || a.keyword.to_lowercase().contains(&query))
+        .collect()
+}
+
+/// Attributes whose name or keyword starts with the query, ignoring case, in
+/// tag order.
+pub fn find_by_prefix(query: &str, filter: &AttributeFilter) -> Vec<&'static DicomAttribute> {
+    let query = query.to_lowercase();
+    ATTRIBUTES
+        .iter()
+        .filter(|a| filter.matches(a))
+        .filter(|a| a.name.to_lowercase().starts_with(&query) || a.keyword.to_lowercase().starts_with(&query))
+        .collect()
+}
+
+/// Attributes within max_distance edits of the query, comparing against both
+/// keyword and name without case or spaces. Results are ordered by distance,
+/// then tag.
+pub fn find_fuzzy(query: &str, max_distance: usize, filter: &AttributeFilter) -> Vec<(&'static DicomAttribute, usize)> {
+    let query = normalize(query);
+    let mut hits: Vec<(&'static DicomAttribute, usize)> = ATTRIBUTES
+        .iter()
+        .filter(|a| filter.matches(a))
+        .filter_map(|a| {
+            let distance = edit_distance(&query, &normalize(a.keyword)).min(edit_distance(&query, &normalize(a.name)));
+            (distance <= max_distance).then_some((a, distance))
+        })
+        .collect();
+    hits.sort_by_key(|(_, distance)| *distance);
+    hits
+}
+
+/// Keyword of the closest current attribute to a misspelled keyword, or None
+/// when nothing is reasonably close. Allows one edit per three characters.
+pub fn suggest_keyword(input: &str) -> Option<&'static str> {
+    let max_distance = (input.chars().count() / 3).max(1);
+    find_fuzzy(input, max_distance, &AttributeFilter::default())
+        .first()
+        .map(|(attr, _)| attr.keyword)
+        .filter(|keyword| !keyword.is_empty())
+}
+
+fn normalize(s: &str) -> Vec<char> {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions
+/// and transpositions of adjacent characters each cost one.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut prev2: Vec<usize> = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur: Vec<usize> = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        cur[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            cur[j] = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cur[j] = cur[j].min(prev2[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut prev2, &mut prev);
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
@@ -0,0 +1,175 @@
+/// DICOM unique identifier registry generated from PS3.6 Tables A-1 and A-2
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UidType {
+    TransferSyntax,
+    SopClass,
+    MetaSopClass,
+    WellKnownSopInstance,
+    WellKnownFrameOfReference,
+    SynchronizationFrameOfReference,
+    ServiceClass,
+    CodingScheme,
+    ApplicationContextName,
+    ApplicationHostingModel,
+    MappingResource,
+    LdapOid,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DicomUid {
+    pub uid: &'static str,
+    pub name: &'static str,
+    pub keyword: &'static str,
+    pub uid_type: UidType,
+    pub retired: bool,
+}
+
+include!(concat!(env!("OUT_DIR"), "/uids.rs"));
+
+pub fn uid_by_value(uid: &str) -> Option<&'static DicomUid> {
+    let uid = uid.trim_end_matches(['\0', ' ']);
+    UIDS.binary_search_by(|entry| entry.uid.cmp(uid))
+        .ok()
+        .map(|idx| &UIDS[idx])
+}
+
+pub fn uid_by_keyword(keyword: &str) -> Option<&'static DicomUid> {
+    UID_KEYWORD_INDEX
+        .binary_search_by(|entry| entry.0.cmp(keyword))
+        .ok()
+        .map(|idx| &UIDS[UID_KEYWORD_INDEX[idx].1])
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VrMode {
+    Explicit,
+    Implicit,
+}
+
+/// Encoding properties of a transfer syntax (PS3.5 Section 10 and Annex A)
+#[derive(Clone, Copy, Debug)]
+pub struct TransferSyntax {
+    pub entry: &'static DicomUid,
+    pub endian: Endianness,
+    pub vr_mode: VrMode,
+    /// Pixel Data is encapsulated in fragments rather than native
+    pub encapsulated: bool,
+    /// Pixel Data may have been compressed lossily
+    pub lossy: bool,
+    /// The data set following the File Meta Information is deflated
+    pub deflated: bool,
+}
+
+pub const IMPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2";
+pub const EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1";
+pub const DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1.99";
+pub const EXPLICIT_VR_BIG_ENDIAN: &str = "1.2.840.10008.1.2.2";
+
+/// Looks up a transfer syntax; returns None for UIDs that are not
+/// transfer syntaxes known to the registry.
+pub fn transfer_syntax(uid: &str) -> Option<TransferSyntax> {
+    let entry = uid_by_value(uid)?;
+    if entry.uid_type != UidType::TransferSyntax {
+        return None;
+    }
+    let uid = entry.uid;
+    let endian = if uid == EXPLICIT_VR_BIG_ENDIAN { Endianness::Big } else { Endianness::Little };
+    // Papyrus 3 (retired) is the only other implicit VR syntax
+    let vr_mode = if uid == IMPLICIT_VR_LITTLE_ENDIAN || uid == "1.2.840.10008.1.20" {
+        VrMode::Implicit
+    } else {
+        VrMode::Explicit
+    };
+    let deflated = matches!(
+        uid,
+        DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN | "1.2.840.10008.1.2.4.95" | "1.2.840.10008.1.2.4.205"
+    );
+    // Native encodings, plus the syntaxes that carry no Pixel Data at all
+    // (JPIP references, SMPTE ST 2110 streams and the retired MIME/XML ones)
+    let encapsulated = !(matches!(
+        uid,
+        IMPLICIT_VR_LITTLE_ENDIAN
+            | EXPLICIT_VR_LITTLE_ENDIAN
+            | DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN
+            | EXPLICIT_VR_BIG_ENDIAN
+            | "1.2.840.10008.1.20"
+            | "1.2.840.10008.1.2.4.94"
+            | "1.2.840.10008.1.2.4.95"
+            | "1.2.840.10008.1.2.4.204"
+            | "1.2.840.10008.1.2.4.205"
+    ) || uid.starts_with("1.2.840.10008.1.2.6.")
+        || uid.starts_with("1.2.840.10008.1.2.7."));
+    let lossy = matches!(
+        uid,
+        "1.2.840.10008.1.2.4.50"
+            | "1.2.840.10008.1.2.4.51"
+            | "1.2.840.10008.1.2.4.52"
+            | "1.2.840.10008.1.2.4.53"
+            | "1.2.840.10008.1.2.4.54"
+            | "1.2.840.10008.1.2.4.55"
+            | "1.2.840.10008.1.2.4.56"
+            | "1.2.840.10008.1.2.4.59"
+            | "1.2.840.10008.1.2.4.60"
+            | "1.2.840.10008.1.2.4.61"
+            | "1.2.840.10008.1.2.4.62"
+            | "1.2.840.10008.1.2.4.63"
+            | "1.2.840.10008.1.2.4.64"
+            | "1.2.840.10008.1.2.4.81"
+            | "1.2.840.10008.1.2.4.91"
+            | "1.2.840.10008.1.2.4.93"
+            | "1.2.840.10008.1.2.4.111"
+            | "1.2.840.10008.1.2.4.112"
+            | "1.2.840.10008.1.2.4.203"
+    ) || is_video(uid);
+    Some(TransferSyntax {
+        entry,
+        endian,
+        vr_mode,
+        encapsulated,
+        lossy,
+        deflated,
+    })
+}
+
+/// MPEG-2, MPEG-4 AVC/H.264 and HEVC/H.265 syntaxes (1.2.840.10008.1.2.4.100
+/// to .108, including the fragmentable variants)
+fn is_video(uid: &str) -> bool {
+    uid.strip_prefix("1.2.840.10008.1.2.4.")
+        .and_then(|rest| rest.split('.').next())
+        .and_then(|n| n.parse::<u32>().ok())
+        .is_some_and(|n| (100..=108).contains(&n))
+}
+
+/// Implementation Class UID written to the File Meta Information of files
+/// this library creates
+pub const IMPLEMENTATION_CLASS_UID: &str = "2.25.189394474637035941228428681891327528597";
+pub const IMPLEMENTATION_VERSION_NAME: &str = concat!("DICOM_RS_", env!("CARGO_PKG_VERSION"));
+
+/// New UID under the 2.25 root (PS3.5 Section B.2): a random version 4
+/// UUID written as one decimal integer.
+pub fn generate_uid() -> String {
+    use std::collections::hash_map::RandomState;
+    use std::hash::BuildHasher;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    // RandomState keys come from the operating system's random source; the
+    // clock and a counter keep UIDs made in quick succession apart
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos());
+    let seed = (nanos, COUNTER.fetch_add(1, Ordering::Relaxed), std::process::id());
+    let high = RandomState::new().hash_one(seed) as u128;
+    let low = RandomState::new().hash_one(seed) as u128;
+    let mut uuid = high << 64 | low;
+    // Version 4 and the RFC 4122 variant
+    uuid = uuid & !(0xF << 76) | 0x4 << 76;
+    uuid = uuid & !(0x3 << 62) | 0x2 << 62;
+    format!("2.25.{uuid}")
+}
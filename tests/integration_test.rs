@@ -10,5 +10,1750 @@ fn it_works() {
 fn test_read_dicom() {
     let path = "test_data/Anonymized_20250717.dcm";
     let ds = read_dicom(path);
-    assert!(ds.elements().len() > 0);
+    assert!(!ds.elements().is_empty());
+}
+
+fn explicit_le_element(group: u16, element: u16, vr: &[u8; 2], value: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&group.to_le_bytes());
+    out.extend_from_slice(&element.to_le_bytes());
+    out.extend_from_slice(vr);
+    if matches!(vr, b"OB" | b"OW" | b"OF" | b"OD") {
+        out.extend_from_slice(&[0, 0]);
+        out.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    } else {
+        out.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    }
+    out.extend_from_slice(value);
+    out
+}
+
+fn part10_with_transfer_syntax(ts_uid: &str) -> Vec<u8> {
+    let mut uid = ts_uid.as_bytes().to_vec();
+    if uid.len() % 2 == 1 {
+        uid.push(0);
+    }
+    let mut buf = vec![0u8; 128];
+    buf.extend_from_slice(b"DICM");
+    buf.extend(explicit_le_element(0x0002, 0x0010, b"UI", &uid));
+    buf.extend(explicit_le_element(0x0008, 0x0060, b"CS", b"CT"));
+    buf
+}
+
+#[test]
+fn test_uid_registry() {
+    use dicom_rs::uids::*;
+    let ct = uid_by_value("1.2.840.10008.5.1.4.1.1.2").unwrap();
+    assert_eq!(ct.name, "CT Image Storage");
+    assert_eq!(ct.uid_type, UidType::SopClass);
+    assert_eq!(uid_by_keyword("CTImageStorage").unwrap().uid, ct.uid);
+
+    let baseline = transfer_syntax("1.2.840.10008.1.2.4.50").unwrap();
+    assert!(baseline.encapsulated && baseline.lossy && !baseline.deflated);
+    let big = transfer_syntax(EXPLICIT_VR_BIG_ENDIAN).unwrap();
+    assert!(big.entry.retired);
+    assert_eq!(big.endian, Endianness::Big);
+    assert_eq!(transfer_syntax(IMPLICIT_VR_LITTLE_ENDIAN).unwrap().vr_mode, VrMode::Implicit);
+    assert!(transfer_syntax("1.2.840.10008.1.2.1.99").unwrap().deflated);
+    // SOP classes and unknown UIDs are not transfer syntaxes
+    assert!(transfer_syntax(ct.uid).is_none());
+    assert!(transfer_syntax("1.2.3.4").is_none());
+}
+
+#[test]
+fn test_reader_rejects_unknown_transfer_syntax() {
+    use dicom_rs::modules::io::read_dicom_bytes;
+    let ds = read_dicom_bytes(&part10_with_transfer_syntax("1.2.840.10008.1.2.1"));
+    assert_eq!(ds.get("Modality").unwrap().to_string(), "(0008,0060) Modality = CT");
+    let ds = read_dicom_bytes(&part10_with_transfer_syntax("1.2.3.4.5"));
+    assert!(ds.elements().is_empty());
+}
+
+#[test]
+fn test_generated_dictionary() {
+    use dicom_rs::dataelem::{attribute_by_keyword, attribute_by_tag, DicomVr};
+    use dicom_rs::dicts::STANDARD_EDITION;
+    assert!(!STANDARD_EDITION.is_empty());
+    // Retired attributes still found in older CR/NM files
+    let dims = attribute_by_tag("(0028,0005)").unwrap();
+    assert_eq!(dims.keyword, "ImageDimensions");
+    assert!(dims.retired);
+    assert!(!attribute_by_keyword("PatientName").unwrap().retired);
+    // Command group and directory structuring elements
+    assert_eq!(attribute_by_keyword("CommandField").unwrap().tag, "(0000,0100)");
+    assert_eq!(attribute_by_tag("00041220").unwrap().vr, Some(DicomVr::Sq));
+    assert_eq!(attribute_by_keyword("OverlayData").unwrap().tag, "(60XX,3000)");
+}
+
+#[test]
+fn test_numeric_tag_lookup() {
+    use dicom_rs::dataelem::{attribute_by_tag, attribute_by_tag_number};
+    assert_eq!(attribute_by_tag_number(0x0010_0010).unwrap().keyword, "PatientName");
+    // Repeating groups resolve to their (60XX,eeee) entry
+    assert_eq!(attribute_by_tag_number(0x6002_3000).unwrap().keyword, "OverlayData");
+    assert_eq!(attribute_by_tag("6002,0010").unwrap().keyword, "OverlayRows");
+    assert!(attribute_by_tag_number(0x0009_1010).is_none());
+    assert!(attribute_by_tag("Modality").is_none());
+}
+
+fn implicit_le_element(group: u16, element: u16, value: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&group.to_le_bytes());
+    out.extend_from_slice(&element.to_le_bytes());
+    out.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    out.extend_from_slice(value);
+    out
+}
+
+#[test]
+fn test_implicit_vr_context() {
+    use dicom_rs::dataelem::{DataElementValue, DicomVr};
+    use dicom_rs::modules::io::read_dicom_bytes;
+    let mut buf = part10_with_transfer_syntax("1.2.840.10008.1.2");
+    buf.truncate(buf.len() - 10); // drop the explicit Modality element
+    buf.extend(implicit_le_element(0x0028, 0x0100, &16u16.to_le_bytes()));
+    buf.extend(implicit_le_element(0x0028, 0x0103, &1u16.to_le_bytes()));
+    buf.extend(implicit_le_element(0x0028, 0x0106, &(-5i16).to_le_bytes()));
+    // VOI LUT Sequence of undefined length with one undefined-length item
+    let mut item = Vec::new();
+    item.extend(implicit_le_element(0x0028, 0x3002, &[1, 0, 0, 0, 16, 0]));
+    item.extend(implicit_le_element(0x0028, 0x3006, &7u16.to_le_bytes()));
+    buf.extend_from_slice(&[0x28, 0x00, 0x10, 0x30, 0xFF, 0xFF, 0xFF, 0xFF]);
+    buf.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF]);
+    buf.extend(item);
+    buf.extend_from_slice(&[0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0]);
+    buf.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+    buf.extend(implicit_le_element(0x0008, 0x0060, b"MR"));
+
+    let ds = read_dicom_bytes(&buf);
+    let smallest = ds.get("SmallestImagePixelValue").unwrap();
+    assert_eq!(smallest.vr, DicomVr::Ss);
+    assert!(matches!(smallest.value, Some(DataElementValue::Int16(-5))));
+    let seq = ds.get("VOILUTSequence").unwrap();
+    let Some(DataElementValue::Sequence(items)) = &seq.value else {
+        panic!("expected a sequence");
+    };
+    assert_eq!(items.len(), 1);
+    let lut_data = items[0].get("LUTData").unwrap();
+    assert_eq!(lut_data.vr, DicomVr::Us);
+    // Parsing resumes after the sequence delimiter
+    assert_eq!(ds.get("Modality").unwrap().to_string(), "(0008,0060) Modality = MR");
+}
+
+#[test]
+fn test_iod_modules() {
+    use dicom_rs::iods::{iod_by_sop_class, module_by_name, AttributeType, ModuleUsage};
+    let mr = iod_by_sop_class("1.2.840.10008.5.1.4.1.1.4\0").unwrap();
+    assert_eq!(mr.name, "MR Image");
+    assert_eq!(mr.module("MR Image").unwrap().usage, ModuleUsage::Mandatory);
+    assert!(matches!(mr.module("Contrast/Bolus").unwrap().usage, ModuleUsage::Conditional(_)));
+    let listed: Vec<(u32, AttributeType)> = mr.attributes().map(|(_, a)| (a.tag, a.attr_type)).collect();
+    assert!(listed.contains(&(0x0018_0081, AttributeType::Type2))); // Echo Time
+    assert!(listed.contains(&(0x0020_000D, AttributeType::Type1))); // Study Instance UID
+    assert!(listed.contains(&(0x0008_1030, AttributeType::Type3))); // Study Description
+    // The vendored tables are excerpts, so no required list is claimed
+    assert!(!mr.complete && !mr.module("MR Image").unwrap().module.complete);
+    assert!(mr.required_attributes().is_none());
+
+    // Macros are expanded under the sequence that includes them
+    let patient = module_by_name("Patient").unwrap();
+    let species = patient.attribute(0x0010_2202).unwrap();
+    assert_eq!(species.attr_type, AttributeType::Type1C);
+    assert_eq!(species.items[3].attribute().unwrap().keyword, "CodeMeaning");
+    let overlay = module_by_name("Overlay Plane").unwrap();
+    assert_eq!(overlay.attribute(0x6000_3000).unwrap().attribute().unwrap().keyword, "OverlayData");
+    assert!(iod_by_sop_class("1.2.3").is_none());
+
+    let seg = iod_by_sop_class("1.2.840.10008.5.1.4.1.1.66.4").unwrap();
+    assert_eq!(seg.name, "Segmentation");
+    assert_eq!(seg.section, "A.51");
+    let segments = seg.module("Segmentation Image").unwrap().module.attribute(0x0062_0002).unwrap();
+    assert_eq!(segments.attr_type, AttributeType::Type1);
+    assert!(segments.items.iter().any(|a| a.tag == 0x0062_0004)); // Segment Number
+    let dose = iod_by_sop_class("1.2.840.10008.5.1.4.1.1.481.2").unwrap();
+    let dose_units = dose.module("RT Dose").unwrap().module.attribute(0x3004_0002).unwrap();
+    assert_eq!(dose_units.attr_type, AttributeType::Type1);
+    for (uid, name) in [
+        ("1.2.840.10008.5.1.4.1.1.481.3", "RT Structure Set"),
+        ("1.2.840.10008.5.1.4.1.1.88.33", "Comprehensive SR"),
+        ("1.2.840.10008.5.1.4.1.1.6.1", "US Image"),
+        ("1.2.840.10008.5.1.4.1.1.1.1.1", "DX Image"),
+        ("1.2.840.10008.5.1.4.1.1.128", "PET Image"),
+    ] {
+        assert_eq!(iod_by_sop_class(uid).unwrap().name, name);
+    }
+}
+
+#[test]
+fn test_codes_and_context_groups() {
+    use dicom_rs::codes::{code_sequence, code_sequence_element, coding_scheme, in_context_group, Code};
+    use dicom_rs::dataset::Dataset;
+    let liver = Code::new("10200004", "SCT", "Liver");
+    // Code Meaning does not take part in equality
+    assert_eq!(liver, Code::new("10200004", "SCT", "liver structure"));
+    assert_ne!(liver, Code::new("10200004", "SRT", "Liver"));
+    assert_eq!(in_context_group(7151, &liver), Some(true));
+    assert_eq!(in_context_group(244, &liver), Some(false));
+    assert_eq!(in_context_group(7151, &Code::new("89837001", "SCT", "Urinary bladder")), Some(true));
+    assert_eq!(in_context_group(29, &Code::new("SM", "DCM", "Slide Microscopy")), Some(true));
+    // Groups the vendored excerpt holds only part of cannot say no
+    assert_eq!(in_context_group(7151, &Code::new("0000000", "SCT", "Not listed")), None);
+    assert_eq!(in_context_group(1, &liver), None);
+    assert_eq!(coding_scheme("UCUM").unwrap().uid, "2.16.840.1.113883.6.8");
+
+    let mut ds = Dataset::new();
+    let long = Code::new("1234567890.1234567", "99TEST", "Long value");
+    ds.push(code_sequence_element("AnatomicRegionSequence", &[liver.clone(), long.clone()]).unwrap());
+    let codes = code_sequence(&ds, "AnatomicRegionSequence");
+    assert_eq!(codes, vec![liver, long]);
+    assert_eq!(codes[0].to_string(), "(10200004, SCT, \"Liver\")");
+    assert!(codes[1].to_item().get("LongCodeValue").is_some());
+    assert!(code_sequence_element("PatientName", &[]).is_none());
+}
+
+#[test]
+fn test_dictionary_search() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue, DicomVr};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::search::{find_by_prefix, find_by_substring, find_fuzzy, suggest_keyword, AttributeFilter};
+    let all = AttributeFilter::default();
+    let dose = find_by_substring("dose", &all);
+    assert!(dose.iter().any(|a| a.keyword == "ContrastBolusTotalDose"));
+    assert!(dose.iter().all(|a| !a.retired));
+    let us_only = AttributeFilter { vr: Some(DicomVr::Us), group: Some(0x0028), ..Default::default() };
+    let rows = find_by_prefix("row", &us_only);
+    assert_eq!(rows.first().unwrap().keyword, "Rows");
+    assert!(rows.iter().all(|a| a.tag.starts_with("(0028,")));
+    let overlays = AttributeFilter { group: Some(0x6002), ..Default::default() };
+    assert!(find_by_prefix("Overlay Data", &overlays).iter().any(|a| a.keyword == "OverlayData"));
+
+    assert_eq!(find_fuzzy("PatinetName", 2, &all)[0].0.keyword, "PatientName");
+    assert_eq!(suggest_keyword("StudyInstanceUId"), Some("StudyInstanceUID"));
+    assert_eq!(suggest_keyword("SeriesDescriptoin"), Some("SeriesDescription"));
+    assert_eq!(suggest_keyword("qqqqqqqq"), None);
+
+    // Dataset lookups suggest a keyword only when asked to
+    let mut ds = Dataset::new();
+    ds.push(DataElement::new("PatientName", DataElementValue::String("DOE^JOHN".to_string())).unwrap());
+    assert!(ds.get("PatinetName").is_none());
+    assert_eq!(ds.get_or_suggest("PatientName").unwrap().tag, 0x0010_0010);
+    assert_eq!(ds.get_or_suggest("PatinetName").unwrap_err(), Some("PatientName"));
+    assert_eq!(ds.get_or_suggest("PatientID").unwrap_err(), None);
+    assert_eq!(ds.get_or_suggest("(0010,0020)").unwrap_err(), None);
+}
+
+fn explicit_be_element(group: u16, element: u16, vr: &[u8; 2], value: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&group.to_be_bytes());
+    out.extend_from_slice(&element.to_be_bytes());
+    out.extend_from_slice(vr);
+    if matches!(vr, b"OB" | b"OW" | b"OF" | b"OD") {
+        out.extend_from_slice(&[0, 0]);
+        out.extend_from_slice(&(value.len() as u32).to_be_bytes());
+    } else {
+        out.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    }
+    out.extend_from_slice(value);
+    out
+}
+
+#[test]
+fn test_decode_native_pixels() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::io::read_dicom_bytes;
+    use dicom_rs::modules::pixel::{decode_frame, decode_pixel_data, PixelBuffer, PixelDescription};
+
+    // 12 of 16 bits stored, signed, big endian, with junk above High Bit
+    let mut buf = part10_with_transfer_syntax("1.2.840.10008.1.2.2");
+    buf.truncate(buf.len() - 10);
+    buf.extend(explicit_be_element(0x0028, 0x0010, b"US", &2u16.to_be_bytes()));
+    buf.extend(explicit_be_element(0x0028, 0x0011, b"US", &2u16.to_be_bytes()));
+    buf.extend(explicit_be_element(0x0028, 0x0100, b"US", &16u16.to_be_bytes()));
+    buf.extend(explicit_be_element(0x0028, 0x0101, b"US", &12u16.to_be_bytes()));
+    buf.extend(explicit_be_element(0x0028, 0x0102, b"US", &11u16.to_be_bytes()));
+    buf.extend(explicit_be_element(0x0028, 0x0103, b"US", &1u16.to_be_bytes()));
+    let pixels: Vec<u8> = [0xF800u16, 0x07FF, 0x1001, 0xFFFF].iter().flat_map(|v| v.to_be_bytes()).collect();
+    buf.extend(explicit_be_element(0x7FE0, 0x0010, b"OW", &pixels));
+    let ds = read_dicom_bytes(&buf);
+    let array = decode_pixel_data(&ds).unwrap();
+    assert_eq!(array.shape(), (1, 2, 2, 1));
+    assert_eq!(array.data, PixelBuffer::I16(vec![-2048, 2047, 1, -1]));
+
+    // Color-by-plane RGB comes back interleaved
+    let mut ds = Dataset::new();
+    for (keyword, value) in [
+        ("SamplesPerPixel", 3u16),
+        ("Rows", 1),
+        ("Columns", 2),
+        ("BitsAllocated", 8),
+        ("PlanarConfiguration", 1),
+        ("NumberOfFrames", 2),
+    ] {
+        ds.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    ds.set_pixel_data(vec![1, 2, 3, 4, 5, 6, 10, 20, 30, 40, 50, 60]);
+    let frame = decode_frame(&ds, 1).unwrap();
+    assert_eq!(frame.data, PixelBuffer::U8(vec![10, 30, 50, 20, 40, 60]));
+    assert_eq!(frame.value(0, 0, 1, 2), 60.0);
+    assert!(decode_frame(&ds, 2).is_none());
+
+    // Rows is US, so a larger value written with another VR is rejected
+    let mut ds = Dataset::new();
+    ds.push(DataElement::new("Rows", DataElementValue::UInt32(70000)).unwrap());
+    ds.push(DataElement::new("Columns", DataElementValue::UInt16(2)).unwrap());
+    ds.push(DataElement::new("BitsAllocated", DataElementValue::UInt16(8)).unwrap());
+    assert!(PixelDescription::from_dataset(&ds).is_none());
+
+    // Frame sizes that overflow are reported as None rather than wrapping
+    let huge = PixelDescription {
+        rows: usize::MAX / 2,
+        columns: 3,
+        samples_per_pixel: 1,
+        bits_allocated: 8,
+        bits_stored: 8,
+        high_bit: 7,
+        signed: false,
+        planar: false,
+        number_of_frames: 1,
+        photometric_interpretation: "MONOCHROME2".to_string(),
+    };
+    assert_eq!(huge.frame_samples(), None);
+    assert_eq!(huge.frame_bytes(), None);
+    assert_eq!(huge.stored_frame_bytes(), None);
+    let small = PixelDescription { rows: 2, columns: 3, samples_per_pixel: 3, bits_allocated: 16, ..huge };
+    assert_eq!(small.frame_samples(), Some(18));
+    assert_eq!(small.frame_bytes(), Some(36));
+}
+
+#[test]
+fn test_grayscale_display_pipeline() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::lut::{render_frame, DisplayDepth, VoiFunction, Window};
+    use dicom_rs::modules::pixel::PixelBuffer;
+
+    let text = |keyword: &str, value: &str| DataElement::new(keyword, DataElementValue::String(value.to_string())).unwrap();
+    let mut ds = Dataset::new();
+    ds.push(DataElement::new("Rows", DataElementValue::UInt16(1)).unwrap());
+    ds.push(DataElement::new("Columns", DataElementValue::UInt16(3)).unwrap());
+    ds.push(DataElement::new("BitsAllocated", DataElementValue::UInt16(16)).unwrap());
+    ds.push(text("PhotometricInterpretation", "MONOCHROME2"));
+    ds.push(text("WindowCenter", "40\\80"));
+    ds.push(text("WindowWidth", "400\\800"));
+    ds.push(text("RescaleIntercept", "-1024"));
+    ds.push(text("RescaleSlope", "1"));
+    // -200, 0 and 300 HU
+    ds.set_pixel_data([824u16, 1024, 1324].iter().flat_map(|v| v.to_le_bytes()).collect());
+
+    let out = render_frame(&ds, 0, None, DisplayDepth::Bits8).unwrap();
+    assert_eq!(out.data, PixelBuffer::U8(vec![0, 102, 255]));
+    let exact = Window { center: 0.0, width: 100.0, function: VoiFunction::LinearExact };
+    let out = render_frame(&ds, 0, Some(exact), DisplayDepth::Bits16).unwrap();
+    assert_eq!(out.data, PixelBuffer::U16(vec![0, 32768, 65535]));
+
+    // MONOCHROME1 inverts unless a Presentation LUT Shape says otherwise
+    let mut mono1 = Dataset::new();
+    for e in ds.elements().iter().filter(|e| e.attribute.keyword != "PhotometricInterpretation") {
+        mono1.push(e.clone());
+    }
+    mono1.push(text("PhotometricInterpretation", "MONOCHROME1"));
+    mono1.set_pixel_data(ds.pixel_data().unwrap().to_vec());
+    let out = render_frame(&mono1, 0, None, DisplayDepth::Bits8).unwrap();
+    assert_eq!(out.data, PixelBuffer::U8(vec![255, 153, 0]));
+    mono1.push(text("PresentationLUTShape", "IDENTITY"));
+    let out = render_frame(&mono1, 0, None, DisplayDepth::Bits8).unwrap();
+    assert_eq!(out.data, PixelBuffer::U8(vec![0, 102, 255]));
+
+    // A Modality LUT Sequence replaces the rescale, and without a window
+    // the output spans the frame's range
+    let mut lut_ds = Dataset::new();
+    for e in ds.elements().iter().filter(|e| !e.attribute.keyword.starts_with("Window")) {
+        lut_ds.push(e.clone());
+    }
+    let mut item = Dataset::new();
+    let words = |v: &[u16]| DataElementValue::Data(v.iter().flat_map(|w| w.to_le_bytes()).collect());
+    item.push(DataElement::new("LUTDescriptor", words(&[3, 824, 12])).unwrap());
+    item.push(DataElement::new("LUTData", words(&[100, 50, 0])).unwrap());
+    lut_ds.push(DataElement::new("ModalityLUTSequence", DataElementValue::Sequence(vec![item])).unwrap());
+    lut_ds.set_pixel_data([824u16, 825, 2000].iter().flat_map(|v| v.to_le_bytes()).collect());
+    let out = render_frame(&lut_ds, 0, None, DisplayDepth::Bits8).unwrap();
+    assert_eq!(out.data, PixelBuffer::U8(vec![255, 128, 0]));
+}
+
+fn encapsulated_element(offset_table: &[u32], fragments: &[Vec<u8>]) -> Vec<u8> {
+    let item = |out: &mut Vec<u8>, data: &[u8]| {
+        out.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0]);
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(data);
+    };
+    let mut out = vec![0xE0, 0x7F, 0x10, 0x00, b'O', b'B', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF];
+    let table: Vec<u8> = offset_table.iter().flat_map(|o| o.to_le_bytes()).collect();
+    item(&mut out, &table);
+    for fragment in fragments {
+        item(&mut out, fragment);
+    }
+    out.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+    out
+}
+
+#[test]
+fn test_rle_lossless() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::codecs::{encode_dataset, encode_pixel_data, RLE_LOSSLESS};
+    use dicom_rs::modules::io::read_dicom_bytes;
+    use dicom_rs::modules::pixel::{decode_pixel_data, PixelBuffer};
+
+    // Hand-made frame: one segment, replicate run of three then a literal
+    let mut frame = vec![0u8; 64];
+    frame[0] = 1;
+    frame[4] = 64;
+    frame.extend_from_slice(&[0xFE, 7, 0x00, 9]);
+    let mut buf = part10_with_transfer_syntax(RLE_LOSSLESS);
+    buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &1u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &4u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0100, b"US", &8u16.to_le_bytes()));
+    buf.extend(encapsulated_element(&[], &[frame]));
+    let ds = read_dicom_bytes(&buf);
+    assert_eq!(ds.encapsulated_pixel_data().unwrap().fragments.len(), 1);
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U8(vec![7, 7, 7, 9]));
+
+    // 16-bit, two frames: encode, wrap in a file and decode again
+    let mut native = Dataset::new();
+    for (keyword, value) in [("Rows", 2u16), ("Columns", 3), ("BitsAllocated", 16), ("NumberOfFrames", 2)] {
+        native.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    let samples: Vec<u16> = vec![0, 0, 0, 0x1234, 0xFFFF, 1, 500, 500, 500, 500, 500, 40000];
+    native.set_pixel_data(samples.iter().flat_map(|v| v.to_le_bytes()).collect());
+    let (encoded, _) = encode_pixel_data(&native, RLE_LOSSLESS).unwrap();
+    assert_eq!(encoded.fragments.len(), 2);
+
+    let mut buf = part10_with_transfer_syntax(RLE_LOSSLESS);
+    buf.extend(explicit_le_element(0x0028, 0x0008, b"IS", b"2 "));
+    buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &2u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &3u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0100, b"US", &16u16.to_le_bytes()));
+    buf.extend(encapsulated_element(&encoded.offset_table, &encoded.fragments));
+    let ds = read_dicom_bytes(&buf);
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U16(samples));
+
+    // Samples stored in the high bits come back in the low bits, once
+    let mut shifted = Dataset::new();
+    for (keyword, value) in [("Rows", 1u16), ("Columns", 3), ("BitsAllocated", 16), ("BitsStored", 12), ("HighBit", 15)] {
+        shifted.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    shifted.set_pixel_data([0x0010u16, 0x7FF0, 0xFFF0].iter().flat_map(|v| v.to_le_bytes()).collect());
+    let encoded = encode_dataset(&shifted, RLE_LOSSLESS).unwrap();
+    assert_eq!(encoded.get_int("HighBit"), Some(11));
+    assert_eq!(decode_pixel_data(&encoded).unwrap().data, PixelBuffer::U16(vec![1, 0x7FF, 0xFFF]));
+
+    // YBR_FULL_422 frames are upsampled, so they are encoded as YBR_FULL
+    let mut ybr = Dataset::new();
+    for (keyword, value) in [("Rows", 1u16), ("Columns", 4), ("BitsAllocated", 8), ("SamplesPerPixel", 3)] {
+        ybr.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    ybr.push(DataElement::new("PhotometricInterpretation", DataElementValue::String("YBR_FULL_422".to_string())).unwrap());
+    ybr.set_pixel_data(vec![10, 20, 128, 130, 30, 40, 100, 90]);
+    let encoded = encode_dataset(&ybr, RLE_LOSSLESS).unwrap();
+    assert_eq!(encoded.get_str("PhotometricInterpretation"), Some("YBR_FULL"));
+    assert_eq!(decode_pixel_data(&encoded), decode_pixel_data(&ybr));
+
+    // Bit-packed samples have no encapsulated form
+    ybr.set(DataElement::new("BitsAllocated", DataElementValue::UInt16(1)).unwrap());
+    ybr.set(DataElement::new("SamplesPerPixel", DataElementValue::UInt16(1)).unwrap());
+    assert!(encode_pixel_data(&ybr, RLE_LOSSLESS).is_none());
+}
+
+/// Minimal 8x8 sequential JPEG: unit quantization except a DC step of 8,
+/// DC categories 0 (code 00) and 5 (code 01) and an AC table holding only EOB.
+fn jpeg_stream(sof: u8, precision: u8, components: u8, adobe_transform: Option<u8>, scan: &[u8]) -> Vec<u8> {
+    let mut s = vec![0xFF, 0xD8];
+    if let Some(transform) = adobe_transform {
+        s.extend([0xFF, 0xEE, 0, 14]);
+        s.extend(b"Adobe");
+        s.extend([0, 100, 0, 0, 0, 0, transform]);
+    }
+    s.extend([0xFF, 0xDB, 0, 67, 0, 8]);
+    s.extend([1u8; 63]);
+    s.extend([0xFF, sof, 0, 8 + 3 * components, precision, 0, 8, 0, 8, components]);
+    for id in 1..=components {
+        s.extend([id, 0x11, 0]);
+    }
+    s.extend([0xFF, 0xC4, 0, 21, 0x00, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5]);
+    s.extend([0xFF, 0xC4, 0, 20, 0x10, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    s.extend([0xFF, 0xDA, 0, 6 + 2 * components, components]);
+    for id in 1..=components {
+        s.extend([id, 0x00]);
+    }
+    s.extend([0, 63, 0]);
+    s.extend(scan);
+    s.extend([0xFF, 0xD9]);
+    s
+}
+
+#[test]
+fn test_jpeg_baseline_and_extended() {
+    use dicom_rs::modules::codecs::{decoded_photometric_interpretation, JPEG_BASELINE, JPEG_EXTENDED};
+    use dicom_rs::modules::io::read_dicom_bytes;
+    use dicom_rs::modules::pixel::{decode_pixel_data, PixelBuffer, PixelDescription};
+
+    let file = |ts: &str, bits: u16, samples: u16, photometric: &[u8], stream: Vec<u8>| {
+        let mut buf = part10_with_transfer_syntax(ts);
+        buf.extend(explicit_le_element(0x0028, 0x0002, b"US", &samples.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0004, b"CS", photometric));
+        buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &8u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &8u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0100, b"US", &bits.to_le_bytes()));
+        buf.extend(encapsulated_element(&[], &[stream]));
+        read_dicom_bytes(&buf)
+    };
+
+    // DC difference +16 times the step of 8 gives 16 above the level shift
+    let ds = file(JPEG_BASELINE, 8, 1, b"MONOCHROME2 ", jpeg_stream(0xC0, 8, 1, None, &[0x60]));
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U8(vec![144; 64]));
+    let ds = file(JPEG_EXTENDED, 16, 1, b"MONOCHROME2 ", jpeg_stream(0xC1, 12, 1, None, &[0x60]));
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U16(vec![2064; 64]));
+
+    // Y 144, Cb 128, Cr 144: converted to RGB unless APP14 says the stream
+    // has no color transform, whatever the Photometric Interpretation says
+    let scan = [0x60, 0x0C, 0x1F];
+    let ds = file(JPEG_BASELINE, 8, 3, b"YBR_FULL_422", jpeg_stream(0xC0, 8, 3, None, &scan));
+    let ts = ds.transfer_syntax().unwrap();
+    assert_eq!(decoded_photometric_interpretation(&ts, &PixelDescription::from_dataset(&ds).unwrap()), "RGB");
+    let rgb = decode_pixel_data(&ds).unwrap();
+    assert_eq!(rgb.shape(), (1, 8, 8, 3));
+    assert_eq!((rgb.value(0, 0, 0, 0), rgb.value(0, 0, 0, 1), rgb.value(0, 0, 0, 2)), (166.0, 133.0, 144.0));
+    let ds = file(JPEG_BASELINE, 8, 3, b"YBR_FULL_422", jpeg_stream(0xC0, 8, 3, Some(0), &scan));
+    let raw = decode_pixel_data(&ds).unwrap();
+    assert_eq!((raw.value(0, 7, 7, 0), raw.value(0, 7, 7, 1), raw.value(0, 7, 7, 2)), (144.0, 128.0, 144.0));
+    let ds = file(JPEG_BASELINE, 8, 3, b"RGB ", jpeg_stream(0xC0, 8, 3, Some(1), &scan));
+    assert_eq!(decode_pixel_data(&ds).unwrap().value(0, 3, 4, 0), 166.0);
+
+    // 32x8 with 2x1 luma sampling, AC coefficients and a restart after each
+    // MCU; AC codes are EOB 0, (0,5) 10 and (1,5) 110. The first MCU has
+    // Y0 DC +16 with F(0,1) 24, Y1 F(1,0) -20 and Cb F(0,1) -20; the second
+    // has Y0 DC +16 again, which only gives 144 if the prediction was reset,
+    // and Cr DC -16. Expected values are the rounded T.81 A.3.3 IDCT.
+    let pack = |fields: &[(u64, u32)]| {
+        let (mut out, mut acc, mut n) = (Vec::new(), 0u64, 0u32);
+        for &(value, len) in fields {
+            acc = acc << len | value;
+            n += len;
+            while n >= 8 {
+                n -= 8;
+                out.push((acc >> n) as u8);
+                if out.last() == Some(&0xFF) {
+                    out.push(0);
+                }
+            }
+        }
+        if n > 0 {
+            out.push((acc << (8 - n)) as u8 | 0xFF >> n);
+        }
+        out
+    };
+    let mut s = vec![0xFF, 0xD8, 0xFF, 0xEE, 0, 14, b'A', b'd', b'o', b'b', b'e', 0, 100, 0, 0, 0, 0, 0];
+    s.extend([0xFF, 0xDB, 0, 67, 0, 8]);
+    s.extend([1u8; 63]);
+    s.extend([0xFF, 0xC0, 0, 17, 8, 0, 8, 0, 32, 3, 1, 0x21, 0, 2, 0x11, 0, 3, 0x11, 0]);
+    s.extend([0xFF, 0xC4, 0, 21, 0x00, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5]);
+    s.extend([0xFF, 0xC4, 0, 22, 0x10, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x05, 0x15]);
+    s.extend([0xFF, 0xDD, 0, 4, 0, 1]);
+    s.extend([0xFF, 0xDA, 0, 12, 3, 1, 0x00, 2, 0x00, 3, 0x00, 0, 63, 0]);
+    let y0 = [(1, 2), (16, 5), (2, 2), (24, 5), (0, 1)];
+    let y1 = [(0, 2), (6, 3), (11, 5), (0, 1)];
+    let cb = [(0, 2), (2, 2), (11, 5), (0, 1)];
+    s.extend(pack(&[&y0[..], &y1, &cb, &[(0, 2), (0, 1)]].concat()));
+    s.extend([0xFF, 0xD0]);
+    s.extend(pack(&[(1, 2), (16, 5), (0, 1), (0, 2), (0, 1), (0, 2), (0, 1), (1, 2), (15, 5), (0, 1)]));
+    s.extend([0xFF, 0xD9]);
+    let mut buf = part10_with_transfer_syntax(JPEG_BASELINE);
+    buf.extend(explicit_le_element(0x0028, 0x0002, b"US", &3u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0004, b"CS", b"YBR_FULL_422"));
+    buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &8u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &32u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0100, b"US", &8u16.to_le_bytes()));
+    buf.extend(encapsulated_element(&[], &[s]));
+    let out = decode_pixel_data(&read_dicom_bytes(&buf)).unwrap();
+    let row = |y: usize, sample: usize| (0..32).map(|x| out.value(0, y, x, sample) as u8).collect::<Vec<_>>();
+    let mut luma = vec![148, 148, 146, 145, 143, 142, 140, 140];
+    luma.extend([141; 8]);
+    luma.extend([144; 16]);
+    assert_eq!(row(0, 0), luma);
+    let column: Vec<u8> = (0..8).map(|y| out.value(0, y, 9, 0) as u8).collect();
+    assert_eq!(column, [141, 141, 142, 143, 145, 146, 147, 147]);
+    let mut cb: Vec<u8> = [125, 125, 126, 127, 129, 130, 131, 131].iter().flat_map(|&v| [v, v]).collect();
+    cb.extend([128; 16]);
+    assert_eq!(row(5, 1), cb);
+    let mut cr = vec![128; 16];
+    cr.extend([112; 16]);
+    assert_eq!(row(7, 2), cr);
+
+    // Frames that disagree with Rows, Columns or Samples per Pixel are
+    // refused before their planes are allocated
+    let ds = file(JPEG_BASELINE, 8, 3, b"YBR_FULL_422", jpeg_stream(0xC0, 8, 1, None, &[0x60]));
+    assert!(decode_pixel_data(&ds).is_none());
+    let mut stream = jpeg_stream(0xC0, 8, 1, None, &[0x60]);
+    let sof = stream.windows(2).position(|w| w == [0xFF, 0xC0]).unwrap();
+    stream[sof + 5..sof + 9].copy_from_slice(&[0xFF; 4]);
+    assert!(decode_pixel_data(&file(JPEG_BASELINE, 8, 1, b"MONOCHROME2 ", stream)).is_none());
+
+    // Three 1-bit DC codes cannot exist; the table is rejected
+    let mut stream = jpeg_stream(0xC0, 8, 1, None, &[0x60]);
+    let dht = stream.windows(2).position(|w| w == [0xFF, 0xC4]).unwrap();
+    stream.splice(dht..dht + 23, [0xFF, 0xC4, 0, 22, 0x00, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 6]);
+    let ds = file(JPEG_BASELINE, 8, 1, b"MONOCHROME2 ", stream);
+    assert!(decode_pixel_data(&ds).is_none());
+
+    // DC category 12 is beyond any 8-bit DCT coefficient
+    let mut stream = jpeg_stream(0xC0, 8, 1, None, &[0x60, 0, 0]);
+    let dht = stream.windows(2).position(|w| w == [0xFF, 0xC4]).unwrap();
+    stream[dht + 22] = 12;
+    let ds = file(JPEG_BASELINE, 8, 1, b"MONOCHROME2 ", stream);
+    assert!(decode_pixel_data(&ds).is_none());
+}
+
+#[test]
+fn test_jpeg_lossless() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::codecs::{encode_pixel_data, JPEG_LOSSLESS, JPEG_LOSSLESS_SV1};
+    use dicom_rs::modules::io::read_dicom_bytes;
+    use dicom_rs::modules::pixel::{decode_pixel_data, PixelBuffer};
+
+    let file = |ts: &str, rows: u16, columns: u16, bits: u16, samples: u16, frames: &[Vec<u8>]| {
+        let mut buf = part10_with_transfer_syntax(ts);
+        buf.extend(explicit_le_element(0x0028, 0x0002, b"US", &samples.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0008, b"IS", format!("{:<2}", frames.len()).as_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &rows.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &columns.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0100, b"US", &bits.to_le_bytes()));
+        buf.extend(encapsulated_element(&[], frames));
+        read_dicom_bytes(&buf)
+    };
+
+    // 2x2 stream coding 130, 132 / 130, x where x is the prediction itself;
+    // categories 0 (code 0) and 2 (code 10)
+    for (predictor, expected) in [(1, 130), (2, 132), (3, 130), (4, 132), (5, 131), (6, 132), (7, 131)] {
+        let mut stream = vec![0xFF, 0xD8, 0xFF, 0xC3, 0, 11, 8, 0, 2, 0, 2, 1, 1, 0x11, 0];
+        stream.extend([0xFF, 0xC4, 0, 21, 0x00, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+        stream.extend([0xFF, 0xDA, 0, 8, 1, 1, 0x00, predictor, 0, 0, 0xAA, 0x3F, 0xFF, 0xD9]);
+        let ds = file(JPEG_LOSSLESS, 2, 2, 8, 1, &[stream]);
+        assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U8(vec![130, 132, 130, expected]));
+    }
+    // A scan with no components is refused
+    let mut stream = vec![0xFF, 0xD8, 0xFF, 0xC3, 0, 11, 8, 0, 2, 0, 2, 1, 1, 0x11, 0];
+    stream.extend([0xFF, 0xC4, 0, 21, 0x00, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+    stream.extend([0xFF, 0xDA, 0, 6, 0, 1, 0, 0, 0xAA, 0x3F, 0xFF, 0xD9]);
+    assert!(decode_pixel_data(&file(JPEG_LOSSLESS, 2, 2, 8, 1, &[stream])).is_none());
+    // So is a frame larger than the dataset says
+    let mut stream = vec![0xFF, 0xD8, 0xFF, 0xC3, 0, 11, 8, 0xFF, 0xFF, 0xFF, 0xFF, 1, 1, 0x11, 0];
+    stream.extend([0xFF, 0xC4, 0, 21, 0x00, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+    stream.extend([0xFF, 0xDA, 0, 8, 1, 1, 0x00, 1, 0, 0, 0xAA, 0x3F, 0xFF, 0xD9]);
+    assert!(decode_pixel_data(&file(JPEG_LOSSLESS, 2, 2, 8, 1, &[stream])).is_none());
+    // And samples more precise than BitsAllocated
+    let mut stream = vec![0xFF, 0xD8, 0xFF, 0xC3, 0, 11, 12, 0, 2, 0, 2, 1, 1, 0x11, 0];
+    stream.extend([0xFF, 0xC4, 0, 21, 0x00, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+    stream.extend([0xFF, 0xDA, 0, 8, 1, 1, 0x00, 1, 0, 0, 0xAA, 0x3F, 0xFF, 0xD9]);
+    assert!(decode_pixel_data(&file(JPEG_LOSSLESS, 2, 2, 8, 1, &[stream.clone()])).is_none());
+    assert!(decode_pixel_data(&file(JPEG_LOSSLESS, 2, 2, 16, 1, &[stream])).is_some());
+
+    // SV1 round trips: 16-bit with jumps needing category 16, and 8-bit RGB
+    let mut native = Dataset::new();
+    for (keyword, value) in [("Rows", 3u16), ("Columns", 3), ("BitsAllocated", 16), ("NumberOfFrames", 2)] {
+        native.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    let samples: Vec<u16> = vec![0, 32768, 0, 65535, 1, 40000, 12345, 45113, 7, 7, 8, 0, 65535, 0, 32768, 3, 2, 1];
+    native.set_pixel_data(samples.iter().flat_map(|v| v.to_le_bytes()).collect());
+    let (encoded, _) = encode_pixel_data(&native, JPEG_LOSSLESS_SV1).unwrap();
+    let ds = file(JPEG_LOSSLESS_SV1, 3, 3, 16, 1, &encoded.fragments);
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U16(samples));
+
+    let mut rgb = Dataset::new();
+    for (keyword, value) in [("Rows", 2u16), ("Columns", 4), ("BitsAllocated", 8), ("SamplesPerPixel", 3)] {
+        rgb.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    let bytes: Vec<u8> = (0..24u32).map(|i| (i * 37 % 256) as u8).collect();
+    rgb.set_pixel_data(bytes.clone());
+    let (encoded, _) = encode_pixel_data(&rgb, JPEG_LOSSLESS_SV1).unwrap();
+    let ds = file(JPEG_LOSSLESS_SV1, 2, 4, 8, 3, &encoded.fragments);
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U8(bytes));
+}
+
+#[test]
+fn test_jpeg_ls() {
+    use dicom_rs::modules::codecs::jpeg_ls::{decode_frame, default_thresholds, encode_frame, Interleave, JpegLsOptions};
+    use dicom_rs::modules::codecs::JPEG_LS_LOSSLESS;
+    use dicom_rs::modules::io::read_dicom_bytes;
+    use dicom_rs::modules::pixel::{decode_pixel_data, PixelBuffer, PixelDescription};
+
+    // Default thresholds worked out from T.87 C.2.4.1.1.1: for MAXVAL 31
+    // FACTOR is 8, so T1 = MAX(2, 3/8 + 3*3) = 9, T2 = MAX(3, 7/8 + 5*3) = 15
+    // and T3 = MAX(4, 21/8 + 7*3) = 23
+    assert_eq!(default_thresholds(31, 3), [9, 15, 23]);
+    assert_eq!(default_thresholds(127, 1), [4, 8, 17]);
+    assert_eq!(default_thresholds(255, 0), [3, 7, 21]);
+    assert_eq!(default_thresholds(4095, 0), [18, 67, 276]);
+
+    // Example image and code stream of ITU-T T.87 Annex H.3
+    let image = vec![0u8, 0, 90, 74, 68, 50, 43, 205, 64, 145, 145, 145, 100, 145, 145, 145];
+    let stream: Vec<u8> = vec![
+        0xFF, 0xD8, 0xFF, 0xF7, 0x00, 0x0B, 0x08, 0x00, 0x04, 0x00, 0x04, 0x01, 0x01, 0x11, 0x00, 0xFF, 0xDA, 0x00,
+        0x08, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x6C, 0x80, 0x20, 0x8E, 0x01, 0xC0, 0x00, 0x00,
+        0x57, 0x40, 0x00, 0x00, 0x6E, 0xE6, 0x00, 0x00, 0x01, 0xBC, 0x18, 0x00, 0x00, 0x05, 0xD8, 0x00, 0x00, 0x91,
+        0x60, 0xFF, 0xD9,
+    ];
+    let mut buf = part10_with_transfer_syntax(JPEG_LS_LOSSLESS);
+    buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &4u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &4u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0100, b"US", &8u16.to_le_bytes()));
+    buf.extend(encapsulated_element(&[], std::slice::from_ref(&stream)));
+    let ds = read_dicom_bytes(&buf);
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U8(image.clone()));
+    let desc = PixelDescription::from_dataset(&ds).unwrap();
+    assert_eq!(encode_frame(&desc, &image, &JpegLsOptions::default()).unwrap(), stream);
+    // The same stream claiming 65535x65535 samples is refused up front
+    let mut huge = stream.clone();
+    huge[7..11].copy_from_slice(&[0xFF; 4]);
+    assert!(decode_frame(&desc, &huge).is_none());
+
+    // Noisy ramps with flat areas, so both regular and run mode are used
+    let sample = |i: usize, bits: u16| {
+        let flat = (i / 7).is_multiple_of(3);
+        let v = if flat { 1000 } else { i * 7919 % 65521 + i * 13 };
+        (v % (1 << bits)) as u16
+    };
+    let desc = |bits_allocated: u16, bits_stored: u16, samples: usize| PixelDescription {
+        rows: 9,
+        columns: 11,
+        samples_per_pixel: samples,
+        bits_allocated,
+        bits_stored,
+        high_bit: bits_stored - 1,
+        signed: false,
+        planar: false,
+        number_of_frames: 1,
+        photometric_interpretation: if samples == 3 { "RGB" } else { "MONOCHROME2" }.to_string(),
+    };
+    for (bits_allocated, bits_stored, samples) in [(8, 8, 1), (16, 12, 1), (16, 16, 1), (8, 8, 3), (16, 12, 3)] {
+        let d = desc(bits_allocated, bits_stored, samples);
+        let values: Vec<u16> = (0..d.frame_samples().unwrap()).map(|i| sample(i, bits_stored)).collect();
+        let native: Vec<u8> = if bits_allocated == 8 {
+            values.iter().map(|&v| v as u8).collect()
+        } else {
+            values.iter().flat_map(|v| v.to_le_bytes()).collect()
+        };
+        for interleave in [Interleave::None, Interleave::Line, Interleave::Sample] {
+            for near in [0u16, 3] {
+                let encoded = encode_frame(&d, &native, &JpegLsOptions { near, interleave }).unwrap();
+                let decoded = decode_frame(&d, &encoded).unwrap();
+                if near == 0 {
+                    assert_eq!(decoded, native, "{bits_stored}-bit x{samples} {interleave:?}");
+                    continue;
+                }
+                let decoded: Vec<u16> = if bits_allocated == 8 {
+                    decoded.iter().map(|&v| v as u16).collect()
+                } else {
+                    decoded.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect()
+                };
+                let worst = values.iter().zip(&decoded).map(|(&a, &b)| (a as i32 - b as i32).abs()).max();
+                assert!(worst <= Some(near as i32), "{bits_stored}-bit x{samples} {interleave:?}: error {worst:?}");
+            }
+        }
+    }
+    // NEAR is at most 255 whatever the precision, and at most half of MAXVAL
+    let (d16, d8) = (desc(16, 16, 1), desc(8, 8, 1));
+    let native16 = vec![0u8; d16.frame_bytes().unwrap()];
+    assert!(encode_frame(&d16, &native16, &JpegLsOptions { near: 255, interleave: Interleave::None }).is_some());
+    assert!(encode_frame(&d16, &native16, &JpegLsOptions { near: 300, interleave: Interleave::None }).is_none());
+    assert!(encode_frame(&d8, &vec![0u8; d8.frame_bytes().unwrap()], &JpegLsOptions { near: 128, interleave: Interleave::None }).is_none());
+}
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+#[test]
+fn test_jpeg2000() {
+    use dicom_rs::modules::codecs::{decoded_photometric_interpretation, JPEG_2000, JPEG_2000_LOSSLESS};
+    use dicom_rs::modules::io::read_dicom_bytes;
+    use dicom_rs::modules::pixel::{decode_frame_at_resolution, decode_pixel_data, PixelBuffer, PixelDescription};
+
+    let dataset = |ts: &str, bits_allocated: u16, bits_stored: u16, signed: bool, pi: &[u8], stream: Vec<u8>| {
+        let mut buf = part10_with_transfer_syntax(ts);
+        buf.extend(explicit_le_element(0x0028, 0x0002, b"US", &(if pi == b"MONOCHROME2 " { 1u16 } else { 3 }).to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0004, b"CS", pi));
+        buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &8u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &8u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0100, b"US", &bits_allocated.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0101, b"US", &bits_stored.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0102, b"US", &(bits_stored - 1).to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0103, b"US", &(signed as u16).to_le_bytes()));
+        buf.extend(encapsulated_element(&[], &[stream]));
+        read_dicom_bytes(&buf)
+    };
+
+    // 12-bit, reversible 5/3 wavelet with two decomposition levels
+    let gray = from_hex(concat!(
+        "FF4FFF5100290000000000080000000800000000000000000000000800000008000000000000000000010B0101FF52000C",
+        "00000001000204040001FF5C000A4060686870686870FF90000A00000000003C0001FF93DFE02007AB5057E503327FC07D",
+        "A0907E60800CFF61C70B3D7F8FC03EA059FE0200221A085D8F00CE82B9B63519E6FFD9",
+    ));
+    let ds = dataset(JPEG_2000_LOSSLESS, 16, 12, false, b"MONOCHROME2 ", gray);
+    let expected: Vec<u16> = (0..64u16).map(|i| (i * 37 + i / 8 * 300) % 4096).collect();
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U16(expected));
+    let reduced = decode_frame_at_resolution(&ds, 0, 1).unwrap();
+    assert_eq!(reduced.shape(), (1, 4, 4, 1));
+    assert_eq!(
+        reduced.data,
+        PixelBuffer::U16(vec![0, 74, 148, 231, 1192, 1266, 1340, 1423, 2384, 2458, 2532, 2615, 2701, 2775, 2849, 2932])
+    );
+    assert_eq!(decode_frame_at_resolution(&ds, 0, 0).unwrap().shape(), (1, 8, 8, 1));
+    // Only two levels can be discarded, and the image must be the size the
+    // dataset says
+    assert_eq!(decode_frame_at_resolution(&ds, 0, 5).unwrap().shape(), (1, 2, 2, 1));
+    let mut desc = PixelDescription::from_dataset(&ds).unwrap();
+    desc.rows = 4;
+    let frame = &ds.encapsulated_pixel_data().unwrap().fragments[0];
+    assert!(dicom_rs::modules::codecs::jpeg2000::decode_frame(&desc, frame).is_none());
+    // Nor can samples be wider than BitsAllocated or of another signedness
+    desc.rows = 8;
+    desc.bits_allocated = 8;
+    desc.bits_stored = 8;
+    assert!(dicom_rs::modules::codecs::jpeg2000::decode_frame(&desc, frame).is_none());
+    desc.bits_allocated = 16;
+    desc.bits_stored = 12;
+    desc.signed = true;
+    assert!(dicom_rs::modules::codecs::jpeg2000::decode_frame(&desc, frame).is_none());
+    desc.signed = false;
+    assert!(dicom_rs::modules::codecs::jpeg2000::decode_frame(&desc, frame).is_some());
+
+    // Signed 16-bit samples
+    let signed = from_hex(concat!(
+        "FF4FFF5100290000000000080000000800000000000000000000000800000008000000000000000000018F0101FF52000C",
+        "00000001000104040001FF5C00074080888890FF90000A00000000007B0001FF93C07EC2E00A83F95E6E53EA68016438A5",
+        "7D26DF76E9879164327EB2C07F23103FC0CC07F8171826545F14403D5BD0778BE0251A030F39B9F65F2E3DBE1714C5585E",
+        "9BC475EF45A06BFD8BE1D7940A2A47E0D90871A81F0C1982DD38D25A7BE4F35692254D8934FB8FE937D8AE8DFFD9",
+    ));
+    let ds = dataset(JPEG_2000, 16, 16, true, b"MONOCHROME2 ", signed);
+    let expected: Vec<i16> = (0..64i32).map(|i| ((i * 997) % 3000 - 1500) as i16).collect();
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::I16(expected));
+
+    // 8-bit color coded with the reversible color transform
+    let rgb = from_hex(concat!(
+        "FF4FFF51002F000000000008000000080000000000000000000000080000000800000000000000000003070101070101",
+        "070101FF52000C00000001010104040001FF5C00074040484850FF90000A0000000000D10001FF93C7D41C1751D256EE",
+        "E2E65E24CB79EE1D46DF809000E9431B1963B11F0966526CCE63CE90BE33DF809017FA10D954CE0BB6B1EE65984980E5",
+        "0EE4A8C3EA108FB4327E61C0142F3A48E5738C8A205525579C63EE6A0F585F0730887D9D8D40210319934510D306DE47",
+        "728CB8DF007FCFC0327E0193F30C24C4385C73E4A758BC12527F0C39C1D5AA74E3011D6FFF7F0BD7469F97D27738F9AE",
+        "FF7FCFC0367E0173F30920C6C49076D2B7020FA1B24FE70CA1EEB2A1B0F863C3DF0F1507C0FEBDADA9D33DFFD9",
+    ));
+    let ds = dataset(JPEG_2000_LOSSLESS, 8, 8, false, b"YBR_RCT ", rgb);
+    let expected: Vec<u8> = (0..192usize).map(|k| (k * 37 % 256) as u8).collect();
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U8(expected));
+    let desc = PixelDescription::from_dataset(&ds).unwrap();
+    assert_eq!(decoded_photometric_interpretation(&ds.transfer_syntax().unwrap(), &desc), "RGB");
+
+    // Part 1 twin of the HTJ2K fixture; the default decoder refuses the HT one
+    let ds = dataset(JPEG_2000_LOSSLESS, 16, 12, false, b"MONOCHROME2 ", empty_j2k_stream(false));
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U16(vec![2048; 64]));
+    #[cfg(not(feature = "openjpeg"))]
+    {
+        use dicom_rs::modules::codecs::HTJ2K_LOSSLESS;
+        let ds = dataset(HTJ2K_LOSSLESS, 16, 12, false, b"MONOCHROME2 ", empty_j2k_stream(true));
+        assert!(decode_pixel_data(&ds).is_none());
+    }
+}
+
+/// 8x8 12-bit codestream, 5/3 wavelet with no decomposition levels, whose
+/// only packet is empty, so every sample is the DC level shift of 2048.
+/// With `ht` it is an HTJ2K codestream: Rsiz bit 14, a CAP marker for
+/// Part 15 and the HT code-block style.
+fn empty_j2k_stream(ht: bool) -> Vec<u8> {
+    let (rsiz, cap, style) = if ht { ("4000", "FF500008000200000000", "40") } else { ("0000", "", "00") };
+    from_hex(&format!(
+        "FF4FFF510029{rsiz}{}0001{}{cap}FF52000C0000000100000404{style}01FF5C00044060FF90000A00000000000F0001FF9300FFD9",
+        "0000000800000008000000000000000000000008000000080000000000000000", "0B0101",
+    ))
+}
+
+#[cfg(feature = "openjpeg")]
+#[test]
+fn test_htj2k() {
+    use dicom_rs::modules::codecs::{HTJ2K, HTJ2K_LOSSLESS, HTJ2K_LOSSLESS_RPCL};
+    use dicom_rs::modules::io::read_dicom_bytes;
+    use dicom_rs::modules::pixel::{decode_pixel_data, PixelBuffer};
+
+    for ts in [HTJ2K_LOSSLESS, HTJ2K_LOSSLESS_RPCL, HTJ2K] {
+        let mut buf = part10_with_transfer_syntax(ts);
+        buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &8u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &8u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0100, b"US", &16u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0101, b"US", &12u16.to_le_bytes()));
+        buf.extend(encapsulated_element(&[], &[empty_j2k_stream(true)]));
+        let ds = read_dicom_bytes(&buf);
+        assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U16(vec![2048; 64]), "{ts}");
+    }
+}
+
+#[test]
+fn test_color_conversions() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::color::{convert_photometric, decode_rgb_frame, expand_segmented_lut, from_rgb, to_rgb, Palette};
+    use dicom_rs::modules::pixel::{decode_frame, PixelBuffer, PixelDescription};
+
+    let image = |pi: &str, samples: u16, bits: u16, columns: u16, pixels: Vec<u8>| {
+        let mut ds = Dataset::new();
+        for (keyword, value) in [("SamplesPerPixel", samples), ("Rows", 1), ("Columns", columns), ("BitsAllocated", bits)] {
+            ds.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+        }
+        ds.push(DataElement::new("PhotometricInterpretation", DataElementValue::String(pi.to_string())).unwrap());
+        ds.set_pixel_data(pixels);
+        ds
+    };
+    let words = |values: &[u16]| DataElementValue::Data(values.iter().flat_map(|v| v.to_le_bytes()).collect());
+
+    // 8-bit palette, LUT Data packed one entry per byte
+    let mut ds = image("PALETTE COLOR", 1, 8, 4, vec![0, 1, 2, 3]);
+    for (color, data) in [("Red", [0u8, 255, 0, 10]), ("Green", [0, 0, 255, 20]), ("Blue", [0, 0, 0, 30])] {
+        ds.push(DataElement::new(&format!("{color}PaletteColorLookupTableDescriptor"), words(&[4, 0, 8])).unwrap());
+        ds.push(DataElement::new(&format!("{color}PaletteColorLookupTableData"), DataElementValue::Data(data.to_vec())).unwrap());
+    }
+    let rgb = decode_rgb_frame(&ds, 0).unwrap();
+    assert_eq!(rgb.shape(), (1, 1, 4, 3));
+    assert_eq!(rgb.data, PixelBuffer::U8(vec![0, 0, 0, 255, 0, 0, 0, 255, 0, 10, 20, 30]));
+
+    // Back to indices through the nearest palette entry
+    let mut colors = image("RGB", 3, 8, 2, vec![250, 5, 0, 12, 18, 33]);
+    for elem in ds.elements().iter().filter(|e| e.attribute.keyword.contains("Palette")) {
+        colors.push(elem.clone());
+    }
+    let indexed = convert_photometric(&colors, "PALETTE COLOR", false).unwrap();
+    assert_eq!(indexed.get_str("PhotometricInterpretation"), Some("PALETTE COLOR"));
+    assert_eq!(indexed.get_int("SamplesPerPixel"), Some(1));
+    assert_eq!(indexed.pixel_data().unwrap(), &[1, 3]);
+
+    // Segmented 16-bit palette: discrete, linear, indirect back to the
+    // first segment, discrete
+    let mut ds = image("PALETTE COLOR", 1, 8, 8, (0..8).collect());
+    for color in ["Red", "Green", "Blue"] {
+        ds.push(DataElement::new(&format!("{color}PaletteColorLookupTableDescriptor"), words(&[8, 0, 16])).unwrap());
+        let segments = words(&[0, 2, 0, 100, 1, 3, 400, 2, 1, 0, 0, 0, 1, 65535]);
+        ds.push(DataElement::new(&format!("Segmented{color}PaletteColorLookupTableData"), segments).unwrap());
+    }
+    let palette = Palette::from_dataset(&ds).unwrap();
+    assert_eq!(palette.red.data, vec![0, 100, 200, 300, 400, 0, 100, 65535]);
+    assert_eq!(palette.bits(), 16);
+    let rgb = decode_rgb_frame(&ds, 0).unwrap();
+    assert_eq!(rgb.value(0, 0, 3, 1), 300.0);
+    assert_eq!(rgb.value(0, 0, 7, 2), 65535.0);
+    // An indirect segment repeating a long ramp expands no further than
+    // the descriptor announces
+    let lut = expand_segmented_lut(&[0, 1, 0, 1, 65535, 65535, 2, 65535, 6, 0], 8).unwrap();
+    assert_eq!(lut, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+
+    // Native YBR_FULL_422 stores Y1 Y2 Cb Cr per pixel pair
+    let ds = image("YBR_FULL_422", 3, 8, 4, vec![10, 20, 128, 128, 30, 40, 100, 160]);
+    assert_eq!(decode_frame(&ds, 0).unwrap().data, PixelBuffer::U8(vec![10, 128, 128, 20, 128, 128, 30, 100, 160, 40, 100, 160]));
+    let rgb = decode_rgb_frame(&ds, 0).unwrap();
+    assert_eq!(rgb.data, PixelBuffer::U8(vec![10, 10, 10, 20, 20, 20, 75, 17, 0, 85, 27, 0]));
+    let planar = convert_photometric(&ds, "RGB", true).unwrap();
+    assert_eq!(planar.get_int("PlanarConfiguration"), Some(1));
+    assert_eq!(planar.pixel_data().unwrap(), &[10, 20, 75, 85, 10, 20, 17, 27, 10, 20, 0, 0]);
+    assert_eq!(decode_rgb_frame(&planar, 0).unwrap(), rgb);
+
+    // Encoding to YBR_FULL_422 averages the chroma of each pair
+    let gray = image("RGB", 3, 8, 2, vec![50, 50, 50, 60, 60, 60]);
+    let ybr = convert_photometric(&gray, "YBR_FULL_422", false).unwrap();
+    assert_eq!(ybr.pixel_data().unwrap(), &[50, 60, 128, 128]);
+
+    // YBR_FULL is lossy by a unit at most; YBR_RCT is exact while the
+    // chroma differences fit
+    let ds = image("RGB", 3, 16, 3, [1000u16, 800, 2500, 3000, 1000, 17, 512, 512, 2048].iter().flat_map(|v| v.to_le_bytes()).collect());
+    let mut desc = PixelDescription::from_dataset(&ds).unwrap();
+    desc.bits_stored = 12;
+    let rgb = decode_frame(&ds, 0).unwrap();
+    for pi in ["YBR_FULL", "YBR_RCT"] {
+        let converted = from_rgb(&rgb, 12, pi, None).unwrap();
+        desc.photometric_interpretation = pi.to_string();
+        let back = to_rgb(&converted, &desc, None).unwrap();
+        let worst = (0..9).map(|i| (back.data.get(i) - rgb.data.get(i)).abs()).fold(0.0, f64::max);
+        assert!(worst <= if pi == "YBR_RCT" { 0.0 } else { 1.0 }, "{pi}: error {worst}");
+    }
+}
+
+#[test]
+fn test_overlays() {
+    use dicom_rs::modules::io::read_dicom_bytes;
+    use dicom_rs::modules::lut::DisplayDepth;
+    use dicom_rs::modules::overlay::{overlay_groups, read_overlay, render_frame_with_overlays, OverlayType};
+    use dicom_rs::modules::pixel::{decode_frame, PixelBuffer};
+
+    let mut buf = part10_with_transfer_syntax("1.2.840.10008.1.2.1");
+    buf.extend(explicit_le_element(0x0028, 0x0008, b"IS", b"2 "));
+    buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &4u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &4u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0100, b"US", &16u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0101, b"US", &12u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0102, b"US", &11u16.to_le_bytes()));
+    // Two 3x3 frames packed across byte boundaries: a diagonal, then the
+    // anti-diagonal starting at bit 9
+    buf.extend(explicit_le_element(0x6000, 0x0010, b"US", &3u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x6000, 0x0011, b"US", &3u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x6000, 0x0015, b"IS", b"2 "));
+    buf.extend(explicit_le_element(0x6000, 0x0040, b"CS", b"G "));
+    let origin: Vec<u8> = [2i16, 2].iter().flat_map(|v| v.to_le_bytes()).collect();
+    buf.extend(explicit_le_element(0x6000, 0x0050, b"SS", &origin));
+    buf.extend(explicit_le_element(0x6000, 0x0051, b"US", &1u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x6000, 0x0100, b"US", &1u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x6000, 0x0102, b"US", &0u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x6000, 0x3000, b"OW", &[0x11, 0xA9, 0, 0]));
+    // Retired embedded overlay in bit 15 of the Pixel Data
+    buf.extend(explicit_le_element(0x6002, 0x0010, b"US", &4u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x6002, 0x0011, b"US", &4u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x6002, 0x0015, b"IS", b"2 "));
+    buf.extend(explicit_le_element(0x6002, 0x0040, b"CS", b"R "));
+    buf.extend(explicit_le_element(0x6002, 0x0100, b"US", &16u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x6002, 0x0102, b"US", &15u16.to_le_bytes()));
+    let pixels: Vec<u8> = (0..32u16)
+        .map(|i| ((i % 16) * 100) | if i == 5 || i == 26 { 0x8000 } else { 0 })
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    buf.extend(explicit_le_element(0x7FE0, 0x0010, b"OW", &pixels));
+    let ds = read_dicom_bytes(&buf);
+
+    assert_eq!(overlay_groups(&ds), vec![0x6000, 0x6002]);
+    let packed = read_overlay(&ds, 0x6000).unwrap();
+    assert_eq!((packed.rows, packed.columns, packed.frames, packed.origin), (3, 3, 2, (2, 2)));
+    assert_eq!(packed.frame_mask(0).unwrap(), vec![1, 0, 0, 0, 1, 0, 0, 0, 1]);
+    assert_eq!(packed.frame_mask(1).unwrap(), vec![0, 0, 1, 0, 1, 0, 1, 0, 0]);
+    assert!(packed.frame(2).is_none());
+    let embedded = read_overlay(&ds, 0x6002).unwrap();
+    assert_eq!(embedded.kind, OverlayType::Roi);
+    assert_eq!(embedded.frame(0).unwrap().iter().position(|&b| b), Some(5));
+    assert_eq!(embedded.frame(1).unwrap().iter().position(|&b| b), Some(10));
+    // The overlay bit is not part of the stored value
+    assert_eq!(decode_frame(&ds, 0).unwrap().value(0, 1, 1, 0), 500.0);
+
+    let PixelBuffer::U8(plain) = dicom_rs::modules::lut::render_frame(&ds, 0, None, DisplayDepth::Bits8).unwrap().data else {
+        panic!("expected 8-bit output");
+    };
+    let PixelBuffer::U8(burnt) = render_frame_with_overlays(&ds, 0, None, DisplayDepth::Bits8).unwrap().data else {
+        panic!("expected 8-bit output");
+    };
+    let changed: Vec<usize> = (0..16).filter(|&i| plain[i] != burnt[i]).collect();
+    assert_eq!(changed, vec![5, 10]);
+    assert!([5, 10, 15].iter().all(|&i| burnt[i] == 255));
+
+    // Frame counts whose bit count overflows, or that the data cannot
+    // hold, are refused
+    for frames in [b"99999999999 ", b"3           "] {
+        let mut buf = part10_with_transfer_syntax("1.2.840.10008.1.2.1");
+        buf.extend(explicit_le_element(0x6000, 0x0010, b"US", &65535u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x6000, 0x0011, b"US", &65535u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x6000, 0x0015, b"IS", frames));
+        buf.extend(explicit_le_element(0x6000, 0x3000, b"OW", &[0xFF; 4]));
+        assert!(read_overlay(&read_dicom_bytes(&buf), 0x6000).is_none());
+    }
+}
+
+#[test]
+fn test_float_pixel_data() {
+    use dicom_rs::codes::{code_sequence_element, Code};
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::io::read_dicom_bytes;
+    use dicom_rs::modules::lut::{real_world_frame, real_world_value_mappings};
+    use dicom_rs::modules::pixel::{decode_frame, PixelBuffer};
+
+    let mapping = |fields: Vec<(&str, DataElementValue)>| {
+        let mut item = Dataset::new();
+        for (keyword, value) in fields {
+            item.push(DataElement::new(keyword, value).unwrap());
+        }
+        item
+    };
+    let image = |columns: u16, bits: u16, element: u16, vr: &[u8; 2], pixels: Vec<u8>| {
+        let mut buf = part10_with_transfer_syntax("1.2.840.10008.1.2.1");
+        buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &1u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &columns.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0100, b"US", &bits.to_le_bytes()));
+        buf.extend(explicit_le_element(0x7FE0, element, vr, &pixels));
+        read_dicom_bytes(&buf)
+    };
+
+    // Float Pixel Data with a linear mapping in the shared functional groups
+    let values = [0.5f32, -1.25, 3.0, f32::NAN];
+    let mut ds = image(4, 32, 0x0008, b"OF", values.iter().flat_map(|v| v.to_le_bytes()).collect());
+    let item = mapping(vec![
+        ("DoubleFloatRealWorldValueFirstValueMapped", DataElementValue::Double(-10.0)),
+        ("DoubleFloatRealWorldValueLastValueMapped", DataElementValue::Double(10.0)),
+        ("RealWorldValueSlope", DataElementValue::Double(2.0)),
+        ("RealWorldValueIntercept", DataElementValue::Double(1.0)),
+    ]);
+    let shared = mapping(vec![("RealWorldValueMappingSequence", DataElementValue::Sequence(vec![item]))]);
+    ds.push(DataElement::new("SharedFunctionalGroupsSequence", DataElementValue::Sequence(vec![shared])).unwrap());
+    let PixelBuffer::F32(stored) = decode_frame(&ds, 0).unwrap().data else {
+        panic!("expected f32 samples");
+    };
+    assert_eq!(stored[..3], values[..3]);
+    assert!(stored[3].is_nan());
+    let PixelBuffer::F64(mapped) = real_world_frame(&ds, 0).unwrap().data else {
+        panic!("expected f64 samples");
+    };
+    assert_eq!(mapped[..3], [2.0, -1.5, 7.0]);
+    assert!(mapped[3].is_nan());
+
+    // Double Float Pixel Data with two top level mappings over disjoint
+    // ranges; values outside both become NaN
+    let values = [1.0f64, 2.0, 100.0, 20.0];
+    let mut ds = image(4, 64, 0x0009, b"OD", values.iter().flat_map(|v| v.to_le_bytes()).collect());
+    let mut low = mapping(vec![
+        ("DoubleFloatRealWorldValueFirstValueMapped", DataElementValue::Double(0.0)),
+        ("DoubleFloatRealWorldValueLastValueMapped", DataElementValue::Double(2.0)),
+        ("RealWorldValueSlope", DataElementValue::Double(10.0)),
+        ("RealWorldValueIntercept", DataElementValue::Double(0.0)),
+        ("LUTLabel", DataElementValue::String("T1".to_string())),
+    ]);
+    low.push(code_sequence_element("MeasurementUnitsCodeSequence", &[Code::new("ms", "UCUM", "millisecond")]).unwrap());
+    let high = mapping(vec![
+        ("DoubleFloatRealWorldValueFirstValueMapped", DataElementValue::Double(50.0)),
+        ("DoubleFloatRealWorldValueLastValueMapped", DataElementValue::Double(150.0)),
+        ("RealWorldValueSlope", DataElementValue::Double(-1.0)),
+        ("RealWorldValueIntercept", DataElementValue::Double(0.0)),
+    ]);
+    ds.push(DataElement::new("RealWorldValueMappingSequence", DataElementValue::Sequence(vec![low, high])).unwrap());
+    assert_eq!(decode_frame(&ds, 0).unwrap().data, PixelBuffer::F64(values.to_vec()));
+    let mappings = real_world_value_mappings(&ds, 0);
+    assert_eq!(mappings[0].label.as_deref(), Some("T1"));
+    assert_eq!(mappings[0].units.as_ref().unwrap().value, "ms");
+    let PixelBuffer::F64(mapped) = real_world_frame(&ds, 0).unwrap().data else {
+        panic!("expected f64 samples");
+    };
+    assert_eq!(mapped[..3], [10.0, 20.0, -100.0]);
+    assert!(mapped[3].is_nan());
+
+    // Integer Pixel Data through a real world value LUT
+    let mut ds = image(3, 16, 0x0010, b"OW", [0u16, 1, 2].iter().flat_map(|v| v.to_le_bytes()).collect());
+    let table: Vec<u8> = [0.0f64, 0.5, 1.0].iter().flat_map(|v| v.to_le_bytes()).collect();
+    let item = mapping(vec![
+        ("RealWorldValueFirstValueMapped", DataElementValue::UInt16(0)),
+        ("RealWorldValueLastValueMapped", DataElementValue::UInt16(2)),
+        ("RealWorldValueLUTData", DataElementValue::Data(table)),
+    ]);
+    ds.push(DataElement::new("RealWorldValueMappingSequence", DataElementValue::Sequence(vec![item])).unwrap());
+    assert_eq!(real_world_frame(&ds, 0).unwrap().data, PixelBuffer::F64(vec![0.0, 0.5, 1.0]));
+}
+
+#[test]
+fn test_packed_binary_frames() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::pixel::{decode_frame, decode_mask, pack_bits, set_mask_frame, unpack_bits, PixelBuffer};
+
+    // 3x3 frames: each of the 9-bit frames after the first starts mid-byte
+    let frames: Vec<Vec<u8>> = (0..5).map(|f| (0..9).map(|i| ((i + f) % 3 == 0) as u8).collect()).collect();
+    let packed = pack_bits(&frames.concat());
+    assert_eq!(packed.len(), 6);
+    assert_eq!(unpack_bits(&packed, 9, 9).unwrap(), frames[1]);
+    assert!(unpack_bits(&packed, 40, 9).is_none());
+
+    let mut ds = Dataset::new();
+    for (keyword, value) in [("Rows", 3u16), ("Columns", 3), ("BitsAllocated", 1), ("NumberOfFrames", 5)] {
+        ds.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    ds.set_pixel_data(packed);
+    for (f, expected) in frames.iter().enumerate() {
+        assert_eq!(decode_frame(&ds, f).unwrap().data, PixelBuffer::U8(expected.clone()));
+        assert_eq!(decode_mask(&ds, f).unwrap(), expected.iter().map(|&v| v == 1).collect::<Vec<_>>());
+    }
+
+    // Writing frame 2 (bits 18 to 26) leaves frames 1 and 3 alone
+    let mask = [true, true, false, false, true, true, false, false, true];
+    set_mask_frame(&mut ds, 2, &mask).unwrap();
+    assert_eq!(decode_mask(&ds, 2).unwrap(), mask);
+    for f in [0, 1, 3, 4] {
+        assert_eq!(decode_frame(&ds, f).unwrap().data, PixelBuffer::U8(frames[f].clone()));
+    }
+    assert!(set_mask_frame(&mut ds, 5, &mask).is_none());
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_export() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::export::{encode_png, encode_pnm, encode_tiff, export_array, export_frame, ExportMode};
+    use dicom_rs::modules::lut::{DisplayDepth, Window};
+    use dicom_rs::modules::pixel::{decode_pixel_data, PixelBuffer};
+
+    // Two 2x2 frames of 12-bit samples
+    let mut ds = Dataset::new();
+    for (keyword, value) in [("SamplesPerPixel", 1), ("Rows", 2), ("Columns", 2), ("BitsAllocated", 16), ("BitsStored", 12)] {
+        ds.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    ds.push(DataElement::new("NumberOfFrames", DataElementValue::String("2".to_string())).unwrap());
+    ds.push(DataElement::new("PhotometricInterpretation", DataElementValue::String("MONOCHROME2".to_string())).unwrap());
+    let samples = [0u16, 1000, 2000, 4095, 4095, 2000, 1000, 0];
+    ds.set_pixel_data(samples.iter().flat_map(|v| v.to_le_bytes()).collect());
+
+    let window = Some(Window::new(1000.0, 2000.0));
+    let display = export_array(&ds, 0, ExportMode::Display { window, depth: DisplayDepth::Bits8 }).unwrap();
+    assert_eq!(display.data, PixelBuffer::U8(vec![0, 128, 255, 255]));
+    let raw = export_array(&ds, 1, ExportMode::Raw).unwrap();
+    assert_eq!(raw.data, PixelBuffer::U16(vec![4095, 2000, 1000, 0]));
+
+    assert_eq!(encode_pnm(&display).unwrap(), b"P5\n2 2\n255\n\x00\x80\xff\xff");
+    assert_eq!(&encode_pnm(&raw).unwrap()[..15], b"P5\n2 2\n65535\n\x0f\xff");
+
+    // Signature, then IHDR width, height, bit depth and color type
+    let png = encode_png(&raw).unwrap();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    assert_eq!(&png[16..26], &[0, 0, 0, 2, 0, 0, 0, 2, 16, 0]);
+
+    // One IFD per frame
+    let tiff = encode_tiff(&decode_pixel_data(&ds).unwrap()).unwrap();
+    assert_eq!(&tiff[..4], b"II*\0");
+    let u32_at = |i: usize| u32::from_le_bytes(tiff[i..i + 4].try_into().unwrap()) as usize;
+    let (mut pages, mut ifd) = (0, u32_at(4));
+    while ifd != 0 {
+        pages += 1;
+        let entries = u16::from_le_bytes([tiff[ifd], tiff[ifd + 1]]) as usize;
+        ifd = u32_at(ifd + 2 + entries * 12);
+    }
+    assert_eq!(pages, 2);
+
+    // Color frames are scaled from Bits Stored to the display depth
+    let mut rgb = Dataset::new();
+    for (keyword, value) in [("SamplesPerPixel", 3), ("Rows", 1), ("Columns", 1), ("BitsAllocated", 16), ("BitsStored", 10)] {
+        rgb.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    rgb.push(DataElement::new("PhotometricInterpretation", DataElementValue::String("RGB".to_string())).unwrap());
+    rgb.set_pixel_data([1023u16, 0, 511].iter().flat_map(|v| v.to_le_bytes()).collect());
+    assert_eq!(export_array(&rgb, 0, ExportMode::default()).unwrap().data, PixelBuffer::U8(vec![255, 0, 127]));
+
+    let path = std::env::temp_dir().join("dicom_rs_export_test.ppm");
+    export_frame(&rgb, 0, &path, ExportMode::default()).unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), b"P6\n1 1\n255\n\xff\x00\x7f");
+    std::fs::remove_file(&path).unwrap();
+    assert!(export_frame(&rgb, 0, "image.bmp", ExportMode::default()).is_none());
+}
+
+#[test]
+fn test_secondary_capture() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::iods::{iod_by_sop_class, ModuleUsage};
+    use dicom_rs::modules::capture::{SecondaryCaptureBuilder, MULTI_FRAME_GRAYSCALE_WORD_SC_IMAGE_STORAGE, SECONDARY_CAPTURE_IMAGE_STORAGE};
+    use dicom_rs::modules::io::{read_dicom_bytes, write_dicom_bytes};
+    use dicom_rs::modules::pixel::{decode_frame, decode_pixel_data, PixelArray, PixelBuffer};
+
+    let mut reference = Dataset::new();
+    let reference_values = [
+        ("PatientName", "Doe^Jane"),
+        ("PatientID", "12345"),
+        ("EthnicGroup", "X"),
+        ("StudyInstanceUID", "1.2.3.4"),
+        ("Modality", "CT"),
+    ];
+    for (keyword, value) in reference_values {
+        reference.push(DataElement::new(keyword, DataElementValue::String(value.to_string())).unwrap());
+    }
+
+    // Single RGB frame joining the reference's study
+    let rgb = PixelArray { frames: 1, rows: 2, columns: 2, samples: 3, data: PixelBuffer::U8((0..12).collect()) };
+    let description = DataElement::new("SeriesDescription", DataElementValue::String("Chart".to_string())).unwrap();
+    let sc = SecondaryCaptureBuilder::new(rgb.clone()).with_reference(&reference).with_attribute(description).build().unwrap();
+    assert_eq!(sc.get_str("SOPClassUID"), Some(SECONDARY_CAPTURE_IMAGE_STORAGE));
+    assert_eq!(sc.get_str("PatientName"), Some("Doe^Jane"));
+    assert_eq!(sc.get_str("StudyInstanceUID"), Some("1.2.3.4"));
+    assert_eq!(sc.get_str("Modality"), Some("OT"));
+    assert!(sc.get_str("SeriesInstanceUID").unwrap().starts_with("2.25."));
+    // Patient attributes the module tables do not list are copied too
+    assert_eq!(sc.get_str("EthnicGroup"), Some("X"));
+    // Every Type 1 and 2 attribute of the mandatory modules is present:
+    // the full list once the published Part 3 is vendored, until then the
+    // rows the excerpt has
+    let conforms = |ds: &Dataset| {
+        let iod = iod_by_sop_class(ds.get_str("SOPClassUID").unwrap()).unwrap();
+        let present = |tag: u32| ds.elements().iter().any(|e| e.tag == tag);
+        match iod.required_attributes() {
+            Some(mut required) => required.all(|a| present(a.tag)),
+            None => iod
+                .attributes()
+                .filter(|(m, a)| m.usage == ModuleUsage::Mandatory && a.attr_type.is_required())
+                .all(|(_, a)| present(a.tag)),
+        }
+    };
+    assert!(conforms(&sc));
+
+    // Through a Part 10 file and back
+    let bytes = write_dicom_bytes(&sc).unwrap();
+    assert_eq!(&bytes[128..132], b"DICM");
+    let read = read_dicom_bytes(&bytes);
+    assert_eq!(read.get_str("MediaStorageSOPInstanceUID"), sc.get_str("SOPInstanceUID"));
+    assert_eq!(read.get_str("TransferSyntaxUID"), Some("1.2.840.10008.1.2.1"));
+    assert_eq!(read.get_str("SeriesDescription"), Some("Chart"));
+    assert_eq!(read.get_str("PatientBirthDate"), Some(""));
+    assert_eq!(decode_frame(&read, 0).unwrap(), rgb);
+
+    // Several 16-bit frames make a Multi-frame Grayscale Word SC Image with
+    // a UID of its own
+    let frames = PixelArray { frames: 3, rows: 1, columns: 2, samples: 1, data: PixelBuffer::U16(vec![0, 4095, 1, 2, 3, 4]) };
+    let builder = SecondaryCaptureBuilder::new(frames.clone()).with_bits_stored(12);
+    let mf = builder.build().unwrap();
+    assert_eq!(mf.get_str("SOPClassUID"), Some(MULTI_FRAME_GRAYSCALE_WORD_SC_IMAGE_STORAGE));
+    assert_ne!(mf.get_str("StudyInstanceUID"), builder.build().unwrap().get_str("StudyInstanceUID"));
+    assert_eq!(mf.get_str("PageNumberVector"), Some("1\\2\\3"));
+    assert!(conforms(&mf));
+    for (samples, frames) in [(1, 2), (3, 2)] {
+        let bytes = PixelArray { frames, rows: 1, columns: 2, samples, data: PixelBuffer::U8(vec![7; frames * 2 * samples]) };
+        assert!(conforms(&SecondaryCaptureBuilder::new(bytes).build().unwrap()));
+    }
+    let read = read_dicom_bytes(&write_dicom_bytes(&mf).unwrap());
+    assert_eq!(read.get_int("NumberOfFrames"), Some(3));
+    assert_eq!(read.get_int("BitsStored"), Some(12));
+    assert!(matches!(read.get("FrameIncrementPointer").unwrap().value, Some(DataElementValue::Tag(0x0018, 0x2001))));
+    assert_eq!(decode_pixel_data(&read).unwrap(), frames);
+
+    // 16-bit color has no multi-frame Secondary Capture IOD
+    let color16 = PixelArray { frames: 2, rows: 1, columns: 1, samples: 3, data: PixelBuffer::U16(vec![0; 6]) };
+    assert!(SecondaryCaptureBuilder::new(color16).build().is_none());
+    // Nor are Rows and Columns above 65535
+    let wide = PixelArray { frames: 1, rows: 1, columns: 65536, samples: 1, data: PixelBuffer::U8(vec![0; 65536]) };
+    assert!(SecondaryCaptureBuilder::new(wide).build().is_none());
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_capture_image_input() {
+    use dicom_rs::modules::capture::{decode_jpeg, SecondaryCaptureBuilder};
+    use dicom_rs::modules::pixel::PixelBuffer;
+
+    let gray = decode_jpeg(&jpeg_stream(0xC0, 8, 1, None, &[0x60])).unwrap();
+    assert_eq!(gray.shape(), (1, 8, 8, 1));
+    assert_eq!(gray.data, PixelBuffer::U8(vec![144; 64]));
+    let rgb = decode_jpeg(&jpeg_stream(0xC0, 8, 3, None, &[0x60, 0x0C, 0x1F])).unwrap();
+    assert_eq!((rgb.value(0, 0, 0, 0), rgb.value(0, 0, 0, 1), rgb.value(0, 0, 0, 2)), (166.0, 133.0, 144.0));
+    let sc = SecondaryCaptureBuilder::new(rgb).build().unwrap();
+    assert_eq!(sc.get_str("PhotometricInterpretation"), Some("RGB"));
+
+    // PNG written by the exporter comes back unchanged
+    #[cfg(feature = "export")]
+    {
+        use dicom_rs::modules::capture::decode_png;
+        use dicom_rs::modules::export::encode_png;
+        use dicom_rs::modules::pixel::PixelArray;
+
+        let image = PixelArray { frames: 1, rows: 2, columns: 3, samples: 1, data: PixelBuffer::U16(vec![0, 1, 256, 4095, 65535, 7]) };
+        assert_eq!(decode_png(&encode_png(&image).unwrap()).unwrap(), image);
+        let image = PixelArray { frames: 1, rows: 1, columns: 2, samples: 3, data: PixelBuffer::U8(vec![1, 2, 3, 4, 5, 6]) };
+        assert_eq!(decode_png(&encode_png(&image).unwrap()).unwrap(), image);
+    }
+}
+
+#[test]
+fn test_write_dicom() {
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::codecs::JPEG_BASELINE;
+    use dicom_rs::modules::io::{read_dicom_bytes, write_dicom_bytes};
+    use dicom_rs::modules::pixel::decode_pixel_data;
+
+    let dump = |ds: &Dataset| ds.elements().iter().map(|e| e.to_string()).collect::<Vec<_>>();
+
+    // Implicit VR with a sequence is written back in Implicit VR
+    let mut buf = part10_with_transfer_syntax("1.2.840.10008.1.2");
+    buf.truncate(buf.len() - 10);
+    buf.extend(implicit_le_element(0x0008, 0x0060, b"MR"));
+    buf.extend(implicit_le_element(0x0028, 0x0103, &1u16.to_le_bytes()));
+    let mut item = implicit_le_element(0x0028, 0x3002, &[1, 0, 0, 0, 16, 0]);
+    item.extend(implicit_le_element(0x0028, 0x3006, &7u16.to_le_bytes()));
+    buf.extend_from_slice(&[0x28, 0x00, 0x10, 0x30, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0xFF, 0x00, 0xE0]);
+    buf.extend_from_slice(&(item.len() as u32).to_le_bytes());
+    buf.extend(item);
+    buf.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+    let ds = read_dicom_bytes(&buf);
+    let written = write_dicom_bytes(&ds).unwrap();
+    let again = read_dicom_bytes(&written);
+    assert_eq!(again.get_str("TransferSyntaxUID"), Some("1.2.840.10008.1.2"));
+    assert_eq!(dump(&again), dump(&ds));
+    assert_eq!(write_dicom_bytes(&again).unwrap(), written);
+
+    // Encapsulated frames keep their fragments and offset table
+    let mut buf = part10_with_transfer_syntax(JPEG_BASELINE);
+    buf.extend(explicit_le_element(0x0028, 0x0002, b"US", &1u16.to_le_bytes()));
+    buf.extend(explicit_le_element(0x0028, 0x0004, b"CS", b"MONOCHROME2 "));
+    for element in [0x0010u16, 0x0011, 0x0100] {
+        buf.extend(explicit_le_element(0x0028, element, b"US", &8u16.to_le_bytes()));
+    }
+    let stream = jpeg_stream(0xC0, 8, 1, None, &[0x60]);
+    buf.extend(encapsulated_element(&[0], &[stream[..20].to_vec(), stream[20..].to_vec()]));
+    let ds = read_dicom_bytes(&buf);
+    let again = read_dicom_bytes(&write_dicom_bytes(&ds).unwrap());
+    assert_eq!(again.encapsulated_pixel_data(), ds.encapsulated_pixel_data());
+    assert_eq!(decode_pixel_data(&again), decode_pixel_data(&ds));
+    assert_eq!(dump(&again), dump(&ds));
+}
+
+#[test]
+fn test_thumbnails_and_icons() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::color::decode_rgb_frame;
+    use dicom_rs::modules::io::{read_dicom_bytes, write_dicom_bytes};
+    use dicom_rs::modules::pixel::{decode_frame, PixelBuffer};
+    use dicom_rs::modules::thumbnail::{set_icon_image, thumbnail, thumbnail_size, ICON_MAX_SIZE};
+
+    let image = |samples: u16, bits: u16, rows: u16, columns: u16, pi: &str, pixels: Vec<u8>| {
+        let mut ds = Dataset::new();
+        for (keyword, value) in [("SamplesPerPixel", samples), ("Rows", rows), ("Columns", columns), ("BitsAllocated", bits)] {
+            ds.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+        }
+        ds.push(DataElement::new("PhotometricInterpretation", DataElementValue::String(pi.to_string())).unwrap());
+        ds.set_pixel_data(pixels);
+        ds
+    };
+    assert_eq!(thumbnail_size(200, 300, 64), (43, 64));
+    assert_eq!(thumbnail_size(20, 30, 64), (20, 30));
+
+    // 12-bit horizontal ramp, windowed over its whole range
+    let ramp: Vec<u8> = (0..200).flat_map(|_| (0..300u32).flat_map(|x| ((x * 4095 / 299) as u16).to_le_bytes())).collect();
+    let mut ds = image(1, 16, 200, 300, "MONOCHROME2", ramp);
+    ds.push(DataElement::new("WindowCenter", DataElementValue::String("2048".to_string())).unwrap());
+    ds.push(DataElement::new("WindowWidth", DataElementValue::String("4096".to_string())).unwrap());
+    let thumb = thumbnail(&ds, 0, 64).unwrap();
+    assert_eq!(thumb.shape(), (1, 43, 64, 1));
+    let row: Vec<f64> = (0..64).map(|x| thumb.value(0, 21, x, 0)).collect();
+    assert!(row.windows(2).all(|w| w[1] >= w[0]));
+    assert!(row[0] <= 3.0 && row[63] >= 252.0);
+    assert!((row[32] - 129.5).abs() <= 3.0);
+
+    // Icons are capped at 128 and survive a Part 10 round trip
+    set_icon_image(&mut ds, 500).unwrap();
+    let read = read_dicom_bytes(&write_dicom_bytes(&ds).unwrap());
+    let Some(DataElementValue::Sequence(items)) = &read.get("IconImageSequence").unwrap().value else {
+        panic!("expected a sequence");
+    };
+    assert_eq!(items[0].get_int("Columns"), Some(ICON_MAX_SIZE as i64));
+    assert_eq!(items[0].get_int("BitsAllocated"), Some(8));
+    assert_eq!(items[0].get_str("PhotometricInterpretation"), Some("MONOCHROME2"));
+    assert_eq!(decode_frame(&items[0], 0).unwrap().shape(), (1, 85, 128, 1));
+
+    // Color images get a PALETTE COLOR icon of their colors
+    let pixels: Vec<u8> = (0..16 * 16).flat_map(|i| if i % 16 < 8 { [200, 30, 30] } else { [20, 40, 220] }).collect();
+    let mut ds = image(3, 8, 16, 16, "RGB", pixels);
+    set_icon_image(&mut ds, 64).unwrap();
+    let Some(DataElementValue::Sequence(items)) = &ds.get("IconImageSequence").unwrap().value else {
+        panic!("expected a sequence");
+    };
+    assert_eq!(items[0].get_str("PhotometricInterpretation"), Some("PALETTE COLOR"));
+    assert_eq!(items[0].get_int("SamplesPerPixel"), Some(1));
+    let rgb = decode_rgb_frame(&items[0], 0).unwrap();
+    assert_eq!(rgb.data, PixelBuffer::U16(decode_rgb_frame(&ds, 0).unwrap().data.to_le_bytes().iter().map(|&v| v as u16 * 257).collect()));
+}
+
+#[test]
+fn test_siemens_mosaic() {
+    use dicom_rs::modules::io::{read_dicom_bytes, write_dicom_bytes};
+    use dicom_rs::modules::mosaic::{csa_image_header, images_in_mosaic, is_mosaic, unpack_mosaic};
+    use dicom_rs::modules::pixel::{decode_frame, PixelBuffer};
+
+    // CSA2 header with six items per entry, as Siemens allocates them
+    let csa = |entries: &[(&str, &[&str])]| {
+        let mut out = b"SV10\x04\x03\x02\x01".to_vec();
+        out.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+        out.extend_from_slice(&77u32.to_le_bytes());
+        for (name, values) in entries {
+            let mut field = name.as_bytes().to_vec();
+            field.resize(64, 0);
+            out.extend(field);
+            out.extend_from_slice(&(values.len() as i32).to_le_bytes());
+            out.extend_from_slice(b"DS\0\0");
+            for word in [3i32, 6, 77] {
+                out.extend_from_slice(&word.to_le_bytes());
+            }
+            for item in 0..6 {
+                let mut value = values.get(item).map(|v| format!("{v}\0").into_bytes()).unwrap_or_default();
+                for word in [value.len(), value.len(), 77, value.len()] {
+                    out.extend_from_slice(&(word as i32).to_le_bytes());
+                }
+                value.resize(value.len().next_multiple_of(4), 0);
+                out.extend(value);
+            }
+        }
+        out
+    };
+    // 8x8 mosaic of three 4x4 slices; pixel values are slice * 100 + index
+    let pixels: Vec<u8> = (0..64u16)
+        .flat_map(|i| {
+            let (row, column) = (i / 8, i % 8);
+            let slice = row / 4 * 2 + column / 4;
+            let value = if slice < 3 { slice * 100 + row % 4 * 4 + column % 4 } else { 0 };
+            value.to_le_bytes()
+        })
+        .collect();
+    let mosaic = |with_mr_header: bool| {
+        let mut buf = part10_with_transfer_syntax("1.2.840.10008.1.2.1");
+        buf.extend(explicit_le_element(0x0008, 0x0008, b"CS", b"ORIGINAL\\PRIMARY\\M\\ND\\MOSAIC"));
+        buf.extend(explicit_le_element(0x0018, 0x0050, b"DS", b"3 "));
+        buf.extend(explicit_le_element(0x0018, 0x0088, b"DS", b"3.5 "));
+        if with_mr_header {
+            buf.extend(explicit_le_element(0x0019, 0x0010, b"LO", b"SIEMENS MR HEADER "));
+            buf.extend(explicit_le_element(0x0019, 0x100A, b"US", &3u16.to_le_bytes()));
+        }
+        buf.extend(explicit_le_element(0x0020, 0x0032, b"DS", b"-100\\-100\\10"));
+        buf.extend(explicit_le_element(0x0020, 0x0037, b"DS", b"1\\0\\0\\0\\1\\0 "));
+        buf.extend(explicit_le_element(0x0028, 0x0002, b"US", &1u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0004, b"CS", b"MONOCHROME2 "));
+        buf.extend(explicit_le_element(0x0028, 0x0010, b"US", &8u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0011, b"US", &8u16.to_le_bytes()));
+        buf.extend(explicit_le_element(0x0028, 0x0030, b"DS", b"2\\1 "));
+        for (element, value) in [(0x0100u16, 16u16), (0x0101, 12), (0x0102, 11), (0x0103, 0)] {
+            buf.extend(explicit_le_element(0x0028, element, b"US", &value.to_le_bytes()));
+        }
+        // Block 0x11 rather than 0x10, so the creator has to be looked up
+        buf.extend(explicit_le_element(0x0029, 0x0011, b"LO", b"SIEMENS CSA HEADER"));
+        let header = csa(&[("NumberOfImagesInMosaic", &["3"]), ("SliceNormalVector", &["0", "0", "-1"])]);
+        buf.extend(explicit_le_element(0x0029, 0x1110, b"OB", &header));
+        buf.extend(explicit_le_element(0x7FE0, 0x0010, b"OW", &pixels));
+        read_dicom_bytes(&buf)
+    };
+
+    let ds = mosaic(true);
+    assert!(is_mosaic(&ds));
+    assert_eq!(images_in_mosaic(&ds), Some(3));
+    assert_eq!(csa_image_header(&ds).unwrap()[1].values, ["0", "0", "-1"]);
+    // Without the MR header the count comes from the CSA header
+    assert_eq!(images_in_mosaic(&mosaic(false)), Some(3));
+
+    let slices = unpack_mosaic(&ds).unwrap();
+    assert_eq!(slices.len(), 3);
+    let sop_uids: std::collections::HashSet<_> = slices.iter().map(|s| s.get_str("SOPInstanceUID").unwrap()).collect();
+    assert_eq!(sop_uids.len(), 3);
+    let expected = [("-98\\-96\\10", "-10"), ("-98\\-96\\6.5", "-6.5"), ("-98\\-96\\3", "-3")];
+    for (k, slice) in slices.iter().enumerate() {
+        assert!(!is_mosaic(slice));
+        assert_eq!(slice.get_str("ImageType"), Some("ORIGINAL\\PRIMARY\\M\\ND"));
+        assert_eq!((slice.get_str("ImagePositionPatient"), slice.get_str("SliceLocation")), (Some(expected[k].0), Some(expected[k].1)));
+        let read = read_dicom_bytes(&write_dicom_bytes(slice).unwrap());
+        let frame = decode_frame(&read, 0).unwrap();
+        assert_eq!(frame.shape(), (1, 4, 4, 1));
+        assert_eq!(frame.data, PixelBuffer::U16((0..16).map(|i| k as u16 * 100 + i).collect()));
+    }
+}
+
+#[test]
+fn test_frame_range_decoding() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::codecs::{encode_dataset, RLE_LOSSLESS};
+    use dicom_rs::modules::pixel::{decode_frame_range, decode_frame_range_into, decode_pixel_data, PixelBuffer};
+
+    // 40 frames of 16x16 16-bit samples, each frame a different ramp
+    let mut ds = Dataset::new();
+    for (keyword, value) in [("Rows", 16u16), ("Columns", 16), ("BitsAllocated", 16), ("NumberOfFrames", 40)] {
+        ds.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    let samples: Vec<u16> = (0..40 * 256u32).map(|i| ((i / 256) * 1000 + i % 256 * 3) as u16).collect();
+    ds.set_pixel_data(samples.iter().flat_map(|v| v.to_le_bytes()).collect());
+    let native = decode_frame_range(&ds, 5..9).unwrap();
+    assert_eq!(native.frames, 4);
+    assert_eq!(native.data, PixelBuffer::U16(samples[5 * 256..9 * 256].to_vec()));
+
+    let ds = encode_dataset(&ds, RLE_LOSSLESS).unwrap();
+    assert_eq!(decode_pixel_data(&ds).unwrap().data, PixelBuffer::U16(samples.clone()));
+
+    // Frames land in order in the caller's buffer
+    let mut out = vec![0u8; 30 * 512];
+    decode_frame_range_into(&ds, 10..40, &mut out).unwrap();
+    let expected: Vec<u8> = samples[10 * 256..].iter().flat_map(|v| v.to_le_bytes()).collect();
+    assert_eq!(out, expected);
+    assert_eq!(decode_frame_range(&ds, 39..40).unwrap().data, PixelBuffer::U16(samples[39 * 256..].to_vec()));
+    assert_eq!(decode_frame_range(&ds, 0..0).unwrap().frames, 0);
+
+    // Wrong buffer sizes and ranges past the last frame are refused
+    assert!(decode_frame_range_into(&ds, 10..40, &mut [0u8; 100]).is_none());
+    assert!(decode_frame_range(&ds, 38..41).is_none());
+
+    // Header values claiming far more frames than there are fragments are
+    // refused before anything is allocated for them
+    let mut huge = ds.clone();
+    for keyword in ["Rows", "Columns", "NumberOfFrames"] {
+        huge.set(DataElement::new(keyword, DataElementValue::UInt16(65535)).unwrap());
+    }
+    assert!(decode_pixel_data(&huge).is_none());
+    assert!(decode_frame_range(&huge, 0..41).is_none());
+    // So are frames the codestreams are far too small for
+    let mut huge = ds.clone();
+    for keyword in ["Rows", "Columns"] {
+        huge.set(DataElement::new(keyword, DataElementValue::UInt16(65535)).unwrap());
+    }
+    assert!(decode_pixel_data(&huge).is_none());
+
+    // Offset tables must start at 0 and give every frame its own fragments
+    let mut encapsulated = ds.encapsulated_pixel_data().unwrap().clone();
+    assert_eq!(encapsulated.frame_fragments(40).unwrap()[1], 1..2);
+    encapsulated.offset_table[2] = encapsulated.offset_table[1];
+    assert!(encapsulated.frame_fragments(40).is_none());
+    let mut encapsulated = ds.encapsulated_pixel_data().unwrap().clone();
+    encapsulated.fragments.insert(0, vec![0; 8]);
+    encapsulated.offset_table.iter_mut().for_each(|o| *o += 16);
+    assert!(encapsulated.frame_fragments(40).is_none());
+}
+
+#[test]
+fn test_bulk_byte_swapping() {
+    use dicom_rs::byteswap::{f64s, swap_bytes, u16s};
+    use dicom_rs::dataelem::{attribute_by_keyword, DataElement, DataElementValue, DicomVr};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::io::{read_dicom_bytes, write_dicom_bytes, write_dicom_bytes_as};
+    use dicom_rs::modules::pixel::{decode_frame, decode_pixel_data};
+    use dicom_rs::uids::{Endianness, EXPLICIT_VR_BIG_ENDIAN, EXPLICIT_VR_LITTLE_ENDIAN};
+
+    // Whole values are reversed, a trailing partial value is left alone
+    let bytes: Vec<u8> = (0..150).collect();
+    for width in [2, 4, 8] {
+        let mut swapped = bytes.clone();
+        swap_bytes(&mut swapped, width);
+        let mut expected = bytes.clone();
+        expected.chunks_exact_mut(width).for_each(|v| v.reverse());
+        assert_eq!(swapped, expected);
+    }
+    assert_eq!(u16s(&[1, 2, 3], Endianness::Big), [0x0102]);
+    assert_eq!(f64s(&1.5f64.to_be_bytes(), Endianness::Big), [1.5]);
+
+    // Signed 12-bit samples and an FD triplet survive a big endian round trip
+    let mut ds = Dataset::new();
+    let attributes = [("Rows", 3u16), ("Columns", 3), ("BitsAllocated", 16), ("BitsStored", 12), ("PixelRepresentation", 1)];
+    for (keyword, value) in attributes {
+        ds.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    let gradient: Vec<u8> = [0.5f64, -0.25, 0.75].iter().flat_map(|v| v.to_le_bytes()).collect();
+    ds.push(DataElement::new("DiffusionGradientOrientation", DataElementValue::Data(gradient)).unwrap());
+    let samples: [i16; 9] = [-2048, -1, 0, 1, 2047, 100, -100, 5, 7];
+    ds.set_pixel_data(samples.iter().flat_map(|v| v.to_le_bytes()).collect());
+    let be = read_dicom_bytes(&write_dicom_bytes_as(&ds, EXPLICIT_VR_BIG_ENDIAN).unwrap());
+    assert_eq!(be.transfer_syntax().unwrap().endian, Endianness::Big);
+    assert_eq!(be.pixel_data(), ds.pixel_data());
+    assert_eq!(decode_pixel_data(&be), decode_pixel_data(&ds));
+    let value = be.get("DiffusionGradientOrientation").unwrap().value.as_ref().unwrap();
+    assert_eq!(value.to_f64s(), [0.5, -0.25, 0.75]);
+    assert_eq!(write_dicom_bytes_as(&be, EXPLICIT_VR_LITTLE_ENDIAN).unwrap(), write_dicom_bytes(&ds).unwrap());
+
+    // 8-bit OW samples are swapped by word, across odd-sized frames
+    let mut ds = Dataset::new();
+    for (keyword, value) in [("Rows", 3u16), ("Columns", 3), ("BitsAllocated", 8)] {
+        ds.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+    }
+    ds.push(DataElement::new("NumberOfFrames", DataElementValue::String("2".to_string())).unwrap());
+    let attribute = attribute_by_keyword("PixelData").unwrap();
+    ds.push(DataElement { attribute, tag: 0x7FE0_0010, vr: DicomVr::Ow, value: None });
+    ds.set_pixel_data((10..28).collect());
+    let be = read_dicom_bytes(&write_dicom_bytes_as(&ds, EXPLICIT_VR_BIG_ENDIAN).unwrap());
+    let bytes = write_dicom_bytes_as(&ds, EXPLICIT_VR_BIG_ENDIAN).unwrap();
+    let start = bytes.len() - 18;
+    assert_eq!(bytes[start..start + 4], [11, 10, 13, 12]);
+    assert_eq!(be.pixel_data(), ds.pixel_data());
+    assert_eq!(decode_frame(&be, 1), decode_frame(&ds, 1));
+}
+
+#[test]
+fn test_scan_directory() {
+    use dicom_rs::dataelem::{DataElement, DataElementValue};
+    use dicom_rs::dataset::Dataset;
+    use dicom_rs::modules::io::{read_dicom_header, write_dicom};
+    use dicom_rs::modules::scan::scan_directory;
+
+    let dir = std::env::temp_dir().join(format!("dicom-rs-scan-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("a/b")).unwrap();
+
+    // 200x200 16-bit images, so Pixel Data is past the first chunk read
+    let image = |attributes: &[(&str, &str)]| {
+        let mut ds = Dataset::new();
+        for (keyword, value) in attributes {
+            ds.push(DataElement::new(keyword, DataElementValue::String(value.to_string())).unwrap());
+        }
+        for (keyword, value) in [("Rows", 200u16), ("Columns", 200), ("BitsAllocated", 16)] {
+            ds.push(DataElement::new(keyword, DataElementValue::UInt16(value)).unwrap());
+        }
+        ds.set_pixel_data(vec![7; 200 * 200 * 2]);
+        ds
+    };
+    let ct = |patient: &str, study: &str, date: &str, series: &str, number: &str, sop: &str| {
+        image(&[
+            ("PatientID", patient),
+            ("PatientName", "DOE^JANE"),
+            ("StudyInstanceUID", study),
+            ("StudyDate", date),
+            ("SeriesInstanceUID", series),
+            ("SeriesNumber", number),
+            ("Modality", if number == "1" { "CT" } else { "SR" }),
+            ("SOPClassUID", "1.2.840.10008.5.1.4.1.1.2"),
+            ("SOPInstanceUID", sop),
+            ("InstanceNumber", &sop[sop.len() - 1..]),
+        ])
+    };
+    let files = [
+        ("a/3.dcm", ct("P1", "1.2.3", "20240102", "1.2.3.1", "1", "1.2.3.1.3")),
+        ("a/1.dcm", ct("P1", "1.2.3", "20240102", "1.2.3.1", "1", "1.2.3.1.1")),
+        ("a/b/2.dcm", ct("P1", "1.2.3", "20240102", "1.2.3.2", "2", "1.2.3.2.1")),
+        ("a/b/copy.dcm", ct("P1", "1.2.3", "20240102", "1.2.3.1", "1", "1.2.3.1.1")),
+        ("0.dcm", ct("P1", "1.2.4", "20230505", "1.2.4.1", "1", "1.2.4.1.1")),
+        ("other.dcm", ct("P2", "1.2.5", "20240101", "1.2.5.1", "1", "1.2.5.1.1")),
+        ("no-series.dcm", image(&[("PatientID", "P3"), ("StudyInstanceUID", "1.2.6"), ("SOPInstanceUID", "1.2.6.1")])),
+    ];
+    for (name, ds) in &files {
+        write_dicom(ds, dir.join(name)).unwrap();
+    }
+    std::fs::write(dir.join("a/b/notes.txt"), "not a DICOM file").unwrap();
+    // Part 10, but with a transfer syntax the reader does not know
+    let mut unknown = part10_with_transfer_syntax("1.2.3.4.5");
+    unknown.extend(explicit_le_element(0x0008, 0x0018, b"UI", b"1.2.7.1\0"));
+    std::fs::write(dir.join("a/unknown-ts.dcm"), &unknown).unwrap();
+
+    // An undefined-length sequence before the UIDs and Pixel Data
+    let mut buf = part10_with_transfer_syntax("1.2.840.10008.1.2.1");
+    buf.extend_from_slice(&[0x08, 0x00, 0x40, 0x11, b'S', b'Q', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+    buf.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF]);
+    buf.extend(explicit_le_element(0x0008, 0x1155, b"UI", b"1.2.9.9\0"));
+    buf.extend_from_slice(&[0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0, 0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+    buf.extend(explicit_le_element(0x0008, 0x0018, b"UI", b"1.2.5.2.1\0"));
+    buf.extend(explicit_le_element(0x0010, 0x0020, b"LO", b"P2"));
+    buf.extend(explicit_le_element(0x0020, 0x000D, b"UI", b"1.2.5\0"));
+    buf.extend(explicit_le_element(0x0020, 0x000E, b"UI", b"1.2.5.2\0"));
+    buf.extend(explicit_le_element(0x7FE0, 0x0010, b"OB", &vec![0; 300_000]));
+    std::fs::write(dir.join("a/b/seq.dcm"), &buf).unwrap();
+    let header = read_dicom_header(dir.join("a/b/seq.dcm")).unwrap();
+    assert!(header.pixel_data().is_none());
+    assert_eq!(header.get_str("SeriesInstanceUID"), Some("1.2.5.2"));
+    assert!(read_dicom_header(dir.join("a/1.dcm")).unwrap().pixel_data().is_none());
+
+    let tree = scan_directory(&dir);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(tree.non_dicom, [dir.join("a/b/notes.txt")]);
+    let errors = [
+        (dir.join("a/unknown-ts.dcm"), "Unknown transfer syntax 1.2.3.4.5".to_string()),
+        (dir.join("no-series.dcm"), "no SeriesInstanceUID".to_string()),
+    ];
+    assert_eq!(tree.errors, errors);
+    assert_eq!(tree.duplicates.len(), 1);
+    assert_eq!((&tree.duplicates[0].kept, &tree.duplicates[0].duplicate), (&dir.join("a/1.dcm"), &dir.join("a/b/copy.dcm")));
+
+    assert_eq!(tree.patients.iter().map(|p| p.patient_id.as_str()).collect::<Vec<_>>(), ["P1", "P2"]);
+    assert_eq!(tree.number_of_instances(), 6);
+    let p1 = tree.patient("P1").unwrap();
+    assert_eq!((p1.patient_name.as_deref(), p1.number_of_studies(), p1.number_of_instances()), (Some("DOE^JANE"), 2, 4));
+    // Studies by date, series by number, instances by number
+    assert_eq!(p1.studies[0].study_instance_uid, "1.2.4");
+    let study = tree.study("1.2.3").unwrap();
+    assert_eq!((study.number_of_series(), study.number_of_instances()), (2, 3));
+    assert_eq!(study.modalities(), ["CT", "SR"]);
+    let series = tree.find_series("1.2.3.1").unwrap();
+    let numbers: Vec<_> = series.instances.iter().map(|i| i.instance_number).collect();
+    assert_eq!(numbers, [Some(1), Some(3)]);
+    assert_eq!(tree.instance("1.2.5.2.1").unwrap().path, dir.join("a/b/seq.dcm"));
+    assert_eq!(tree.patient("P2").unwrap().studies[0].series.len(), 2);
 }